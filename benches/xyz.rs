@@ -0,0 +1,40 @@
+/*!
+Tristimulus value calculation benchmarks.
+
+Calculates the tristimulus values of a batch of 100 LED, and thermal radiator spectra, both as
+lights, and as illuminants for a gray sample.
+Run with `cargo bench --features bench-utils --bench xyz`.
+
+Reference timings, on a single x86-64 core:
+- 37 µs for the batch of 100 lights;
+- 92 µs for the batch of 100 illuminated samples.
+
+The `xyz_batch` group calculates the tristimulus values of a batch of 10,000 lights as a single
+matrix product, and in parallel with the `rayon` feature.
+*/
+
+use colorimetry::{bench_utils::mixed_batch, prelude::*};
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+
+fn xyz_from_spectrum(c: &mut Criterion) {
+    let spectra = mixed_batch(100);
+    let mut group = c.benchmark_group("xyz");
+    group.throughput(Throughput::Elements(spectra.len() as u64));
+    group.bench_function("light_batch_100", |b| {
+        b.iter(|| spectra.iter().map(|s| CIE1931.xyz_from_spectrum(black_box(s), None).luminous_value()).sum::<f64>())
+    });
+    let colorant = Colorant::gray(0.5);
+    group.bench_function("sample_batch_100", |b| {
+        b.iter(|| spectra.iter().map(|s| CIE1931.xyz(black_box(s), Some(&colorant)).luminous_value()).sum::<f64>())
+    });
+    group.finish();
+
+    let spectra: Vec<Spectrum> = mixed_batch(10_000).iter().map(|s| **s).collect();
+    let mut group = c.benchmark_group("xyz_batch");
+    group.throughput(Throughput::Elements(spectra.len() as u64));
+    group.bench_function("light_batch_10000", |b| b.iter(|| CIE1931.xyz_batch(black_box(&spectra))));
+    group.finish();
+}
+
+criterion_group!(benches, xyz_from_spectrum);
+criterion_main!(benches);
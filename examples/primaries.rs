@@ -13,7 +13,7 @@ struct Gauss{
 
 impl Gauss {
     fn new(xyz: XYZ, d: StdIlluminant) -> Self {
-        let [x, y] = xyz.chromaticity();
+        let [x, y] = xyz.xy().values();
         Self { x, y, d }
     }
 }
@@ -25,7 +25,7 @@ impl CostFunction for Gauss{
 
     fn cost(&self, param: &Self::Param) -> Result<Self::Output, argmin::core::Error> {
         let [l, w] = param.clone().try_into().unwrap();
-        let [xt, yt] = CIE1931.xyz(&StdIlluminant::D65, Some(&Colorant::gaussian(l, w))).chromaticity();
+        let [xt, yt] = CIE1931.xyz(&StdIlluminant::D65, Some(&Colorant::gaussian(l, w))).xy().values();
       //  println!("({l},{w}) cost: {xt:.4}, {yt:.4}");
         Ok((xt-self.x).hypot(yt-self.y))
     }
@@ -41,7 +41,7 @@ struct GaussWithAnchor{
 
 impl GaussWithAnchor {
     fn new(xyz: XYZ, anchor: XYZ, d: StdIlluminant) -> Self {
-        let [x,y] = xyz.chromaticity();
+        let [x,y] = xyz.xy().values();
         Self { x, y, anchor: anchor.set_illuminance(100.0), d}
     }
 }
@@ -55,7 +55,7 @@ impl CostFunction for GaussWithAnchor{
         let [l, w, c]: [f64;3] = param.clone().try_into().unwrap(); 
         let r = CIE1931.xyz(&StdIlluminant::D65, Some(&Colorant::gaussian(l, w))).set_illuminance(100.0);
         let t = c * self.anchor + (1.0-c) * r;
-        let [xt, yt] = t.chromaticity();
+        let [xt, yt] = t.xy().values();
         Ok((xt-self.x).hypot(yt-self.y))
     }
 }
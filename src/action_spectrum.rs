@@ -0,0 +1,221 @@
+/*!
+# Action Spectra
+
+An action spectrum describes the relative effectiveness of radiation, as a function of wavelength,
+for a photobiological, photochemical, or photometric effect. The effective quantity of a light
+source is its spectral irradiance, or radiance, weighted by the action spectrum, and scaled by a
+constant, such as the maximum luminous efficacy of 683 lm/W for the photopic luminous efficiency
+function V(λ).
+
+An [`ActionSpectrum`] combines these weights with a name, and the unit of the weighted quantity,
+and an [`ActionSpectrumRegistry`] collects them, so that applications can evaluate a user
+selected set of effects uniformly, with their own action spectra added to the standard ones:
+- `"photopic"`, the CIE 1931 V(λ) function, for illuminance, in lux;
+- `"scotopic"`, the CIE 1951 V'(λ) function, for scotopic illuminance, in scotopic lux;
+- `"erythemal"`, the CIE/ISO 17166 erythema reference action spectrum, for erythemally weighted
+  irradiance, in W/m², of which only the tail above 380 nanometer is within the wavelength domain
+  of this library;
+- `"photosynthetic"`, the photosynthetic photon flux density, the photon irradiance from 400 to
+  700 nanometer, in µmol/m²/s.
+
+Spectral data of lights is taken as spectral irradiance in W/m²/nm.
+```
+use colorimetry::prelude::*;
+
+let registry = ActionSpectrumRegistry::standard();
+let lamp = Illuminant::planckian(3000.0).set_illuminance(&CIE1931, 500.0);
+let lux = registry.weight("photopic", &lamp).unwrap();
+approx::assert_abs_diff_eq!(lux, 500.0, epsilon = 0.01);
+for (name, value, unit) in registry.evaluate(&lamp) {
+    println!("{name}: {value:.3} {unit}");
+}
+```
+*/
+
+use nalgebra::SVector;
+
+use crate::{
+    data::observers::CIE1931,
+    error::CmtError,
+    math,
+    mesopic::CIE1951_SCOTOPIC,
+    physics::{C, H},
+    spectrum::{Spectrum, NS},
+    traits::Light,
+    units::{KM, KM_PRIME},
+};
+
+/// Avogadro constant, in 1/mol.
+const N_A: f64 = 6.02214076E23;
+
+/// Spectrum from a function of wavelength, in nanometer.
+fn spectrum_from_fn(f: impl Fn(f64) -> f64) -> Spectrum {
+    Spectrum(SVector::<f64, NS>::from_fn(|i, _| f((i + 380) as f64)))
+}
+
+/// A named action spectrum, with a constant scaling the weighted sum to the unit of the effective
+/// quantity.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ActionSpectrum {
+    name: String,
+    unit: String,
+    constant: f64,
+    weights: Spectrum,
+}
+
+impl ActionSpectrum {
+    /// Action spectrum with relative weights, and a unit constant of 1.0.
+    pub fn new(name: &str, unit: &str, weights: Spectrum) -> Self {
+        Self { name: name.to_string(), unit: unit.to_string(), constant: 1.0, weights }
+    }
+
+    /// Sets the constant, by which the weighted spectral values are multiplied.
+    pub fn set_constant(mut self, constant: f64) -> Self {
+        self.constant = constant;
+        self
+    }
+
+    /// The CIE 1931 photopic luminous efficiency function V(λ), for illuminance in lux.
+    pub fn photopic() -> Self {
+        Self::new("photopic", "lx", Spectrum(CIE1931.data.row(1).transpose())).set_constant(KM)
+    }
+
+    /// The CIE 1951 scotopic luminous efficiency function V'(λ), for scotopic illuminance.
+    pub fn scotopic() -> Self {
+        Self::new("scotopic", "scotopic lx", *CIE1951_SCOTOPIC).set_constant(KM_PRIME)
+    }
+
+    /// The CIE/ISO 17166 erythema reference action spectrum, for erythemally weighted irradiance.
+    pub fn erythemal() -> Self {
+        let weights = spectrum_from_fn(|l| match l {
+            l if l <= 298.0 => 1.0,
+            l if l <= 328.0 => math::powf(10.0, 0.094 * (298.0 - l)),
+            l if l <= 400.0 => math::powf(10.0, 0.015 * (140.0 - l)),
+            _ => 0.0,
+        });
+        Self::new("erythemal", "W/m²", weights)
+    }
+
+    /// Photon flux weighting from 400 to 700 nanometer, for the photosynthetic photon flux density.
+    pub fn photosynthetic() -> Self {
+        let weights = spectrum_from_fn(|l| if (400.0..=700.0).contains(&l) { l * 1E-9 / (H * C * N_A) * 1E6 } else { 0.0 });
+        Self::new("photosynthetic", "µmol/m²/s", weights)
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn unit(&self) -> &str {
+        &self.unit
+    }
+
+    pub fn constant(&self) -> f64 {
+        self.constant
+    }
+
+    /// Relative weights, at 1 nanometer intervals, from 380 to 780 nanometer.
+    pub fn weights(&self) -> &Spectrum {
+        &self.weights
+    }
+
+    /// Effective quantity of a light, in the unit of the action spectrum.
+    pub fn weight(&self, light: &dyn Light) -> f64 {
+        self.constant * self.weights.0.dot(&light.spectrum().0)
+    }
+}
+
+/**
+A collection of action spectra, by name.
+
+The standard registry contains the photopic, scotopic, erythemal, and photosynthetic action
+spectra; [`ActionSpectrumRegistry::new`] creates an empty registry.
+```
+use colorimetry::prelude::*;
+
+// a custom action spectrum, for the blue light content between 440 and 490 nanometer
+let blue = Colorant::top_hat(465.0, 50.0).spectrum().into_owned();
+let registry = ActionSpectrumRegistry::standard()
+    .register(ActionSpectrum::new("blue", "W/m²", blue));
+assert!(registry.weight("blue", &Illuminant::d65()).unwrap() > 0.0);
+assert!(registry.weight("green", &Illuminant::d65()).is_err());
+```
+*/
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ActionSpectrumRegistry {
+    spectra: Vec<ActionSpectrum>,
+}
+
+impl ActionSpectrumRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registry with the standard action spectra.
+    pub fn standard() -> Self {
+        Self::new()
+            .register(ActionSpectrum::photopic())
+            .register(ActionSpectrum::scotopic())
+            .register(ActionSpectrum::erythemal())
+            .register(ActionSpectrum::photosynthetic())
+    }
+
+    /// Adds an action spectrum; an action spectrum with the same name is replaced.
+    pub fn register(mut self, action_spectrum: ActionSpectrum) -> Self {
+        self.spectra.retain(|a| a.name != action_spectrum.name);
+        self.spectra.push(action_spectrum);
+        self
+    }
+
+    /// An action spectrum, by name.
+    pub fn get(&self, name: &str) -> Option<&ActionSpectrum> {
+        self.spectra.iter().find(|a| a.name == name)
+    }
+
+    /// Names of the action spectra, in the order of registration.
+    pub fn names(&self) -> Vec<&str> {
+        self.spectra.iter().map(|a| a.name.as_str()).collect()
+    }
+
+    /// Effective quantity of a light, for an action spectrum by name.
+    pub fn weight(&self, name: &str, light: &dyn Light) -> Result<f64, CmtError> {
+        self.get(name)
+            .map(|a| a.weight(light))
+            .ok_or_else(|| CmtError::ErrorString(format!("ActionSpectrum: unknown action spectrum \"{name}\"")))
+    }
+
+    /// Name, effective quantity, and unit, for all action spectra.
+    pub fn evaluate(&self, light: &dyn Light) -> Vec<(String, f64, String)> {
+        self.spectra.iter().map(|a| (a.name.clone(), a.weight(light), a.unit.clone())).collect()
+    }
+}
+
+#[cfg(test)]
+mod action_spectrum_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_action_spectrum() {
+        let lamp = Illuminant::planckian(4000.0).set_illuminance(&CIE1931, 1000.0);
+        let registry = ActionSpectrumRegistry::standard();
+        assert_eq!(registry.names(), ["photopic", "scotopic", "erythemal", "photosynthetic"]);
+        assert_abs_diff_eq!(registry.weight("scotopic", &lamp).unwrap(), scotopic_value(&lamp.spectrum()), epsilon = 1E-9);
+
+        // about 1.4 µmol/m²/s per watt, for 1 W/m² at 555 nm
+        let ppfd = ActionSpectrum::photosynthetic().weights()[555];
+        assert_abs_diff_eq!(ppfd, 4.639, epsilon = 1E-3);
+        // about 15 µmol/m²/s per 1000 lux for white light
+        let value = registry.weight("photosynthetic", &lamp).unwrap();
+        assert!(value > 12.0 && value < 20.0);
+
+        let erythemal = ActionSpectrum::erythemal();
+        assert_abs_diff_eq!(erythemal.weights()[380], 10f64.powf(-3.6), epsilon = 1E-12);
+        assert_eq!(erythemal.weights()[401], 0.0);
+
+        // registering an action spectrum with an existing name replaces it
+        let registry = registry.register(ActionSpectrum::photopic().set_constant(1.0));
+        assert_eq!(registry.names().len(), 4);
+        assert_abs_diff_eq!(registry.weight("photopic", &lamp).unwrap(), ActionSpectrum::photopic().weight(&lamp) / KM, epsilon = 1E-12);
+    }
+}
@@ -0,0 +1,149 @@
+/*!
+# Lamp Aging
+
+Light sources lose output during their life, and their spectral distribution changes as they
+age: in phosphor converted white LEDs for example, the phosphor degrades faster than the blue
+pump LED, which makes them bluer, and increases their correlated color temperature.
+
+A lamp's lumen depreciation is specified by its lumen maintenance factor, such as 0.7 at the end
+of life for an L70 rating, as used in IES LM-80 and TM-21.
+An [`Aging`] model combines this with relative spectral changes, and projects the spectral
+distribution of a lamp at the end of its life, from its initial spectral distribution, to
+estimate the shift in its correlated color temperature, and color rendering.
+*/
+
+use crate::{
+    data::observers::CIE1931,
+    illuminant::Illuminant,
+    math,
+    spectrum::{Spectrum, NS},
+};
+
+#[cfg(feature = "cct")]
+use crate::error::CmtError;
+
+/// Width, in nanometer, of the transition from pump to phosphor emission, in the phosphor decay
+/// model.
+const PHOSPHOR_TRANSITION: f64 = 10.0;
+
+/**
+Spectral aging model for a light source.
+```
+use colorimetry::prelude::*;
+
+// white LED: phosphor emission decays by 10% relative to the blue pump, with an L70 lumen maintenance
+let spd = *Illuminant::led(450.0, 20.0) * 0.4 + *Illuminant::led(570.0, 110.0) * 2.0;
+let values: &[f64] = spd.as_ref();
+let led = Illuminant::try_from(values).unwrap();
+let aging = Aging::new().set_phosphor_decay(490.0, 0.9).set_lumen_maintenance(0.7);
+let aged = aging.apply(&led);
+approx::assert_abs_diff_eq!(aged.illuminance(&CIE1931), 0.7 * led.illuminance(&CIE1931), epsilon = 1E-9);
+assert!(aging.cct_shift(&led).unwrap() > 0.0);
+```
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub struct Aging {
+    lumen_maintenance: f64,
+    bands: Vec<(f64, f64, f64)>,
+    phosphor_decay: Option<(f64, f64)>,
+}
+
+impl Default for Aging {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Aging {
+    /// A model without aging, with a lumen maintenance factor of 1.0.
+    pub fn new() -> Self {
+        Self { lumen_maintenance: 1.0, bands: Vec::new(), phosphor_decay: None }
+    }
+
+    /// Sets the lumen maintenance factor, as the ratio of the luminous output of the aged lamp to
+    /// its initial output, such as 0.7 for an L70 rating.
+    pub fn set_lumen_maintenance(mut self, factor: f64) -> Self {
+        self.lumen_maintenance = factor;
+        self
+    }
+
+    /// Adds a relative scale factor for the wavelength band from `start` to `end`, in nanometer,
+    /// with factors of overlapping bands multiplied.
+    pub fn add_band(mut self, start: f64, end: f64, factor: f64) -> Self {
+        self.bands.push((start, end, factor));
+        self
+    }
+
+    /// Sets a phosphor decay profile: emission at wavelengths above the `boundary` wavelength, in
+    /// nanometer, is scaled by a factor relative to the emission of the pump below it, with a
+    /// smooth transition.
+    pub fn set_phosphor_decay(mut self, boundary: f64, factor: f64) -> Self {
+        self.phosphor_decay = Some((boundary, factor));
+        self
+    }
+
+    /// The relative spectral change of the model, excluding its lumen maintenance.
+    pub fn spectral_factors(&self) -> Spectrum {
+        let mut s = Spectrum([1.0; NS].into());
+        for (i, v) in s.0.iter_mut().enumerate() {
+            let l = (i + 380) as f64;
+            for &(start, end, factor) in &self.bands {
+                if l >= start && l <= end {
+                    *v *= factor;
+                }
+            }
+            if let Some((boundary, factor)) = self.phosphor_decay {
+                let w = 0.5 * (1.0 + math::tanh((l - boundary) / PHOSPHOR_TRANSITION));
+                *v *= 1.0 + w * (factor - 1.0);
+            }
+        }
+        s
+    }
+
+    /// The spectral distribution of the aged lamp, with its illuminance scaled by the lumen
+    /// maintenance factor.
+    pub fn apply(&self, illuminant: &Illuminant) -> Illuminant {
+        let aged = Illuminant(**illuminant * self.spectral_factors());
+        let illuminance = self.lumen_maintenance * illuminant.illuminance(&CIE1931);
+        aged.set_illuminance(&CIE1931, illuminance)
+    }
+
+    /// The change in correlated color temperature, in Kelvin, of the aged lamp.
+    #[cfg(feature = "cct")]
+    pub fn cct_shift(&self, illuminant: &Illuminant) -> Result<f64, CmtError> {
+        let cct = |ill: &Illuminant| CIE1931.xyz(ill, None).cct().map(|c| c.t());
+        Ok(cct(&self.apply(illuminant))? - cct(illuminant)?)
+    }
+
+    /// The change in general color rendering index R<sub>a</sub> of the aged lamp.
+    #[cfg(feature = "cri")]
+    pub fn ra_shift(&self, illuminant: &Illuminant) -> Result<f64, CmtError> {
+        Ok(self.apply(illuminant).cri()?.ra() - illuminant.cri()?.ra())
+    }
+}
+
+#[cfg(test)]
+mod aging_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_aging() {
+        let aging = Aging::new().add_band(380.0, 480.0, 0.8).add_band(450.0, 500.0, 0.5);
+        let f = aging.spectral_factors();
+        assert_abs_diff_eq!(f[400], 0.8);
+        assert_abs_diff_eq!(f[460], 0.4);
+        assert_abs_diff_eq!(f[490], 0.5);
+        assert_abs_diff_eq!(f[600], 1.0);
+
+        let f = Aging::new().set_phosphor_decay(500.0, 0.8).spectral_factors();
+        assert_abs_diff_eq!(f[450], 1.0, epsilon = 1E-4);
+        assert_abs_diff_eq!(f[500], 0.9, epsilon = 1E-12);
+        assert_abs_diff_eq!(f[600], 0.8, epsilon = 1E-4);
+
+        // uniform depreciation does not change the spectral shape
+        let a = Illuminant::planckian(2856.0);
+        let aged = Aging::new().set_lumen_maintenance(0.7).apply(&a);
+        assert_abs_diff_eq!(aged.irradiance(), 0.7 * a.irradiance(), epsilon = 1E-12);
+    }
+}
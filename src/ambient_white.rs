@@ -0,0 +1,156 @@
+/*!
+# Ambient Adaptive Display White
+
+Our visual system adapts to the ambient light, and a display with a fixed D65 white looks bluish
+in a room lit by warm white lamps, and too bright in a dim room. Some devices therefore adjust
+their white point, and brightness, to the ambient light, measured with a color sensor.
+
+An [`AmbientWhiteAdvisor`] models this with the primitives of this library: the degree of
+adaptation to the ambient light is estimated with the CIECAM16 formula, for an adaptation
+luminance of one fifth of the luminance of a white surface in the ambient light, and the display
+white is shifted from its native white towards the ambient white, in the CAT16 cone space, by
+this degree of adaptation, multiplied by a strength factor, as full adaptation to the ambient light
+is not desired for color critical work. The recommended white luminance equals the luminance of a
+perfect white diffuser in the ambient light, limited to the range of the display.
+
+This is a heuristic model, useful as a starting point, and not a standardized method.
+```
+use colorimetry::prelude::*;
+
+// an evening room, lit by incandescent light at 150 lux
+let lamp = Illuminant::planckian(2700.0).set_illuminance(&CIE1931, 150.0);
+let advice = AmbientWhiteAdvisor::new().recommend(&lamp).unwrap();
+assert!(advice.cct < 6000.0 && advice.cct > 2700.0);
+assert!(advice.luminance < 100.0);
+```
+*/
+
+use nalgebra::Vector3;
+
+use crate::{
+    cam::{M16, M16INV},
+    data::observers::CIE1931,
+    error::CmtError,
+    illuminant::Illuminant,
+    observer::Observer,
+    viewconditions::ViewConditions,
+    whitepoint::WhitePoint,
+    xyz::XYZ,
+};
+
+/// Recommended display white, and the ambient light it was derived from.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WhiteRecommendation {
+    /// CIE 1931 chromaticity of the recommended white.
+    pub chromaticity: [f64; 2],
+    /// Correlated color temperature of the recommended white, in Kelvin.
+    pub cct: f64,
+    /// Recommended white luminance, in cd/m².
+    pub luminance: f64,
+    /// Fraction of the shift from the native white to the ambient white, from 0.0 to 1.0.
+    pub adaptation: f64,
+    pub ambient_cct: f64,
+    /// Ambient illuminance, in lux.
+    pub ambient_illuminance: f64,
+}
+
+/// Display white point, and brightness, advisor, for an ambient light.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AmbientWhiteAdvisor {
+    native: WhitePoint,
+    strength: f64,
+    luminance_range: (f64, f64),
+}
+
+impl Default for AmbientWhiteAdvisor {
+    fn default() -> Self {
+        Self { native: WhitePoint::D65, strength: 0.6, luminance_range: (80.0, 500.0) }
+    }
+}
+
+impl AmbientWhiteAdvisor {
+    /// Advisor for a display with a native D65 white, a luminance range from 80 to 500 cd/m²,
+    /// and a strength of 0.6.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_native_white(mut self, white: WhitePoint) -> Self {
+        self.native = white;
+        self
+    }
+
+    /// Sets the strength of the adaptation to the ambient light, from 0.0, keeping the native
+    /// white, to 1.0, for a full shift by the degree of adaptation.
+    pub fn set_strength(mut self, strength: f64) -> Self {
+        self.strength = strength.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the minimum, and maximum, white luminance of the display, in cd/m².
+    pub fn set_luminance_range(mut self, min: f64, max: f64) -> Self {
+        self.luminance_range = (min, max);
+        self
+    }
+
+    /// Recommended white for an ambient light, given as its spectral irradiance, in W/m²/nm, at
+    /// the display.
+    pub fn recommend(&self, ambient: &Illuminant) -> Result<WhiteRecommendation, CmtError> {
+        let ambient_illuminance = ambient.illuminance(&CIE1931);
+        if ambient_illuminance.is_nan() || ambient_illuminance <= 0.0 {
+            return Err(CmtError::ErrorString("Ambient white: requires an ambient light".into()));
+        }
+        let xyz_ambient = CIE1931.xyz(ambient, None);
+        let ambient_cct = xyz_ambient.cct()?.t();
+        let xyz_native = self.native.xyz(Observer::Std1931)?;
+
+        // adaptation luminance of one fifth of the luminance of a white diffuser
+        let white_luminance = ambient_illuminance / std::f64::consts::PI;
+        let vc = ViewConditions::new(20.0, 1.0, 1.0, 0.69, white_luminance / 5.0, None);
+        let adaptation = self.strength * vc.dd();
+
+        let lms = |xyz: XYZ| M16 * (xyz.xyzn / xyz.xyzn.y);
+        let lms_white = lms(xyz_native) * (1.0 - adaptation) + lms(xyz_ambient) * adaptation;
+        let xyz: Vector3<f64> = M16INV * lms_white;
+        let white = XYZ::from_vecs(xyz * (100.0 / xyz.y), None, Observer::Std1931);
+        let (min, max) = self.luminance_range;
+        Ok(WhiteRecommendation {
+            chromaticity: white.xy().values(),
+            cct: white.cct()?.t(),
+            luminance: white_luminance.clamp(min, max),
+            adaptation,
+            ambient_cct,
+            ambient_illuminance,
+        })
+    }
+}
+
+#[cfg(test)]
+mod ambient_white_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_ambient_white() {
+        // without adaptation, the native white is kept
+        let office = Illuminant::planckian(4000.0).set_illuminance(&CIE1931, 500.0);
+        let native = AmbientWhiteAdvisor::new().set_strength(0.0).recommend(&office).unwrap();
+        assert_abs_diff_eq!(native.cct, 6504.0, epsilon = 10.0);
+        assert_eq!(native.adaptation, 0.0);
+        assert_abs_diff_eq!(native.ambient_cct, 4000.0, epsilon = 1.0);
+
+        // brighter light gives a higher degree of adaptation, and a warmer white
+        let advisor = AmbientWhiteAdvisor::new();
+        let dim = advisor.recommend(&office.clone().set_illuminance(&CIE1931, 20.0)).unwrap();
+        let bright = advisor.recommend(&office).unwrap();
+        assert!(bright.adaptation > dim.adaptation);
+        assert!(bright.cct < dim.cct && dim.cct < 6504.0);
+        assert_eq!(dim.luminance, 80.0);
+        assert_abs_diff_eq!(bright.luminance, 500.0 / std::f64::consts::PI, epsilon = 1E-9);
+
+        // full adaptation, with a degree of adaptation of 1.0, gives the ambient white
+        let full = advisor.set_strength(1.0).recommend(&office.clone().set_illuminance(&CIE1931, 1E7)).unwrap();
+        assert_abs_diff_eq!(full.cct, 4000.0, epsilon = 50.0);
+        assert!(advisor.recommend(&Illuminant::default()).is_err());
+    }
+}
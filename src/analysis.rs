@@ -0,0 +1,154 @@
+/*!
+# Source Analysis
+
+Interactive applications, such as a dashboard showing the chromaticity, correlated color
+temperature, and color rendering metrics of a light source being edited, redraw their views much
+more often than the source changes.
+A [`SourceAnalysis`] wraps an [`Illuminant`], and calculates its metrics on first use, caching
+the results until the illuminant is changed through [`SourceAnalysis::update`], or
+[`SourceAnalysis::set_illuminant`], or the cache is cleared with [`SourceAnalysis::invalidate`].
+
+Each change increases a generation count, which views can compare to the count at their last
+redraw, to skip redrawing an unchanged source.
+All metrics are calculated for the CIE 1931 observer.
+```
+use colorimetry::prelude::*;
+
+let mut analysis = SourceAnalysis::new(Illuminant::planckian(3000.0).set_illuminance(&CIE1931, 500.0));
+approx::assert_abs_diff_eq!(analysis.illuminance(), 500.0, epsilon = 1E-9);
+let generation = analysis.generation();
+
+// dim the source to 50%
+analysis.update(|s| *s = s.clone() * 0.5);
+assert!(analysis.generation() > generation);
+approx::assert_abs_diff_eq!(analysis.illuminance(), 250.0, epsilon = 1E-9);
+```
+*/
+
+use std::cell::OnceCell;
+
+use crate::{data::observers::CIE1931, error::CmtError, illuminant::Illuminant, xyz::XYZ};
+
+#[cfg(feature = "cct")]
+use crate::cct::CCT;
+#[cfg(feature = "cri")]
+use crate::cri::CRI;
+#[cfg(all(feature = "cri", feature = "supplemental-observers"))]
+use crate::tm30::TM30;
+
+/// A light source, with its metrics calculated on first use, and cached until it changes.
+#[derive(Default)]
+pub struct SourceAnalysis {
+    illuminant: Illuminant,
+    generation: u64,
+    xyz: OnceCell<XYZ>,
+    irradiance: OnceCell<f64>,
+    #[cfg(feature = "cct")]
+    cct: OnceCell<Result<CCT, CmtError>>,
+    #[cfg(feature = "cri")]
+    cri: OnceCell<Result<CRI, CmtError>>,
+    #[cfg(all(feature = "cri", feature = "supplemental-observers"))]
+    tm30: OnceCell<Result<TM30, CmtError>>,
+}
+
+impl SourceAnalysis {
+    pub fn new(illuminant: Illuminant) -> Self {
+        Self { illuminant, ..Default::default() }
+    }
+
+    pub fn illuminant(&self) -> &Illuminant {
+        &self.illuminant
+    }
+
+    /// Replaces the light source, and clears the cached metrics.
+    pub fn set_illuminant(&mut self, illuminant: Illuminant) {
+        self.illuminant = illuminant;
+        self.invalidate();
+    }
+
+    /// Changes the light source in place, and clears the cached metrics.
+    pub fn update(&mut self, f: impl FnOnce(&mut Illuminant)) {
+        f(&mut self.illuminant);
+        self.invalidate();
+    }
+
+    /// Clears the cached metrics, and increases the generation count.
+    pub fn invalidate(&mut self) {
+        *self = Self { illuminant: std::mem::take(&mut self.illuminant), generation: self.generation + 1, ..Default::default() };
+    }
+
+    /// Number of changes of the light source, or invalidations of the cache, since its creation.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Tristimulus values, not normalized, with the illuminance, in lux, as luminous value, for a
+    /// spectral irradiance in W/m²/nm.
+    pub fn xyz(&self) -> XYZ {
+        *self.xyz.get_or_init(|| CIE1931.xyz_from_spectrum(&self.illuminant, None))
+    }
+
+    /// Illuminance, in lux.
+    pub fn illuminance(&self) -> f64 {
+        self.xyz().xyzn.y
+    }
+
+    /// Irradiance, in W/m².
+    pub fn irradiance(&self) -> f64 {
+        *self.irradiance.get_or_init(|| self.illuminant.irradiance())
+    }
+
+    /// Luminous efficacy of radiation, in lm/W.
+    pub fn luminous_efficacy(&self) -> f64 {
+        self.illuminance() / self.irradiance()
+    }
+
+    #[cfg(feature = "cct")]
+    pub fn cct(&self) -> Result<&CCT, &CmtError> {
+        self.cct.get_or_init(|| self.xyz().cct()).as_ref()
+    }
+
+    #[cfg(feature = "cri")]
+    pub fn cri(&self) -> Result<&CRI, &CmtError> {
+        self.cri.get_or_init(|| CRI::try_new(&self.illuminant)).as_ref()
+    }
+
+    #[cfg(all(feature = "cri", feature = "supplemental-observers"))]
+    pub fn tm30(&self) -> Result<&TM30, &CmtError> {
+        self.tm30.get_or_init(|| TM30::try_new(&self.illuminant)).as_ref()
+    }
+
+    /// Checks if the tristimulus values, used by most other metrics, have been calculated since
+    /// the last change of the light source.
+    pub fn is_cached(&self) -> bool {
+        self.xyz.get().is_some()
+    }
+}
+
+#[cfg(test)]
+mod analysis_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_source_analysis() {
+        let lamp = Illuminant::planckian(4000.0).set_illuminance(&CIE1931, 100.0);
+        let mut analysis = SourceAnalysis::new(lamp.clone());
+        assert!(!analysis.is_cached());
+        assert_abs_diff_eq!(analysis.luminous_efficacy(), lamp.luminous_efficacy(&CIE1931), epsilon = 1E-9);
+        assert!(analysis.is_cached());
+        #[cfg(feature = "cct")]
+        assert_abs_diff_eq!(analysis.cct().unwrap().t(), 4000.0, epsilon = 1.0);
+        #[cfg(feature = "cri")]
+        assert_eq!(analysis.cri().unwrap().ra(), lamp.cri().unwrap().ra());
+
+        analysis.set_illuminant(Illuminant::led(550.0, 20.0));
+        assert_eq!(analysis.generation(), 1);
+        assert!(!analysis.is_cached());
+        #[cfg(feature = "cct")]
+        assert!(analysis.cct().is_err());
+        analysis.invalidate();
+        assert_eq!(analysis.generation(), 2);
+        assert_abs_diff_eq!(analysis.irradiance(), 1.0, epsilon = 1E-9);
+    }
+}
@@ -0,0 +1,172 @@
+/*!
+# ArgyllCMS Spectral Files
+
+Reading and writing of ArgyllCMS spectral sample (`.sp`) files, and spectral measurement data in
+`.ti3` files, such as produced by Argyll's `spotread`, `chartread`, and `illumread` tools, using
+X-Rite i1Pro, ColorMunki, and similar instruments.
+
+Argyll files use the CGATS format. Spectral values in `.sp` files are scaled by their
+`SPECTRAL_NORM` keyword value, and reflectance values in `.ti3` files are percentages.
+*/
+
+use crate::{
+    cgats::Cgats,
+    colorant::Colorant,
+    data::observers::CIE1931,
+    error::CmtError,
+    spectrum::Spectrum,
+    std_illuminants::StdIlluminant,
+};
+
+/// Wavelength interval, in nanometer, used in files written by this library.
+const ARGYLL_INTERVAL: usize = 10;
+
+/**
+Reads an Argyll `.sp` spectral sample file, such as an illuminant measured with `illumread`, or a
+filter transmission spectrum.
+
+The file's spectral values are divided by its `SPECTRAL_NORM` value. Use
+`Illuminant::try_from`, or `Colorant::try_from`, to use the spectrum as an illuminant or a
+colorant.
+```
+use colorimetry::prelude::*;
+use approx::assert_abs_diff_eq;
+
+let sp = write_argyll_sp(&Colorant::gray(0.5), "gray filter");
+let spectrum = read_argyll_sp(&sp).unwrap();
+assert_abs_diff_eq!(spectrum[550], 0.5, epsilon = 1E-12);
+```
+*/
+pub fn read_argyll_sp(s: &str) -> Result<Spectrum, CmtError> {
+    let cgats = Cgats::parse(s)?;
+    if cgats.rows.is_empty() {
+        return Err(CmtError::ParseError("Argyll: no spectral data".into()));
+    }
+    let norm = spectral_norm(&cgats, 1.0)?;
+    cgats.spectrum(0, norm)
+}
+
+/// Writes a spectrum as an Argyll `.sp` spectral sample file, with a 10 nanometer interval.
+pub fn write_argyll_sp(spectrum: &Spectrum, description: &str) -> String {
+    let mut cgats = argyll_cgats("SPECT", description);
+    let wavelengths: Vec<usize> = (380..=780).step_by(ARGYLL_INTERVAL).collect();
+    cgats.keywords.extend([
+        ("SPECTRAL_BANDS".to_string(), wavelengths.len().to_string()),
+        ("SPECTRAL_START_NM".to_string(), format!("{:.6}", 380.0)),
+        ("SPECTRAL_END_NM".to_string(), format!("{:.6}", 780.0)),
+        ("SPECTRAL_NORM".to_string(), format!("{:.6}", 1.0)),
+    ]);
+    cgats.fields = wavelengths.iter().map(|wl| format!("SPEC_{wl}")).collect();
+    cgats.rows = vec![wavelengths.iter().map(|&wl| format!("{:.6}", spectrum[wl])).collect()];
+    cgats.write()
+}
+
+/**
+Reads the spectral reflectance data of the samples in an Argyll `.ti3` measurement file, as
+produced by `chartread`, returning the sample identifiers and their spectral reflectance
+distributions.
+
+Reflectance values are clamped to a range from 0.0 to 1.0, which affects samples with optical
+brighteners, showing reflectance values larger than 100% in the blue part of the spectrum.
+*/
+pub fn read_argyll_ti3(s: &str) -> Result<Vec<(String, Colorant)>, CmtError> {
+    let cgats = Cgats::parse(s)?;
+    let norm = spectral_norm(&cgats, 100.0)?;
+    (0..cgats.rows.len()).map(|r| {
+        let name = cgats.sample_name(r);
+        let mut spectrum = cgats.spectrum(r, norm)?;
+        spectrum.clamp(0.0, 1.0);
+        Ok((name, Colorant(spectrum)))
+    }).collect()
+}
+
+/// Writes samples as an Argyll `.ti3` file, with their spectral reflectance values, and their
+/// CIE 1931 XYZ tristimulus values for the CIE D50 illuminant, as used by Argyll for profiling.
+pub fn write_argyll_ti3(samples: &[(String, Colorant)], description: &str) -> String {
+    let mut cgats = argyll_cgats("CTI3", description);
+    let wavelengths: Vec<usize> = (380..=780).step_by(ARGYLL_INTERVAL).collect();
+    cgats.keywords.extend([
+        ("SPECTRAL_BANDS".to_string(), wavelengths.len().to_string()),
+        ("SPECTRAL_START_NM".to_string(), format!("{:.6}", 380.0)),
+        ("SPECTRAL_END_NM".to_string(), format!("{:.6}", 780.0)),
+        ("COLOR_REP".to_string(), "XYZ".to_string()),
+    ]);
+    cgats.fields = ["SAMPLE_ID", "XYZ_X", "XYZ_Y", "XYZ_Z"].into_iter().map(String::from)
+        .chain(wavelengths.iter().map(|wl| format!("SPEC_{wl}")))
+        .collect();
+    cgats.rows = samples.iter().map(|(name, colorant)| {
+        let xyz = CIE1931.xyz(&StdIlluminant::D50, Some(colorant)).values();
+        [name.clone()].into_iter()
+            .chain(xyz.iter().map(|v| format!("{v:.6}")))
+            .chain(wavelengths.iter().map(|&wl| format!("{:.6}", colorant[wl] * 100.0)))
+            .collect()
+    }).collect();
+    cgats.write()
+}
+
+fn argyll_cgats(file_type: &str, description: &str) -> Cgats {
+    Cgats {
+        file_type: file_type.to_string(),
+        keywords: vec![
+            ("DESCRIPTOR".to_string(), description.to_string()),
+            ("ORIGINATOR".to_string(), "colorimetry".to_string()),
+        ],
+        ..Default::default()
+    }
+}
+
+fn spectral_norm(cgats: &Cgats, default: f64) -> Result<f64, CmtError> {
+    match cgats.keyword("SPECTRAL_NORM") {
+        Some(v) => v.parse().map_err(|_| CmtError::ParseError(format!("Argyll: invalid SPECTRAL_NORM '{v}'"))),
+        None => Ok(default),
+    }
+}
+
+#[cfg(test)]
+mod argyll_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_read_sp(){
+        let sp = r#"SPECT
+
+DESCRIPTOR "Argyll illuminant"
+ORIGINATOR "Argyll illumread"
+KEYWORD "SPECTRAL_BANDS"
+SPECTRAL_BANDS "3"
+KEYWORD "SPECTRAL_NORM"
+SPECTRAL_NORM "2.0"
+
+NUMBER_OF_FIELDS 3
+BEGIN_DATA_FORMAT
+SPEC_380 SPEC_580 SPEC_780
+END_DATA_FORMAT
+
+NUMBER_OF_SETS 1
+BEGIN_DATA
+1.0 2.0 3.0
+END_DATA
+"#;
+        let spectrum = read_argyll_sp(sp).unwrap();
+        assert_abs_diff_eq!(spectrum[380], 0.5, epsilon = 1E-12);
+        assert_abs_diff_eq!(spectrum[680], 1.25, epsilon = 1E-12);
+    }
+
+    #[test]
+    fn test_ti3_roundtrip(){
+        let samples = vec![
+            ("A1".to_string(), Colorant::white()),
+            ("A2".to_string(), Colorant::gray(0.2)),
+        ];
+        let ti3 = write_argyll_ti3(&samples, "test chart");
+        assert!(ti3.starts_with("CTI3"));
+        assert!(ti3.contains("XYZ_X XYZ_Y XYZ_Z SPEC_380"));
+        let read = read_argyll_ti3(&ti3).unwrap();
+        assert_eq!(read.len(), 2);
+        assert_eq!(read[1].0, "A2");
+        assert_abs_diff_eq!(read[1].1[555], 0.2, epsilon = 1E-9);
+        let xyz = CIE1931.xyz(&StdIlluminant::D50, Some(&read[0].1)).values();
+        assert_abs_diff_eq!(xyz[1], 100.0, epsilon = 1E-6);
+    }
+}
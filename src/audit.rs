@@ -0,0 +1,193 @@
+/*!
+# Roundtrip Audits
+
+Conversions which have an inverse, such as tristimulus values to CIELAB values, and back, should
+return their input values, but numerical errors, clipping, and approximations, such as the
+spectral representation of RGB values, cause deviations, which depend on the configuration, and
+the range of input values. Before using a configured conversion chain in production, an audit
+pushes a grid of input values through the chain, and back, and reports the worst-case error.
+
+[`roundtrip`] audits one of the built-in [`RoundtripChain`]s, and [`roundtrip_with`] any chain
+given as a function, such as a conversion through a configured [`Pipeline`](crate::pipeline::Pipeline)
+and its inverse. The errors are the largest absolute differences of the three input values.
+```
+use colorimetry::prelude::*;
+
+let chain = RoundtripChain::XyzLabXyz(Observer::Std1931);
+let report = roundtrip(&chain.grid(11), &chain).unwrap();
+assert!(report.max_error < 1E-10);
+
+let chain = RoundtripChain::RgbStimulusRgb(Observer::Std1931, RgbSpace::SRGB);
+let report = roundtrip(&chain.grid(5), &chain).unwrap();
+report.check(1E-6).unwrap();
+```
+*/
+
+use nalgebra::Vector3;
+
+use crate::{
+    error::CmtError,
+    lab::CieLab,
+    observer::Observer,
+    rgb::RGB,
+    rgbspace::RgbSpace,
+    stimulus::Stimulus,
+    xyz::XYZ,
+};
+
+/// Built-in forward, and inverse, conversion chains.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RoundtripChain {
+    /// Tristimulus values, to CIELAB values, and back, with the white of illuminant D65, with a
+    /// luminous value of 100, as reference white.
+    XyzLabXyz(Observer),
+    /// Tristimulus values, to linear RGB values, and back.
+    XyzRgbXyz(Observer, RgbSpace),
+    /// Linear RGB values, to a spectral stimulus, to tristimulus values, and back to RGB values.
+    RgbStimulusRgb(Observer, RgbSpace),
+}
+
+/// The outcome of a roundtrip audit.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RoundtripReport {
+    pub name: String,
+    /// Number of input values.
+    pub samples: usize,
+    /// Largest absolute difference between an input, and output, value.
+    pub max_error: f64,
+    /// Mean of the largest absolute differences of the samples.
+    pub mean_error: f64,
+    /// The input values with the largest error.
+    pub worst_input: [f64; 3],
+    /// The output values for the worst input.
+    pub worst_output: [f64; 3],
+}
+
+impl RoundtripReport {
+    /// Returns an [`CmtError::InvariantViolation`] error if the largest error exceeds a tolerance.
+    pub fn check(&self, tolerance: f64) -> Result<(), CmtError> {
+        if self.max_error.is_nan() || self.max_error > tolerance {
+            Err(CmtError::InvariantViolation { check: self.name.clone(), deviation: self.max_error, tolerance })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl RoundtripChain {
+    pub fn name(&self) -> String {
+        match self {
+            RoundtripChain::XyzLabXyz(observer) => format!("XYZ-Lab-XYZ {observer:?}"),
+            RoundtripChain::XyzRgbXyz(observer, space) => format!("XYZ-RGB-XYZ {observer:?} {}", space.data().1),
+            RoundtripChain::RgbStimulusRgb(observer, space) => format!("RGB-Stimulus-XYZ-RGB {observer:?} {}", space.data().1),
+        }
+    }
+
+    /// A grid of `n` by `n` by `n` input values: tristimulus values from zero to the tristimulus
+    /// values of the white, or RGB values from 0.0 to 1.0.
+    pub fn grid(&self, n: usize) -> Vec<[f64; 3]> {
+        let max: [f64; 3] = match self {
+            RoundtripChain::XyzLabXyz(observer) => white(*observer).into(),
+            RoundtripChain::XyzRgbXyz(observer, space) => RGB::new(1.0, 1.0, 1.0, Some(*observer), Some(*space)).xyz().xyz.unwrap().into(),
+            RoundtripChain::RgbStimulusRgb(..) => [1.0; 3],
+        };
+        let step = |i: usize| if n > 1 { i as f64 / (n - 1) as f64 } else { 1.0 };
+        let mut grid = Vec::with_capacity(n * n * n);
+        for i in 0..n {
+            for j in 0..n {
+                for k in 0..n {
+                    grid.push([max[0] * step(i), max[1] * step(j), max[2] * step(k)]);
+                }
+            }
+        }
+        grid
+    }
+
+    /// Converts input values through the chain, and back.
+    pub fn convert(&self, values: [f64; 3]) -> Result<[f64; 3], CmtError> {
+        match *self {
+            RoundtripChain::XyzLabXyz(observer) => {
+                let xyz = XYZ::from_vecs(white(observer), Some(values.into()), observer);
+                let lab = CieLab::try_from(xyz)?;
+                Ok(lab.xyz().xyz.ok_or(CmtError::NoColorant)?.into())
+            }
+            RoundtripChain::XyzRgbXyz(observer, space) => {
+                let xyz = XYZ::from_vecs(white(observer), Some(values.into()), observer);
+                Ok(xyz.rgb(Some(space)).xyz().xyz.ok_or(CmtError::NoColorant)?.into())
+            }
+            RoundtripChain::RgbStimulusRgb(observer, space) => {
+                let obs = observer.data();
+                let [r, g, b] = values;
+                let stimulus = Stimulus::from(RGB::new(r, g, b, Some(observer), Some(space)));
+                // scaled to the tristimulus values of RGB values, with a luminous value of 100
+                // for the white of the space
+                let full = Stimulus::from(RGB::new(1.0, 1.0, 1.0, Some(observer), Some(space)));
+                let scale = 100.0 / obs.xyz_from_spectrum(&full, None).xyzn.y;
+                let xyz = obs.xyz_from_spectrum(&stimulus, None).xyzn * scale;
+                Ok(XYZ::from_vecs(white(observer), Some(xyz), observer).rgb(Some(space)).rgb.into())
+            }
+        }
+    }
+}
+
+/// Tristimulus values of illuminant D65, with a luminous value of 100.
+fn white(observer: Observer) -> Vector3<f64> {
+    observer.data().xyz_d65().set_illuminance(100.0).xyzn
+}
+
+/// Audits a built-in conversion chain, for a set of input values.
+pub fn roundtrip(inputs: &[[f64; 3]], chain: &RoundtripChain) -> Result<RoundtripReport, CmtError> {
+    roundtrip_with(&chain.name(), inputs, |v| chain.convert(v))
+}
+
+/// Audits a conversion chain, given as a function converting input values forward, and back, for
+/// a set of input values.
+pub fn roundtrip_with(name: &str, inputs: &[[f64; 3]], chain: impl Fn([f64; 3]) -> Result<[f64; 3], CmtError>) -> Result<RoundtripReport, CmtError> {
+    if inputs.is_empty() {
+        return Err(CmtError::ErrorString("Roundtrip: requires at least one input value".into()));
+    }
+    let mut report = RoundtripReport {
+        name: name.to_string(),
+        samples: inputs.len(),
+        max_error: 0.0,
+        mean_error: 0.0,
+        worst_input: inputs[0],
+        worst_output: inputs[0],
+    };
+    for &input in inputs {
+        let output = chain(input)?;
+        let error = input.iter().zip(output.iter()).map(|(a, b)| (a - b).abs()).fold(0.0, f64::max);
+        report.mean_error += error / inputs.len() as f64;
+        if error > report.max_error || error.is_nan() {
+            report.max_error = error;
+            report.worst_input = input;
+            report.worst_output = output;
+        }
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod audit_test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_roundtrip() {
+        for chain in [RoundtripChain::XyzLabXyz(Observer::Std1931), RoundtripChain::XyzRgbXyz(Observer::Std1931, RgbSpace::DisplayP3)] {
+            let grid = chain.grid(6);
+            assert_eq!(grid.len(), 216);
+            roundtrip(&grid, &chain).unwrap().check(1E-9).unwrap();
+        }
+        let chain = RoundtripChain::RgbStimulusRgb(Observer::Std1931, RgbSpace::SRGB);
+        let report = roundtrip(&chain.grid(4), &chain).unwrap();
+        report.check(1E-6).unwrap();
+        assert!(report.mean_error <= report.max_error);
+
+        // a lossy chain, quantizing RGB values to 8 bits
+        let quantize = |v: [f64; 3]| Ok(v.map(|c| (c * 255.0).round() / 255.0));
+        let report = roundtrip_with("8 bit", &RoundtripChain::RgbStimulusRgb(Observer::Std1931, RgbSpace::SRGB).grid(10), quantize).unwrap();
+        assert!(report.max_error > 0.0 && report.max_error <= 0.5 / 255.0 + 1E-12);
+        assert!(report.check(1E-3).is_err());
+        assert!(roundtrip(&[], &chain).is_err());
+    }
+}
@@ -0,0 +1,128 @@
+/*!
+# Band Metrics
+
+Radiant power in wavelength bands, and ratios of these, such as the blue light content of a
+source, as the fraction of its power in the 440 to 490 nanometer band, which lighting reports
+increasingly require.
+
+A [`BandMetric`] is built from named bands, with a reference band for ratios, and is evaluated on
+illuminants. The band `"total"`, covering the full 380 to 780 nanometer domain, is always defined.
+*/
+
+use std::ops::RangeInclusive;
+
+use crate::{error::CmtError, illuminant::Illuminant};
+
+/// Name of the band covering the full wavelength domain.
+pub const TOTAL_BAND: &str = "total";
+
+/**
+Band power, and band power ratio, metrics.
+```
+use colorimetry::prelude::*;
+
+let metric = BandMetric::new()
+    .band("blue", 440.0..=490.0)
+    .band("red", 600.0..=700.0)
+    .ratio_to("total");
+
+let cool = metric.evaluate_band(&Illuminant::d65(), "blue").unwrap();
+let warm = metric.evaluate_band(&Illuminant::planckian(2700.0), "blue").unwrap();
+assert!(cool > warm);
+
+let values = metric.evaluate(&Illuminant::planckian(2700.0)).unwrap();
+assert_eq!(values[0].0, "blue");
+assert!(values[1].1 > values[0].1);
+```
+*/
+#[derive(Clone, Debug, Default)]
+pub struct BandMetric {
+    bands: Vec<(String, RangeInclusive<f64>)>,
+    reference: Option<String>,
+}
+
+impl BandMetric {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a named band, with a wavelength range in nanometer; a band with the same name is
+    /// replaced.
+    pub fn band(mut self, name: &str, range: RangeInclusive<f64>) -> Self {
+        self.bands.retain(|(n, _)| n != name);
+        self.bands.push((name.to_string(), range));
+        self
+    }
+
+    /// Reports band powers as ratios to the power in a reference band, instead of as absolute
+    /// powers.
+    pub fn ratio_to(mut self, reference: &str) -> Self {
+        self.reference = Some(reference.to_string());
+        self
+    }
+
+    /// The wavelength range of a band, by name.
+    fn range(&self, name: &str) -> Result<RangeInclusive<f64>, CmtError> {
+        if let Some((_, range)) = self.bands.iter().find(|(n, _)| n == name) {
+            Ok(range.clone())
+        } else if name == TOTAL_BAND {
+            Ok(380.0..=780.0)
+        } else {
+            Err(CmtError::ErrorString(format!("BandMetric: unknown band \"{name}\"")))
+        }
+    }
+
+    /// Power of an illuminant in a wavelength range, as the sum of its spectral values at the
+    /// integer nanometer wavelengths in the range, consistent with
+    /// [`Illuminant::irradiance`](crate::illuminant::Illuminant::irradiance).
+    fn power(illuminant: &Illuminant, range: &RangeInclusive<f64>) -> f64 {
+        let start = range.start().max(380.0).ceil() as usize;
+        let end = range.end().min(780.0).floor() as usize;
+        (start..=end).map(|l| illuminant[l]).sum()
+    }
+
+    /// Power, or power ratio, of an illuminant in a band.
+    pub fn evaluate_band(&self, illuminant: &Illuminant, name: &str) -> Result<f64, CmtError> {
+        let power = Self::power(illuminant, &self.range(name)?);
+        match &self.reference {
+            Some(reference) => {
+                let reference_power = Self::power(illuminant, &self.range(reference)?);
+                if reference_power > 0.0 {
+                    Ok(power / reference_power)
+                } else {
+                    Err(CmtError::ErrorString(format!("BandMetric: no power in reference band \"{reference}\"")))
+                }
+            }
+            None => Ok(power),
+        }
+    }
+
+    /// Powers, or power ratios, of an illuminant in all bands, in the order they were added.
+    pub fn evaluate(&self, illuminant: &Illuminant) -> Result<Vec<(String, f64)>, CmtError> {
+        self.bands.iter().map(|(name, _)| Ok((name.clone(), self.evaluate_band(illuminant, name)?))).collect()
+    }
+}
+
+#[cfg(test)]
+mod band_metric_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_band_metric() {
+        let ee = Illuminant::equal_energy();
+        let absolute = BandMetric::new().band("blue", 440.0..=490.0).band("green", 500.5..=550.0);
+        assert_abs_diff_eq!(absolute.evaluate_band(&ee, "blue").unwrap(), 51.0 * ee[500], epsilon = 1E-9);
+        assert_abs_diff_eq!(absolute.evaluate_band(&ee, TOTAL_BAND).unwrap(), ee.irradiance(), epsilon = 1E-9);
+
+        let relative = absolute.ratio_to("blue");
+        let values = relative.evaluate(&ee).unwrap();
+        assert_eq!(values[0], ("blue".to_string(), 1.0));
+        assert_abs_diff_eq!(values[1].1, 50.0 / 51.0, epsilon = 1E-12);
+
+        assert!(relative.evaluate_band(&ee, "red").is_err());
+        assert!(relative.clone().ratio_to("red").evaluate(&ee).is_err());
+        let dark = relative.band("blue", 300.0..=370.0);
+        assert!(dark.evaluate_band(&ee, "green").is_err());
+    }
+}
@@ -0,0 +1,281 @@
+/*!
+# Batch Calculations on Flat Arrays
+
+Web applications pass spectra from JavaScript to WebAssembly, and the cost of crossing this
+boundary, for each spectrum, and for each value calculated from it, dominates the calculation
+itself. [`batch_metrics`], and its WebAssembly entry points `batchLightMetrics`, and
+`batchColorantMetrics`, take many spectra in a single flat array, such as a `Float64Array`, and
+return all metrics in a single flat array, in one call.
+
+The spectra have 401 values, from 380 to 780 nanometer, in steps of 1 nanometer, and start every
+`stride` values, which is 401 for tightly packed spectra, or larger, for records with other
+values, such as an identifier, after, or before, each spectrum, with `offset` the index of the
+first spectral value. The tristimulus values of all spectra are calculated as a single matrix
+product, directly on the flat array, without copying the spectra.
+
+The result is a row-major table, with a row for each spectrum, and the columns listed by
+[`BatchMetrics::columns`]:
+- for lights: `X`, `Y`, `Z`, `x`, `y`, `u'`, `v'`, and, with the `cct` feature, `cct` and `duv`,
+  and, with the `cri` feature, `ra` and `r9`;
+- for colorants, with an illuminant: `X`, `Y`, `Z`, `x`, `y`, `u'`, `v'`, `L*`, `a*`, and `b*`,
+  with the tristimulus values normalized to an illuminant luminous value of 100.
+
+Values which can not be calculated for a spectrum, such as the correlated color temperature of a
+saturated green LED, are `NaN`. In JavaScript, the `values` method returns a new `Float64Array`,
+whose buffer can be transferred to a worker, or to the main thread, without copying.
+```
+use colorimetry::prelude::*;
+
+// two lights, with a record stride of 402, as each spectrum is preceded by an identifier
+let mut data = Vec::new();
+for (id, light) in [Illuminant::d65(), Illuminant::planckian(3000.0)].iter().enumerate() {
+    data.push(id as f64);
+    data.extend_from_slice(AsRef::<[f64]>::as_ref(&**light));
+}
+let metrics = batch_metrics(&data, 1, 402, &CIE1931, None).unwrap();
+assert_eq!(metrics.rows(), 2);
+let x = metrics.columns().iter().position(|c| c == "x").unwrap();
+approx::assert_abs_diff_eq!(metrics.value(0, x), 0.3127, epsilon = 1E-4);
+```
+*/
+
+use nalgebra::{DMatrixView, Dyn, Matrix3xX, SMatrix, SVector, U1};
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    error::CmtError,
+    illuminant::Illuminant,
+    lab::CieLab,
+    observer::{Observer, ObserverData},
+    spectrum::{Spectrum, NS},
+    traits::Light,
+    xyz::XYZ,
+};
+
+/// Metrics of a batch of spectra, as a row-major table, with a row for each spectrum, and a
+/// column for each metric.
+#[wasm_bindgen]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BatchMetrics {
+    columns: Vec<String>,
+    values: Vec<f64>,
+}
+
+impl BatchMetrics {
+    /// Names of the metrics, in the order of the columns.
+    pub fn columns(&self) -> &[String] {
+        &self.columns
+    }
+
+    /// Number of spectra.
+    pub fn rows(&self) -> usize {
+        if self.columns.is_empty() { 0 } else { self.values.len() / self.columns.len() }
+    }
+
+    /// All values, row by row.
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    /// Value of a metric, by its column index, for a spectrum.
+    pub fn value(&self, row: usize, column: usize) -> f64 {
+        self.values[row * self.columns.len() + column]
+    }
+
+    /// The values of a metric, by its column index, for all spectra.
+    pub fn column(&self, column: usize) -> Vec<f64> {
+        self.values.iter().skip(column).step_by(self.columns.len()).copied().collect()
+    }
+
+    pub fn into_values(self) -> Vec<f64> {
+        self.values
+    }
+}
+
+#[wasm_bindgen]
+impl BatchMetrics {
+    /// Names of the metrics, in the order of the columns.
+    #[wasm_bindgen(js_name=columns)]
+    pub fn columns_js(&self) -> Vec<String> {
+        self.columns.clone()
+    }
+
+    /// Number of spectra.
+    #[wasm_bindgen(js_name=rows)]
+    pub fn rows_js(&self) -> usize {
+        self.rows()
+    }
+
+    /// All values, row by row, as a Float64Array.
+    #[wasm_bindgen(js_name=values)]
+    pub fn values_js(&self) -> Box<[f64]> {
+        self.values.as_slice().into()
+    }
+}
+
+/// Column-wise view of the spectra in a flat array, or an error if the array does not contain
+/// at least one spectrum, or if the stride is less than 401.
+fn spectra_view(data: &[f64], offset: usize, stride: usize) -> Result<DMatrixView<'_, f64, U1, Dyn>, CmtError> {
+    if stride < NS {
+        return Err(CmtError::OutOfRange { name: "Batch stride".into(), low: NS as f64, high: f64::INFINITY });
+    }
+    let len = data.len().saturating_sub(offset);
+    if len < NS {
+        return Err(CmtError::DataSize401Error);
+    }
+    let count = (len - NS) / stride + 1;
+    let data = &data[offset..offset + (count - 1) * stride + NS];
+    Ok(DMatrixView::from_slice_with_strides_generic(data, Dyn(NS), Dyn(count), U1, Dyn(stride)))
+}
+
+/**
+Metrics of a batch of spectra in a flat array, with the spectra starting at `offset`, and every
+`stride` values, as described in the [module documentation](self).
+
+Without an illuminant, the spectra are interpreted as lights, and with an illuminant, as spectral
+reflectance factors.
+*/
+pub fn batch_metrics(
+    data: &[f64],
+    offset: usize,
+    stride: usize,
+    observer: &ObserverData,
+    illuminant: Option<&dyn Light>,
+) -> Result<BatchMetrics, CmtError> {
+    let spectra = spectra_view(data, offset, stride)?;
+    let mut columns: Vec<String> = ["X", "Y", "Z", "x", "y", "u'", "v'"].map(String::from).into();
+    let (xyzn, xyz): (Option<XYZ>, Matrix3xX<f64>) = match illuminant {
+        Some(light) => {
+            // color matching functions weighted by the illuminant, and normalized to an illuminant
+            // luminous value of 100
+            let s = light.spectrum();
+            let weighted = SMatrix::<f64, 3, NS>::from_fn(|r, c| observer.data[(r, c)] * s.0[c]);
+            let xyzn = weighted.column_sum() * observer.lumconst;
+            let k = 100.0 / xyzn.y;
+            columns.extend(["L*", "a*", "b*"].map(String::from));
+            (Some(XYZ::from_vecs(xyzn * k, None, observer.tag)), weighted * spectra * (observer.lumconst * k))
+        }
+        None => {
+            #[cfg(feature = "cct")]
+            columns.extend(["cct", "duv"].map(String::from));
+            #[cfg(feature = "cri")]
+            columns.extend(["ra", "r9"].map(String::from));
+            (None, observer.data * spectra * observer.lumconst)
+        }
+    };
+
+    let mut values = Vec::with_capacity(columns.len() * xyz.ncols());
+    #[cfg_attr(not(feature = "cri"), allow(unused_variables))]
+    for (v, spectrum) in xyz.column_iter().zip(spectra.column_iter()) {
+        let xyz = match xyzn {
+            Some(white) => XYZ::from_vecs(white.xyzn, Some(v.into_owned()), observer.tag),
+            None => XYZ::from_vecs(v.into_owned(), None, observer.tag),
+        };
+        values.extend(xyz.values());
+        values.extend(xyz.xy().values());
+        values.extend(xyz.xy().uvprime().values());
+        if xyzn.is_some() {
+            match CieLab::try_from(xyz) {
+                Ok(lab) => values.extend(lab.values()),
+                Err(_) => values.extend([f64::NAN; 3]),
+            }
+            continue;
+        }
+        #[cfg(feature = "cct")]
+        match xyz.cct() {
+            Ok(cct) => values.extend([cct.t(), cct.d()]),
+            Err(_) => values.extend([f64::NAN; 2]),
+        }
+        #[cfg(feature = "cri")]
+        {
+            let illuminant = Illuminant(Spectrum(SVector::from_iterator(spectrum.iter().copied())));
+            match crate::cri::CRI::try_new(&illuminant) {
+                Ok(cri) => values.extend([cri.ra(), cri[8]]),
+                Err(_) => values.extend([f64::NAN; 2]),
+            }
+        }
+    }
+    Ok(BatchMetrics { columns, values })
+}
+
+/// Metrics of a batch of light spectra, in a flat Float64Array, with the spectra starting at
+/// `offset`, and every `stride` values.
+#[wasm_bindgen(js_name=batchLightMetrics)]
+pub fn batch_light_metrics_js(data: &[f64], offset: usize, stride: usize, observer: Observer) -> Result<BatchMetrics, CmtError> {
+    batch_metrics(data, offset, stride, observer.data(), None)
+}
+
+/// Metrics of a batch of spectral reflectance factors, in a flat Float64Array, with the spectra
+/// starting at `offset`, and every `stride` values, for an illuminant.
+#[wasm_bindgen(js_name=batchColorantMetrics)]
+pub fn batch_colorant_metrics_js(
+    data: &[f64],
+    offset: usize,
+    stride: usize,
+    illuminant: &Illuminant,
+    observer: Observer,
+) -> Result<BatchMetrics, CmtError> {
+    batch_metrics(data, offset, stride, observer.data(), Some(illuminant))
+}
+
+#[cfg(test)]
+mod batch_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_batch_lights() {
+        let lights = [Illuminant::d65(), Illuminant::planckian(3000.0), Illuminant::led(530.0, 20.0)];
+        let data: Vec<f64> = lights.iter().flat_map(|l| AsRef::<[f64]>::as_ref(&**l).to_vec()).collect();
+        let metrics = batch_metrics(&data, 0, 401, &CIE1931, None).unwrap();
+        assert_eq!(metrics.rows(), 3);
+        assert_eq!(metrics.values().len(), 3 * metrics.columns().len());
+
+        // same values as for the single spectra
+        let index = |name: &str| metrics.columns().iter().position(|c| c == name).unwrap();
+        for (row, light) in lights.iter().enumerate() {
+            let xyz = CIE1931.xyz_from_spectrum(light, None);
+            assert_abs_diff_eq!(&metrics.values()[row * metrics.columns().len()..][..3], xyz.values().as_slice(), epsilon = 1E-9);
+            assert_abs_diff_eq!(metrics.value(row, index("v'")), xyz.xy().uvprime().values()[1], epsilon = 1E-12);
+        }
+        #[cfg(feature = "cct")]
+        {
+            assert_abs_diff_eq!(metrics.value(1, index("cct")), 3000.0, epsilon = 1.0);
+            assert!(metrics.value(2, index("cct")).is_nan());
+        }
+        #[cfg(feature = "cri")]
+        assert_abs_diff_eq!(metrics.value(1, index("ra")), 100.0, epsilon = 0.05);
+
+        // strided records, with an identifier before, and a weight after, each spectrum
+        let mut records = Vec::new();
+        for (id, light) in lights.iter().enumerate() {
+            records.push(id as f64);
+            records.extend_from_slice(AsRef::<[f64]>::as_ref(&**light));
+            records.push(1.0);
+        }
+        let strided = batch_metrics(&records, 1, 403, &CIE1931, None).unwrap();
+        // identical, including the NaN values of the green LED
+        let bits = |m: &BatchMetrics| m.values().iter().map(|v| v.to_bits()).collect::<Vec<u64>>();
+        assert_eq!(bits(&strided), bits(&metrics));
+        assert_eq!(metrics.column(index("Y")).len(), 3);
+
+        assert!(batch_metrics(&data, 0, 400, &CIE1931, None).is_err());
+        assert!(batch_metrics(&data[..400], 0, 401, &CIE1931, None).is_err());
+    }
+
+    #[test]
+    fn test_batch_colorants() {
+        let colorants = [Colorant::white(), Colorant::gaussian(600.0, 40.0)];
+        let data: Vec<f64> = colorants.iter().flat_map(|c| AsRef::<[f64]>::as_ref(&**c).to_vec()).collect();
+        let d65 = Illuminant::d65();
+        let metrics = batch_colorant_metrics_js(&data, 0, 401, &d65, Observer::Std1931).unwrap();
+        assert_eq!(metrics.columns()[7..], ["L*", "a*", "b*"]);
+        for (row, colorant) in colorants.iter().enumerate() {
+            let xyz = CIE1931.xyz(&d65, Some(colorant));
+            assert_abs_diff_eq!(metrics.value(row, 1), xyz.values()[1], epsilon = 1E-9);
+            let lab = CieLab::try_from(xyz).unwrap().values();
+            assert_abs_diff_eq!(&metrics.values()[row * 10 + 7..row * 10 + 10], lab.as_slice(), epsilon = 1E-9);
+        }
+        assert_abs_diff_eq!(metrics.value(0, 7), 100.0, epsilon = 1E-9);
+    }
+}
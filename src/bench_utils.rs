@@ -0,0 +1,49 @@
+/*!
+# Benchmark Utilities
+
+Deterministic generators of synthetic spectral distributions, as used in this library's
+benchmarks, and available with the `bench-utils` feature, to allow users to benchmark their own
+workloads on the same data.
+
+All generators produce the same spectra for the same arguments, so results can be compared
+between runs, and machines.
+*/
+
+use crate::{illuminant::Illuminant, rgb::RGB, spectrum::Spectrum};
+
+/// Thermal radiator spectra, with temperatures evenly spaced between 2000 and 10000 K.
+pub fn planckian_batch(n: usize) -> Vec<Illuminant> {
+    (0..n).map(|i| Illuminant::planckian(2000.0 + 8000.0 * fraction(i, n))).collect()
+}
+
+/// Phosphor converted white LED approximations, with a blue LED and a broad phosphor emission
+/// band, with their correlated color temperatures ranging from about 3000 to 8000 K.
+pub fn led_batch(n: usize) -> Vec<Illuminant> {
+    (0..n).map(|i| {
+        let f = fraction(i, n);
+        let blue = Illuminant::led(450.0, 20.0).set_irradiance(0.15 + 0.35 * f);
+        let phosphor = Illuminant::led(570.0 - 10.0 * f, 110.0).set_irradiance(1.0);
+        Illuminant(Spectrum(blue.0.0 + phosphor.0.0))
+    }).collect()
+}
+
+/// A mix of thermal radiator, and LED spectra.
+pub fn mixed_batch(n: usize) -> Vec<Illuminant> {
+    let mut planckian = planckian_batch(n.div_ceil(2)).into_iter();
+    let mut led = led_batch(n / 2).into_iter();
+    (0..n).filter_map(|i| if i % 2 == 0 { planckian.next() } else { led.next() }).collect()
+}
+
+/// sRGB pixel values, evenly distributed over the RGB cube.
+pub fn rgb_batch(n: usize) -> Vec<RGB> {
+    (0..n).map(|i| {
+        // quasi-random sequence, using the plastic number's reciprocal powers
+        let [a, b, c] = [0.8191725133961645, 0.6710436067037893, 0.5497004779019703];
+        let k = i as f64 + 0.5;
+        RGB::new((a * k).fract(), (b * k).fract(), (c * k).fract(), None, None)
+    }).collect()
+}
+
+fn fraction(i: usize, n: usize) -> f64 {
+    if n > 1 { i as f64 / (n - 1) as f64 } else { 0.0 }
+}
@@ -0,0 +1,183 @@
+/*!
+# Fluorescent Colorants
+
+Fluorescent materials, such as papers, and textiles, with optical brightening agents, and
+fluorescent inks, absorb light at shorter wavelengths, and emit part of it at longer wavelengths.
+Their color depends on the spectral distribution of the illuminant, and can not be described by a
+reflectance factor alone.
+A [`BiSpectralColorant`] holds their Donaldson matrix D(λ<sub>em</sub>, λ<sub>ex</sub>), with the
+bispectral radiance factors for light emitted at a wavelength λ<sub>em</sub>, for excitation at a
+wavelength λ<sub>ex</sub>, per nanometer of excitation bandwidth.
+Its diagonal is the ordinary reflected radiance factor, and an ordinary [`Colorant`] is a
+bispectral colorant with a diagonal matrix only.
+
+The light leaving the sample, for an illuminant E(λ), is
+
+S(λ<sub>em</sub>) = Σ D(λ<sub>em</sub>, λ<sub>ex</sub>) E(λ<sub>ex</sub>),
+
+with the tristimulus values calculated by [`ObserverData::xyz_bispectral`].
+Excitation is limited to the wavelength domain of this library, from 380 to 780 nanometer, so
+the contribution of ultraviolet excitation below 380 nanometer is not included.
+```
+use colorimetry::prelude::*;
+
+// a white paper, with an optical brightener, excited in the violet, and emitting in the blue
+let paper = Colorant::gray(0.85);
+let excitation = [380.0, 400.0, 420.0];
+let emission = [0.004, 0.006, 0.002].map(|f| *Colorant::gaussian(440.0, 15.0) * f);
+let brightened = BiSpectralColorant::try_from_excitation_emission(&paper, &excitation, &emission).unwrap();
+
+let d65 = CIE1931.xyz(&StdIlluminant::D65, None);
+let plain = CIE1931.xyz(&StdIlluminant::D65, Some(&paper));
+let fluorescent = CIE1931.xyz_bispectral(&StdIlluminant::D65, &brightened);
+assert!(fluorescent.cie_whiteness(d65).unwrap().0 > plain.cie_whiteness(d65).unwrap().0);
+```
+*/
+
+use nalgebra::{DMatrix, DVector, SVector};
+
+use crate::{
+    colorant::Colorant,
+    error::CmtError,
+    spectrum::{Spectrum, NS},
+    traits::{Filter, Light},
+};
+
+/// A colorant described by its Donaldson matrix, with its rows for the emission wavelengths, and
+/// its columns for the excitation wavelengths, from 380 to 780 nanometer, with 1 nanometer steps.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BiSpectralColorant(DMatrix<f64>);
+
+impl BiSpectralColorant {
+    /// A bispectral colorant from a Donaldson matrix, with 401 rows, for the emission wavelengths,
+    /// and 401 columns, for the excitation wavelengths.
+    pub fn try_from_donaldson(matrix: DMatrix<f64>) -> Result<Self, CmtError> {
+        if matrix.shape() != (NS, NS) {
+            return Err(CmtError::DataSize401Error);
+        }
+        Ok(Self(matrix))
+    }
+
+    /**
+    A bispectral colorant from a non-fluorescent reflectance factor, and measured fluorescence
+    emission spectra, such as obtained with a two-monochromator method, for a set of excitation
+    wavelengths, in nanometer, in increasing order.
+
+    The emission spectra are given as bispectral radiance factors, per nanometer of excitation
+    bandwidth, and are linearly interpolated between the excitation wavelengths; there is no
+    fluorescence for excitation outside the range of the excitation wavelengths.
+    Only emission at wavelengths longer than the excitation wavelength is used, as the reflected
+    light is described by the reflectance factor.
+    */
+    pub fn try_from_excitation_emission(reflectance: &Colorant, excitation: &[f64], emission: &[Spectrum]) -> Result<Self, CmtError> {
+        if excitation.is_empty() || excitation.len() != emission.len() {
+            return Err(CmtError::ErrorString(
+                "BiSpectralColorant: provide an emission spectrum for each excitation wavelength".into(),
+            ));
+        }
+        if excitation.windows(2).any(|w| w[0] >= w[1]) || excitation[0] < 380.0 || excitation[excitation.len() - 1] > 780.0 {
+            return Err(CmtError::WavelengthOutOfRange);
+        }
+        let mut matrix = DMatrix::from_diagonal(&DVector::from_column_slice(reflectance.0 .0.as_slice()));
+        for (j, mut column) in matrix.column_iter_mut().enumerate() {
+            let ex = (380 + j) as f64;
+            let weights: Vec<(usize, f64)> = match excitation.iter().position(|&l| l >= ex) {
+                Some(0) if excitation[0] == ex => vec![(0, 1.0)],
+                Some(0) | None => continue,
+                Some(i) => {
+                    let t = (ex - excitation[i - 1]) / (excitation[i] - excitation[i - 1]);
+                    vec![(i - 1, 1.0 - t), (i, t)]
+                }
+            };
+            for (i, w) in weights {
+                for k in j + 1..NS {
+                    column[k] += w * emission[i].0[k];
+                }
+            }
+        }
+        Ok(Self(matrix))
+    }
+
+    /// The Donaldson matrix.
+    pub fn donaldson(&self) -> &DMatrix<f64> {
+        &self.0
+    }
+
+    /// The reflected, non-fluorescent, part of the radiance factor, as the diagonal of the
+    /// Donaldson matrix.
+    pub fn reflectance(&self) -> Colorant {
+        Colorant(Spectrum(SVector::from_iterator(self.0.diagonal().iter().copied())))
+    }
+
+    /// Spectral distribution of the light leaving the sample, reflected and emitted, for an
+    /// illuminant.
+    pub fn radiance(&self, light: &dyn Light) -> Spectrum {
+        let e = DVector::from_column_slice(light.spectrum().0.as_slice());
+        Spectrum(SVector::from_iterator((&self.0 * e).iter().copied()))
+    }
+
+    /// The total radiance factor for an illuminant, as the ratio of the light leaving the sample,
+    /// and the illuminant, which depends on the illuminant for fluorescent colorants; at
+    /// wavelengths without illumination, the reflected radiance factor is used.
+    pub fn total_radiance_factor(&self, light: &dyn Light) -> Colorant {
+        let e = light.spectrum();
+        let total = self.radiance(light);
+        let diagonal = self.0.diagonal();
+        Colorant(Spectrum(SVector::from_fn(|i, _| if e.0[i] > 0.0 { total.0[i] / e.0[i] } else { diagonal[i] })))
+    }
+}
+
+/// An ordinary, non-fluorescent, colorant, with its reflectance factor as the diagonal of the
+/// Donaldson matrix.
+impl From<&Colorant> for BiSpectralColorant {
+    fn from(colorant: &Colorant) -> Self {
+        Self(DMatrix::from_diagonal(&DVector::from_column_slice(colorant.spectrum().0.as_slice())))
+    }
+}
+
+#[cfg(test)]
+mod bispectral_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_bispectral() {
+        // ordinary colorants give the same tristimulus values
+        let red = Colorant::gaussian(620.0, 40.0);
+        let bispectral = BiSpectralColorant::from(&red);
+        let xyz = CIE1931.xyz(&StdIlluminant::D65, Some(&red));
+        assert_abs_diff_eq!(xyz, CIE1931.xyz_bispectral(&StdIlluminant::D65, &bispectral), epsilon = 1E-9);
+        #[cfg(feature = "cie-illuminants")]
+        {
+            let xyz = CIE1931.xyz(&StdIlluminant::A, Some(&red));
+            assert_abs_diff_eq!(xyz, CIE1931.xyz_bispectral(&StdIlluminant::A, &bispectral), epsilon = 1E-9);
+        }
+        assert_abs_diff_eq!(bispectral.reflectance(), red, epsilon = 1E-12);
+
+        // a fluorescent colorant, excited at 450 nm only, and emitting at 550 nm only
+        let mut emission = Spectrum::default();
+        emission[550] = 0.5;
+        let colorant = BiSpectralColorant::try_from_excitation_emission(&Colorant::black(), &[450.0], &[emission]).unwrap();
+        let mut laser = Spectrum::default();
+        laser[450] = 1.0;
+        let out = colorant.radiance(&Illuminant(laser));
+        assert_abs_diff_eq!(out[550], 0.5);
+        assert_abs_diff_eq!(out[450], 0.0);
+
+        // the total radiance factor depends on the illuminant, and exceeds the reflectance
+        let paper = Colorant::gray(0.8);
+        let emission = [0.01, 0.01].map(|f| *Colorant::gaussian(450.0, 10.0) * f);
+        let brightened = BiSpectralColorant::try_from_excitation_emission(&paper, &[380.0, 420.0], &emission).unwrap();
+        let d65 = brightened.total_radiance_factor(&StdIlluminant::D65);
+        assert!(d65[450] > 0.8);
+        assert_abs_diff_eq!(d65[600], 0.8, epsilon = 1E-12);
+        #[cfg(feature = "cie-illuminants")]
+        {
+            let a = brightened.total_radiance_factor(&StdIlluminant::A);
+            assert!(a[450] > 0.8 && d65[450] != a[450]);
+        }
+
+        assert!(BiSpectralColorant::try_from_excitation_emission(&paper, &[420.0, 380.0], &emission).is_err());
+        assert!(BiSpectralColorant::try_from_donaldson(nalgebra::DMatrix::zeros(3, 3)).is_err());
+    }
+}
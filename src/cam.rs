@@ -1,3 +1,32 @@
+/*!
+# CIECAM16 Color Appearance Model
+
+The CIECAM16 color appearance model, as defined in CIE 248:2022, predicts the appearance of a
+stimulus, seen against a reference white, in a set of [`ViewConditions`], by its correlates of
+lightness J, chroma C, hue angle h, colorfulness M, saturation s, and brightness Q.
+The CAM16-UCS uniform color space, with its coordinates J', a', and b', is derived from these for
+color difference calculations, and gamut mapping.
+
+The model is invertible: a [`CieCam16`] appearance can be created from its correlates, or from its
+uniform color space coordinates, and transformed back to tristimulus values, for the same, or for
+another white, and other viewing conditions, to obtain corresponding colors.
+```
+use colorimetry::prelude::*;
+
+let xyz = XYZ::new(&[96.46, 100.0, 108.62], Some(&[60.70, 49.60, 10.29]), Observer::Std1931);
+let vc = ViewConditions::new(16.0, 1.0, 1.0, 0.69, 40.0, None);
+let cam = CieCam16::new(xyz, vc).unwrap();
+approx::assert_abs_diff_eq!(cam.lightness(), 70.4406, epsilon = 1E-4);
+approx::assert_abs_diff_eq!(cam.chroma(), 58.6035, epsilon = 1E-4);
+approx::assert_abs_diff_eq!(cam.hue_angle(), 57.9145, epsilon = 1E-4);
+
+// and back, from the uniform color space coordinates
+let white = XYZ::new(&[96.46, 100.0, 108.62], None, Observer::Std1931);
+let back = CieCam16::from_ucs(cam.ucs(), white, vc).unwrap().xyz(None, None).unwrap();
+approx::assert_abs_diff_eq!(back, xyz, epsilon = 1E-6);
+```
+*/
+
 use std::f64::consts::PI;
 
 const P1C:f64 = 50_000.0 / 13.0;
@@ -13,7 +42,7 @@ const UCS_C2:f64 = 0.0228;
 use nalgebra::{matrix, vector, SMatrix, Matrix3, Vector3};
 
 
-use crate::{error::CmtError, geometry::distance, prelude::Observer, traits::{Filter, Light}, xyz::XYZ};
+use crate::{error::CmtError, geometry::distance, math, prelude::Observer, traits::{Filter, Light}, xyz::XYZ};
 
 use super::viewconditions::{ViewConditions, ReferenceValues};
 
@@ -33,10 +62,9 @@ pub struct CieCam16 {
 
 impl CieCam16 {
 
-    /// CIECAM16 coordinates for a particular set of viewing conditions.
-    /// 
-
-    fn new(xyz: XYZ, vc: ViewConditions) -> Result<Self, CmtError> {
+    /// CIECAM16 coordinates of a stimulus, for its reference white, and a particular set of
+    /// viewing conditions.
+    pub fn new(xyz: XYZ, vc: ViewConditions) -> Result<Self, CmtError> {
         let xyz0= xyz.xyz.ok_or(CmtError::NoColorant)?;
         let xyzn0 = xyz.xyzn;
         let ReferenceValues {
@@ -55,16 +83,16 @@ impl CieCam16 {
         let cb = (rgb[0] + rgb[1] - 2. * rgb[2]) / 9.0;
 
         // calculate h in radians
-        let mut h = cb.atan2(ca);
+        let mut h = math::atan2(cb, ca);
         if h<0.0 { h+= 2.0* PI;}
 
         // calculate J = jj
-        let jj = 100.0 * (achromatic_rsp(rgb, nbb) / aw).powf(vc.c * z);
+        let jj = 100.0 * math::powf(achromatic_rsp(rgb, nbb) / aw, vc.c * z);
 
         // calculate C = cc
-        let et = 0.25f64 * ((h + 2.0).cos() + 3.8);
+        let et = 0.25f64 * (math::cos(h + 2.0) + 3.8);
         let t = (50000.0 / 13.0 * ncb * vc.nc * et * (ca * ca + cb * cb).sqrt()) / (rgb[0] + rgb[1] + 21.0 / 20.0 * rgb[2]);
-        let cc = t.powf(0.9) * (jj / 100.).sqrt() * (1.64 - (0.29f64).powf(n)).powf(0.73);
+        let cc = math::powf(t, 0.9) * (jj / 100.).sqrt() * math::powf(1.64 - math::powf(0.29, n), 0.73);
 
         Ok(Self{
             vc,
@@ -75,25 +103,93 @@ impl CieCam16 {
 
     }
 
+    /// Appearance from its correlates of lightness J, chroma C, and hue angle h, in degrees, for a
+    /// reference white, and viewing conditions.
+    pub fn from_jch(jch: [f64; 3], white: XYZ, vc: ViewConditions) -> Result<Self, CmtError> {
+        let [j, c, h] = jch;
+        if j < 0.0 || c < 0.0 {
+            return Err(CmtError::ErrorString("CAM16: lightness and chroma can not be negative".into()));
+        }
+        Ok(Self { observer: white.observer, jch: Vector3::new(j, c, h.rem_euclid(360.0)), xyzn: white.xyzn, vc })
+    }
+
+    /// Appearance from its CAM16-UCS coordinates J', a', and b', for a reference white, and
+    /// viewing conditions.
+    pub fn from_ucs(jab: [f64; 3], white: XYZ, vc: ViewConditions) -> Result<Self, CmtError> {
+        let [jp, a, b] = jab;
+        let j = jp / (1.0 + UCS_C1 * (100.0 - jp));
+        let mp = math::hypot(a, b);
+        let m = (math::exp(UCS_C2 * mp) - 1.0) / UCS_C2;
+        let c = m / math::powf(vc.f_l(), 0.25);
+        Self::from_jch([j, c, math::atan2(b, a).to_degrees()], white, vc)
+    }
+
+    /// Lightness J.
+    pub fn lightness(&self) -> f64 {
+        self.jch[0]
+    }
+
+    /// Chroma C.
+    pub fn chroma(&self) -> f64 {
+        self.jch[1]
+    }
+
+    /// Hue angle h, in degrees.
+    pub fn hue_angle(&self) -> f64 {
+        self.jch[2]
+    }
+
+    /// Lightness, chroma, and hue angle, as an array.
+    pub fn jch(&self) -> [f64; 3] {
+        self.jch.into()
+    }
+
+    /// Colorfulness M.
+    pub fn colorfulness(&self) -> f64 {
+        self.jch[1] * math::powf(self.vc.f_l(), 0.25)
+    }
+
+    /// Brightness Q.
+    pub fn brightness(&self) -> f64 {
+        let aw = ReferenceValues::new(self.xyzn, self.vc).aw;
+        (4.0 / self.vc.c) * (self.jch[0] / 100.0).sqrt() * (aw + 4.0) * math::powf(self.vc.f_l(), 0.25)
+    }
+
+    /// Saturation s.
+    pub fn saturation(&self) -> f64 {
+        let q = self.brightness();
+        if q > 0.0 { 100.0 * (self.colorfulness() / q).sqrt() } else { 0.0 }
+    }
+
+    /// CAM16-UCS coordinates J', a', and b'.
+    pub fn ucs(&self) -> [f64; 3] {
+        self.jabp().into()
+    }
+
+    /// Color difference ΔE' in the CAM16-UCS color space.
+    pub fn delta_e(&self, other: &Self) -> f64 {
+        Self::delta_e_prime(&self.ucs(), &other.ucs())
+    }
+
     fn jabp(&self) -> Vector3<f64> {
         let &[jj, cc, h] = self.jch.as_ref();
-        let m = cc * self.vc.f_l().powf(0.25);
-        let mprime = 1.0 / UCS_C2 * (1.0 + UCS_C2 * m).ln();
+        let m = cc * math::powf(self.vc.f_l(), 0.25);
+        let mprime = 1.0 / UCS_C2 * math::ln(1.0 + UCS_C2 * m);
         Vector3::new(
             (1.0 + 100.0 * UCS_C1) * jj / (1.0 + UCS_C1 * jj),
-            mprime * (h * PI / 180.0).cos(),
-            mprime * (h * PI / 180.0).sin(),
+            mprime * math::cos(h * PI / 180.0),
+            mprime * math::sin(h * PI / 180.0),
         )
     }
 
     fn jchp(&self) -> Vector3<f64> {
         let &[jj, cc, h] = self.jch.as_ref();
-        let m = cc * self.vc.f_l().powf(0.25);
-        let mprime = 1.0 / UCS_C2 * (1.0 + UCS_C2 * m).ln();
+        let m = cc * math::powf(self.vc.f_l(), 0.25);
+        let mprime = 1.0 / UCS_C2 * math::ln(1.0 + UCS_C2 * m);
         Vector3::new(
             (1.0 + 100.0 * UCS_C1) * jj / (1.0 + UCS_C1 * jj),
-            mprime * (h * PI / 180.0).cos(),
-            mprime * (h * PI / 180.0).sin()
+            mprime * math::cos(h * PI / 180.0),
+            mprime * math::sin(h * PI / 180.0)
         )
     }
 
@@ -116,7 +212,7 @@ impl CieCam16 {
     /// If no different white adaptation or viewing conditionns are given, this is a straight back
     /// transform from the input parameters, which can for example be used to test the backward
     /// transform.
-    fn xyz(&self, white_opt: Option<XYZ>, vc_opt: Option<ViewConditions>) -> Result<XYZ, CmtError> {
+    pub fn xyz(&self, white_opt: Option<XYZ>, vc_opt: Option<ViewConditions>) -> Result<XYZ, CmtError> {
         let vc = vc_opt.unwrap_or(self.vc);
         let xyzn = if let Some(white) = white_opt {
             if white.observer == self.observer {
@@ -131,10 +227,10 @@ impl CieCam16 {
             = ReferenceValues::new(xyzn, vc);
         let d_rgb_vec = Vector3::from(d_rgb);
         let &[lightness, chroma, hue_angle] = self.jch.as_ref();
-        let t = (chroma / ((lightness / 100.0).sqrt() * (1.64 - 0.29f64.powf(n)).powf(0.73))).powf(RCPR_9);
+        let t = math::powf(chroma / ((lightness / 100.0).sqrt() * math::powf(1.64 - math::powf(0.29, n), 0.73)), RCPR_9);
         let p1 = (P1C * vc.nc * ncb * eccentricity(hue_angle)) / t; // NaN if t=0, but OK, as check on t==0.0 if used
         let p2 = achromatic_response_from_lightness(aw, vc.c, z, lightness) / nbb + 0.305;
-        let (a, b) = match hue_angle.to_radians().sin_cos() {
+        let (a, b) = match (math::sin(hue_angle.to_radians()), math::cos(hue_angle.to_radians())) {
             (_, _) if t.is_nan() || t == 0.0 => (0.0, 0.0),
             (hs, hc) if hs.abs() >= hc.abs() => {
                 let b = p2 * NOM / (p1 / hs + DEN1 * hc / hs + DEN2);
@@ -166,17 +262,17 @@ pub fn achromatic_rsp(rgb: Vector3<f64>, nbb: f64) -> f64 {
 }
 
 pub fn eccentricity(hue_angle: f64) -> f64 {
-    0.25 * ((hue_angle.to_radians() + 2.0).cos() + 3.8)
+    0.25 * (math::cos(hue_angle.to_radians() + 2.0) + 3.8)
 }
 
 pub fn achromatic_response_from_lightness(aw: f64, c: f64, z:f64, lightness: f64) -> f64 {
-    aw * (lightness / 100.0).powf(1.0f64 / (c * z))
+    aw * math::powf(lightness / 100.0, 1.0f64 / (c * z))
 }
 
 fn inv_cone_adaptation(f_l: f64, x: f64) -> f64 {
     let x = x - 0.1;
     let t = 27.13 * x.abs() / (400.0 - x.abs());
-    x.signum() * ((100.0 * t.powf(1.0 / 0.42)) / f_l)
+    x.signum() * ((100.0 * math::powf(t, 1.0 / 0.42)) / f_l)
 }
 
 pub const MCAT02: SMatrix<f64, 3, 3> = matrix![
@@ -269,4 +365,29 @@ mod cam_test {
     
     }
 
+    #[test]
+    fn test_correlates(){
+        let white = XYZ::new(&[96.46, 100.0, 108.62], None, Observer::Std1931);
+        let xyz = XYZ::new(&[96.46, 100.0, 108.62], Some(&[60.70, 49.60, 10.29]), Observer::Std1931);
+        let vc = ViewConditions::new(16.0, 1.0, 1.0, 0.69, 40.0, None);
+        let cam = CieCam16::new(xyz, vc).unwrap();
+        assert_ulps_eq!(cam.colorfulness(), cam.chroma() * vc.f_l().powf(0.25));
+        assert_abs_diff_eq!(cam.saturation(), 100.0 * (cam.colorfulness() / cam.brightness()).sqrt(), epsilon = 1E-12);
+
+        // the white has a lightness of 100, and no chroma
+        let cam_white = CieCam16::new(XYZ::new(&[96.46, 100.0, 108.62], Some(&[96.46, 100.0, 108.62]), Observer::Std1931), vc).unwrap();
+        assert_abs_diff_eq!(cam_white.lightness(), 100.0, epsilon = 1E-9);
+        assert_abs_diff_eq!(cam_white.ucs()[0], 100.0, epsilon = 1E-9);
+        assert!(cam_white.chroma() < 2.0);
+
+        // round trips through the correlates, and the uniform color space
+        let from_jch = CieCam16::from_jch(cam.jch(), white, vc).unwrap();
+        assert_abs_diff_eq!(from_jch.xyz(None, None).unwrap(), xyz, epsilon = 1E-6);
+        let from_ucs = CieCam16::from_ucs(cam.ucs(), white, vc).unwrap();
+        assert_abs_diff_eq!(from_ucs.jch().as_ref(), cam.jch().as_ref(), epsilon = 1E-9);
+        assert_abs_diff_eq!(cam.delta_e(&from_ucs), 0.0, epsilon = 1E-9);
+        assert!(cam.delta_e(&cam_white) > 10.0);
+        assert!(CieCam16::from_jch([-1.0, 0.0, 0.0], white, vc).is_err());
+    }
+
 }
\ No newline at end of file
@@ -0,0 +1,116 @@
+/*!
+# Chromatic Adaptation Transforms
+
+An observer adapts to the white of the light source illuminating a scene, and colors seen under
+different light sources match if their tristimulus values, relative to their white, correspond.
+A chromatic adaptation transform predicts these corresponding colors, by scaling the tristimulus
+values, transformed into a space of cone-like responses, by the ratio of the responses of the two
+whites, as originally proposed by von Kries.
+
+The transforms differ in their cone response matrices:
+- [`Cat::Bradford`], the linearized Bradford transform, as used in ICC color management;
+- [`Cat::Cat02`], the CIECAT02 transform of the CIECAM02 color appearance model;
+- [`Cat::Cat16`], the CAT16 transform of CIECAM16, as defined in CIE 248:2022;
+- [`Cat::VonKries`], using the Hunt-Pointer-Estevez cone fundamentals;
+- [`Cat::XyzScaling`], scaling the tristimulus values directly.
+
+All transforms here assume complete adaptation.
+```
+use colorimetry::prelude::*;
+
+let d65 = CIE1931.xyz(&StdIlluminant::D65, None).set_illuminance(100.0);
+let d50 = CIE1931.xyz(&StdIlluminant::D50, None).set_illuminance(100.0);
+let red = CIE1931.xyz(&StdIlluminant::D65, Some(&Colorant::gaussian(620.0, 40.0)));
+// the color corresponding to red under D65, as seen under D50
+let adapted = red.adapt(Cat::Bradford, &d65, &d50).unwrap();
+assert!(adapted.values() != red.values());
+// and white maps to white
+let white = d65.adapt(Cat::Bradford, &d65, &d50).unwrap();
+approx::assert_abs_diff_eq!(white.values().as_ref(), d50.values().as_ref(), epsilon = 1E-9);
+```
+*/
+
+use nalgebra::{matrix, Matrix3, Vector3};
+use strum_macros::{Display, EnumIter};
+
+use crate::cam::{MCAT02, MHPE, M16};
+
+/// Linearized Bradford cone response matrix.
+const MBFD: Matrix3<f64> = matrix![
+     0.8951,  0.2664, -0.1614;
+    -0.7502,  1.7135,  0.0367;
+     0.0389, -0.0685,  1.0296;
+];
+
+/// Chromatic adaptation transforms.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Display, EnumIter)]
+pub enum Cat {
+    Bradford,
+    Cat02,
+    #[default]
+    Cat16,
+    VonKries,
+    XyzScaling,
+}
+
+impl Cat {
+    /// Matrix transforming tristimulus values into the cone-like responses of the transform.
+    pub fn cone_matrix(&self) -> Matrix3<f64> {
+        match self {
+            Cat::Bradford => MBFD,
+            Cat::Cat02 => MCAT02,
+            Cat::Cat16 => M16,
+            Cat::VonKries => MHPE,
+            Cat::XyzScaling => Matrix3::identity(),
+        }
+    }
+
+    /// Adaptation matrix, which maps tristimulus values seen under a white `xyzw_from` to the
+    /// corresponding values seen under a white `xyzw_to`.
+    pub fn matrix(&self, xyzw_from: Vector3<f64>, xyzw_to: Vector3<f64>) -> Matrix3<f64> {
+        let m = self.cone_matrix();
+        let gain = (m * xyzw_to).component_div(&(m * xyzw_from));
+        // the cone matrices are all invertible
+        m.try_inverse().unwrap() * Matrix3::from_diagonal(&gain) * m
+    }
+}
+
+#[cfg(test)]
+mod cat_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+    use nalgebra::{matrix, Vector3};
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn test_bradford() {
+        // D65 to D50 Bradford matrix, as published by B. Lindbloom
+        let d65 = Vector3::new(0.95047, 1.0, 1.08883);
+        let d50 = Vector3::new(0.96422, 1.0, 0.82521);
+        let want = matrix![
+             1.0478112,  0.0228866, -0.0501270;
+             0.0295424,  0.9904844, -0.0170491;
+            -0.0092345,  0.0150436,  0.7521316;
+        ];
+        assert_abs_diff_eq!(Cat::Bradford.matrix(d65, d50), want, epsilon = 1E-6);
+    }
+
+    #[test]
+    fn test_adapt() {
+        let d65 = CIE1931.xyz(&StdIlluminant::D65, None).set_illuminance(100.0);
+        let a = CIE1931.xyz(&Illuminant::planckian(2856.0), None).set_illuminance(100.0);
+        let green = CIE1931.xyz(&StdIlluminant::D65, Some(&Colorant::gaussian(530.0, 30.0)));
+        for cat in Cat::iter() {
+            // whites map to whites, and adapting back restores the original values
+            let adapted = green.adapt(cat, &d65, &a).unwrap();
+            assert_abs_diff_eq!(adapted.xyzn, a.xyzn, epsilon = 1E-9);
+            let back = adapted.adapt(cat, &a, &d65).unwrap();
+            assert_abs_diff_eq!(back, green, epsilon = 1E-9);
+        }
+        #[cfg(feature = "supplemental-observers")]
+        {
+            let d65_10 = XYZ::new(&[94.81, 100.0, 107.32], None, Observer::Std1964);
+            assert!(green.adapt(Cat::Cat16, &d65_10, &a).is_err());
+        }
+    }
+}
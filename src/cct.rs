@@ -38,7 +38,11 @@ Changing it would have had big implications for the lighting industry, having to
 definitions over a long time, effectively changing the definition from a percetption to a purely
 mathematical definition.
 
-The CIE standard requires CCT to be calculated using the CIE 1931 standard observer.
+The CIE standard requires CCT to be calculated using the CIE 1931 standard observer, and this is
+the only observer accepted by the `TryFrom<XYZ>` conversion.
+For research use, [`CCT::try_from_xyz_with_observer`] uses the Planckian locus of the observer of the
+tristimulus values instead, such as the CIE 2015 cone fundamental based observers, available with the
+`supplemental-observers` feature; these values are not standard correlated color temperatures.
 
 # References
 
@@ -49,7 +53,7 @@ use std::{cmp::max, sync::OnceLock};
 
 use approx::{assert_ulps_eq, relative_eq, ulps_eq, AbsDiffEq, RelativeEq, UlpsEq};
 
-use crate::{geometry::distance_to_line, physics::planck, error::CmtError, observer::{Observer, ObserverData}, data::observers::CIE1931, spectrum::NS, xyz::XYZ};
+use crate::{compliance::compliance_mode, geometry::distance_to_line, math, physics::planck, error::CmtError, observer::{Observer, ObserverData, N_OBSERVERS}, data::observers::CIE1931, spectrum::NS, xyz::XYZ};
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct CCT(f64, f64);
@@ -117,10 +121,67 @@ impl CCT {
         self.1
     }
 
+    /// Distance to the Planckian locus in the CIE 1960 UCS diagram, positive above the locus.
+    /// Same as [`d`](Self::d).
+    pub fn duv(&self) -> f64 {
+        self.1
+    }
+
     pub fn tint(&self) -> f64 {
         1000.0 * self.1
     }
 
+    /**
+    Correlated color temperature and distance to the Planckian locus, using the Planckian locus,
+    and CIE 1960 UCS chromaticity coordinates, of the observer of the tristimulus values.
+
+    For CIE 1931 tristimulus values this is the same as the standard `TryFrom<XYZ>` conversion;
+    for other observers the result is not a standard correlated color temperature, and a
+    `RequiresCIE1931XYZ` error is returned in the strict [`ComplianceMode`](crate::compliance::ComplianceMode).
+    */
+    pub fn try_from_xyz_with_observer(xyz: XYZ) -> Result<Self, CmtError> {
+        if compliance_mode().is_strict() && xyz.observer != Observer::Std1931 {
+            return Err(CmtError::RequiresCIE1931XYZ);
+        }
+        cct_from_xyz(xyz.observer.data(), xyz)
+    }
+
+    /**
+    Correlated color temperature and distance to the Planckian locus, using the Planckian locus of
+    the observer of the tristimulus values, with the method, temperature range, and accuracy set by
+    a [`CctOptions`].
+
+    Returns a `CCTTemperatureTooLow`, or `CCTTemperatureTooHigh`, error for chromaticities with a
+    correlated color temperature outside the range of the options, and, in the strict
+    [`ComplianceMode`](crate::compliance::ComplianceMode), a `RequiresCIE1931XYZ` error for other
+    observers than the CIE 1931 observer.
+    ```
+    use colorimetry::prelude::*;
+
+    let xyz = CIE1931.xyz_planckian_locus(2700.0);
+    let options = CctOptions::default().set_method(CctMethod::Ohno2013).set_range([2000.0, 4000.0]);
+    let cct = CCT::try_from_xyz_with_options(xyz, &options).unwrap();
+    approx::assert_abs_diff_eq!(cct.t(), 2700.0, epsilon = 0.01);
+    approx::assert_abs_diff_eq!(cct.duv(), 0.0, epsilon = 1E-6);
+
+    let options = options.set_range([3000.0, 4000.0]);
+    assert_eq!(CCT::try_from_xyz_with_options(xyz, &options), Err(CmtError::CCTTemperatureTooLow));
+    ```
+    */
+    pub fn try_from_xyz_with_options(xyz: XYZ, options: &CctOptions) -> Result<Self, CmtError> {
+        CctResult::try_from_xyz_with_options(xyz, options).map(|cct| cct.cct())
+    }
+
+    /// Tristimulus values for this correlated color temperature and Planckian locus distance,
+    /// using the Planckian locus of an observer, with a luminous value of 100.0.
+    pub fn try_into_xyz_with_observer(self, observer: Observer) -> Result<XYZ, CmtError> {
+        let CCT(t, d) = self;
+        let [u0,v0,m] = iso_temp_line(observer.data(), t);
+        let du = m.signum() * d/(m*m+1.0).sqrt();
+        let dv = m * du;
+        XYZ::try_from_luv60(u0+du, v0+dv, None, Some(observer))
+    }
+
 }
 
 /// Get cct and duv values as an array.
@@ -130,6 +191,270 @@ impl From<CCT> for [f64;2] {
     }
 }
 
+/**
+A correlated color temperature, and Planckian locus distance, with the method used to obtain it,
+the number of iterations of the method, and the residual, as the distance in the CIE 1960 UCS
+diagram between the chromaticity, and the chromaticity recalculated from the result.
+
+The residual measures the accuracy of the solution: it is less than 1E-7 for the default
+Robertson method, and depends on the tolerance for Ohno's method. Record it, with the method, in
+reports, or to check the reference illuminant selection of the color rendering index calculation.
+```
+use colorimetry::prelude::*;
+
+let xyz = CIE1931.xyz(&Illuminant::planckian(3000.0), None);
+let cct = CctResult::try_from(xyz).unwrap();
+approx::assert_abs_diff_eq!(cct.t(), 3000.0, epsilon = 0.1);
+assert_eq!(cct.method(), CctMethod::Robertson);
+assert!(cct.residual() < 1E-7);
+
+let options = CctOptions::default().set_method(CctMethod::Ohno2013).set_tolerance(1.0);
+let ohno = CctResult::try_from_xyz_with_options(xyz, &options).unwrap();
+assert!(ohno.iterations() > 1);
+```
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CctResult {
+    cct: CCT,
+    method: CctMethod,
+    iterations: usize,
+    residual: f64,
+}
+
+impl CctResult {
+    /**
+    Correlated color temperature, with its metadata, using the Planckian locus of the observer of
+    the tristimulus values, with the method, temperature range, and accuracy set by a
+    [`CctOptions`], returning the same errors as [`CCT::try_from_xyz_with_options`].
+    */
+    pub fn try_from_xyz_with_options(xyz: XYZ, options: &CctOptions) -> Result<Self, CmtError> {
+        let [t_min, t_max] = options.range;
+        let [low, high] = [im2t(N_STEPS - 1), im2t(0)];
+        if !(low..=high).contains(&t_min) || !(low..=high).contains(&t_max) || t_min >= t_max {
+            return Err(CmtError::OutOfRange { name: "CCT range".into(), low, high });
+        }
+        if compliance_mode().is_strict() && xyz.observer != Observer::Std1931 {
+            return Err(CmtError::RequiresCIE1931XYZ);
+        }
+        let observer = xyz.observer.data();
+        let (cct, iterations) = match options.method {
+            CctMethod::Robertson => (cct_from_xyz(observer, xyz)?, N_DEPTH),
+            CctMethod::Ohno2013 => cct_ohno(observer, xyz, options)?,
+        };
+        match cct.t() {
+            t if t < t_min * (1.0 - f64::EPSILON.sqrt()) => Err(CmtError::CCTTemperatureTooLow),
+            t if t > t_max * (1.0 + f64::EPSILON.sqrt()) => Err(CmtError::CCTTemperatureTooHigh),
+            _ => Ok(Self { cct, method: options.method, iterations, residual: residual(observer, xyz, cct) }),
+        }
+    }
+
+    /// The correlated color temperature, and Planckian locus distance, without metadata.
+    pub fn cct(&self) -> CCT {
+        self.cct
+    }
+
+    /// Correlated color temperature, in kelvin.
+    pub fn t(&self) -> f64 {
+        self.cct.t()
+    }
+
+    /// Distance to the Planckian locus in the CIE 1960 UCS diagram, positive above the locus.
+    pub fn duv(&self) -> f64 {
+        self.cct.duv()
+    }
+
+    pub fn method(&self) -> CctMethod {
+        self.method
+    }
+
+    /// Number of binary search steps for the Robertson method, or the number of tables in the
+    /// cascade of Ohno's method.
+    pub fn iterations(&self) -> usize {
+        self.iterations
+    }
+
+    /// Distance in the CIE 1960 UCS diagram between the chromaticity, and the chromaticity
+    /// recalculated from the correlated color temperature, and Planckian locus distance.
+    pub fn residual(&self) -> f64 {
+        self.residual
+    }
+}
+
+/// Correlated color temperature with metadata, using the Robertson method, for CIE 1931 tristimulus
+/// values only, as the `TryFrom<XYZ>` conversion for [`CCT`].
+impl TryFrom<XYZ> for CctResult {
+    type Error = CmtError;
+
+    fn try_from(xyz: XYZ) -> Result<Self, Self::Error> {
+        if xyz.observer != Observer::Std1931 { return Err(CmtError::RequiresCIE1931XYZ); }
+        Self::try_from_xyz_with_options(xyz, &CctOptions::default())
+    }
+}
+
+impl From<CctResult> for CCT {
+    fn from(cct: CctResult) -> Self {
+        cct.cct
+    }
+}
+
+/// Distance in the CIE 1960 UCS diagram between the chromaticity of tristimulus values, and the
+/// chromaticity of a correlated color temperature, and Planckian locus distance.
+fn residual(observer: &ObserverData, xyz: XYZ, cct: CCT) -> f64 {
+    let [u, v] = xyz.xy().uv60().values();
+    let [u0, v0, m] = iso_temp_line(observer, cct.t());
+    let du = m.signum() * cct.d() / (m * m + 1.0).sqrt();
+    math::hypot(u - u0 - du, v - v0 - m * du)
+}
+
+/// Method to find the correlated color temperature of a chromaticity.
+#[derive(Clone, Copy, Debug, Default, PartialEq, strum_macros::Display, strum_macros::EnumIter)]
+pub enum CctMethod {
+    /// Binary search in a table of 4096 iso-temperature lines, equally spaced in mired, from
+    /// 1000 to 1_000_000 kelvin, followed by Robertson's interpolation between the two lines
+    /// enclosing the chromaticity, as used by `TryFrom<XYZ>`.
+    /// Its accuracy is set by the size of the table, and is not changed by the tolerance of the
+    /// options.
+    #[default]
+    Robertson,
+    /// Ohno's (2013) method: the Planckian locus point nearest to the chromaticity is found in a
+    /// cascade of tables, with temperatures equally spaced on a logarithmic scale, each covering the
+    /// interval around the nearest point of the previous table, followed by a triangular
+    /// interpolation for chromaticities close to the locus, with a |Duv| less than 0.002, and a
+    /// parabolic interpolation otherwise.
+    Ohno2013,
+}
+
+/**
+Options for the calculation of correlated color temperatures, as used by
+[`CCT::try_from_xyz_with_options`].
+
+By default the Robertson method is used, with a temperature range from 1000 to 1_000_000 kelvin,
+and a tolerance of 0.01 kelvin.
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CctOptions {
+    method: CctMethod,
+    range: [f64; 2],
+    tolerance: f64,
+}
+
+impl Default for CctOptions {
+    fn default() -> Self {
+        Self { method: CctMethod::default(), range: [im2t(N_STEPS - 1), im2t(0)], tolerance: 0.01 }
+    }
+}
+
+impl CctOptions {
+    pub fn set_method(mut self, method: CctMethod) -> Self {
+        self.method = method;
+        self
+    }
+
+    /// Sets the lowest and highest correlated color temperatures, in kelvin, accepted as result,
+    /// within the range from 1000 to 1_000_000 kelvin.
+    /// For the Ohno method this is also the range of the first table of the cascade.
+    pub fn set_range(mut self, range: [f64; 2]) -> Self {
+        self.range = range;
+        self
+    }
+
+    /// Sets the width, in kelvin, of the temperature interval of the last table in the cascade of
+    /// Ohno's method; the error of the interpolated temperature is much smaller than this interval.
+    pub fn set_tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    pub fn method(&self) -> CctMethod {
+        self.method
+    }
+
+    pub fn range(&self) -> [f64; 2] {
+        self.range
+    }
+
+    pub fn tolerance(&self) -> f64 {
+        self.tolerance
+    }
+}
+
+/**
+Interval of correlated color temperatures, and Planckian locus distances, covered by an
+uncertainty ellipse of a chromaticity in the CIE 1931 xy diagram, as obtained by
+[`CctInterval::try_from_xy_ellipse`].
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CctInterval {
+    cct: CCT,
+    t_range: [f64; 2],
+    d_range: [f64; 2],
+}
+
+impl CctInterval {
+    /// Number of points on the ellipse boundary projected onto the iso-temperature lines.
+    const N_ELLIPSE: usize = 360;
+
+    /**
+    Correlated color temperature and distance to the Planckian locus intervals, for an
+    uncertainty ellipse in the CIE 1931 xy chromaticity diagram, with its center at `x` and `y`,
+    semi-axes `a` and `b`, and the angle of the `a` axis with the x axis `theta`, in radians.
+
+    The points on the boundary of the ellipse are projected onto the Planckian locus, along the
+    iso-temperature lines, to find the range of the correlated color temperatures, and the range
+    of their distances to the locus.
+    For an ellipse with axes of one standard uncertainty, the half widths of the intervals are
+    the standard uncertainties of the CCT and Duv values.
+    ```
+    use colorimetry::prelude::*;
+
+    // D65, with an uncertainty of 0.001 in x and y
+    let interval = CctInterval::try_from_xy_ellipse(0.3127, 0.3290, 0.001, 0.001, 0.0).unwrap();
+    let [t_min, t_max] = interval.t_range();
+    assert!(t_min < 6504.0 && t_max > 6504.0);
+    approx::assert_abs_diff_eq!(interval.t_uncertainty(), 55.0, epsilon = 1.0);
+    ```
+    */
+    pub fn try_from_xy_ellipse(x: f64, y: f64, a: f64, b: f64, theta: f64) -> Result<Self, CmtError> {
+        let cct = CCT::try_from(XYZ::try_from_chromaticity(x, y, None, None)?)?;
+        let [mut t_range, mut d_range] = [[cct.t(), cct.t()], [cct.d(), cct.d()]];
+        let (sin, cos) = (math::sin(theta), math::cos(theta));
+        for i in 0..Self::N_ELLIPSE {
+            let phi = 2.0 * f64::consts::PI * i as f64 / Self::N_ELLIPSE as f64;
+            let [da, db] = [a * math::cos(phi), b * math::sin(phi)];
+            let xyz = XYZ::try_from_chromaticity(x + da * cos - db * sin, y + da * sin + db * cos, None, None)?;
+            let CCT(t, d) = CCT::try_from(xyz)?;
+            t_range = [t_range[0].min(t), t_range[1].max(t)];
+            d_range = [d_range[0].min(d), d_range[1].max(d)];
+        }
+        Ok(Self { cct, t_range, d_range })
+    }
+
+    /// Correlated color temperature, and distance to the Planckian locus, of the ellipse's center.
+    pub fn cct(&self) -> CCT {
+        self.cct
+    }
+
+    /// Lowest and highest correlated color temperatures, in kelvin, within the ellipse.
+    pub fn t_range(&self) -> [f64; 2] {
+        self.t_range
+    }
+
+    /// Lowest and highest distances to the Planckian locus within the ellipse.
+    pub fn d_range(&self) -> [f64; 2] {
+        self.d_range
+    }
+
+    /// Half width of the correlated color temperature interval, in kelvin.
+    pub fn t_uncertainty(&self) -> f64 {
+        (self.t_range[1] - self.t_range[0]) / 2.0
+    }
+
+    /// Half width of the Planckian locus distance interval.
+    pub fn d_uncertainty(&self) -> f64 {
+        (self.d_range[1] - self.d_range[0]) / 2.0
+    }
+}
+
 /// Number of iterations in binary search.
 pub const N_DEPTH: usize = 12; // 2^12 iso temperature line
 
@@ -154,65 +479,132 @@ impl TryFrom<XYZ> for CCT {
 
     fn try_from(xyz: XYZ) -> Result<Self, Self::Error> {
         if xyz.observer != Observer::Std1931 { return Err(CmtError::RequiresCIE1931XYZ); }
-        let [u, v] = xyz.uv60();
-        // index bounderies N_STEPS-1 length lookup table e.g. 0-4095
-        let [mut imlow, mut imhigh]  = [0usize, N_STEPS-1];
-        let [mut dlow, mut dhigh] = [0.0, 0.0];
-       
-        for _ in 0..N_DEPTH {
-            let im = (imhigh+imlow)/2;
-            let &[ub, vb, m] = robertson_table(im);
-            let d = distance_to_line(u, v, ub, vb, m);
-            if d<0.0 { // line is located left of (xyz)
-                imlow = im;
-                dlow = d;
-            } else { // line is located right of (xyz)
-                imhigh = im;
-                dhigh = d;
+        cct_from_xyz(&CIE1931, xyz)
+    }
+
+}
+
+/// Robertson and binary search algorithm, using the Planckian locus of an observer.
+fn cct_from_xyz(observer: &ObserverData, xyz: XYZ) -> Result<CCT, CmtError> {
+    let [u, v] = xyz.xy().uv60().values();
+    debug_span!("cct", observer = ?observer.tag, u, v);
+    // index bounderies N_STEPS-1 length lookup table e.g. 0-4095
+    let [mut imlow, mut imhigh]  = [0usize, N_STEPS-1];
+    let [mut dlow, mut dhigh] = [0.0, 0.0];
+   
+    for _ in 0..N_DEPTH {
+        let im = (imhigh+imlow)/2;
+        let &[ub, vb, m] = robertson_row(observer, im);
+        let d = distance_to_line(u, v, ub, vb, m);
+        debug_event!(im, t = im2t(im), d, "iso-temperature line");
+        if d<0.0 { // line is located left of (xyz)
+            imlow = im;
+            dlow = d;
+        } else { // line is located right of (xyz)
+            imhigh = im;
+            dhigh = d;
+        }
+    }  
+    if imlow == 0 { // xyz is in the first interval, or above high temperature limit
+        let &[ub, vb, m] = robertson_row(observer, imlow);
+        let d = distance_to_line(u, v, ub, vb, m);
+        match distance_to_line(u, v, ub, vb, m){
+            d if ulps_eq!(d,0.0,epsilon=1E-10) => { // at low temp limit
+                dlow = 0.0;
+                dhigh = 1.0;
+                imhigh = 1;
             }
-        }  
-        if imlow == 0 { // xyz is in the first interval, or above high temperature limit
-            let &[ub, vb, m] = robertson_table(imlow);
-            let d = distance_to_line(u, v, ub, vb, m);
-            match distance_to_line(u, v, ub, vb, m){
-                d if ulps_eq!(d,0.0,epsilon=1E-10) => { // at low temp limit
-                    dlow = 0.0;
-                    dhigh = 1.0;
-                    imhigh = 1;
-                }
-                d if d>0.0 => return Err(CmtError::CCTTemperatureTooHigh),
-                d => dlow = d 
-            };
+            d if d>0.0 => return Err(CmtError::CCTTemperatureTooHigh),
+            d => dlow = d 
         };
-        if imhigh == N_STEPS - 1 { // xyz is in the last interval, or below low temperature limit
-            let &[ub, vb, m] = robertson_table(imhigh);
-            match distance_to_line(u, v, ub, vb, m){
-                d if ulps_eq!(d,0.0,epsilon=1E-10) => { // at high temp limit
-                    dhigh = -0.0;
-                    imlow = N_STEPS - 2;
-                }
-                d if d<0.0 => return Err(CmtError::CCTTemperatureTooLow),
-                d => dlow = d 
-            };
+    };
+    if imhigh == N_STEPS - 1 { // xyz is in the last interval, or below low temperature limit
+        let &[ub, vb, m] = robertson_row(observer, imhigh);
+        match distance_to_line(u, v, ub, vb, m){
+            d if ulps_eq!(d,0.0,epsilon=1E-10) => { // at high temp limit
+                dhigh = -0.0;
+                imlow = N_STEPS - 2;
+            }
+            d if d<0.0 => return Err(CmtError::CCTTemperatureTooLow),
+            d => dlow = d 
         };
+    };
 
-        let t = robertson_interpolate(im2t(imlow), dlow, im2t(imhigh), dhigh);
-        let d = duv_interpolate(u, v, imlow, imhigh, dlow, dhigh);
-        CCT::try_new(t, d)
-    }
+    let t = robertson_interpolate(im2t(imlow), dlow, im2t(imhigh), dhigh);
+    let d = duv_interpolate(observer, u, v, imlow, imhigh, dlow, dhigh);
+    debug_event!(imlow, imhigh, dlow, dhigh, t, duv = d, "interpolated");
+    CCT::try_new(t, d)
+}
+
+/// Number of Planckian locus points in each table of the cascade in Ohno's method.
+const N_OHNO: usize = 15;
+
+/// Maximum number of tables in the cascade of Ohno's method.
+const MAX_CASCADE: usize = 40;
 
+/// Limit of |Duv| for the triangular solution in Ohno's method; the parabolic solution is used
+/// for chromaticities further from the Planckian locus.
+const OHNO_TRIANGULAR_DUV: f64 = 0.002;
+
+/// Ohno's (2013) cascade, with the triangular and parabolic solutions, using the Planckian locus of
+/// an observer.
+///
+/// Yoshi Ohno, Practical Use and Calculation of CCT and Duv, LEUKOS 10:1, 47-55 (2014).
+/// The correction factor of 0.99991 for the triangular solution, in the paper, is for a single
+/// table with 1% steps, and is not used with the cascade here.
+fn cct_ohno(observer: &ObserverData, xyz: XYZ, options: &CctOptions) -> Result<(CCT, usize), CmtError> {
+    let [u, v] = xyz.xy().uv60().values();
+    debug_span!("cct_ohno", observer = ?observer.tag, u, v);
+    let locus = |t: f64| observer.xyz_planckian_locus(t).xy().uv60().values();
+    let [mut t_low, mut t_high] = options.range;
+    let mut points = [[0.0; 4]; 3]; // temperature, u, v, distance
+    let mut iterations = 0;
+    for _ in 0..MAX_CASCADE {
+        iterations += 1;
+        let table: Vec<[f64; 4]> = (0..N_OHNO)
+            .map(|i| {
+                let t = t_low * math::powf(t_high / t_low, i as f64 / (N_OHNO - 1) as f64);
+                let [ut, vt] = locus(t);
+                [t, ut, vt, math::hypot(u - ut, v - vt)]
+            })
+            .collect();
+        let m = (0..N_OHNO).min_by(|&i, &j| table[i][3].total_cmp(&table[j][3])).unwrap().clamp(1, N_OHNO - 2);
+        points = [table[m - 1], table[m], table[m + 1]];
+        [t_low, t_high] = [points[0][0], points[2][0]];
+        debug_event!(t_low, t_high, "cascade");
+        if t_high - t_low < options.tolerance {
+            break;
+        }
+    }
+    let [[tp, up, vp, dp], [tm, _, _, dm], [tn, un, vn, dn]] = points;
+
+    // triangular solution
+    let l = math::hypot(un - up, vn - vp);
+    let x = (dp * dp - dn * dn + l * l) / (2.0 * l);
+    let mut t = tp + (tn - tp) * x / l;
+    let mut duv = (dp * dp - x * x).max(0.0).sqrt();
+
+    if duv >= OHNO_TRIANGULAR_DUV {
+        // parabolic solution, with temperatures relative to the middle point for numerical stability
+        let [sp, sn] = [tp - tm, tn - tm];
+        let [gp, gn] = [(dp - dm) / sp, (dn - dm) / sn];
+        let a = (gp - gn) / (sp - sn);
+        let b = gp - a * sp;
+        t = tm - b / (2.0 * a);
+        duv = dm - b * b / (4.0 * a);
+    }
+    let [_, vt] = locus(t);
+    let duv = if v < vt { -duv } else { duv };
+    debug_event!(t, duv, iterations, "interpolated");
+    Ok((CCT::try_new(t, duv)?, iterations))
 }
-    
+
 /// Calculate tristimulus values from a Correlated Color Temperature and a Planckian Locus Distance.
 /// This can fail for lower temperatures, and positive distances, with a chromaticity outsiode the CIE 1931 gamut.
 impl TryFrom<CCT> for XYZ {
     type Error = CmtError;
     fn try_from(cct: CCT) -> Result<Self, Self::Error> {
-        let CCT(t, d) = cct;
-        let [u0,v0,m] = iso_temp_line(t);
-        let du = m.signum() * d/(m*m+1.0).sqrt();
-        let dv = m * du;
-        XYZ::try_from_luv60(u0+du, v0+dv, None, None)
+        cct.try_into_xyz_with_observer(Observer::Std1931)
     }
     
 }
@@ -220,11 +612,11 @@ impl TryFrom<CCT> for XYZ {
 /// Calculates Robertson's Table values for a temperature value of t, in units of Kelvin.
 /// These are the coordinates of the blackbody locus at temperature T, and it's line normal,
 /// which is the slope of the curve at that point rotated by 90º.
-fn iso_temp_line(t: f64) -> [f64;3] {
-    let xyz = CIE1931.xyz_planckian_locus(t);
+fn iso_temp_line(observer: &ObserverData, t: f64) -> [f64;3] {
+    let xyz = observer.xyz_planckian_locus(t);
     let [x, y, z] = xyz.values();
-    let [u, v ]= xyz.uv60();
-    let [dx, dy, dz] = CIE1931.xyz_planckian_locus_slope(t).values();
+    let [u, v ]= xyz.xy().uv60().values();
+    let [dx, dy, dz] = observer.xyz_planckian_locus_slope(t).values();
     let sigma = x + 15.0 * y + 3.0 * z;
     let dsigma = dx + 15.0 * dy + 3.0 * dz;
     let den = 6.0 * y * dsigma - 6.0 * dy * sigma;
@@ -249,18 +641,50 @@ fn iso_temp_line(t: f64) -> [f64;3] {
 /// For more information, see `The Improved Robertson Method for Calculating
 /// Correlated Color Temperature` by Gerard Harbers.
 pub fn robertson_table(im: usize) -> &'static [f64;3] {
-    static ROBERTSON_TABLE: OnceLock<[OnceLock<[f64;3]>;N_STEPS]> = OnceLock::new();
+    robertson_row(&CIE1931, im)
+}
+
+/**
+Chromaticity coordinates of the Planckian locus of an observer, for a set of temperatures, in
+kelvin, with rows of the temperature, the CIE 1931 (x,y), and the CIE 1960 UCS (u,v), coordinates,
+to be compared with published reference values, such as the tables in CIE 015.
+```
+use colorimetry::prelude::*;
+
+let table = planckian_locus_table(&CIE1931, &[2000.0, 6500.0]);
+let [t, x, y, u, v] = table[1];
+assert_eq!(t, 6500.0);
+approx::assert_abs_diff_eq!([x, y].as_ref(), [0.3135, 0.3236].as_ref(), epsilon = 1E-4);
+approx::assert_abs_diff_eq!([u, v].as_ref(), [0.2004, 0.3104].as_ref(), epsilon = 1E-4);
+```
+*/
+pub fn planckian_locus_table(observer: &ObserverData, temperatures: &[f64]) -> Vec<[f64; 5]> {
+    temperatures
+        .iter()
+        .map(|&t| {
+            let xyz = observer.xyz_planckian_locus(t);
+            let [x, y] = xyz.xy().values();
+            let [u, v] = xyz.xy().uv60().values();
+            [t, x, y, u, v]
+        })
+        .collect()
+}
+
+/// Row of the Robertson table of an observer, with the rows calculated on first use, as for the CIE
+/// 1931 table returned by `robertson_table`.
+fn robertson_row(observer: &ObserverData, im: usize) -> &'static [f64;3] {
+    static ROBERTSON_TABLES: [OnceLock<[OnceLock<[f64;3]>;N_STEPS]>; N_OBSERVERS] = [const { OnceLock::new() }; N_OBSERVERS];
 
     // Get reference to table, or initialize it when not done yet.
     const UVM_EMPTY: OnceLock<[f64;3]> = OnceLock::new();
-    let robertson_table = ROBERTSON_TABLE.get_or_init(|| {
+    let robertson_table = ROBERTSON_TABLES[observer.tag as usize].get_or_init(|| {
         [UVM_EMPTY; N_STEPS]
     });
     
     // Get table row, or calculate when not done yet.
     robertson_table[im].get_or_init(||{
             let cct = im2t(im);
-            iso_temp_line(cct)
+            iso_temp_line(observer, cct)
     })
 }
 
@@ -286,18 +710,18 @@ fn robertson_interpolate(tp: f64, dp: f64, tn: f64, dn: f64) -> f64 {
 /// Finds intersection between iso-temperature lines at (ux,vx), and calculates the distance to this point for the
 /// table points. Linear interpolates the value between thse two, and substracts this value of the distance from 
 /// the test point to the intersection.
-fn duv_interpolate(u: f64, v: f64, imp: usize, imn: usize, dp: f64, dh: f64) -> f64 {
-    let [ul, vl, ml] = robertson_table(imp);
-    let [uh, vh, mh] = robertson_table(imn);
+fn duv_interpolate(observer: &ObserverData, u: f64, v: f64, imp: usize, imn: usize, dp: f64, dh: f64) -> f64 {
+    let [ul, vl, ml] = robertson_row(observer, imp);
+    let [uh, vh, mh] = robertson_row(observer, imn);
 
     // (ux, vx) intersection point of the two iso temperature lines
     let ux =(ml * ul - vl - mh * uh + vh)/(ml - mh);
     let vx = ml * (ux - ul) + vl;
 
     // distances blackbody points to intersection
-    let ddl = (ul - ux).hypot(vl - vx);
-    let ddh = (uh - ux).hypot(vh - vx);
-    let dd = (u - ux).hypot(v - vx);
+    let ddl = math::hypot(ul - ux, vl - vx);
+    let ddh = math::hypot(uh - ux, vh - vx);
+    let dd = math::hypot(u - ux, v - vx);
 
     // interpolated distance at blackbody locus
     let d = ddl + dp/(dp-dh) * (ddh - ddl);
@@ -383,6 +807,70 @@ fn test_cct(){
 
 }
 
+#[test]
+fn ohno_test(){
+    let ohno = CctOptions::default().set_method(CctMethod::Ohno2013);
+    for (t, d) in [(1000.0, 0.0), (1500.0, -0.01), (2700.0, -0.001), (4000.0, 0.0015), (6500.0, 0.003), (6500.0, -0.02), (20_000.0, 0.04), (500_000.0, -0.03)] {
+        let xyz: XYZ = CCT(t, d).try_into().unwrap();
+        let robertson = CCT::try_from_xyz_with_options(xyz, &CctOptions::default()).unwrap();
+        assert_eq!(robertson, xyz.cct().unwrap());
+        let cct = CCT::try_from_xyz_with_options(xyz, &ohno).unwrap();
+        approx::assert_abs_diff_eq!(cct.t(), t, epsilon = 1E-5 * t);
+        approx::assert_abs_diff_eq!(cct.duv(), d, epsilon = 1E-6);
+    }
+
+    // range and tolerance
+    let xyz: XYZ = CCT(3000.0, 0.0).try_into().unwrap();
+    let options = ohno.set_range([2000.0, 2500.0]);
+    assert_eq!(CCT::try_from_xyz_with_options(xyz, &options), Err(CmtError::CCTTemperatureTooHigh));
+    let robertson = CctOptions::default().set_range([3500.0, 4000.0]);
+    assert_eq!(CCT::try_from_xyz_with_options(xyz, &robertson), Err(CmtError::CCTTemperatureTooLow));
+    let coarse = CCT::try_from_xyz_with_options(xyz, &ohno.set_tolerance(100.0)).unwrap();
+    approx::assert_abs_diff_eq!(coarse.t(), 3000.0, epsilon = 1.0);
+    assert!(CCT::try_from_xyz_with_options(xyz, &ohno.set_range([500.0, 4000.0])).is_err());
+}
+
+#[test]
+fn cct_details_test(){
+    let xyz: XYZ = CCT(4000.0, 0.01).try_into().unwrap();
+    let cct = CctResult::try_from(xyz).unwrap();
+    assert_eq!(CCT::from(cct), xyz.cct().unwrap());
+    assert_eq!(cct.iterations(), N_DEPTH);
+    assert!(cct.residual() < 1E-7);
+
+    // a coarse tolerance takes fewer tables, and leaves a larger residual
+    let ohno = CctOptions::default().set_method(CctMethod::Ohno2013);
+    let fine = CctResult::try_from_xyz_with_options(xyz, &ohno).unwrap();
+    let coarse = CctResult::try_from_xyz_with_options(xyz, &ohno.set_tolerance(100.0)).unwrap();
+    assert_eq!(fine.method(), CctMethod::Ohno2013);
+    assert!(coarse.iterations() < fine.iterations());
+    assert!(coarse.residual() > fine.residual());
+    approx::assert_abs_diff_eq!(fine.t(), 4000.0, epsilon = 0.05);
+    approx::assert_abs_diff_eq!(fine.duv(), 0.01, epsilon = 1E-6);
+
+    #[cfg(feature = "supplemental-observers")]
+    assert_eq!(CctResult::try_from(XYZ { observer: Observer::Std1964, ..xyz }), Err(CmtError::RequiresCIE1931XYZ));
+}
+
+#[test]
+fn cct_interval_test(){
+    // ellipse reduced to a point
+    let interval = CctInterval::try_from_xy_ellipse(0.4476, 0.4074, 0.0, 0.0, 0.0).unwrap();
+    assert_eq!(interval.t_range(), [interval.cct().t(); 2]);
+
+    // an ellipse elongated along the locus mainly changes the temperature, and across it the distance
+    let [x0, y0] = CIE1931.xyz_planckian_locus(2800.0).xy().values();
+    let [x1, y1] = CIE1931.xyz_planckian_locus(2900.0).xy().values();
+    let theta = math::atan2(y1 - y0, x1 - x0);
+    let along = CctInterval::try_from_xy_ellipse(0.4476, 0.4074, 0.002, 0.0002, theta).unwrap();
+    let across = CctInterval::try_from_xy_ellipse(0.4476, 0.4074, 0.002, 0.0002, theta + f64::consts::FRAC_PI_2).unwrap();
+    // iso-temperature lines are not orthogonal to the locus in the xy diagram
+    assert!(along.t_uncertainty() > across.t_uncertainty());
+    assert!(across.d_uncertainty() > 3.0 * along.d_uncertainty());
+    let [d_min, d_max] = across.d_range();
+    assert!(d_min < across.cct().d() && d_max > across.cct().d());
+}
+
 #[test]
 fn f1_test(){
     let xyz_f1 = CIE1931.xyz_cie_table(&crate::std_illuminants::StdIlluminant::F1, None);
@@ -397,3 +885,19 @@ fn f3_1_test(){
     approx::assert_ulps_eq!(xyz_f3_1.cct().unwrap().t(), 2932.0, epsilon = 0.5);
 }
 
+
+#[test]
+#[cfg(feature="supplemental-observers")]
+fn observer_test(){
+    use crate::data::observers::CIE2015;
+    // a blackbody is on the Planckian locus of any observer
+    let xyz = CIE2015.xyz_planckian_locus(4000.0);
+    assert_eq!(CCT::try_from(xyz), Err(CmtError::RequiresCIE1931XYZ));
+    let cct = CCT::try_from_xyz_with_observer(xyz).unwrap();
+    approx::assert_abs_diff_eq!(cct, CCT(4000.0, 0.0), epsilon = 1E-3);
+
+    let cct0 = CCT::try_new(3000.0, 0.01).unwrap();
+    let xyz = cct0.try_into_xyz_with_observer(Observer::Std2015).unwrap();
+    assert_eq!(xyz.observer, Observer::Std2015);
+    approx::assert_abs_diff_eq!(CCT::try_from_xyz_with_observer(xyz).unwrap(), cct0, epsilon = 1E-3);
+}
@@ -0,0 +1,245 @@
+/*!
+# Color Fidelity Index
+
+The CIE 2017 color fidelity index Rf, as defined in CIE 224:2017, is the successor of the CIE 13.3
+general color rendering index Ra. It compares the colors of 99 Color Evaluation Samples (CES),
+illuminated by a test source, and by a reference illuminant, using color differences in the
+CAM02-UCS color space, calculated with the CIE 1964 10º observer.
+
+The reference illuminant is a Planckian radiator for test sources with a correlated color
+temperature below 4000 K, a CIE D illuminant above 5000 K, and a mixture of the two in between,
+with both normalized to the same luminous value.
+As the calculation uses the CIE 1964 observer, this module requires the `supplemental-observers`
+feature, in addition to the `cri` feature.
+The special indices Rf,i, and the general index Rf, are obtained from the color differences ΔE'
+with a scaling factor of 6.73:
+
+Rf = 10 ln(exp((100 - 6.73 ΔE') / 10) + 1).
+
+# Color Evaluation Samples
+The spectral reflectance data of the 99 Color Evaluation Samples is not included in this library.
+It is available from CIE, as a supplement to CIE 224:2017, and has to be registered with
+[`set_ces_samples`] before an [`Rf`] can be calculated from an illuminant only.
+Alternatively, use [`Rf::try_new_with_samples`] with a sample set of your own.
+
+As the samples are not included, the calculation has not been validated against the Rf, and Rf,i,
+values published in CIE 224:2017, for example for the CIE F illuminants; the tests of this module
+only check it with synthetic samples. Bundling the samples, with such a validation, is still open.
+```
+use colorimetry::prelude::*;
+
+let samples: Vec<Colorant> = (0..12).map(|i| Colorant::gaussian(420.0 + 25.0 * i as f64, 30.0)).collect();
+let rf = Rf::try_new_with_samples(StdIlluminant::F3_11.illuminant(), &samples).unwrap();
+assert!(rf.rf() < 95.0);
+assert_eq!(rf.as_ref().len(), 12);
+```
+
+# References
+- CIE 224:2017 CIE 2017 Colour Fidelity Index for accurate scientific use
+*/
+
+use std::{f64::consts::PI, ops::Index, sync::OnceLock};
+
+use nalgebra::Vector3;
+
+use crate::{
+    cam::{MCAT02, MCAT02INV, MHPE},
+    cct::CctResult,
+    colorant::Colorant,
+    data::observers::{CIE1931, CIE1964},
+    error::CmtError,
+    illuminant::Illuminant,
+    math,
+    spectrum::Spectrum,
+    viewconditions::TM30VC,
+};
+
+/// Number of Color Evaluation Samples.
+pub const N_CES: usize = 99;
+
+/// Scaling factor, relating color differences to fidelity values.
+const CF: f64 = 6.73;
+
+static CES: OnceLock<Vec<Colorant>> = OnceLock::new();
+
+/**
+Registers the spectral reflectance factors of the 99 CIE 224 Color Evaluation Samples, in the
+order of the standard, for use in [`Rf`] calculations.
+
+They can be set only once, and an error is returned if they were set before, or if the number of
+samples is not 99.
+*/
+pub fn set_ces_samples(samples: Vec<Colorant>) -> Result<(), CmtError> {
+    if samples.len() != N_CES {
+        return Err(CmtError::ErrorString(format!("CES: requires {N_CES} samples, got {}", samples.len())));
+    }
+    CES.set(samples)
+        .map_err(|_| CmtError::ErrorString("CES: samples have already been set".into()))
+}
+
+/// The 99 Color Evaluation Samples, if registered.
+pub fn ces_samples() -> Option<&'static [Colorant]> {
+    CES.get().map(|v| v.as_slice())
+}
+
+/// CIE 2017 color fidelity index Rf, and its special indices Rf,i.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rf {
+    rf: f64,
+    rfi: Vec<f64>,
+}
+
+impl Rf {
+    pub fn try_new(s: &Illuminant) -> Result<Self, CmtError> {
+        s.try_into()
+    }
+
+    /// Fidelity indices for a set of samples, other than the Color Evaluation Samples, using the
+    /// method of CIE 224:2017.
+    pub fn try_new_with_samples(s: &Illuminant, samples: &[Colorant]) -> Result<Self, CmtError> {
+        if samples.is_empty() {
+            return Err(CmtError::ErrorString("Rf: requires at least one sample".into()));
+        }
+        let (jab, jab_ref) = cam02_ucs_samples(s, samples)?;
+        let delta_e: Vec<f64> = jab.iter().zip(jab_ref.iter()).map(|(t, r)| (t - r).norm()).collect();
+        let mean = delta_e.iter().sum::<f64>() / delta_e.len() as f64;
+        Ok(Self { rf: fidelity(mean), rfi: delta_e.into_iter().map(fidelity).collect() })
+    }
+
+    /// General color fidelity index.
+    pub fn rf(&self) -> f64 {
+        self.rf
+    }
+
+    /// Special color fidelity index of the sample at position `i`, counting from 0 as with
+    /// indexing, so `rfi(0)` is Rf,1 of CIE 224:2017, or `None` if out of range.
+    pub fn rfi(&self, i: usize) -> Option<f64> {
+        self.rfi.get(i).copied()
+    }
+}
+
+/// Rf calculation.
+///
+/// Requires the Color Evaluation Samples to be registered with [`set_ces_samples`], and can fail
+/// if the correlated color temperature of the source is out of range.
+impl TryFrom<&Illuminant> for Rf {
+    type Error = CmtError;
+
+    fn try_from(illuminant: &Illuminant) -> Result<Self, Self::Error> {
+        let samples = ces_samples().ok_or_else(|| {
+            CmtError::ErrorString("Rf: the CIE 224 Color Evaluation Samples have not been set".into())
+        })?;
+        Self::try_new_with_samples(illuminant, samples)
+    }
+}
+
+impl Index<usize> for Rf {
+    type Output = f64;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.rfi[index]
+    }
+}
+
+impl AsRef<[f64]> for Rf {
+    fn as_ref(&self) -> &[f64] {
+        &self.rfi
+    }
+}
+
+/// Fidelity value, from a CAM02-UCS color difference.
+pub(crate) fn fidelity(delta_e: f64) -> f64 {
+    10.0 * math::ln(math::exp((100.0 - CF * delta_e) / 10.0) + 1.0)
+}
+
+/// CIE 224 reference illuminant, for a test source, normalized with the CIE 1964 observer.
+pub(crate) fn cfi_reference_illuminant(s: &Illuminant) -> Result<Illuminant, CmtError> {
+    let cct = CctResult::try_from(CIE1931.xyz(s, None))?.t();
+    let planckian = || Illuminant::planckian(cct).set_illuminance(&CIE1964, 100.0);
+    let daylight = || Illuminant::try_d_illuminant(cct).map(|d| d.set_illuminance(&CIE1964, 100.0));
+    Ok(if cct < 4000.0 {
+        planckian()
+    } else if cct > 5000.0 {
+        daylight()?
+    } else {
+        let m = (cct - 4000.0) / 1000.0;
+        Illuminant(Spectrum(planckian().0 .0 * (1.0 - m) + daylight()?.0 .0 * m))
+    })
+}
+
+/// CAM02-UCS J'a'b' values of a set of samples, for a test source, and for its reference.
+type JabPairs = (Vec<Vector3<f64>>, Vec<Vector3<f64>>);
+
+/// CAM02-UCS J'a'b' values of samples, illuminated by a test source, and by its CIE 224 reference
+/// illuminant.
+pub(crate) fn cam02_ucs_samples(s: &Illuminant, samples: &[Colorant]) -> Result<JabPairs, CmtError> {
+    let reference = cfi_reference_illuminant(s)?;
+    let jab = |light: &Illuminant| -> Vec<Vector3<f64>> {
+        let white = CIE1964.xyz(light, None).xyzn;
+        samples
+            .iter()
+            .map(|c| cam02_ucs(CIE1964.xyz(light, Some(c)).xyz.unwrap(), white))
+            .collect()
+    };
+    Ok((jab(s), jab(&reference)))
+}
+
+/// CAM02-UCS J'a'b' values, for the CIE 224 viewing conditions, with complete adaptation to a
+/// white with a luminous value of 100.
+pub(crate) fn cam02_ucs(xyz: Vector3<f64>, xyzw: Vector3<f64>) -> Vector3<f64> {
+    let vc = TM30VC;
+    let fl = vc.f_l();
+    let n = vc.yb / xyzw.y;
+    let z = 1.48 + n.sqrt();
+    let nbb = 0.725 * math::powf(n, -0.2);
+    let d = vc.dd();
+    let rgb_w = MCAT02 * xyzw;
+    let gain = rgb_w.map(|v| d * xyzw.y / v + 1.0 - d);
+    let compress = |v: f64| {
+        let t = math::powf(fl * v.abs() / 100.0, 0.42);
+        v.signum() * 400.0 * t / (27.13 + t) + 0.1
+    };
+    let adapted = |xyz: Vector3<f64>| (MHPE * MCAT02INV * (MCAT02 * xyz).component_mul(&gain)).map(compress);
+    let achromatic = |rgb: &Vector3<f64>| (2.0 * rgb.x + rgb.y + rgb.z / 20.0 - 0.305) * nbb;
+
+    let rgb = adapted(xyz);
+    let aw = achromatic(&adapted(xyzw));
+    let a = rgb.x - 12.0 * rgb.y / 11.0 + rgb.z / 11.0;
+    let b = (rgb.x + rgb.y - 2.0 * rgb.z) / 9.0;
+    let h = math::atan2(b, a).rem_euclid(2.0 * PI);
+    let j = 100.0 * math::powf(achromatic(&rgb) / aw, vc.c * z);
+    let et = 0.25 * (math::cos(h + 2.0) + 3.8);
+    let t = 50000.0 / 13.0 * vc.nc * nbb * et * (a * a + b * b).sqrt() / (rgb.x + rgb.y + 21.0 / 20.0 * rgb.z);
+    let c = math::powf(t, 0.9) * (j / 100.0).sqrt() * math::powf(1.64 - math::powf(0.29, n), 0.73);
+    let m = c * math::powf(fl, 0.25);
+    let mp = math::ln(1.0 + 0.0228 * m) / 0.0228;
+    Vector3::new(1.7 * j / (1.0 + 0.007 * j), mp * math::cos(h), mp * math::sin(h))
+}
+
+#[cfg(test)]
+mod cfi_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_rf() {
+        let samples: Vec<Colorant> = (0..16).map(|i| Colorant::gaussian(410.0 + 20.0 * i as f64, 25.0)).collect();
+        // a Planckian source is its own reference
+        let rf = Rf::try_new_with_samples(&Illuminant::planckian(3000.0), &samples).unwrap();
+        assert_abs_diff_eq!(rf.rf(), 100.0, epsilon = 1E-3);
+        assert_abs_diff_eq!(rf.rfi(15).unwrap(), 100.0, epsilon = 1E-3);
+        assert!(rf.rfi(16).is_none());
+
+        // a narrow band fluorescent lamp has a lower fidelity, in the transition range too
+        #[cfg(feature = "cie-illuminants")]
+        for lamp in [StdIlluminant::F3_11, StdIlluminant::F2] {
+            let rf = Rf::try_new_with_samples(lamp.illuminant(), &samples).unwrap();
+            assert!(rf.rf() < 90.0);
+            assert!(rf.as_ref().iter().all(|&r| r <= 100.01));
+            assert_eq!(rf.rfi(3), Some(rf[3]));
+        }
+        assert!(Rf::try_new_with_samples(&Illuminant::planckian(3000.0), &[]).is_err());
+        assert!(Rf::try_new(&Illuminant::planckian(3000.0)).is_err());
+        assert!(set_ces_samples(samples).is_err());
+    }
+}
@@ -0,0 +1,338 @@
+/*!
+# CGATS
+
+Reading and writing of the CGATS.17 text format, as used for color measurement data exchange, and
+as base format of ArgyllCMS spectral and measurement data files.
+
+A CGATS file consists of a file type identifier line, keyword-value pairs, a data format
+section listing the field names, and a data section with the field values for each measurement
+set.
+
+Measured spectral reflectance data are read as named [`Colorant`]s, and calculated results can be
+written as XYZ and CIELAB values:
+```
+use colorimetry::prelude::*;
+
+let samples = vec![("White".to_string(), CIE1931.xyz(&StdIlluminant::D50, Some(&Colorant::white())))];
+let cgats = Cgats::from_xyz_lab(&samples, "Calculated values").unwrap();
+let text = cgats.write();
+assert!(text.contains("SAMPLE_NAME XYZ_X XYZ_Y XYZ_Z LAB_L LAB_A LAB_B"));
+```
+*/
+
+use crate::{
+    colorant::Colorant,
+    error::CmtError,
+    lab::CieLab,
+    spectral_data::{QuantityKind, SpectralData, SpectralUnit},
+    spectrum::{Spectrum, NS},
+    xyz::XYZ,
+};
+
+/// Keywords defined in the CGATS.17 standard, which do not need a `KEYWORD` declaration.
+const STANDARD_KEYWORDS: [&str; 12] = [
+    "ORIGINATOR", "DESCRIPTOR", "CREATED", "MANUFACTURER", "MANUFACTURE", "PROD_DATE", "SERIAL",
+    "MATERIAL", "INSTRUMENTATION", "MEASUREMENT_SOURCE", "PRINT_CONDITIONS", "FILE_DESCRIPTOR",
+];
+
+/// Parsed contents of a CGATS file, with all data values kept as text.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Cgats {
+    pub(crate) file_type: String,
+    pub(crate) keywords: Vec<(String, String)>,
+    pub(crate) fields: Vec<String>,
+    pub(crate) rows: Vec<Vec<String>>,
+}
+
+impl Cgats {
+
+    /// Parses a CGATS file. Comment lines, starting with `#`, and `KEYWORD` declarations are
+    /// ignored.
+    pub fn parse(s: &str) -> Result<Self, CmtError> {
+        let mut cgats = Cgats::default();
+        let mut lines = s.lines().map(str::trim).filter(|l| !l.is_empty() && !l.starts_with('#'));
+        cgats.file_type = tokenize(lines.next().ok_or_else(|| CmtError::ParseError("CGATS: empty file".into()))?)
+            .into_iter().next().unwrap_or_default();
+        let mut section = Section::Header;
+        for line in lines {
+            let tokens = tokenize(line);
+            let Some(first) = tokens.first() else { continue };
+            match (section, first.as_str()) {
+                (_, "BEGIN_DATA_FORMAT") => section = Section::DataFormat,
+                (_, "END_DATA_FORMAT") | (_, "END_DATA") => section = Section::Header,
+                (_, "BEGIN_DATA") => section = Section::Data,
+                (Section::DataFormat, _) => cgats.fields.extend(tokens),
+                (Section::Data, _) => cgats.rows.push(tokens),
+                (Section::Header, "KEYWORD" | "NUMBER_OF_FIELDS" | "NUMBER_OF_SETS") => {}
+                (Section::Header, key) => {
+                    cgats.keywords.push((key.to_string(), tokens[1..].join(" ")));
+                }
+            }
+        }
+        if cgats.fields.is_empty() {
+            return Err(CmtError::ParseError("CGATS: no data format section".into()));
+        }
+        if let Some(row) = cgats.rows.iter().find(|r| r.len() != cgats.fields.len()) {
+            return Err(CmtError::ParseError(format!("CGATS: expected {} values in data row, found {}", cgats.fields.len(), row.len())));
+        }
+        Ok(cgats)
+    }
+
+    /// File type identifier, such as "CGATS.17", or "CTI3".
+    pub fn file_type(&self) -> &str {
+        &self.file_type
+    }
+
+    /// Value of a keyword, such as "ORIGINATOR", or "CREATED".
+    pub fn keyword(&self, key: &str) -> Option<&str> {
+        self.keywords.iter().find(|(k, _)| k == key).map(|(_, v)| v.as_str())
+    }
+
+    /// Field names, as listed in the data format section.
+    pub fn fields(&self) -> &[String] {
+        &self.fields
+    }
+
+    /// Number of measurement sets in the data section.
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Value of a field, by its name, for a measurement set.
+    pub fn get(&self, row: usize, field: &str) -> Option<&str> {
+        let i = self.field_index(field)?;
+        self.rows.get(row).map(|r| r[i].as_str())
+    }
+
+    pub(crate) fn field_index(&self, name: &str) -> Option<usize> {
+        self.fields.iter().position(|f| f == name)
+    }
+
+    /// Name of a measurement set, using its `SAMPLE_NAME`, or `SAMPLE_ID` field, or its
+    /// sequence number, starting at 1, if neither of these is available.
+    pub fn sample_name(&self, row: usize) -> String {
+        self.get(row, "SAMPLE_NAME")
+            .or(self.get(row, "SAMPLE_ID"))
+            .map(String::from)
+            .unwrap_or_else(|| (row + 1).to_string())
+    }
+
+    /**
+    Spectral reflectance data of all measurement sets, as named colorants.
+
+    Values are scaled by the file's `SPECTRAL_NORM` keyword value, if present; otherwise, values
+    are taken as percentages if any of them exceeds 1.0, as in most instrument exports.
+    Reflectance values are clamped to a range from 0.0 to 1.0.
+    */
+    pub fn colorants(&self) -> Result<Vec<(String, Colorant)>, CmtError> {
+        let norm = self.reflectance_norm()?;
+        (0..self.rows.len()).map(|r| {
+            let mut spectrum = self.spectrum(r, norm)?;
+            spectrum.clamp(0.0, 1.0);
+            Ok((self.sample_name(r), Colorant(spectrum)))
+        }).collect()
+    }
+
+    /// Spectral reflectance data of a measurement set, in the file's wavelength domain, which
+    /// should have regular intervals. Values are scaled as in [`Cgats::colorants`].
+    pub fn spectral_data(&self, row: usize) -> Result<SpectralData, CmtError> {
+        let fields = self.spectral_fields();
+        if fields.len() < 2 || row >= self.rows.len() {
+            return Err(CmtError::ParseError("CGATS: no spectral data".into()));
+        }
+        let (start, step) = (fields[0].1, fields[1].1 - fields[0].1);
+        if fields.iter().enumerate().any(|(i, &(_, wl))| (wl - (start + i as f64 * step)).abs() > 1E-6) {
+            return Err(CmtError::ParseError("CGATS: spectral fields do not have a regular interval".into()));
+        }
+        let norm = self.reflectance_norm()?;
+        let values = fields.iter().map(|&(i, _)| self.value(row, i)).collect::<Result<Vec<f64>, _>>()?;
+        let (unit, values) = if norm == 100.0 {
+            (SpectralUnit::Percent, values)
+        } else {
+            (SpectralUnit::Fraction, values.into_iter().map(|v| v / norm).collect())
+        };
+        SpectralData::try_new(QuantityKind::Reflectance, unit, start, step, values)
+    }
+
+    /// Scale of the spectral reflectance values: the `SPECTRAL_NORM` keyword value, if present,
+    /// or 100.0 if any value exceeds 1.0.
+    fn reflectance_norm(&self) -> Result<f64, CmtError> {
+        match self.keyword("SPECTRAL_NORM") {
+            Some(v) => v.parse().map_err(|_| CmtError::ParseError(format!("CGATS: invalid SPECTRAL_NORM '{v}'"))),
+            None => {
+                let fields = self.spectral_fields();
+                let percent = (0..self.rows.len()).any(|r| fields.iter().any(|&(i, _)| self.value(r, i).is_ok_and(|v| v > 1.0)));
+                Ok(if percent { 100.0 } else { 1.0 })
+            }
+        }
+    }
+
+    /**
+    CGATS data set with the XYZ tristimulus values, and the CIELAB values, of named samples.
+
+    The XYZ values should be those of colorants, calculated with a reference illuminant;
+    for tristimulus values of lights, which have no CIELAB values, this returns an error.
+    */
+    pub fn from_xyz_lab(samples: &[(String, XYZ)], descriptor: &str) -> Result<Self, CmtError> {
+        let rows = samples.iter().map(|(name, xyz)| {
+            let lab = CieLab::try_from(*xyz)?;
+            Ok([name.clone()].into_iter()
+                .chain(xyz.values().iter().chain(lab.as_ref()).map(|v| format!("{v:.4}")))
+                .collect())
+        }).collect::<Result<Vec<Vec<String>>, CmtError>>()?;
+        Ok(Self {
+            file_type: "CGATS.17".to_string(),
+            keywords: vec![
+                ("ORIGINATOR".to_string(), "colorimetry".to_string()),
+                ("DESCRIPTOR".to_string(), descriptor.to_string()),
+            ],
+            fields: ["SAMPLE_NAME", "XYZ_X", "XYZ_Y", "XYZ_Z", "LAB_L", "LAB_A", "LAB_B"].into_iter().map(String::from).collect(),
+            rows,
+        })
+    }
+
+    /// Column indices and wavelengths, in nanometer, of the spectral fields, using any of the
+    /// `SPEC_380`, `SPECTRAL_NM380`, `SPECTRAL_380`, or `nm380` field name conventions.
+    pub(crate) fn spectral_fields(&self) -> Vec<(usize, f64)> {
+        self.fields.iter().enumerate().filter_map(|(i, f)| {
+            ["SPEC_", "SPECTRAL_NM", "SPECTRAL_", "NM", "nm"].iter()
+                .find_map(|p| f.strip_prefix(p))
+                .and_then(|wl| wl.parse().ok())
+                .map(|wl| (i, wl))
+        }).collect()
+    }
+
+    /// Numeric value of a field in a data row.
+    pub(crate) fn value(&self, row: usize, field: usize) -> Result<f64, CmtError> {
+        let s = &self.rows[row][field];
+        s.parse().map_err(|_| CmtError::ParseError(format!("CGATS: invalid number '{s}' in field {}", self.fields[field])))
+    }
+
+    /// Spectrum from the spectral fields of a data row, divided by `norm`, and resampled to the
+    /// library's wavelength domain.
+    pub(crate) fn spectrum(&self, row: usize, norm: f64) -> Result<Spectrum, CmtError> {
+        let fields = self.spectral_fields();
+        if fields.len() < 3 {
+            return Err(CmtError::ParseError("CGATS: no spectral fields".into()));
+        }
+        let wl: Vec<f64> = fields.iter().map(|&(_, wl)| wl).collect();
+        let values = fields.iter().map(|&(i, _)| self.value(row, i).map(|v| v / norm)).collect::<Result<Vec<f64>, _>>()?;
+        // data in the library's own domain: no need to interpolate
+        if wl.len() == NS && wl[0] == 380.0 && wl[NS - 1] == 780.0 {
+            return Spectrum::try_from(values.as_slice());
+        }
+        Spectrum::try_linear_interpolate(&wl, &values)
+    }
+
+    /// Writes the data set in CGATS format, adding `KEYWORD` declarations for non-standard
+    /// keywords.
+    pub fn write(&self) -> String {
+        let mut s = format!("{}\n\n", self.file_type);
+        for (k, v) in &self.keywords {
+            if !STANDARD_KEYWORDS.contains(&k.as_str()) {
+                s += &format!("KEYWORD \"{k}\"\n");
+            }
+            s += &format!("{k} \"{v}\"\n");
+        }
+        s += &format!("\nNUMBER_OF_FIELDS {}\nBEGIN_DATA_FORMAT\n{}\nEND_DATA_FORMAT\n", self.fields.len(), self.fields.join(" "));
+        s += &format!("\nNUMBER_OF_SETS {}\nBEGIN_DATA\n", self.rows.len());
+        for row in &self.rows {
+            let quoted: Vec<String> = row.iter().map(|v| if v.contains(char::is_whitespace) { format!("\"{v}\"") } else { v.clone() }).collect();
+            s += &quoted.join(" ");
+            s += "\n";
+        }
+        s += "END_DATA\n";
+        s
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Section {
+    Header,
+    DataFormat,
+    Data,
+}
+
+/// Splits a line into whitespace separated tokens, keeping double quoted strings together, and
+/// removing their quotes.
+fn tokenize(line: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut token = String::new();
+    let mut quoted = false;
+    for c in line.chars() {
+        match c {
+            '"' => {
+                if quoted {
+                    tokens.push(std::mem::take(&mut token));
+                }
+                quoted = !quoted;
+            }
+            c if c.is_whitespace() && !quoted => {
+                if !token.is_empty() {
+                    tokens.push(std::mem::take(&mut token));
+                }
+            }
+            c => token.push(c),
+        }
+    }
+    if !token.is_empty() {
+        tokens.push(token);
+    }
+    tokens
+}
+
+#[cfg(test)]
+mod cgats_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    const CGATS: &str = r#"CGATS.17
+ORIGINATOR	"i1Profiler"
+# a comment line
+CREATED	"May 1, 2024"
+NUMBER_OF_FIELDS	6
+BEGIN_DATA_FORMAT
+SampleID	SAMPLE_NAME	SPECTRAL_NM380	SPECTRAL_NM580	SPECTRAL_NM780	LAB_L
+END_DATA_FORMAT
+NUMBER_OF_SETS	2
+BEGIN_DATA
+1	"Paper white"	90.0	92.0	94.0	95.1
+2	A2	10.0	20.0	30.0	40.0
+END_DATA
+"#;
+
+    #[test]
+    fn test_parse(){
+        let cgats = Cgats::parse(CGATS).unwrap();
+        assert_eq!(cgats.file_type(), "CGATS.17");
+        assert_eq!(cgats.keyword("CREATED"), Some("May 1, 2024"));
+        assert_eq!(cgats.len(), 2);
+        assert_eq!(cgats.get(0, "LAB_L"), Some("95.1"));
+        let colorants = cgats.colorants().unwrap();
+        assert_eq!(colorants[0].0, "Paper white");
+        assert_abs_diff_eq!(colorants[1].1[680], 0.25, epsilon = 1E-12);
+        let data = cgats.spectral_data(1).unwrap();
+        assert_eq!((data.start, data.step, data.unit), (380.0, 200.0, SpectralUnit::Percent));
+        assert_abs_diff_eq!(Colorant::try_from(&data).unwrap()[680], 0.25, epsilon = 1E-12);
+    }
+
+    #[test]
+    fn test_write_roundtrip(){
+        let samples: Vec<(String, XYZ)> = [0.2, 0.8].iter()
+            .map(|&g| (format!("gray {g}"), CIE1931.xyz(&StdIlluminant::D65, Some(&Colorant::gray(g)))))
+            .collect();
+        let cgats = Cgats::from_xyz_lab(&samples, "grays").unwrap();
+        let read = Cgats::parse(&cgats.write()).unwrap();
+        assert_eq!(read, cgats);
+        assert_eq!(read.sample_name(1), "gray 0.8");
+        let l: f64 = read.get(1, "LAB_L").unwrap().parse().unwrap();
+        assert_abs_diff_eq!(l, 91.6, epsilon = 0.1);
+
+        let light = vec![("D65".to_string(), CIE1931.xyz(&StdIlluminant::D65, None))];
+        assert!(Cgats::from_xyz_lab(&light, "light").is_err());
+    }
+}
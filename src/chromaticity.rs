@@ -0,0 +1,355 @@
+/*!
+# Chromaticity Coordinates, and Interpolation in Chromaticity Space
+
+Chromaticities are given in different diagrams, such as the CIE 1931 (x,y) diagram, the CIE 1976
+(u',v') diagram, and the CIE 1960 (u,v) diagram, which is still used for correlated color
+temperatures, and these coordinates are easily mixed up when passed around as `[f64; 2]` arrays.
+A [`Chromaticity`] stores CIE 1931 (x,y) coordinates, obtained with [`XYZ::xy`], and converts them
+to the other diagrams, as [`UvPrime`], and [`Uv60`], coordinates, which can not be mixed up with
+each other, and calculates distances in each of them, on request.
+```
+use colorimetry::prelude::*;
+
+let d65 = CIE1931.xyz_d65().xy();
+let d50 = Chromaticity::try_new(0.3457, 0.3585).unwrap();
+approx::assert_abs_diff_eq!(d65.distance_uvprime(&d50), 0.0228, epsilon = 1E-4);
+approx::assert_abs_diff_eq!(d65.uv60().v(), d65.uvprime().v() * 2.0 / 3.0, epsilon = 1E-12);
+```
+
+Tunable white light sources, and user interface elements such as color temperature sliders, require
+intermediate colors between two end points.
+There are different ways to do this, with different results:
+
+- **Additive mixing** of two lights, as done by [`xy_mix`], produces chromaticities on a straight line
+  in the CIE 1931 (x,y) chromaticity diagram, but the position on this line is not proportional to the
+  mixing ratio: the lights are weighted by their X+Y+Z sums, and not by their luminance.
+- **Interpolation in the CIE 1976 u'v'** diagram, as done by [`uvprime_interpolate`], produces steps
+  which are perceptually more uniform, as this diagram is more uniform than the CIE 1931 diagram.
+- **Interpolation along the Planckian locus**, as done by [`cct_interpolate`], keeps the intermediate
+  points at the same distance from the locus, and uses steps of equal size on the reciprocal
+  temperature, or mired, scale.
+*/
+
+use crate::{error::CmtError, math, observer::Observer, xyz::XYZ};
+
+#[cfg(feature="cct")]
+use crate::cct::CCT;
+
+/// Chromaticity coordinates, stored as CIE 1931 (x,y) values.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Chromaticity {
+    pub(crate) x: f64,
+    pub(crate) y: f64,
+}
+
+impl Chromaticity {
+    /// Chromaticity from CIE 1931 (x,y) coordinates, which have to be positive, with a sum not
+    /// larger than one.
+    pub fn try_new(x: f64, y: f64) -> Result<Self, CmtError> {
+        if x >= 0.0 && y > 0.0 && x + y <= 1.0 {
+            Ok(Self { x, y })
+        } else {
+            Err(CmtError::InvalidChromaticityValues)
+        }
+    }
+
+    /// Chromaticity from CIE 1976 (u',v') coordinates.
+    pub fn try_from_uvprime(u: f64, v: f64) -> Result<Self, CmtError> {
+        let den = 6.0 * u - 16.0 * v + 12.0;
+        Self::try_new(9.0 * u / den, 4.0 * v / den)
+    }
+
+    /// Chromaticity from CIE 1960 UCS (u,v) coordinates.
+    pub fn try_from_uv60(u: f64, v: f64) -> Result<Self, CmtError> {
+        Self::try_from_uvprime(u, 1.5 * v)
+    }
+
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    /// CIE 1931 (x,y) coordinates, as an array.
+    pub fn values(&self) -> [f64; 2] {
+        [self.x, self.y]
+    }
+
+    /// CIE 1976 (u',v') coordinates.
+    pub fn uvprime(&self) -> UvPrime {
+        let den = -2.0 * self.x + 12.0 * self.y + 3.0;
+        UvPrime { u: 4.0 * self.x / den, v: 9.0 * self.y / den }
+    }
+
+    /// CIE 1960 UCS (u,v) coordinates.
+    pub fn uv60(&self) -> Uv60 {
+        let UvPrime { u, v } = self.uvprime();
+        Uv60 { u, v: v / 1.5 }
+    }
+
+    /// Distance Δxy in the CIE 1931 (x,y) diagram.
+    pub fn distance_xy(&self, other: &Self) -> f64 {
+        math::hypot(other.x - self.x, other.y - self.y)
+    }
+
+    /// Distance Δu'v' in the CIE 1976 (u',v') diagram.
+    pub fn distance_uvprime(&self, other: &Self) -> f64 {
+        self.uvprime().distance(&other.uvprime())
+    }
+
+    /// Distance Δuv in the CIE 1960 UCS diagram, as used for the chromaticity difference of a
+    /// test source, and its reference, in color rendering calculations.
+    pub fn distance_uv60(&self, other: &Self) -> f64 {
+        self.uv60().distance(&other.uv60())
+    }
+
+    /// Tristimulus values for this chromaticity, with a luminous value, 100.0 if omitted, for an
+    /// observer, the CIE 1931 observer if omitted.
+    pub fn xyz(&self, l: Option<f64>, observer: Option<Observer>) -> Result<XYZ, CmtError> {
+        XYZ::try_from_chromaticity(self.x, self.y, l, observer)
+    }
+}
+
+impl From<Chromaticity> for [f64; 2] {
+    fn from(chromaticity: Chromaticity) -> Self {
+        chromaticity.values()
+    }
+}
+
+impl TryFrom<[f64; 2]> for Chromaticity {
+    type Error = CmtError;
+
+    /// Chromaticity from CIE 1931 (x,y) coordinates.
+    fn try_from([x, y]: [f64; 2]) -> Result<Self, Self::Error> {
+        Self::try_new(x, y)
+    }
+}
+
+/// CIE 1976 (u',v') chromaticity coordinates, obtained with [`Chromaticity::uvprime`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UvPrime {
+    u: f64,
+    v: f64,
+}
+
+impl UvPrime {
+    pub fn u(&self) -> f64 {
+        self.u
+    }
+
+    pub fn v(&self) -> f64 {
+        self.v
+    }
+
+    /// The (u',v') coordinates, as an array.
+    pub fn values(&self) -> [f64; 2] {
+        [self.u, self.v]
+    }
+
+    /// Distance Δu'v' to other (u',v') coordinates.
+    pub fn distance(&self, other: &Self) -> f64 {
+        math::hypot(other.u - self.u, other.v - self.v)
+    }
+}
+
+impl From<UvPrime> for [f64; 2] {
+    fn from(uvprime: UvPrime) -> Self {
+        uvprime.values()
+    }
+}
+
+/// CIE 1960 UCS (u,v) chromaticity coordinates, obtained with [`Chromaticity::uv60`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Uv60 {
+    u: f64,
+    v: f64,
+}
+
+impl Uv60 {
+    pub fn u(&self) -> f64 {
+        self.u
+    }
+
+    pub fn v(&self) -> f64 {
+        self.v
+    }
+
+    /// The (u,v) coordinates, as an array.
+    pub fn values(&self) -> [f64; 2] {
+        [self.u, self.v]
+    }
+
+    /// Distance Δuv to other (u,v) coordinates.
+    pub fn distance(&self, other: &Self) -> f64 {
+        math::hypot(other.u - self.u, other.v - self.v)
+    }
+}
+
+impl From<Uv60> for [f64; 2] {
+    fn from(uv60: Uv60) -> Self {
+        uv60.values()
+    }
+}
+
+/// Chromaticity of an additive mixture of two lights.
+///
+/// The parameter t is the fraction of the luminous value of the second light, in a range from 0.0 to
+/// 1.0, and (1-t) the fraction of the first light: the mixture is the sum of the first light scaled
+/// by (1-t), and the second light scaled by t.
+/// The resulting chromaticity is on the straight line through the two chromaticity points, but not
+/// at a relative distance t, as the contribution of each light is determined by its total X+Y+Z
+/// tristimulus value.
+///
+/// Both values should be illuminant values, without a reference white, and use the same observer.
+/// ```
+/// use colorimetry::prelude::*;
+/// use approx::assert_ulps_eq;
+///
+/// let red = XYZ::try_from_chromaticity(0.6, 0.3, Some(10.0), None).unwrap();
+/// let green = XYZ::try_from_chromaticity(0.2, 0.5, Some(10.0), None).unwrap();
+/// let mix = xy_mix(&red, &green, 0.5).unwrap();
+/// let [x, y] = mix.xy().values();
+/// assert_ulps_eq!(x, 0.45, epsilon = 1E-10);
+/// assert_ulps_eq!(mix.luminous_value(), 10.0, epsilon = 1E-10);
+/// ```
+pub fn xy_mix(a: &XYZ, b: &XYZ, t: f64) -> Result<XYZ, CmtError> {
+    check_t(t)?;
+    (*a * (1.0 - t)).try_add(*b * t)
+}
+
+/// Interpolation between two chromaticities along a straight line in the CIE 1976 u'v' diagram, with
+/// the luminous value interpolated linearly.
+///
+/// The parameter t, in a range from 0.0 to 1.0, is the relative distance from the first to the second
+/// point. As the u'v' diagram is approximately perceptually uniform, equal steps in t result in
+/// approximately equal perceived color differences.
+/// ```
+/// use colorimetry::prelude::*;
+/// use approx::assert_ulps_eq;
+///
+/// let a = XYZ::try_from_uvprime(0.2, 0.45, None, None).unwrap();
+/// let b = XYZ::try_from_uvprime(0.3, 0.5, None, None).unwrap();
+/// let [u, v] = uvprime_interpolate(&a, &b, 0.5).unwrap().xy().uvprime().values();
+/// assert_ulps_eq!(u, 0.25, epsilon = 1E-10);
+/// assert_ulps_eq!(v, 0.475, epsilon = 1E-10);
+/// ```
+pub fn uvprime_interpolate(a: &XYZ, b: &XYZ, t: f64) -> Result<XYZ, CmtError> {
+    check_t(t)?;
+    if a.observer != b.observer {
+        return Err(CmtError::RequireSameObserver);
+    }
+    let [ua, va] = a.xy().uvprime().values();
+    let [ub, vb] = b.xy().uvprime().values();
+    let l = (1.0 - t) * a.luminous_value() + t * b.luminous_value();
+    XYZ::try_from_uvprime(ua + t * (ub - ua), va + t * (vb - va), Some(l), Some(a.observer))
+}
+
+/// Interpolation between two correlated color temperatures, constrained to the Planckian locus.
+///
+/// The temperatures are interpolated linearly on the mired (reciprocal temperature) scale, which is
+/// approximately perceptually uniform along the locus, and the distances to the locus are
+/// interpolated linearly. Use `XYZ::try_from` to get the tristimulus values of the result.
+/// ```
+/// use colorimetry::prelude::*;
+/// use approx::assert_ulps_eq;
+///
+/// let warm = CCT::try_new(2700.0, 0.0).unwrap();
+/// let cool = CCT::try_new(6500.0, 0.0).unwrap();
+/// let mid = cct_interpolate(&warm, &cool, 0.5).unwrap();
+/// assert_ulps_eq!(mid.t(), 2.0/(1.0/2700.0 + 1.0/6500.0), epsilon = 1E-8);
+/// ```
+#[cfg(feature="cct")]
+pub fn cct_interpolate(a: &CCT, b: &CCT, t: f64) -> Result<CCT, CmtError> {
+    check_t(t)?;
+    let mired = (1.0 - t) * 1E6 / a.t() + t * 1E6 / b.t();
+    let duv = (1.0 - t) * a.d() + t * b.d();
+    CCT::try_new(1E6 / mired, duv)
+}
+
+fn check_t(t: f64) -> Result<(), CmtError> {
+    if (0.0..=1.0).contains(&t) {
+        Ok(())
+    } else {
+        Err(CmtError::OutOfRange { name: "Interpolation parameter".into(), low: 0.0, high: 1.0 })
+    }
+}
+
+#[cfg(test)]
+mod chromaticity_test {
+    use crate::prelude::*;
+    use approx::assert_ulps_eq;
+
+    #[test]
+    fn test_chromaticity(){
+        let xyz = XYZ::new(&[30.0, 40.0, 20.0], None, Observer::Std1931);
+        let c = xyz.xy();
+        assert_ulps_eq!(c.values().as_slice(), [30.0 / 90.0, 40.0 / 90.0].as_slice(), epsilon = 1E-15);
+        // from the tristimulus values, with denominator X + 15Y + 3Z
+        assert_ulps_eq!(c.uvprime().values().as_slice(), [120.0 / 690.0, 360.0 / 690.0].as_slice(), epsilon = 1E-15);
+        assert_ulps_eq!(c.uv60().values().as_slice(), [120.0 / 690.0, 240.0 / 690.0].as_slice(), epsilon = 1E-15);
+
+        // roundtrips through the other diagrams
+        let [u, v] = c.uvprime().into();
+        assert_ulps_eq!(Chromaticity::try_from_uvprime(u, v).unwrap().values().as_slice(), c.values().as_slice(), epsilon = 1E-14);
+        let [u, v] = c.uv60().into();
+        assert_ulps_eq!(Chromaticity::try_from_uv60(u, v).unwrap().values().as_slice(), c.values().as_slice(), epsilon = 1E-14);
+        let back = c.xyz(Some(40.0), None).unwrap();
+        assert_ulps_eq!(back.values().as_slice(), xyz.values().as_slice(), epsilon = 1E-12);
+
+        // distances
+        let other = XYZ::new(&[35.0, 40.0, 25.0], None, Observer::Std1931);
+        assert_ulps_eq!(c.distance_uvprime(&other.xy()), xyz.uv_prime_distance(&other), epsilon = 1E-15);
+        let ([x1, y1], [x2, y2]) = (xyz.xy().values(), other.xy().values());
+        assert_ulps_eq!(c.distance_xy(&other.xy()), (x2 - x1).hypot(y2 - y1), epsilon = 1E-15);
+        assert!(c.distance_uv60(&other.xy()) < c.distance_uvprime(&other.xy()));
+
+        assert_eq!(Chromaticity::try_new(0.6, 0.5), Err(CmtError::InvalidChromaticityValues));
+        assert!(Chromaticity::try_from([0.3, 0.0]).is_err());
+        assert_eq!(<[f64; 2]>::from(Chromaticity::try_new(0.3, 0.4).unwrap()), [0.3, 0.4]);
+    }
+
+    #[test]
+    fn test_xy_mix(){
+        let red = XYZ::try_from_chromaticity(0.6, 0.3, Some(10.0), None).unwrap();
+        let green = XYZ::try_from_chromaticity(0.2, 0.5, Some(30.0), None).unwrap();
+
+        // end points
+        assert_ulps_eq!(xy_mix(&red, &green, 0.0).unwrap(), red);
+        assert_ulps_eq!(xy_mix(&red, &green, 1.0).unwrap(), green);
+
+        // mixed chromaticities are on the line through both points
+        let line = LineAB::try_new(red.xy().values(), green.xy().values()).unwrap();
+        for t in [0.1, 0.3, 0.5, 0.7, 0.9] {
+            let [x, y] = xy_mix(&red, &green, t).unwrap().xy().values();
+            assert_ulps_eq!(line.distance(x, y), 0.0, epsilon = 1E-12);
+        }
+
+        assert!(xy_mix(&red, &green, 1.5).is_err());
+    }
+
+    #[test]
+    fn test_uvprime_interpolate(){
+        let a = XYZ::try_from_uvprime(0.2, 0.45, Some(50.0), None).unwrap();
+        let b = XYZ::try_from_uvprime(0.3, 0.5, Some(100.0), None).unwrap();
+        let d = a.uv_prime_distance(&b);
+        let m = uvprime_interpolate(&a, &b, 0.25).unwrap();
+        assert_ulps_eq!(m.uv_prime_distance(&a), 0.25 * d, epsilon = 1E-12);
+        assert_ulps_eq!(m.luminous_value(), 62.5, epsilon = 1E-10);
+    }
+
+    #[cfg(feature="cct")]
+    #[test]
+    fn test_cct_interpolate(){
+        let warm = CCT::try_new(2700.0, 0.002).unwrap();
+        let cool = CCT::try_new(6500.0, -0.002).unwrap();
+        assert_ulps_eq!(cct_interpolate(&warm, &cool, 0.0).unwrap(), warm, epsilon = 1E-8);
+        assert_ulps_eq!(cct_interpolate(&warm, &cool, 1.0).unwrap(), cool, epsilon = 1E-8);
+        let mid = cct_interpolate(&warm, &cool, 0.5).unwrap();
+        assert_ulps_eq!(mid.d(), 0.0, epsilon = 1E-12);
+        let xyz: XYZ = mid.try_into().unwrap();
+        let cct: CCT = xyz.try_into().unwrap();
+        assert_ulps_eq!(cct, mid, epsilon = 1E-4);
+    }
+}
@@ -0,0 +1,195 @@
+/*!
+# Color Index
+
+A [`ColorIndex`] is a k-d tree of the colors in a [`Palette`], for fast nearest-neighbor searches in
+large color catalogs, such as matching measured samples to the closest entries of a paint, or
+textile, color library with many thousands of colors.
+
+The tree is built in the color space of the selected color difference metric: CIELAB for
+[`DeltaE::CIE76`], and OKLab for [`DeltaE::OkLab`], in which the color differences are Euclidean
+distances. A search visits only the branches of the tree which can contain a closer color, and
+returns the same results as an exhaustive search, such as [`Palette::nearest`], typically in
+logarithmic time.
+
+The [`DeltaE::CIE94`], and [`DeltaE::CIEDE2000`], color differences are not distances in a color
+space, and searches with these metrics compare the sample with all colors of the palette.
+*/
+
+use approx::ulps_eq;
+use nalgebra::Vector3;
+
+use crate::{
+    error::CmtError,
+    lab::{CieLab, DeltaE},
+    oklab::oklab,
+    palette::{NamedColor, Palette},
+};
+
+/// Number of dimensions of the color spaces.
+const K: usize = 3;
+
+/**
+Nearest-neighbor search index, over the colors of a palette.
+```
+use colorimetry::prelude::*;
+
+let palette = Palette::new(&StdIlluminant::D50, Observer::Std1931)
+    .add_lab("Red", [45.0, 60.0, 35.0])
+    .add_lab("Green", [55.0, -50.0, 30.0])
+    .add_lab("Blue", [35.0, 20.0, -55.0]);
+let index = ColorIndex::new(palette, DeltaE::OkLab);
+let sample = CieLab::new([50.0, -40.0, 20.0], index.palette().white());
+let (color, _) = index.nearest(&sample).unwrap();
+assert_eq!(color.name, "Green");
+```
+*/
+#[derive(Clone)]
+pub struct ColorIndex {
+    palette: Palette,
+    metric: DeltaE,
+    /// Coordinates of the palette colors, in the metric's color space.
+    points: Vec<[f64; K]>,
+    /// Palette color indices, in the order of an implicit k-d tree, with the median of each slice
+    /// as its node, splitting the slice by coordinates cycling over the dimensions.
+    tree: Vec<usize>,
+}
+
+impl ColorIndex {
+    /// Builds an index for a palette, and a color difference metric.
+    pub fn new(palette: Palette, metric: DeltaE) -> Self {
+        let points: Vec<[f64; K]> = palette.colors().iter().map(|c| coordinates(&c.lab, metric)).collect();
+        let mut tree: Vec<usize> = (0..points.len()).collect();
+        build(&mut tree, &points, 0);
+        Self { palette, metric, points, tree }
+    }
+
+    pub fn palette(&self) -> &Palette {
+        &self.palette
+    }
+
+    pub fn metric(&self) -> DeltaE {
+        self.metric
+    }
+
+    /// The color closest to a sample, with their color difference. The sample's CIELAB values
+    /// need to use the palette's reference white.
+    pub fn nearest(&self, lab: &CieLab) -> Result<(&NamedColor, f64), CmtError> {
+        self.k_nearest(lab, 1)?.into_iter().next().ok_or_else(|| CmtError::ErrorString("ColorIndex: no colors".to_string()))
+    }
+
+    /// The `k` colors closest to a sample, with their color differences, in order of increasing
+    /// difference.
+    pub fn k_nearest(&self, lab: &CieLab, k: usize) -> Result<Vec<(&NamedColor, f64)>, CmtError> {
+        if !ulps_eq!(lab.xyzn, self.palette.white().xyzn) {
+            return Err(CmtError::RequiresSameIlluminant);
+        }
+        if !matches!(self.metric, DeltaE::CIE76 | DeltaE::OkLab) {
+            let mut all = self
+                .palette
+                .colors()
+                .iter()
+                .map(|c| self.metric.delta_e(lab, &c.lab).map(|de| (c, de)))
+                .collect::<Result<Vec<_>, _>>()?;
+            all.sort_by(|a, b| a.1.total_cmp(&b.1));
+            all.truncate(k);
+            return Ok(all);
+        }
+        let query = coordinates(lab, self.metric);
+        let mut best: Vec<(usize, f64)> = Vec::with_capacity(k + 1);
+        if k > 0 {
+            self.search(&self.tree, 0, &query, k, &mut best);
+        }
+        Ok(best.into_iter().map(|(i, d2)| (&self.palette.colors()[i], d2.sqrt())).collect())
+    }
+
+    /// Searches a slice of the tree, keeping the `k` closest colors, with their squared distances,
+    /// sorted by distance.
+    fn search(&self, tree: &[usize], depth: usize, query: &[f64; K], k: usize, best: &mut Vec<(usize, f64)>) {
+        if tree.is_empty() {
+            return;
+        }
+        let mid = tree.len() / 2;
+        let i = tree[mid];
+        let d2 = distance2(&self.points[i], query);
+        if best.len() < k || d2 < best[best.len() - 1].1 {
+            let at = best.partition_point(|&(_, d)| d <= d2);
+            best.insert(at, (i, d2));
+            best.truncate(k);
+        }
+        let axis = depth % K;
+        let delta = query[axis] - self.points[i][axis];
+        let (near, far) = if delta < 0.0 { (&tree[..mid], &tree[mid + 1..]) } else { (&tree[mid + 1..], &tree[..mid]) };
+        self.search(near, depth + 1, query, k, best);
+        if best.len() < k || delta * delta < best[best.len() - 1].1 {
+            self.search(far, depth + 1, query, k, best);
+        }
+    }
+}
+
+/// Coordinates of a color in the color space of a metric.
+fn coordinates(lab: &CieLab, metric: DeltaE) -> [f64; K] {
+    match metric {
+        DeltaE::CIE76 | DeltaE::CIE94 | DeltaE::CIEDE2000 => lab.values(),
+        DeltaE::OkLab => {
+            let xyz = lab.xyz();
+            (oklab(xyz.xyz.unwrap_or(xyz.xyzn), xyz.xyzn) * 100.0).into()
+        }
+    }
+}
+
+fn distance2(a: &[f64; K], b: &[f64; K]) -> f64 {
+    (Vector3::from(*a) - Vector3::from(*b)).norm_squared()
+}
+
+/// Orders a slice of point indices as an implicit k-d tree.
+fn build(tree: &mut [usize], points: &[[f64; K]], depth: usize) {
+    if tree.len() <= 1 {
+        return;
+    }
+    let axis = depth % K;
+    let mid = tree.len() / 2;
+    tree.select_nth_unstable_by(mid, |&a, &b| points[a][axis].total_cmp(&points[b][axis]));
+    let (left, right) = tree.split_at_mut(mid);
+    build(left, points, depth + 1);
+    build(&mut right[1..], points, depth + 1);
+}
+
+#[cfg(test)]
+mod color_index_test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_color_index() {
+        // pseudo-random palette, and samples
+        let mut seed = 12345u64;
+        let mut random = move |range: f64| {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            ((seed >> 11) as f64 / (1u64 << 53) as f64 - 0.5) * range
+        };
+        let mut palette = Palette::new(&StdIlluminant::D65, Observer::Std1931);
+        for i in 0..2000 {
+            palette = palette.add_lab(&format!("C{i}"), [50.0 + random(80.0), random(120.0), random(120.0)]);
+        }
+        for metric in [DeltaE::CIE76, DeltaE::OkLab, DeltaE::CIEDE2000] {
+            let index = ColorIndex::new(palette.clone(), metric);
+            for _ in 0..50 {
+                let lab = CieLab::new([50.0 + random(80.0), random(120.0), random(120.0)], palette.white());
+                let (color, de) = index.nearest(&lab).unwrap();
+                let (want, de_want) = palette.nearest(&lab, metric).unwrap();
+                assert_eq!(color.name, want.name);
+                approx::assert_abs_diff_eq!(de, de_want, epsilon = 1E-9);
+
+                let five = index.k_nearest(&lab, 5).unwrap();
+                assert_eq!(five.len(), 5);
+                assert_eq!(five[0].0.name, want.name);
+                assert!(five.windows(2).all(|w| w[0].1 <= w[1].1));
+            }
+        }
+
+        #[cfg(feature = "cie-illuminants")]
+        {
+            let other_white = CieLab::new([50.0, 0.0, 0.0], CIE1931.xyz(&StdIlluminant::A, None).set_illuminance(100.0));
+            assert!(ColorIndex::new(palette, DeltaE::CIE76).nearest(&other_white).is_err());
+        }
+    }
+}
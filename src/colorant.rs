@@ -4,10 +4,11 @@ use approx::AbsDiffEq;
 use colored::Color;
 use nalgebra::SVector;
 
-use crate::{physics::{gaussian_peak_one, wavelength}, spectrum::wavelengths, error::CmtError, traits::Filter, spectrum::{Spectrum, NS}};
+use crate::{physics::{gaussian_peak_one, wavelength}, spectrum::wavelengths, error::CmtError, illuminant::Illuminant, traits::{Filter, Light}, spectrum::{Spectrum, NS}};
 
 
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Colorant(pub(crate) Spectrum);
 
 impl Colorant {
@@ -34,6 +35,25 @@ impl Colorant {
     }
 
 
+    /**
+    The light reflected, or transmitted, by this colorant, when illuminated by a light, as an
+    illuminant.
+
+    The colorant's values are taken as spectral reflectance factors, or transmittance values, of
+    a non-fluorescent sample, and the result has the light's units. Use this to describe the
+    light from a filtered lamp, or from a reflecting surface, as a new light source.
+    ```
+    use colorimetry::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    let filtered = Colorant::gray(0.5).into_illuminant(&StdIlluminant::D65);
+    assert_abs_diff_eq!(filtered.irradiance(), 0.5 * Illuminant::d65().irradiance(), epsilon = 1E-9);
+    ```
+    */
+    pub fn into_illuminant(self, light: &dyn Light) -> Illuminant {
+        Illuminant(self.0 * *light.spectrum())
+    }
+
     /// A Rectangular Band Filter, specified by a central wavelength, and a
     /// width, both in units of meter, or nanometer.
     ///
@@ -114,12 +134,12 @@ impl<F> From<F> for Colorant
 
         // linear filter from 0.0 to 1.0.
         let tilt: Colorant = (|x:f64|x).into();
-        let xy = CIE1931.xyz(&D65, Some(&tilt)).chromaticity();
+        let xy = CIE1931.xyz(&D65, Some(&tilt)).xy().values();
         approx::assert_abs_diff_eq!(xy.as_ref(), [0.4066, 0.4049].as_ref(), epsilon = 1E-4);
 
         // parabolic filter
         let parabolic: Colorant = (|x:f64|1.0 - 4.0 * (x - 0.5).powi(2)).into();
-        let xy = CIE1931.xyz(&D65, Some(&parabolic)).chromaticity();
+        let xy = CIE1931.xyz(&D65, Some(&parabolic)).xy().values();
         approx::assert_abs_diff_eq!(xy.as_ref(), [0.3466, 0.3862].as_ref(), epsilon = 1E-4);
         ```
     */
@@ -0,0 +1,167 @@
+/*!
+# Colorimeter
+
+A [`Colorimeter`] combines the choices needed to get from a spectral reflectance, or
+transmittance, to color values: an observer, an illuminant, an RGB color space, chromatic
+adaptation, and a color difference metric. It is configured once, and then used to measure
+samples, as tristimulus values, CIELAB values, and RGB display values, and to compare them.
+
+It is a convenience layer on the [`Pipeline`](crate::pipeline::Pipeline), and the
+[`lab`](crate::lab) module, for the most common tasks; use those directly for more control.
+*/
+
+use crate::{
+    colorant::Colorant,
+    error::CmtError,
+    lab::{CieLab, DeltaE},
+    observer::Observer,
+    pipeline::Pipeline,
+    rgb::RGB,
+    rgbspace::RgbSpace,
+    traits::Light,
+    xyz::XYZ,
+};
+
+/**
+A configurable colorimeter, measuring color values of samples.
+```
+use colorimetry::prelude::*;
+
+let colorimeter = Colorimeter::new().set_illuminant(&StdIlluminant::D50);
+let red = colorimeter.measure(&Colorant::gaussian(630.0, 30.0)).unwrap();
+let [l, a, b] = red.lab().values();
+assert!(a > 0.0 && l > 0.0);
+let [r, g, _]: [u8; 3] = red.rgb().into();
+assert!(r > g);
+
+let gray = Colorant::gray(0.5);
+assert!(colorimeter.delta_e(&gray, &Colorant::gray(0.505)).unwrap() < 1.0);
+```
+*/
+#[derive(Clone)]
+pub struct Colorimeter {
+    pipeline: Pipeline,
+    metric: DeltaE,
+}
+
+/// Color values of a sample, as measured by a [`Colorimeter`].
+#[derive(Clone, Copy, Debug)]
+pub struct ColorReading {
+    xyz: XYZ,
+    lab: CieLab,
+    rgb: RGB,
+}
+
+impl ColorReading {
+    /// Tristimulus values, with a luminous value of 100 for the illuminant.
+    pub fn xyz(&self) -> XYZ {
+        self.xyz
+    }
+
+    /// CIELAB values, with the illuminant as reference white.
+    pub fn lab(&self) -> CieLab {
+        self.lab
+    }
+
+    /// Linear RGB values, chromatically adapted to the white of the color space, if enabled,
+    /// and mapped into its gamut.
+    pub fn rgb(&self) -> RGB {
+        self.rgb
+    }
+}
+
+impl Default for Colorimeter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Colorimeter {
+    /// A colorimeter for the CIE 1931 observer, the D65 illuminant, and the sRGB color space,
+    /// with chromatic adaptation, and the CIE 1976 color difference metric.
+    pub fn new() -> Self {
+        Self { pipeline: Pipeline::new(RgbSpace::SRGB), metric: DeltaE::default() }
+    }
+
+    pub fn set_observer(mut self, observer: Observer) -> Self {
+        self.pipeline = self.pipeline.set_observer(observer);
+        self
+    }
+
+    pub fn set_illuminant(mut self, light: &dyn Light) -> Self {
+        self.pipeline = self.pipeline.set_illuminant(light);
+        self
+    }
+
+    /// Sets the color space for the RGB values.
+    pub fn set_space(mut self, space: RgbSpace) -> Self {
+        self.pipeline = self.pipeline.set_space(space);
+        self
+    }
+
+    /// Enables, or disables, the chromatic adaptation of the RGB values from the white of the
+    /// illuminant to the white of the color space, using a von Kries transform in the CAT16 cone
+    /// space.
+    pub fn set_adaptation(mut self, adaptation: bool) -> Self {
+        self.pipeline = self.pipeline.set_adaptation(adaptation);
+        self
+    }
+
+    /// Sets the metric used for color differences.
+    pub fn set_metric(mut self, metric: DeltaE) -> Self {
+        self.metric = metric;
+        self
+    }
+
+    /// Color values of a sample.
+    pub fn measure(&self, sample: &Colorant) -> Result<ColorReading, CmtError> {
+        let output = self.pipeline.run(sample)?;
+        let lab = CieLab::try_from(output.xyz)?;
+        Ok(ColorReading { xyz: output.xyz, lab, rgb: output.mapped })
+    }
+
+    /// CIELAB values of a sample.
+    pub fn lab(&self, sample: &Colorant) -> Result<CieLab, CmtError> {
+        Ok(self.measure(sample)?.lab)
+    }
+
+    /// RGB values of a sample.
+    pub fn rgb(&self, sample: &Colorant) -> Result<RGB, CmtError> {
+        Ok(self.measure(sample)?.rgb)
+    }
+
+    /// Color difference between two samples, using the colorimeter's metric.
+    pub fn delta_e(&self, a: &Colorant, b: &Colorant) -> Result<f64, CmtError> {
+        self.metric.delta_e(&self.lab(a)?, &self.lab(b)?)
+    }
+}
+
+#[cfg(test)]
+mod colorimeter_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_colorimeter() {
+        let colorimeter = Colorimeter::new();
+        let white = colorimeter.measure(&Colorant::white()).unwrap();
+        assert_abs_diff_eq!(white.lab().values().as_ref(), [100.0, 0.0, 0.0].as_ref(), epsilon = 1E-9);
+        assert_eq!(<[u8; 3]>::from(white.rgb()), [255, 255, 255]);
+
+        // a white sample under illuminant A looks warm, unless adapted
+        #[cfg(feature = "cie-illuminants")]
+        {
+            let a = colorimeter.clone().set_illuminant(&StdIlluminant::A);
+            assert_eq!(<[u8; 3]>::from(a.rgb(&Colorant::white()).unwrap()), [255, 255, 255]);
+            let [r, _, b]: [u8; 3] = a.set_adaptation(false).rgb(&Colorant::white()).unwrap().into();
+            assert!(r > b);
+        }
+
+        let de = colorimeter.delta_e(&Colorant::gray(0.2), &Colorant::gray(0.3)).unwrap();
+        let de_ok = colorimeter.clone().set_metric(DeltaE::OkLab).delta_e(&Colorant::gray(0.2), &Colorant::gray(0.3)).unwrap();
+        assert!(de > 5.0 && de_ok > 5.0 && de != de_ok);
+
+        let p3 = colorimeter.set_space(RgbSpace::DisplayP3).rgb(&Colorant::gray(0.5)).unwrap();
+        assert_eq!(p3.space, RgbSpace::DisplayP3);
+    }
+}
@@ -0,0 +1,164 @@
+/*!
+# Columnar Export
+
+Exports batches of spectra, together with their colorimetric values, as
+[Apache Arrow](https://arrow.apache.org) record batches, with the `arrow` feature, and as
+[Parquet](https://parquet.apache.org) files, with the `parquet` feature.
+These can be read directly by data analysis tools such as Polars, and pandas.
+
+Each row represents one sample, with the following columns:
+
+| Column | Type | Content |
+|---|---|---|
+| `name` | Utf8 | sample name |
+| `spectrum` | FixedSizeList\<Float64, 401\> | spectral values, from 380 to 780 nanometer |
+| `X`, `Y`, `Z` | Float64 | tristimulus values |
+| `x`, `y` | Float64 | chromaticity coordinates |
+| `cct`, `duv` | Float64, nullable | correlated color temperature, and distance to the Planckian locus, for lights only, and with the `cct` feature |
+
+The schema's metadata records the observer, and the wavelength domain, as `observer`,
+`wavelength_start_nm`, and `wavelength_step_nm`.
+*/
+
+use std::sync::Arc;
+
+use arrow::{
+    array::{ArrayRef, FixedSizeListArray, Float64Array, RecordBatch, StringArray},
+    datatypes::{DataType, Field, Schema},
+};
+
+use crate::{
+    error::CmtError,
+    observer::ObserverData,
+    spectrum::{Spectrum, NS},
+    traits::Light,
+    xyz::XYZ,
+};
+
+/**
+Arrow record batch, with the names, spectra, and colorimetric values of a batch of samples.
+
+Without an illuminant, the spectra are interpreted as lights, and with an illuminant, as spectral
+reflectance factors, with their tristimulus values normalized to an illuminant luminous value of
+100, as in [`ObserverData::xyz`].
+```
+use colorimetry::prelude::*;
+
+let d65 = Illuminant::d65();
+let a = Illuminant::planckian(2856.0);
+let batch = record_batch(&[("D65", &*d65), ("A", &*a)], &CIE1931, None).unwrap();
+assert_eq!(batch.num_rows(), 2);
+assert_eq!(batch.schema().field(1).name(), "spectrum");
+```
+*/
+pub fn record_batch<S: AsRef<str>>(
+    samples: &[(S, &Spectrum)],
+    observer: &ObserverData,
+    illuminant: Option<&dyn Light>,
+) -> Result<RecordBatch, CmtError> {
+    let xyzn = illuminant.map(|light| light.xyzn(observer.tag, None));
+    let xyz: Vec<XYZ> = samples
+        .iter()
+        .map(|(_, s)| match (illuminant, xyzn) {
+            (Some(light), Some(xyzn)) => {
+                observer.xyz_from_spectrum(&(**s * *light.spectrum()), Some(xyzn)).set_illuminance(100.0)
+            }
+            _ => observer.xyz_from_spectrum(s, None),
+        })
+        .collect();
+
+    let names = StringArray::from_iter_values(samples.iter().map(|(name, _)| name.as_ref()));
+    let values = Float64Array::from_iter_values(
+        samples.iter().flat_map(|(_, s)| AsRef::<[f64]>::as_ref(*s).iter().copied()),
+    );
+    let item = Arc::new(Field::new("item", DataType::Float64, false));
+    let spectra = FixedSizeListArray::try_new(item, NS as i32, Arc::new(values), None).map_err(arrow_error)?;
+
+    let mut fields = vec![Field::new("name", DataType::Utf8, false), Field::new_fixed_size_list(
+        "spectrum",
+        Field::new("item", DataType::Float64, false),
+        NS as i32,
+        false,
+    )];
+    let mut columns: Vec<ArrayRef> = vec![Arc::new(names), Arc::new(spectra)];
+    let tristimulus = xyz.iter().map(XYZ::values);
+    for (i, name) in ["X", "Y", "Z"].into_iter().enumerate() {
+        fields.push(Field::new(name, DataType::Float64, false));
+        columns.push(Arc::new(Float64Array::from_iter_values(tristimulus.clone().map(|v| v[i]))));
+    }
+    for (i, name) in ["x", "y"].into_iter().enumerate() {
+        fields.push(Field::new(name, DataType::Float64, false));
+        columns.push(Arc::new(Float64Array::from_iter_values(xyz.iter().map(|v| v.xy().values()[i]))));
+    }
+    #[cfg(feature = "cct")]
+    {
+        let cct: Vec<Option<crate::cct::CCT>> =
+            xyz.iter().map(|&v| if illuminant.is_none() { v.cct().ok() } else { None }).collect();
+        fields.push(Field::new("cct", DataType::Float64, true));
+        columns.push(Arc::new(cct.iter().map(|c| c.as_ref().map(|c| c.t())).collect::<Float64Array>()));
+        fields.push(Field::new("duv", DataType::Float64, true));
+        columns.push(Arc::new(cct.iter().map(|c| c.as_ref().map(|c| c.d())).collect::<Float64Array>()));
+    }
+
+    let metadata = [
+        ("observer".to_string(), format!("{:?}", observer.tag)),
+        ("wavelength_start_nm".to_string(), "380".to_string()),
+        ("wavelength_step_nm".to_string(), "1".to_string()),
+    ];
+    let schema = Schema::new(fields).with_metadata(metadata.into_iter().collect());
+    RecordBatch::try_new(Arc::new(schema), columns).map_err(arrow_error)
+}
+
+/**
+Writes a record batch, as produced by [`record_batch`], as a Parquet file, to a writer.
+```
+use colorimetry::prelude::*;
+
+let d65 = Illuminant::d65();
+let batch = record_batch(&[("D65", &*d65)], &CIE1931, None).unwrap();
+let mut bytes = Vec::new();
+write_parquet(&mut bytes, &batch).unwrap();
+assert_eq!(&bytes[..4], b"PAR1");
+```
+*/
+#[cfg(feature = "parquet")]
+pub fn write_parquet<W: std::io::Write + Send>(writer: W, batch: &RecordBatch) -> Result<(), CmtError> {
+    let parquet_error = |e: parquet::errors::ParquetError| CmtError::ErrorString(e.to_string());
+    let mut writer = parquet::arrow::ArrowWriter::try_new(writer, batch.schema(), None).map_err(parquet_error)?;
+    writer.write(batch).map_err(parquet_error)?;
+    writer.close().map_err(parquet_error)?;
+    Ok(())
+}
+
+fn arrow_error(e: arrow::error::ArrowError) -> CmtError {
+    CmtError::ErrorString(e.to_string())
+}
+
+#[cfg(test)]
+mod columnar_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+    use arrow::array::{Array, AsArray};
+    use arrow::datatypes::Float64Type;
+
+    #[test]
+    fn test_record_batch() {
+        let red = Colorant::top_hat(650.0, 100.0);
+        let gray = Colorant::gray(0.5);
+        let batch = record_batch(&[("red", &*red), ("gray", &*gray)], &CIE1931, Some(&StdIlluminant::D65)).unwrap();
+        assert_eq!(batch.num_rows(), 2);
+        assert_eq!(batch.schema().metadata()["wavelength_step_nm"], "1");
+
+        let spectra = batch.column_by_name("spectrum").unwrap().as_fixed_size_list();
+        assert_eq!(spectra.value(0).as_primitive::<Float64Type>().value(650 - 380), 1.0);
+
+        let y = batch.column_by_name("Y").unwrap().as_primitive::<Float64Type>();
+        assert_abs_diff_eq!(y.value(1), 50.0, epsilon = 1E-9);
+        let want = CIE1931.xyz(&StdIlluminant::D65, Some(&red)).xy().values();
+        let x = batch.column_by_name("x").unwrap().as_primitive::<Float64Type>();
+        assert_abs_diff_eq!(x.value(0), want[0], epsilon = 1E-12);
+
+        #[cfg(feature = "cct")]
+        assert!(batch.column_by_name("cct").unwrap().is_null(0));
+    }
+}
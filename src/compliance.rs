@@ -0,0 +1,139 @@
+/*!
+# Standards Compliance Modes
+
+The CIE standards implemented in this library leave little room for interpretation, but using them
+strictly is not always practical: a color rendering index is, according to CIE 13.3, not
+meaningful for a light source too far from the Planckian, or daylight, locus, but is still useful
+to compare LED engines during their development, and spectral data covering only part of the
+visible spectrum is often all there is available.
+
+A [`ComplianceMode`] sets how these cases are handled.
+The `Practical` mode, the default, calculates results whenever possible; the `Strict` mode, for
+use in certification, and test reports, follows the CIE standards, and returns errors otherwise.
+In the strict mode:
+
+- correlated color temperatures are only calculated for the CIE 1931 observer, as defined by the
+  CIE, also by [`CCT::try_from_xyz_with_observer`](crate::cct::CCT::try_from_xyz_with_observer),
+  and [`CCT::try_from_xyz_with_options`](crate::cct::CCT::try_from_xyz_with_options),
+- color rendering indices are only calculated for light sources with a chromaticity difference
+  DC, with their reference illuminant, less than 5.4E-3, as required by CIE 13.3,
+- general color rendering indices, calculated with the default [`RaOptions`](crate::cri::RaOptions),
+  are rounded to an integer, as reported according to CIE 13.3, and
+- spectral data parsed by [`SpdText`](crate::spd_text::SpdText) has to cover the domain from 380
+  to 780 nanometer, instead of being extrapolated with its end values.
+
+The mode is set for the whole process with [`set_compliance_mode`], typically once at start-up, and
+can be overridden for the calculations in a closure, on the current thread, with
+[`with_compliance_mode`].
+```
+use colorimetry::prelude::*;
+
+assert_eq!(compliance_mode(), ComplianceMode::Practical);
+let spd = "400 1.0\n500 1.0\n600 1.0\n700 1.0";
+assert!(SpdText::parse(spd).is_ok());
+let strict = with_compliance_mode(ComplianceMode::Strict, || SpdText::parse(spd));
+assert!(strict.is_err());
+```
+*/
+
+use std::{
+    cell::Cell,
+    sync::atomic::{AtomicU8, Ordering},
+};
+
+/// How strictly the CIE standards are applied, as described in the [module documentation](self).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, strum_macros::Display, strum_macros::EnumIter)]
+pub enum ComplianceMode {
+    /// Calculates results whenever possible, also outside the scope of the standards.
+    #[default]
+    Practical,
+    /// Follows the CIE standards, and returns errors for cases outside their scope.
+    Strict,
+}
+
+impl ComplianceMode {
+    pub fn is_strict(&self) -> bool {
+        *self == ComplianceMode::Strict
+    }
+}
+
+static MODE: AtomicU8 = AtomicU8::new(ComplianceMode::Practical as u8);
+
+thread_local! {
+    static OVERRIDE: Cell<Option<ComplianceMode>> = const { Cell::new(None) };
+}
+
+/// The compliance mode in effect on the current thread.
+pub fn compliance_mode() -> ComplianceMode {
+    OVERRIDE.with(Cell::get).unwrap_or(match MODE.load(Ordering::Relaxed) {
+        m if m == ComplianceMode::Strict as u8 => ComplianceMode::Strict,
+        _ => ComplianceMode::Practical,
+    })
+}
+
+/// Sets the compliance mode for all threads, except within [`with_compliance_mode`] closures.
+pub fn set_compliance_mode(mode: ComplianceMode) {
+    MODE.store(mode as u8, Ordering::Relaxed);
+}
+
+/**
+Runs a closure with a compliance mode, on the current thread only, restoring the previous mode
+afterwards, also if the closure panics.
+
+Calculations which are run on other threads by the closure, such as the batch calculations with
+the `rayon` feature, use the mode set by [`set_compliance_mode`].
+*/
+pub fn with_compliance_mode<T>(mode: ComplianceMode, f: impl FnOnce() -> T) -> T {
+    struct Restore(Option<ComplianceMode>);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            OVERRIDE.with(|o| o.set(self.0));
+        }
+    }
+    let _restore = Restore(OVERRIDE.with(|o| o.replace(Some(mode))));
+    f()
+}
+
+#[cfg(test)]
+mod compliance_test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_with_compliance_mode() {
+        assert_eq!(compliance_mode(), ComplianceMode::Practical);
+        let mode = with_compliance_mode(ComplianceMode::Strict, compliance_mode);
+        assert_eq!(mode, ComplianceMode::Strict);
+        assert_eq!(compliance_mode(), ComplianceMode::Practical);
+
+        // restored after a panic
+        let result = std::panic::catch_unwind(|| with_compliance_mode(ComplianceMode::Strict, || panic!()));
+        assert!(result.is_err());
+        assert_eq!(compliance_mode(), ComplianceMode::Practical);
+    }
+
+    #[test]
+    #[cfg(feature = "cri")]
+    fn test_strict_cri() {
+        // a greenish white, above the Planckian locus
+        let lamp = Illuminant(Spectrum(Illuminant::planckian(3000.0).set_irradiance(1.0).0 .0 + Illuminant::led(530.0, 30.0).set_irradiance(0.08).0 .0));
+        let d = CIE1931.xyz(&lamp, None).cct().unwrap().d();
+        assert!(d > 5.4E-3 && d < 0.05);
+        assert!(CRI::try_new(&lamp).is_ok());
+        let strict = with_compliance_mode(ComplianceMode::Strict, || CRI::try_new(&lamp));
+        assert!(matches!(strict, Err(CmtError::CriChromaticityDifference(dc)) if dc > 5.4E-3));
+
+        let cri = CRI::try_new(&Illuminant::planckian(4000.0)).unwrap();
+        assert_eq!(cri.ra_with(&RaOptions::default()).unwrap(), cri.ra());
+        let ra = with_compliance_mode(ComplianceMode::Strict, || cri.ra_with(&RaOptions::default()).unwrap());
+        assert_eq!(ra, cri.ra().round());
+    }
+
+    #[test]
+    #[cfg(all(feature = "cct", feature = "supplemental-observers"))]
+    fn test_strict_cct() {
+        let xyz = CIE2015.xyz_planckian_locus(4000.0);
+        assert!(CCT::try_from_xyz_with_observer(xyz).is_ok());
+        let strict = with_compliance_mode(ComplianceMode::Strict, || CCT::try_from_xyz_with_observer(xyz));
+        assert_eq!(strict, Err(CmtError::RequiresCIE1931XYZ));
+    }
+}
@@ -0,0 +1,161 @@
+/*!
+# CIE 170-2 Cone Fundamentals
+
+The CIE 2015 observers, published in CIE 170-2:2015, are derived from the cone fundamentals: the
+spectral sensitivities of the L, M, and S cones, as proposed by Stockman and Sharpe, for 2º and
+10º fields of view.
+Their color matching functions, x̄<sub>F</sub>, ȳ<sub>F</sub>, and z̄<sub>F</sub>, are linear
+transformations of the cone fundamentals, which are given here as matrices, and used to recover the
+cone fundamentals from the observer data in this library.
+
+Besides the x<sub>F</sub>, y<sub>F</sub> chromaticity coordinates, derived from the transformed
+color matching functions, CIE 170-2 also defines the MacLeod–Boynton chromaticity diagram,
+with the L and S cone excitations relative to a luminance, defined as the weighted sum of the L
+and M cone excitations, as coordinates.
+```
+use colorimetry::prelude::*;
+
+let cones = ConeFundamentals::try_new(Observer::Std2015).unwrap();
+let [l, s] = cones.macleod_boynton(&Illuminant::equal_energy());
+approx::assert_abs_diff_eq!(l, 0.708, epsilon = 5E-4);
+approx::assert_abs_diff_eq!(s, 0.0192, epsilon = 5E-4);
+```
+*/
+
+use nalgebra::{matrix, Matrix3, SMatrix, Vector3};
+
+use crate::{
+    error::CmtError,
+    observer::Observer,
+    spectrum::{Spectrum, NS},
+    xyz::XYZ,
+};
+
+/// Transformation from the CIE 2006 2º cone fundamentals, l̄, m̄, s̄, to the CIE 2015 2º color
+/// matching functions x̄<sub>F</sub>, ȳ<sub>F</sub>, z̄<sub>F</sub> (CIE 170-2:2015).
+pub const XYZF_FROM_LMS_2: Matrix3<f64> = matrix![
+    1.94735469, -1.41445123, 0.36476327;
+    0.68990272, 0.34832189, 0.0;
+    0.0, 0.0, 1.93485343;
+];
+
+/// Transformation from the CIE 2006 10º cone fundamentals, l̄<sub>10</sub>, m̄<sub>10</sub>,
+/// s̄<sub>10</sub>, to the CIE 2015 10º color matching functions (CIE 170-2:2015).
+pub const XYZF_FROM_LMS_10: Matrix3<f64> = matrix![
+    1.93986443, -1.34664359, 0.43044935;
+    0.69283932, 0.34967567, 0.0;
+    0.0, 0.0, 2.14687945;
+];
+
+/// Cone fundamentals for a CIE 2015 observer, and the chromaticity coordinates derived from them.
+#[derive(Clone, Debug)]
+pub struct ConeFundamentals {
+    observer: Observer,
+    lms: SMatrix<f64, 3, NS>,
+    s_scale: f64,
+}
+
+impl ConeFundamentals {
+    /// Cone fundamentals for the CIE 2015 2º, or 10º, observer, recovered from its color
+    /// matching functions; other observers are not based on cone fundamentals, and return an
+    /// error.
+    pub fn try_new(observer: Observer) -> Result<Self, CmtError> {
+        let matrix = xyz_from_lms_matrix(observer)?;
+        let data = observer.data();
+        if data.data.row(0) == data.data.row(1) {
+            return Err(CmtError::ErrorString(format!(
+                "ConeFundamentals: the {observer:?} data does not have independent x̄ and ȳ functions"
+            )));
+        }
+        let inverse = matrix.try_inverse().ok_or(CmtError::CouldNotInvertRGBMatrix)?;
+        let lms = inverse * data.data;
+        // CIE 170-2 scales s/V to a maximum of one over the spectral locus
+        let s_max = lms
+            .column_iter()
+            .filter_map(|c| {
+                let v = matrix[(1, 0)] * c[0] + matrix[(1, 1)] * c[1];
+                (v > 1E-9).then(|| c[2] / v)
+            })
+            .fold(0.0, f64::max);
+        Ok(Self { observer, lms, s_scale: 1.0 / s_max })
+    }
+
+    pub fn observer(&self) -> Observer {
+        self.observer
+    }
+
+    /// The l̄, m̄, and s̄ cone fundamentals, normalized to a peak value of one, as rows.
+    pub fn data(&self) -> &SMatrix<f64, 3, NS> {
+        &self.lms
+    }
+
+    /// The L, M, and S cone excitations for a spectral distribution, scaled by the maximum
+    /// luminous efficacy of the observer, consistent with its tristimulus values.
+    pub fn lms(&self, spectrum: &Spectrum) -> [f64; 3] {
+        let lms = self.lms * spectrum.0 * self.observer.data().lumconst;
+        lms.into()
+    }
+
+    /// The X<sub>F</sub>, Y<sub>F</sub>, Z<sub>F</sub> tristimulus values, for cone excitations.
+    pub fn xyz_from_lms(&self, lms: [f64; 3]) -> XYZ {
+        let matrix = xyz_from_lms_matrix(self.observer).unwrap_or_else(|_| Matrix3::identity());
+        XYZ::from_vecs(matrix * Vector3::from(lms), None, self.observer)
+    }
+
+    /// The x<sub>F</sub>, y<sub>F</sub> chromaticity coordinates for a spectral distribution.
+    pub fn chromaticity(&self, spectrum: &Spectrum) -> [f64; 2] {
+        self.xyz_from_lms(self.lms(spectrum)).xy().values()
+    }
+
+    /**
+    The l<sub>MB</sub>, s<sub>MB</sub> MacLeod–Boynton chromaticity coordinates for a spectral
+    distribution: the weighted L cone excitation, and the S cone excitation, relative to the
+    luminance, with the s<sub>MB</sub> coordinate scaled to a maximum of one for the spectral
+    locus.
+    */
+    pub fn macleod_boynton(&self, spectrum: &Spectrum) -> [f64; 2] {
+        let matrix = xyz_from_lms_matrix(self.observer).unwrap_or_else(|_| Matrix3::identity());
+        let [l, m, s] = self.lms(spectrum);
+        let (lw, mw) = (matrix[(1, 0)] * l, matrix[(1, 1)] * m);
+        let v = lw + mw;
+        [lw / v, self.s_scale * s / v]
+    }
+}
+
+/// The CIE 170-2 transformation from cone fundamentals to color matching functions for a CIE
+/// 2015 observer.
+pub fn xyz_from_lms_matrix(observer: Observer) -> Result<Matrix3<f64>, CmtError> {
+    match observer {
+        Observer::Std2015 => Ok(XYZF_FROM_LMS_2),
+        Observer::Std2015_10 => Ok(XYZF_FROM_LMS_10),
+        _ => Err(CmtError::ErrorString(format!("ConeFundamentals: {observer:?} is not based on cone fundamentals"))),
+    }
+}
+
+#[cfg(test)]
+mod cone_fundamentals_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_cone_fundamentals() {
+        let cones = ConeFundamentals::try_new(Observer::Std2015).unwrap();
+        // cone fundamentals are non-negative, with a peak value of one
+        for row in cones.data().row_iter() {
+            assert!(row.min() >= -1E-6);
+            assert_abs_diff_eq!(row.max(), 1.0, epsilon = 1E-4);
+        }
+
+        let d65 = Illuminant::d65();
+        let xyz = CIE2015.xyz_from_spectrum(&d65, None);
+        let xyz_f = cones.xyz_from_lms(cones.lms(&d65));
+        assert_abs_diff_eq!(xyz_f.xyzn.as_slice(), xyz.xyzn.as_slice(), epsilon = 1E-6);
+        assert_abs_diff_eq!(cones.chromaticity(&d65).as_ref(), xyz.xy().values().as_ref(), epsilon = 1E-9);
+
+        // the s coordinate of a short wavelength line is close to its maximum
+        let [l, s] = cones.macleod_boynton(&Colorant::gaussian(420.0, 1.0));
+        assert!(s > 0.99 && s <= 1.0 + 1E-9 && l < 0.7);
+
+        assert!(ConeFundamentals::try_new(Observer::Std1931).is_err());
+    }
+}
@@ -7,11 +7,11 @@
  */
 
 use std::{ops::Index, sync::{LazyLock, OnceLock}};
-use nalgebra::{ArrayStorage, SMatrix};
+use nalgebra::{ArrayStorage, SMatrix, SVector};
 use wasm_bindgen::prelude::*;
 
 
-use crate::{error::CmtError, colorant::Colorant, spectrum::Spectrum, rgbspace::RgbSpace, illuminant::Illuminant, data::observers::CIE1931, xyz::XYZ, traits::Light};
+use crate::{cct::CCT, compliance::compliance_mode, error::CmtError, colorant::Colorant, lab::CieLab, math, observer::ObserverData, rendition::{MetricResult, RenditionMetric}, spectrum::{Spectrum, NS}, rgbspace::RgbSpace, illuminant::{d_illuminant_coefficients, Illuminant, CIE_D_S_NS}, data::observers::CIE1931, observer::Observer, xyz::XYZ, traits::Light};
 
 /// Nummer of Test Color Sample Spectra
 const N_TCS: usize = 14;
@@ -20,14 +20,244 @@ const N_TCS: usize = 14;
 ///
 /// The data for these is obtained from the <https:://cie.co.at> site's dataset library, on Sep 4, 2024.
 /// The dataset uses a 360-830-5nm domain, included below in the 'TCS5' static matrix truncated to a domain from 380-780-5nm.
-/// Here the dataset is converted to an array of 14 Spectra, using linear interpolation.
+/// Here the dataset is converted to an array of 14 Spectra, using Sprague interpolation, as recommended
+/// by CIE 15:2018 for equally spaced data.
 pub static TCS: LazyLock<[Colorant;N_TCS]> = LazyLock::new(|| {
         let s_vec: Vec<Colorant> = TCS5.column_iter().map(|s|
-            Colorant(Spectrum::linear_interpolate(&[380.0, 780.0],s.as_slice()).unwrap())).collect();
+            Colorant(Spectrum::try_sprague_interpolate([380.0, 780.0],s.as_slice()).unwrap())).collect();
         s_vec.try_into().unwrap()
     }
 );
 
+/// Munsell designation, and ISCC-NBS color name, of the Test Color Samples, as listed in CIE 13.3.
+const TCS_NAMES: [(&str, &str); N_TCS] = [
+    ("7.5 R 6/4", "Light greyish red"),
+    ("5 Y 6/4", "Dark greyish yellow"),
+    ("5 GY 6/8", "Strong yellow green"),
+    ("2.5 G 6/6", "Moderate yellowish green"),
+    ("10 BG 6/4", "Light bluish green"),
+    ("5 PB 6/8", "Light blue"),
+    ("2.5 P 6/8", "Light violet"),
+    ("10 P 6/8", "Light reddish purple"),
+    ("4.5 R 4/13", "Strong red"),
+    ("5 Y 8/10", "Strong yellow"),
+    ("4.5 G 5/8", "Strong green"),
+    ("3 PB 3/11", "Strong blue"),
+    ("5 YR 8/4", "Light yellowish pink (human complexion)"),
+    ("5 GY 4/4", "Moderate olive green (leaf green)"),
+];
+
+/// A CIE 13.3 Test Color Sample, with its Munsell designation, and color name.
+#[derive(Clone, Copy, Debug)]
+pub struct TcsInfo {
+    /// Sample number, from 1 to 14, as used in the special color rendering index labels R1 to R14.
+    pub number: usize,
+    pub munsell: &'static str,
+    pub description: &'static str,
+    pub colorant: &'static Colorant,
+}
+
+impl TcsInfo {
+    /// CIELAB values of the sample, for the CIE 1931 observer, and illuminant D65.
+    pub fn lab_d65(&self) -> CieLab {
+        CIE1931.lab_d65(self.colorant)
+    }
+}
+
+/**
+Test Color Sample `i`, with `i` from 1 to 14, as used for the special color rendering index Ri.
+```
+use colorimetry::prelude::*;
+
+let r9 = tcs(9).unwrap();
+assert_eq!(r9.description, "Strong red");
+let [l, a, b] = *r9.lab_d65().as_ref();
+assert!(a > 50.0 && l < 50.0);
+```
+*/
+pub fn tcs(i: usize) -> Option<TcsInfo> {
+    let &(munsell, description) = TCS_NAMES.get(i.checked_sub(1)?)?;
+    Some(TcsInfo { number: i, munsell, description, colorant: &TCS[i - 1] })
+}
+
+/// Number of tristimulus values calculated for an illuminant: the values of the illuminant, followed
+/// by the values of the Test Color Samples.
+const N_TCS_XYZ: usize = 3 * (N_TCS + 1);
+
+/// Number of color matching functions in a block of [`TCS_CMF`], and the number of blocks.
+const TCS_CMF_BLOCK: usize = 15;
+const N_TCS_CMF_BLOCKS: usize = N_TCS_XYZ.div_ceil(TCS_CMF_BLOCK);
+
+/// CIE 1931 color matching functions, followed by the color matching functions multiplied by the
+/// Test Color Sample spectra, with the X, Y, and Z functions of the first sample first, followed by
+/// the second sample, and so on, padded with zeros.
+///
+/// The functions are stored in blocks of [`TCS_CMF_BLOCK`] functions, interleaved by wavelength, so
+/// that the tristimulus values of a block are accumulated in registers, reading the illuminant
+/// spectrum once for each block.
+static TCS_CMF: LazyLock<Vec<[[f64; TCS_CMF_BLOCK]; NS]>> = LazyLock::new(|| {
+    let mut blocks = vec![[[0.0; TCS_CMF_BLOCK]; NS]; N_TCS_CMF_BLOCKS];
+    for j in 0..N_TCS_XYZ {
+        let cmf = CIE1931.data.row(j % 3);
+        for (l, row) in blocks[j / TCS_CMF_BLOCK].iter_mut().enumerate() {
+            row[j % TCS_CMF_BLOCK] = if j < 3 { cmf[l] } else { cmf[l] * TCS[j / 3 - 1].0.0[l] };
+        }
+    }
+    blocks
+});
+
+/// CIE 1931 tristimulus values of an illuminant, and of the Test Color Samples illuminated by it, in
+/// a single vector, without normalization.
+///
+/// Uses the prescaled color matching functions in [`TCS_CMF`], without copying the illuminant,
+/// or the samples.
+fn tcs_values(illuminant: &SVector<f64, NS>) -> SVector<f64, N_TCS_XYZ> {
+    let mut values = SVector::<f64, N_TCS_XYZ>::zeros();
+    for (b, block) in TCS_CMF.iter().enumerate() {
+        let mut acc = [0.0; TCS_CMF_BLOCK];
+        for (row, &s) in block.iter().zip(illuminant.as_slice()) {
+            for k in 0..TCS_CMF_BLOCK {
+                acc[k] += row[k] * s;
+            }
+        }
+        for (value, a) in values.iter_mut().skip(b * TCS_CMF_BLOCK).zip(acc) {
+            *value = a;
+        }
+    }
+    values
+}
+
+/// Tristimulus values of an illuminant, normalized to an illuminance of 100, and of the Test Color
+/// Samples illuminated by it, from the values calculated by [`tcs_values`].
+fn tcs_xyz_from_values(values: &SVector<f64, N_TCS_XYZ>) -> (XYZ, [XYZ; N_TCS]) {
+    let values = values * (100.0 / values[1]);
+    let xyzn = values.fixed_rows::<3>(0).into_owned();
+    let samples = std::array::from_fn(|i| XYZ::from_vecs(xyzn, Some(values.fixed_rows::<3>(3 + 3 * i).into_owned()), Observer::Std1931));
+    (XYZ::from_vecs(xyzn, None, Observer::Std1931), samples)
+}
+
+/// CIE 1931 tristimulus values of an illuminant, with an illuminance of 100, and of the Test Color
+/// Samples illuminated by it.
+fn tcs_xyz(illuminant: &Spectrum) -> (XYZ, [XYZ; N_TCS]) {
+    tcs_xyz_from_values(&tcs_values(&illuminant.0))
+}
+
+/// Values calculated by [`tcs_values`] for the CIE daylight S-vectors. A CIE D illuminant is a
+/// linear combination of the S-vectors, and so are its values.
+static TCS_DAYLIGHT: LazyLock<[SVector<f64, N_TCS_XYZ>; 3]> = LazyLock::new(|| CIE_D_S_NS.each_ref().map(|s| tcs_values(&s.0)));
+
+/// Reciprocal temperatures, in mired, of the first and last Planckian reference illuminants in
+/// [`TCS_PLANCKIAN`], from 5000 to 1000 kelvin, and the number of illuminants in the table.
+const PLANCKIAN_MIRED: [f64; 2] = [200.0, 1000.0];
+const N_PLANCKIAN: usize = 801;
+
+/// Values calculated by [`tcs_values`] for Planckian illuminants, at 1 mired intervals, normalized
+/// to an illuminance of 100, for the interpolation of the reference illuminant values.
+static TCS_PLANCKIAN: LazyLock<Vec<SVector<f64, N_TCS_XYZ>>> = LazyLock::new(|| {
+    let [low, high] = PLANCKIAN_MIRED;
+    (0..N_PLANCKIAN).map(|i| {
+        let mired = low + (high - low) * i as f64 / (N_PLANCKIAN - 1) as f64;
+        let values = tcs_values(&Illuminant::planckian(1E6 / mired).0.0);
+        values * (100.0 / values[1])
+    }).collect()
+});
+
+/// Values calculated by [`tcs_values`] for a Planckian illuminant, using four point Lagrange
+/// interpolation in [`TCS_PLANCKIAN`], for temperatures from 1000 to 5000 kelvin.
+fn planckian_tcs_values(cct: f64) -> SVector<f64, N_TCS_XYZ> {
+    let [low, high] = PLANCKIAN_MIRED;
+    let x = (1E6 / cct - low) / (high - low) * (N_PLANCKIAN - 1) as f64;
+    let i = (x.floor() as usize).clamp(1, N_PLANCKIAN - 3);
+    let t = x - i as f64;
+    let w = [
+        -t * (t - 1.0) * (t - 2.0) / 6.0,
+        (t + 1.0) * (t - 1.0) * (t - 2.0) / 2.0,
+        -(t + 1.0) * t * (t - 2.0) / 2.0,
+        (t + 1.0) * t * (t - 1.0) / 6.0,
+    ];
+    let table = &*TCS_PLANCKIAN;
+    (0..4).map(|k| w[k] * table[i + k - 1]).sum()
+}
+
+/// Tristimulus values of the reference illuminant, as returned by [`reference_illuminant`], for a
+/// test source with a correlated color temperature `cct`, and of the Test Color Samples illuminated
+/// by it, as calculated by [`tcs_xyz`], but without calculating the spectrum of the reference
+/// illuminant.
+fn reference_tcs_xyz(cct: f64) -> Result<(XYZ, [XYZ; N_TCS]), CmtError> {
+    if cct <= 5000.0 {
+        Ok(tcs_xyz_from_values(&planckian_tcs_values(cct)))
+    } else {
+        let [m1, m2] = d_illuminant_coefficients(cct)?;
+        let [s0, s1, s2] = &*TCS_DAYLIGHT;
+        Ok(tcs_xyz_from_values(&(s0 + m1 * s1 + m2 * s2)))
+    }
+}
+
+#[test]
+fn tcs_xyz_test(){
+    use crate::prelude::*;
+    // matrix based calculation equals the straightforward calculation
+    let illuminant = Illuminant::try_d_illuminant(5500.0).unwrap().set_illuminance(&CIE1931, 100.0);
+    let (xyzn, samples) = tcs_xyz(&illuminant);
+    approx::assert_abs_diff_eq!(xyzn, CIE1931.xyz_from_spectrum(&illuminant, None), epsilon = 1E-9);
+    for (xyz, tcs) in samples.iter().zip(TCS.iter()) {
+        approx::assert_abs_diff_eq!(*xyz, CIE1931.xyz(&illuminant, Some(tcs)), epsilon = 1E-9);
+    }
+}
+
+#[test]
+fn reference_tcs_xyz_test(){
+    // interpolated, and linearly combined, values equal the values of the reference spectra
+    for cct in [1000.0, 1234.5, 2700.0, 4999.9, 5000.0, 5000.1, 6504.0, 25000.0] {
+        let illuminant = Illuminant::planckian(cct);
+        let (xyz_ref, samples_ref) = reference_tcs_xyz(cct).unwrap();
+        let reference = if cct <= 5000.0 { illuminant } else { Illuminant::try_d_illuminant(cct).unwrap() };
+        let (xyzn, samples) = tcs_xyz(&reference);
+        approx::assert_abs_diff_eq!(xyz_ref, xyzn, epsilon = 1E-9);
+        for (xyz, xyzr) in samples.iter().zip(samples_ref.iter()) {
+            approx::assert_abs_diff_eq!(*xyz, *xyzr, epsilon = 1E-9);
+        }
+    }
+    assert!(reference_tcs_xyz(30000.0).is_err());
+}
+
+#[test]
+fn tcs_info_test(){
+    use crate::prelude::*;
+    assert!(tcs(0).is_none() && tcs(15).is_none());
+    let r14 = tcs(14).unwrap();
+    assert_eq!((r14.number, r14.munsell), (14, "5 GY 4/4"));
+    // hue angles of the samples, in CIELAB, are consistent with their Munsell hues
+    let hue = |i: usize| { let [_, a, b] = *tcs(i).unwrap().lab_d65().as_ref(); math::atan2(b, a).to_degrees() };
+    assert!(hue(9).abs() < 45.0); // red
+    assert!((60.0..120.0).contains(&hue(10))); // yellow
+    assert!((-120.0..-60.0).contains(&hue(12))); // blue
+}
+
+#[test]
+fn special_index_test(){
+    use crate::prelude::*;
+    // the special index of a standard sample equals its value in the CRI
+    let f2 = Illuminant(*Illuminant::led(450.0, 20.0) + *Illuminant::led(560.0, 90.0) * 3.0);
+    let cri = CRI::try_new(&f2).unwrap();
+    approx::assert_abs_diff_eq!(special_index(&f2, &TCS[8]).unwrap(), cri[8], epsilon = 1E-9);
+}
+
+#[test]
+#[cfg(feature="supplemental-observers")]
+fn cri_observer_test(){
+    use crate::prelude::*;
+    // mirrors the CIE 1931 calculation for the CIE 1964 observer
+    let cri = CRI::try_new_with_observer(&Illuminant::planckian(3000.0), &CIE1964).unwrap();
+    approx::assert_abs_diff_eq!(cri.ra(), 100.0, epsilon = 0.05);
+    let led = Illuminant(*Illuminant::led(450.0, 20.0) + *Illuminant::led(560.0, 90.0) * 3.0);
+    let cri_2 = CRI::try_new_with_observer(&led, &CIE1931).unwrap();
+    let cri_10 = CRI::try_new_with_observer(&led, &CIE1964).unwrap();
+    approx::assert_abs_diff_eq!(cri_2.ra(), CRI::try_new(&led).unwrap().ra());
+    approx::assert_abs_diff_eq!(cri_10.ra(), cri_2.ra(), epsilon = 5.0);
+    assert!(CriMetric.evaluate(&led, &CIE1964).is_ok());
+}
+
 #[test]
 fn tcs_test(){
     for (i,s) in TCS.iter().enumerate() {
@@ -47,6 +277,7 @@ fn tcs_test(){
 
 #[wasm_bindgen]
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// Encapcsulated Array of calculated Ri values, from a test light source.
 pub struct CRI([f64;N_TCS]);
 
@@ -55,9 +286,146 @@ impl CRI {
         s.try_into()
     }
 
+    /**
+    Color rendering indices calculated with an alternative observer, such as the CIE 1964 10º
+    observer, as sometimes used for large field applications.
+
+    The reference illuminant is selected by the correlated color temperature of the test source,
+    which is defined for the CIE 1931 observer, and the sample colors are calculated with the
+    given observer. This is not part of CIE 13.3, and its results can differ from those obtained
+    with the CIE 1931 observer, in particular for narrow band sources, and a `RequiresCIE1931XYZ`
+    error is returned in the strict [`ComplianceMode`](crate::compliance::ComplianceMode).
+    */
+    pub fn try_new_with_observer(s: &Illuminant, observer: &ObserverData) -> Result<Self, CmtError> {
+        if observer.tag == Observer::Std1931 {
+            return Self::try_new(s);
+        }
+        if compliance_mode().is_strict() {
+            return Err(CmtError::RequiresCIE1931XYZ);
+        }
+        let illuminant_ref = reference_illuminant(CIE1931.xyz(s, None))?;
+        let xyz_dut = observer.xyz(s, None);
+        let xyz_ref = observer.xyz(&illuminant_ref, None);
+        let special_index = SpecialIndex::new(xyz_dut, xyz_ref);
+        let ri = std::array::from_fn(|i| {
+            let xyz = observer.xyz(s, Some(&TCS[i]));
+            let xyzr = observer.xyz(&illuminant_ref, Some(&TCS[i]));
+            special_index.index(xyz, xyzr)
+        });
+        Ok(CRI(ri))
+    }
+
     pub fn ra(&self) -> f64 {
         self.0.iter().take(8).sum::<f64>()/8.0
     }
+
+    /**
+    General index, as the mean of a selection of special indices, with optional rounding, to
+    reconcile results with software using other conventions than CIE 13.3.
+    ```
+    use colorimetry::prelude::*;
+
+    let cri = CRI::try_new(StdIlluminant::F3_11.illuminant()).unwrap();
+    assert_eq!(cri.ra_with(&RaOptions::default()).unwrap(), cri.ra());
+    let ra = cri.ra_with(&RaOptions::default().set_rounding(RaRounding::Result)).unwrap();
+    assert_eq!(ra, cri.ra().round());
+
+    // R1 to R15, with the Japanese complexion sample R15 obtained from its spectral data
+    let r15 = 85.0;
+    let options = RaOptions::default().set_samples(RaSamples::Extended).add_special(r15);
+    let ra15 = cri.ra_with(&options).unwrap();
+    approx::assert_abs_diff_eq!(ra15, (cri.as_ref().iter().sum::<f64>() + r15) / 15.0);
+    ```
+    */
+    pub fn ra_with(&self, options: &RaOptions) -> Result<f64, CmtError> {
+        let selected: Vec<f64> = match &options.samples {
+            RaSamples::General => self.0[..8].to_vec(),
+            RaSamples::Extended => self.0.to_vec(),
+            RaSamples::Custom(numbers) => numbers
+                .iter()
+                .map(|&i| {
+                    i.checked_sub(1)
+                        .and_then(|k| self.0.get(k).copied())
+                        .ok_or_else(|| CmtError::ErrorString(format!("Ra: no test color sample R{i}")))
+                })
+                .collect::<Result<_, _>>()?,
+        };
+        let values: Vec<f64> = selected.into_iter().chain(options.supplementary.iter().copied()).collect();
+        if values.is_empty() {
+            return Err(CmtError::ErrorString("Ra: requires at least one special index".into()));
+        }
+        let mean = match options.rounding {
+            RaRounding::Each => values.iter().map(|r| r.round()).sum::<f64>() / values.len() as f64,
+            _ => values.iter().sum::<f64>() / values.len() as f64,
+        };
+        Ok(match options.rounding {
+            RaRounding::None => mean,
+            _ => mean.round(),
+        })
+    }
+}
+
+/// Special indices included in a general color rendering index.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum RaSamples {
+    /// R1 to R8, as in CIE 13.3.
+    #[default]
+    General,
+    /// R1 to R14, to be completed with a supplementary R15 value, for the Japanese complexion
+    /// sample of JIS Z 8726, obtained with [`special_index`].
+    Extended,
+    /// Selection of sample numbers, from 1 to 14.
+    Custom(Vec<usize>),
+}
+
+/// Rounding of a general color rendering index.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RaRounding {
+    /// No rounding.
+    #[default]
+    None,
+    /// Rounds the mean to an integer, as reported according to CIE 13.3.
+    Result,
+    /// Rounds each special index to an integer before taking the mean, and rounds the mean, as
+    /// done in some JIS based software.
+    Each,
+}
+
+/// Options for [`CRI::ra_with`].
+///
+/// By default the general index is the unrounded mean of R1 to R8, or, in the strict
+/// [`ComplianceMode`](crate::compliance::ComplianceMode), this mean rounded to an integer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RaOptions {
+    samples: RaSamples,
+    rounding: RaRounding,
+    supplementary: Vec<f64>,
+}
+
+impl Default for RaOptions {
+    fn default() -> Self {
+        let rounding = if compliance_mode().is_strict() { RaRounding::Result } else { RaRounding::None };
+        Self { samples: RaSamples::default(), rounding, supplementary: Vec::new() }
+    }
+}
+
+impl RaOptions {
+    pub fn set_samples(mut self, samples: RaSamples) -> Self {
+        self.samples = samples;
+        self
+    }
+
+    pub fn set_rounding(mut self, rounding: RaRounding) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Adds a special index for a sample which is not one of the standard Test Color Samples,
+    /// such as R15, to the mean.
+    pub fn add_special(mut self, ri: f64) -> Self {
+        self.supplementary.push(ri);
+        self
+    }
 }
 
 impl Index<usize> for CRI {
@@ -76,69 +444,190 @@ impl TryFrom<&Illuminant> for CRI {
     type Error = CmtError;
 
     fn try_from(illuminant: &Illuminant) -> Result<Self, Self::Error> {
-        let illuminant = &illuminant.clone().set_illuminance(&CIE1931, 100.0);
+        debug_span!("cri");
         // Calculate Device Under Test (dut) XYZ illuminant and sample values
-        let xyz_dut = CIE1931.xyz_from_spectrum(illuminant, None);
-        let xyz_dut_samples: [XYZ; N_TCS] = 
-            TCS
-                .iter()
-               // .map(|colorant|CIE1931.xyz_of_sample_with_illuminant(illuminant, colorant))
-                .map(|colorant|CIE1931.xyz(illuminant, Some(colorant)))
-                .collect::<Vec<XYZ>>()
-                .try_into().unwrap();
-
-        // Determine reference color temperarture value
-        let cct_dut = xyz_dut.cct()?.t();
-        //println!("cct dut {cct_dut}");
-        let illuminant_ref = if cct_dut <= 5000.0 {
-            Illuminant::planckian(cct_dut).set_illuminance(&CIE1931, 100.0)
-        } else {
-            Illuminant::d_illuminant(cct_dut)?.set_illuminance(&CIE1931, 100.0)
-        };
+        let (xyz_dut, xyz_dut_samples) = tcs_xyz(illuminant);
+        debug_event!(chromaticity = ?xyz_dut.xy().values(), "test source");
 
         // Calculate the reference illuminant values
-        let xyz_ref = CIE1931.xyz_from_spectrum(&illuminant_ref, None);
-        let xyz_ref_samples: [XYZ; N_TCS] = 
-            TCS
-                .iter()
-              //  .map(|sample|CIE1931.xyz_of_sample_with_illuminant(&illuminant_ref, sample))
-                .map(|sample|CIE1931.xyz(&illuminant_ref, Some(sample)))
-                .collect::<Vec<XYZ>>()
-                .try_into().unwrap();
-        
-
-        let cdt = cd(xyz_dut.uv60());
-        let cdr = cd(xyz_ref.uv60());
-        
-        let ri : [f64; N_TCS] =
-            xyz_ref_samples
-                .iter()
-                .zip(xyz_dut_samples.iter())
-                .map(|(xyzr,xyz)|{
-                    let cdti = cd(xyz.uv60());
-                    let uv_vk = uv_kries(cdt, cdr, cdti);
-                    let xyz_vk = XYZ::try_from_luv60( uv_vk[0], uv_vk[1], Some(xyz.xyz.unwrap().y), None).unwrap();
-                    let uvw = xyz_vk.uvw64(xyz_ref);
-                    let uvwr = xyzr.uvw64(xyz_ref);
-                    100.0 - 4.6 * ((uvw[0] - uvwr[0]).powi(2) + (uvw[1] - uvwr[1]).powi(2) + (uvw[2] - uvwr[2]).powi(2)).sqrt()
-                }).collect::<Vec<f64>>().try_into().unwrap();
+        let (xyz_ref, xyz_ref_samples) = reference_tcs_xyz(reference_cct(xyz_dut)?)?;
+        debug_event!(chromaticity = ?xyz_ref.xy().values(), "reference illuminant");
+        check_chromaticity_difference(xyz_dut, xyz_ref)?;
+
+        let special_index = SpecialIndex::new(xyz_dut, xyz_ref);
+        let ri: [f64; N_TCS] = std::array::from_fn(|i| special_index.index(xyz_dut_samples[i], xyz_ref_samples[i]));
+        debug_event!(?ri, "special color rendering indices");
 
         Ok(CRI(ri))
     }
 }
 
+/**
+Special color rendering index of a light source, for a sample which is not one of the 14 standard
+Test Color Samples, using the CIE 13.3 method.
+
+Use this for supplementary samples, such as the Japanese complexion sample No. 15 of JIS Z 8726,
+required in some lighting specifications as R15. Its spectral data is not included in this
+library, and has to be obtained from the standard.
+```
+use colorimetry::prelude::*;
+
+let lamp = Illuminant::planckian(3000.0);
+let sample = Colorant::gaussian(600.0, 60.0);
+approx::assert_abs_diff_eq!(special_index(&lamp, &sample).unwrap(), 100.0, epsilon = 0.05);
+```
+*/
+pub fn special_index(illuminant: &Illuminant, sample: &Colorant) -> Result<f64, CmtError> {
+    let xyz_dut = CIE1931.xyz(illuminant, None);
+    let illuminant_ref = reference_illuminant(xyz_dut)?;
+    let xyz_ref = CIE1931.xyz(&illuminant_ref, None);
+    check_chromaticity_difference(xyz_dut, xyz_ref)?;
+    let xyz = CIE1931.xyz(illuminant, Some(sample));
+    let xyzr = CIE1931.xyz(&illuminant_ref, Some(sample));
+    Ok(SpecialIndex::new(xyz_dut, xyz_ref).index(xyz, xyzr))
+}
+
+/// Planckian reference illuminant for test sources with a correlated color temperature up to
+/// 5000 K, and a CIE D illuminant otherwise.
+pub(crate) fn reference_illuminant(xyz_dut: XYZ) -> Result<Illuminant, CmtError> {
+    let cct_dut = reference_cct(xyz_dut)?;
+    if cct_dut <= 5000.0 {
+        Ok(Illuminant::planckian(cct_dut))
+    } else {
+        Illuminant::try_d_illuminant(cct_dut)
+    }
+}
+
+/// Correlated color temperature of a test source, which selects its reference illuminant.
+///
+/// Uses a `CCT`, without the residual of a `CctResult`, which requires two additional spectral
+/// integrations.
+fn reference_cct(xyz_dut: XYZ) -> Result<f64, CmtError> {
+    let cct = CCT::try_from(xyz_dut)?;
+    debug_event!(cct = cct.t(), duv = cct.d(), planckian = cct.t() <= 5000.0, "reference illuminant");
+    Ok(cct.t())
+}
+
+/// Maximum chromaticity difference DC, in the CIE 1960 UCS diagram, between a test source and its
+/// reference illuminant, for which CIE 13.3 considers the color rendering index meaningful.
+const DC_MAX: f64 = 5.4E-3;
+
+/// Checks the chromaticity difference between a test source and its reference illuminant, in the
+/// strict [`ComplianceMode`](crate::compliance::ComplianceMode) only.
+fn check_chromaticity_difference(xyz_dut: XYZ, xyz_ref: XYZ) -> Result<(), CmtError> {
+    let ([ut, vt], [ur, vr]) = (xyz_dut.xy().uv60().values(), xyz_ref.xy().uv60().values());
+    let dc = math::hypot(ut - ur, vt - vr);
+    if compliance_mode().is_strict() && dc >= DC_MAX {
+        return Err(CmtError::CriChromaticityDifference(dc));
+    }
+    Ok(())
+}
+
+/// Chromatic adaptation of the special color rendering index calculation, for a test source and its
+/// reference illuminant, with tristimulus values normalized to an illuminance of 100, calculated
+/// once for all samples.
+struct SpecialIndex {
+    cdt: [f64; 2],
+    cdr: [f64; 2],
+    uv_ref: [f64; 2],
+}
+
+impl SpecialIndex {
+    fn new(xyz_dut: XYZ, xyz_ref: XYZ) -> Self {
+        let uv_ref = xyz_ref.xy().uv60().values();
+        Self { cdt: cd(xyz_dut.xy().uv60().values()), cdr: cd(uv_ref), uv_ref }
+    }
+
+    /// Special color rendering index, from the tristimulus values of a sample illuminated by the
+    /// test source, and by the reference illuminant.
+    fn index(&self, xyz: XYZ, xyzr: XYZ) -> f64 {
+        let uv_vk = uv_kries(self.cdt, self.cdr, cd(xyz.xy().uv60().values()));
+        let uvw = self.uvw64(uv_vk, xyz.luminous_value());
+        let uvwr = self.uvw64(xyzr.xy().uv60().values(), xyzr.luminous_value());
+        100.0 - 4.6 * ((uvw[0] - uvwr[0]).powi(2) + (uvw[1] - uvwr[1]).powi(2) + (uvw[2] - uvwr[2]).powi(2)).sqrt()
+    }
+
+    /// CIE 1964 U*V*W* values, relative to the reference illuminant, as calculated by
+    /// [`XYZ::uvw64`].
+    fn uvw64(&self, [u, v]: [f64; 2], yy: f64) -> [f64; 3] {
+        let [ur, vr] = self.uv_ref;
+        let ww = 25.0 * math::cbrt(yy) - 17.0;
+        [13.0 * ww * (u - ur), 13.0 * ww * (v - vr), ww]
+    }
+}
+
 impl AsRef<[f64]> for CRI {
     fn as_ref(&self) -> &[f64] {
        &self.0
     }
 }
 
+/**
+The CIE 13.3 Color Rendering Index, as a [`RenditionMetric`], with its general index Ra, and its
+special indices R1 to R14. For observers other than the CIE 1931 observer, the indices are
+calculated with [`CRI::try_new_with_observer`].
+```
+use colorimetry::prelude::*;
+
+let metrics: Vec<Box<dyn RenditionMetric>> = vec![Box::new(CriMetric)];
+let lamp = Illuminant::planckian(3000.0);
+for metric in &metrics {
+    let result = metric.evaluate(&lamp, &CIE1931).unwrap();
+    approx::assert_abs_diff_eq!(result.general, 100.0, epsilon = 0.05);
+    approx::assert_abs_diff_eq!(result.special("R9").unwrap(), 100.0, epsilon = 0.05);
+}
+```
+*/
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CriMetric;
+
+impl RenditionMetric for CriMetric {
+    fn name(&self) -> &str {
+        "CIE 13.3 Ra"
+    }
+
+    fn evaluate(&self, illuminant: &Illuminant, observer: &ObserverData) -> Result<MetricResult, CmtError> {
+        let cri = CRI::try_new_with_observer(illuminant, observer)?;
+        Ok(MetricResult {
+            name: self.name().to_string(),
+            general: cri.ra(),
+            special: cri.0.iter().enumerate().map(|(i, &r)| (format!("R{}", i + 1), r)).collect(),
+        })
+    }
+}
+
 // JS-WASM Interface code
 #[cfg(target_arch="wasm32")] 
 #[wasm_bindgen]
 impl CRI {
 
+    /// Calculates the color rendering indices of an illuminant.
+    #[wasm_bindgen(constructor)]
+    pub fn new_js(illuminant: &Illuminant) -> Result<CRI, CmtError> {
+        Self::try_new(illuminant)
+    }
+
+    /// The general color rendering index Ra, as the mean of the special indices R1 to R8.
+    #[wasm_bindgen(js_name=ra)]
+    pub fn ra_js(&self) -> f64 {
+        self.ra()
+    }
+
+    /// The special color rendering index with number `index`, from 1 to 14, such as 9 for R9.
+    #[wasm_bindgen(js_name=ri)]
+    pub fn ri_js(&self, index: usize) -> Result<f64, CmtError> {
+        if (1..=N_TCS).contains(&index) {
+            Ok(self.0[index - 1])
+        } else {
+            Err(CmtError::ErrorString(format!("CRI: special index number {index} out of range 1 to {N_TCS}")))
+        }
+    }
 
+    /// Returns the special color rendering indices R1 to R14, as a Float64Array.
+    #[wasm_bindgen(js_name=values)]
+    pub fn values_js(&self) -> Box<[f64]> {
+        self.0.into()
+    }
 }
 
 #[cfg(test)]
@@ -184,6 +673,19 @@ mod cri_test {
             epsilon = 1.0
         );
     }
+
+    #[test]
+    #[cfg(feature = "cie-illuminants")]
+    fn cri_ra_options(){
+        let cri0: CRI = StdIlluminant::F3_1.illuminant().try_into().unwrap();
+        let rounded = cri0.as_ref().iter().take(8).map(|r| r.round()).sum::<f64>() / 8.0;
+        let each = cri0.ra_with(&RaOptions::default().set_rounding(RaRounding::Each)).unwrap();
+        assert_eq!(each, rounded.round());
+        let custom = cri0.ra_with(&RaOptions::default().set_samples(RaSamples::Custom(vec![9, 12]))).unwrap();
+        approx::assert_abs_diff_eq!(custom, (cri0[8] + cri0[11]) / 2.0);
+        assert!(cri0.ra_with(&RaOptions::default().set_samples(RaSamples::Custom(vec![15]))).is_err());
+        assert!(cri0.ra_with(&RaOptions::default().set_samples(RaSamples::Custom(vec![]))).is_err());
+    }
 }
 
 fn cd(uv60: [f64;2]) -> [f64;2] {
@@ -0,0 +1,162 @@
+/*!
+# Damage Potential of Light
+
+Light fades, and degrades, objects in museums, and archives: pigments, dyes, paper, and textiles,
+are damaged by radiation, increasingly so towards shorter wavelengths. CIE 157:2004 describes the
+relative spectral responsivity for damage of a material by an exponential damage function
+
+s<sub>dm,rel</sub>(λ) = exp(-b (λ - 300 nm)),
+
+normalized at 300 nanometer, with a material dependent coefficient b, in 1/nm.
+
+The relative damage potential of a light source is its damage weighted irradiance, per unit of
+illuminance, in W/lm; multiplied by the illuminance, and the exposure time, it gives the damage
+weighted radiant exposure, or dose, of an object. As the wavelength domain of this library starts
+at 380 nanometer, radiation below 380 nanometer, which is the most damaging, is not included;
+museum lighting is usually filtered to remove it, but check measured data with
+[`Spectrum::truncated_power_fraction`](crate::spectrum::Spectrum::truncated_power_fraction).
+```
+use colorimetry::prelude::*;
+
+let damage = DamageFunction::new(DamageMaterial::Watercolor);
+let led = Illuminant::planckian(3000.0);
+let daylight = Illuminant::d65();
+assert!(damage.relative_damage_potential(&daylight) > damage.relative_damage_potential(&led));
+
+// 50 lux, 8 hours a day, 300 days a year
+let exposure = damage.annual_exposure(&led, 50.0, 8.0 * 300.0);
+assert_eq!(exposure.lux_hours, 120_000.0);
+```
+
+# References
+- CIE 157:2004 Control of damage to museum objects by optical radiation
+- CIE 089:1991 On the deterioration of exhibited museum objects by optical radiation
+*/
+
+use nalgebra::SVector;
+
+use crate::{
+    action_spectrum::ActionSpectrum,
+    math,
+    spectrum::{Spectrum, NS},
+    traits::Light,
+};
+
+/// Materials with damage function coefficients listed in CIE 157.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, strum_macros::Display, strum_macros::EnumIter)]
+pub enum DamageMaterial {
+    /// The general damage function of CIE 89, for museum objects in general.
+    General,
+    Newsprint,
+    RagPaper,
+    /// Watercolours on rag paper.
+    Watercolor,
+    /// Oil paint on canvas.
+    OilPaint,
+}
+
+impl DamageMaterial {
+    /// Coefficient b of the damage function, in 1/nm.
+    pub fn b(&self) -> f64 {
+        match self {
+            DamageMaterial::General => 0.012,
+            DamageMaterial::Newsprint => 0.038,
+            DamageMaterial::RagPaper => 0.0125,
+            DamageMaterial::Watercolor => 0.0115,
+            DamageMaterial::OilPaint => 0.0100,
+        }
+    }
+}
+
+/// The CIE 157 relative spectral responsivity for damage, exp(-b (λ - 300 nm)).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DamageFunction {
+    b: f64,
+}
+
+/// Annual exposure of an object, as calculated by [`DamageFunction::annual_exposure`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DamageExposure {
+    /// Illuminance times exposure time, in lx·h.
+    pub lux_hours: f64,
+    /// Damage weighted irradiance, in W/m².
+    pub damage_irradiance: f64,
+    /// Damage weighted radiant exposure, in Wh/m².
+    pub dose: f64,
+}
+
+impl DamageFunction {
+    pub fn new(material: DamageMaterial) -> Self {
+        Self { b: material.b() }
+    }
+
+    /// Damage function with a coefficient b, in 1/nm, for materials with measured responsivity.
+    pub fn with_coefficient(b: f64) -> Self {
+        Self { b }
+    }
+
+    pub fn b(&self) -> f64 {
+        self.b
+    }
+
+    /// Relative spectral responsivity for damage, at a wavelength in nanometer.
+    pub fn value(&self, wavelength: f64) -> f64 {
+        math::exp(-self.b * (wavelength - 300.0))
+    }
+
+    /// The damage function as an action spectrum, for damage weighted irradiance in W/m².
+    pub fn action_spectrum(&self) -> ActionSpectrum {
+        let weights = Spectrum(SVector::<f64, NS>::from_fn(|i, _| self.value((i + 380) as f64)));
+        ActionSpectrum::new("damage", "W/m²", weights)
+    }
+
+    /// Relative damage potential of a light source, as its damage weighted irradiance per unit of
+    /// illuminance, in W/lm.
+    pub fn relative_damage_potential(&self, light: &dyn Light) -> f64 {
+        self.action_spectrum().weight(light) / ActionSpectrum::photopic().weight(light)
+    }
+
+    /// Annual exposure of an object, for a light source with an illuminance in lux, and the number
+    /// of hours per year the object is lit.
+    pub fn annual_exposure(&self, light: &dyn Light, illuminance: f64, hours_per_year: f64) -> DamageExposure {
+        let damage_irradiance = self.relative_damage_potential(light) * illuminance;
+        DamageExposure {
+            lux_hours: illuminance * hours_per_year,
+            damage_irradiance,
+            dose: damage_irradiance * hours_per_year,
+        }
+    }
+}
+
+#[cfg(test)]
+mod damage_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn test_damage() {
+        let damage = DamageFunction::new(DamageMaterial::General);
+        assert_abs_diff_eq!(damage.value(300.0), 1.0);
+        assert_abs_diff_eq!(damage.action_spectrum().weights()[400], (-1.2f64).exp(), epsilon = 1E-12);
+
+        // independent of the level of the light
+        let a = Illuminant::planckian(2856.0);
+        let rdp = damage.relative_damage_potential(&a);
+        assert_abs_diff_eq!(damage.relative_damage_potential(&(a.clone() * 7.0)), rdp, epsilon = 1E-15);
+
+        // more damage from daylight, and a blue LED, than from incandescent light, for all materials
+        for material in DamageMaterial::iter() {
+            let damage = DamageFunction::new(material);
+            let rdp_a = damage.relative_damage_potential(&a);
+            assert!(damage.relative_damage_potential(&Illuminant::d65()) > rdp_a);
+            assert!(damage.relative_damage_potential(&Illuminant::led(450.0, 20.0)) > rdp_a);
+        }
+
+        let exposure = damage.annual_exposure(&a, 200.0, 3000.0);
+        assert_abs_diff_eq!(exposure.lux_hours, 600_000.0);
+        assert_abs_diff_eq!(exposure.damage_irradiance, rdp * 200.0, epsilon = 1E-12);
+        assert_abs_diff_eq!(exposure.dose, rdp * 600_000.0, epsilon = 1E-9);
+        assert_eq!(DamageFunction::with_coefficient(0.038), DamageFunction::new(DamageMaterial::Newsprint));
+    }
+}
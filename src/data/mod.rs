@@ -2,4 +2,5 @@
 pub mod munsell_matt;
 
 pub mod illuminants;
-pub mod observers;
\ No newline at end of file
+pub mod observers;
+pub mod sigmoid_srgb;
\ No newline at end of file
@@ -259,6 +259,8 @@ pub static CIE2015: ObserverData = ObserverData{tag: Observer::Std2015, lumconst
     
 ]))};
 
+// FIXME: the x̄ values in this table are a copy of its ȳ values, and need to be replaced by the
+// CIE 170-2:2015 10º x̄F,10 data; chromaticities for this observer are not valid until then.
 #[cfg(feature="supplemental-observers")]
 pub static CIE2015_10: ObserverData = ObserverData{tag: Observer::Std2015_10, lumconst: 683.0, data: SMatrix::<f64, 3, NS>::from_array_storage( ArrayStorage([
     [0.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0],
@@ -0,0 +1,4103 @@
+pub(crate) const SIGMOID_SRGB_RESOLUTION: usize = 16;
+
+/// Sigmoid-polynomial coefficients for the sRGB color space, and the CIE 1931 observer, on a grid of
+/// 16 linear RGB values from 0.0 to 1.0 along each axis, with the blue index varying fastest, as
+/// computed by `SigmoidTable::new(RgbSpace::SRGB, Observer::Std1931, 16)`.
+pub(crate) static SIGMOID_SRGB: [[f64; 3]; SIGMOID_SRGB_RESOLUTION * SIGMOID_SRGB_RESOLUTION * SIGMOID_SRGB_RESOLUTION] = [
+    [14572.063448956702, -25609.95192669726, -148.16580063543768],
+    [-153.6259083689267, 51.11833181386656, -5.7955945274849405],
+    [-109.41333468491588, 35.947895832586525, -3.8886240240262824],
+    [-89.86845605116235, 29.088957514668028, -2.9821144143504417],
+    [-78.15833451283582, 24.849870917598036, -2.391779804475758],
+    [-70.0338380068979, 21.782906211101555, -1.9414737523760253],
+    [-63.82503577254841, 19.307382282614437, -1.5591992059064665],
+    [-58.68621125520388, 17.11386224790984, -1.2052679667129351],
+    [-54.08123160970008, 14.984377485357237, -0.8501686213192274],
+    [-49.56460621380802, 12.706665631300814, -0.4632946692570989],
+    [-44.62914558461014, 9.99823031973227, -0.0017893383420183517],
+    [-38.53718382861499, 6.401487566372868, 0.6064684904498797],
+    [-30.11988764814049, 1.1339408438005747, 1.4900608076265904],
+    [-17.42741327985093, -7.209298669509338, 2.8920368967477406],
+    [3.2096000373276508, -21.505453534482374, 5.341252513548517],
+    [39.78076550063322, -48.90927133338709, 10.270159401272528],
+    [-125.93791047765698, 102.47680561274011, -22.307388776210782],
+    [-22.6660738198195, 13.177716360336875, -3.3976015526248537],
+    [-13.706624123614896, 5.022017976351933, -1.560419395087635],
+    [-10.829235178587497, 2.0924051321868884, -0.8105179894879635],
+    [-9.585867098848837, 0.6064819806198792, -0.37194380086874557],
+    [-8.867351712893097, -0.37595996132613624, -0.05235150623686977],
+    [-8.256846881793443, -1.2090141999230055, 0.22132251021727045],
+    [-7.543976218715963, -2.071449373943254, 0.4862860406816149],
+    [-6.582065611129386, -3.0865151381295175, 0.7680031161303082],
+    [-5.228867761008816, -4.371481799291232, 1.0901180862212052],
+    [-3.30786496270179, -6.070196646514411, 1.4809839912245744],
+    [-0.5646511325394007, -8.390580881766281, 1.9812531988956525],
+    [3.405991353248533, -11.669317041624799, 2.6572124280268974],
+    [9.312631518865023, -16.512663246992368, 3.630557287715491],
+    [18.5365092614914, -24.161724368914243, 5.158940761101587],
+    [33.94779834174465, -37.53921762315109, 7.8969472246297245],
+    [-93.15124670859747, 75.74025141173746, -16.265847498717424],
+    [-28.058834566227695, 19.501846253828027, -4.369201445970517],
+    [-17.304920035644496, 10.065372686930493, -2.3450208813368008],
+    [-12.712735929730192, 5.907881896767051, -1.4174306666460557],
+    [-10.154235466940419, 3.49411953542166, -0.8515954463233991],
+    [-8.463761359926483, 1.8312886519025053, -0.4432390115216021],
+    [-7.157702004661113, 0.5099870517579984, -0.10883722779666752],
+    [-5.978837262218727, -0.6859761137103241, 0.19535949529363186],
+    [-4.755821821486694, -1.8995944266875078, 0.4980700133592968],
+    [-3.3437394879727473, -3.2513654502673006, 0.8233734015529686],
+    [-1.5870765446142812, -4.871020445118691, 1.1972000385164268],
+    [0.7176836880465135, -6.930230054274197, 1.6541017052709757],
+    [3.8800430695112658, -9.694895614755314, 2.248317357975356],
+    [8.434606437341335, -13.636519420198205, 3.0779537376425203],
+    [15.43431883792509, -19.722830938725014, 4.350556766582847],
+    [27.329536784709987, -30.389985619523646, 6.616534372312728],
+    [-79.78610007463196, 64.82017454081698, -13.732923616555452],
+    [-31.292099013609935, 22.921121431152788, -4.870820359095988],
+    [-20.43720983322788, 13.455807204822053, -2.856679265941982],
+    [-15.326998693557154, 8.919326241225075, -1.8703674398855192],
+    [-12.281131807312992, 6.150272767909421, -1.2505958161606001],
+    [-10.191178764006947, 4.200096203270127, -0.8005563605482461],
+    [-8.581247193453246, 2.6633136229590133, -0.4367521416173312],
+    [-7.193269200015365, 1.3212219917509243, -0.11450249508187776],
+    [-5.854589050890867, 0.028300996472073837, 0.19569866996260382],
+    [-4.422091094710248, -1.3358074907794166, 0.5181583424714825],
+    [-2.748891912859042, -2.895254035666177, 0.8780304212466825],
+    [-0.650954319055651, -4.807539114322321, 1.307554938657349],
+    [2.145353964405842, -7.311115924038072, 1.8562893585176183],
+    [6.112786164608247, -10.827770352803725, 2.6136051874685933],
+    [12.20600192686962, -16.238417723234246, 3.771154813165515],
+    [22.801329364958924, -25.86859352361007, 5.856688867086244],
+    [-72.72275718164191, 59.029762471117735, -12.336709328194436],
+    [-33.681281634267776, 25.294036833457373, -5.203342075193176],
+    [-23.066735634175895, 16.056074263016498, -3.242053302674657],
+    [-17.759431290395227, 11.375745314084908, -2.2332662484718715],
+    [-14.475743072955774, 8.428518972908389, -1.5844477068078897],
+    [-12.173483085824627, 6.320317111454703, -1.1092432639181562],
+    [-10.39419926131996, 4.658998462602986, -0.7264041557278297],
+    [-8.887145332577001, 3.2308243879354706, -0.39191318878650594],
+    [-7.484756411202602, 1.893456341237607, -0.07662699948768045],
+    [-6.048473639739101, 0.52932752462143, 0.2434518297435099],
+    [-4.4366960510006495, -0.9822882462617795, 0.5930483758485103],
+    [-2.474156596537384, -2.7926191042949315, 1.0034682521403162],
+    [0.097155363999348, -5.128192310001633, 1.5223462031396269],
+    [3.7248781889736975, -8.390601777295316, 2.235740150428396],
+    [9.335976352425623, -13.435630627517664, 3.3314598721388133],
+    [19.36916746027396, -22.62653904132286, 5.347795608883603],
+    [-68.72200947262583, 55.72928555263765, -11.488293978673662],
+    [-35.75789359348221, 27.243047871767775, -5.468349264653348],
+    [-25.452013186138398, 18.279924470886655, -3.5671279660378894],
+    [-20.063854537109357, 13.541548032776614, -2.5495720584728137],
+    [-16.642682632437438, 10.488195330867331, -1.8822989860017432],
+    [-14.207896980661323, 8.277505044748477, -1.389323236701206],
+    [-12.318737647987435, 6.531611829634566, -0.9920706284527646],
+    [-10.732425998957268, 5.042806428324772, -0.6475404096990077],
+    [-9.287514489984291, 3.673194521257858, -0.32729455797685214],
+    [-7.851466172664118, 2.309551077556244, -0.008019580883363023],
+    [-6.28829094909958, 0.8351532074600277, 0.3344031011467728],
+    [-4.428023015640654, -0.897478331422515, 0.7307590029431282],
+    [-2.0192497369567795, -3.1100737576059645, 1.2280536433173794],
+    [1.3778025585446532, -6.1983894247872415, 1.911757418431517],
+    [6.696441301912452, -11.02398617065041, 2.972141751411193],
+    [16.532573085472354, -20.084968860750145, 4.979882401770502],
+    [-66.58260602209222, 53.938652544290406, -10.966767303625776],
+    [-37.78811374723891, 29.055978396232117, -5.712968884952179],
+    [-27.773941144508605, 20.348706285295, -3.8670806593516223],
+    [-22.348391184344933, 15.58337062209265, -2.8455416023115894],
+    [-18.83459750961025, 12.45564745589298, -2.1644157529924906],
+    [-16.30570761889565, 10.168825178024676, -1.6571033113861657],
+    [-14.336744858859136, 8.358083925070648, -1.2475971662690393],
+    [-12.691914863826364, 6.821213906562215, -0.8938985239630824],
+    [-11.215158729690751, 5.42441585725977, -0.5682746675652361],
+    [-9.779902259441862, 4.0589919242576205, -0.24819151160170724],
+    [-8.256615305353687, 2.6131345082991575, 0.08962888586727459],
+    [-6.480643811178108, 0.9433089252368376, 0.4753575076440856],
+    [-4.203360753188623, -1.1704503076636306, 0.9559109571498545],
+    [-0.9829589331425238, -4.1264729659435835, 1.6178067574159452],
+    [4.142391377742763, -8.812496910871252, 2.658059924661984],
+    [14.009531625218848, -17.94446358068354, 4.698649756596297],
+    [-65.78367570755007, 53.2314631034986, -10.673550459499703],
+    [-39.9447538980643, 30.90573250080003, -5.965420233505716],
+    [-30.17071672013788, 22.4079660440489, -4.16503775956265],
+    [-24.7175159204509, 17.620691157143316, -3.13976975450592],
+    [-21.12970977113651, 14.430650762831803, -2.446209879822793],
+    [-18.52531106062921, 12.07955958070367, -1.9258177484379884],
+    [-16.492414937514624, 10.213566374513622, -1.5048204505734404],
+    [-14.80078565446003, 8.634928478475178, -1.1420718183832015],
+    [-13.298603469418179, 7.213128941713109, -0.810427031820783],
+    [-11.864414628461468, 5.843440780812988, -0.48806117489039014],
+    [-10.375344194080482, 4.419277255542591, -0.15263851030167883],
+    [-8.673464199664195, 2.8023623085310914, 0.2250908547974497],
+    [-6.51279140258493, 0.774475585945409, 0.6919102645032689],
+    [-3.4436803102888143, -2.0702729537170974, 1.3363610069949607],
+    [1.5418360561233777, -6.6620004576909775, 2.3656669977314264],
+    [11.608610289975976, -16.019479615974117, 4.472774051958614],
+    [-66.10970738557761, 53.42923120530572, -10.563977175704077],
+    [-42.375159388553826, 32.92766781883107, -6.247669501345371],
+    [-32.7702945685572, 24.577453585080654, -4.479980956099232],
+    [-27.27921924666438, 19.757331388286183, -3.448296546837701],
+    [-23.62068073254611, 16.50503736808426, -2.7416052968386264],
+    [-20.947988413689647, 14.092786222150455, -2.2079069243303895],
+    [-18.859040890668542, 12.175063740898532, -1.7751892397201372],
+    [-17.127484055540364, 10.55727888415826, -1.402932392531125],
+    [-15.604485739778498, 9.111230523765418, -1.0644143292283732],
+    [-14.172624111093695, 7.73533283249625, -0.7383783804602865],
+    [-12.715192116998377, 6.327899572936242, -0.40339366729805526],
+    [-11.082347225266064, 4.757149675936805, -0.031413451098734654],
+    [-9.033502614495498, 2.8090246241764887, 0.42367950868228926],
+    [-6.109460805086422, 0.06860326000532443, 1.0527138081284186],
+    [-1.240554403689444, -4.450846587603792, 2.0761957635065027],
+    [9.168340884566838, -14.169782839539765, 4.281111418632711],
+    [-67.52866963412207, 54.501576111312644, -10.62426982679234],
+    [-45.240508350273046, 35.25922159526071, -6.581944429209247],
+    [-35.71644236725899, 26.980275348856622, -4.831351685995874],
+    [-30.161575446228216, 22.10343908262971, -3.7880386424119825],
+    [-26.424313085184306, 18.77967055325887, -3.0657219762019237],
+    [-23.682698577021736, 16.302871773254363, -2.5172248314204326],
+    [-21.5405006602373, 14.33263057659501, -2.071687053844202],
+    [-19.773250687750387, 12.675881779349005, -1.6889148891586654],
+    [-18.23375488581047, 11.205645480555228, -1.3424064253155301],
+    [-16.807473694177403, 9.822546013411667, -1.011299143102963],
+    [-15.383002107286924, 8.429106223036099, -0.6749406915917163],
+    [-13.819184781424067, 6.900596102313039, -0.30661911031083033],
+    [-11.88513903288136, 5.030852280096157, 0.1383195433144045],
+    [-9.116244872494336, 2.3995279106084557, 0.7521967961434581],
+    [-4.36817581122789, -2.0484971780864107, 1.7713442304001146],
+    [6.516053389453452, -12.261168788804358, 4.106163830808652],
+    [-70.16768260838298, 56.545435664054466, -10.865392741956653],
+    [-48.75402868512383, 38.07352020636519, -6.9962518310285144],
+    [-39.19881979612111, 29.76989382780541, -5.2433154948049046],
+    [-33.53752681836398, 24.798285962025385, -4.180296738062896],
+    [-29.702827853543806, 21.38450846162719, -3.437909072625495],
+    [-26.885073163543144, 18.833768745792053, -2.8717713427535103],
+    [-24.68893653544139, 16.80663358983298, -2.411384760345193],
+    [-22.889322262049678, 15.10947407724285, -2.016481337011311],
+    [-21.339110602005693, 13.615217765360827, -1.6605228449414382],
+    [-19.925440833703494, 12.225673964204763, -1.3228347368579196],
+    [-18.54119884546479, 10.846700928217608, -0.9833630636316036],
+    [-17.053758277470557, 9.360466043557, -0.6166975562573263],
+    [-15.245968200073321, 7.572150147600454, -0.18036971670054663],
+    [-12.659269483338125, 5.065984801218523, 0.4172994092543765],
+    [-8.070427065576903, 0.7145856169835592, 1.429283626689482],
+    [3.417460823094483, -10.126080694815176, 3.9286380114920343],
+    [-74.35751391662949, 59.819376263093304, -11.32765266439379],
+    [-53.23944580904845, 41.626615279661294, -7.5321103176972235],
+    [-43.50163961711126, 33.169273983785885, -5.750940144896128],
+    [-37.66830469671309, 28.045037214980358, -4.6560771274363875],
+    [-33.704446294567035, 24.510926895784216, -3.8865612602454678],
+    [-30.79665936332223, 21.870064562452885, -3.2982052480176423],
+    [-28.543767912354255, 19.77836325256133, -2.819783835561665],
+    [-26.716647765120424, 18.038762643813904, -2.4103929122229184],
+    [-25.166281873583557, 16.52239095296839, -2.0431094592145405],
+    [-23.780156896255864, 15.131039081234857, -1.6971848950055153],
+    [-22.454416947554233, 13.77291459819643, -1.3529328302096995],
+    [-21.06439764321192, 12.336458967397691, -0.9860780866547457],
+    [-19.409210466892983, 10.640219798226191, -0.5567189850814054],
+    [-17.053090124723504, 8.287491691899595, 0.023134165727732844],
+    [-12.714864119518623, 4.097291130632325, 1.0190207175240384],
+    [-0.5154748129409849, -7.498310774349985, 3.7194601075807863],
+    [-80.78289351582396, 64.8617387036964, -12.10024445474329],
+    [-59.24844080846937, 46.34953221915926, -8.259608897646956],
+    [-49.10191891736951, 37.54701170467358, -6.412208677293679],
+    [-42.992833400854, 32.179306411832215, -5.266572158371728],
+    [-38.8489299133652, 28.476652766996143, -4.458759804057451],
+    [-35.829592827351156, 25.720607160805525, -3.841029076950566],
+    [-33.51767194312749, 23.55396999979139, -3.339791227240855],
+    [-31.675015039648407, 21.771981678175706, -2.912653467017975],
+    [-30.148047370412495, 20.24157876793245, -2.5317928248545387],
+    [-28.823087144767147, 18.863023281064994, -2.176017447336918],
+    [-27.598658955772482, 17.54580502061221, -1.8256957900469113],
+    [-26.35764474842817, 16.183854958269485, -1.4573975563347792],
+    [-24.91675891376541, 14.609689357680974, -1.0337500192743063],
+    [-22.878642703446125, 12.458682904415337, -0.47303552883006067],
+    [-18.97346162890726, 8.557683820237546, 0.48959460741409566],
+    [-6.046984346255061, -3.8599597770166967, 3.4218079786014863],
+    [-90.90393851352177, 72.82288351350105, -13.377796370312524],
+    [-67.84650043779169, 53.07038693516493, -9.313770821929051],
+    [-56.905724881574045, 43.59848219605785, -7.336622333299562],
+    [-50.343188274195576, 37.832425884005225, -6.108128280395179],
+    [-45.93553022250682, 33.881609942870476, -5.243403864140263],
+    [-42.77498364780256, 30.973002244183405, -4.584863685481606],
+    [-40.411288620024884, 28.72194012769228, -4.053771975132712],
+    [-38.589275300516334, 26.909010352706712, -3.6048167242221347],
+    [-37.147133665952495, 25.393405582107068, -3.208436730716916],
+    [-35.96877714480081, 24.072119493019205, -2.842435085628835],
+    [-34.95535380476287, 22.855153781133506, -2.4867738855279726],
+    [-33.99872162071752, 21.641786593908545, -2.1184114084968146],
+    [-32.93614111531793, 20.279138547507984, -1.7020466045854097],
+    [-31.420350823342083, 18.44225638152495, -1.1630474927583536],
+    [-28.304343993201584, 15.062342330488562, -0.25734975525764237],
+    [-15.019598774933101, 2.038647091612849, 2.894943793505359],
+    [-108.3409599460031, 86.55796561258623, -15.647659916314161],
+    [-81.47383730741794, 63.68210414140851, -11.004010475003676],
+    [-68.94441444069874, 52.87837188975564, -8.769373404781064],
+    [-61.57766460632183, 46.409783784118, -7.39545577734195],
+    [-56.758211551151255, 42.06752757820377, -6.439464976290928],
+    [-53.42768756311638, 38.95526343688282, -5.721019146498044],
+    [-51.06881727610894, 36.63227438578703, -5.15063433458892],
+    [-49.39588603249458, 34.85212899144075, -4.677350782124035],
+    [-48.23575013065215, 33.46189203855585, -4.268440186857612],
+    [-47.47347472020803, 32.35560451192732, -3.8998961126748233],
+    [-47.02191498513019, 31.447524970761094, -3.550743192292279],
+    [-46.79676126800417, 30.649089245686884, -3.1978167394113197],
+    [-46.677974693040255, 29.832201689350033, -2.8071420734382113],
+    [-46.40149062092911, 28.726517362968284, -2.3099378888492823],
+    [-45.05238844528552, 26.442503638324762, -1.492314089517559],
+    [-33.525752370865135, 14.32424358194973, 1.67155088684594],
+    [-145.4610545417316, 115.82754789404935, -20.583818189396073],
+    [-107.55860979970323, 83.94446635988245, -14.274202379773456],
+    [-91.18622269852749, 69.95032761070422, -11.43156131271728],
+    [-82.12093955620183, 62.00900501770645, -9.755516553865247],
+    [-76.60119676189737, 56.9809166838566, -8.63288274905793],
+    [-73.17848765885495, 53.649598213551826, -7.823627441448785],
+    [-71.18745871911104, 51.447955194003036, -7.213102184543974],
+    [-70.29984262700357, 50.085651329809686, -6.73915827013948],
+    [-70.36289501102814, 49.412452711795495, -6.3648537143451005],
+    [-71.33664943565773, 49.36240376704644, -6.066397138032932],
+    [-73.27526384083221, 49.93195399779861, -5.826768087943094],
+    [-76.33717457199457, 51.17626049090084, -5.630925459305465],
+    [-80.82729026550784, 53.218738623842945, -5.45909723162053],
+    [-87.28255130611063, 56.260978927614694, -5.268202386297031],
+    [-96.49087650768523, 60.41182058752793, -4.896486880867152],
+    [-105.05611414715018, 60.80472692409512, -2.724311786797961],
+    [-18.53040440265999, 44.631955527003434, -22.771348784352114],
+    [46.20375588806585, -37.00011808727364, 2.545330784648108],
+    [37.488636163829625, -31.63382052827686, 2.7444550333974225],
+    [31.89020133787013, -28.13785327858065, 2.7869173686511246],
+    [27.990734861770694, -25.77982863996919, 2.826908132169095],
+    [25.187284296132983, -24.184490714341962, 2.8924067494480323],
+    [23.231415084666093, -23.190125904155728, 2.996508089991952],
+    [22.03884016960366, -22.743120839025476, 3.1515462255896742],
+    [21.606073553041618, -22.84924969910025, 3.372012450547833],
+    [21.97837238676459, -23.556776078758045, 3.6765366983710277],
+    [23.25014083544765, -24.961900504569627, 4.091437819376974],
+    [25.58739490439241, -27.233643631348674, 4.657312314217716],
+    [29.286873371832478, -30.675234825360256, 5.443063040474694],
+    [34.953064583010836, -35.896781667413585, 6.58281095452316],
+    [44.091668723429216, -44.38219755392517, 8.394565054644865],
+    [61.794156453498346, -61.05689186429375, 11.916193643894234],
+    [-54.655738959707065, 52.52407881224383, -14.028049198311955],
+    [1.7018826300053275e-8, -1.2984241985845163e-8, -1.7371980700010647],
+    [5.096814822154887, -5.384871356266097, -0.3444773739218689],
+    [6.684509034470522, -7.3827010803701985, 0.26170574438042815],
+    [7.402120305816994, -8.496884248025903, 0.6495438251703397],
+    [7.8867727287296, -9.33205719921644, 0.9565900179906351],
+    [8.37928652998912, -10.117061089958273, 1.2355126791380413],
+    [9.009626692365948, -10.974734525943033, 1.5147156744705543],
+    [9.875688827312153, -11.996374851089367, 1.8150286897030123],
+    [11.07566436264135, -13.272490500747876, 2.1566019694278387],
+    [12.729773205001495, -14.913936053460965, 2.563664442132371],
+    [15.005238265341122, -17.077157188269013, 3.070264203427503],
+    [18.16079494949655, -20.00985823976591, 3.7306570709427342],
+    [22.65807247516198, -24.16220596367345, 4.644104706136066],
+    [29.51763541638752, -30.53167378807085, 6.030213452097176],
+    [41.82231839077604, -42.10734115892161, 8.542461683553334],
+    [-56.60862785001298, 50.39458006979381, -12.10973937146508],
+    [-13.193512948045697, 10.448570779263658, -3.143631867482918],
+    [-5.51569792188147, 3.2030709334584038, -1.4786443813595966],
+    [-2.2121707440776466, -0.04430066321355407, -0.6961933798381371],
+    [-0.330975342562498, -1.9839450838720067, -0.203670578833543],
+    [0.9776170067647665, -3.3865149757441917, 0.16697961572077008],
+    [2.0664421573958434, -4.570264278734138, 0.48460937739514093],
+    [3.121054683220401, -5.701749277991486, 0.785047174280874],
+    [4.265663556534045, -6.8920513563247, 1.0920696748842456],
+    [5.6086227282277985, -8.238019786769483, 1.4263188075531963],
+    [7.26984658467545, -9.847527839486588, 1.8107894465153198],
+    [9.407829758868356, -11.86531874871781, 2.276564481321389],
+    [12.261170433576517, -14.514546660845571, 2.872283536393169],
+    [16.241138518264723, -18.190195100405408, 3.6855347739448314],
+    [22.219807786614723, -23.742055467817032, 4.906513039184487],
+    [32.756415571909734, -33.6593183426994, 7.091489226990421],
+    [-55.81728969715598, 48.25823078263755, -11.026683681655673],
+    [-19.999383690316616, 15.886189393444043, -3.884345804237041],
+    [-11.535435220430333, 8.135300427913085, -2.157151356812791],
+    [-7.500879292546978, 4.361081039645469, -1.2951755387876882],
+    [-5.060837672685726, 2.018442670668533, -0.743665565221282],
+    [-3.343362544270953, 0.32728149813816454, -0.333964817770282],
+    [-1.967355411331295, -1.0517979204264445, 0.006708187856248125],
+    [-0.7234698133773135, -2.304263480884949, 0.3178063759435066],
+    [0.5284623160009425, -3.553860184430513, 0.6254947094327847],
+    [1.9046303536166225, -4.903294791218609, 0.9514582999213603],
+    [3.5260584044071828, -6.460397108755153, 1.3185679354350044],
+    [5.547173565994282, -8.364738422819508, 1.7567318746668783],
+    [8.198963255628803, -10.82937593017721, 2.312310717396294],
+    [11.877885383561987, -14.229993599168404, 3.0687201873031054],
+    [17.40545577470333, -19.364553321897866, 4.206380722138565],
+    [27.173037965131716, -28.5685497852902, 6.25576138587315],
+    [-54.9923547392294, 46.79923740396594, -10.341646998450452],
+    [-24.428616473499307, 19.445063002512022, -4.364762311779346],
+    [-15.746231996560834, 11.603023287522605, -2.64093581739515],
+    [-11.347755311228763, 7.571112803361303, -1.7398670510150045],
+    [-8.59356723837367, 4.999402772876788, -1.1525517418471067],
+    [-6.6297876250350285, 3.129615569467571, -0.7158075043693415],
+    [-5.074325791044258, 1.6234968486784012, -0.3573064187534407],
+    [-3.71372617245912, 0.2927936311265983, -0.03706574058160436],
+    [-2.4026057721835286, -0.9903248558075428, 0.27190798144470424],
+    [-1.0201790422525079, -2.332167495803821, 0.592030894195104],
+    [0.5567505785769709, -3.8419945347272293, 0.9464665880148806],
+    [2.481759210585847, -5.657896250502416, 1.364827601476029],
+    [4.9832003189220275, -7.988985439878699, 1.892650929548598],
+    [8.45769810563853, -11.207035300636814, 2.612455947846349],
+    [13.7272951713782, -16.10524284091251, 3.7043596378362453],
+    [23.174880144917648, -25.02048054434064, 5.7060417847130855],
+    [-54.594328635357755, 45.99056493719984, -9.901218138985362],
+    [-27.799744091698667, 22.158159967984997, -4.727798921452331],
+    [-19.110998924247216, 14.371652408871034, -3.0293784614971515],
+    [-14.509975380129458, 10.198994570069932, -2.1072660227836044],
+    [-11.557611536923998, 7.480809710527767, -1.495913646698808],
+    [-9.429143468688668, 5.488260841728756, -1.039018361037798],
+    [-7.7483103371449475, 3.8895498471214873, -0.6657785958622948],
+    [-6.303676387707825, 2.4986655959273687, -0.33670075509481],
+    [-4.950883378214388, 1.1888381876338219, -0.024985609859873456],
+    [-3.5689536829112734, -0.14628635850866986, 0.2918259581356826],
+    [-2.033608859338708, -1.616705401096775, 0.6370830859289666],
+    [-0.19042226942277873, -3.3608665427586053, 1.0405156202876944],
+    [2.189742575308023, -5.587740086929048, 1.547698426324559],
+    [5.5096561067372924, -8.672337213375602, 2.2419218583294263],
+    [10.622558854526963, -13.430999765369865, 3.3086716005372834],
+    [20.016447509116833, -22.309010630181728, 5.31593296061534],
+    [-54.720516672442756, 45.76177500335164, -9.634909819008552],
+    [-30.696328365068585, 24.48590659201088, -5.039451767850578],
+    [-22.076237388607012, 16.79960131113388, -3.3714852503347594],
+    [-17.34875883109596, 12.539633745531239, -2.436368024774445],
+    [-14.257526224409384, 9.716496739229434, -1.8068912413852622],
+    [-12.008639472697174, 7.631156378602879, -1.3336057649037887],
+    [-10.233269843175414, 5.959312396146652, -0.9474484085605066],
+    [-8.72319733144829, 4.518114540707474, -0.6096172101162014],
+    [-7.336972890334183, 3.1834109894349303, -0.29383559980160456],
+    [-5.956358618190776, 1.8512340286532187, 0.021902022581106052],
+    [-4.45847203414095, 0.41285486415610717, 0.3607270961236567],
+    [-2.688235154062328, -1.2705457946930774, 0.7524998476081757],
+    [-0.41346574374899514, -3.410156114330188, 1.243348604491245],
+    [2.7791714568785624, -6.3892247637093345, 1.9184937353651645],
+    [7.790776831721831, -11.063854473939989, 2.972459880800322],
+    [17.32390734264592, -20.08700314110198, 5.02494079387306],
+    [-55.41155478696511, 46.07778510457164, -9.508574922996566],
+    [-33.44191839948904, 26.6849028728894, -5.337474334304514],
+    [-24.901433126828277, 19.096061874928697, -3.697003740005792],
+    [-20.08090375783323, 14.770087042471122, -2.751587106737168],
+    [-16.88088872448552, 11.861882992759249, -2.1065340753099804],
+    [-14.535874320817516, 9.699364740708496, -1.6185964164748494],
+    [-12.684069363642676, 7.965171404865279, -1.2203356840350603],
+    [-11.120185672931688, 6.479300398898308, -0.8735902461350433],
+    [-9.705638289473466, 5.120155979773915, -0.5526070310565319],
+    [-8.325892791544828, 3.786955843053203, -0.23600719766958766],
+    [-6.86175749624709, 2.3741512305039083, 0.09872677697151422],
+    [-5.159503912987119, 0.7441715525671319, 0.4812508864092691],
+    [-2.9833572434532933, -1.3170735182007975, 0.9583952655937324],
+    [0.09443485430837105, -4.205056604945867, 1.618202281815975],
+    [5.034683316417185, -8.828176749180319, 2.6671800020031897],
+    [14.867909098343649, -18.149895857856272, 4.7993117321156955],
+    [-56.72536426837591, 46.949758680077544, -9.508674955622741],
+    [-36.26107745378469, 28.933491230612763, -5.648772132100988],
+    [-27.774842397290858, 21.41231849786428, -4.028175973164352],
+    [-22.8691599494581, 17.022044076702723, -3.071738386339447],
+    [-19.57285453219074, 14.03522664356614, -2.411386906438853],
+    [-17.143871766329006, 11.802025646925934, -1.9090671601214857],
+    [-15.225898832305306, 10.010534878085133, -1.498664808316916],
+    [-13.61567801553759, 8.482775500913665, -1.142499982324626],
+    [-12.176774006359569, 7.099076998864605, -0.8152241636111545],
+    [-10.798127512461233, 5.761631861729081, -0.4960508276447378],
+    [-9.365424359376336, 4.369059171345244, -0.1632859371824289],
+    [-7.729183508412096, 2.787474491403976, 0.21201336463867532],
+    [-5.6520191859147895, 0.8014904688997062, 0.6770398152743885],
+    [-2.6892674690011114, -1.9991101834293634, 1.3232088061537444],
+    [2.191916718058406, -6.587438146600825, 2.3718942058705283],
+    [12.472641494759111, -16.3513956731034, 4.617202911173885],
+    [-58.76891684705601, 48.44297501009044, -9.637073254148163],
+    [-39.353087881410396, 31.389523151671895, -5.997772585875738],
+    [-30.86895104487283, 23.885649221253537, -4.385744494028737],
+    [-25.867233169174465, 19.41787054088633, -3.4148007554951816],
+    [-22.474484457517388, 16.348792507587813, -2.737558244603522],
+    [-19.965226722647607, 14.044610007254855, -2.2198663727870267],
+    [-17.98608549666543, 12.196597895662062, -1.7964778548995552],
+    [-16.334464901492037, 10.627588960621623, -1.4299554712608933],
+    [-14.875074058350831, 9.21895431543167, -1.0951807301601322],
+    [-13.499575319981574, 7.875194251843046, -0.771826731174234],
+    [-12.09864331626532, 6.49916034480058, -0.4390388956954473],
+    [-10.529547619450453, 4.96276642072921, -0.06902845858523386],
+    [-8.558075237270241, 3.05341876952559, 0.384895690349199],
+    [-5.723648373258076, 0.34687269739424575, 1.017362965000817],
+    [-0.9081561699960886, -4.207414069797196, 2.0674982508523714],
+    [9.964376418416395, -14.558980871489318, 4.461473621530911],
+    [-61.73238848073148, 50.69666417620431, -9.911574637692215],
+    [-42.942835426638894, 34.23032965764857, -6.412527451741444],
+    [-34.38107608140986, 26.671296131170447, -4.79362637875649],
+    [-29.254607178054023, 22.098076043983088, -3.8018099679453616],
+    [-25.754009287530717, 18.933576522138104, -3.1041562671053224],
+    [-23.161233082830968, 16.552018396561508, -2.5687911639989696],
+    [-21.122159899728906, 14.644647647496747, -2.1306944874361804],
+    [-19.432928168751516, 13.033424958570922, -1.7523363828151979],
+    [-17.958119100301925, 11.599640690936656, -1.4086007967796648],
+    [-16.591125879007173, 10.24921322601107, -1.0794348012904258],
+    [-15.227067612424296, 8.888705025138085, -0.7447090044105811],
+    [-13.73129488468897, 7.396907954069684, -0.3780056586161247],
+    [-11.879080321962496, 5.5694481760197085, 0.06572517528922425],
+    [-9.202106455734421, 2.975197535957737, 0.6827839739165285],
+    [-4.486044012986083, -1.5240714907609387, 1.7323227968186952],
+    [7.126949892982099, -12.619148961375679, 4.314927973429104],
+    [-65.95151863800498, 53.970175128289874, -10.372441522647652],
+    [-47.34010924774203, 37.69924031871825, -6.932127979073012],
+    [-38.58277960578268, 29.98097661155841, -5.284827633336315],
+    [-33.280850741682556, 25.25561527743856, -4.261966555516211],
+    [-29.64838377562429, 21.971270669735862, -3.53792764644764],
+    [-26.96213028267712, 19.49934272776467, -2.980952738081509],
+    [-24.861854364150567, 17.526432678606344, -2.525336027448759],
+    [-23.139661199249648, 15.871225352760883, -2.1329733626317435],
+    [-21.658333995333162, 14.41344215053664, -1.7784413477658423],
+    [-20.311816491474858, 13.059324592314534, -1.4417197456859683],
+    [-18.99882630168263, 11.718306074027014, -1.1032047640702947],
+    [-17.593032215663726, 10.276116412713954, -0.7378286225566195],
+    [-15.88463798647841, 8.541190590061015, -0.3031169016032457],
+    [-13.415356682960846, 6.09113119325893, 0.2955606321497973],
+    [-8.878523362906668, 1.7035063119526432, 1.336575636516812],
+    [3.628740472691847, -10.305303204755198, 4.154127345101487],
+    [-72.044990749586, 58.75055412203324, -11.100103359276234],
+    [-53.044822597091894, 42.18798433652151, -7.620107685898492],
+    [-43.908520337607165, 34.15152237572109, -5.912211608045325],
+    [-38.346569386741365, 29.19791546801599, -4.842064687817841],
+    [-34.54080170816561, 25.75307645381745, -4.0819444704876195],
+    [-31.743751690205787, 23.169466460631348, -3.497054925822477],
+    [-29.580746279058438, 21.121952330000678, -3.0196118379870502],
+    [-27.835664963306275, 19.42229742553184, -2.6101994398006507],
+    [-26.36717694420145, 17.94654298981842, -2.2426301998411824],
+    [-25.068369207489514, 16.599855401612917, -1.8965796062151408],
+    [-23.840674184383776, 15.293512649985946, -1.5526822162950629],
+    [-22.565850014169555, 13.91963032487407, -1.187003885568198],
+    [-21.05228553997478, 12.301844013722109, -0.7600254016415313],
+    [-18.874446083382093, 10.04616262476927, -0.18289386505931263],
+    [-14.682880419525812, 5.890733450177515, 0.8321083572845251],
+    [-1.1612523623498119, -7.190647503798354, 3.9346489802487246],
+    [-81.26468456106356, 66.03044802046435, -12.26245932268597],
+    [-60.99048092147053, 48.42723138095095, -8.595654143426245],
+    [-51.159194166159054, 39.80209036618692, -6.773336913933528],
+    [-45.18994326886229, 34.48928560944255, -5.628284054446353],
+    [-41.140979069954355, 30.815852614799674, -4.815827172970757],
+    [-38.20803490452816, 28.08795912578735, -4.192861116519304],
+    [-35.987788157023864, 25.956664144571928, -3.687157606963127],
+    [-34.2490913323433, 24.220825179648973, -3.2567524546214863],
+    [-32.843185584376045, 22.749617155739706, -2.873951894356901],
+    [-31.660924938551357, 21.445412036119283, -2.5176137989107485],
+    [-30.606224590058087, 20.220379661185063, -2.1681811291502244],
+    [-29.569650597379233, 18.972603243120552, -1.80244186365076],
+    [-28.380522941432318, 17.54188779415282, -1.3835962135483943],
+    [-26.6644072357663, 15.577013599633988, -0.8310859572208658],
+    [-23.153106202847475, 11.887290758013732, 0.12829934350910752],
+    [-8.730090909187552, -2.263380449150458, 3.545984967083044],
+    [-96.5928110097086, 78.1814459820635, -14.264836989812332],
+    [-73.25354324780245, 58.041755882413305, -10.124812326981502],
+    [-62.08450632351214, 48.28371823705811, -8.081673132998183],
+    [-55.419560179167675, 42.35730178002402, -6.808527163537558],
+    [-51.00386931575738, 38.33333349987418, -5.913973763467365],
+    [-47.909243956081085, 35.41569817510555, -5.235940044971855],
+    [-45.675782409672024, 33.207834123611796, -4.693056954469579],
+    [-44.04560396561504, 31.48498639623329, -4.238465453756902],
+    [-42.85903175629214, 30.105153414601713, -3.841680532107722],
+    [-42.00625149065043, 28.967244947263893, -3.479955814364732],
+    [-41.39918127723451, 27.986148670935634, -3.1329593308935384],
+    [-40.946598694789984, 27.069801152525308, -2.777615372185821],
+    [-40.51292967910663, 26.080254445702437, -2.3790825934899953],
+    [-39.79936123271447, 24.72117057228528, -1.8646531050608737],
+    [-37.76833880049539, 21.993726130594474, -0.9977383874057929],
+    [-23.823849885092294, 7.732554498045408, 2.617168272666706],
+    [-127.63239790061667, 102.85124464022222, -18.4213990556803],
+    [-95.89919161807043, 75.77834189273645, -12.98722169369113],
+    [-81.64464088636527, 63.42530704906438, -10.443659647693968],
+    [-73.57460745534165, 56.26389470741631, -8.913767423966322],
+    [-68.55739189645621, 51.64503753952267, -7.873400935150142],
+    [-65.35566755505458, 48.51632800474785, -7.1124180741092635],
+    [-63.38720753492475, 46.376182091580034, -6.528534979714717],
+    [-62.35366339861869, 44.95970390684918, -6.065122633847315],
+    [-62.1023452280019, 44.120570033298065, -5.687433082834262],
+    [-62.568815071034614, 43.78018067404631, -5.3717671206586],
+    [-63.75331124720787, 43.903636418682716, -5.099344860508351],
+    [-65.71407216028264, 44.48648473272441, -4.851119758418008],
+    [-68.56834974626102, 45.54019317522731, -4.599727453642366],
+    [-72.4714938266859, 47.03881963879973, -4.286987828983801],
+    [-77.32095710773325, 48.55853811687431, -3.716022271834718],
+    [-75.99148430532603, 42.55319345145131, -0.8943891265198723],
+    [-8.298273954391407, 28.165381484108796, -15.591437267518819],
+    [42.9112420831214, -32.473838361163175, 2.046447491750623],
+    [38.318512900343734, -30.376440886458123, 2.562500762352505],
+    [34.82529881550175, -28.52437299632068, 2.7819482567037355],
+    [32.34587847859586, -27.23600934947578, 2.946708235063132],
+    [30.634133374164136, -26.435182377285614, 3.111006931989385],
+    [29.540479685083916, -26.057208347694303, 3.296351654669476],
+    [28.991520863042968, -26.074727136469253, 3.5167512377754084],
+    [28.96987856811662, -26.49591453279196, 3.785962692256432],
+    [29.50755480565829, -27.36613148083971, 4.1213005746230715],
+    [30.693310986846807, -28.779002544363813, 4.547720025667908],
+    [32.70047865652131, -30.90499797942715, 5.104775037284686],
+    [35.85772204807567, -34.059588814110384, 5.861447493119866],
+    [40.83589562397642, -38.88034790349208, 6.953509683595327],
+    [49.236776548995266, -46.88304471231947, 8.69986754649492],
+    [66.21585492505857, -62.93934829735976, 12.119427466832247],
+    [-34.339454317540905, 37.36977172047632, -11.31103289180057],
+    [5.98471662116562, -3.388877547524319, -1.3146996382524618],
+    [10.315304771126879, -8.169036883347387, -0.029290214419994985],
+    [11.841789896128992, -10.096481316001599, 0.5614350214593715],
+    [12.653911736886029, -11.263882075055177, 0.9563447642594953],
+    [13.261543936982786, -12.1810520471911, 1.2769195636950288],
+    [13.861942770206719, -13.04553426335582, 1.5706535139647348],
+    [14.563943009791263, -13.96577474703552, 1.8636585757384176],
+    [15.450770901047681, -15.023527834264929, 2.175307335768643],
+    [16.607016675646836, -16.30056609566609, 2.524447262836611],
+    [18.13887254617805, -17.898501057726982, 2.9339077021170263],
+    [20.2012349254286, -19.96496160385408, 3.436303090780026],
+    [23.050062506193925, -22.743709031753564, 4.084984769506799],
+    [27.1685200538797, -26.694796406052046, 4.980135916287477],
+    [33.64369290600615, -32.85272219675505, 6.346358675500161],
+    [45.720409966327026, -44.30403879413112, 8.852506044824596],
+    [-40.31845996929436, 38.766185854442895, -10.146954609407441],
+    [-6.348943975764347, 6.260008759277833, -2.577104594157668],
+    [4.187871096350548e-6, -3.259766596843544e-6, -1.0786381253672812],
+    [2.787742236503478, -2.8676152155193857, -0.35849184808368856],
+    [4.428930728262207, -4.632849803412052, 0.10676061003914986],
+    [5.6217257227151025, -5.9558368283138075, 0.46669288844141443],
+    [6.651883097331365, -7.1059470020492705, 0.7819826308666561],
+    [7.669251964679823, -8.22405648458011, 1.0842308624057149],
+    [8.775993760845747, -9.405956948410676, 1.394812662741379],
+    [10.063493584824906, -10.737267245557323, 1.7327165509151656],
+    [11.63551747991797, -12.315491162896626, 2.1194800429873855],
+    [13.633902617827117, -14.274667688313135, 2.5846896784653626],
+    [16.283967433329778, -16.828994409893113, 3.1756855120734904],
+    [20.000917685057047, -20.37480982518607, 3.9801868776169163],
+    [25.704895109266584, -25.79193938243562, 5.193041212211518],
+    [36.113402222215036, -35.6791452867647, 7.389722976018628],
+    [-42.72746492562973, 39.05426497860471, -9.499777661772002],
+    [-13.586256678372559, 11.866725487928585, -3.3205943765520574],
+    [-6.410200329498743, 5.0664412502076885, -1.7559394375658175],
+    [-2.94527179141182, 1.7072508667555655, -0.9628830830914998],
+    [-0.816762295260816, -0.41067625868287194, -0.44772964976923413],
+    [0.7170077426920273, -1.972381996196491, -0.05796066992616442],
+    [1.9821927986801777, -3.2779080586907203, 0.27270009685972363],
+    [3.156897550299739, -4.490490977039639, 0.5800618423264207],
+    [4.359776389525172, -5.718747690427678, 0.8878484507865749],
+    [5.6902262559276355, -7.053974596398486, 1.2159911173778708],
+    [7.253802586538697, -8.593971883116918, 1.5859282636297154],
+    [9.188034244844337, -10.46770570922096, 2.0261403200263275],
+    [11.705139199241822, -12.876536666785814, 2.5814528349629233],
+    [15.190523877494389, -16.1892524740525, 3.334471621654373],
+    [20.496429931708754, -21.224613342912612, 4.469213537898952],
+    [30.16946601750284, -30.431003119668148, 6.536658489772564],
+    [-44.10066044116442, 39.19757264711669, -9.090253512947314],
+    [-18.53926939022094, 15.70345958300204, -3.8281915173061534],
+    [-11.028684231091164, 8.727186212914722, -2.2541436801647747],
+    [-7.180284048549201, 5.096115624658201, -1.4205954358722517],
+    [-4.743789032600717, 2.7540020729456303, -0.8712418776901071],
+    [-2.980118661235413, 1.02701272959157, -0.4575155033530768],
+    [-1.5543748730316869, -0.3892550549675725, -0.11274288465360147],
+    [-0.27767481357720286, -1.6658949383073631, 0.20029961117007553],
+    [0.9787132795808432, -2.91931323054139, 0.5068153187318936],
+    [2.3215568510637046, -4.246348400120185, 0.827771474639636],
+    [3.8606402836215166, -5.747504285553139, 1.1850653213616933],
+    [5.734696302815353, -7.551618372692142, 1.6070811422116251],
+    [8.152909843390267, -9.856229168970437, 2.137924491682654],
+    [11.489530557684887, -13.01993112145303, 2.8586160371361427],
+    [16.572512748943623, -17.842215882931786, 3.951051650371249],
+    [25.9349643764594, -26.772878340903127, 5.974047021362508],
+    [-45.224367488515746, 39.478477424023346, -8.833720804733245],
+    [-22.35521531648614, 18.666351040116634, -4.219255677595523],
+    [-14.723645994570424, 11.663075025960145, -2.6577292722879124],
+    [-10.639111220168548, 7.867966167912606, -1.8000545782295343],
+    [-7.993949665618586, 5.3727797823729135, -1.2262214166229812],
+    [-6.065249145997661, 3.524133997124842, -0.7931803790024456],
+    [-4.519106321550438, 2.020882043725149, -0.43534131054132097],
+    [-3.165095233238199, 0.6916902360779292, -0.11558560353307996],
+    [-1.8714510175172092, -0.5818320368309867, 0.19162783639151496],
+    [-0.5273323897789512, -1.899516110442124, 0.5078344974408511],
+    [0.9810137723388916, -3.3646817299020357, 0.8554651626899963],
+    [2.7957292659123962, -5.10822518630077, 1.263277788249844],
+    [5.129667591742438, -7.32955071097955, 1.7757418237616958],
+    [8.360879096796298, -10.389646547664736, 2.4746901074266483],
+    [13.321351678849426, -15.095229797829404, 3.5454629348304048],
+    [22.62689309102039, -23.993375379238415, 5.576338625515554],
+    [-46.42135512168395, 40.008168353464846, -8.694175630643509],
+    [-25.597924391557136, 21.19211356988418, -4.554171646783157],
+    [-17.937463541498094, 14.219205080114216, -3.0120140883521],
+    [-13.693565182043928, 10.314256842835816, -2.1382146878217894],
+    [-10.895673935710123, 7.705238947142268, -1.5453183375083395],
+    [-8.841131082686546, 5.761123003847623, -1.095958147425136],
+    [-7.199779176344832, 4.185835676260409, -0.7259451449572801],
+    [-5.782473154944707, 2.8099928815929527, -0.3986985218425917],
+    [-4.458240282271582, 1.5163611136293067, -0.08897238703271756],
+    [-3.115884113419035, 0.20516672125498975, 0.22472788893716097],
+    [-1.6395982902276824, -1.2282825756896576, 0.5650951827480588],
+    [0.11629087835769127, -2.9173724692220913, 0.9613859582416772],
+    [2.3705902268915358, -5.065752626904225, 1.4589544997354806],
+    [5.515260454116739, -8.045226479293525, 2.142044991759952],
+    [10.416029865396059, -12.694717064631822, 3.204356160476384],
+    [19.85255358121288, -21.740719679362883, 5.282981561791038],
+    [-47.86813006957613, 40.855144505457844, -8.65604133344274],
+    [-28.597114838539053, 23.535849627235514, -4.869276147045896],
+    [-20.937889302242873, 16.60457636703534, -3.3456946486108072],
+    [-16.572867523667977, 12.614641642326506, -2.4589553779764977],
+    [-13.653185930615715, 9.912329101906455, -1.8495748898161066],
+    [-11.496134137548088, 7.887599555924014, -1.3854296591720772],
+    [-9.77568698682741, 6.24939708488566, -1.0036815469743412],
+    [-8.304365737248734, 4.830442444632612, -0.6683091230205165],
+    [-6.953085964471208, 3.515496070714501, -0.3545301128876126],
+    [-5.612666872007649, 2.206791804535706, -0.04128785539098274],
+    [-4.167989319177771, 0.8005169644789725, 0.29391568487575276],
+    [-2.4711539722184948, -0.8382566782740092, 0.6806544966924345],
+    [-0.2966489924255598, -2.9184352366126607, 1.165412372555257],
+    [2.765618318878945, -5.826837117161557, 1.8357639831633508],
+    [7.641879943845419, -10.456631530455889, 2.8980680876593543],
+    [17.376699561672286, -19.810352814504753, 5.060539262875727],
+    [-49.702295736141, 42.08965337082713, -8.716089529778342],
+    [-31.584721416195382, 25.877488177208132, -5.190996709303666],
+    [-23.91971666854433, 18.971463023411225, -3.680537098368938],
+    [-19.447548388884144, 14.902672247111648, -2.7808112559731772],
+    [-16.42094353433895, 12.115261385199538, -2.1554731718703657],
+    [-14.174179746937568, 10.016861407142468, -1.6768504056124773],
+    [-12.38449730173974, 8.320409804013996, -1.2832192709791488],
+    [-10.865561681037171, 6.860152180824244, -0.9389917979148306],
+    [-9.489978243532498, 5.522590082837399, -0.6197951758777378],
+    [-8.151361185600715, 4.212566349799575, -0.3051330206417443],
+    [-6.737630183018986, 2.829105221800605, 0.02689486033267351],
+    [-5.101474990454007, 1.2380773166827577, 0.4056957043303553],
+    [-3.0115034096967075, -0.7741589686397626, 0.8787848999177118],
+    [-0.036875234138435325, -3.6122372679481374, 1.5377122442381006],
+    [4.8264846079701496, -8.239279272515057, 2.604873016839999],
+    [15.02853846298288, -18.06172301617574, 4.888225671113551],
+    [-52.07431482815511, 43.80861296550474, -8.881630482610188],
+    [-34.76246522569932, 28.374264458182385, -5.5432009625137155],
+    [-27.057046387682213, 21.456027223858182, -4.036716128123792],
+    [-22.473488791189148, 17.300055306622664, -3.121314140024353],
+    [-19.34288152347264, 14.426189509370364, -2.4787995491681327],
+    [-17.011442347261653, 12.254843320347016, -1.984876379931147],
+    [-15.157810195068434, 10.501140861902703, -1.5785498465447398],
+    [-13.595740144010785, 8.999845581549586, -1.2244549502831195],
+    [-12.198783307252794, 7.63849176316636, -0.89848032405454],
+    [-10.863118350353922, 6.324269750980357, -0.5806327557316739],
+    [-9.48091740687555, 4.960023588855154, -0.24981035191471476],
+    [-7.9089384659489, 3.4153772154973545, 0.12261094159611795],
+    [-5.913508084015906, 1.475008417121462, 0.5845307005259293],
+    [-3.0421338260399833, -1.2846063710161133, 1.2317182167385854],
+    [1.801076094027326, -5.910067013924846, 2.3057005865863482],
+    [12.649478255646041, -16.374290635353788, 4.750843756299407],
+    [-55.190551606876404, 46.16263829628608, -9.172426379608998],
+    [-38.34975748857986, 31.19803698289118, -5.952768451455672],
+    [-30.542071417225895, 24.208460145598504, -4.437114528034222],
+    [-25.82579588708615, 19.942895026895275, -3.500618075116217],
+    [-22.583424261981282, 16.97219968963782, -2.8378992652632027],
+    [-20.165819090257774, 14.722921816370535, -2.3266418397784916],
+    [-18.25004906964551, 12.90967555731508, -1.906024181936476],
+    [-16.648357054827503, 11.366284254418275, -1.5406043317535105],
+    [-15.234157539531262, 9.980318827728828, -1.2063238441201571],
+    [-13.905455314199918, 8.660612757474924, -0.8835399768056367],
+    [-12.5588146800921, 7.313871848929218, -0.5519479058419515],
+    [-11.057855741445666, 5.815823473855852, -0.18419075774469035],
+    [-9.17328442555381, 3.9552515737880123, 0.2667861369910597],
+    [-6.4351925528120795, 1.2934199514146145, 0.9001631710788897],
+    [-1.6420707976172755, -3.3128756030457533, 1.979569273821572],
+    [10.044544807656452, -14.61066391073572, 4.633752906105094],
+    [-59.375221833410926, 49.40203244248765, -9.627233311061174],
+    [-42.63849776817289, 34.57825606699152, -6.456375375721191],
+    [-34.631079223614094, 27.42877464170172, -4.9127655666600845],
+    [-29.740327030366164, 23.013749159551963, -3.9462244912877558],
+    [-26.366645278186883, 19.925328532434783, -3.257991232214983],
+    [-23.855031949713755, 17.58693577749514, -2.725845618262773],
+    [-21.87646265028405, 15.70869043758494, -2.2883353189469386],
+    [-20.239290705266104, 14.121358688603282, -1.909509076481919],
+    [-18.81531862769384, 12.711200664381579, -1.5650761594397333],
+    [-17.50334078275947, 11.387704554855196, -1.2355232205534825],
+    [-16.20379412929152, 10.06086798250546, -0.9011990620077251],
+    [-14.788547752789393, 8.613586949074273, -0.5362079058238386],
+    [-13.0404083088492, 6.845423015010168, -0.09573675322045844],
+    [-10.486794942451379, 4.315565884494837, 0.5199460979239672],
+    [-5.8136532667108884, -0.22218211951011332, 1.59794605969042],
+    [6.914489805404664, -12.568339547103935, 4.517259623657079],
+    [-65.19409483584712, 53.97411475764754, -10.319800356106715],
+    [-48.08774794945608, 38.87652471206323, -7.1126024012183455],
+    [-39.724790886389236, 31.42935388334205, -5.512649441755549],
+    [-34.587332976849886, 26.798364731699806, -4.50159219027197],
+    [-31.04691325358631, 23.5564603904214, -3.7791172380955445],
+    [-28.42652029547379, 21.11001201219002, -3.2203363530008593],
+    [-26.383891746029732, 19.158450765607782, -2.761933857842421],
+    [-24.71982950366694, 17.526249598001044, -2.3667875659655078],
+    [-23.30244353215825, 16.096436904893572, -2.009956565250526],
+    [-22.030009369288976, 14.777850047117443, -1.6717440657699143],
+    [-20.806049197671992, 13.48285857629316, -1.332897103086479],
+    [-19.510985834158923, 12.101483108348821, -0.9688871160460125],
+    [-17.945542264185626, 10.448824702965796, -0.5380590236163838],
+    [-15.660551452096016, 8.105090606109844, 0.054926808392828146],
+    [-11.256765900643398, 3.746380945817304, 1.114915572989248],
+    [2.7174122166298087, -9.883667064518164, 4.36627830747454],
+    [-73.75769252342573, 60.765632938528924, -11.400173745149544],
+    [-55.53852671944052, 44.75607005577591, -8.029733062392648],
+    [-46.549504221126874, 36.77602122311605, -6.32601203324592],
+    [-41.038111823406176, 31.813974393180857, -5.24579710103959],
+    [-37.27016821696073, 28.35815971494696, -4.474413489772089],
+    [-34.51887240387474, 25.774253103178665, -3.8797010804574814],
+    [-32.41644614384058, 23.74043189684423, -3.3943921442940517],
+    [-30.75014586081215, 22.069546897704743, -2.979086741053007],
+    [-29.38135073346332, 20.638472981843464, -2.6075179624883584],
+    [-28.206406384319976, 19.35372731404048, -2.2593287119447885],
+    [-27.13147524455452, 18.12904563770429, -1.9152642227337915],
+    [-26.04604866472081, 16.86117526877006, -1.551816460322576],
+    [-24.77250592827058, 15.38291148649669, -1.1305968341473098],
+    [-22.91166412693413, 13.3178854401233, -0.5650981539635824],
+    [-19.093457123481976, 9.368539923390598, 0.44431538622823785],
+    [-3.789231396385925, -5.713761433363885, 4.093826150689119],
+    [-87.645246399538, 71.84460490098574, -13.222335395509914],
+    [-66.82816366689104, 53.6668534270654, -9.44562997987366],
+    [-56.66701290893155, 44.68525322596523, -7.5454348167966145],
+    [-50.53369692840928, 39.170067752926016, -6.349001128894687],
+    [-46.43081210997055, 35.39249888540555, -5.502001266072419],
+    [-43.5250298353443, 32.629640487921236, -4.8558090881006075],
+    [-41.39902392930711, 30.517712329374323, -4.335095557283324],
+    [-39.81608268881836, 28.848376795296495, -3.896092240297443],
+    [-38.62728256412452, 27.488202104858562, -3.5100269568044293],
+    [-37.727586444318646, 26.34025341721783, -3.1551263020842035],
+    [-37.02944816578714, 25.32049295276467, -2.8115461624827804],
+    [-36.43802586108314, 24.33484209665854, -2.4562123189748726],
+    [-35.807840172590446, 23.238197098242722, -2.0533543945825277],
+    [-34.814260334183736, 21.712395443373147, -1.526195263633016],
+    [-32.30856704045403, 18.652253268913757, -0.6141409660141794],
+    [-16.496075525359988, 2.6249070479776364, 3.4001929310210013],
+    [-114.8618456317223, 93.64185866288078, -16.89361127245102],
+    [-87.18831771705767, 69.74019242288641, -12.040406402343917],
+    [-74.4090690203945, 58.53155998761364, -9.706654753393241],
+    [-67.05747656482193, 51.93305592760869, -8.282636132178084],
+    [-62.41894261410424, 47.621207426154285, -7.303719090778173],
+    [-59.40083312922092, 44.65608695080145, -6.58035099152339],
+    [-57.48026570077272, 42.58269292532968, -6.019011488007257],
+    [-56.38273727594507, 41.15583139607383, -5.567174249395165],
+    [-55.9602374177486, 40.234902196933064, -5.191963904367621],
+    [-56.138689596903575, 39.73720352883513, -4.870235862607397],
+    [-56.89343208522868, 39.61393005891084, -4.582749145917425],
+    [-58.236122477886454, 39.83343140191661, -4.308935859708666],
+    [-60.19958471355646, 40.35718995547551, -4.018347378774639],
+    [-62.776673029728535, 41.061664877468246, -3.646316392330599],
+    [-65.50694792674668, 41.29903254202112, -2.9776434811967007],
+    [-58.387181248001845, 30.978696047005347, 0.5095446046503588],
+    [-2.5821806734493675, 20.04969107928844, -12.291423618182922],
+    [40.705790248732804, -29.670903417837607, 1.7389974340595078],
+    [38.107760299221546, -29.133895443608804, 2.4022799807930815],
+    [35.669493829822635, -28.12501375847176, 2.709116101822382],
+    [33.84559726920499, -27.366506186540654, 2.9333488516756048],
+    [32.58435942277161, -26.931672320216098, 3.141628156674013],
+    [31.819535311922422, -26.821834626417818, 3.3612276938472374],
+    [31.516798150032418, -27.04169981714977, 3.6090325750608203],
+    [31.677825164621908, -27.61575482212727, 3.9002423104172803],
+    [32.34415315317028, -28.59775983599771, 4.2528402526382285],
+    [33.609884018064896, -30.085680584784274, 4.692013955486629],
+    [35.65267186032097, -32.25232433312852, 5.2572868689055126],
+    [38.8066949784958, -35.41442087664236, 6.017375066949975],
+    [43.751129576275225, -40.20903223248689, 7.1072572971082915],
+    [52.09903360286167, -48.143345810023376, 8.842942890894541],
+    [69.00366798175966, -64.02746364899997, 12.22792909347356],
+    [-24.660593878666408, 29.76119272947498, -9.825858034540822],
+    [8.66946447318566, -4.826495670577156, -1.1322700624168491],
+    [12.808326541033654, -9.417768725248772, 0.11039252791316424],
+    [14.358184402113054, -11.348459891383085, 0.6977093838432304],
+    [15.235822575055968, -12.559288646138285, 1.0980997826669654],
+    [15.914828664487063, -13.528288565779526, 1.4266948109398372],
+    [16.580545326900882, -14.443103741190555, 1.7290655230767278],
+    [17.33609007724106, -15.407813276473817, 2.0305141440702057],
+    [18.261141625601393, -16.501651187895124, 2.349969610049318],
+    [19.437992836024268, -17.804771029099292, 2.7060004570380456],
+    [20.971906998316648, -19.417996553150275, 3.121288735763349],
+    [23.01911529014079, -21.48948696176957, 3.6285001551243665],
+    [25.8411839270671, -24.26609359529932, 4.281437557004325],
+    [29.935649346016408, -28.21642476191385, 5.1816441125287405],
+    [36.42531985963524, -34.39741713916596, 6.557539116197921],
+    [48.668611289598395, -45.97108933605298, 9.0916432120903],
+    [-31.036931623237095, 31.9639641821581, -8.95544429855221],
+    [-2.537315587181717, 3.935023986275136, -2.259717923150066],
+    [3.085443568747984, -1.7681398776603638, -0.8585284100557968],
+    [5.621751310648441, -4.443304309389711, -0.17123983376377083],
+    [7.161586239723519, -6.131924410637513, 0.28161468505611315],
+    [8.313984071070516, -7.426865415065723, 0.6379951852445408],
+    [9.328831888166917, -8.570236196398485, 0.953834605639715],
+    [10.339112213379602, -9.690248517007724, 1.2585660646183414],
+    [11.437910633085476, -10.876070488124109, 1.5724748288578667],
+    [12.711153319504623, -12.209456644167206, 1.9139463989931966],
+    [14.259474755189906, -13.78572148160077, 2.304199481687807],
+    [16.224175392174857, -15.738466910248947, 2.7727883047843487],
+    [18.83447498410482, -18.28443498563136, 3.367516681531134],
+    [22.51879522571806, -21.828987400648444, 4.1777036612576905],
+    [28.234266619803186, -27.278339045198734, 5.40302944728932],
+    [38.823284562425705, -37.32542213660545, 7.6371167592665605],
+    [-34.42830268617477, 33.12263518037454, -8.495533597183682],
+    [-9.48520712294041, 9.289535465399037, -2.9572388675566312],
+    [-3.1164306185068895, 3.099678793805031, -1.499208111738492],
+    [5.5603613626636905e-8, -4.163524619082287e-8, -0.7499999923401117],
+    [1.9439052422638181, -1.9824390984138833, -0.2569790775053726],
+    [3.3728122883948526, -3.469837506204889, 0.12149567287643134],
+    [4.576171775010717, -4.735131893235468, 0.44708726603670673],
+    [5.711438773683337, -5.926314019486205, 0.7530124535059656],
+    [6.884416211975139, -7.142638067852098, 1.0614329642362808],
+    [8.18602188444591, -8.469408497789045, 1.3913260986176028],
+    [9.716109589582748, -10.000544452256603, 1.7635560525576233],
+    [11.609244162139163, -11.863092135930613, 2.206366881307259],
+    [14.079223898064052, -14.25965805944289, 2.764884857184033],
+    [17.522736711641457, -17.567222675220503, 3.523245280607377],
+    [22.828373038049293, -22.631660559012584, 4.670561529824089],
+    [32.67373214968163, -32.004236918319954, 6.778078337639646],
+    [-36.68745278264467, 33.966321837662974, -8.2182175500381],
+    [-14.463290447801533, 13.106077475891016, -3.4545192963905103],
+    [-7.741826067465445, 6.724407557592355, -1.9850970678661233],
+    [-4.260927954036355, 3.3659442660965464, -1.198100065998907],
+    [-2.0342286572940598, 1.1777298708497346, -0.6744165366354693],
+    [-0.40070533579331546, -0.4556105972474044, -0.27574905424278573],
+    [0.941884960144399, -1.8146264449256118, 0.0605318982072854],
+    [2.1644820204526662, -3.057510070027775, 0.36951197272667524],
+    [3.3834023806979014, -4.291801715051212, 0.6749402190503534],
+    [4.695786129504401, -5.60759856541346, 0.9967109956127093],
+    [6.203578048866542, -7.100137256166707, 1.3559397750144435],
+    [8.039363548337969, -8.894359216980044, 1.7805013321438603],
+    [10.409964582791318, -11.186643230404103, 2.3144760313151025],
+    [13.697631454107755, -14.341841371134574, 3.0401531428409223],
+    [18.766070589086915, -19.18671212619303, 4.144622486448025],
+    [28.288540151361147, -28.283283091336948, 6.2090479077117315],
+    [-38.51815420715392, 34.781922620094136, -8.057502092500682],
+    [-18.38576170395628, 16.114547151262013, -3.8469014490714977],
+    [-11.501457711478569, 9.674026157263475, -2.385130707172745],
+    [-7.781535512767108, 6.149447692688455, -1.5741637215124187],
+    [-5.352338203054765, 3.8130609132491315, -1.0272661619701273],
+    [-3.563159021087181, 2.0658018289279814, -0.6110566246846425],
+    [-2.110252438995141, 0.6286262323894062, -0.2637385504969577],
+    [-0.8186062160466422, -0.6588718619348646, 0.05001771530382494],
+    [0.4335412531597595, -1.9081959636982293, 0.35467525355754526],
+    [1.7486849724491174, -3.2135191908015437, 0.6709071549674647],
+    [3.2326309662788657, -4.672918978578094, 1.0203838636875373],
+    [5.01952675491315, -6.412304230223453, 1.4312179117296955],
+    [7.31475293765272, -8.626846429911076, 1.9474625804361625],
+    [10.496039093153474, -11.67870802346494, 2.6515486011699356],
+    [15.426798456337954, -16.39944799490812, 3.7337253947623035],
+    [24.87680632526134, -25.460621267114114, 5.807180973453191],
+    [-40.257073978111144, 35.71215304775142, -7.987914653605323],
+    [-21.741139921809005, 18.69679503711591, -4.1861656832935665],
+    [-14.783187164003206, 12.253389307384575, -2.738516860438099],
+    [-10.894067244396341, 8.612207832768155, -1.9107313723272774],
+    [-8.311289914804728, 6.161951793553734, -1.345159205546614],
+    [-6.398970930022498, 4.3220275956879455, -0.9135010454450567],
+    [-4.855293143021352, 2.8172678100332287, -0.5552074993097676],
+    [-3.5052029058447767, 1.4883068667050106, -0.23535472941198093],
+    [-2.2258794366446337, 0.2233673907827127, 0.07048131595366106],
+    [-0.9123140131618404, -1.073410880121997, 0.38326470659804346],
+    [0.5449153044027346, -2.5027531791272954, 0.7251729317112611],
+    [2.284009899584553, -4.19353826164684, 1.1249189718557115],
+    [4.514689205139531, -6.344338143920354, 1.6273679922780555],
+    [7.61953456377612, -9.322743342037441, 2.3167065660675905],
+    [12.478872089083692, -13.982318490772425, 3.3902466471236177],
+    [22.038945793343967, -23.185931251787718, 5.512667298303166],
+    [-42.10596382700324, 36.852229470207334, -8.00026838128932],
+    [-24.83105514485999, 21.08667229403447, -4.505036636816556],
+    [-17.83408605720585, 14.655379073424664, -3.071262837363905],
+    [-13.812820932708071, 10.92177935798831, -2.2296767764461736],
+    [-11.105076744404993, 8.376599943652957, -1.6477027439730394],
+    [-9.090219295320972, 6.4568558736676716, -1.2017443920862805],
+    [-7.469075768217438, 4.891423219632176, -0.8324759791423935],
+    [-6.067538699287353, 3.5226191345040414, -0.5054918767917438],
+    [-4.763767242531277, 2.2400401644139296, -0.19673974463745714],
+    [-3.453098603992046, 0.948535008185227, 0.1145438294612066],
+    [-2.024603102138715, -0.45352569210523946, 0.45068584720226995],
+    [-0.3360893779606129, -2.0980148024121217, 0.841001413487339],
+    [1.8297082751744902, -4.1895288106405495, 1.331651915586071],
+    [4.871076162216223, -7.109540712555052, 2.0101079678190925],
+    [9.707126359584372, -11.75298405599279, 3.0848215826740706],
+    [19.537650950423668, -21.256364631735174, 5.2926173119780024],
+    [-44.22201226419847, 38.29025437806672, -8.095129377986003],
+    [-27.87458858054621, 23.453958355900046, -4.828090167638476],
+    [-20.83904210054274, 17.024157986357505, -3.403676318885417],
+    [-16.701028002701573, 13.205436684488427, -2.548406216047006],
+    [-13.883008379857838, 10.57348251292902, -1.950526812708974],
+    [-11.777531877849162, 8.580240088361778, -1.4904477650057575],
+    [-10.087509067985051, 6.95780757439546, -1.1098558599696065],
+    [-8.639532069293354, 5.549803140412276, -0.774769053323519],
+    [-7.3130956828118805, 4.247398267586754, -0.4615405137032771],
+    [-6.005493391880741, 2.9574686335085256, -0.14987674538385035],
+    [-4.606950809123388, 1.579729038457458, 0.18223223463457636],
+    [-2.9732511767970835, -0.01905968713927036, 0.5643638011537485],
+    [-0.8791462567407726, -2.05001911479528, 1.0440777015163782],
+    [2.0954562810043504, -4.913590213891569, 1.7131110062067614],
+    [6.938368194817391, -9.570180575648196, 2.7957217798555134],
+    [17.20654537897955, -19.536293233433312, 5.1272082276828845],
+    [-46.76614568337615, 40.13242851271102, -8.281738054971402],
+    [-31.064490739483304, 25.948772996530725, -5.177876125861095],
+    [-23.96474695661975, 19.489892114712372, -3.75484516068411],
+    [-19.708629042100597, 15.579957415147874, -2.883569029009058],
+    [-16.78424853259662, 12.860687615298387, -2.268700185128798],
+    [-14.59328269690583, 10.794798050992465, -1.7936978550034663],
+    [-12.839209607676775, 9.116046289084894, -1.4009117151077035],
+    [-11.348457484246483, 7.668472953413115, -1.0565909838013727],
+    [-10.00138131624877, 6.344320612452166, -0.7373828744103759],
+    [-8.697593208487305, 5.0527278108419935, -0.42350668552366855],
+    [-7.330526327387192, 3.696474253498308, -0.093625311798063],
+    [-5.757383092102084, 2.1439137845450196, 0.28147655843500885],
+    [-3.7469978107833244, 0.17913644571239934, 0.7503821479367838],
+    [-0.8548738255673416, -2.6197250609160636, 1.4096141836259826],
+    [3.999532628355102, -7.298834038082392, 2.5033677182697414],
+    [14.901427771583657, -17.916469620715894, 5.003165912483759],
+    [-49.94430941759893, 42.52986079111651, -8.580131539996978],
+    [-34.60953485464154, 28.734899982126848, -5.579853666064819],
+    [-27.394328877300108, 22.19589733654475, -4.146475323660207],
+    [-23.00287703667433, 18.175495406377596, -3.254326278971213],
+    [-19.9662047962724, 15.360004571199024, -2.6197086747412195],
+    [-17.68894888735865, 13.217102546589883, -2.1278773842129057],
+    [-15.87261133989357, 11.479817899732433, -1.721339335257388],
+    [-14.342054560052306, 9.991294691064487, -1.3662964717958697],
+    [-12.977569567650844, 8.643963048232559, -1.0395000939946772],
+    [-11.68066354993325, 7.348774571438785, -0.721579334453543],
+    [-10.348879807379422, 6.0122773915337895, -0.392007102032366],
+    [-8.844570400137973, 4.50762635083821, -0.022602940927319935],
+    [-6.936310562996471, 2.619098828361984, 0.4351669191220316],
+    [-4.1563439127924955, -0.09563971074733602, 1.082378837075669],
+    [0.6863774331448552, -4.784783354140983, 2.186746188588081],
+    [12.457472068889889, -16.281612440327766, 4.9094399095713985],
+    [-54.065338163653735, 45.72199856242327, -9.027355351325197],
+    [-38.78439240341959, 32.029201080264556, -6.068599115103818],
+    [-31.369509053212468, 25.331677752079003, -4.607901060205578],
+    [-26.80660667236039, 21.165321094578935, -3.686722537416213],
+    [-23.640800065326765, 18.234904089821594, -3.0274682587390926],
+    [-21.270563609959733, 16.004857426622557, -2.515488955723743],
+    [-19.39145359549183, 14.203908886588353, -2.0927160736485266],
+    [-17.824644552455524, 12.67237643268621, -1.7249039831277209],
+    [-16.44902992846205, 11.30163364460335, -1.3886409304136633],
+    [-15.167137931913713, 10.00358496573046, -1.0647699903638745],
+    [-13.880552019702268, 8.688365091617055, -0.7335126713524737],
+    [-12.459376461998334, 7.236091836410455, -0.3681567248938482],
+    [-10.680619209731718, 5.4388791069190185, 0.07814999628473436],
+    [-8.063622411407328, 2.8433042846460297, 0.7089514467413096],
+    [-3.29325861099267, -1.8133142966799285, 1.8181991004260856],
+    [9.620227025556883, -14.462024863033786, 4.831625170944567],
+    [-59.65355613861558, 50.12592461499508, -9.692156423865068],
+    [-44.016110365637026, 36.170271695341974, -6.6989610833787845],
+    [-36.264423309928134, 29.19086279119304, -5.185164075298758],
+    [-31.46612804198488, 24.81854592267684, -4.221691305926218],
+    [-28.13972451422093, 21.740719567077754, -3.5297310132072295],
+    [-25.66333298395723, 19.40613442035462, -2.992232163281196],
+    [-23.720219293511196, 17.53363430133758, -2.549427228545428],
+    [-22.124572144610532, 15.95775512635441, -2.1660129133876227],
+    [-20.751976222068564, 14.567028300301653, -1.8180237776625154],
+    [-19.50477063496667, 13.27309289320653, -1.4862249176738451],
+    [-18.287977103267522, 11.988926171616088, -1.1513740913893562],
+    [-16.980553992798157, 10.602304679062117, -0.7883022742417483],
+    [-15.37626765588631, 8.920307543222883, -0.35329592830195067],
+    [-13.007048236823978, 6.501383914501635, 0.2546475064713654],
+    [-8.443101376762497, 1.9775882305835106, 1.3534579235141901],
+    [5.907031160365738, -12.138238524344555, 4.74182523168913],
+    [-67.72020950573886, 56.55516766856292, -10.71233057365531],
+    [-51.07920620082786, 41.773692463603815, -7.571574742447568],
+    [-42.751250914061515, 34.30131990579597, -5.961638160458909],
+    [-37.604068859797906, 29.618823767953714, -4.9332790817365355],
+    [-34.06251395515242, 26.338434738864635, -4.1950436655540635],
+    [-31.4598512898968, 23.87211162662799, -3.62333102990075],
+    [-29.456110303877015, 21.91928391415593, -3.154775764493334],
+    [-27.853066257700092, 20.303832552651873, -2.751999776984891],
+    [-26.520166156029905, 18.908718161126245, -2.3898548598476137],
+    [-25.358160376132137, 17.64370258017127, -2.048579383044669],
+    [-24.27499609610373, 16.423621982584137, -1.709097622005331],
+    [-23.159129559784112, 15.143754344022062, -1.3475267336221968],
+    [-21.826623750010654, 13.630126953138067, -0.9238569440358892],
+    [-19.85576854799475, 11.48261247853402, -0.34578522804121503],
+    [-15.795102240963475, 7.314184227197675, 0.7091999754158598],
+    [0.24153962618749633, -8.586762610150775, 4.571356740071764],
+    [-80.57813879961057, 66.87942204477702, -12.408484110099417],
+    [-61.64859409795386, 50.17250834089281, -8.90556273203323],
+    [-52.26289189845432, 41.788825081520294, -7.115912649240359],
+    [-46.54611604803137, 36.59584743294961, -5.979735616351306],
+    [-42.693006991688, 33.01467793765162, -5.170599469738795],
+    [-39.94215905848361, 30.37798109365139, -4.550132345029756],
+    [-37.908978060096466, 28.347164570854638, -4.047656133766038],
+    [-36.373321920895734, 26.726686099023624, -3.6218021116585866],
+    [-35.19495954187422, 25.389941427075907, -3.245136664044887],
+    [-34.273050331928566, 24.243457371112463, -2.8966452569764334],
+    [-33.52107662254918, 23.2042796572972, -2.55684369635233],
+    [-32.842084096858414, 22.176922693877266, -2.202579038048262],
+    [-32.083446791885606, 21.010416102757137, -1.7971006037402932],
+    [-30.899233275621597, 19.366894713468582, -1.2594954108461063],
+    [-28.03751525536849, 16.037977476582977, -0.3048329577996605],
+    [-10.685332659941427, -1.5266819900019164, 4.07641557262432],
+    [-105.25073763357211, 86.78932662118645, -15.76263732455458],
+    [-80.42596527239694, 65.11237930955555, -11.319010960013937],
+    [-68.72699145220865, 54.74067863347468, -9.13930702610476],
+    [-61.91702237041255, 48.565520706360644, -7.795020404816104],
+    [-57.57445021253007, 44.49218474552725, -6.863551905446659],
+    [-54.71091948925813, 41.66168802897604, -6.170214058182872],
+    [-52.84764432352576, 39.65346651275896, -5.627945476252586],
+    [-51.72914523882524, 38.237733804257125, -5.187340490625335],
+    [-51.2132442348686, 37.279606754618385, -4.81705843315027],
+    [-51.22249439776662, 36.69570063719263, -4.494587038295368],
+    [-51.71993512503706, 36.43071225672516, -4.200675301211509],
+    [-52.6932133369028, 36.4392210500373, -3.91412644792295],
+    [-54.131704621036334, 36.6569622336882, -3.6029412653602493],
+    [-55.94564216665821, 36.90947278995346, -3.198617806328603],
+    [-57.486046145944435, 36.4205683775243, -2.471436786840418],
+    [-45.975131278879886, 22.429676121426276, 1.7200883318092854],
+    [1.833882282818382, 14.510826669958973, -10.22412781317995],
+    [39.54286618071385, -27.988087365242738, 1.5545757729541945],
+    [38.085621463352496, -28.32902919835317, 2.29816366302945],
+    [36.29601222432392, -27.835737146602483, 2.657336474721969],
+    [34.8871104018051, -27.4144893706041, 2.918780573190076],
+    [33.91356113316906, -27.217154519918367, 3.15517411333572],
+    [33.359902637164616, -27.28358214002085, 3.396980533420712],
+    [33.21847358898655, -27.6391497336657, 3.662892316989918],
+    [33.50598363640499, -28.320154777049414, 3.969177303204499],
+    [34.27305647046862, -29.387429945126176, 4.3344631573048185],
+    [35.61991474825231, -30.943379145032218, 4.784327401991798],
+    [37.72926006602912, -33.16380046416065, 5.35852516027678],
+    [40.94090959862222, -36.3676911740446, 6.125889614495503],
+    [45.94258840889549, -41.19400694719452, 7.2213542805811635],
+    [54.36385332491958, -49.15064024250002, 8.960289236544215],
+    [71.3971557154897, -65.03595074348762, 12.341535770432937],
+    [-18.838093040631424, 24.991706092013917, -8.826100114278253],
+    [10.53432924932016, -5.80239894054721, -1.0050958711389322],
+    [14.586345737583454, -10.285135657344773, 0.20797235207355305],
+    [16.15756310670303, -12.221721272149995, 0.7928451147047652],
+    [17.077339855282702, -13.462075380193047, 1.196810639279816],
+    [17.801910252836088, -14.466110046244797, 1.5308057909661927],
+    [18.511851899377728, -15.416483691998797, 1.8392308176393488],
+    [19.30861942548893, -16.415560108871702, 2.1469741716776434],
+    [20.271366106411158, -17.54199822070959, 2.472814353403109],
+    [21.482610361505, -18.87600425358887, 2.835305389031609],
+    [23.04882073112138, -20.519132766652607, 3.2572429903694684],
+    [25.129122143572584, -22.621380766677508, 3.771603306593128],
+    [27.991344639937584, -25.43366218041948, 4.432894280602444],
+    [32.14697015013906, -29.433841685023513, 5.344304359489451],
+    [38.75434430318843, -35.70315015537057, 6.738688443832027],
+    [51.2952597175673, -47.49142694429706, 9.315099238849188],
+    [-24.964363010815486, 27.409759167413373, -8.12674773561826],
+    [-0.06846697322575446, 2.4403661911234504, -2.0525227854526538],
+    [5.163080082652096, -2.9407966877206517, -0.7122771115490821],
+    [7.583244957418619, -5.5174113186236236, -0.04380044185621061],
+    [9.083513321348926, -7.1721289562143244, 0.4028293369641782],
+    [10.22700467533128, -8.459931403880644, 0.7583426822286317],
+    [11.24624800136097, -9.608355639010933, 1.075855039471413],
+    [12.266685698169761, -10.739334861715433, 1.3836058176933217],
+    [13.377893754088173, -11.93916575332683, 1.7013539976511884],
+    [14.664358341335863, -13.288424625659738, 2.0472836039570526],
+    [16.226887489102356, -14.882483849332472, 2.4426405118036096],
+    [18.209008773901818, -16.856500304280964, 2.917277242224177],
+    [20.845839455029367, -19.431433421317646, 3.5197829846053903],
+    [24.57984459763065, -23.02289021955424, 4.341393782634635],
+    [30.405036598415432, -28.56472687542004, 5.587067325094513],
+    [41.29462552482696, -38.85153948720805, 7.870401683227249],
+    [-28.640295941747375, 28.924678542462708, -7.768752425093262],
+    [-6.620672989539605, 7.489391288613479, -2.7012573623197706],
+    [-0.7985522891768584, 1.7238315663137271, -1.319388982024067],
+    [2.0943938216726643, -1.204335765291233, -0.600083805645252],
+    [3.9280043310034873, -3.102995385064712, -0.1212237703720833],
+    [5.299770572902396, -4.548593921899531, 0.250755509906694],
+    [6.473777142367157, -5.794715423427443, 0.5741070352538694],
+    [7.594504689342184, -6.979349952519378, 0.8802679291695013],
+    [8.760815096208432, -8.196421658595007, 1.1904629766364903],
+    [10.060043898397536, -9.528532290336301, 1.5232147990943907],
+    [11.590773449736307, -11.068675843732967, 1.8992581510201407],
+    [13.488708069010007, -12.94478024557167, 2.3470365882820103],
+    [15.972665215603483, -15.363232138709684, 2.912410717814383],
+    [19.452751951286718, -18.71119467155027, 3.681465351454399],
+    [24.85455640926778, -23.863257904755212, 4.848898015919896],
+    [34.991282462676025, -33.479712814931005, 7.007804620330322],
+    [-31.270934481892855, 30.105931628140972, -7.565474784366582],
+    [-11.455740342705235, 11.187090706516129, -3.1770076896681534],
+    [-5.301974619377721, 5.241514223072759, -1.7857887407409103],
+    [-2.0811846729775607, 2.079618106935501, -1.0329483638950339],
+    [6.137403365201726e-10, -4.086931967709536e-10, -0.5276448529414275],
+    [1.545713250191801, -1.56934807298381, -0.1393307856884274],
+    [2.834272641634697, -2.891105775474677, 0.19152459874327357],
+    [4.023481769120708, -4.113686685384181, 0.4983225046221727],
+    [5.221309434711025, -5.3384026660311905, 0.8037394628970413],
+    [6.519638185724255, -6.651473705616984, 1.1270182534255764],
+    [8.017636930120448, -8.146166121898933, 1.4889717347481124],
+    [9.847742461415798, -9.947443340753516, 1.9175368837965967],
+    [12.220713604807786, -12.254794369837366, 2.4574272374308372],
+    [15.531471440812926, -15.442920247161071, 3.192847568026715],
+    [20.681125364313246, -20.368158355528863, 4.316698934715452],
+    [30.48685433219459, -29.710472410485664, 6.434078461219149],
+    [-33.44360693292792, 31.203749346689577, -7.460477287222583],
+    [-15.342900840790822, 14.156323314173322, -3.5602433982106256],
+    [-9.015910240923688, 8.1421908871385, -2.1752113928957577],
+    [-5.566203171481641, 4.821093586857225, -1.3998490956178782],
+    [-3.295731967394123, 2.6029786338723953, -0.87322954029995],
+    [-1.6079565235134319, 0.9301272345939313, -0.4694336792287754],
+    [-0.22176952980818712, -0.4596797781005551, -0.12959040352823065],
+    [1.026036084483218, -1.7183005374052267, 0.18019858388362364],
+    [2.249437901163734, -2.9516740174189797, 0.48348363782422327],
+    [3.544991245211882, -4.249764384322183, 0.8002593717232719],
+    [5.014145697906814, -5.707662643709035, 1.1517445885071027],
+    [6.788933607561425, -7.450015315536867, 1.5659216319172895],
+    [9.076898616903769, -9.673949172313218, 2.087301551242335],
+    [12.267482885353777, -12.75090458144259, 2.800146403612981],
+    [17.26254023968102, -17.543136626932473, 3.9007624872773365],
+    [26.988861240990406, -26.852442478881574, 6.028992339343408],
+    [-35.46516641482368, 32.35732574648837, -7.432816799437875],
+    [-18.70092815041021, 16.72896229433237, -3.8954115005792804],
+    [-12.280652194868269, 10.696246446475639, -2.5220038341889106],
+    [-8.662629710173963, 7.259125693723831, -1.7302149874041834],
+    [-6.243680435823847, 4.931125256744757, -1.1858736461833679],
+    [-4.439142765297791, 3.1708277883763496, -0.7678102042403568],
+    [-2.9689058803158592, 1.7192481811621154, -0.418319546661988],
+    [-1.6687850839804554, 0.4248280530449084, -0.10378049390162591],
+    [-0.42251354326980906, -0.8197200717025169, 0.19953481883229277],
+    [0.8697621683998303, -2.106830482659094, 0.5120879263388263],
+    [2.312635450484698, -3.5340947756124694, 0.8556196506104364],
+    [4.039995104731667, -5.227800607188598, 1.2585434623495584],
+    [6.259596311882662, -7.385899932313892, 1.765834967275519],
+    [9.361566648500576, -10.382641293907099, 2.463149760000723],
+    [14.265238304207344, -15.10299618824144, 3.554031008816004],
+    [24.093356307673464, -24.557254249358913, 5.733465945451252],
+    [-37.53150932093667, 33.66561921612052, -7.47596820260009],
+    [-21.79909222462592, 19.115642878285875, -4.211656389759981],
+    [-15.319021863601222, 13.079025966314163, -2.8495538129918336],
+    [-11.566371978435663, 9.547837038890195, -2.0439642668121296],
+    [-9.024157838267586, 7.126535531688186, -1.4837644729565813],
+    [-7.120192185560114, 5.289261411138876, -1.0521589832262601],
+    [-5.576235180240609, 3.780567091213719, -0.6926073905673823],
+    [-4.228585376188169, 2.450278953821071, -0.37197283293099626],
+    [-2.961140338502014, 1.191844150665034, -0.06678494793993285],
+    [-1.6731192719719037, -0.08754655196140028, 0.24343373510075292],
+    [-0.25737558628971074, -1.4873523591342808, 0.5807807804319335],
+    [1.423814488082911, -3.13695265301737, 0.974334038230506],
+    [3.5829314305568567, -5.238683199922436, 1.4701781685882886],
+    [6.617178384109478, -8.175388216667866, 2.1565778466964973],
+    [11.475813999524206, -12.868155508418782, 3.2475936254424855],
+    [21.5607513299487, -22.623087706748056, 5.515031238594994],
+    [-39.799487953334236, 35.22040918387467, -7.592038344063351],
+    [-24.839141152450903, 21.473640849234382, -4.531541956111291],
+    [-18.30231629765066, 15.424797111185956, -3.1766168657191907],
+    [-14.429854871673005, 11.806573168478243, -2.3572867268982547],
+    [-11.777840842291576, 9.299316581175509, -1.7815816469103674],
+    [-9.784903242264088, 7.390346641934014, -1.3364255859353151],
+    [-8.174251687585567, 5.827028605821839, -0.9662430658512648],
+    [-6.782660814855666, 4.460390976463526, -0.638324645502632],
+    [-5.4950393622991625, 3.185256793217538, -0.3295841042355956],
+    [-4.211697712618046, 1.910161064901724, -0.019888501464935683],
+    [-2.8251406701526234, 0.5357091981262244, 0.3128006716026702],
+    [-1.1942649298656587, -1.0700799294369459, 0.6980899178815508],
+    [0.9013894288745536, -3.1163511179058734, 1.1835765092067818],
+    [3.876299436400887, -6.002395370038554, 1.8615459675951866],
+    [8.727624957877648, -10.702307326163433, 2.9603835984392757],
+    [19.226088985394345, -20.916973705462798, 5.354504844604467],
+    [-42.42861238328669, 37.128970687324326, -7.790913876250579],
+    [-28.0019654862038, 23.944523666397185, -4.876206418722941],
+    [-21.387524509738167, 17.856694738801306, -3.521148056440936],
+    [-17.394944883639557, 14.146250126255147, -2.6859089671278853],
+    [-14.637045231003643, 11.552590429742686, -2.0936328634373833],
+    [-12.559785747389506, 9.572481441186035, -1.6340754908093682],
+    [-10.886499780556191, 7.954672057584509, -1.2522955155622102],
+    [-9.453713025593546, 6.550615932382272, -0.9158348823677434],
+    [-8.147150470002703, 5.256244174962197, -0.6019270571634483],
+    [-6.869111811053565, 3.9821516268077968, -0.290928643830707],
+    [-5.514175911158577, 2.6310979377351615, 0.038698304316527425],
+    [-3.94056191868184, 1.0708856492126642, 0.4165703606240781],
+    [-1.9200230942935304, -0.9141717958902709, 0.8916989174757086],
+    [0.9861733968504948, -3.74525365053649, 1.5613732018757072],
+    [5.855130472549941, -8.475414395765734, 2.673388828330465],
+    [16.95299340841813, -19.336440770481513, 5.239920207834045],
+    [-45.61873092379874, 39.539224244164124, -8.092357062286702],
+    [-31.484909293601348, 26.68392472806053, -5.2697654324358645],
+    [-24.748159349039142, 20.511209666747924, -3.90375837704192],
+    [-20.620532407060075, 16.691126040829214, -3.048071473527468],
+    [-17.751674518594236, 14.002900950644946, -2.4366084218041193],
+    [-15.589431602662147, 11.947485301406196, -1.9607962545151147],
+    [-13.85498224801957, 10.272816302504319, -1.565853209710842],
+    [-12.383334024934179, 8.829547702734885, -1.2193087485885277],
+    [-11.060255272365008, 7.513953126626359, -0.8985478317356905],
+    [-9.789964859934244, 6.238542910527071, -0.5843770518314451],
+    [-8.470689882658156, 4.909546585869419, -0.2560296069484495],
+    [-6.963958691270873, 3.398051277024588, 0.11537264635964335],
+    [-5.037759181228003, 1.4854545302349484, 0.5794489334216251],
+    [-2.2272519748089583, -1.2727659760379502, 1.238729112169576],
+    [2.6539392197520377, -6.03288217638152, 2.3652687016969742],
+    [14.597758295270417, -17.782252745110753, 5.163048601549789],
+    [-49.66371819729457, 42.68057406690625, -8.531893239481597],
+    [-35.547470763397136, 29.89802943945291, -5.745064320475895],
+    [-28.613131754280797, 23.568956739492076, -4.352384968721997],
+    [-24.318012410258014, 19.606634522791957, -3.4686236095981378],
+    [-21.323015464557763, 16.806522162017398, -2.833359429114858],
+    [-19.069701176844877, 14.666269395592792, -2.3381181111752904],
+    [-17.273497718621368, 12.929709428254734, -1.927592766978738],
+    [-15.765946373178924, 11.444894185581619, -1.568921770300686],
+    [-14.431638911262564, 10.10728108860087, -1.2393898587622523],
+    [-13.176026417851777, 8.830592846253282, -0.9200900138354509],
+    [-11.901434827730622, 7.524813900222041, -0.5910663768683899],
+    [-10.476315218851243, 6.067428477954502, -0.2248270110126004],
+    [-8.67331114956578, 4.2444630009699935, 0.22721530798856218],
+    [-6.007512738036666, 1.5937143353004188, 0.871513271256307],
+    [-1.1627639055165315, -3.162380867452852, 2.0082076802757887],
+    [11.954700911476548, -16.12042121070971, 5.115465700081851],
+    [-55.055698612934755, 46.94733261279059, -9.174574631377748],
+    [-40.591652958104895, 33.907940854464904, -6.354155076514433],
+    [-33.33567954044514, 27.309258353845877, -4.91086016589003],
+    [-28.814859418670366, 23.14919718777886, -3.9866001555498305],
+    [-25.664948169989053, 20.20695260285914, -3.3199198770790224],
+    [-23.308404537882655, 17.96549245104923, -2.8001339236649176],
+    [-21.449123678057024, 16.159390049988204, -2.3703629692997517],
+    [-19.91213924548911, 14.6313355707098, -1.9967800444334818],
+    [-18.57908173759576, 13.274284784476004, -1.6561990147743082],
+    [-17.355526604408052, 12.002044430079389, -1.329727893786218],
+    [-16.147557939285555, 10.727852708428555, -0.9980655506008931],
+    [-14.832598176816878, 9.337174007310876, -0.6353828803321067],
+    [-13.198181817239837, 7.629667235292751, -0.19602356383962571],
+    [-10.761598509465365, 5.145864030829775, 0.4258487954967952],
+    [-6.072861893640926, 0.48407757299964965, 1.5588090721895058],
+    [8.621726828057664, -14.090977516885982, 5.079231729924245],
+    [-62.734860731204236, 53.10184332107254, -10.1497297779711],
+    [-47.34350646872087, 39.295420694073336, -7.1923020299661955],
+    [-39.548281729489354, 32.232928822458184, -5.658418705241623],
+    [-34.697923597713874, 27.77851716848216, -4.67250022807873],
+    [-31.34291173034179, 24.64267563008673, -3.961602689161191],
+    [-28.864410830331266, 22.274350935660525, -3.4090001481019483],
+    [-26.944778697708163, 20.390051730746915, -2.954474366095711],
+    [-25.397519296338295, 18.822555534296036, -2.5622785896697127],
+    [-24.09863187457201, 17.459729658711513, -2.2081600377846953],
+    [-22.9524495424399, 16.213910926914657, -1.8728154125250986],
+    [-21.868325998628446, 15.000682772482994, -1.5372613483631103],
+    [-20.733671003030206, 13.71375516701396, -1.1771916514164058],
+    [-19.35870282281191, 12.17262914449724, -0.7509797817713257],
+    [-17.30139660037245, 9.955055896815015, -0.1607983997449949],
+    [-13.046567899875503, 5.600227098680458, 0.9353757339241212],
+    [3.6408133872961765, -11.05992077037738, 5.000983220483852],
+    [-74.83404348027872, 62.88264831132485, -11.756089436211035],
+    [-57.36626107086849, 47.315296851553995, -8.466220456769024],
+    [-48.59466576907309, 39.40490851516124, -6.764370164251363],
+    [-43.21294283901191, 34.47076992497372, -5.67667645845479],
+    [-39.564144544125305, 31.049794037963427, -4.8983667400516095],
+    [-36.943076827627024, 28.51806583326282, -4.29911263022719],
+    [-34.991010367625236, 26.55685945372978, -3.8119104986778183],
+    [-33.50107907840054, 24.9808328644532, -3.3972981594313563],
+    [-32.34018015504075, 23.668907606950604, -3.0289082418561377],
+    [-31.4110989676533, 22.53048541888373, -2.686325171358342],
+    [-30.628475589834476, 21.483579414073194, -2.350326743263892],
+    [-29.89401605727936, 20.43157916474735, -1.9976223312980166],
+    [-29.049513247585296, 19.21839438379744, -1.5904571405340293],
+    [-27.730528258339895, 17.48788110007529, -1.0437981104975518],
+    [-24.589999806295307, 13.93333438721476, -0.0488550115147623],
+    [-5.890390854080908, -5.039841056689219, 4.682866493451585],
+    [-97.76029824014144, 81.52551196910696, -14.899307121328063],
+    [-75.0230641137399, 61.47419807739829, -10.755982883581737],
+    [-64.14319825219232, 51.73462204680848, -8.692876027049937],
+    [-57.75454967480168, 45.88716238411849, -7.410258004418978],
+    [-53.649825278902284, 42.00400990112266, -6.51631492655392],
+    [-50.91852460083877, 39.286366912074826, -5.847431772188657],
+    [-49.11560815042891, 37.339834792850894, -5.32143578351474],
+    [-48.00094582961003, 35.94684312022705, -4.891339411182906],
+    [-47.43822317477964, 34.97773530196125, -4.527051036907723],
+    [-47.349594968716524, 34.34995691554514, -4.206657741942202],
+    [-47.692102684967445, 34.00533731283052, -3.911066548301279],
+    [-48.44054605549293, 33.89125363321489, -3.618826016889309],
+    [-49.56046189096371, 33.92920573404941, -3.297028853994304],
+    [-50.91580629817157, 33.914947507225705, -2.874415662079127],
+    [-51.74034095897055, 32.983798488791926, -2.1080284532808102],
+    [-36.67312747772663, 15.776383685295206, 2.7757253012949064],
+    [5.8085095320617235, 10.064126520400682, -8.719623045378095],
+    [39.19299508490465, -27.076436626139284, 1.4555765980565447],
+    [38.42604082310749, -27.94186809212301, 2.2445978871093204],
+    [37.05274459040462, -27.779762206923255, 2.6370274294269764],
+    [35.9187937311904, -27.583907794955767, 2.9233963585031026],
+    [35.14018294765688, -27.549829497999063, 3.179307377932124],
+    [34.73292340184759, -27.740581339548797, 3.4370192884689734],
+    [34.70697927955375, -28.19483508612468, 3.7163531381442567],
+    [35.08976269426711, -28.957038242592017, 4.034321264871408],
+    [35.93905712299582, -30.09339334961833, 4.4100763160517635],
+    [37.36064934011784, -31.710229140296363, 4.869610216313849],
+    [39.542706160282776, -33.9867620608148, 5.453075463746447],
+    [42.832237329934216, -37.24588175014796, 6.229806373348541],
+    [47.929491553713035, -42.132611648058614, 7.335579474339857],
+    [56.49230598569359, -50.167710413593966, 9.08762469339759],
+    [73.80253303074814, -66.18862676334936, 12.490505147771174],
+    [-14.723467369246531, 21.532130290471752, -8.065451817338062],
+    [12.154246350305122, -6.660560039962441, -0.8911764668454808],
+    [16.13531608125375, -11.049093180207915, 0.2950925480987425],
+    [17.71720861843828, -12.985468750852345, 0.8770267025330716],
+    [18.664311427918193, -14.245674497349947, 1.2833695845375963],
+    [19.42046688886597, -15.275240539267843, 1.6214614069434137],
+    [20.163352143575846, -16.25316749794659, 1.9347433583168712],
+    [20.993869377958465, -17.28085259084917, 2.247810328385882],
+    [21.99154576861266, -18.436981935993945, 2.5793895785676524],
+    [23.23998028300183, -19.802409035750607, 2.9481225142510947],
+    [24.84770345254249, -21.480061630555095, 3.3770351843457025],
+    [26.977652511807943, -23.622537801074408, 3.8995621345026192],
+    [29.90506125796644, -26.485870011864577, 4.5711325662496956],
+    [34.157283863937714, -30.559070818504114, 5.496975042039165],
+    [40.93310731583033, -36.95241737344941, 6.9153608354215885],
+    [53.8571814952185, -49.02184209423179, 9.545608599990297],
+    [-20.59557040492732, 24.070640532362784, -7.4976958735951795],
+    [1.8217686482083426, 1.301275050074292, -1.8923521457954893],
+    [6.842826610221447, -3.8834116996543435, -0.5942731898018786],
+    [9.200208692730957, -6.39900061923433, 0.06100951275213304],
+    [10.682292454340212, -8.035028942548815, 0.5036691378550553],
+    [11.826861085882166, -9.322537862130224, 0.8592187290996457],
+    [12.856675228023192, -10.4797654843454, 1.178754799957796],
+    [13.893258080165786, -11.624892881608154, 1.4897116078379733],
+    [15.02478654597283, -12.842805387626859, 1.811545915428252],
+    [16.335802823170436, -14.213939258251106, 2.1624018858306346],
+    [17.928523473609385, -15.834608271541812, 2.563696511173578],
+    [19.949837392469053, -17.84239031262274, 3.0457326415251895],
+    [22.641931522075787, -20.46344957306708, 3.6580743821359984],
+    [26.46291172954664, -24.12510836748522, 4.4941760845772665],
+    [32.44733005556417, -29.79224410707039, 5.764969620883297],
+    [43.714070087909285, -40.37431458982854, 8.106704202861282],
+    [-24.315684841589857, 25.74849776322936, -7.20660208093888],
+    [-4.453783334760791, 6.129835102467246, -2.506008075138686],
+    [0.9995045296245757, 0.6630360818147473, -1.180400750472128],
+    [3.7587483097262804, -2.1552496203697937, -0.4817320904515204],
+    [5.532170255536952, -4.004145865633071, -0.01204654045565743],
+    [6.877075092227465, -5.428160675102887, 0.35629605232526446],
+    [8.042108935903942, -6.668183626328246, 0.6791145221319123],
+    [9.1643717567329, -7.855992624472481, 0.986633296559163],
+    [10.33925165697975, -9.08255409899489, 1.299502254575931],
+    [11.652880476330806, -10.429398019182145, 1.6360399541528992],
+    [13.204415653288269, -11.989875024257937, 2.0170527104806966],
+    [15.13229944240672, -13.89394814650238, 2.471374139987622],
+    [17.661888822595706, -16.352967342431786, 3.045812222548744],
+    [21.218445476890885, -19.765776228823825, 3.8286984509523894],
+    [26.76785423098803, -25.038665955678212, 5.020932659429473],
+    [37.272515655608785, -34.952777588412324, 7.239863868893756],
+    [-27.095092586804668, 27.104275527450714, -7.050471223696163],
+    [-9.11075272466051, 9.691092368961307, -2.959324227569764],
+    [-3.3791969465855205, 4.0773203893268075, -1.6290940401932905],
+    [-0.3443242695604711, 1.0598740424481201, -0.9021178920027045],
+    [1.6382188416945083, -0.9435846225309182, -0.41017681627065766],
+    [3.128798612259713, -2.4711796483545543, -0.028877100853319158],
+    [4.387537928626331, -3.771692750954768, 0.2988413440878203],
+    [5.5627285121432575, -4.9861842021266565, 0.6050578081715734],
+    [6.756980774855824, -6.211760547735974, 0.9116843329201552],
+    [8.059519303498528, -7.532545514324161, 1.2375886374010494],
+    [9.569021590553412, -9.041412559606316, 1.6035287286846767],
+    [11.419849340566136, -10.864813844149747, 2.0377417871678807],
+    [13.828653590959917, -13.206815172370495, 2.585830247371334],
+    [17.20507380361055, -16.453664558031015, 3.3342432053782365],
+    [22.490912148024236, -21.49423820724706, 4.482310905411122],
+    [32.660579091601285, -31.138026151944917, 6.661447381880355],
+    [-29.432225283054564, 28.358879796267345, -6.981062206130871],
+    [-12.907647809139299, 12.589309507445742, -3.3299638274821333],
+    [-7.010730821461446, 6.9098491837561085, -2.006249091548511],
+    [-3.7663606581900115, 3.7461726116220553, -1.2589488144912075],
+    [-1.6142299646381737, 1.6179016307799483, -0.7480268864195103],
+    [9.931618695442667e-12, -5.250521915641936e-12, -0.35355339059261287],
+    [1.3400073911453132, -1.3565743359057916, -0.01899099784382989],
+    [2.5600249979652485, -2.596987270731149, 0.2883984867349689],
+    [3.7684702163741246, -3.823010505500804, 0.5914417095586343],
+    [5.0584317481091015, -5.121996148978401, 0.9096814648899765],
+    [6.529904370203425, -6.587968752299471, 1.2641685236786542],
+    [8.315961106822298, -8.34644776527471, 1.6830812694944572],
+    [10.62931319410268, -10.59866030544467, 2.211741687334129],
+    [13.873737931376322, -13.727436574140166, 2.936642875671991],
+    [18.992831490712426, -18.62877649571429, 4.060802928373008],
+    [29.083771079296795, -28.246596468299952, 6.253222340431813],
+    [-31.60047089615377, 29.64166418319776, -6.9803970576877425],
+    [-16.217354251874486, 15.1223769721888, -3.6574883851550215],
+    [-10.221564486446287, 9.418393523925703, -2.3443639759734447],
+    [-6.816097618834804, 6.143495069168056, -1.581597009961087],
+    [-4.524493127334968, 3.911824547022068, -1.054208995137736],
+    [-2.802748112084643, 2.213440544368152, -0.6468318560244363],
+    [-1.3878425813751998, 0.802247755675866, -0.3040535422628111],
+    [-0.12409529271435453, -0.46710905467061326, 0.006693978957831638],
+    [1.0996569454348986, -1.698305150187484, 0.3085535651528248],
+    [2.379597281392173, -2.9812075214600124, 0.6215814962495458],
+    [3.817884761841759, -4.411756075550803, 0.9672758206715918],
+    [5.5481511265185395, -6.116249891483128, 1.3741138568285847],
+    [7.78227596346993, -8.296030080547967, 1.8877657343523409],
+    [10.92440348288032, -11.336563875831049, 2.596092304333245],
+    [15.937422045213076, -16.15825642306378, 3.7097050805816822],
+    [26.13357545728395, -25.931023540265723, 5.956604976855457],
+    [-33.782320669317905, 31.048716244257808, -7.043415661426546],
+    [-19.282055996761294, 17.481466364046085, -3.96814481336381],
+    [-13.216822185478678, 11.765264821023676, -2.6649312181672493],
+    [-9.679199603587715, 8.397941979333048, -1.888819930442982],
+    [-7.268983087810353, 6.076492722277159, -1.3463575005479471],
+    [-5.452970260462318, 4.305243715208612, -0.9263398406099844],
+    [-3.969677124881133, 2.841408847361355, -0.5744974909846876],
+    [-2.6636618176819837, 1.5408198117161178, -0.2587159395033426],
+    [-1.4233910574485145, 0.30002868098527713, 0.04399855164994234],
+    [-0.15122605331094438, -0.9717975690020282, 0.35386220417593917],
+    [1.25722057049443, -2.3724615983933455, 0.6927789207740719],
+    [2.937563643633551, -4.030266053004494, 1.0897621250364689],
+    [5.10313333866893, -6.148868256441501, 1.5913084615061133],
+    [8.162571579660392, -9.12069170641118, 2.2876569446479507],
+    [13.111008169119248, -13.904221166306979, 3.400324373784207],
+    [23.567153916498523, -23.989461324925724, 5.739366200643418],
+    [-36.12803798959721, 32.67065452267905, -7.172961163643581],
+    [-22.287241192296793, 19.812306339189863, -4.282721510539956],
+    [-16.15564704915197, 14.07601446490718, -2.9853970321709187],
+    [-12.498992073241968, 10.62225296120638, -2.1958338736851895],
+    [-9.981755944882401, 8.217124239801455, -1.638448400016796],
+    [-8.080030254402017, 6.37696509824502, -1.2055606068945766],
+    [-6.533477358492238, 4.861607172333416, -0.8438486108363756],
+    [-5.187054024019142, 3.5280589228016743, -0.5216341732905009],
+    [-3.9299759882000354, 2.274042839393853, -0.2162735116098099],
+    [-2.6650084219583396, 1.0094532148686413, 0.09223361283617316],
+    [-1.2866242283505724, -0.3642438232867328, 0.42591547367274507],
+    [0.3439795152597794, -1.978086374696231, 0.8143981021840987],
+    [2.445251834189587, -4.04088670720037, 1.3055078941281748],
+    [5.435230962260995, -6.9566494716430824, 1.9928802877810103],
+    [10.351114098277852, -11.733624942311067, 3.112041452058294],
+    [21.21856927065568, -22.289803149828366, 5.582698396807905],
+    [-38.79171302685693, 34.61293215140512, -7.379107386984864],
+    [-25.40226783518363, 22.24837031835099, -4.621082175649938],
+    [-19.185816842440946, 16.467296576186634, -3.3227192639517003],
+    [-15.409820110617833, 12.922054375366317, -2.51756998011096],
+    [-12.788893632328945, 10.43246801844644, -1.9441490257979441],
+    [-10.805267625890416, 8.523404975563395, -1.497453789915108],
+    [-9.198518392293979, 6.955953568758424, -1.12478859989188],
+    [-7.813375746501785, 5.587607449183406, -0.7947519900166838],
+    [-6.539859189479363, 4.317226186852641, -0.4850372575712446],
+    [-5.282377636174009, 3.056494950344984, -0.17608703218628446],
+    [-3.936500914782771, 1.7081721122393867, 0.15380518068801172],
+    [-2.3615265892669792, 0.139815905142639, 0.5345447447919498],
+    [-0.3312991096812749, -1.8642597318870733, 1.015519756368109],
+    [2.591508452114585, -4.727164850471378, 1.6950973547072192],
+    [7.503450313570623, -9.523820121873708, 2.8267927945322797],
+    [18.954217203483207, -20.732904385904607, 5.475469781395207],
+    [-41.96562321985763, 37.01936953144234, -7.681217732881771],
+    [-28.813897998650827, 24.937979795696247, -5.0061805945845315],
+    [-22.472052536780577, 19.0695642271966, -3.6965740045252273],
+    [-18.56299777661562, 15.41644619545513, -2.871491046102207],
+    [-15.833574187877337, 12.834535601039462, -2.279485635449004],
+    [-13.767186304395771, 10.852337042433325, -1.8171352647678458],
+    [-12.101210935867572, 9.230121191042825, -1.4319152325302926],
+    [-10.678970425147305, 7.824708047038959, -1.0924480515682444],
+    [-9.390658427863496, 6.535452454998082, -0.7766213108452524],
+    [-8.14259765941472, 5.276001331055444, -0.46535036579857786],
+    [-6.833465055309444, 3.9521589073826595, -0.1376241037778689],
+    [-5.324242947800821, 2.4333329999996636, 0.23602442136519938],
+    [-3.3829218079158268, 0.49888461671231116, 0.7060582631489267],
+    [-0.5462620155548249, -2.2982639869497654, 1.376378830980038],
+    [4.378035390261198, -7.129182216422519, 2.5242728904180174],
+    [16.64089110744489, -19.22837641797651, 5.411279950998573],
+    [-45.93046158095672, 40.1109146679178, -8.113593451257591],
+    [-32.76878752631646, 28.07858098925654, -5.469478943964811],
+    [-26.232450549400106, 22.056182067141027, -4.133785144703103],
+    [-22.160302565589966, 18.264504916503714, -3.2814987863448564],
+    [-19.30819790742677, 15.573805826750446, -2.6664707839565946],
+    [-17.1532153326505, 13.509252109263073, -2.1853763659083683],
+    [-15.427193711614729, 11.827196016921075, -1.78520989842625],
+    [-13.970218792757889, 10.382069534391674, -1.4342542275188648],
+    [-12.671533100008629, 9.072639470540379, -1.1103540747484086],
+    [-11.438888814779212, 7.813992476101874, -0.7947730487410489],
+    [-10.175038670751867, 6.515749815911695, -0.4673443043842901],
+    [-8.746884512161136, 5.052920298068304, -0.09984153000511169],
+    [-6.923771144733309, 3.2065400885399282, 0.3577945178206878],
+    [-4.218215353115393, 0.5076372814107589, 1.0143894261836668],
+    [0.6909898709577813, -4.338176838319519, 2.176351391112032],
+    [14.104737521959462, -17.666678324460353, 5.3862545189296425],
+    [-51.15346109485018, 44.26497047917485, -8.738613222101375],
+    [-37.64938341048296, 31.97810732435561, -6.060960895546204],
+    [-30.803884780287667, 25.69569368800217, -4.676549895198449],
+    [-26.514467716090422, 21.713111095493492, -3.7852253799564815],
+    [-23.512745246996065, 18.884913240174992, -3.139873882192774],
+    [-21.25764760954968, 16.722304850197784, -2.635093347609012],
+    [-19.470094851301287, 14.972866225755272, -2.2164035012918513],
+    [-17.984077068489185, 13.486018903857525, -1.8511982227635222],
+    [-16.686196265462765, 12.15833177738985, -1.5169238535096152],
+    [-15.484632083691146, 10.905298187510075, -1.1949425855444302],
+    [-14.286188379761176, 9.640153890197903, -0.8658442451640045],
+    [-12.966699838511907, 8.24601963841167, -0.5031338383931825],
+    [-11.30813143369696, 6.51573506631676, -0.059341695469813445],
+    [-8.81645354915668, 3.9750612847171345, 0.5754883233286957],
+    [-4.025510552655687, -0.8067255160380797, 1.7393240110541273],
+    [11.029111743694301, -15.851280657627997, 5.393509112802343],
+    [-58.523659666367635, 50.20839295232711, -9.679841291460582],
+    [-44.14635219856908, 37.1946495165033, -6.8721968745077655],
+    [-36.789705660831444, 30.469953060345794, -5.401254070034546],
+    [-32.18606869611386, 26.205205065487583, -4.45073219262711],
+    [-28.98764458366217, 23.190633390305518, -3.762791089040846],
+    [-26.614678198410726, 20.9053971572849, -3.226346633749344],
+    [-24.767841162593324, 19.080011485953886, -2.7837699656894914],
+    [-23.270297969729718, 17.554563540387704, -2.400655123355416],
+    [-22.00345934049569, 16.220940402625867, -2.0534778696779856],
+    [-20.874607450089016, 14.993543664242415, -1.7232910799150878],
+    [-19.794196003338037, 13.788432027908865, -1.3911410065914476],
+    [-18.648559769278933, 12.497676142963384, -1.0322680885146904],
+    [-17.242455689814513, 10.934490572282945, -0.6034542814100307],
+    [-15.115676206269917, 8.656232843478074, -0.001680888593339819],
+    [-10.702510875486512, 4.140411516185202, 1.1318995966659398],
+    [6.600717775353663, -13.258072633129025, 5.399239706148345],
+    [-70.05244260777108, 59.594694164300705, -11.22206230054397],
+    [-53.74684073049039, 44.93239884467194, -8.10203030028791],
+    [-45.47272882412815, 37.4046712966723, -6.471354503588912],
+    [-40.36622895153267, 32.68258493104212, -5.423414416220581],
+    [-36.88788914670215, 29.394735366257365, -4.670657612993605],
+    [-34.377504134782725, 26.95189218689188, -4.089201278320318],
+    [-32.49723334019094, 25.051318119733683, -3.6150021654731845],
+    [-31.051053642489936, 23.515949180869846, -3.2101398716299485],
+    [-29.911790600391097, 22.2292419717612, -2.8491094414191855],
+    [-28.985359778279122, 21.102996895473566, -2.511973297587675],
+    [-28.18755365772387, 20.056056991422427, -2.179697765603458],
+    [-27.419182984453013, 18.990841854593384, -1.8288151087084805],
+    [-26.517473813586328, 17.74665821928304, -1.420566595754098],
+    [-25.10152231207326, 15.950257817565548, -0.8658793878091409],
+    [-21.73775669804394, 12.202878640516847, 0.1668723358797739],
+    [-1.7933245634774542, -8.121256201011628, 5.245654316113084],
+    [-91.76857231512771, 77.39330175779286, -14.227049280093166],
+    [-70.60640406876873, 58.5609799546614, -10.30932240901408],
+    [-60.36301849171595, 49.3090560524107, -8.336186847921896],
+    [-54.30977964446061, 43.72041910701994, -7.102127090863563],
+    [-50.40050726740487, 39.99198698277476, -6.238385007667183],
+    [-47.78426341189042, 37.370652916120264, -5.589751987847935],
+    [-46.04239949568491, 35.48226850555461, -5.0778175058281665],
+    [-44.947343123599794, 34.11907707875387, -4.657492648859779],
+    [-44.368341630144876, 33.156089938073535, -4.299705767587536],
+    [-44.22873310554278, 32.51233199880778, -3.983084544319884],
+    [-44.483145894112894, 32.12880946871097, -3.688761677788663],
+    [-45.09989478735553, 31.949440765285527, -3.395218718826368],
+    [-46.03168099351666, 31.887989230166102, -3.0689803769097637],
+    [-47.11607185072317, 31.722144492338543, -2.6366312580380145],
+    [-47.507790678737045, 30.520715883285295, -1.8428881664761014],
+    [-29.36238080730386, 10.346671333434651, 3.7316242508977253],
+    [9.722380439903334, 6.103987466449826, -7.515312715350286],
+    [39.523297438952476, -26.75676816836395, 1.4225422339844056],
+    [39.16954078304249, -27.933585449449893, 2.235801387509203],
+    [38.059490225782945, -27.9843196938926, 2.649640930461526],
+    [37.10417920645703, -27.938937952948407, 2.953119542962469],
+    [36.455380555036555, -28.017269821192176, 3.2230428827229036],
+    [36.14871585991685, -28.296492436684893, 3.492665670728108],
+    [36.205763755408775, -28.823907037769587, 3.7825293344862887],
+    [36.66150117092917, -29.64964763422926, 4.110187091663716],
+    [37.57933996278765, -30.844085036820967, 4.495243027539589],
+    [39.07016958232762, -32.51714989274602, 4.96413773308498],
+    [41.32812303243927, -34.85199609872962, 5.557583353457237],
+    [44.70923805214597, -38.177155839867424, 6.345797238760184],
+    [49.930927922615645, -43.14811947125681, 7.466289722926748],
+    [58.69230127604809, -51.3108514432809, 9.240611649846585],
+    [76.4165263472825, -67.59128022043707, 12.688950337832575],
+    [-11.364264725615193, 18.690071763543408, -7.430380901932232],
+    [13.742012742253484, -7.526210192044627, -0.7755404104765622],
+    [17.644796458967274, -11.816786682957346, 0.3837606979833641],
+    [19.224793149087823, -13.744871865130794, 0.9619561690336882],
+    [20.187229150878856, -15.016982712644134, 1.3698186618310366],
+    [20.96475475332253, -16.06520431057339, 1.7112280029933842],
+    [21.732589750341635, -17.06520762281885, 2.028721992669264],
+    [22.591399061233524, -18.11764720032819, 2.346644702399668],
+    [23.62142321145798, -19.30148110856307, 2.683719552856894],
+    [24.907765943502678, -20.69852854373058, 3.0587361462228677],
+    [26.561584119916752, -22.413535077770835, 3.4950355478912645],
+    [28.750444405872614, -24.602373788599113, 4.026639789072079],
+    [31.75832727646598, -27.52734297042976, 4.710120975082545],
+    [36.13109967641523, -31.69090567527388, 5.6531960205786875],
+    [43.114609732443796, -38.23821158195307, 7.100696405279329],
+    [56.50074175187037, -50.652855905919886, 9.7965008979789],
+    [-17.20096898633441, 21.440293994153496, -6.988255786647127],
+    [3.4966164826698143, 0.2899358931994924, -1.7491485013057553],
+    [8.379419774490854, -4.7492377893789195, -0.48558377886689014],
+    [10.69235801555664, -7.217235033383294, 0.15860847444240428],
+    [12.162754848836892, -8.839630933662885, 0.598149796812343],
+    [13.310881159910636, -10.128829301592964, 0.9540735226774077],
+    [14.352739634786452, -11.295900900630064, 1.2757808945590023],
+    [15.407436155412684, -12.456348509765405, 1.5900690359767564],
+    [16.562682337961846, -13.694311532244448, 1.916197774582183],
+    [17.903810477374616, -15.09061470471226, 2.2723573468369223],
+    [19.535156520617043, -16.74304135549073, 2.680225678822846],
+    [21.607800177225567, -18.79223465728978, 3.170671072684133],
+    [24.372179413456763, -21.47052077358549, 3.794410693602684],
+    [28.30418326821972, -25.218720458227786, 4.647457607315493],
+    [34.484135735364696, -31.037134677187392, 5.947570756946533],
+    [46.196076707034756, -41.96658610872904, 8.356901523492507],
+    [-20.899993838250833, 23.214808333941296, -6.749173274196642],
+    [-2.667770335208591, 5.0110886923804765, -2.3440185990743316],
+    [2.5537206658466487, -0.25161748489700114, -1.0603550680942182],
+    [5.23362294091892, -2.9969227522649087, -0.3769454912584783],
+    [6.972593703123894, -4.813621420695804, 0.0861341097680313],
+    [8.305342298936928, -6.226052407569317, 0.45228723558331385],
+    [9.471309957961742, -7.466514267021964, 0.7754689505342335],
+    [10.603238741098867, -8.66261880388598, 1.084991409523123],
+    [11.794811540160978, -9.90359673969883, 1.401116244848136],
+    [13.132167082263704, -11.27075262642376, 1.7420902700961653],
+    [14.716031514341957, -12.858503565675212, 2.128906252095991],
+    [16.68857528989731, -14.799583351917537, 2.590913390311479],
+    [19.28292267698299, -17.311262569956405, 3.176039962044674],
+    [22.94150812465922, -20.805699731794515, 3.975172055421001],
+    [28.67528866366625, -26.224816582271842, 5.196168247119373],
+    [39.614216817570686, -36.48582658631779, 7.48361429834103],
+    [-23.730878190427646, 24.6694429126241, -6.626928782163754],
+    [-7.181508646559943, 8.46175737441178, -2.7793843716166102],
+    [-1.761678913324858, 3.1015564537220257, -1.4975942376567626],
+    [1.147971887916256, 0.18699042301118637, -0.7901918197653183],
+    [3.069641181817855, -1.7657402800449353, -0.3078825833240908],
+    [4.529861484793666, -3.268106044751068, 0.06878459144520967],
+    [5.776295337537445, -4.558861086907246, 0.39497373779694644],
+    [6.951207647495115, -5.7740036927829, 0.701767480791388],
+    [8.154195705635718, -7.008008283441396, 1.0105454709808368],
+    [9.4735328286915, -8.344072586864716, 1.3399798098504543],
+    [11.008773097380306, -9.875633722801867, 1.7109249776253137],
+    [12.897468024408791, -11.731545343304694, 2.152069386624839],
+    [15.363574994191803, -14.12152049230844, 2.7100926296442314],
+    [18.833536378889544, -17.445011220729874, 3.4739967802337484],
+    [24.294509114776876, -22.62723424028203, 4.650272664876193],
+    [34.896746104853136, -32.62251068224819, 6.899651446592016],
+    [-26.143089979555683, 26.015724071194885, -6.58239194421887],
+    [-10.878072824738279, 11.284832014997548, -3.1375350419731434],
+    [-5.317622317189849, 5.872837177085713, -1.8639984381384866],
+    [-2.2282362354297365, 2.8313149316242616, -1.1391533650159609],
+    [-0.16133243485843368, 0.7701020224251939, -0.640415072470948],
+    [1.4035246768549552, -0.8092359872814493, -0.25280020511394463],
+    [2.7162471388719513, -2.1451604511271145, 0.07831869544295443],
+    [3.9242070155265814, -3.377555490568603, 0.3847287919498457],
+    [5.1319418618115265, -4.605128366271106, 0.6886917357580262],
+    [6.430665053934373, -5.913698121769602, 1.0094625473736893],
+    [7.9204378671450515, -7.397284464865754, 1.3680925876332268],
+    [9.73686829655172, -9.183352338508548, 1.7931334040832558],
+    [12.099470038821218, -11.478453490337223, 2.330931387141595],
+    [15.42860184645348, -14.678580296225416, 3.0705395605976324],
+    [20.714333760942495, -19.71733872541819, 4.222434602881647],
+    [31.24387597951782, -29.696926069824837, 6.4879597148569],
+    [-28.3840706563361, 27.375593764369263, -6.600362754062241],
+    [-14.119948980964217, 13.767692134251694, -3.4564220363717926],
+    [-8.465346669737498, 8.332176665174638, -2.1934036789752853],
+    [-5.2278981925097465, 5.187893910330524, -1.4544847356155566],
+    [-3.034917011734901, 3.0324178079890847, -0.9408203809829127],
+    [-1.375428793201672, 1.3816877596744888, -0.5418779335937787],
+    [1.4501051794351023e-12, 7.203934528039269e-13, -0.2041241452321684],
+    [1.2405286041341979, -1.2531286853698194, 0.104157270491664],
+    [2.453670965127189, -2.478664666118989, 0.40564236088929767],
+    [3.733495794318473, -3.7648797769857976, 0.7201105286897136],
+    [5.181642570219354, -5.207322502846948, 1.0689999852280176],
+    [6.933620574613385, -6.9337742408378915, 1.4810814528191816],
+    [9.207565197390988, -9.150530332539136, 2.0029861802985094],
+    [12.424056288743996, -12.256209508918209, 2.7251443072449963],
+    [17.594275367310356, -17.210599620157925, 3.866017487489594],
+    [28.241122783133818, -27.360813084008434, 6.19012550206457],
+    [-30.62319140893853, 28.841342431667048, -6.676923951194846],
+    [-17.131919003599585, 16.088536199385455, -3.760372035928558],
+    [-11.40579162817725, 10.637622024546644, -2.5066107412606238],
+    [-8.042460776604766, 7.4049814259644275, -1.7551040048710396],
+    [-5.738149235667951, 5.164852295011511, -1.2273263395940623],
+    [-3.991739204805779, 3.4466586336991893, -0.8167760217574218],
+    [-2.555354110618919, 2.017999338695811, -0.47106786422446567],
+    [-1.2800804896540958, 0.7395087748989894, -0.15891695051284171],
+    [-0.05784270316351208, -0.48985681354937183, 0.14228890824283205],
+    [1.2069138979486158, -1.7595564980257405, 0.45256883618755256],
+    [2.6175000828278674, -3.166747779554715, 0.7937554397566365],
+    [4.310467273864468, -4.840609643474426, 1.1950670778693924],
+    [6.5046606622942775, -6.9892367920670555, 1.703873279959259],
+    [9.625171757485521, -10.018255560727782, 2.412990990775588],
+    [14.718070354374882, -14.927897229278877, 3.5522775136297184],
+    [25.64120174136148, -25.41116254495522, 5.973962826982401],
+    [-33.00297418618137, 30.501166751753598, -6.81539870045162],
+    [-20.086658959780717, 18.383940517286614, -4.068792716718535],
+    [-14.290279912570007, 12.90891669182375, -2.820190340210504],
+    [-10.811446012438784, 9.592167225245548, -2.0557351226407232],
+    [-8.404715057649957, 7.271730724172987, -1.5137357489564496],
+    [-6.5772929325304395, 5.488252342980988, -1.0910778507946892],
+    [-5.082397554471461, 4.011913640074955, -0.7363277873799438],
+    [-3.7715978524029237, 2.7045836255298323, -0.4186517273416089],
+    [-2.5374679787339995, 1.4662503427499778, -0.11575252140924777],
+    [-1.2845836972105114, 0.20781089010773013, 0.19227262783181528],
+    [0.09137051640187797, -1.1687739997093811, 0.5274612823403921],
+    [1.7286748899081954, -2.7946358443618995, 0.9195740734751918],
+    [3.8483711863559287, -4.881194773000121, 1.4170661292399445],
+    [6.882764991934064, -7.844273371427025, 2.1159566581261995],
+    [11.923558064405212, -12.736798518072597, 3.2606762785412515],
+    [23.27540951681942, -23.715387758378476, 5.82083946331312],
+    [-35.671223073328356, 32.45795120413878, -7.025917057641843],
+    [-23.14367684271869, 20.780539927487116, -4.400497857917727],
+    [-17.259303561397246, 15.257614107450712, -3.1503279038546235],
+    [-13.663851878547256, 11.851294952932399, -2.3707423914310195],
+    [-11.156921370706149, 9.449086891810902, -1.8133132920357478],
+    [-9.251058124007505, 7.599515080623413, -1.3774894527028971],
+    [-7.699352566365173, 6.073996294323228, -1.01246946506712],
+    [-6.353267607405686, 4.7349971139767355, -0.6877259788611841],
+    [-5.106235939686774, 3.4836882275021597, -0.38131165932856204],
+    [-3.864234220802971, 2.2325110471050906, -0.0737116340282364],
+    [-2.5234896441097767, 0.8841123289908911, 0.25693726359455354],
+    [-0.9438166049943655, -0.6943703831902762, 0.6408040222505506],
+    [1.1010106216813782, -2.7198618280269264, 1.127782131961563],
+    [4.0547593927740815, -5.622469424572208, 1.81801334141179],
+    [9.062457610038589, -10.518565709637457, 2.973647367313317],
+    [21.009467439381407, -22.17530547086646, 5.720127434726491],
+    [-38.81282981431607, 34.851601383496856, -7.327483230630314],
+    [-26.480626809263654, 23.420514635304645, -4.777490473623848],
+    [-20.470254319280443, 17.80925387212755, -3.515954832101372],
+    [-16.744908665842715, 14.297420900072254, -2.716989375060998],
+    [-14.132783352790897, 11.805536391840297, -2.141594417276153],
+    [-12.147121021373335, 9.88535969972171, -1.6907276671945497],
+    [-10.538861629076465, 8.307536018377773, -1.3137755660096895],
+    [-9.158227439973658, 6.934011242774849, -0.9802726257855843],
+    [-7.8989941368141015, 5.666612542155774, -0.6685056704352621],
+    [-6.669063619576756, 4.419765588883832, -0.3594429861828791],
+    [-5.367293107943636, 3.098723722276692, -0.03182409185124451],
+    [-3.8540977870832434, 1.5713820991836784, 0.34433507442376426],
+    [-1.8970708952584345, -0.3848844206421894, 0.8202491902884848],
+    [0.9688744890713704, -3.2217288092520744, 1.5014083793240298],
+    [5.962031332493475, -8.138366092188598, 2.6722509886478485],
+    [18.71353666063489, -20.704665006484696, 5.666636763653025],
+    [-42.697376144389445, 37.89631701061144, -7.753477743283647],
+    [-30.333555310259158, 26.49435726641003, -5.230158426867561],
+    [-24.132393598472746, 20.731504428668426, -3.9430395399494858],
+    [-20.24864486694451, 17.08470504937539, -3.117659860478279],
+    [-17.517657089590166, 14.48715799489325, -2.5199673851674382],
+    [-15.446350984563667, 12.487248642952041, -2.051011028588462],
+    [-13.780350985279423, 10.851877902235852, -1.6597350194706917],
+    [-12.366884277236936, 9.44081254623883, -1.315381975904893],
+    [-11.09901713022873, 8.155517926707436, -0.9962497729489261],
+    [-9.886287432082604, 6.912071737180046, -0.6837091718661769],
+    [-8.63157961983591, 5.619540685101892, -0.3573550416650643],
+    [-7.200255390924776, 4.150558077377134, 0.011733885645802722],
+    [-5.3588967020899165, 2.281861644213354, 0.4748971437761644],
+    [-2.617219946618501, -0.4618643434533814, 1.143110543376181],
+    [2.359665581790177, -5.397353180514927, 2.329770912599497],
+    [16.228799258639945, -19.20711668683386, 5.6595326594830775],
+    [-47.77297151664917, 41.95724134278694, -8.36441496817627],
+    [-35.06975328568616, 30.300322293795123, -5.807004691589003],
+    [-28.569894322717268, 24.28499593603062, -4.4726082866413845],
+    [-24.47640107070854, 20.453072197768805, -3.609388271175567],
+    [-21.60084979696268, 17.7221802644326, -2.982309659914274],
+    [-19.432743187150265, 15.627252122743528, -2.4904344857176497],
+    [-17.70729639332614, 13.926802907767705, -2.081308280973855],
+    [-16.26600831854487, 12.47589099839652, -1.7233492852037953],
+    [-14.999614247783539, 11.174065250423572, -1.3945223980441708],
+    [-13.8183889004213, 9.938120893302735, -1.0763743922330946],
+    [-12.629558791730155, 8.681069420215824, -0.7493463018738346],
+    [-11.307292633811354, 7.283649924146984, -0.38627510273906435],
+    [-9.628485686004469, 5.532387792803378, 0.06200108148163068],
+    [-7.089909481596666, 2.940462028602627, 0.7090227026175937],
+    [-2.2085245222489025, -1.9513028313088574, 1.9017512491330335],
+    [13.294625122848922, -17.53000114047537, 5.700565869355601],
+    [-54.89212252591277, 47.73745941123855, -9.28012239876994],
+    [-41.35426581023531, 35.380540048218855, -6.597184889401967],
+    [-34.3649923198912, 28.938877588727546, -5.17920012919159],
+    [-29.969726359504172, 24.834292336968407, -4.2586655245207234],
+    [-26.904733850553384, 21.922974738528154, -3.5902982106806944],
+    [-24.62275817835345, 19.70920737917109, -3.0677254742319917],
+    [-22.839759098348644, 17.935182457271104, -2.6354835551879248],
+    [-21.386984912701358, 16.447081757674336, -2.2602847048364647],
+    [-20.150374485426507, 15.140124998334114, -1.9192046945991688],
+    [-19.03965589888815, 13.93037709912022, -1.5935738661025853],
+    [-17.96612507526012, 12.734152586701905, -1.264420500682356],
+    [-16.814994358549836, 11.441820135722152, -0.9065114357676225],
+    [-15.385852968336541, 9.86051677999366, -0.4750698100969024],
+    [-13.202195305726422, 7.528829944652797, 0.13777339250592233],
+    [-8.656368747330596, 2.8707627811110292, 1.3057943103347083],
+    [9.270409911910331, -15.280382125795432, 5.779945265548676],
+    [-65.98680085932595, 56.83921315421124, -10.77728007022751],
+    [-50.62440639912745, 42.90936332201748, -7.795115884135149],
+    [-42.76066187484867, 35.69613521727276, -6.2230567635220435],
+    [-37.88411692629811, 31.15049601239239, -5.208205763994747],
+    [-34.55035371449602, 27.974997575776435, -4.476949351263719],
+    [-32.135867670746144, 25.60859087063783, -3.910653982607475],
+    [-30.31998938948285, 23.761608818414192, -3.4476970499831383],
+    [-28.915693722505388, 22.263800623837536, -3.051421804431505],
+    [-27.800800529831072, 21.002388847217148, -2.697029085735719],
+    [-26.88394179739356, 19.891189520706906, -2.3649661938220246],
+    [-26.082011469203945, 18.849749836747844, -2.0363329013422753],
+    [-25.295211132827987, 17.779623083580546, -1.687459807817415],
+    [-24.356933260978334, 16.515921416861772, -1.2786016165314908],
+    [-22.871476336953055, 14.669362566287804, -0.7167698038887541],
+    [-19.327357458278087, 10.755927415981766, 0.3512047479692811],
+    [1.823379981718435, -10.91616842305961, 5.784591010827868],
+    [-86.87625318433355, 74.10206959999314, -13.697461535472135],
+    [-66.92468657240654, 56.19650870164231, -9.951249444314442],
+    [-57.18417852633702, 47.325398523501505, -8.04823976805761],
+    [-51.402607833852116, 41.94396137898602, -6.852832048530707],
+    [-47.65698346054908, 38.34343591913687, -6.013708470730193],
+    [-45.14266577199454, 35.805778407176454, -5.382096739951177],
+    [-43.461973441436456, 33.97268632122371, -4.882504586231589],
+    [-42.397761018526914, 32.64438121908759, -4.471345793231901],
+    [-41.82449519430217, 31.70005316958756, -4.120387835232941],
+    [-41.66767933622316, 31.060756589096524, -3.8087433068370937],
+    [-41.88187806574182, 30.668030513988526, -3.517806128059264],
+    [-42.433122197799506, 30.464855003749516, -3.226113182285338],
+    [-43.26852957594304, 30.361652857454988, -2.899861039303457],
+    [-44.21235840513221, 30.12603869513298, -2.4639507900421815],
+    [-44.36645257800879, 28.776478025815372, -1.6518809047919576],
+    [-23.376143463388484, 5.708321517196311, 4.638240658080719],
+    [13.814860866840242, 2.3029154490477777, -6.481011982511936],
+    [40.479569057184364, -26.942794191400495, 1.4457448604900283],
+    [40.34459424570376, -28.285404184614173, 2.2688164901659804],
+    [39.38437001315332, -28.46424411026968, 2.69592864773465],
+    [38.53343647200633, -28.513693885882194, 3.0110498488086948],
+    [37.96296473879949, -28.665802085093006, 3.2911090307869224],
+    [37.72019396151384, -29.00596256397198, 3.5698579278577025],
+    [37.83407541180851, -29.58695821070343, 3.8683023603626374],
+    [38.344856770305796, -30.462909803490867, 4.204412650293171],
+    [39.32045957868411, -31.707552192407476, 4.598213168790501],
+    [40.87663751337186, -33.434313787385435, 5.0766507055920895],
+    [43.21416925732819, -35.83095256195645, 5.68117496866243],
+    [46.700219538696146, -39.23355244344065, 6.483286164237002],
+    [52.07456636345273, -44.31274990261655, 7.623153437989928],
+    [61.09208850027249, -52.65276310899408, 9.429199754543236],
+    [79.3755154779268, -69.32044580682236, 12.947545514738243],
+    [-8.251554134057153, 16.090383177426045, -6.858516354150883],
+    [15.41211241661914, -8.465728179328288, -0.6504896637452876],
+    [19.219857863791844, -12.647226344464656, 0.48047753402464344],
+    [20.784273825372892, -14.558139365952055, 1.054072133581451],
+    [21.75079736488556, -15.834878664040806, 1.462788291266836],
+    [22.540702610290523, -16.8959567317395, 1.8070034966154451],
+    [23.326686221386936, -17.913678120279442, 2.128350792145785],
+    [24.209219044306263, -18.98799902082992, 2.4509403563121284],
+    [25.269414181308644, -20.19828204865512, 2.793524020680663],
+    [26.594178766737542, -21.627554603031484, 3.1750904794472574],
+    [28.297794911499594, -23.38278622501727, 3.6193757550619368],
+    [30.553300507480152, -25.623817219199328, 4.161122088623606],
+    [33.65495266674182, -28.620484155571923, 4.85828674065019],
+    [38.170300376162466, -32.89136309812476, 5.8215834761043554],
+    [45.400344657774795, -39.62333388330001, 7.303726441767823],
+    [59.334411416870296, -52.453453970769566, 10.07820578599456],
+    [-14.362936897624028, 19.226028919240477, -6.552143565263604],
+    [5.129832720529171, -0.702595093796158, -1.6087142221777648],
+    [9.899879264500516, -5.614546328922234, -0.3769249374054235],
+    [12.172982949890466, -8.038767589255501, 0.25687547494923046],
+    [13.632404736762828, -9.648629056682756, 0.6936281590822153],
+    [14.783701239559296, -10.939691041102064, 1.0500890548211501],
+    [15.837355989586255, -12.116738331432057, 1.37409360577309],
+    [16.910750403942963, -13.293101472015605, 1.6918820593050978],
+    [18.091629132150917, -14.552540056606349, 2.0225775657614204],
+    [19.46658776118503, -15.976620002979498, 2.3844766105305086],
+    [21.142643104981858, -17.66502226842031, 2.7995934689140505],
+    [23.27585775077104, -19.762073667558578, 3.2994736624319145],
+    [26.126231407736427, -22.50730616564348, 3.9361798836990927],
+    [30.190030987809106, -26.357136309376003, 4.808676996344875],
+    [36.59987058338403, -32.3526203514527, 6.142566640666528],
+    [48.82932545859276, -43.68614322218664, 8.62983052260592],
+    [-18.063676407775876, 21.096742313783547, -6.360736429725948],
+    [-1.0499713973838802, 3.997815306494139, -2.1968397549155902],
+    [4.0259329205228855, -1.1197039723726725, -0.9464765068876031],
+    [6.651759189074903, -3.809218240392145, -0.2758448536867031],
+    [8.367649511649775, -5.601693953160869, 0.18184287722415052],
+    [9.694856554528561, -7.007284206708322, 0.5465604112953761],
+    [10.866500808387508, -8.25154635869353, 0.8706366338061439],
+    [12.01242739318358, -9.458916238470785, 1.1826145047423895],
+    [13.225534955079496, -10.717534622718784, 1.5024672659922995],
+    [14.592675808897228, -12.108986059697548, 1.848451267372393],
+    [16.21682881514512, -13.729255287692224, 2.2418296541931957],
+    [18.244726050751165, -15.714475598618922, 2.7125769033045244],
+    [20.918521903608827, -18.28881665096723, 3.3099181807809503],
+    [24.700222607772435, -21.87964753010455, 4.127644935268082],
+    [30.651853207968358, -27.469327597603783, 5.3814823922590325],
+    [42.09394314727291, -38.12990546276524, 7.746885956593736],
+    [-20.911922180443607, 22.619450641529376, -6.266118361745175],
+    [-5.498875626558481, 7.391263617011457, -2.6220752018399924],
+    [-0.29523398704769993, 2.218602394341761, -1.378621764504139],
+    [2.536247559439605, -0.624564531788558, -0.6862526891362329],
+    [4.421226414902858, -2.542682821406539, -0.21126974093893475],
+    [5.865574942991066, -4.029544917039204, 0.16217707941995316],
+    [7.1098309770629555, -5.317329462834427, 0.4878239262932729],
+    [8.29268014167271, -6.5385398770836325, 0.7959652048976906],
+    [9.51217108425171, -7.785993919403234, 1.1075887431495604],
+    [10.856676102486064, -9.142688375226799, 1.4412825368591264],
+    [12.42752723822466, -10.703264580236167, 1.8181008911299512],
+    [14.366430904010219, -12.599686110576439, 2.267303378946464],
+    [16.905973065921554, -15.048316675332606, 2.836824432618702],
+    [20.491773584058492, -18.46366195917919, 3.6185580113353804],
+    [26.16222867877434, -23.81194444065274, 4.827031973021906],
+    [37.26694670158785, -34.21131157162795, 7.155964705675523],
+    [-23.356168481342895, 24.024415508182894, -6.240721938211397],
+    [-9.114322025295069, 10.153395506336167, -2.970160972219373],
+    [-3.813182356238867, 4.954334095392731, -1.7380408936251435],
+    [-0.834128739364025, 2.00453864145736, -1.031055283242213],
+    [1.177197200075627, -0.009401575581415657, -0.5416195854765893],
+    [2.713275978985568, -1.563800998057754, -0.15890754332624452],
+    [4.013890137086074, -2.889068289393939, 0.17019329770844127],
+    [5.221948797903909, -4.121361431534359, 0.4767345532632151],
+    [6.439789852812971, -5.357406416707598, 0.7825615145748943],
+    [7.758077858198274, -6.682377812029555, 1.1067725363693284],
+    [9.27809776491117, -8.191094886204851, 1.470545839493781],
+    [11.1391616457805, -10.013809229498879, 1.9029452020490052],
+    [13.56905693629411, -12.363495693773732, 2.451532584035672],
+    [17.007192562235602, -15.651213434242978, 3.208267109382348],
+    [22.496112710525008, -20.852906501672102, 4.391970844646063],
+    [33.53823138994084, -31.249317941293395, 6.740245863003186],
+    [-25.62991218401195, 25.432466949185457, -6.272574536449801],
+    [-12.288407375799425, 12.587536267535569, -3.280982525924069],
+    [-6.908437600272688, 7.372787751562517, -2.0601751309007246],
+    [-3.8012999245493697, 4.332877694286227, -1.3409625103780856],
+    [-1.6812782021753754, 2.236097524803228, -0.8383529694270748],
+    [-0.06471859951250009, 0.6200356435289663, -0.44591267619785746],
+    [1.2868980952942828, -0.7425496025740649, -0.11166417548282905],
+    [2.5177423487328823, -1.988313846087646, 0.19541681466487676],
+    [3.7327936979817706, -3.216228243994313, 0.49763716555287085],
+    [5.0250800242008715, -4.513672120499304, 0.8145995435286366],
+    [6.496877050926854, -5.976600895539229, 1.1678068457680826],
+    [8.286860055587729, -7.7352048138959155, 1.5864765322133445],
+    [10.621043922535343, -10.00194287113782, 2.1184109635580577],
+    [13.939066630056924, -13.190605132289832, 2.856976154965149],
+    [19.30666127616985, -18.30530005584675, 4.029410694350017],
+    [30.48519698149822, -28.89275049425162, 6.441097614195709],
+    [-27.89282088645812, 26.933591458663848, -6.358954865391222],
+    [-15.242173888916636, 14.867971682114804, -3.5782205620896286],
+    [-9.795273970453389, 9.639016280204933, -2.3665989084078576],
+    [-6.572665329878279, 6.517338692872021, -1.6358393317166537],
+    [-4.351865656073602, 4.3429598796816125, -1.1202784109865014],
+    [-2.658557389803037, 2.6664819005871707, -0.717431053888766],
+    [-1.255912726919719, 1.2640384112076422, -0.3764842558655983],
+    [1.667673836268922e-14, -7.798452137538041e-15, -0.06681531047804144],
+    [1.2149582496695617, -1.2250087153339166, 0.23390609551855626],
+    [2.4835656495570873, -2.4997617648675976, 0.5455869641749493],
+    [3.9095087136449265, -3.9217039653780748, 0.8901169938662999],
+    [5.632033622627764, -5.622100463749942, 1.2971069160503832],
+    [7.877441373530538, -7.814919640246177, 1.8150459553255878],
+    [11.089944291452516, -10.921094601481238, 2.539720088386557],
+    [16.372947457255066, -15.987729475832271, 3.7103054490357104],
+    [27.854750292329474, -26.93622940192806, 6.226169532803005],
+    [-30.280658133061067, 28.61386216679567, -6.503647162644343],
+    [-18.14057141275458, 17.125551694751728, -3.8804112473808634],
+    [-12.624183077577209, 11.871509625179554, -2.6736300004628553],
+    [-9.292253045776446, 8.669659017139482, -1.9306049897427229],
+    [-6.975664090486944, 6.419528315151524, -1.4016430056993288],
+    [-5.207867616467783, 4.682440378309181, -0.9875538600861962],
+    [-3.75331915873561, 3.237230987592368, -0.6385006512110659],
+    [-2.4688366398533206, 1.949664091527313, -0.3243355972515629],
+    [-1.2493894279708162, 0.7213577229045949, -0.023015355467276323],
+    [-0.00047725615451842015, -0.5363107759305803, 0.2853253356827296],
+    [1.3823081460666728, -1.9216500288415521, 0.6228188014753957],
+    [3.039225407034593, -3.5674681927186778, 1.0195710503837854],
+    [5.198163025211875, -5.690640120074863, 1.525085594234602],
+    [8.310828915916781, -8.722529763243827, 2.238386169897705],
+    [13.529967223418813, -13.766173532154987, 3.4138811104207765],
+    [25.473986487699207, -25.245612137321256, 6.076841873492214],
+    [-32.93541760889603, 30.573793614860083, -6.716848724061954],
+    [-21.135724930540157, 19.48180235678096, -4.205592253503073],
+    [-15.531438724480964, 14.178789406749354, -2.9969819001045988],
+    [-12.087382101936168, 10.890304733551941, -2.2393861815277236],
+    [-9.675421145502543, 8.561833589949327, -1.6956656186046868],
+    [-7.833839484180795, 6.762124146235301, -1.2691084022832997],
+    [-6.32708194574651, 5.271325824357763, -0.9105290279271733],
+    [-5.012113799338604, 3.9559875956761843, -0.5901235216921924],
+    [-3.784960324184242, 2.719022904726055, -0.2862139011413552],
+    [-2.552531376543403, 1.4732368306563208, 0.020722559718938494],
+    [-1.2110363388747845, 0.12081390013528048, 0.3527315007456969],
+    [0.3805871338613843, -1.4723113392702925, 0.7403215012674981],
+    [2.452524022194513, -3.526769860828412, 1.2342182546552434],
+    [5.465289652839672, -6.486539123289232, 1.9373384389369097],
+    [10.629713110239113, -11.521980284298376, 3.1225452063524037],
+    [23.205641811768785, -23.722127761960234, 5.982781510297323],
+    [-36.03544310442466, 32.950000477791676, -7.017306867103431],
+    [-24.39794654673668, 22.07408498691463, -4.575035823178866],
+    [-18.669013923746288, 16.682854987608547, -3.355053685804291],
+    [-15.099297286073138, 13.29174815863171, -2.578660263184856],
+    [-12.586304735248582, 10.876666276392937, -2.0176100609274163],
+    [-10.668709045473118, 9.00929368051661, -1.5766428169111828],
+    [-9.108940079725846, 7.469103518692131, -1.2067830883194017],
+    [-7.762964023898122, 6.1223175265131395, -0.8783303337002522],
+    [-6.527397598398969, 4.872703480095374, -0.5698818800085587],
+    [-5.3111878193527975, 3.6351063950794216, -0.26240492888873973],
+    [-4.012955864447682, 2.3140050073544858, 0.06562903159301169],
+    [-2.492063570656368, 0.7756101670249301, 0.4447053536727425],
+    [-0.5140890424289815, -1.2055521235978588, 0.9268565025593252],
+    [2.3945536263756724, -4.090005706183698, 1.6197430226926666],
+    [7.507959631944709, -9.125362613089925, 2.8179757480764964],
+    [20.918470122338128, -22.280555617899058, 5.939607301382505],
+    [-39.8411848610448, 35.951543284358216, -7.43776131400529],
+    [-28.154749838778123, 25.087578168286857, -5.018332484682165],
+    [-22.239272361926137, 19.547164221447044, -3.773196371523742],
+    [-18.516205575742113, 16.024672381189404, -2.971113397064647],
+    [-15.888609119543451, 13.507151725188416, -2.388452897789007],
+    [-13.888870530554438, 11.562873539661572, -1.9300342524922203],
+    [-12.274343913042046, 9.967737768099884, -1.5464749463771932],
+    [-10.898270167761689, 8.586004376235794, -1.2078291615518657],
+    [-9.656841586773195, 7.321323343805809, -0.8927590945338273],
+    [-8.46090210062216, 6.090419974508226, -0.5826858406531075],
+    [-7.213120603520819, 4.8015827576099195, -0.2569355298716653],
+    [-5.77712276550546, 3.325028417719536, 0.11408922512795978],
+    [-3.91654861022891, 1.4333460269737337, 0.5829111800633098],
+    [-1.1359996162882329, -1.3563235692396731, 1.2627287280563508],
+    [3.92984721927114, -6.394351332143443, 2.4755663064072446],
+    [18.457142191301912, -20.83017991327934, 5.948553693589606],
+    [-44.78619203669109, 39.93504947290078, -8.037515642502612],
+    [-32.76176506527439, 28.81367470166142, -5.582977302529567],
+    [-26.55671239405388, 23.026833630814757, -4.291652732354879],
+    [-22.630885359824195, 19.32425337739495, -3.452725671792689],
+    [-19.863746922590597, 16.677243739049352, -2.841500093321082],
+    [-17.770836766090337, 14.64097481469453, -2.3608657210533717],
+    [-16.099538911618716, 12.983274049127303, -1.960101019871396],
+    [-14.697712409800777, 11.563965407923227, -1.608492411349089],
+    [-13.459539559107181, 10.285059705362025, -1.2844309553091346],
+    [-12.296946543941424, 9.064321566295213, -0.96958926393834],
+    [-11.117281855649283, 7.814318921860767, -0.6442225890010381],
+    [-9.79288240867437, 6.413314769210646, -0.28049148786445105],
+    [-8.09578115979265, 4.641821376836408, 0.17236684554088288],
+    [-5.514270781874739, 2.0015029140614584, 0.8311514000698418],
+    [-0.5429914871951929, -2.9984983392197337, 2.0521402426978783],
+    [15.585940104916608, -19.241563118558037, 6.017362298836852],
+    [-51.69750345646047, 45.58893054938961, -8.934248074316617],
+    [-38.865989703720075, 33.78470321410878, -6.356743163072751],
+    [-32.18870255889043, 27.583329012220904, -4.983925605043353],
+    [-27.971562695412267, 23.615620179420116, -4.089124652914246],
+    [-25.021546512587665, 20.793233246122266, -3.437663384230919],
+    [-22.81886411739307, 18.641653252710483, -2.9271599719364203],
+    [-21.092423995365714, 16.912935830886013, -2.503984238002442],
+    [-19.68032139351857, 15.458399467800849, -2.1357911134788217],
+    [-18.472315427910306, 14.176045416274434, -1.8001539879395716],
+    [-17.380161082074356, 12.98327061223268, -1.478617207460352],
+    [-16.31575972705599, 11.796451563101087, -1.1521518754072995],
+    [-15.16311311409381, 10.504174974906352, -0.7950332410308233],
+    [-13.716804387545787, 8.907630349531031, -0.36095260845687666],
+    [-11.485455522502274, 6.527946829149793, 0.26250906681834585],
+    [-6.823722135850885, 1.7400778460668376, 1.4629170272135754],
+    [11.795976138387918, -17.22659804279566, 6.1577138915415555],
+    [-62.45935696094098, 54.49033126522285, -10.401107125104742],
+    [-47.87508876571184, 41.16170523373266, -7.532387797380816],
+    [-40.35472062687398, 34.210168817609194, -6.009183483781481],
+    [-35.67291985121396, 29.813130628225597, -5.022203820464547],
+    [-32.46343382993282, 26.73364807981942, -4.30926680747324],
+    [-30.133175515714186, 24.433871506622808, -3.7560655257141016],
+    [-28.375798490075216, 22.634946056090257, -3.302978045421646],
+    [-27.011842784848994, 21.17228793197964, -2.9143931000739185],
+    [-25.923381644385064, 19.9362750233879, -2.5660936983537046],
+    [-25.02141975605754, 18.84242808967445, -2.2388386244756333],
+    [-24.223888395964593, 17.810890860317503, -1.9138191154134554],
+    [-23.430580851783883, 16.742430345711885, -1.5671496842685455],
+    [-22.47162426614727, 15.46830827696064, -1.158126053464907],
+    [-20.937941073565, 13.584196351189393, -0.5899910938812901],
+    [-17.24793312257949, 9.527241054324197, 0.5106558790432113],
+    [5.124115031733831, -13.538330445330812, 6.31665494177647],
+    [-82.81100612321943, 71.45686612222043, -13.278383514727377],
+    [-63.79866083635616, 54.257413957007635, -9.662520406090309],
+    [-54.4595433892507, 45.684813789648494, -7.814220884215284],
+    [-48.900841063844524, 40.47054473442554, -6.649716456143834],
+    [-45.29450877355743, 36.97717706740708, -5.830845164969957],
+    [-42.87221937614887, 34.51360332507422, -5.213749747983474],
+    [-41.253143927980496, 32.733974842753305, -4.7251990909115795],
+    [-40.22893642730733, 31.445073355065276, -4.322799661912965],
+    [-39.67901892890044, 30.52989649472365, -3.979016528330592],
+    [-39.5318149363596, 29.911897273760243, -3.673405257004071],
+    [-39.743642611246614, 29.53424286567053, -3.3876540891468383],
+    [-40.281521074379185, 29.341023167546105, -3.100464943762577],
+    [-41.09268862705537, 29.242940169375355, -2.777930845736707],
+    [-41.998569077176285, 29.004285578762996, -2.343751131475359],
+    [-42.0708082530041, 27.608459037956646, -1.521695957009215],
+    [-18.291105688698995, 1.566902037969366, 5.539833502414613],
+    [18.291045487847835, -1.5668387933262329, -5.539849824521181],
+    [42.070808081344424, -27.608458865822424, 1.5216959151992446],
+    [41.998569022931456, -29.004285542600375, 2.343751127668211],
+    [41.09268905752783, -29.24294015898695, 2.7779307041289822],
+    [40.28152141746727, -29.341023370113735, 3.1004649483419238],
+    [39.743642781067294, -29.53424297911103, 3.3876540987797084],
+    [39.53181502675415, -29.911897336543817, 3.6734052635486107],
+    [39.67901897848875, -30.529896530392577, 3.9790165326293994],
+    [40.22893687153192, -31.445073695226178, 4.322799708291423],
+    [41.253143940913766, -32.7339748429946, 4.725199087335494],
+    [42.87221920376785, -34.513603160557494, 5.213749711456035],
+    [45.2945077945991, -36.97717618284247, 5.830844982273684],
+    [48.900835687527966, -40.47053993398297, 6.649715478518986],
+    [54.459544967308325, -45.68481509675357, 7.814221123308321],
+    [63.79866083362856, -54.25741395458358, 9.662520405594924],
+    [82.81100431023266, -71.45686451008879, 13.278383186877546],
+    [-5.124115041558827, 13.538330455095982, -6.316654944084997],
+    [17.247931913589408, -9.527239849508774, -0.5106561694480933],
+    [20.937941142533465, -13.584196410808712, 0.5899911061545943],
+    [22.471623469984475, -15.468307426242761, 1.158125832879063],
+    [23.43058083486869, -16.74243031655295, 1.5671496737886688],
+    [24.22388840232826, -17.810890863004744, 1.9138191150123227],
+    [25.021419760498976, -18.842428092162514, 2.2388386245465264],
+    [25.923377724862455, -19.936271954201274, 2.566093289271375],
+    [27.011842778826626, -21.172287925472926, 2.9143930984477113],
+    [28.37579845424295, -22.63494602093132, 3.3029780373741446],
+    [30.133175338136635, -24.433871341629768, 3.7560654900932833],
+    [32.46343301853077, -26.73364734002618, 4.309266650833959],
+    [35.67291571725335, -29.813126888323644, 5.022203034192691],
+    [40.35472122432671, -34.21016931674184, 6.009183579150954],
+    [47.87509776406139, -41.16171293827161, 7.532389323906541],
+    [62.45935610771425, -54.49033049600226, 10.401106963191632],
+    [-11.795992529520724, 17.226615185578357, -6.157718264282356],
+    [6.823721353652354, -1.7400770641249017, -1.4629172161655126],
+    [11.485456040246222, -6.527947288906225, -0.2625089693090396],
+    [13.716802443431048, -8.907628390454606, 0.3609521327576417],
+    [15.16311304489782, -10.504174899592034, 0.7950332213490433],
+    [16.31575972566853, -11.79645156046697, 1.1521518744557515],
+    [17.3801610827191, -12.983270612383167, 1.478617207379392],
+    [18.472315364695024, -14.176045366081981, 1.8001539813327516],
+    [19.68032138856212, -15.458399462602689, 2.1357911122168507],
+    [21.092423963540504, -16.912935800008086, 2.5039842309907163],
+    [22.818863960741336, -18.64165310619461, 2.9271599398163293],
+    [25.021545783804235, -20.793232575769707, 3.4376632395493525],
+    [27.971558947110566, -23.61561675740944, 4.0891239188905715],
+    [32.18870294899659, -27.58332934060257, 4.983925669562322],
+    [38.86599600786984, -33.784708652627145, 6.356744267552323],
+    [51.69750261848668, -45.58892978738999, 8.934247910554964],
+    [-15.585934572238163, 19.241557515119833, -6.017360907394586],
+    [0.5429911399926112, 2.9984986859105707, -2.052140326227926],
+    [5.5142715204723, -2.0015035882386227, -0.8311512519484571],
+    [8.095777757729193, -4.641818003539545, -0.17236764934429558],
+    [9.792882255242791, -6.413314612097874, 0.2804914492873864],
+    [11.117281849897937, -7.814318915235976, 0.6442225872133285],
+    [12.296946543949298, -9.064321566110983, 0.9695892638535903],
+    [13.45953955849093, -10.285059704847484, 1.2844309552443292],
+    [14.69771415662165, -11.563966733263017, 1.6084925962769225],
+    [16.09953888567045, -12.983274023836897, 1.9601010140787294],
+    [17.770836626122506, -14.640974682942689, 2.36086569180801],
+    [19.86374623354375, -16.67724310080144, 2.8414999536672667],
+    [22.63088168481985, -19.324249998644326, 3.4527249367808266],
+    [26.556712723208452, -23.026833909692908, 4.291652788179161],
+    [32.76177077221377, -28.813679657381694, 5.582978326465122],
+    [44.78619088678885, -39.935048419672285, 8.037515412651262],
+    [-18.457141054574816, 20.830178794097908, -5.948553423552301],
+    [-3.9298473766444113, 6.394351487937714, -2.475566343572],
+    [1.1360002813551937, 1.3563229544662125, -1.262728590992398],
+    [3.9165442805050903, -1.4333417630146046, -0.5829121877649092],
+    [5.777122494590036, -3.3250281465587235, -0.1140892900891375],
+    [7.213120590545068, -4.8015827438846745, 0.25693552643410655],
+    [8.460902100254822, -6.090419974037315, 0.5826858405225503],
+    [9.656841586792645, -7.321323343809857, 0.8927590945324371],
+    [10.898270423116543, -8.586004567552045, 1.2078291880910224],
+    [12.27434389253732, -9.9677377479774, 1.546474941724727],
+    [13.88887040434432, -11.562873420148657, 1.930034225702354],
+    [15.888608450761707, -13.507151101706201, 2.3884527598526324],
+    [18.51620181569677, -16.024668902340608, 2.971112631771476],
+    [22.239272677264275, -19.547164490605045, 3.7731964262891386],
+    [28.154755775727377, -25.08758335891848, 5.018333572653686],
+    [39.84118295556604, -35.95154152776467, 7.43776092589852],
+    [-20.91846984901165, 22.280555353916885, -5.939607238978912],
+    [-7.507959709562329, 9.125362688654473, -2.817975765791871],
+    [-2.394553081069775, 4.090005201191969, -1.6197429098661211],
+    [0.5140844472627919, 1.2055566234044957, -0.9268575588313351],
+    [2.492063185685961, -0.7756097860630174, -0.44470544379191873],
+    [4.012955839958807, -2.3140049823151716, -0.0656290376676067],
+    [5.311187818467637, -3.635106394096854, 0.26240492864012827],
+    [6.527397598390847, -4.872703480080716, 0.5698818800049864],
+    [7.76296406107313, -6.122317553657793, 0.878330337356496],
+    [9.108940064110321, -7.4691035032354876, 1.2067830847108307],
+    [10.668708932777468, -9.0092935730257, 1.576642792579223],
+    [12.586304077216155, -10.876665659053716, 2.0176099230655935],
+    [15.099293342208723, -13.291744486917894, 2.5786594476199043],
+    [18.669014252471346, -16.68285527024971, 3.355053744083516],
+    [24.39794654192424, -22.0740849825055, 4.57503582221217],
+    [36.0354394963214, -32.949997131315854, 7.017306119900975],
+    [-23.205641734822017, 23.722127688787648, -5.982781493307941],
+    [-10.629713150110414, 11.52198032256386, -3.122545215187872],
+    [-5.465289191022882, 6.486538700595953, -1.9373383455715745],
+    [-2.4525285519964104, 3.526774258481003, -1.2342192774467482],
+    [-0.38058760462680985, 1.4723118007423381, -0.7403216092951439],
+    [1.2110362994336403, -0.12081386052579349, -0.35273151018925253],
+    [2.5525313746987517, -1.473236828691448, -0.020722560203136436],
+    [3.7849603241548437, -2.719022904690808, 0.28621390113342177],
+    [5.012113803501493, -3.9559875984622797, 0.5901235220043012],
+    [6.327081934473033, -5.2713258130759, 0.9105290252653271],
+    [7.833839385383836, -6.762124051241071, 1.2691083805713617],
+    [9.675420498050286, -8.561832978245695, 1.695665480746821],
+    [12.087377903517924, -10.89030079915851, 2.239385299753954],
+    [15.531439086144145, -14.178789720194404, 2.99698196553125],
+    [21.13572492245485, -19.481802349312584, 4.205592251848548],
+    [32.93540999385076, -30.57378650210465, 6.716847118952166],
+    [-25.47402166874475, 25.24564581989323, -6.076849694162356],
+    [-13.529967244815012, 13.766173552432724, -3.4138811150363533],
+    [-8.310828519565192, 8.722529405537477, -2.238386091999997],
+    [-5.198167368891771, 5.6906442932897985, -1.5250865542587984],
+    [-3.039225935816748, 3.5674687056366268, -1.0195711691314264],
+    [-1.3823082017709434, 1.9216500840573234, -0.6228188144705646],
+    [0.0004772526735301831, 0.5363107795644404, -0.2853253365679414],
+    [1.2493894278969944, -0.7213577228250784, 0.02301535544914209],
+    [2.468836639871941, -1.9496640914249752, 0.32433559720308963],
+    [3.7533191511215853, -3.2372309798863643, 0.6385006493762378],
+    [5.207867532876916, -4.68244029715199, 0.9875538413448837],
+    [6.975663460236402, -6.419527714904239, 1.4016428691499498],
+    [9.292248553254675, -8.66965477561025, 1.9306040304620724],
+    [12.624183489810113, -11.871509985655178, 2.6736300766001673],
+    [18.14057139876604, -17.12555168173057, 3.880411244469691],
+    [30.2806404417433, -28.613845518990832, 6.503643366806014],
+    [-27.854764238296752, 26.93624263800219, -6.2261725718346685],
+    [-16.372947469151153, 15.987729486985629, -3.710305451543036],
+    [-11.089943946894307, 10.921094294531184, -2.5397200225151626],
+    [-7.8774454737331965, 7.814923543823284, -1.8150468442372825],
+    [-5.632034188315597, 5.622101007002346, -1.2971070404695866],
+    [-3.9095087855165613, 3.9217040357590425, -0.8901170102313443],
+    [-2.4835656555191328, 2.4997617709825923, -0.5455869656455271],
+    [-1.2149582495821267, 1.2250087153426645, -0.23390609554337294],
+    [-9.815632765001461e-11, 1.1327908484284541e-10, 0.06681531045032833],
+    [1.255912722317481, -1.264038406461029, 0.37648425471919256],
+    [2.6585573233193425, -2.666481835239031, 0.7174310386145439],
+    [4.351865058068568, -4.342959304584418, 1.1202782788013028],
+    [6.572660538294166, -6.517334129293416, 1.6358382895807977],
+    [9.79527444896359, -9.639016703300262, 2.3665989989332075],
+    [15.242173863459485, -14.867971658179956, 3.57822055667483],
+    [27.89277582873362, -26.933548699762294, 6.358945010071051],
+    [-30.48520304815371, 28.892756207449374, -6.44109891183168],
+    [-19.30666128305209, 18.305300062247127, -4.029410695774032],
+    [-13.93906632505784, 13.190604863450055, -2.856976098037661],
+    [-10.621047776929556, 10.001946512284903, -2.118411784838606],
+    [-8.286860643373242, 7.735205373707548, -1.586476659176242],
+    [-6.496877137858552, 5.976600979822571, -1.1678068651548967],
+    [-5.025080033485602, 4.513672129878504, -0.8145995457560907],
+    [-3.732793694461016, 3.2162282415020496, -0.49763716525834106],
+    [-2.5177423487809514, 1.9883138461405243, -0.19541681467654157],
+    [-1.286898097788623, 0.7425496052068709, 0.11166417483722149],
+    [0.06471855135944078, -0.6200355954448693, 0.44591266479106956],
+    [1.6812776630753559, -2.2360969998608224, 0.8383528472627535],
+    [3.8012948957201993, -4.3328728532346314, 1.340961392744565],
+    [6.9084381586141275, -7.372788252332865, 2.0601752396177764],
+    [12.28840732792469, -12.587536222020674, 3.280982515499158],
+    [25.629912293106937, -25.43246705154502, 6.272574559940758],
+    [-33.5382342900367, 31.249320653195035, -6.740246472317587],
+    [-22.496106893772087, 20.85290131792825, -4.39196973355636],
+    [-17.007192282660732, 15.651213190150903, -3.2082670584026363],
+    [-13.569060578276705, 12.363499110424618, -2.451533347357091],
+    [-11.139162249552195, 10.013809800212854, -1.9029453302179535],
+    [-9.27809786617197, 8.191094983524787, -1.4705458616437563],
+    [-7.758077871499762, 6.682377825317922, -1.1067725394911467],
+    [-6.439789818947224, 5.357406391305595, -0.7825615111157487],
+    [-5.221948797924782, 4.121361431558466, -0.4767345532681056],
+    [-4.013890138286868, 2.8890682907115224, -0.17019329803983638],
+    [-2.7132760097468824, 1.5638010293711608, 0.15890753576366942],
+    [-1.1771976477593393, 0.00940201759518049, 0.5416194812009699],
+    [0.834123645675056, -2.0045336736641715, 1.0310541216940707],
+    [3.813182997181564, -4.954334681502356, 1.7380410231990668],
+    [9.114321932222527, -10.153395416614945, 2.9701609513612266],
+    [23.356168876363125, -24.024415883715843, 6.240722025732016],
+    [-37.266948245457336, 34.21131300569472, -7.155965024226619],
+    [-26.162223552736, 23.811939907751906, -4.827031014101057],
+    [-20.491773313847677, 18.463661725281344, -3.6185579632010088],
+    [-16.905976556334075, 15.048319928909123, -2.836825152248742],
+    [-14.366431523874372, 12.599686692606738, -2.267303508346992],
+    [-12.427527353135403, 10.703264689927433, -1.8181009158598775],
+    [-10.856676120586581, 9.142688393125843, -1.4412825410161572],
+    [-9.512170841179659, 7.785993733896906, -1.107588717286144],
+    [-8.292680141671736, 6.538539877091813, -0.7959652049005426],
+    [-7.109830977550307, 5.317329463439978, -0.4878239264583388],
+    [-5.865574959664775, 4.029544934535075, -0.16217708376729384],
+    [-4.421226739331284, 2.5426831461825903, 0.21126966323283025],
+    [-2.5362524125251493, 0.6245693154575024, 0.6862515587851419],
+    [0.2952347220346385, -2.2186030768058216, 1.3786219173376324],
+    [5.498875440199792, -7.3912634339888745, 2.6220751584873],
+    [20.911923856060312, -22.619452261818765, 6.26611874637443],
+    [-42.09394408676517, 38.12990632985905, -7.746886146811005],
+    [-30.65184825688583, 27.4693232518239, -5.381481486690659],
+    [-24.700222323913504, 21.879647286431563, -4.127644885970272],
+    [-20.918525336647182, 18.288819829910825, -3.309918875624805],
+    [-18.244726695167287, 15.71447619976956, -2.71257703544245],
+    [-16.2168289450418, 13.72925541086036, -2.2418296816567875],
+    [-14.592675832401481, 12.108986082790384, -1.8484512726870383],
+    [-13.225533230663396, 10.717533295045758, -1.5024670800750306],
+    [-12.012427393483732, 9.458916238749305, -1.1826145047771943],
+    [-10.866500808441456, 8.251546358930405, -0.8706366339007767],
+    [-9.694856562016511, 7.007284215142476, -0.5465604135284428],
+    [-8.367649700462888, 5.6016941462789935, -0.18184292448285974],
+    [-6.651763139963176, 3.809222169541612, 0.2758439163901904],
+    [-4.025932093642313, 1.119703208844428, 0.946476676518623],
+    [1.04997100001119, -3.9978149089413937, 2.196839659017362],
+    [18.06368458519655, -21.096750417435803, 6.360738402750945],
+    [-48.82932615231541, 43.68614385817201, -8.629830660007599],
+    [-36.59986506322036, 32.352615542025255, -6.142565656969256],
+    [-30.1900306463752, 26.357136018581308, -4.808676938726245],
+    [-26.1262349332842, 22.507309407152643, -3.9361805819287317],
+    [-23.275858439268607, 19.76207430524831, -3.299473800605238],
+    [-21.142643252699887, 17.66502240747642, -2.7995934994977127],
+    [-19.466587791017748, 15.976620032097989, -2.384476617156008],
+    [-18.091629136569942, 14.552540061308184, -2.022577566911273],
+    [-16.9107504478227, 13.293101508267847, -1.6918820640862504],
+    [-15.837355988991602, 12.116738331295975, -1.3740936058492896],
+    [-14.783701241741355, 10.939691044532413, -1.0500890559638083],
+    [-13.63240482197432, 9.648629148588293, -0.6936281828375991],
+    [-12.172985283913754, 8.038769945917323, -0.25687604677180936],
+    [-9.899878619703564, 5.6145457478628815, 0.3769250625007167],
+    [-5.129833630252357, 0.7025960081284985, 1.6087140004542446],
+    [14.362907989460226, -19.225998996170063, 6.552136001861028],
+    [-59.334412135291835, 52.453454623541674, -10.078205924000372],
+    [-45.40033668274601, 39.623326990485204, -7.3037250676327305],
+    [-38.17029984717897, 32.89136265132222, -5.821583390118996],
+    [-33.65495658891142, 28.620487728760374, -4.858287494442251],
+    [-30.553301283754617, 25.62381793167838, -4.161122239902328],
+    [-28.297795082337895, 23.38278638460901, -3.6193757895612872],
+    [-26.594178801448972, 21.627554637215933, -3.1750904872648724],
+    [-25.269414186919306, 20.198282054803755, -2.7935240222294557],
+    [-24.20922222437244, 18.988001573533765, -2.4509406976894503],
+    [-23.32668621726269, 17.913678117959766, -2.1283507920831464],
+    [-22.5407026048367, 16.895956729730656, -1.807003497134513],
+    [-21.750797387896, 15.83487869936339, -1.462788303263209],
+    [-20.78427476098882, 14.55814036283464, -1.054072390582544],
+    [-19.219857749150634, 12.647226246522418, -0.4804775143620703],
+    [-15.412113968243482, 8.465729736585718, 0.650489286538305],
+    [8.25155409758214, -16.090383138932737, 6.8585163443011155],
+    [-79.3755170623568, 69.32044722699068, -12.947545804502472],
+    [-61.092088502718816, 52.65276311119881, -9.429199754998493],
+    [-52.074564904465404, 44.31274868251015, -7.623153213962816],
+    [-46.70022472299455, 39.233557104559175, -6.483287115325551],
+    [-43.214170215693194, 35.830953432655924, -5.681175148466011],
+    [-40.87663768666196, 33.434313952768875, -5.0766507420750635],
+    [-39.3204595664817, 31.707552192399458, -4.598213172366483],
+    [-38.344856169125144, 30.462909336885257, -4.204412586176379],
+    [-37.834075360648754, 29.586958173424804, -3.868302355843719],
+    [-37.72019386955915, 29.005962499438652, -3.569857921137094],
+    [-37.96296456225496, 28.665801965705867, -3.291109020628776],
+    [-38.53343611252265, 28.51369367136865, -3.011049844234369],
+    [-39.3843695506226, 28.464244119030408, -2.6959288023033356],
+    [-40.344594284845826, 28.285404210916784, -2.2688164928827854],
+    [-40.47956928132741, 26.942794418553913, -1.445744916198773],
+    [-13.815124848672998, -2.3026346674653277, 6.480938666763365],
+    [23.37612898892357, -5.708306481928934, -4.638244497848798],
+    [44.36645245718556, -28.776477905134886, 1.651880875510604],
+    [44.212358332441696, -30.126038647055633, 2.4639507849326914],
+    [43.26853005234379, -30.361652891172206, 2.899860911004715],
+    [42.433122549481304, -30.464855210535323, 3.2261131878473597],
+    [41.88187824014523, -30.668030629376364, 3.5178061379169097],
+    [41.667679429910336, -31.060756653475345, 3.8087433135403326],
+    [41.82449524734705, -31.70005320732477, 4.120387839778902],
+    [42.397761373573594, -32.64438148746688, 4.471345829530056],
+    [43.46197345760067, -33.972686323430125, 4.882504582866175],
+    [45.14266559697001, -35.805778240578576, 5.382096702900624],
+    [47.65698244730856, -38.34343500909454, 6.0137082829973645],
+    [51.40260219680917, -41.943956380577106, 6.852831033110103],
+    [57.184180299011246, -47.32539997816179, 8.048240032851105],
+    [66.92468656931769, -56.196508698899045, 9.951249443750088],
+    [86.87625102405215, -74.10206769490416, 13.69746114965374],
+    [-1.8233799835527564, 10.916168424580027, -5.784591011082325],
+    [19.32735655290852, -10.75592652098355, -0.35120496236919735],
+    [22.87147636471524, -14.669362592954654, 0.716769810217546],
+    [24.35693259780824, -16.515920702684635, 1.2786014294249999],
+    [25.295211122183446, -17.779623060379205, 1.687459798716813],
+    [26.082011476774568, -18.849749840265623, 2.0363329010604767],
+    [26.883941802356603, -19.891189523493228, 2.3649661939146895],
+    [27.800795690103353, -21.002385130301686, 2.6970285916832846],
+    [28.915693715847326, -22.263800616795557, 3.051421802698394],
+    [30.31998935267079, -23.76160878232028, 3.4476970416895543],
+    [32.13586748580469, -25.608590699681223, 3.9106539457416143],
+    [34.550352859593985, -27.974996801450633, 4.476949187840992],
+    [37.884112530353725, -31.150492063903428, 5.208204937145463],
+    [42.76066256470697, -35.6961357874426, 6.223056871622565],
+    [50.624416749529736, -42.90937210381562, 7.795117613571497],
+    [65.98679981927357, -56.839212223775455, 10.777279875246379],
+    [-9.270419858603699, 15.280392527867967, -5.7799479185811045],
+    [8.656368076406759, -2.8707621154735183, -1.305794470233792],
+    [13.202195703173103, -7.528830291834692, -0.13777332013440027],
+    [15.38585137335022, -9.860515176066581, 0.47506942042865774],
+    [16.814994302927992, -11.441820074472822, 0.9065114195659136],
+    [17.96612507470813, -12.734152584840913, 1.264420499902303],
+    [19.03965589968808, -13.930377099347998, 1.5935738660220036],
+    [20.150374396464553, -15.140124929653119, 1.9192046855706604],
+    [21.38698490721037, -16.447081751963918, 2.2602847034551368],
+    [22.83975906428101, -17.93518242437648, 2.635483547729588],
+    [24.622758011381386, -19.709207224083876, 3.067725440363556],
+    [26.904733074182342, -21.92297402937944, 3.5902980582623854],
+    [29.969722342441106, -24.834288696669045, 4.258664747405593],
+    [34.36499277685221, -28.938877968892395, 5.179200203167718],
+    [41.35427314401595, -35.38054631242578, 6.597186151820759],
+    [54.892121491712146, -47.73745847801937, 9.28012219921859],
+    [-13.294621183402873, 17.529997079424515, -5.700564844985917],
+    [2.2085242141645183, 1.9513031372602103, -1.9017513225454532],
+    [7.089910135683413, -2.9404626173248136, -0.7090225750360768],
+    [9.628482803831023, -5.5323849461392545, -0.062001758522227675],
+    [11.307292510500337, -7.283649797776602, 0.3862750716297804],
+    [12.629558787494354, -8.68106941513249, 0.7493463004586078],
+    [13.818388900504083, -9.938120893167874, 1.0763743921543187],
+    [14.999614246631987, -11.174065249521295, 1.3945223979307275],
+    [16.266010131920474, -12.475892356079294, 1.7233494739815485],
+    [17.707296364737875, -13.92680288008006, 2.0813082746531215],
+    [19.43274303593232, -15.627251981337785, 2.490434454455434],
+    [21.600849056020675, -17.722179582980427, 2.9823095115168115],
+    [24.476397108055842, -20.45306858212531, 3.6093874888397983],
+    [28.569894708163638, -24.284996258671825, 4.4726083506131555],
+    [35.06975994681796, -30.30032801847694, 5.8070058647220115],
+    [47.77297008283062, -41.957240040360674, 8.364414685673617],
+    [-16.228798458456424, 19.207115885575266, -5.659532463242949],
+    [-2.359665719785695, 5.397353317249955, -2.3297709452548756],
+    [2.617220566985296, 0.46186377659224, -1.1431104183454581],
+    [5.358892860010934, -2.2818578742351683, -0.47489803284265475],
+    [7.200255167571121, -4.15055785422811, -0.011733939092947768],
+    [8.631579609884465, -5.61954067448826, 0.35735503898707116],
+    [9.886287431853921, -6.912071736838584, 0.6837091717639205],
+    [11.099017130270127, -8.155517926725203, 0.9962497729491159],
+    [12.366884550665525, -9.440812747899555, 1.31538200375593],
+    [13.780350962031712, -10.851877879588464, 1.6597350142591927],
+    [15.446350846410516, -12.487248512959447, 2.0510109995672687],
+    [17.517656363789726, -14.48715732331557, 2.519967237406152],
+    [20.24864079371572, -17.08470130987785, 3.1176590425947643],
+    [24.132393970984396, -20.731504742338057, 3.9430396030485535],
+    [30.33356227902756, -26.494363291342786, 5.230159677944935],
+    [42.69737375719284, -37.89631482838596, 7.753477264231251],
+    [-18.71353646704376, 20.704664816667012, -5.666636718184349],
+    [-5.96203139881133, 8.138366157246958, -2.672251004001786],
+    [-0.9688739844840726, 3.2217283444045055, -1.5014082759832967],
+    [1.8970666991964424, 0.38488852302645477, -0.820250152540938],
+    [3.854097457210379, -1.571381773242297, -0.3443351514954252],
+    [5.367293088751521, -3.0987237025896115, 0.03182408705771441],
+    [6.669063618960971, -4.419765588176291, 0.3594429860000613],
+    [7.8989922149702485, -5.666610864746345, 0.6685053386228216],
+    [9.158227482036686, -6.93401127279392, 0.9802726297904047],
+    [10.538861610841625, -8.307536000472114, 1.3137755618524316],
+    [12.14712089577781, -9.885359580781666, 1.6907276404075902],
+    [14.132782632623199, -11.80553572136247, 2.141594268416407],
+    [16.744904368388347, -14.297416931483065, 2.716988499147169],
+    [20.470254711172807, -17.80925420404869, 3.515954899650839],
+    [26.480626803410953, -23.42051462999014, 4.777490472467015],
+    [38.81282524806587, -34.85159718348752, 7.327482298935283],
+    [-21.009467384263406, 22.17530541766553, -5.720127422211236],
+    [-9.062457643960682, 10.518565742438575, -2.973647374932785],
+    [-4.054758986078544, 5.622469049694495, -1.8180132581492972],
+    [-1.1010147760214397, 2.7198658744470916, -1.127783075899675],
+    [0.9438161901423826, 0.6943707903306658, -0.6408041176999802],
+    [2.5234896121070354, -0.8841122967527546, -0.256937271305166],
+    [3.8642342194908412, -2.2325110456816732, 0.07371163367321226],
+    [5.10623593967482, -3.4836882274809, 0.3813116593231396],
+    [6.353267612521633, -4.7349971172412495, 0.6877259792121122],
+    [7.699352552755205, -6.073996280817049, 1.0124694619003263],
+    [9.25105801172777, -7.599514973525115, 1.377489428377259],
+    [11.156920654416195, -9.449086220613074, 1.8133131417612256],
+    [13.663847279090557, -11.851290678547182, 2.3707414399205673],
+    [17.259303995614914, -15.257614477608564, 3.1503279800025297],
+    [23.14367683309419, -20.780539918665387, 4.4004978559757735],
+    [35.67121336580021, -32.45794221658342, 7.025915043960865],
+    [-23.27543734289436, 23.715414625226494, -5.8208457446541875],
+    [-11.923558082721017, 12.736798535557332, -3.2606762825431583],
+    [-6.8827646506679985, 7.844273059325324, -2.1159565894459864],
+    [-3.8483751603335294, 4.881198617751977, -1.4170670189048098],
+    [-1.72867536143179, 2.7946363042846816, -0.9195741804534543],
+    [-0.09137056312070291, 1.1687740462521121, -0.527461293340904],
+    [1.2845836946216396, -0.20781088737288503, -0.19227262850271],
+    [2.5374679779633893, -1.4662503422582975, 0.11575252134524092],
+    [3.7715978525494736, -2.704583625445852, 0.41865172728006433],
+    [5.08239754496042, -4.01191363052316, 0.7363277851179602],
+    [6.577292835427927, -5.488252249535652, 1.0910778293714654],
+    [8.404714352116548, -7.271730058052057, 1.5137355985116725],
+    [10.811441060550893, -9.592162591146717, 2.0557340822864263],
+    [14.290280412540406, -12.908917120965349, 2.8201904293638727],
+    [20.086658942755133, -18.383940501581367, 4.068792713233206],
+    [33.002951423634656, -30.50114552723215, 6.815393897923866],
+    [-25.641212883480666, 25.411173206017104, -5.973965290563245],
+    [-14.718070364424273, 14.927897238780549, -3.5522775157797795],
+    [-9.62517146228106, 10.018255293672032, -2.4129909327526082],
+    [-6.5046644295018465, 6.989240405974079, -1.7038741079517876],
+    [-4.310467783605345, 4.840610136610019, -1.1950671914736264],
+    [-2.6175001446455677, 3.1667478405286262, -0.7937554540123035],
+    [-1.2069139025959286, 1.7595565028392384, -0.4525688373525021],
+    [0.057842703311224535, 0.48985681348815513, -0.14228890825063742],
+    [1.2800823964866772, -0.7395099737940879, 0.15891711137012407],
+    [2.55535410463986, -2.017999332584873, 0.4710678627577198],
+    [3.9917391254605055, -3.4466585564452377, 0.8167760038431692],
+    [5.738148556740376, -5.164851648081113, 1.2273261920579523],
+    [8.042455456083097, -7.404976406249596, 1.7551028678327667],
+    [11.40579221547328, -10.63762253290917, 2.5066108479834535],
+    [17.13191897293302, -16.0885361708044, 3.7603720295091962],
+    [30.623132796455305, -28.841287330579217, 6.676911352190736],
+    [-28.241127671873564, 27.36081772490176, -6.190126562673605],
+    [-17.594275373172806, 17.210599625642978, -3.8660174887148413],
+    [-12.424056026357942, 12.256209274129173, -2.7251442569372775],
+    [-9.207568758333128, 9.1505337219141, -2.0029869492459556],
+    [-6.933621110629134, 6.933774755137253, -1.4810815701197637],
+    [-5.1816426468316354, 5.207322577694129, -1.0690000025390416],
+    [-3.733495801855139, 3.764879784684939, -0.7201105305314636],
+    [-2.4536709617383106, 2.4786646635958793, -0.40564236057461883],
+    [-1.2405286042132075, 1.2531286854554327, -0.10415727051085358],
+    [-3.3285246073321093e-9, 3.482760038345375e-9, 0.20412414438203863],
+    [1.3754287341639824, -1.381687701266342, 0.5418779198488649],
+    [3.0349163881319288, -3.0324172068181454, 0.9408202422991523],
+    [5.22789255826777, -5.187888540914768, 1.4544835068679176],
+    [8.46534736494833, -8.332177273498395, 2.193403808196596],
+    [14.119948922828431, -13.767692079542838, 3.4564220239499375],
+    [28.384070814370066, -27.37559391062043, 6.6003627872231165],
+    [-31.24387834232299, 29.696928296226776, -6.4879602178930975],
+    [-20.714328703452793, 19.71733416233395, -4.222433615085257],
+    [-15.42860160541718, 14.678580082612802, -3.0705395154521473],
+    [-12.099473421078054, 11.47845668699586, -2.330932105243749],
+    [-9.736868852544466, 9.183352868040092, -1.7931335236576977],
+    [-7.92043795777954, 7.397284552720346, -1.3680926077565039],
+    [-6.430665065192013, 5.913698133134669, -1.0094625500623768],
+    [-5.131941829964045, 4.605128341655584, -0.6886917323671865],
+    [-3.9242070155551754, 3.377555490601119, -0.3847287919564678],
+    [-2.7162471404911637, 2.1451604528888235, -0.07831869588361816],
+    [-1.4035247153047752, 0.8092360261903462, 0.25280019576914214],
+    [0.1613319087706801, -0.7701015070985818, 0.6404149517855439],
+    [2.2282362577608184, -2.831314951201214, 1.1391533691735147],
+    [5.317623126276124, -5.8728378967635235, 1.8639985934564474],
+    [10.8780727111473, -11.284831906706835, 3.1375350170411047],
+    [26.143090563237205, -26.015724617737245, 6.582392069867651],
+    [-34.89674737125327, 32.622511867879126, -6.89965171145945],
+    [-24.294504634958844, 22.627230232585223, -4.650271809357022],
+    [-18.833536143678963, 17.445011014300903, -3.473996737309239],
+    [-15.363578254666068, 14.121523553434281, -2.710093310050585],
+    [-12.897468601005082, 11.73154588871043, -2.1520695084974575],
+    [-11.00877320262418, 9.875633823991297, -1.7109250005428547],
+    [-9.473532844531096, 8.344072602691806, -1.3399798135512233],
+    [-8.154195469662769, 7.008008099470738, -1.0105454451799063],
+    [-6.95120764750223, 5.77400369279678, -0.7017674807948426],
+    [-5.776295338210863, 4.558861087713865, -0.39497373801130997],
+    [-4.529861505932059, 3.268106066827275, -0.06878459690125711],
+    [-3.0696415687128917, 1.765740666382952, 0.3078824911829272],
+    [-1.147971871844405, -0.18699043742260257, 0.7901918228793403],
+    [1.7616798014699384, -3.1015572653728105, 1.4975944170387434],
+    [7.1815084185646425, -8.46175715321234, 2.779384319774459],
+    [23.730880719805487, -24.669445315572816, 6.626929343412947],
+    [-39.614217597289965, 36.48582731147206, -7.483614458208874],
+    [-28.67528430681233, 26.224812716089648, -5.196167434922956],
+    [-22.941507876708993, 20.805699516058834, -3.9751720113224436],
+    [-19.282925901927168, 17.3112655778081, -3.1760406225401807],
+    [-16.688575895848686, 14.799583921112287, -2.5909135159512013],
+    [-14.716031635353453, 12.858503681226612, -2.1289062779628645],
+    [-13.132167103643427, 11.27075264758644, -1.7420902749837006],
+    [-11.79480980028458, 9.903595377465422, -1.401116053316089],
+    [-10.603238741199677, 8.662618804021765, -1.0849914095407698],
+    [-9.471309958069298, 7.466514267318553, -0.7754689506430852],
+    [-8.305342308480485, 6.226052418180193, -0.4522872383579857],
+    [-6.972593932998368, 4.813621655573347, -0.08613416707957618],
+    [-5.233627502932141, 2.996927292074933, 0.37694440926450995],
+    [-2.553719723686255, 0.2516166099625695, 1.0603552635901001],
+    [2.66776986223982, -5.011088222352593, 2.344018486403587],
+    [20.899991649854403, -23.214806165355178, 6.749172749017262],
+    [-46.196077289445604, 41.966586646764476, -8.35690164025705],
+    [-34.48413081805876, 31.037130349655023, -5.947569865931586],
+    [-28.304182964649357, 25.218720196604433, -4.64745755507646],
+    [-24.372182749494634, 21.47052386250576, -3.794411361587371],
+    [-21.60780083157118, 18.79223526746501, -3.1706712053517294],
+    [-19.53515666132912, 16.743041488729208, -2.6802257081878462],
+    [-17.90381050530679, 15.090614732182736, -2.272357353106174],
+    [-16.56268234190839, 13.694311536511353, -1.9161977756326116],
+    [-15.407436183691162, 12.456348534854023, -1.5900690393036885],
+    [-14.352739634219931, 11.295900900506055, -1.2757808946329965],
+    [-13.310881162864836, 10.128829305856865, -0.9540735240343611],
+    [-12.162754951687344, 8.839631044180493, -0.5981498252100081],
+    [-10.692360780800287, 7.217237832319929, -0.15860915351355442],
+    [-8.379418992526794, 4.7492370754294235, 0.48558393459683363],
+    [-3.4966175404066115, -0.28993482541594373, 1.7491482415997408],
+    [17.200915367987044, -21.440239278482178, 6.988242132125078],
+    [-56.500742364921464, 50.65285646738724, -9.796501017164635],
+    [-43.11460250948351, 38.23820527867947, -7.100695141721495],
+    [-36.13109919804792, 31.69090526666375, -5.65319594142765],
+    [-31.75833102956925, 27.527346413640174, -4.710121703658645],
+    [-28.750445154427673, 24.60237448007416, -4.026639936203675],
+    [-26.5615842860828, 22.413535233833926, -3.4950355816394327],
+    [-24.907765977510874, 20.698528577311393, -3.05873615387761],
+    [-23.62142321638437, 19.301481114151386, -2.6837195543028374],
+    [-22.591401580530142, 18.117649295981572, -2.3466449839865224],
+    [-21.73258974645422, 17.06520762063016, -2.0287219926170263],
+    [-20.964754748476185, 16.06520430907057, -1.7112280036250844],
+    [-20.18722917930378, 15.016982753966353, -1.3698186754359873],
+    [-19.224794230575554, 13.744873018051052, -0.9619564654479821],
+    [-17.64479629227603, 11.816786539786785, -0.3837606692264612],
+    [-13.742014677932778, 7.526212148829328, 0.7755399341223849],
+    [11.364264591522048, -18.69007162060901, 7.430380864889193],
+    [-76.4165277935023, 67.59128152782256, -12.688950605431014],
+    [-58.692301278287665, 51.310851445332396, -9.240611650275865],
+    [-49.930926551785454, 43.14811831234585, -7.466289509056726],
+    [-44.709243115553654, 38.17716042357058, -6.34579817507001],
+    [-41.328123972841254, 34.85199695884841, -5.5575835312525825],
+    [-39.070169752491935, 32.51715005621574, -4.964137769218209],
+    [-37.57933994964517, 30.844085035791494, -4.495243030959987],
+    [-36.66150027182968, 29.64964692575654, -4.110186993528119],
+    [-36.20576370416678, 28.823907000029187, -3.782529329926418],
+    [-36.14871575906407, 28.296492364948396, -3.4926656632055986],
+    [-36.45538035366255, 28.01726968281417, -3.2230428707067276],
+    [-37.10417878246715, 27.938937692773642, -2.9531195354643742],
+    [-38.059489618855515, 27.98431963058169, -2.649641096112176],
+    [-39.16954081011513, 27.93358546779195, -2.2358013893316806],
+    [-39.52329772225658, 26.756768456374477, -1.4225423045053514],
+    [-9.722380474878447, -6.103987429401143, 7.515312705783094],
+    [29.36237751971479, -10.34666795109414, -3.7316251072147026],
+    [47.507795804099416, -30.52071993546508, 1.8428888500103158],
+    [47.116071753490154, -31.722144428465906, 2.6366312511977594],
+    [46.03168159918542, -31.887989355153138, 3.0689802624193883],
+    [45.09989518075357, -31.949440997544727, 3.3952187269410254],
+    [44.483146089711276, -32.128809597252754, 3.6887616889885404],
+    [44.228733213256476, -32.51233207236062, 3.9830845520803986],
+    [44.36834168930932, -33.156089979734, 4.299705772597547],
+    [44.94734344685617, -34.11907732030102, 4.657492681363581],
+    [46.0423995223107, -35.48226851494981, 5.077817503365063],
+    [47.78426324325637, -37.37065275405137, 5.5897519511506335],
+    [50.400506216188035, -39.99198604357634, 6.238384814006891],
+    [54.309773647398806, -43.7204138280078, 7.1021260221395845],
+    [60.363020560586115, -49.30905773369864, 8.336187152187783],
+    [70.60640406482217, -58.56097995121687, 10.309322408317742],
+    [91.76856960572893, -77.39329938884131, 14.227048802667312],
+    [1.7933245635387522, 8.12125620070005, -5.245654315951395],
+    [21.737756061490174, -12.202878016281336, -0.16687248448768935],
+    [25.101522301339593, -15.950257815315197, 0.8658793894130741],
+    [26.517473271854488, -17.746657628384845, 1.4205664386989474],
+    [27.41918298116192, -18.990841837761057, 1.8288151009155569],
+    [28.18755366712497, -20.056056996153064, 2.1796977654707144],
+    [28.985359784361755, -21.10299689895672, 2.511973297745998],
+    [29.91179060483648, -22.229241974783886, 2.8491094417689036],
+    [31.051053635508573, -23.515949173516848, 3.2101398698198365],
+    [32.49723330249865, -25.05131808272628, 3.615002156922262],
+    [34.37750394059209, -26.95189200841947, 4.089201239916368],
+    [36.88788823946084, -29.394734550071362, 4.670657441358641],
+    [40.36622423212709, -32.6825807237484, 5.423413539173479],
+    [45.47272964763349, -37.4046719701011, 6.4713546301109695],
+    [53.74684072852688, -44.93239884291495, 8.102030299914126],
+    [70.05244129340232, -59.59469299834092, 11.222062057589138],
+    [-6.600724164852, 13.258079255463516, -5.399241382070058],
+    [10.702510307049797, -4.140410957012622, -1.1318997300586973],
+    [15.115676490033174, -8.656233087107262, 0.001680938446916747],
+    [17.24245441081528, -10.934489287191106, 0.6034539686520428],
+    [18.64855972638845, -12.497676094502744, 1.0322680753920437],
+    [19.79419600363874, -13.78843202678452, 1.3911410059633322],
+    [20.874607451013805, -14.993543664507909, 1.7232910798254053],
+    [22.003459214611606, -16.22094030751326, 2.0534778571825414],
+    [23.2702979635829, -17.554563534067306, 2.4006551218361873],
+    [24.76784112616174, -19.080011450908724, 2.783769957747819],
+    [26.614678020177266, -20.905396992712525, 3.2263465979214674],
+    [28.98764375119555, -23.19063263528108, 3.7627909274892297],
+    [32.18606435713151, -26.205201163440687, 4.450731363980338],
+    [36.789706202564666, -30.469953506105306, 5.401254156026087],
+    [44.14636088948344, -37.194656867216096, 6.872198344535665],
+    [58.523658353716726, -50.20839177806753, 9.679841041951427],
+    [-11.029108730297693, 15.851277520339105, -5.393508314831999],
+    [4.025510277635243, 0.80672578706618, -1.7393240756874455],
+    [8.816454113831456, -3.9750617846204155, -0.5754882168056032],
+    [11.308129050942687, -6.5157327228469475, 0.059341139291770977],
+    [12.966699742146721, -8.246019539260704, 0.5031338138425806],
+    [14.286188376775423, -9.640153886380313, 0.8658442440566417],
+    [15.484632083877464, -10.90529818743251, 1.1949425854690459],
+    [16.686196263426424, -12.15833177585216, 1.5169238533145173],
+    [17.984079002115266, -13.486020334271785, 1.8511984210417813],
+    [19.4700948197811, -14.972866195417067, 2.2164034943903412],
+    [21.25764744547063, -16.72230469773685, 2.635093314033715],
+    [23.51274444538739, -18.884912508459482, 3.1398737236971983],
+    [26.514463415725068, -21.713107202277804, 3.785224542340048],
+    [30.803885240316376, -25.695694068456035, 4.676549969871164],
+    [37.64939133871932, -31.97811406579782, 6.060962264787358],
+    [51.15345927021487, -44.264968836047956, 8.738612868039615],
+    [-14.104736932996387, 17.666677725992102, -5.3862543704753785],
+    [-0.6909899953820676, 4.338176960950972, -2.176351420276824],
+    [4.218215935593945, -0.507637805735272, -1.0143893122019438],
+    [6.923767790819598, -3.206536815037516, -0.3577952870459705],
+    [8.746884333346092, -5.052920119530413, 0.09984148719123769],
+    [10.175038663448628, -6.515749807970103, 0.46734430234748636],
+    [11.438888814670115, -7.813992475862488, 0.794773048659021],
+    [12.671533100075782, -9.072639470574229, 1.11035407475071],
+    [13.970219093492506, -10.382069753122721, 1.4342542576095236],
+    [15.42719368531368, -11.827195991451918, 1.7852098925870221],
+    [17.153215180320657, -13.509251966987073, 2.1853763343113086],
+    [19.30819711470051, -15.573805098945028, 2.666470624762687],
+    [22.160298119695515, -18.264500868053073, 3.281497906456282],
+    [26.23245099698434, -22.056182439085195, 4.133785218653072],
+    [32.76878752203331, -28.078580985401928, 5.469478943131557],
+    [45.930458527821, -40.11091190152821, 8.113592848175063],
+    [-16.640890965589882, 19.228376276878848, -5.411279916781474],
+    [-4.378035448980943, 7.129182274056886, -2.524272904024788],
+    [0.5462625027606968, 2.2982635440555867, -1.37637873373467],
+    [3.3829179869444896, -0.49888089911267386, -0.7060591321287438],
+    [5.3242426710297766, -2.43333272731368, -0.23602448575802318],
+    [6.833465040789758, -3.952158892384758, 0.1376241001019661],
+    [8.142597659027976, -5.276001330571817, 0.4653503656674551],
+    [9.390658154549216, -6.535452009161692, 0.7766211643317397],
+    [10.678970473935113, -7.8247080812126555, 1.0924480560909788],
+    [12.10121091453217, -9.230121170257904, 1.4319152277317118],
+    [13.767186163661115, -10.852336910202148, 1.8171352351670755],
+    [15.833573394679176, -12.834534868620437, 2.279485473891259],
+    [18.562993062312174, -15.416441878439455, 2.8714900996813144],
+    [22.4720530101565, -19.069564622389557, 3.6965740839213135],
+    [28.81389799173383, -24.937979789435094, 5.006180593221525],
+    [41.96561735109681, -37.01936418208535, 7.681216554872028],
+    [-18.954217162957107, 20.732904346207953, -5.475469771936688],
+    [-7.503450343041031, 9.523820150533322, -2.826792801220228],
+    [-2.5915080651048124, 4.727164496713605, -1.6950972767499892],
+    [0.33129524421210305, 1.8642634867057506, -1.0155206306133457],
+    [2.3615262251801634, -0.1398155489017473, -0.5345448281446079],
+    [3.9365008892768647, -1.7081720865507037, -0.15380518683295857],
+    [5.282377635294648, -3.0564949493586195, 0.17608703193549255],
+    [6.5398591894937885, -4.3172261868498065, 0.48503725756859295],
+    [7.8133757532338945, -5.587607453353254, 0.7947519904557087],
+    [9.198518375887684, -6.955953552603224, 1.1247885961251631],
+    [10.805267497767616, -8.523404854333348, 1.4974537625559499],
+    [12.788892835385575, -10.432467278082022, 1.9441488612030808],
+    [15.409815036754583, -12.922049702269812, 2.5175689475418896],
+    [19.185817371068122, -16.467297019663608, 3.3227193538646556],
+    [25.402267823423276, -22.248370307671415, 4.62108217331579],
+    [38.79170043052291, -34.61292059927767, 7.379104819014386],
+    [-21.218591561288857, 22.289824846242556, -5.582703501991183],
+    [-10.351114113988237, 11.733624957416213, -3.1120414555359006],
+    [-5.435230649927394, 6.956649185843223, -1.992880224909682],
+    [-2.445255537699985, 4.040890297003171, -1.3055087261118963],
+    [-0.34397993999937954, 1.9780867887709244, -0.8143981984809258],
+    [1.2866241895002406, 0.3642438619989293, -0.42591548282833014],
+    [2.665008420123641, -1.0094532128973868, -0.09223361332480484],
+    [3.929975981190198, -2.274042835104005, 0.21627351107908818],
+    [5.187054024456066, -3.5280589228067734, 0.5216341732179395],
+    [6.533477346565328, -4.861607160462484, 0.8438486080443087],
+    [8.080030141375314, -6.37696499037676, 1.2055605823318596],
+    [9.981755150967148, -8.21712349703867, 1.6384482335455726],
+    [12.498986576322052, -10.622247864828608, 2.195832738557611],
+    [16.155647661728665, -14.076014981601618, 2.9853971378278343],
+    [22.287241171622263, -19.812306320280967, 4.282721506372107],
+    [36.12800819597473, -32.670627013518946, 7.1729549906264065],
+    [-23.567162934196457, 23.989470020757594, -5.739368222163381],
+    [-13.111008177844804, 13.904221174606514, -3.400324375670128],
+    [-8.162571315812153, 9.120691466105825, -2.287656892192909],
+    [-5.103136837731681, 6.148871631425345, -1.591309238086313],
+    [-2.937564106865794, 4.030266502975407, -1.0897622290337732],
+    [-1.257220623660079, 2.372461650976607, -0.6927789330931835],
+    [0.15122604983196813, 0.9717975726580318, -0.353862205069247],
+    [1.4233910576781073, -0.3000286811215831, -0.04399855164262549],
+    [2.663661817441293, -1.5408198114476281, 0.2587159394372503],
+    [3.9696771170439593, -2.8414088394352524, 0.5744974890980458],
+    [5.452970165721333, -4.305243623806661, 0.9263398195785343],
+    [7.26898231399456, -6.076491991823475, 1.3463573353020124],
+    [9.6791936535758, -8.397936421335617, 1.8888186822919164],
+    [13.216822914345151, -11.76526543937297, 2.664931345611814],
+    [19.28205595888435, -17.48146632914355, 3.968144805603485],
+    [33.78224311384125, -31.04864408315937, 7.043399308269337],
+    [-26.133579455518856, 25.931027364700768, -5.956605855771096],
+    [-15.937422050286877, 16.15825642783922, -3.709705081652672],
+    [-10.924403250839877, 11.336563666027835, -2.5960922590310433],
+    [-7.78227927844826, 8.296033256658195, -1.8877664584996086],
+    [-5.548151617598339, 6.116250365656683, -1.3741139654931138],
+    [-3.8178848291068626, 4.41175614168195, -0.9672758360378948],
+    [-2.3795972873574742, 2.9812075276296053, -0.6215814977382397],
+    [-1.099656941994463, 1.6983051475319781, -0.3085535648080519],
+    [0.12409529274479782, 0.46710905472523295, -0.006693978979842534],
+    [1.3878425768760385, -0.8022477510113595, 0.3040535411331107],
+    [2.80274803928283, -2.213440473077774, 0.646831839395172],
+    [4.5244924020314015, -3.91182385524658, 1.0542088370627094],
+    [6.816097656195012, -6.1434950999745235, 1.5815970161375348],
+    [10.221565363931557, -9.418394273727445, 2.3443641318158304],
+    [16.217354180835468, -15.122376905944495, 3.657488370226083],
+    [31.600471132383046, -29.6416643987012, 6.98039710591589],
+    [-29.08377302334072, 28.24659831427699, -6.253222759831624],
+    [-18.992827040912005, 18.628772430647132, -4.060802039846519],
+    [-13.873737717923815, 13.727436382792934, -2.9366428349093443],
+    [-10.62931635514558, 10.598663314010986, -2.2117423665887466],
+    [-8.315961621673782, 8.346448258950852, -1.6830813814941452],
+    [-6.529904451894951, 6.587968831993824, -1.2641685420066195],
+    [-5.058431757502856, 5.121996158567738, -0.909681467174901],
+    [-3.768470185492928, 3.8230104809224015, -0.5914417061379146],
+    [-2.560024997986496, 2.596987270769228, -0.28839848674436674],
+    [-1.3400073933633474, 1.3565743382935322, 0.01899099725172869],
+    [-4.8840981867908674e-8, 4.8876738885155965e-8, 0.35355337897085376],
+    [1.6142293378079835, -1.6179010238677842, 0.7480267457651087],
+    [3.766360692309147, -3.7461726401098954, 1.2589488202903296],
+    [7.0107318697895975, -6.909850088652164, 2.0062492816030635],
+    [12.907647669227085, -12.589309375419148, 3.3299637973499028],
+    [29.432226168696285, -28.358880612857057, 6.981062391217762],
+    [-32.66058014589646, 31.138027146509412, -6.661447605187829],
+    [-22.490908182323274, 21.494234617363688, -4.482310132394421],
+    [-17.205073595985606, 16.453664373467785, -3.3342431666344297],
+    [-13.828656654790692, 13.206818069197801, -2.5858308942835473],
+    [-11.419849879803035, 10.864814357766567, -2.037741902448593],
+    [-9.569021687004936, 9.041412653064151, -1.603528749954597],
+    [-8.059519317297779, 7.5325455282520135, -1.2375886406772587],
+    [-6.756980539870583, 6.211760360190576, -0.9116843064622722],
+    [-5.562728512148747, 4.98618420214312, -0.6050578081757723],
+    [-4.387537929503959, 3.771692752004305, -0.2988413443658283],
+    [-3.128798639404274, 2.471179676465843, 0.028877093965369086],
+    [-1.6382193117885804, 0.9435850875632169, 0.41017670638103104],
+    [0.3443242942590306, -1.0598740637440602, 0.9021178964734818],
+    [3.3791981357388927, -4.077321435328061, 1.6290942637629457],
+    [9.110752440108895, -9.691092096594456, 2.959324164511478],
+    [27.095096499489806, -27.104279179994712, 7.050472063034979],
+    [-37.272516309029086, 34.95277820085497, -7.239864004580975],
+    [-26.76785034302292, 25.03866246666556, -5.0209319207685],
+    [-21.21844525566282, 19.765776033914424, -3.8286984107774655],
+    [-17.661891872157966, 16.352970206867347, -3.0458128542422056],
+    [-15.132300014830802, 13.893948687994838, -2.471374259997265],
+    [-13.204415766637483, 11.989875133220137, -2.0170527349529124],
+    [-11.65288049568755, 10.429398038521859, -1.6360399586402037],
+    [-10.33924987277952, 9.082552674721748, -1.2995020533930348],
+    [-9.16437175658398, 7.85599262446182, -0.9866332965606818],
+    [-8.04210893605266, 6.668183626694291, -0.679114522260755],
+    [-6.877075104417557, 5.428160688506357, -0.35629605578921514],
+    [-5.532170536207183, 4.004146151605083, 0.012046470980967708],
+    [-3.758748298052187, 2.155249609895992, 0.4817320927178343],
+    [-0.9995033662709139, -0.6630371466291551, 1.1804009857157065],
+    [4.453782742847856, -6.129834523582119, 2.5060079383916802],
+    [24.315680793883793, -25.748493802261013, 7.206601132484962],
+    [-43.71407058581536, 40.374315053466574, -8.106704303931268],
+    [-32.447325624930706, 29.792240165004902, -5.764968803449813],
+    [-26.462911455132186, 24.12510812800898, -4.494176036411108],
+    [-22.64193470150892, 20.463452538158233, -3.658075025752761],
+    [-19.9498380183482, 17.84239090028051, -3.045732769714128],
+    [-17.92852360730337, 15.834608399086283, -2.563696539383441],
+    [-16.335802849306795, 14.213939284184306, -2.162401891771427],
+    [-15.024786549367217, 12.842805391409335, -1.8115459163749588],
+    [-13.893258093842741, 11.6248928966535, -1.4897116098865353],
+    [-12.856675227414518, 10.47976548419816, -1.1787548000275934],
+    [-11.82686108978669, 9.322537867445332, -0.8592187307253949],
+    [-10.682292578411985, 8.035029075607799, -0.5036691718612258],
+    [-9.200211969326507, 6.399003937368363, -0.06101031612717852],
+    [-6.842825664417013, 3.883410827568167, 0.5942733819835082],
+    [-1.8217699044172913, -1.3012737833564723, 1.8923518383142057],
+    [20.595467140957197, -24.070536744937208, 7.497670328543906],
+    [-53.85718203463182, 49.02184259230182, -9.54560870609265],
+    [-40.93310064848746, 36.95241149532631, -6.9153596509289255],
+    [-34.15728342094521, 30.559070435604134, -5.496974967462031],
+    [-29.905064878267456, 26.48587335706707, -4.571133275978291],
+    [-26.977653238487868, 23.622538477032503, -3.8995622786569752],
+    [-24.84770361395084, 21.48006178323788, -3.377035217436655],
+    [-23.23998031612422, 19.802409068652125, -2.9481225217521687],
+    [-21.991545772641892, 18.43698194087903, -2.579389579894944],
+    [-20.993871218036396, 17.280854223188268, -2.247810549738728],
+    [-20.16335213959908, 16.253167495675356, -1.9347433582513083],
+    [-19.420466884294683, 15.275240538084358, -1.6214614076807858],
+    [-18.664311461780688, 14.245674545103071, -1.2833695999486907],
+    [-17.71720986000477, 12.985470076769175, -0.8770270426993514],
+    [-16.135315855163867, 11.049092983712983, -0.2950925082191118],
+    [-12.154248735938047, 6.6605624660373, 0.8911758742605814],
+    [14.723466835478519, -21.532129726448503, 8.065451671937025],
+    [-73.80253441339507, 66.18862802399605, -12.490505406315611],
+    [-56.492305987861776, 50.16771041558903, -9.087624693813064],
+    [-47.92949022052506, 42.1326105073758, -7.33557926296936],
+    [-42.83224233733494, 37.245886316356994, -6.229807306696446],
+    [-39.54270709783606, 33.98676292372371, -5.453075641973408],
+    [-37.36064950942796, 31.71022930382129, -4.869610252467856],
+    [-35.939057102386165, 30.09339334243195, -4.410076318629563],
+    [-35.08976123070762, 28.95703707037586, -4.034321101319816],
+    [-34.70697921869462, 28.194835040526797, -3.7163531325829955],
+    [-34.732923285386114, 27.74058125534886, -3.437019279549722],
+    [-35.140182705549606, 27.549829328615342, -3.1793073629226942],
+    [-35.91879319529222, 27.58390745554122, -2.923396345776512],
+    [-37.05274366264164, 27.779761956288553, -2.637027602382608],
+    [-38.426040840455656, 27.941868103967593, -2.2445978881673674],
+    [-39.19299542041912, 27.076436969030315, -1.455576682042722],
+    [-5.808509894649563, -10.064126125560728, 8.71962294024313],
+    [36.67312686354964, -15.776383052909273, -2.7757254618702034],
+    [51.74034358385287, -32.98380058136443, 2.108028811828204],
+    [50.91580616888487, -33.91494742278978, 2.874415652973266],
+    [49.5604627475783, -33.92920601898359, 3.2970287549550927],
+    [48.44054654741568, -33.891253927167774, 3.618826030540367],
+    [47.69210292451788, -34.00533746954921, 3.911066562352534],
+    [47.3495950999729, -34.34995700457918, 4.2066577514390495],
+    [47.438223246748926, -34.97773535216895, 4.527051042941761],
+    [48.00094615889587, -35.94684336364907, 4.891339443833797],
+    [49.115608195094275, -37.339834814812164, 5.321435782679836],
+    [50.918524434764116, -39.2863667512075, 5.8474317352845],
+    [53.64982417760561, -42.004008922067456, 6.516314724803825],
+    [57.75454320793286, -45.887156734044126, 7.410256865137964],
+    [64.14320079577654, -51.73462409347284, 8.692876394625864],
+    [75.02306410915999, -61.474198073405354, 10.755982882769963],
+    [97.76029463992485, -81.52550884996118, 14.899306496604563],
+    [5.890243676618436, 5.039991364985541, -4.682904188209656],
+    [24.589999397610182, -13.933333989435846, 0.048854917346846334],
+    [27.730528207876365, -17.48788107007461, 1.0437981081582302],
+    [29.049512819153396, -19.218393904859106, 1.5904570101232132],
+    [29.894016062665177, -20.431579154785368, 1.9976223247347547],
+    [30.62847560223263, -21.483579420730027, 2.350326743340086],
+    [31.411098975247945, -22.530485423286557, 2.686325171600967],
+    [32.34018016077374, -23.668907610827702, 3.028908242310474],
+    [33.50107907103337, -24.98083285672905, 3.397298157532],
+    [34.99101032929727, -26.556859415914456, 3.8119104898678806],
+    [36.943076623418385, -28.518065646399826, 4.299112590057577],
+    [39.564143573362756, -31.049793170811633, 4.898366558447295],
+    [43.21293771173288, -34.47076539083322, 5.6766755184061815],
+    [48.594666772542254, -39.40490932713194, 6.76437031552651],
+    [57.36626106821407, -47.31529684921828, 8.466220456280178],
+    [74.83404175437012, -62.88264679466004, 11.756089122373751],
+    [-3.640817648046207, 11.059925132117536, -5.000984312291205],
+    [13.046567432364522, -5.600226642997166, -0.9353758417979083],
+    [17.301396779632956, -9.955056048664204, 0.16079843039581557],
+    [19.358701823989968, -12.172628139936425, 0.7509795364354489],
+    [20.73367097147764, -13.713755129724532, 1.1771916409428116],
+    [21.868325999987203, -15.00068277218763, 1.5372613478828083],
+    [22.95244954379876, -16.2139109274188, 1.8728154124479865],
+    [24.09863169249257, -17.459729523668504, 2.20816002006284],
+    [25.397519289537698, -18.822555527343983, 2.5622785880031325],
+    [26.94477865836983, -20.390051693110802, 2.954474357589318],
+    [28.8644106384191, -22.274350759642367, 3.4090001099391136],
+    [31.3429108308479, -24.642674820450182, 3.9616025168242373],
+    [34.69791886654133, -27.778512948216076, 4.672499337122423],
+    [39.54828239170618, -32.232929361099764, 5.658418808110801],
+    [47.343506466036544, -39.2954206916884, 7.192302029458556],
+    [62.734859021764606, -53.1018418050185, 10.149729457872048],
+    [-8.621724307419171, 14.090974903003033, -5.079231067736867],
+    [6.072861648777286, -0.4840773338117645, -1.558809128821437],
+    [10.761598972847306, -5.145864433438036, -0.4258487113671575],
+    [13.198179915363314, -7.629665370907757, 0.19602312182096682],
+    [14.832598104098219, -9.337173931782809, 0.6353828614327025],
+    [16.14755793752385, -10.72785270577121, 0.9980655497578995],
+    [17.35552660471085, -12.002044430050518, 1.3297278937085002],
+    [18.579081733863145, -13.27428478173727, 1.6561990144241276],
+    [19.912141377335388, -14.631337130086234, 1.9967802599494744],
+    [21.449123643074067, -16.15939001651399, 2.3703629617123942],
+    [23.30840435831484, -17.965492285464883, 2.8001338874011363],
+    [25.664947296784153, -20.20695181191114, 3.3199197067092436],
+    [28.814854702940448, -23.149192955226983, 3.9865992510185757],
+    [33.33568010158915, -27.309258812352095, 4.9108602549284415],
+    [40.59165295455035, -33.90794085131466, 6.354155075843248],
+    [55.05569623762534, -46.947330492899106, 9.174574177726175],
+    [-11.954700448912211, 16.12042073631845, -5.1154655815092385],
+    [1.1627637908464794, 3.1623809793760778, -2.008207706668517],
+    [6.007513277610904, -1.593714812600092, -0.8715131692781908],
+    [8.67330830682241, -4.244460242272189, -0.22721595376817647],
+    [10.476315080251192, -6.067428339601884, 0.22482697778943375],
+    [11.901434822712158, -7.524813894541791, 0.5910663753663715],
+    [13.176026417854281, -8.83059284609331, 0.9200900137656951],
+    [14.43163891136156, -10.107281088653389, 1.2393898587667829],
+    [15.765946714723029, -11.444894430930146, 1.5689218039290522],
+    [17.273497688768686, -12.929709399516879, 1.9275927604145953],
+    [19.069701007929392, -14.666269239016668, 2.338118076597582],
+    [21.32301459316409, -16.806521368623297, 2.8333592566978405],
+    [24.318007516989617, -19.606630105809426, 3.46862265626652],
+    [28.613132301468642, -23.568957188301454, 4.352385056927654],
+    [35.547470757995455, -29.898029434633717, 5.745064319441957],
+    [49.6637141993385, -42.68057047868441, 8.5318924632479],
+    [-14.59775818617275, 17.78225263525678, -5.163048574634629],
+    [-2.653939273675944, 6.032882229050004, -2.3652687140849307],
+    [2.2272524519950747, 1.2727655495118178, -1.2387290200246543],
+    [5.037755758837094, -1.4854512223019396, -0.5794497028846257],
+    [6.96395846630613, -3.398051056216852, -0.11537269838934504],
+    [8.470689872167831, -4.909546574900159, 0.25602960423168486],
+    [9.789964859727734, -6.238542910211276, 0.5843770517376393],
+    [11.060252012681785, -7.513951021266947, 0.8985475080652846],
+    [12.383334082641406, -8.829547742527074, 1.2193087538141159],
+    [13.854982223144443, -10.272816278409936, 1.565853204169322],
+    [15.58943144405321, -11.947485153560041, 1.9607962216229973],
+    [17.751673638751114, -14.00290014518799, 2.4366082453606976],
+    [20.620527192085742, -16.69112130892916, 3.0480704439433333],
+    [24.748159929709054, -20.51121014479919, 3.9037584718873344],
+    [31.48490928510431, -26.68392472046076, 5.269765430798907],
+    [45.618723192258436, -39.53921726610741, 8.092355538180396],
+    [-16.95304235452, 19.33648929223392, -5.239931844285031],
+    [-5.855130499293017, 8.475414421776334, -2.6733888343995114],
+    [-0.9861730105654839, 3.7452533029620607, -1.5613731263889854],
+    [1.920019500102282, 0.9141752646165994, -0.8916997210771329],
+    [3.9405616062756152, -1.0708853452164788, -0.41657043147278705],
+    [5.514175891989525, -2.6310979183186363, -0.03869830898776635],
+    [6.869111810521919, -3.982151626166142, 0.2909286436611722],
+    [8.147150470049418, -5.256244174980072, 0.6019270571635236],
+    [9.453713034298286, -6.5506159376491, 0.9158348829109209],
+    [10.886499760556557, -7.954672038073346, 1.252295511046906],
+    [12.559785600255974, -9.572481303194287, 1.6340754598915344],
+    [14.637044337762871, -11.552589607519764, 2.0936326820462927],
+    [17.394939239963016, -14.146244977588648, 2.685907838551178],
+    [21.387525163273132, -17.856695278664258, 3.5211481643441833],
+    [28.001965471780036, -23.944523653400594, 4.8762064158989515],
+    [42.42859567606123, -37.12895552252148, 7.790910534640667],
+    [-19.22610712147455, 20.916991491338916, -5.354509054038086],
+    [-8.727624971941335, 10.70230733973421, -2.960383601571306],
+    [-3.876299129364625, 6.00239509240361, -1.861545907181863],
+    [-0.9013929394533964, 3.116354504765608, -1.1835772912513576],
+    [1.1942645487827277, 1.0700802989779605, -0.6980900034745712],
+    [2.825140638824427, -0.535709166956041, -0.3128006789663897],
+    [4.211697711432332, -1.9101610635771402, 0.01988850112942088],
+    [5.495039330239322, -3.185256773757507, 0.3295841018244423],
+    [6.782660815748181, -4.460390976625771, 0.638324645419101],
+    [8.17425167255847, -5.827028590992412, 0.96624306238742],
+    [9.784903109626287, -7.390346516519405, 1.3364255576010224],
+    [11.777839942962695, -9.299315747732154, 1.7815814616141241],
+    [14.429848719413801, -11.806567521864546, 2.3572854800738186],
+    [18.302317061610808, -15.42479774459932, 3.176616993182618],
+    [24.839141126912487, -21.473640826095895, 4.531541951049593],
+    [39.79944805371145, -35.22037274264516, 7.592030243561275],
+    [-21.560758726937106, 22.62309489364063, -5.5150329188121185],
+    [-11.475814007250905, 12.868155515811639, -3.247593627130123],
+    [-6.617178132375137, 8.175387988737068, -2.156577797246227],
+    [-3.5829347501400814, 5.238686401138225, -1.4701789049606524],
+    [-1.423814915257908, 3.1369530665803773, -0.9743341335503071],
+    [0.2573755410762164, 1.487352403748816, -0.5807807908651502],
+    [1.673119269514925, 0.0875465545929649, -0.24343373575067892],
+    [2.9611403387803374, -1.191844150847644, 0.06678494795699062],
+    [4.228585375935398, -2.4502789534713485, 0.3719728328354781],
+    [5.576235169956733, -3.7805670808993894, 0.6926073881283277],
+    [7.120192071793233, -5.289261302358205, 1.0521589583886894],
+    [9.024156947647402, -7.12653469975503, 1.4837642864801446],
+    [11.566365267196245, -9.547830836809066, 2.0439628870548283],
+    [15.319022781546215, -13.079026730373796, 2.849553967677751],
+    [21.79909217735453, -19.11564283518306, 4.211656380260922],
+    [37.53150943352456, -33.66561931642602, 7.4759682244886125],
+    [-24.093359611881226, 24.55725743373143, -5.73346668127676],
+    [-14.265233511493475, 15.102991720982743, -3.5540300109695697],
+    [-9.361566431126926, 10.382641097591549, -2.4631497177322244],
+    [-6.259599443881257, 7.385902943979076, -1.765835655658682],
+    [-4.039995561794074, 5.227801048954661, -1.258543563618896],
+    [-2.3126355100407063, 3.5340948340997054, -0.8556196641819316],
+    [-0.8697621730177827, 2.106830487476597, -0.5120879275058217],
+    [0.4225135469108589, 0.8197200688358478, -0.19953481844962984],
+    [1.6687850845377108, -0.4248280532476659, 0.10378049390096725],
+    [2.9689058742317704, -1.719248174886553, 0.418319545147527],
+    [4.439142675074649, -3.1708277008790877, 0.7678101840036259],
+    [6.243679585983321, -4.931124455079687, 1.185873464787281],
+    [8.66262976321969, -7.259125736140477, 1.7302149956655626],
+    [12.280653315591739, -10.696247383416438, 2.522004024982077],
+    [18.700928060899184, -16.728962211972004, 3.895411482242202],
+    [35.46516678170559, -32.35732607602295, 7.432816872144174],
+    [-26.988862863262387, 26.852444030826184, -6.028992693737426],
+    [-17.262536242777905, 17.54313293616568, -3.9007616743235713],
+    [-12.26748268872435, 12.750904404543707, -2.800146365878802],
+    [-9.07690160706199, 9.6739520333845, -2.0873021994737573],
+    [-6.788934089232862, 7.450015779373664, -1.5659217374306624],
+    [-5.014145771767187, 5.707662715987659, -1.1517446051544944],
+    [-3.544991252906686, 4.249764392247922, -0.8002593736223024],
+    [-2.249437869590995, 2.9516739917192627, -0.4834836342186099],
+    [-1.0260360841049703, 1.7183005372412443, -0.18019858387336848],
+    [0.221769526769874, 0.4596797813748805, 0.12959040271599112],
+    [1.6079564609099712, -0.9301271725720492, 0.46943366461355357],
+    [3.2957312124309848, -2.602977912046299, 0.8732293749390536],
+    [5.566203224005577, -4.821093628878642, 1.3998491038289484],
+    [9.01591161284174, -8.142192040016498, 2.1752116290221233],
+    [15.34290066349227, -14.156323149213156, 3.56024336103831],
+    [33.443608329504436, -31.203750613689476, 7.46047757009761],
+    [-30.48685522053227, 29.71047325471836, -6.434078651650789],
+    [-20.68112179713847, 20.36815509001963, -4.316698225995223],
+    [-15.53147124907933, 15.442920075788926, -3.1928475319978196],
+    [-12.220716511142287, 12.254797135394716, -2.457427857536812],
+    [-9.847742970022187, 9.947443828062198, -1.9175369935563988],
+    [-8.017637019179935, 8.146166208598741, -1.4889717545252397],
+    [-6.519638197697734, 6.651473717790281, -1.127018256298846],
+    [-5.221309196629692, 5.338402471252951, -0.8037394352525745],
+    [-4.023481768655007, 4.113686685160407, -0.49832250460077854],
+    [-2.83427264281839, 2.891105776893947, -0.19152459911800498],
+    [-1.5457132857399563, 1.5693481094422361, 0.13933077683737333],
+    [-5.841362853867098e-7, 5.699182418685694e-7, 0.5276447200262874],
+    [2.0811847175436875, -2.079618142702026, 1.0329483709251812],
+    [5.301976255962866, -5.241515609026724, 1.7857890267188061],
+    [11.455739980378436, -11.187090364822735, 3.1770076116286274],
+    [31.27094074627593, -30.10593737219301, 7.565476082245741],
+    [-34.991283023013914, 33.479713344079286, -7.007804738069083],
+    [-24.854552888916356, 23.86325470979768, -4.848897334530778],
+    [-19.452751747219047, 18.711194490274927, -3.681465313969133],
+    [-15.972668124305564, 15.36323488962161, -2.9124113268477174],
+    [-13.48870861397209, 12.944780764663394, -2.3470367037652076],
+    [-11.590773556294769, 11.068675946819575, -1.899258174244519],
+    [-10.060043915856246, 9.528532307936032, -1.5232148031968686],
+    [-8.76081323407338, 8.196420138742821, -1.1904627608182166],
+    [-7.59450468748001, 6.979349951599172, -0.8802679290694363],
+    [-6.4737771424654, 5.794715423830642, -0.5741070354052484],
+    [-5.299770588397327, 4.548593938907043, -0.2507555142801288],
+    [-3.928004683721632, 3.1029957410214326, 0.12122368477807403],
+    [-2.0943937995933903, 1.2043357468144986, 0.6000838094453186],
+    [0.7985539965788363, -1.723833045996124, 1.3193892937241862],
+    [6.620672229799532, -7.48939055846168, 2.701257192549531],
+    [28.640288176475437, -28.92467105391756, 7.768750655702831],
+    [-41.29462595644559, 38.851539892259154, -7.870401771866052],
+    [-30.40503253612774, 28.56472322019362, -5.587066562177587],
+    [-24.579844345962552, 23.022889997405734, -4.3413937376560305],
+    [-20.845842512689018, 19.431436293320612, -3.5197836101383944],
+    [-18.20900937728545, 16.856500874811566, -2.9172773670526433],
+    [-16.226887617540804, 14.882483972677129, -2.4426405391457418],
+    [-14.664358366005278, 13.288424650302817, -2.047283609610391],
+    [-13.377893757002646, 11.939165756691267, -1.7013539985052795],
+    [-12.266685687346236, 10.739334861676545, -1.3836058179037363],
+    [-11.246248000554553, 9.60835563875421, -1.0758550395285251],
+    [-10.227004680166736, 8.459931410368778, -0.7583426841792428],
+    [-9.083513472899767, 7.172129118181153, -0.40282937808310926],
+    [-7.583248881455959, 5.517415282312741, 0.043799486445985004],
+    [-5.163078871308754, 2.9407955770676004, 0.7122773558408928],
+    [0.0684654015152321, -2.4403646275795188, 2.0525224107922573],
+    [24.96436301051297, -27.409759167177345, 8.126747735597766],
+    [-51.295260202307, 47.491427395881956, -9.315099335411242],
+    [-38.75433806235148, 35.703144588574965, -6.7386873152717754],
+    [-32.14696973457943, 29.43384132109998, -5.34430428817208],
+    [-27.991348166047555, 25.433665463306134, -4.43289497886789],
+    [-25.129122856746566, 22.6213814345497, -3.7716034492114257],
+    [-23.0488208905599, 20.51913291835897, -3.257243023242343],
+    [-21.48261039390574, 18.87600428598356, -2.8353053964191597],
+    [-20.271366109293016, 17.541998224691525, -2.4728143545871197],
+    [-19.30862038193278, 16.415561157600337, -2.146974318418372],
+    [-18.511851894824638, 15.416483689307801, -1.8392308175180596],
+    [-17.801910248211076, 14.466110045218272, -1.530805791812377],
+    [-17.077339894434957, 13.462075434907936, -1.1968106567723988],
+    [-16.157564528893992, 12.221722795558733, -0.7928455049794905],
+    [-14.586345442775785, 10.285135396540351, -0.20797229814683663],
+    [-10.534332184246082, 5.802401935903022, 1.0050951389065677],
+    [18.838090758832458, -24.9917037293125, 8.826099515684403],
+    [-71.39715708253976, 65.03595200234287, -12.341536029209745],
+    [-54.363853327071844, 49.15064024450153, -8.960289236961998],
+    [-45.94258706757126, 41.19400578396266, -7.221354064253893],
+    [-40.9409146245832, 36.36769579251371, -6.125890558463234],
+    [-37.72926101309622, 33.16380134144358, -5.358525341216628],
+    [-35.61991491668866, 30.943379308830743, -4.784327438321206],
+    [-34.273056443637024, 29.387429932468617, -4.33446315917741],
+    [-33.50598102689565, 28.32015264752761, -3.969177004037158],
+    [-33.2184735151941, 27.6391496773122, -3.662892310070587],
+    [-33.35990249007426, 27.2835820315833, -3.3969805217572504],
+    [-33.91356081422483, 27.217154291871736, -3.155174092505429],
+    [-34.887109642808355, 27.414488870194525, -2.9187805482741123],
+    [-36.296010605359825, 27.83573645597617, -2.65733664299397],
+    [-38.085621475185825, 28.32902920654219, -2.2981636635484155],
+    [-39.54286654777782, 27.98808774511788, -1.5545758669249354],
+    [-1.833879784994954, -14.51082941748435, 10.224128552306905],
+    [45.975131212895214, -22.42967605087, -1.7200883504104807],
+    [57.4860466142272, -36.420568873244, 2.4714369058001164],
+    [55.94564200285166, -36.909472683647834, 3.198617794782114],
+    [54.131705970514275, -36.6569628187111, 3.602941187932906],
+    [52.693214012408056, -36.43922145841412, 3.914126471263789],
+    [51.719935453230086, -36.430712470879406, 4.2006753210839305],
+    [51.22249457061402, -36.69570075369629, 4.494587050855847],
+    [51.213244331206305, -37.27960682119174, 4.8170584411574975],
+    [51.72914562368064, -38.23773408622491, 5.18734052849064],
+    [52.8476443986004, -39.65346655584499, 5.627945478168549],
+    [54.710919343580656, -41.6616878820701, 6.170214022801265],
+    [57.57444905737883, -44.49218372241822, 6.863551694415597],
+    [61.91701526912475, -48.56551455156699, 7.795019169939343],
+    [68.72699474184928, -54.74068125342731, 9.139307492683171],
+    [80.4259652662576, -65.11237930421831, 11.319010958928223],
+    [105.25073250737162, -86.78932222202882, 15.762636449582569],
+    [10.685241860994157, 1.5267735493345747, -4.07643827007397],
+    [28.037515021288478, -16.037977250703253, 0.304832904712855],
+    [30.899233177845854, -19.366894652484675, 1.2594954047179856],
+    [32.08344646864573, -21.010415723931498, 1.797100496271462],
+    [32.84208411286679, -22.17692269155301, 2.2025790326196812],
+    [33.52107663940695, -23.204279666766674, 2.556843696711868],
+    [34.27305034240323, -24.24345737734613, 2.8966452574103405],
+    [35.194959550094175, -25.389941432611348, 3.2451366647020556],
+    [36.37332191343351, -26.726686091141808, 3.621802109700085],
+    [37.908978022182545, -28.347164532937903, 4.047656124781009],
+    [39.942158842390306, -30.37798089696471, 4.550132302831733],
+    [42.69300594045387, -33.01467700621479, 5.170599275754469],
+    [46.54611040324704, -36.59584248414184, 5.979734596796822],
+    [52.2628931710064, -41.788826100315305, 7.115912837268685],
+    [61.64859409465542, -50.17250833799512, 8.905562731426466],
+    [80.57813644164113, -66.87941999259539, 12.408483688601667],
+    [-0.24154255469472088, 8.586765568848355, -4.571357472013267],
+    [15.795101876597418, -7.314183875310808, -0.709200058097429],
+    [19.855768634687816, -11.48261255327883, 0.3457852435218224],
+    [21.82662299671552, -13.630126191532037, 0.9238567565573572],
+    [23.159129538268303, -15.143754316318711, 1.3475267253622867],
+    [24.274996098675647, -16.423621983163972, 1.7090976216606644],
+    [25.35816037785449, -17.64370258084023, 2.048579382964722],
+    [26.520165883098613, -18.90871796186121, 2.389854833718549],
+    [27.853066250102277, -20.303832544953142, 2.7519997751492338],
+    [29.4561102616812, -21.919283873889718, 3.1547757553927913],
+    [31.45985108229407, -23.872111437477567, 3.6233309890588115],
+    [34.06251297478705, -26.338433863339716, 4.195043480241922],
+    [37.60406363535877, -29.61881914943948, 4.933278113501013],
+    [42.75125174637943, -34.30132057496517, 5.961638286910942],
+    [51.079206197408446, -41.77369246059253, 7.57157474181115],
+    [67.72020718513404, -56.55516563176954, 10.71233014730267],
+    [-5.907028894659806, 12.138236210674737, -4.7418246537936115],
+    [8.44310116087854, -1.9775880219055146, -1.3534579724823377],
+    [13.007048585638788, -6.501384211415777, -0.25464744578414245],
+    [15.376266195291386, -8.920306115702642, 0.35329559011203443],
+    [16.980553940025846, -10.602304623317076, 0.7883022600590867],
+    [18.287977102654587, -11.988926169985415, 1.1513740907673278],
+    [19.50477063544955, -13.273092893252919, 1.4862249175923068],
+    [20.751976215202163, -14.567028295373683, 1.8180237770299654],
+    [22.124574589497584, -15.95775689550654, 2.1660131571923578],
+    [23.72021925481518, -17.533634264482185, 2.5494272202111214],
+    [25.66333278659894, -19.40613423971618, 2.992232123930023],
+    [28.139723552100627, -21.740718703388296, 3.529730828488131],
+    [31.466122824187394, -24.818541281533324, 4.221690321125853],
+    [36.264424013755665, -29.190863359163146, 5.185164184329182],
+    [44.016110360973435, -36.17027169124968, 6.698961082515312],
+    [59.65355293532751, -50.125921785246135, 9.692155823429967],
+    [-9.620226627015512, 14.462024455142789, -4.831625069219585],
+    [3.293258505741995, 1.813314398408747, -1.8181991242250266],
+    [8.063622894548953, -2.8433047035749874, -0.7089513590483257],
+    [10.680616899789955, -5.438876878335934, -0.07815051597728866],
+    [12.45937635940743, -7.236091733692066, 0.36815670011457535],
+    [13.880552016554415, -8.688365087761882, 0.7335126702787611],
+    [15.16713793203515, -10.003584965636302, 1.0647699902990928],
+    [16.44902992857504, -11.301633644663607, 1.3886409304194827],
+    [17.824644954815085, -12.672376718429506, 1.7249040221662755],
+    [19.391453561294252, -14.203908853910795, 2.092716066224747],
+    [21.270563421695588, -16.004857253385172, 2.5154889176772155],
+    [23.640799099283264, -18.234903218120124, 3.027468070660869],
+    [26.806601227552797, -21.165316226671937, 3.6867214950439764],
+    [31.369509741324208, -25.331678308816954, 4.607901168216351],
+    [38.7843923967425, -32.029201074357054, 6.068599113843204],
+    [54.06533276724123, -45.721993769768396, 9.0273543236748],
+    [-12.457471978834283, 16.28161234900547, -4.909439887075272],
+    [-0.6863774842147983, 4.784783403461903, -2.186746200074743],
+    [4.156344377536914, 0.09563930327761935, -1.0823787506954878],
+    [6.936307589830985, -2.6190959765903172, -0.4351675786428249],
+    [8.844570225989651, -4.507626180449452, 0.02260290083595152],
+    [10.348879800303223, -6.012277383946359, 0.39200710011699824],
+    [11.680663549888587, -7.3487745712556425, 0.7215793343855009],
+    [12.977569567772706, -8.643963048297493, 1.0395000940003125],
+    [14.34205463062641, -9.991294739117421, 1.3662964780813016],
+    [15.872611310572887, -11.479817871547986, 1.7213393288134784],
+    [17.688948706993575, -13.217102379980796, 2.1278773474215686],
+    [19.966203812079893, -15.360003678592445, 2.6197084807033777],
+    [23.00287120546034, -18.175490168422748, 3.2543251489541785],
+    [27.394329609498257, -22.195897930563262, 4.146475439885827],
+    [34.60953484394069, -28.73489997262069, 5.579853664028422],
+    [49.94429894987269, -42.52985144723313, 8.580129518405828],
+    [-14.901468402672098, 17.916510120807054, -5.003175665786952],
+    [-3.9995326536131404, 7.298834062472644, -2.5033677239269605],
+    [0.8548742191176042, 2.6197247135218986, -1.409614109559093],
+    [3.7469945219060485, -0.17913329871844735, -0.7503828721096556],
+    [5.757382835240988, -2.143913536030681, -0.2814766161251109],
+    [7.330526313772188, -3.69647423959733, 0.09362530843167455],
+    [8.697593208244976, -5.052727810471968, 0.4235066854152441],
+    [10.001381316334266, -6.344320612494527, 0.7373828744135054],
+    [11.348457496051635, -7.6684729604612825, 1.0565909845236836],
+    [12.839209583286271, -9.116046265486494, 1.4009117096837076],
+    [14.593282527125863, -10.794797893117954, 1.7936978198775495],
+    [16.78424752364858, -12.860686695581926, 2.268699983887878],
+    [19.708622698090316, -15.579951688349949, 2.8835677850054715],
+    [23.96474778159029, -19.48989278580294, 3.754845292922146],
+    [31.064490721343866, -25.948772980337026, 5.1778761223686205],
+    [46.76612291192449, -40.13240808053813, 8.28173359761854],
+    [-17.206560471147814, 19.53630812902861, -5.127211769864592],
+    [-6.938368207791343, 9.570180588142785, -2.7957217827344047],
+    [-2.0954559611573007, 4.913589930183189, -1.713110945583833],
+    [0.8791429272171526, 2.0500222981110623, -1.0440784312465008],
+    [2.973250844116459, 0.01906000732074642, -0.5643638748879786],
+    [4.606950785299614, -1.5797290147955205, -0.18223224021848747],
+    [6.005493391261026, -2.9574686327315334, 0.14987674517475402],
+    [7.3130955747328565, -4.247398202542934, 0.46154050564876914],
+    [8.63953207089765, -5.549803140832785, 0.7747690532263714],
+    [10.08750904896511, -6.957807555778677, 1.1098558556494034],
+    [11.77753172144442, -8.58023994180371, 1.4904477321461997],
+    [13.883007349844403, -10.573481568373321, 1.9505266046285754],
+    [16.701021033129468, -13.205430359557752, 2.5484048332073264],
+    [20.839043073881815, -17.024158779770275, 3.4036764760021025],
+    [27.87458854808689, -23.453958326876915, 4.828090161362739],
+    [44.22195738293341, -38.290204858343124, 8.095118487377949],
+    [-19.537657129837115, 21.256370676661415, -5.292618732159916],
+    [-9.707126366641457, 11.752984062759873, -3.08482158422086],
+    [-4.871075902741929, 7.109540481454125, -2.010107918460739],
+    [-1.829711492517941, 4.18953188841431, -1.331652618919793],
+    [0.33608898625473715, 2.098015178349491, -0.8410014995358337],
+    [2.0246030651758353, 0.45352572836460375, -0.4506858556449124],
+    [3.4530986025004258, -0.9485350065000973, -0.11454382989022843],
+    [4.763767242833247, -2.2400401646241823, 0.19673974466039315],
+    [6.067538700471636, -3.5226191348486013, 0.505491876748091],
+    [7.469075754574798, -4.891423206054035, 0.8324759759519507],
+    [9.090219156888537, -6.456855742671293, 1.2017443624520467],
+    [11.105075709707945, -8.37659898780457, 1.6477025318125331],
+    [13.812821003229175, -10.921779413095267, 2.2296767869011314],
+    [17.83408723816666, -14.655380038141505, 3.0712630292230885],
+    [24.83105508500558, -21.086672240096423, 4.505036625046478],
+    [42.105964009365245, -36.852229630103764, 8.000268415669439],
+    [-22.038948576027483, 23.185933952162618, -5.512667925221978],
+    [-12.478867578289178, 13.982314272881682, -3.3902457033940334],
+    [-7.619534345226983, 9.322743147067168, -2.3167065246070226],
+    [-4.514692252399113, 6.344341062297715, -1.6273686569738215],
+    [-2.284010332471525, 4.193538676887089, -1.1249190664499986],
+    [-0.5449153563361598, 2.502753229742983, -0.7251729433870509],
+    [0.9123140099689657, 1.073410883532773, -0.3832647074371246],
+    [2.225879440268427, -0.22336739371050246, -0.07048131555664937],
+    [3.5052029073405278, -1.4883068673818587, 0.23535472945269598],
+    [4.8552931344431265, -2.8172678012800696, 0.5552074972169828],
+    [6.398970816528797, -4.322027486761279, 0.9135010204896232],
+    [8.311288907777975, -6.161950854725971, 1.3451589953432126],
+    [10.894067320646041, -8.612207892207547, 1.9107313836125144],
+    [14.783188626378617, -12.253390504344624, 2.738517099285129],
+    [21.741139807495006, -18.696794933231843, 4.186165660403357],
+    [40.25707457959573, -35.71215357907997, 7.987914769018417],
+    [-24.8768077022357, 25.460622594069186, -5.807181277928468],
+    [-15.426794729731506, 16.39944453417424, -3.7337246300035236],
+    [-10.496038898175202, 11.678707849645622, -2.6515485644906667],
+    [-7.314755834634674, 8.626849202985172, -1.9474632085272812],
+    [-5.019527216639091, 6.412304673353065, -1.4312180122089144],
+    [-3.232631033831784, 4.67291904423217, -1.020383878725694],
+    [-1.7486849785400815, 3.213519197095726, -0.6709071564765707],
+    [-0.4335412203737861, 1.9081959366250159, -0.3546752497431267],
+    [0.8186062175598495, 0.6588718611633824, -0.0500177152283347],
+    [2.110252434635726, -0.6286262277093905, 0.26373854933933544],
+    [3.5631589386100924, -2.0658017481710256, 0.6110566058685201],
+    [5.352337282670214, -3.81306004442556, 1.0272659652553444],
+    [7.781535592983746, -6.149447754879871, 1.5741637332794778],
+    [11.501459539682669, -9.674027656336195, 2.385131007045227],
+    [18.385761475333272, -16.114546941721578, 3.8469014024915715],
+    [38.518156534405676, -34.78192469357153, 8.057502547587738],
+    [-28.288540913879178, 28.28328382140015, -6.209048073129512],
+    [-18.766067273416038, 19.18670906841382, -4.1446218200835],
+    [-13.697631267492714, 14.341841204968384, -3.04015310813831],
+    [-10.409967396674979, 11.18664591648396, -2.314476634434437],
+    [-8.03936403942484, 8.894359687568537, -1.780501438036712],
+    [-6.203578132905442, 7.100137337597749, -1.355939793505594],
+    [-4.695786139894029, 5.607598575923712, -0.9967109980831155],
+    [-3.3834021305893156, 4.291801506782924, -0.6749401893949358],
+    [-2.164482016983295, 3.0575100681559415, -0.3695119725147524],
+    [-0.9418849616889398, 1.8146264468586388, -0.06053189872847547],
+    [0.4007052872928972, 0.45561064640836624, 0.27574904244906945],
+    [2.03422791639671, -1.1777291582569502, 0.6744163726240742],
+    [4.260928030736746, -3.3659443249179613, 1.1981000770320203],
+    [7.741828328353629, -6.724409414324551, 1.9850974398624055],
+    [14.463289978130712, -13.106077039753545, 3.454519198148748],
+    [36.687463349170606, -33.96633133941016, 8.218219657689417],
+    [-32.673732635075275, 32.00423738053515, -6.7780784409727515],
+    [-22.82836975909387, 22.631657556403304, -4.670560886489633],
+    [-17.522736514129207, 17.56722249973227, -3.5232452444881157],
+    [-14.07922671809426, 14.259660740615208, -2.7648854522695054],
+    [-11.609244692087337, 11.863092642068432, -2.2063669939592323],
+    [-9.716109692536921, 10.000544551597919, -1.7635560748585342],
+    [-8.18602190088465, 8.469408514149807, -1.3913261023899495],
+    [-6.8844142344557415, 7.1426364164248595, -1.061432728567497],
+    [-5.711438757144919, 5.926314010477425, -0.7530124524628337],
+    [-4.576171774946015, 4.735131893658647, -0.4470872662210475],
+    [-3.372812309312926, 3.469837528983313, -0.12149567866705914],
+    [-1.9439057051230892, 1.9824395585970291, 0.2569789684639766],
+    [-5.184755189334986e-12, 1.9574056747884887e-12, 0.750000000000711],
+    [3.1164332428378407, -3.0996809519913104, 1.4992085443606054],
+    [9.485206125112477, -9.289534524145225, 2.957238652432606],
+    [34.42828703445246, -33.1226203373384, 8.495530143811697],
+    [-38.82328494490858, 37.325422498678414, -7.637116838836638],
+    [-28.23426280543796, 27.27833557688151, -5.403028719703529],
+    [-22.518794981125232, 21.82898718374635, -4.1777036174748385],
+    [-18.834477967194275, 18.284437806517644, -3.36751729764001],
+    [-16.224175985369158, 15.738467473802578, -2.7727884282201973],
+    [-14.25947488223178, 13.78572160351533, -2.3041995086260085],
+    [-12.711153343997921, 12.209456668315143, -1.913946404465984],
+    [-11.437910635248196, 10.876070490878416, -1.5724748295901583],
+    [-10.339112094079104, 9.690248455732865, -1.2585660576246522],
+    [-9.328831886911692, 8.570236195895927, -0.95383460567001],
+    [-8.313984076748742, 7.426865422969207, -0.6379951876320122],
+    [-7.161586430448976, 6.131924613797793, -0.2816147362722952],
+    [-5.621756236516384, 4.443309223835683, 0.17123866473567373],
+    [-3.085441551505476, 1.768138155626341, 0.8585287672220778],
+    [2.5373134686332817, -3.935021936094347, 2.259717444138123],
+    [31.036931621944003, -31.963964180795706, 8.955444298246407],
+    [-48.6686117369565, 45.9710897567742, -9.09164330236369],
+    [-36.42531385776853, 34.397411719740255, -6.557538012079398],
+    [-29.935648943978986, 28.216424405417538, -5.1816440424559636],
+    [-25.84118741319225, 24.266096866533868, -4.281438254313725],
+    [-23.019116002690268, 21.48948763325251, -3.628500298612715],
+    [-20.971907159587598, 19.41799670680443, -3.121288768948585],
+    [-19.43799286972196, 17.804771062374485, -2.7060004645210194],
+    [-18.26114162675474, 16.501651190503388, -2.349969611025752],
+    [-17.336089108801925, 15.40781314652452, -2.0305141456998754],
+    [-16.58054532150582, 14.443103737932253, -1.7290655228822531],
+    [-15.914828658777063, 13.528288564352778, -1.4266948118612826],
+    [-15.23582261947708, 12.559288709083644, -1.0980998027938789],
+    [-14.358186048831469, 11.348461662627242, -0.697709837112393],
+    [-12.80832615796819, 9.417768378235897, -0.11039245383532158],
+    [-8.669468141808471, 4.826499411007618, 1.1322691510131255],
+    [24.66058344993744, -29.761182135325935, 9.825855396968937],
+    [-69.00366940788338, 64.02746497712744, -12.22792936703158],
+    [-52.09903360522103, 48.14334581224098, -8.842942891356754],
+    [-43.75112819292598, 40.20903101159659, -7.107257069038468],
+    [-38.80670010412447, 35.41442562956268, -6.017376038567125],
+    [-35.652672834065804, 32.252325241057726, -5.25728705581109],
+    [-33.60988418751512, 30.085680750230356, -4.692013992353917],
+    [-32.344153108576975, 28.597759808030826, -4.252840252448783],
+    [-31.67782007048426, 27.615750568353235, -3.9002417085369734],
+    [-31.516798051272527, 27.0416997397929, -3.6090325654408115],
+    [-31.819535109648793, 26.821834473604806, -3.361227677071726],
+    [-32.5843589501967, 26.931671973333508, -3.141628123623859],
+    [-33.84559604169757, 27.36650533935465, -2.9333487977449515],
+    [-35.6694905830951, 28.125011959594886, -2.709116223607483],
+    [-38.1077603207035, 29.133895459476957, -2.402279981672824],
+    [-40.705790642109015, 29.670903830377643, -1.7389975370205717],
+    [2.581795861666338, -20.049257266703748, 12.291304105059568],
+    [58.387181265062694, -30.97869606152698, -0.5095446017645322],
+    [65.5069467360754, -41.29903186952794, 2.9776434403849317],
+    [62.776672835414516, -41.06166475192553, 3.646316378505572],
+    [60.199587122683766, -40.357191174381526, 4.018347341869638],
+    [58.236123550656565, -39.833432059555406, 4.308935904616712],
+    [56.893432581831426, -39.613930381947036, 4.582749176786379],
+    [56.1386898560952, -39.7372037025895, 4.870235881594543],
+    [55.960237554915835, -40.23490229077082, 5.191963915630539],
+    [56.38273779578832, -41.15583177401729, 5.567174300312265],
+    [57.48026583938826, -42.58269301277614, 6.019011495791092],
+    [59.40083301480794, -44.656086825083, 6.580350958600776],
+    [62.41894139799068, -47.62120635168549, 7.303718868615164],
+    [67.05746861907787, -51.93304909943116, 8.282634770144524],
+    [74.40907353886342, -58.53156354899662, 9.706655381950954],
+    [87.18831770793092, -69.74019241513196, 12.040406400806052],
+    [114.86183751899634, -93.64185177925178, 16.89360991661177],
+    [16.496020357145373, -2.62485213646452, -3.400206383288067],
+    [32.3085669244166, -18.652253157788163, 0.6141409400447222],
+    [34.814260169366385, -21.71239534006543, 1.526195252815362],
+    [35.80783994854213, -23.2381968081606, 2.0533543060371673],
+    [36.43802589366297, -24.33484210523731, 2.456212314812441],
+    [37.02944819030386, -25.320492966992088, 2.8115461632981407],
+    [37.72758645978345, -26.340253426624887, 3.1551263028526644],
+    [38.627282576154144, -27.488202112914426, 3.510026957769822],
+    [39.816082681305346, -28.848376787297227, 3.896092238288875],
+    [41.3990238937012, -30.517712292586374, 4.3350955482720845],
+    [43.52502960682603, -32.62964028064269, 4.855809043638817],
+    [46.430810965004895, -35.39249787834675, 5.502001057329696],
+    [50.53369061370007, -39.17006226768356, 6.349000006822486],
+    [56.66701459927262, -44.68525456397562, 7.545435061148307],
+    [66.82816366244828, -53.666853423191974, 9.445629979067778],
+    [87.64524296398794, -71.84460194260768, 13.222334793216822],
+    [3.789229334799559, 5.713763486846125, -4.093826652247785],
+    [19.09345686325186, -9.368539674951414, -0.44431544399415596],
+    [22.91166413155954, -13.317885451882805, 0.5650981583555555],
+    [24.772505378231255, -15.382910924902847, 1.130596694224831],
+    [26.046048652866116, -16.86117524973413, 1.5518164539337824],
+    [27.131475248883284, -18.129045639452464, 1.9152642225403875],
+    [28.206406386910587, -19.353727315187733, 2.259328711895004],
+    [29.381350302604165, -20.638472671830403, 2.607517921896112],
+    [30.75014585248226, -22.069546889287704, 2.9790867390459077],
+    [32.41644609881578, -23.740431853872465, 3.3943921345583155],
+    [34.518872178319256, -25.77425289900115, 3.8797010365524622],
+    [37.270167134708686, -28.358158757147933, 4.474413288459998],
+    [41.038105977770186, -31.81396927503241, 5.245796036323337],
+    [46.54950531187752, -36.77602208942961, 6.3260121950356965],
+    [55.53852671494541, -44.75607005184872, 8.029733061568328],
+    [73.75768924113753, -60.7656300881946, 11.400173153783802],
+    [-2.7174101030478033, 9.883664943993749, -4.36627778630344],
+    [11.25676571638694, -3.7463807696256164, -1.1149156139609817],
+    [15.660551675311908, -8.105090792955297, -0.054926770882021976],
+    [17.945541199083976, -10.448823662522484, 0.5380587766326793],
+    [19.51098579818321, -12.101483068988076, 0.968887105720404],
+    [20.806049198236735, -13.482858575626691, 1.3328971026516223],
+    [22.0300093700555, -14.777850047280351, 1.6717440656822233],
+    [23.30244351949439, -16.096436895951232, 2.0099565640993724],
+    [24.719832443090183, -17.526251702147107, 2.3667878550091177],
+    [26.383891702830592, -19.158450724684542, 2.7619338486200697],
+    [28.426520076661262, -21.110011813505047, 3.220336309978684],
+    [31.046912185314113, -23.556459439889213, 3.779117036206248],
+    [34.587327121537534, -26.79835957543606, 4.501591105184383],
+    [39.72479180235138, -31.429354612825282, 5.512649580003556],
+    [48.0877479436974, -38.876524707028146, 7.112602400155776],
+    [65.19409034565625, -53.97411083501574, 10.319799531645602],
+    [-6.914489430184443, 12.568339168796829, -4.517259530583034],
+    [5.813653170380831, 0.22218221157774093, -1.5979460810273756],
+    [10.486795344454384, -4.315566225437804, -0.5199460281992351],
+    [13.040406536774393, -6.845421314549958, 0.09573635795612048],
+    [14.788547681008728, -8.613586876622323, 0.5362078881874166],
+    [16.203794127771463, -10.060867980165984, 0.9011990612754173],
+    [17.503340783031565, -11.387704554824559, 1.235523220486821],
+    [18.815318627670585, -12.71120066434768, 1.5650761594336084],
+    [20.239291200448843, -14.121359036467485, 1.9095091238662352],
+    [21.876462610993045, -15.708690400291951, 2.2883353105157043],
+    [23.85503173742719, -17.586935583944943, 2.7258455760674623],
+    [26.366644195538733, -19.925327564864094, 3.2579910250960133],
+    [29.740320899259505, -23.01374373507824, 3.9462233399080064],
+    [34.63108011449398, -27.428775352597146, 4.91276570277508],
+    [42.638497759485055, -34.578256059401475, 6.456375374120443],
+    [59.37521428647211, -49.40202581589916, 9.627231904035101],
+    [-10.044544725978305, 14.610663828138446, -4.633752885812313],
+    [1.6420707494375002, 3.3128756490174442, -1.9795692844210933],
+    [6.435192994545794, -1.2934203301755909, -0.9001630925866315],
+    [9.17328196997248, -3.955249237327492, -0.26678667411349927],
+    [11.05785561531412, -5.815823350483851, 0.18419072867312358],
+    [12.558814675837812, -7.313871844081617, 0.5519479045653559],
+    [13.905455314319358, -8.660612757400587, 0.8835399767528987],
+    [15.234157539701776, -9.980318827823249, 1.2063238441294228],
+    [16.648357145915124, -11.366284315785247, 1.5406043397612819],
+    [18.250049034944656, -12.909675524192753, 1.9060241744062627],
+    [20.16581888439725, -14.722921627767349, 2.326641798397028],
+    [22.583423148144597, -16.972198689850146, 2.8378990498179717],
+    [25.82578928865427, -19.94288916401346, 3.5006168219750498],
+    [30.54207236662593, -24.208460904509714, 4.437114674444137],
+    [38.34975747491485, -31.19803697088376, 5.952768448904419],
+    [55.190536880634475, -46.162625309300154, 9.172423599398947],
+    [-12.649513455613533, 16.374325737500616, -4.750852209820336],
+    [-1.8010761185570545, 5.910067037282598, -2.3057005919394813],
+    [3.0421342275452403, 1.2846060244028845, -1.2317181444387262],
+    [5.9135051763859385, -1.4750056626970054, -0.5845313293410362],
+    [7.9089382685744045, -3.415377025426265, -0.12261098560269519],
+    [9.480917398144399, -4.960023579730102, 0.24981034966512636],
+    [10.863118350358077, -6.32426975082088, 0.5806327556667675],
+    [12.198783307314166, -7.638491763196737, 0.8984803240563487],
+    [13.595740160593323, -8.999845591378374, 1.2244549513004626],
+    [15.157810165459997, -10.501140833399543, 1.5785498400157394],
+    [17.011442149123464, -12.254843137864137, 1.9848763396568905],
+    [19.34288036836682, -14.426188468000184, 2.47879932347603],
+    [22.47348155580595, -17.30004885218309, 3.121312752427596],
+    [27.05704746531893, -21.456028086381, 4.036716295442221],
+    [34.76246520202824, -28.37426443733001, 5.543200958068251],
+    [52.07428258879306, -43.80858440588796, 8.881624322316028],
+    [-15.028551494420306, 18.061735921214442, -4.888228746759484],
+    [-4.826484620708189, 8.23927928464945, -2.6048730196104843],
+    [0.03687557515880718, 3.6122369721498675, -1.537712182377824],
+    [3.0115003086741723, 0.7741618996074303, -0.878785565580996],
+    [5.101474715246983, -1.238077054126536, -0.405695764399509],
+    [6.737630166691154, -2.8291052054751393, -0.026894864209168905],
+    [8.151361185426852, -4.2125663494396575, 0.3051330205268043],
+    [9.489977917556129, -5.522589888162719, 0.6197951517886029],
+    [10.865561684007805, -6.860152181824875, 0.9389917978265938],
+    [12.384497277441545, -8.320409780407621, 1.283219265535445],
+    [14.174179560561553, -10.016861234183544, 1.6768503671527235],
+    [16.42094234150277, -12.115260303850947, 2.155472936030405],
+    [19.44754038275574, -14.902665072497578, 2.780809704720727],
+    [23.919717948338835, -18.971464048469713, 3.6805372979498117],
+    [31.584721373993805, -25.877488139874945, 5.190996701301745],
+    [49.70221722562601, -42.08958349663887, 8.716074350486176],
+    [-17.376704896647293, 19.810358058564574, -5.06054049884359],
+    [-7.641879950737567, 10.456631537018083, -2.898068089149803],
+    [-2.7656180343545516, 5.826836869273823, -1.83576393132377],
+    [0.2966458745948405, 2.918438180702545, -1.1654130382393795],
+    [2.4711536256572, 0.8382570071083407, -0.6806545712866179],
+    [4.167989291451447, -0.8005169374007227, -0.29391569116171096],
+    [5.612666871368948, -2.2067918036657743, 0.041287855148652075],
+    [6.953085964559503, -3.5154960708200695, 0.3545301129003047],
+    [8.304365748248857, -4.83044245033995, 0.6683091235760332],
+    [9.775686968441516, -6.249397066740585, 1.0036815427427255],
+    [11.496133967142809, -7.887599396465504, 1.3854296234538963],
+    [13.65318470992055, -9.912327988454914, 1.8495746454727886],
+    [16.572867623618524, -12.61464171888695, 2.458955392219518],
+    [20.937890870991506, -16.604577624114228, 3.345694894036468],
+    [28.597114759807145, -23.535849557319956, 4.869276131988827],
+    [47.86813038665917, -40.85514477866957, 8.656041391233595],
+    [-19.852555990640628, 21.74072203099921, -5.282982109749533],
+    [-10.416025232937676, 12.6947127780097, -3.2043552105352653],
+    [-5.515260216326987, 8.045226270779514, -2.142044948175224],
+    [-2.370593270755324, 5.065755502182912, -1.458955147274064],
+    [-0.11629128486548791, 2.917372853600318, -0.9613860448027723],
+    [1.639598247395564, 1.2282826169318686, -0.5650951921736593],
+    [3.115884111625201, -0.2051667192059764, -0.2247278894607063],
+    [4.458240284957868, -1.5163611161428836, 0.08897238738962596],
+    [5.782473157942887, -2.8099928829991545, 0.39869852194310473],
+    [7.199779164068452, -4.185835663822724, 0.725945142001105],
+    [8.841130936958441, -5.76112286564507, 1.0959581161161391],
+    [10.8956727200808, -7.705237829300597, 1.5453180903515689],
+    [13.693565293732266, -10.314256928007715, 2.138214703640171],
+    [17.937465513465447, -14.219206660057587, 3.0120143971989704],
+    [25.5979242385099, -21.192113433219475, 4.5541716171578],
+    [46.42135618640539, -40.00816927657238, 8.694175827655378],
+    [-22.626894293798358, 23.993376545774293, -5.576338894206986],
+    [-13.321347930985695, 15.095226337405228, -3.545462174786734],
+    [-8.360878891484223, 10.389646366278527, -2.4746900696077874],
+    [-5.129670531420311, 7.329553492184457, -1.7757424475944998],
+    [-2.795729721336706, 5.108225616246185, -1.2632778844511903],
+    [-0.9810138335412423, 3.364681788212057, -0.8554651758405317],
+    [0.5273323854636406, 1.899516114921295, -0.5078344985170713],
+    [1.8714510482450029, 0.5818320100585326, -0.1916278325687054],
+    [3.16509523721121, -0.6916902381590109, 0.11558560375023773],
+    [4.519106314837795, -2.020882036573929, 0.4353413087849191],
+    [6.0652490340319085, -3.5241338889139353, 0.793180354100935],
+    [7.993948518639898, -5.3727787155894235, 1.2262211783812],
+    [10.63911134361745, -7.867966261315231, 1.8000545954707485],
+    [14.723648511664054, -11.663077040666108, 2.657729666022837],
+    [22.355215011872065, -18.666350765434665, 4.219255617416457],
+    [45.22437169488413, -39.47848109554236, 8.833721594981618],
+    [-25.934965044977222, 26.772878985585326, -5.974047168039952],
+    [-16.572509451814774, 17.84221284933324, -3.9510509919372767],
+    [-11.489530367125303, 13.019930951954416, -2.8586160020036444],
+    [-8.152912704350609, 9.856231879899244, -2.137925096428955],
+    [-5.734696802449024, 7.551618843999651, -1.6070812469315272],
+    [-3.8606403663935005, 5.74750436361252, -1.1850653387211583],
+    [-2.321556860225989, 4.2463484090397925, -0.8277714766785362],
+    [-0.9787130200046059, 2.9193130092716935, -0.5068152871132192],
+    [0.277674825098178, 1.6658949320063006, -0.20029961044115527],
+    [1.5543748705118305, 0.38925505807332683, 0.11274288382766813],
+    [2.980118591195355, -1.0270126594701914, 0.45751548675015935],
+    [4.743788062027519, -2.754001154437762, 0.8712416695510137],
+    [7.180284177349074, -5.096115720701333, 1.420595453369619],
+    [11.02868744825084, -8.72718878183091, 2.2541441810093317],
+    [18.53926875418565, -15.703459004441045, 3.828191389455204],
+    [44.10065870162376, -39.19757105884086, 9.090253157199196],
+    [-30.169466449666796, 30.43100353435329, -6.5366585828258605],
+    [-20.496426686175976, 21.22461036888246, -4.469212902301293],
+    [-15.190523681983983, 16.18925229878029, -3.3344715856627922],
+    [-11.705142073399042, 12.876539388667648, -2.581453436467768],
+    [-9.188034797632339, 10.467706228946765, -2.0261404342211855],
+    [-7.253802696381548, 8.59397198516758, -1.585928285888241],
+    [-5.690226273871351, 7.0539746128609195, -1.215991120953254],
+    [-4.359774218124017, 5.718745847679838, -0.8878481872609997],
+    [-3.1568974820189113, 4.490490939375609, -0.5800618379191366],
+    [-1.9821927983921908, 3.277908059224507, -0.27270009712113613],
+    [-0.7170077733184815, 1.9723820294036691, 0.05796066156894038],
+    [0.8167616463498559, 0.4106768918154063, 0.44772950255305327],
+    [2.9452719071574704, -1.7072509501557092, 0.9628830977600303],
+    [6.410204312574115, -5.066444411777095, 1.7559400497381046],
+    [13.586255315629815, -11.866724230358201, 3.320594094905831],
+    [42.72743075995643, -39.054233276231244, 9.499770434441164],
+    [-36.113402569590626, 35.679145618525624, -7.389723049203902],
+    [-25.704891335028965, 25.79193593349079, -5.193040488995609],
+    [-20.000917452759182, 20.37480961409565, -3.980186834752782],
+    [-16.2839704887572, 16.828997295958203, -3.1756861406213606],
+    [-13.633903253559687, 14.27466828163896, -2.584689806539834],
+    [-11.635517628195002, 12.315491297513962, -2.119480071524231],
+    [-10.063493618963454, 10.737267274897695, -1.73271655690901],
+    [-8.77599376199481, 9.405956950385518, -1.3948126633217646],
+    [-7.669251254556105, 8.22405609333023, -1.0842308166164947],
+    [-6.651883095671075, 7.105947001362575, -0.7819826308853912],
+    [-5.621725728769301, 5.955836838132011, -0.466692891538281],
+    [-4.428930992778097, 4.632850082413368, -0.1067606793188919],
+    [-2.7877421673626537, 2.8676151708943247, 0.3584918549940753],
+    [-2.1755363567219337e-9, 2.0040707085370105e-9, 1.078638742827162],
+    [6.348941031337059, -6.260005985779092, 2.5771039621271665],
+    [40.31845996300601, -38.76618584810922, 10.146954607946407],
+    [-45.72041039063644, 44.30403919745888, -8.852506131674982],
+    [-33.643686880226504, 32.85271669779796, -6.346357552862785],
+    [-27.168519670518208, 26.694796053816113, -4.980135846434903],
+    [-23.05006612235011, 22.74371242937424, -4.084985492055312],
+    [-20.201235715371013, 19.96496233287927, -3.436303243900718],
+    [-18.138872753817772, 17.89850124104536, -2.9339077395335926],
+    [-16.607016734826846, 16.300566143920864, -2.524447272213998],
+    [-15.450770898997572, 15.023527834347087, -2.1753073363795044],
+    [-14.56394302222327, 13.965774753453765, -1.8636585764700202],
+    [-13.861942766973572, 13.045534261058998, -1.5706535138492637],
+    [-13.261543927147923, 12.181052043727565, -1.2769195645678093],
+    [-12.653911781803263, 11.26388214694171, -0.9563447881602838],
+    [-11.841791911159588, 10.09648348840537, -0.5614355744576519],
+    [-10.31530397462703, 8.169036230876884, 0.029290346663957273],
+    [-5.9847216521634286, 3.38888255442046, 1.3146984468027025],
+    [34.33940116262836, -37.36971888228953, 11.31102000109945],
+    [-66.21585650826397, 62.93934979207777, -12.119427775308111],
+    [-49.23677655167137, 46.883044714856844, -8.69986754702107],
+    [-40.83589418865437, 38.880346588375026, -6.953509435586333],
+    [-35.857727535634474, 34.05959392548137, -5.861448534456684],
+    [-32.700479773818365, 30.904998998326086, -5.104775243086022],
+    [-30.693311220894937, 28.779002748268727, -4.547720068125311],
+    [-29.507554764896152, 27.366131444761628, -4.121300573053125],
+    [-28.96986776376246, 26.495905205855454, -3.7859613605798024],
+    [-28.99152072431313, 26.074727023938223, -3.516751223559503],
+    [-29.540479379034565, 26.057208107946764, -3.2963516276471037],
+    [-30.63413258446873, 26.435181778174954, -3.111006872333638],
+    [-32.34587618930786, 27.236007692191908, -2.9467081090974236],
+    [-34.8252911737551, 28.524368029576816, -2.7819481811546507],
+    [-38.318513013941065, 30.376440979468924, -2.5625007692050805],
+    [-42.91124251445214, 32.47383882597838, -2.0464476106958385],
+    [8.298279454141891, -28.16538781753998, 15.59143904702713],
+    [75.99139097515987, -42.55310279826094, 0.8943674098125084],
+    [77.32095565263175, -48.55853721891563, 3.7160221846397525],
+    [72.47149366282729, -47.038819533181154, 4.286987816939431],
+    [68.56835488355883, -45.540196027831236, 4.599727524384156],
+    [65.71407418814782, -44.486485990626804, 4.851119856520211],
+    [63.75331214830197, -43.90363700443635, 5.099344918526162],
+    [62.56881552211597, -43.78018097526396, 5.3717671541285545],
+    [62.102345443972666, -44.12057017937907, 5.687433100296328],
+    [62.35366427571479, -44.95970453931976, 6.06512271936833],
+    [63.38720781194529, -46.376182275377744, 6.528535000323875],
+    [65.3556675393519, -48.51632794778346, 7.112418050209059],
+    [68.5573906297763, -51.645036418213465, 7.87340070144518],
+    [73.57459835903295, -56.263886956988195, 8.913765887065741],
+    [81.64464768722375, -63.42531235041904, 10.443660572921907],
+    [95.89919160346722, -75.778341880482, 12.987221691290937],
+    [127.63238336468075, -102.85123245152221, 18.421396680466565],
+    [23.823817593714768, -7.732522811378531, -2.6171759337205183],
+    [37.76833875087607, -21.99372608350542, 0.9977383764890101],
+    [39.799360957140074, -24.721170399966233, 1.8646530868548812],
+    [40.512929559193346, -26.080254238583752, 2.3790825198733483],
+    [40.94659875516335, -27.06980117822498, 2.7776153695990558],
+    [41.399181317024976, -27.98614869466062, 3.1329593326289853],
+    [42.006251515547945, -28.967244962724006, 3.4799558157969743],
+    [42.85903177577904, -30.1051534275975, 3.8416805336789377],
+    [44.04560395813164, -31.484986388192834, 4.2384654517166025],
+    [45.675782381605615, -33.20783409152778, 4.693056945906656],
+    [47.909243717321004, -35.4156979583755, 5.2359399982623485],
+    [51.00386805334497, -38.33333239804159, 5.913973536263062],
+    [55.41955298748708, -42.35729559398085, 6.808525908065383],
+    [62.08450871595346, -48.28372010874428, 8.08167347068879],
+    [73.25354324128128, -58.04175587679828, 10.124812325827495],
+    [96.59280556122808, -78.18144134665532, 14.2648360562977],
+    [8.730089426215898, 2.2633819045334276, -3.5459853177825105],
+    [23.15310604626927, -11.887290610051608, -0.12829937762576368],
+    [26.6644071585425, -15.577013555186085, 0.8310859534498564],
+    [28.38052255458066, -17.541887391258165, 1.3835961110148098],
+    [29.569650595079516, -18.97260323192549, 1.8024418587767868],
+    [30.606224597183772, -20.220379664693088, 2.168181129151016],
+    [31.660924942851555, -21.445412038267985, 2.517613798948011],
+    [32.84318485301568, -22.74961663663359, 2.873951826500414],
+    [34.24909132333751, -24.220825170542746, 3.2567524524444447],
+    [35.98778810962988, -25.956664099139882, 3.6871575965991874],
+    [38.20803465714193, -28.08795890337508, 4.19286106890897],
+    [41.14097786035209, -30.815851554357096, 4.815826951757705],
+    [45.189936611291756, -34.48927984169388, 5.628282865140041],
+    [51.15919566055749, -39.802091538534775, 6.773337130191241],
+    [60.99048091526961, -48.42723137557719, 8.595654142304708],
+    [81.26467959912252, -66.03044376284753, 12.262458448600427],
+    [1.1612544295659049, 7.190645471975435, -3.9346484903259973],
+    [14.682880272060173, -5.890733310968906, -0.8321083892898705],
+    [18.874446180739994, -10.04616270844455, 0.18289388239063875],
+    [21.052284804924653, -12.30184329368769, 0.7600252297826723],
+    [22.56584999167501, -13.919630298396346, 1.1870038782276349],
+    [23.840674186323373, -15.293512650325559, 1.552682216026242],
+    [25.0683692088078, -16.599855402040838, 1.8965796061324975],
+    [26.36717691851464, -17.946542971973198, 2.2426301975404983],
+    [27.83566869835204, -19.422300070649094, 2.61019980199488],
+    [29.580746231291137, -21.121952284839256, 3.019611827811176],
+    [31.743751445865552, -23.169466240598272, 3.497054878476649],
+    [34.54080050582383, -25.75307539478223, 4.081944247418845],
+    [38.346562708229655, -29.19790965130257, 4.842063475219374],
+    [43.908521573180295, -34.151523346933416, 5.912211789807411],
+    [53.04482258917987, -42.18798432967868, 7.620107684469739],
+    [72.04498409935793, -58.750548383738625, 11.100102166283484],
+    [-3.6287400993821404, 10.305302835747437, -4.154127255909993],
+    [8.878523276448231, -1.7035062304031645, -1.336575655200131],
+    [13.415356972041215, -6.09113143317353, -0.2955605842429458],
+    [15.884636725535016, -8.541189384582648, 0.3031166217616637],
+    [17.593032168832245, -10.276116364532863, 0.7378286106097177],
+    [18.998826301583545, -11.718306072927893, 1.1032047636003035],
+    [20.311816491984235, -13.059324592372521, 1.4417197456119035],
+    [21.658333994723712, -14.413442150122156, 1.778441347712223],
+    [23.139661844302474, -15.87122580110899, 2.132973423541434],
+    [24.861854319105014, -17.5264326360378, 2.5253360178543716],
+    [26.96213004146164, -19.499342509840137, 2.9809526909211606],
+    [29.648382546201162, -21.971269582664487, 3.5379274158288054],
+    [33.28084371869449, -25.255609135898528, 4.261965265026768],
+    [38.58278080470199, -29.980977554868232, 5.2848278115024225],
+    [47.34010923597012, -37.699240308546166, 6.932127976947534],
+    [65.95150751280326, -53.97016548403523, 10.372439497930637],
+    [-7.126949809874733, 12.619148878901333, -4.314927953500899],
+    [4.486043967184926, 1.5240715338110367, -1.73232280661641],
+    [9.202106847322376, -2.9751978635906693, -0.6827839077569217],
+    [11.879078444341644, -5.569446403569202, -0.06572558047171839],
+    [13.731294800765811, -7.396907871615643, 0.37800563906972345],
+    [15.22706761044548, -8.88870502242713, 0.7447090036188996],
+    [16.591125879314845, -10.249213226029058, 1.0794348012419355],
+    [17.958119100366986, -11.59964069096863, 1.408600796781326],
+    [19.43292829391779, -13.033425042074494, 1.7523363937129819],
+    [21.122159858399776, -14.644647608335523, 2.1306944785857094],
+    [23.16123284460501, -16.552018180481255, 2.5687911169886397],
+    [25.754008009977873, -18.933575388365362, 3.1041560251631504],
+    [29.25459957628468, -22.098069372897605, 3.801808557471476],
+    [34.38107736184142, -26.67129713907532, 4.793626570326323],
+    [42.94283540814096, -34.230329641611824, 6.412527448372965],
+    [61.732366738153104, -50.6966452608996, 9.911570637299887],
+    [-9.964408821916678, 14.55901285554433, -4.461481258201632],
+    [0.9081561461340236, 4.207414092194236, -2.0674982559239683],
+    [5.723648771957131, -0.34687303320383756, -1.0173628966828923],
+    [8.558072826473722, -3.05341650961816, -0.3848962020732189],
+    [10.529547480718108, -4.962766287745797, 0.06902842787186517],
+    [12.098643311461107, -6.4991603394726, 0.43903889432694315],
+    [13.49957532020517, -7.87519425183784, 0.7718267311343592],
+    [14.875074057900315, -9.21895431516588, 1.0951807301261427],
+    [16.334464926832318, -10.627588975641276, 1.4299554728662869],
+    [17.98608545998194, -12.19659786065162, 1.7964778469403928],
+    [19.96522648937195, -14.044609794469755, 2.21986632620848],
+    [22.474483117072747, -16.348791313747526, 2.7375579885934664],
+    [25.86722477583946, -19.417863151370465, 3.414799185265825],
+    [30.868952507278536, -23.885650372182173, 4.385744713675251],
+    [39.35308784951024, -31.389523123948514, 5.997772580034514],
+    [58.76886880563338, -48.442933073584825, 9.637064327839814],
+    [-12.472653414873536, 16.35140742441288, -4.6172057005523675],
+    [-2.191916730924751, 6.587438158656032, -2.3718942085850405],
+    [2.6892678331163036, 1.9991098753782175, -1.3232087433062623],
+    [5.652016425135016, -0.8014878932534856, -0.6770403941332311],
+    [7.729183299984603, -2.7874742944634407, -0.2120134093777571],
+    [9.365424349613324, -4.369059161394523, 0.1632859347839292],
+    [10.798127512652831, -5.761631861677171, 0.49605082759346225],
+    [12.176773044384387, -7.099076428943943, 0.8152240931600951],
+    [13.61567802184254, -8.48277550353614, 1.1424999823449242],
+    [15.225898800885764, -10.010534847826019, 1.4986648013920927],
+    [17.143871540183444, -11.802025439491983, 1.9090671144693088],
+    [19.57285312529822, -14.035225385229946, 2.4113866352769477],
+    [22.869160079298993, -17.02204417466526, 3.0717384042281246],
+    [27.77484414916331, -21.41231987564366, 4.0281762367544065],
+    [36.261077395941975, -28.93349118028235, 5.648772121468226],
+    [56.72524572078826, -46.94965483536723, 9.508652720148122],
+    [-14.86791397213235, 18.14990064427087, -4.799312858680575],
+    [-5.034683323562712, 8.828176755847435, -2.6671800034915143],
+    [-0.09443453434685524, 4.205056332954693, -1.6182022262720515],
+    [2.983354303216193, 1.3170762520152508, -0.9583958760017453],
+    [5.159503627825601, -0.7441712857243632, -0.48125094628517984],
+    [6.861757477985276, -2.3741512127213675, -0.09872678109334124],
+    [8.325892791603504, -3.786955842814645, 0.23600719756643715],
+    [9.70563828783691, -5.12015597893224, 0.5526070309591634],
+    [11.120185734975648, -6.4793004332394615, 0.8735902501119518],
+    [12.684069338604042, -7.965171380314888, 1.220335678344872],
+    [14.535874107560547, -9.699364543591685, 1.6185963728074846],
+    [16.880887257134212, -11.861881673594294, 2.106533789576991],
+    [20.080903905001517, -14.77008715303012, 2.7515871269200667],
+    [24.90143530927581, -19.096063588606196, 3.697004068050492],
+    [33.441918292260965, -26.68490277907541, 5.337474314356753],
+    [55.41155539398973, -46.07778561792458, 9.508575029721994],
+    [-17.323909544899337, 20.08700529371018, -5.024941295615337],
+    [-7.790776835803138, 11.063854477753802, -2.972459881648933],
+    [-2.77917117938648, 6.3892245256116125, -1.9184936866421138],
+    [0.41346272445590687, 3.410158913263809, -1.2433492252391867],
+    [2.6882347916577656, 1.2705461311677857, -0.7524999222895326],
+    [4.458472002453636, -0.41285483418822533, -0.3607271028817063],
+    [5.9563586176802525, -1.851234027814526, -0.021902022830282142],
+    [7.336972885255339, -3.1834109878227013, 0.2938355996850343],
+    [8.723197338498831, -4.518114544166837, 0.6096172104097032],
+    [10.233269824972238, -5.959312377855476, 0.9474484042515033],
+    [12.008639281623575, -7.631156199807803, 1.333605724894896],
+    [14.25752472412424, -9.716495381320374, 1.8068909454712865],
+    [17.348759001752075, -12.539633872950494, 2.4363680479503773],
+    [22.07624018434783, -16.799603501048452, 3.3714856691848176],
+    [30.696328154360998, -24.485906406931168, 5.039451728305291],
+    [54.720518784648704, -45.76177679575627, 9.634910193821089],
+    [-20.016448613435706, 22.30901170437327, -5.315933208289426],
+    [-10.622554532995753, 13.430995867118721, -3.3086707620350104],
+    [-5.509655872231426, 8.67233700697081, -2.241921815604946],
+    [-2.1897456417479786, 5.587742921397241, -1.547699050268846],
+    [0.19042182990208611, 3.3608669477065947, -1.0405157091633115],
+    [2.0336088080609853, 1.6167054484933892, -0.6370830963763132],
+    [3.5689536805205018, 0.1462863610494311, -0.2918259587543143],
+    [4.950883374070828, -1.1888381965560957, 0.02498561165247303],
+    [6.303676400762689, -2.498665602900324, 0.3367007558626418],
+    [7.748310326187354, -3.889549835525056, 0.6657785930362415],
+    [9.429143311878235, -5.48826069228847, 1.039018327111648],
+    [11.557610062707601, -7.480808363920396, 1.4959133510217755],
+    [14.50997557867995, -10.198994716957165, 2.1072660492948336],
+    [19.111002589981855, -14.371655268956477, 3.0293790068295565],
+    [27.799743661451732, -22.158159588180798, 4.727798839851097],
+    [54.59433727646709, -45.99057229824746, 9.901219686842062],
+    [-23.17488076579499, 25.020481145712136, -5.706041921768726],
+    [-13.727291417017879, 16.105239450069455, -3.7043589147001557],
+    [-8.457697925071281, 11.207035128642561, -2.6124559107938046],
+    [-4.983203461618504, 7.988988335209302, -1.8926515611713632],
+    [-2.4817597374868043, 5.657896730655351, -1.364827705301003],
+    [-0.5567506588053992, 3.841994606811606, -0.9464666034594506],
+    [1.0201790349318616, 2.332167502429899, -0.5920308956348669],
+    [2.4026058727896875, 0.9903247104860647, -0.27190795822907804],
+    [3.7137262235215243, -0.2927936590262413, 0.03706574383941611],
+    [5.074325786484761, -1.6234968430806114, 0.35730641727569423],
+    [6.629787516527997, -3.1296154624892694, 0.7158074794478788],
+    [8.593565909183853, -4.99940154027791, 1.1525514679364863],
+    [11.347755536566645, -7.571112967595851, 1.7398670802452372],
+    [15.746236875117608, -11.603027070533852, 2.6409365342775484],
+    [24.428615565466483, -19.445062195772262, 4.364762137309377],
+    [54.99235061608887, -46.7992337273706, 10.341646192622962],
+    [-27.173038370986227, 28.568550177206156, -6.255761474045252],
+    [-17.405452122008192, 19.36455001561557, -4.20638002514578],
+    [-11.877885293074092, 14.22999347695648, -3.0687201569574194],
+    [-8.198966591349077, 10.82937898341312, -2.3123113746511286],
+    [-5.547174216742314, 8.364739003958112, -1.7567319974212308],
+    [-3.526058532673219, 6.460397218914886, -1.318567958045166],
+    [-1.9046303744877204, 4.9032948079160334, -0.9514583031687575],
+    [-0.5284609058253978, 3.5538586468392723, -0.6254944761474178],
+    [0.7234701817088468, 2.304263280173515, -0.3178063525480781],
+    [1.9673554115059986, 1.0517979216679356, -0.006708188369380402],
+    [3.3433624917101428, -0.3272814416991259, 0.33396480379584664],
+    [5.060836683471243, -2.0184417270350448, 0.7436653507646586],
+    [7.500879527469492, -4.361081205990876, 1.295175567527743],
+    [11.53543521763845, -8.13530042549035, 2.157151356317072],
+    [19.99938170125976, -15.88618760978337, 3.884345415261332],
+    [55.81720433452602, -48.25815382965802, 11.026666607765186],
+    [-32.75641590755648, 33.65931866606231, -7.0914892985569],
+    [-22.219803614786944, 23.7420516568087, -4.9065122457700845],
+    [-16.241138644357996, 18.19019508899688, -3.6855347564910916],
+    [-12.261174314552433, 14.514550142049247, -2.872284266224807],
+    [-9.407830670338237, 11.865319522049935, -2.276564636709201],
+    [-7.269846827581511, 9.847528029825192, -1.8107894823053394],
+    [-5.608622794032743, 8.238019832940152, -1.4263188153565134],
+    [-4.265663555697189, 6.892051356807137, -1.0920696751847898],
+    [-3.1210500237579635, 5.701746746585548, -0.7850468809809255],
+    [-2.066442156067381, 4.570264278411414, -0.4846093775087264],
+    [-0.977617014792024, 3.386514991319007, -0.16697962076026682],
+    [0.3309748884427095, 1.9839455520411153, 0.20367046581692605],
+    [2.212170935140046, 0.044300538320228754, 0.6961933995610537],
+    [5.515697918140464, -3.2030709301725153, 1.478644380678043],
+    [13.1935085121569, -10.448566749268442, 3.143630979834417],
+    [56.608627804509375, -50.39458002433671, 12.109739360676864],
+    [-41.82231882650174, 42.10734157794627, -8.542461774075045],
+    [-29.517629065174887, 30.531667817007218, -6.0302122232485065],
+    [-22.65807357568848, 24.16220646166174, -4.644104749384156],
+    [-18.16080087054949, 20.009863186728204, -3.730658038304927],
+    [-15.00524026333197, 17.077158659321405, -3.07026446128264],
+    [-12.729774014452602, 14.913936587289427, -2.563664525256872],
+    [-11.07566471064379, 13.272490710859692, -2.1566019986139384],
+    [-9.875688821704198, 11.996374847732096, -1.8150286897794725],
+    [-9.00962683495602, 10.974734602953802, -1.5147156833176898],
+    [-8.379286579042947, 10.117061115214282, -1.2355126820641866],
+    [-7.886772715137823, 9.332057194255814, -0.9565900191807571],
+    [-7.402120314215017, 8.496884323246467, -0.6495438566950548],
+    [-6.684512002970992, 7.3827043027345844, -0.26170655474456633],
+    [-5.096814824022057, 5.384871357956637, 0.3444773735667491],
+    [-8.535729137688214e-6, 7.976514725956119e-6, 1.7371962807934138],
+    [54.65537330221977, -52.523732024825044, 14.027968226607051],
+    [-61.7941584076425, 61.056893745411074, -11.916194033503348],
+    [-44.09166872707864, 44.38219755744295, -8.394565055374288],
+    [-34.95307583048885, 35.896787617118946, -6.582811681326828],
+    [-29.286897894081154, 30.67525144625583, -5.443065691866046],
+    [-25.58741171123092, 27.233653687627427, -4.657313699925507],
+    [-23.250151574118185, 24.96190660734674, -4.091438601532469],
+    [-21.97837839894483, 23.55677935685469, -3.676537097697165],
+    [-21.606052388548676, 22.84922901838447, -3.3720094082973824],
+    [-22.038833705660448, 22.743117206155734, -3.151545796434975],
+    [-23.23141702769123, 23.19012678855236, -2.9965081861515093],
+    [-25.18728325702238, 24.18448970909469, -2.8924066434036124],
+    [-27.990728674975188, 25.779824011000798, -2.82690772052099],
+    [-31.890200467738, 28.137852828610473, -2.78691732169122],
+    [-37.4886351203076, 31.633820307644278, -2.744455002034835],
+    [-46.2037564374959, 37.000118757088956, -2.545330982500811],
+    [18.53040737306832, -44.631958984304454, 22.771349756912155],
+    [105.056112806567, -60.80472510823838, 2.724311206111162],
+    [96.4908727127514, -60.41181812867657, 4.896486668636087],
+    [87.28255131334788, -56.26097893238765, 5.268202386920318],
+    [80.82730489648576, -53.218747231808926, 5.459097727175871],
+    [76.33717956665988, -51.1762636224124, 5.630925730989532],
+    [73.27526586446275, -49.93195531107984, 5.826768222058624],
+    [71.33665039450996, -49.36240440435226, 6.066397209844995],
+    [70.36289537221245, -49.412452952037434, 6.364853742624304],
+    [70.29984450018986, -50.08565266893398, 6.739158451608758],
+    [71.18745932898115, -51.44795560835624, 7.213102235839432],
+    [73.17848786322158, -53.64959831004699, 7.8236274383729025],
+    [76.60119553958107, -56.980915579204, 8.632882511506578],
+    [82.12092882767449, -62.00899595500641, 9.755514767819882],
+    [91.18623416320557, -69.95033644529855, 11.431562835579362],
+    [107.55860977458327, -83.944466339087, 14.27420237575927],
+    [145.4610225842224, -115.82752146965917, 20.583813111812724],
+    [33.52573565768008, -14.32422744388933, -1.6715547299039684],
+    [45.05238882504538, -26.44250432468595, 1.4923143247964565],
+    [46.40148513834874, -28.7265116213492, 2.3099364038901187],
+    [46.67797471543513, -29.832201577082735, 2.8071420112466385],
+    [46.796761384150706, -30.649089304557517, 3.1978167394619614],
+    [47.02191505780203, -31.447525014919034, 3.550743196022477],
+    [47.47347476534685, -32.3556045403833, 3.89989611555718],
+    [48.23575016492126, -33.461892061256826, 4.268440189613593],
+    [49.39588602693049, -34.852128984750706, 4.677350780261355],
+    [51.068817265989004, -36.632274365453064, 5.1506343274271424],
+    [53.42768731913018, -38.9552632138203, 5.721019097879081],
+    [56.75821013476403, -42.06752635212585, 6.439464724973727],
+    [61.57765617386887, -46.409776611319394, 7.39545433560839],
+    [68.94441808026221, -52.878374702919054, 8.769373905884281],
+    [81.4738372970229, -63.68210413264313, 11.004010473244097],
+    [108.34095032614043, -86.55795753487929, 15.647658309390271],
+    [15.019597722163413, -2.0386460749024464, -2.8949440349398903],
+    [28.30434392023598, -15.062342262190873, 0.2573497396322297],
+    [31.420350639388154, -18.44225627020096, 1.1630474811362905],
+    [32.936140857465865, -20.279138266624695, 1.7020465300169592],
+    [33.998721630414686, -21.64178659129722, 2.11841140493626],
+    [34.955353817273725, -22.855153787933943, 2.4867738858613953],
+    [35.96877715254929, -24.07211949727056, 2.8424350858800884],
+    [37.14713230474321, -25.393404628683427, 3.208436606372157],
+    [38.58927529078397, -26.909010342881114, 3.6048167218733203],
+    [40.41128857223977, -28.721940081025416, 4.053771964276146],
+    [42.774983377027475, -30.973002002008368, 4.584863633777447],
+    [45.93552885069341, -33.88160875223164, 5.2434036178043035],
+    [50.343180516580674, -37.83241924081454, 6.1081269243498735],
+    [56.9057270704996, -43.598483890954945, 7.336622641603739],
+    [67.84650042845259, -53.070386927243966, 9.313770820312989],
+    [90.90393033863526, -72.82287659280428, 13.37779496708232],
+    [6.0469864786764305, 3.859957728746203, -3.4218074953277253],
+    [18.973461526667077, -8.557683724989216, -0.48959462905661],
+    [22.878642677427692, -12.458682897275784, 0.4730355308033444],
+    [24.916758432906178, -14.609688883221034, 1.0337499048799053],
+    [26.357644737363973, -16.18385494211717, 1.4573975512538044],
+    [27.59865895967291, -17.54580502223932, 1.825695789949485],
+    [28.82308714710259, -18.863023282021658, 2.176017447283212],
+    [30.148047313781515, -20.241578729145555, 2.531792819846951],
+    [31.675020120578484, -21.771985237069217, 2.9126539526117896],
+    [33.517671890286195, -23.5539699498808, 3.3397912159895995],
+    [35.829592552050904, -25.72060691493219, 3.8410290243817866],
+    [38.84892853499445, -28.47665156667711, 4.458759553703444],
+    [42.99282561324665, -32.17929971211974, 5.266570776721056],
+    [49.10192069088913, -37.547013079314425, 6.412208930855662],
+    [59.24844079724891, -46.349532209550134, 8.259608895656763],
+    [80.78288292414483, -64.86172968695374, 12.100242603010997],
+    [0.5154752065680545, 7.498310394076357, -3.7194600175625134],
+    [12.7148640462262, -4.097291062598477, -1.019020732892461],
+    [17.05309026802401, -8.2874918118848, -0.023134141553155917],
+    [19.409209642678377, -10.640219011933892, 0.5567188024153088],
+    [21.064397615638388, -12.336458937621687, 0.9860780789598562],
+    [22.454416948957835, -13.772914598254898, 1.3529328299508443],
+    [23.78015689723291, -15.131039081487819, 1.6971848949263058],
+    [25.16628187037836, -16.52239095087927, 2.0431094589483854],
+    [26.716648660922225, -18.038763259796294, 2.4103929956760304],
+    [28.543767860487446, -19.778363203759717, 2.819783824597445],
+    [30.79665908608549, -21.870064314385985, 3.298205194763359],
+    [33.7044448740542, -24.510925654660646, 3.8865609996596],
+    [37.668296481683484, -28.045030123565596, 4.6560756543670605],
+    [43.50164132037579, -33.169275304038806, 5.7509403905485295],
+    [53.23944579252169, -41.62661526555142, 7.53211031477918],
+    [74.35749636678707, -59.81936126764988, 11.327649557457038],
+    [-3.417460733029951, 10.126080607436998, -3.928637990797031],
+    [8.07042702334948, -0.7145855778571021, -1.4292836354874006],
+    [12.65926977535314, -5.065985040268205, -0.41729936214690294],
+    [15.245966912129937, -7.572148940453002, 0.18036944197078478],
+    [17.05375822662093, -9.360465993026319, 0.6166975441276478],
+    [18.541198845356725, -10.846700927148111, 0.9833630631907888],
+    [19.925440834293006, -12.225673964329863, 1.3228347368034619],
+    [21.33911060077558, -13.615217764618865, 1.6605228448475753],
+    [22.88932244614616, -15.109474198943216, 2.0164813529279333],
+    [24.68893648593306, -16.806633543247877, 2.411384749878893],
+    [26.88507288541956, -18.833768495970286, 2.8717712888422735],
+    [29.702826359815308, -21.384507153153685, 3.437908796587533],
+    [33.537517868125775, -24.79827821623017, 4.180295120577035],
+    [39.19882161697487, -29.76989523801388, 5.2433157585607395],
+    [48.75402865877272, -38.07352018379681, 6.99625182633534],
+    [70.16764820466763, -56.54540619003754, 10.865386595197917],
+    [-6.516084894340056, 12.261199261471214, -4.106170984843474],
+    [4.368175787683558, 2.048497199785507, -1.771344235238299],
+    [9.11624523616011, -2.39952820903001, -0.7521967371065014],
+    [11.885137237781773, -5.03085061580547, -0.13831991703747504],
+    [13.819184695276528, -6.900596019894217, 0.30661909125965536],
+    [15.38300210545814, -8.429106220490954, 0.6749406908524245],
+    [16.807473694641413, -9.822546013526207, 1.011299143071049],
+    [18.233754881258896, -11.205645477901935, 1.3424064249901502],
+    [19.77325073138072, -12.675881805376621, 1.6889148920834958],
+    [21.540500614403065, -14.332630533245199, 2.0716870440676627],
+    [23.68269829697421, -16.30287152093352, 2.51722477677919],
+    [26.4243114991396, -18.77966915979342, 3.0657216809567633],
+    [30.161565470317775, -22.10343043211545, 3.788036829157286],
+    [35.71644445915789, -26.980276966646247, 4.831351989534562],
+    [45.24050830424755, -35.259221555981114, 6.581944421067708],
+    [67.52859274307365, -54.50151010177843, 10.624255989564906],
+    [-9.168352734042521, 14.16979429884482, -4.281114096260769],
+    [1.2405543904622611, 4.450846599759696, -2.0761957661990507],
+    [6.109461180357819, -0.06860356896519307, -1.0527137468548458],
+    [9.033500368883121, -2.8090225591470923, -0.42367996747004605],
+    [11.082347088326673, -4.7571495478483685, 0.03141342218849083],
+    [12.715192112383987, -6.327899567906625, 0.4033936660270341],
+    [14.17262411162227, -7.73533283267135, 0.7383783804449539],
+    [15.604482780825089, -9.111228784112898, 1.0644141142422172],
+    [17.1274840723275, -10.557278892424788, 1.4029323931277995],
+    [18.859040849891425, -12.175063701891208, 1.7751892308474668],
+    [20.947988134912844, -14.092785969701428, 2.207906869405172],
+    [23.6206790353799, -16.505035873204243, 2.741604979106365],
+    [27.27921944329296, -19.757331533970078, 3.4482965729737227],
+    [32.77029711438138, -24.57745554873333, 4.4799813247097955],
+    [42.37515930632244, -32.92766774831252, 6.247669486631614],
+    [66.10970782718326, -53.429231570284095, 10.563977249694538],
+    [-11.608615196326165, 16.019484360649535, -4.472775154777882],
+    [-1.5418360638357993, 6.66200046473785, -2.365666999278005],
+    [3.443680668875387, 2.0702726569597867, -1.3363609480256942],
+    [6.5127888116899655, -0.7744732202164407, -0.6919107849848083],
+    [8.673463995454258, -2.8023621206681444, -0.22509089642075059],
+    [10.3753441843272, -4.419277245993656, 0.15263850807203727],
+    [11.864414629078897, -5.8434407810163185, 0.48806117487399836],
+    [13.298603455841615, -7.213128934184308, 0.8104270309275194],
+    [14.800785965939655, -8.63492865342645, 1.142071839538011],
+    [16.49241490251062, -10.213566340570795, 1.5048204427864234],
+    [18.525310788162923, -12.079559332275538, 1.925817694078215],
+    [21.129707961213885, -14.430649162841068, 2.446209538513792],
+    [24.7175161504631, -17.620691326587224, 3.1397697848426387],
+    [30.17071995795971, -22.407968531711692, 4.16503822582661],
+    [39.944753741990446, -30.905732366666534, 5.965420205433945],
+    [65.78367707413271, -53.231464233888545, 10.673550689593275],
+    [-14.009533869322885, 17.944465748799747, -4.698650257125246],
+    [-4.142391382411153, 8.812496915114137, -2.658059925585626],
+    [0.9829592668392412, 4.126472686663004, -1.6178067016922657],
+    [4.203357895699374, 1.1704528970829953, -0.9559115208822528],
+    [6.480643524278327, -0.9433086653443062, -0.47535756423777875],
+    [8.256615285943202, -2.613134490534255, -0.08962888978480726],
+    [9.77990225999082, -4.05899192433057, 0.24819151154580732],
+    [11.215158666091575, -5.4244158236339866, 0.5682746636914198],
+    [12.691914886487094, -6.821213918342116, 0.8938985251699622],
+    [14.336744831281637, -8.358083897585319, 1.2475971598506508],
+    [16.305707361633527, -10.168824940973852, 1.6571032591026538],
+    [18.834595597830265, -12.455645758186144, 2.164415389475826],
+    [22.348391462585383, -15.58337082550933, 2.8455416385479304],
+    [27.77394541308591, -20.348709548030147, 3.867081268802179],
+    [37.78811343083738, -29.05597812467313, 5.7129688281446525],
+    [66.582611048507, -53.93865670436035, 10.966768152893174],
+    [-16.532574229171935, 20.084969963351778, -4.979882654075219],
+    [-6.696435357568877, 11.02398102696134, -2.972140684122972],
+    [-1.377802260207437, 6.198389166261641, -1.911757365889484],
+    [2.019246618387565, 3.1100765568792066, -1.2280542450257113],
+    [4.4280226264810345, 0.8974786775452501, -0.7307590768227626],
+    [6.288290911935563, -0.8351531752024719, -0.33440310792573485],
+    [7.851466172120059, -2.309551076772055, 0.008019580662057515],
+    [9.287514163596246, -3.673194357329039, 0.32729453971705824],
+    [10.73242606514593, -5.042806464209605, 0.6475404138553618],
+    [12.318737628993096, -6.531611809716154, 0.9920706236534993],
+    [14.207896751216218, -8.27750482999054, 1.3893231888046629],
+    [16.64268065956266, -10.488193568189722, 1.8822986069233127],
+    [20.063854881312047, -13.541548281722815, 2.5495721024250857],
+    [25.452019024915707, -18.279928900118136, 3.567128787968306],
+    [35.75789293142752, -27.2430473028006, 5.468349145400584],
+    [68.72203157433455, -55.729303836784034, 11.48829771659277],
+    [-19.369168124636253, 22.62653968031024, -5.347795753578576],
+    [-9.335970994679682, 13.435625951151072, -3.331458905018561],
+    [-3.724877963595892, 8.390601554755696, -2.2357401019215484],
+    [-0.09715885117127862, 5.128195396145163, -1.5223468551077473],
+    [2.474156066622131, 2.792619563093976, -1.0034683474253765],
+    [4.436695980040619, 0.9822883044003796, -0.5930483874315325],
+    [6.048473634207001, -0.5293275204699179, -0.2434518305154184],
+    [7.484754336623525, -1.8934553370730907, 0.07662689043774709],
+    [8.887145736603607, -3.2308246072352786, 0.39191321460770345],
+    [10.39419925175795, -4.65899845097162, 0.7264041526967933],
+    [12.173482905166093, -6.320316936745771, 1.1092432240529415],
+    [14.475741149863293, -8.428517234654757, 1.5844473298647075],
+    [17.759431719438858, -11.375745619768459, 2.233266301706801],
+    [23.06673563123373, -16.056074260606483, 3.242053302207772],
+    [33.68128017900709, -25.29403558208685, 5.203341812681818],
+    [72.72274482053517, -59.02975179221222, 12.33670705564183],
+    [-22.8013298242966, 25.868593964434776, -5.8566889656558425],
+    [-12.20599708808705, 16.23841326208828, -3.7711538697151465],
+    [-6.112786194600396, 10.827770255687323, -2.613605152253238],
+    [-2.145358178213616, 7.311119557307649, -1.8562901049885534],
+    [0.6509535369659967, 4.807539759743005, -1.3075550667173161],
+    [2.7488917624125833, 2.895254148817472, -0.8780304419931662],
+    [4.422091068566915, 1.3358075075718654, -0.5181583450739071],
+    [5.854570011178268, -0.028291857350004737, -0.1956996573436597],
+    [7.193273718536747, -1.3212244091863872, 0.11450277589209941],
+    [8.581247192920584, -2.6633136191269067, 0.43675214022701864],
+    [10.191178654764625, -4.200096088354571, 0.8005563328392956],
+    [12.28113014486718, -6.150271229396121, 1.2505954770105665],
+    [15.32699922065287, -8.919326608184438, 1.8703675023526594],
+    [20.437209828774943, -13.455807201058317, 2.8566792651867563],
+    [31.29209564194479, -22.92111853216363, 4.870819751399576],
+    [79.78581203347629, -64.81992552226396, 13.732870510842872],
+    [-27.329537203572475, 30.38998602033417, -6.616534460380644],
+    [-15.43431684675099, 19.722827564955974, -4.350555880565486],
+    [-8.434607802696448, 13.636520010099645, -3.0779537815495543],
+    [-3.8800492993609437, 9.694900676670775, -2.24831834164241],
+    [-0.717685163688908, 6.930231160211106, -1.6541019059623443],
+    [1.587076130085021, 4.871020720499912, -1.197200082876968],
+    [3.343739360031955, 3.251365524443856, -0.8233734115904536],
+    [4.755821819475093, 1.8995944279117287, -0.4980700135579967],
+    [5.9788372208759375, 0.6859761352544751, -0.19535949772184058],
+    [7.157702006448438, -0.5099870513095337, 0.10883722728554061],
+    [8.463761338496434, -1.8312886094337122, 0.44323899829912483],
+    [10.154234419180694, -3.494118493788328, 0.8515952051963627],
+    [12.71273651835532, -5.907882290017271, 1.417430730880129],
+    [17.304920028504498, -10.065372680884327, 2.345020880122128],
+    [28.058826398000615, -19.50183924863432, 4.369199984100362],
+    [93.151246309328, -75.74025102057456, 16.265847407235174],
+    [-33.94779904379689, 37.53921829341261, -7.896947368295045],
+    [-18.53650926515715, 24.16172437216577, -5.158940761769948],
+    [-9.312645024622844, 16.51267011128646, -3.630558079179933],
+    [-3.4059981192556847, 11.669320594829452, -2.6572128575531373],
+    [0.5646456329632181, 8.390584322147076, -1.9812537206111254],
+    [3.3078627104565217, 6.070197918392638, -1.4809841612308636],
+    [5.228866740768357, 4.371482333274971, -1.090118150072133],
+    [6.58205754375304, 3.086519180145069, -0.7680035639465889],
+    [7.543977033843965, 2.0714489610092066, -0.48628599490586105],
+    [8.256846733483101, 1.2090142695077237, -0.22132251784356474],
+    [8.867351804039156, 0.375959921238097, 0.052351505960735664],
+    [9.585867174630254, -0.6064817913801867, 0.3719437142283025],
+    [10.829235622607664, -2.092405399099622, 0.8105180285727773],
+    [13.706624115443514, -5.02201796960433, 1.56041939376509],
+    [22.66605439103387, -13.177699875028821, 3.3975981697615127],
+    [125.93791030629072, -102.47680546491185, 22.307388744974986],
+    [-39.78079678048742, 48.90929437041495, -10.270163450770525],
+    [-3.2096002811245716, 21.505453700187463, -5.34125254092486],
+    [17.42741327307891, 7.209298673415897, -2.8920368973058457],
+    [30.11988764862846, -1.1339408441162073, -1.490060807581896],
+    [38.5371538123646, -6.401479778745738, -0.606468684415702],
+    [44.629111790082796, -9.998216583983412, 0.001788016903403491],
+    [49.56458313679228, -12.70665619037678, 0.46329376802795524],
+    [54.08123160118058, -14.984377480706149, 0.8501686207298651],
+    [58.686208820399315, -17.113861617730198, 1.2052679285444459],
+    [63.82504316273413, -19.307386236422, 1.5591996307819473],
+    [70.03385918191064, -21.782917166848954, 1.9414748668745214],
+    [78.15833545819092, -24.849871291430944, 2.391779839581898],
+    [89.86846171524924, -29.088959666528318, 2.9821146109170313],
+    [109.41333492091348, -35.94789594887607, 3.888624035084418],
+    [153.6260734355004, -51.11841923677402, 5.795603052529902],
+    [5552.169318274039, -294.16970168547937, 241.5849107842406],
+];
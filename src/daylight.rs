@@ -0,0 +1,151 @@
+/*!
+# Daylight Presets
+
+The CIE D illuminants represent phases of daylight, for correlated color temperatures from 4000 to
+25000 kelvin, with their chromaticities on the daylight locus.
+The color temperature of daylight depends on the elevation of the sun, the weather, the season,
+and the location: direct sunlight is warmer when the sun is low, and skylight is bluer than
+sunlight, so shade, overcast skies, and north facing windows, have higher color temperatures.
+
+A [`DaylightPreset`] is a typical color temperature for a daylight scenario, for quick scenario
+studies; the values are approximate averages, for mid-latitude locations, of the combination of
+direct sunlight and skylight on a horizontal plane, unless noted otherwise, and real daylight
+varies considerably around them.
+Daylight with a color temperature below 4000 K, such as the direct sunlight at sunrise and sunset,
+is outside the range of the D illuminants, and is not included.
+
+Intermediate phases of daylight are interpolated along the daylight locus, in equal steps of
+reciprocal temperature, in mired, by [`daylight_interpolate`], and [`daylight_series`].
+```
+use colorimetry::prelude::*;
+
+let noon = DaylightPreset::Noon.illuminant();
+let [x, y] = CIE1931.xyz(&noon, None).xy().values();
+approx::assert_abs_diff_eq!(x, 0.3324, epsilon = 5E-4);
+
+// from morning to noon, in 5 steps
+let day = daylight_series(DaylightPreset::Morning.cct(), DaylightPreset::Noon.cct(), 5).unwrap();
+assert_eq!(day.len(), 5);
+```
+*/
+
+use crate::{error::CmtError, illuminant::Illuminant};
+
+/// Lowest correlated color temperature of the CIE D illuminants.
+const D_MIN_CCT: f64 = 4000.0;
+
+/// Highest correlated color temperature of the CIE D illuminants.
+const D_MAX_CCT: f64 = 25000.0;
+
+/// Typical daylight scenarios, by correlated color temperature.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, strum_macros::Display, strum_macros::EnumIter)]
+pub enum DaylightPreset {
+    /// Daylight with the sun about 10° above the horizon, shortly after sunrise, or before
+    /// sunset, 4000 K.
+    LowSun,
+    /// Mid-morning, or mid-afternoon, daylight, with the sun at about 30° elevation, 4800 K.
+    Morning,
+    /// Summer noon daylight, sunlight with skylight, 5500 K, as CIE D55.
+    Noon,
+    /// Winter noon daylight, with a low sun, and a larger contribution of skylight, 6000 K.
+    WinterNoon,
+    /// Average daylight, and overcast sky, 6500 K, as CIE D65.
+    Overcast,
+    /// Light from a north facing window, in the northern hemisphere, without direct sunlight,
+    /// 7500 K, as CIE D75.
+    NorthSky,
+    /// Shade, lit by clear blue skylight only, 10000 K.
+    Shade,
+    /// Clear blue sky, at high altitude, or high latitude, 15000 K.
+    BlueSky,
+}
+
+impl DaylightPreset {
+    /// Correlated color temperature, in kelvin.
+    pub fn cct(&self) -> f64 {
+        match self {
+            DaylightPreset::LowSun => 4000.0,
+            DaylightPreset::Morning => 4800.0,
+            DaylightPreset::Noon => 5500.0,
+            DaylightPreset::WinterNoon => 6000.0,
+            DaylightPreset::Overcast => 6500.0,
+            DaylightPreset::NorthSky => 7500.0,
+            DaylightPreset::Shade => 10000.0,
+            DaylightPreset::BlueSky => 15000.0,
+        }
+    }
+
+    /// The CIE D illuminant for the preset's color temperature, normalized to an irradiance of
+    /// 1 W/m².
+    pub fn illuminant(&self) -> Illuminant {
+        // unwrap: all presets are within the range of the D illuminants
+        Illuminant::try_d_illuminant(self.cct()).unwrap()
+    }
+}
+
+/// CIE 1931 chromaticity coordinates on the daylight locus, for a correlated color temperature
+/// from 4000 to 25000 kelvin, as defined in CIE 15.
+pub fn daylight_chromaticity(cct: f64) -> Result<[f64; 2], CmtError> {
+    if !(D_MIN_CCT..=D_MAX_CCT).contains(&cct) {
+        return Err(CmtError::OutOfRange { name: "CIE D Illuminant Temperature".to_string(), low: D_MIN_CCT, high: D_MAX_CCT });
+    }
+    let xd = match cct {
+        t if t < 7000.0 => 0.244063 + 0.09911E3 / t + 2.9678E6 / t.powi(2) - 4.607E9 / t.powi(3),
+        t => 0.23704 + 0.24748E3 / t + 1.9018E6 / t.powi(2) - 2.0064E9 / t.powi(3),
+    };
+    Ok([xd, -3. * xd.powi(2) + 2.87 * xd - 0.275])
+}
+
+/// A D illuminant interpolated between two correlated color temperatures, linearly in mired, with
+/// a parameter t from 0.0, for the first, to 1.0, for the second temperature.
+pub fn daylight_interpolate(from_cct: f64, to_cct: f64, t: f64) -> Result<Illuminant, CmtError> {
+    if !(0.0..=1.0).contains(&t) {
+        return Err(CmtError::OutOfRange { name: "Interpolation parameter".into(), low: 0.0, high: 1.0 });
+    }
+    Illuminant::try_d_illuminant(1.0 / ((1.0 - t) / from_cct + t / to_cct))
+}
+
+/// A series of `n` D illuminants, from one correlated color temperature to another, both
+/// included, in equal steps of mired.
+pub fn daylight_series(from_cct: f64, to_cct: f64, n: usize) -> Result<Vec<Illuminant>, CmtError> {
+    if n < 2 {
+        return Err(CmtError::ProvideAtLeastNValues(2));
+    }
+    (0..n).map(|i| daylight_interpolate(from_cct, to_cct, i as f64 / (n - 1) as f64)).collect()
+}
+
+#[cfg(test)]
+mod daylight_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn test_daylight() {
+        // D65 chromaticity, from the daylight locus, and from the tabulated spectrum
+        let [x, y] = daylight_chromaticity(6504.0).unwrap();
+        assert_abs_diff_eq!(x, 0.3127, epsilon = 1E-4);
+        assert_abs_diff_eq!(y, 0.3291, epsilon = 1E-4);
+        assert!(daylight_chromaticity(3000.0).is_err());
+
+        // presets are ordered by color temperature, and on the daylight locus
+        let ccts: Vec<f64> = DaylightPreset::iter().map(|p| p.cct()).collect();
+        assert!(ccts.windows(2).all(|w| w[0] < w[1]));
+        for preset in DaylightPreset::iter() {
+            let xy = CIE1931.xyz(&preset.illuminant(), None).xy().values();
+            let locus = daylight_chromaticity(preset.cct()).unwrap();
+            assert_abs_diff_eq!(xy.as_ref(), locus.as_ref(), epsilon = 2E-3);
+        }
+
+        // equal steps in mired
+        #[cfg(feature = "cct")]
+        {
+            let series = daylight_series(4000.0, 10000.0, 4).unwrap();
+            let mired: Vec<f64> = series.iter().map(|s| 1E6 / CIE1931.xyz(s, None).cct().unwrap().t()).collect();
+            assert_abs_diff_eq!(mired[0] - mired[1], mired[2] - mired[3], epsilon = 1.0);
+            assert_abs_diff_eq!(mired[0], 250.0, epsilon = 3.0);
+        }
+        assert!(daylight_interpolate(4000.0, 6500.0, 1.5).is_err());
+        assert!(daylight_series(4000.0, 6500.0, 1).is_err());
+    }
+}
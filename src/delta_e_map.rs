@@ -0,0 +1,264 @@
+/*!
+# Color Difference Maps
+
+Imaging engineers compare the output of a camera pipeline, a display, or a renderer, with a
+reference image, pixel by pixel.
+A [`DeltaEMap`] holds the color differences of all pixels of two images, of the same size, given as
+RGB values, as spectral images, or as tristimulus values, with summary statistics, such as the
+mean, the 95th percentile, and the maximum color difference.
+
+The color differences are calculated for the viewing conditions set by [`DiffConditions`]: the
+reference white, which adapts the CIELAB metrics, and the metric, either one of the CIELAB based
+[`DeltaE`] metrics, or the ITU-R BT.2124 ΔE<sub>ITP</sub> metric for HDR video, for which the
+luminance of the white, in cd/m², sets the absolute luminance levels of the pixels.
+The ΔE<sub>ITP</sub> metric is defined for a D65 white, and the CIE 1931 observer.
+```
+use colorimetry::prelude::*;
+
+let image: Vec<RGB> = (0..16).map(|i| RGB::new(i as f64 / 15.0, 0.5, 0.2, None, None)).collect();
+let mut shifted = image.clone();
+shifted[5] = RGB::new(5.0 / 15.0, 0.55, 0.2, None, None);
+
+let white = RGB::new(1.0, 1.0, 1.0, None, None).xyz();
+let map = DeltaEMap::try_from_rgb(4, 4, &image, &shifted, &DiffConditions::new(white)).unwrap();
+assert_eq!(map.get(1, 1), Some(map.stats().max));
+assert_eq!(map.stats().median, 0.0);
+assert!(map.fraction_above(1.0) > 0.0 && map.fraction_above(1.0) < 0.1);
+```
+*/
+
+use nalgebra::Vector3;
+
+use crate::{
+    error::CmtError,
+    lab::{CieLab, DeltaE},
+    observer::{Observer, ObserverData},
+    rgb::RGB,
+    spectral_image::SpectralImage,
+    traits::Light,
+    video::ICtCp,
+    xyz::XYZ,
+};
+
+/// Color difference metric of a [`DeltaEMap`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, strum_macros::Display)]
+pub enum DiffMetric {
+    /// A CIELAB based metric, such as CIEDE2000.
+    Lab(DeltaE),
+    /// ITU-R BT.2124 ΔE<sub>ITP</sub>, using ICtCp values.
+    Itp,
+}
+
+impl Default for DiffMetric {
+    fn default() -> Self {
+        DiffMetric::Lab(DeltaE::CIEDE2000)
+    }
+}
+
+/// Viewing conditions, and metric, for the calculation of a [`DeltaEMap`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DiffConditions {
+    white: XYZ,
+    white_luminance: f64,
+    metric: DiffMetric,
+}
+
+impl DiffConditions {
+    /// Conditions with a reference white, using the CIEDE2000 metric, and a white luminance of
+    /// 203 cd/m², the reference white of ITU-R BT.2408 for HDR video.
+    pub fn new(white: XYZ) -> Self {
+        Self { white, white_luminance: 203.0, metric: DiffMetric::default() }
+    }
+
+    pub fn set_metric(mut self, metric: DiffMetric) -> Self {
+        self.metric = metric;
+        self
+    }
+
+    /// Sets the luminance of the reference white, in cd/m², as used by the ΔE<sub>ITP</sub> metric.
+    pub fn set_white_luminance(mut self, luminance: f64) -> Self {
+        self.white_luminance = luminance;
+        self
+    }
+
+    pub fn white(&self) -> XYZ {
+        self.white
+    }
+
+    pub fn white_luminance(&self) -> f64 {
+        self.white_luminance
+    }
+
+    pub fn metric(&self) -> DiffMetric {
+        self.metric
+    }
+}
+
+/// Summary statistics of the color differences of a [`DeltaEMap`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DeltaEStats {
+    pub mean: f64,
+    pub median: f64,
+    pub percentile_95: f64,
+    pub max: f64,
+}
+
+/// Color differences of the pixels of two images, in row-major order.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeltaEMap {
+    width: usize,
+    height: usize,
+    values: Vec<f64>,
+}
+
+impl DeltaEMap {
+    /**
+    Color differences of two images, with the tristimulus values of their pixels, in row-major
+    order; the tristimulus values of colors, such as the sample values obtained for reflectance
+    factors, and of lights, which are taken as absolute values, are compared to the white of the
+    conditions.
+    */
+    pub fn try_from_xyz(width: usize, height: usize, reference: &[XYZ], sample: &[XYZ], conditions: &DiffConditions) -> Result<Self, CmtError> {
+        if reference.len() != width * height || sample.len() != width * height {
+            return Err(CmtError::ErrorString(format!("DeltaEMap: expected {} pixels for a {width}x{height} image", width * height)));
+        }
+        let observer = conditions.white.observer;
+        if reference.iter().chain(sample).any(|xyz| xyz.observer != observer) {
+            return Err(CmtError::RequireSameObserver);
+        }
+        if conditions.metric == DiffMetric::Itp && observer != Observer::Std1931 {
+            return Err(CmtError::RequiresCIE1931XYZ);
+        }
+        let white = absolute(&conditions.white);
+        let relative = |xyz: &XYZ| XYZ::from_vecs(white, Some(absolute(xyz)), observer);
+        let values = reference
+            .iter()
+            .zip(sample)
+            .map(|(r, s)| match conditions.metric {
+                DiffMetric::Lab(metric) => metric.delta_e(&CieLab::try_from(relative(r))?, &CieLab::try_from(relative(s))?),
+                DiffMetric::Itp => {
+                    let itp = |xyz: &XYZ| ICtCp::from_xyz(&relative(xyz), conditions.white_luminance);
+                    Ok(itp(r).delta_e_itp(&itp(s)))
+                }
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { width, height, values })
+    }
+
+    /// Color differences of two images, with the RGB values of their pixels, in row-major order.
+    pub fn try_from_rgb(width: usize, height: usize, reference: &[RGB], sample: &[RGB], conditions: &DiffConditions) -> Result<Self, CmtError> {
+        let xyz = |rgb: &[RGB]| rgb.iter().map(RGB::xyz).collect::<Vec<_>>();
+        Self::try_from_xyz(width, height, &xyz(reference), &xyz(sample), conditions)
+    }
+
+    /// Color differences of two spectral images, with their tristimulus values calculated as by
+    /// [`SpectralImage::xyz`], for an observer, and, for reflectance images, an illuminant.
+    pub fn try_from_spectral(
+        reference: &SpectralImage,
+        sample: &SpectralImage,
+        observer: &ObserverData,
+        illuminant: Option<&dyn Light>,
+        conditions: &DiffConditions,
+    ) -> Result<Self, CmtError> {
+        if (reference.width(), reference.height()) != (sample.width(), sample.height()) {
+            return Err(CmtError::ErrorString("DeltaEMap: images differ in size".into()));
+        }
+        let (width, height) = (reference.width(), reference.height());
+        Self::try_from_xyz(width, height, &reference.xyz(observer, illuminant), &sample.xyz(observer, illuminant), conditions)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The color differences, in row-major order.
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    /// The color difference of the pixel in column `x` and row `y`, or `None` if out of range.
+    pub fn get(&self, x: usize, y: usize) -> Option<f64> {
+        (x < self.width && y < self.height).then(|| self.values[y * self.width + x])
+    }
+
+    /// Mean, median, 95th percentile, and maximum, of the color differences, all zero for an empty
+    /// map; percentiles are linearly interpolated between the sorted values.
+    pub fn stats(&self) -> DeltaEStats {
+        if self.values.is_empty() {
+            return DeltaEStats::default();
+        }
+        let mut sorted = self.values.clone();
+        sorted.sort_by(f64::total_cmp);
+        let percentile = |p: f64| {
+            let f = p * (sorted.len() - 1) as f64;
+            let (i, t) = (f.floor() as usize, f.fract());
+            sorted[i] + t * (sorted[(i + 1).min(sorted.len() - 1)] - sorted[i])
+        };
+        DeltaEStats {
+            mean: sorted.iter().sum::<f64>() / sorted.len() as f64,
+            median: percentile(0.5),
+            percentile_95: percentile(0.95),
+            max: sorted[sorted.len() - 1],
+        }
+    }
+
+    /// Fraction of the pixels with a color difference larger than a threshold, such as a just
+    /// noticeable difference.
+    pub fn fraction_above(&self, threshold: f64) -> f64 {
+        self.values.iter().filter(|&&v| v > threshold).count() as f64 / self.values.len().max(1) as f64
+    }
+}
+
+/// Tristimulus values, not normalized to a luminous value of 100 for lights, as `XYZ::values` does.
+fn absolute(xyz: &XYZ) -> Vector3<f64> {
+    xyz.xyz.unwrap_or(xyz.xyzn)
+}
+
+#[cfg(test)]
+mod delta_e_map_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_delta_e_map() {
+        let d65 = CIE1931.xyz(&StdIlluminant::D65, None);
+        let colorants = [Colorant::gaussian(450.0, 40.0), Colorant::gaussian(550.0, 40.0), Colorant::gray(0.5)];
+        let xyz: Vec<XYZ> = colorants.iter().map(|c| CIE1931.xyz(&StdIlluminant::D65, Some(c))).collect();
+        let shifted: Vec<XYZ> = [Colorant::gaussian(452.0, 40.0), Colorant::gaussian(550.0, 40.0), Colorant::gray(0.5)]
+            .iter()
+            .map(|c| CIE1931.xyz(&StdIlluminant::D65, Some(c)))
+            .collect();
+        let conditions = DiffConditions::new(d65);
+        let map = DeltaEMap::try_from_xyz(3, 1, &xyz, &shifted, &conditions).unwrap();
+        assert_abs_diff_eq!(map.values()[0], xyz[0].delta_e2000(&shifted[0], &d65).unwrap(), epsilon = 1E-9);
+        assert_eq!(&map.values()[1..], [0.0, 0.0]);
+        let stats = map.stats();
+        assert_abs_diff_eq!(stats.mean, map.values()[0] / 3.0, epsilon = 1E-12);
+        assert_abs_diff_eq!(stats.percentile_95, 0.9 * stats.max, epsilon = 1E-12);
+
+        let itp = DeltaEMap::try_from_xyz(3, 1, &xyz, &shifted, &conditions.set_metric(DiffMetric::Itp)).unwrap();
+        assert!(itp.values()[0] > 0.0 && itp.values()[2] == 0.0);
+        // ΔEITP increases with the absolute luminance level
+        let bright = DeltaEMap::try_from_xyz(3, 1, &xyz, &shifted, &conditions.set_metric(DiffMetric::Itp).set_white_luminance(1000.0)).unwrap();
+        assert!(bright.stats().max > itp.stats().max);
+
+        // the same, from spectral images
+        let image = |c: &[Colorant]| SpectralImage::try_new(3, 1, c.iter().flat_map(|c| c.0 .0.iter().copied()).collect()).unwrap();
+        let spectral = DeltaEMap::try_from_spectral(
+            &image(&colorants),
+            &image(&[Colorant::gaussian(452.0, 40.0), Colorant::gaussian(550.0, 40.0), Colorant::gray(0.5)]),
+            &CIE1931,
+            Some(&StdIlluminant::D65),
+            &conditions,
+        )
+        .unwrap();
+        assert_abs_diff_eq!(spectral.values(), map.values(), epsilon = 1E-9);
+
+        assert!(DeltaEMap::try_from_xyz(2, 2, &xyz, &shifted, &conditions).is_err());
+        assert_eq!(DeltaEMap::try_from_xyz(0, 0, &[], &[], &conditions).unwrap().stats(), DeltaEStats::default());
+    }
+}
@@ -0,0 +1,134 @@
+/*!
+# Dimming
+
+The spectral distribution of a dimmed light source depends on how it is dimmed:
+
+- LEDs dimmed by pulse width modulation (PWM), or other fixed spectrum drivers, only change their
+  output, and keep their spectral distribution;
+- incandescent, and halogen, lamps are dimmed by lowering their filament temperature, which
+  shifts their spectral distribution to the red, and lowers their correlated color temperature.
+
+A [`Dimming`] model produces dimmed [`Illuminant`]s, for output fractions relative to full
+output, to chart the change of colorimetric metrics over a dimming curve.
+*/
+
+use crate::{
+    data::observers::CIE1931,
+    error::CmtError,
+    illuminant::Illuminant,
+    physics::planck,
+    spectrum::Spectrum,
+};
+
+/// Lowest filament temperature, in Kelvin, in the incandescent dimming model.
+const MIN_FILAMENT_TEMPERATURE: f64 = 1000.0;
+
+/**
+Dimming model for a light source.
+```
+use colorimetry::prelude::*;
+
+let halogen = Illuminant::planckian(3000.0);
+let dimmed = Dimming::Incandescent { temperature: 3000.0 }.dim(&halogen, 0.1).unwrap();
+approx::assert_abs_diff_eq!(dimmed.illuminance(&CIE1931), 0.1 * halogen.illuminance(&CIE1931), epsilon = 1E-9);
+// dimmed halogen lamps are more reddish
+let [x, _] = CIE1931.xyz(&dimmed, None).xy().values();
+assert!(x > CIE1931.xyz(&halogen, None).xy().x());
+```
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Dimming {
+    /// Output scaled with a constant spectral distribution, as in PWM dimmed LEDs.
+    ConstantSpectrum,
+
+    /// Incandescent lamp, with its filament temperature at full output, in Kelvin, dimmed by
+    /// lowering its filament temperature.
+    Incandescent { temperature: f64 },
+}
+
+impl Dimming {
+    /**
+    The dimmed light source, at an output fraction, from 0.0 to 1.0, of its luminous output at
+    full power, as given by `illuminant`.
+
+    For incandescent lamps, the spectral distribution of the lamp is scaled by the ratio of the
+    Planckian radiators at the dimmed and full filament temperatures, preserving spectral
+    features such as those of filter glass.
+    Returns an error if the fraction is out of range, or if it requires a filament temperature
+    below 1000 K.
+    */
+    pub fn dim(&self, illuminant: &Illuminant, fraction: f64) -> Result<Illuminant, CmtError> {
+        if !(0.0..=1.0).contains(&fraction) {
+            return Err(CmtError::OutOfRange { name: "Dimming Output Fraction".into(), low: 0.0, high: 1.0 });
+        }
+        let illuminance = fraction * illuminant.illuminance(&CIE1931);
+        match *self {
+            Dimming::ConstantSpectrum => Ok(illuminant.clone() * fraction),
+            Dimming::Incandescent { temperature } => {
+                let at = |t: f64| thermal_scaled(illuminant, temperature, t);
+                let rel = |t: f64| at(t).illuminance(&CIE1931) - illuminance;
+                let (mut low, mut high) = (MIN_FILAMENT_TEMPERATURE, temperature);
+                if rel(low) > 0.0 {
+                    return Err(CmtError::OutOfRange {
+                        name: "Dimming Output Fraction".into(),
+                        low: at(low).illuminance(&CIE1931) / illuminant.illuminance(&CIE1931),
+                        high: 1.0,
+                    });
+                }
+                // bisection on the filament temperature, to a precision of about 1E-9 K
+                for _ in 0..60 {
+                    let mid = 0.5 * (low + high);
+                    if rel(mid) > 0.0 {
+                        high = mid;
+                    } else {
+                        low = mid;
+                    }
+                }
+                Ok(at(0.5 * (low + high)).set_illuminance(&CIE1931, illuminance))
+            }
+        }
+    }
+
+    /// Dimmed light sources, for a series of output fractions.
+    pub fn curve(&self, illuminant: &Illuminant, fractions: &[f64]) -> Result<Vec<Illuminant>, CmtError> {
+        fractions.iter().map(|&f| self.dim(illuminant, f)).collect()
+    }
+}
+
+/// Spectral distribution of a thermal source, at filament temperature `t`, from its distribution
+/// at temperature `t_full`.
+fn thermal_scaled(illuminant: &Illuminant, t_full: f64, t: f64) -> Illuminant {
+    let mut s: Spectrum = **illuminant;
+    for (i, v) in s.0.iter_mut().enumerate() {
+        let l = (i + 380) as f64 * 1E-9;
+        *v *= planck(l, t) / planck(l, t_full);
+    }
+    Illuminant(s)
+}
+
+#[cfg(test)]
+mod dimming_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    #[cfg(feature = "cct")]
+    fn test_dimming() {
+        let led = Illuminant::led(450.0, 20.0);
+        let dimmed = Dimming::ConstantSpectrum.curve(&led, &[1.0, 0.5]).unwrap();
+        assert_abs_diff_eq!(dimmed[1].irradiance(), 0.5 * led.irradiance(), epsilon = 1E-12);
+
+        // dimming a Planckian radiator results in a Planckian radiator at a lower temperature
+        let p = Illuminant::planckian(2856.0);
+        let dimmed = Dimming::Incandescent { temperature: 2856.0 }.dim(&p, 0.5).unwrap();
+        let [x, y] = CIE1931.xyz(&dimmed, None).xy().values();
+        let t = CIE1931.xyz(&dimmed, None).cct().unwrap().t();
+        let [xp, yp] = CIE1931.xyz(&Illuminant::planckian(t), None).xy().values();
+        assert!(t < 2856.0);
+        assert_abs_diff_eq!(x, xp, epsilon = 1E-5);
+        assert_abs_diff_eq!(y, yp, epsilon = 1E-5);
+
+        assert!(Dimming::ConstantSpectrum.dim(&led, 1.5).is_err());
+        assert!(Dimming::Incandescent { temperature: 2856.0 }.dim(&p, 1E-9).is_err());
+    }
+}
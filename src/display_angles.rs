@@ -0,0 +1,175 @@
+/*!
+# Display Viewing Angles
+
+The colors of many displays, in particular liquid crystal displays, change with viewing angle:
+their white point shifts in chromaticity, their luminance drops, and their tone response changes,
+typically washing out dark tones at large angles.
+
+A [`DisplayAngles`] collection holds spectral radiance measurements of a display, at a range of
+viewing angles, and for a range of gray drive levels, and summarizes these as chromaticity shifts,
+as Δu'v' distances to the display's normal, head-on, measurement, as relative luminance values,
+and as shifts of the display's effective gamma.
+See also the [`polarization`](crate::polarization) module, to combine measurements made through a
+polarizer.
+*/
+
+use crate::{error::CmtError, math, observer::Observer, spectrum::Spectrum, xyz::XYZ};
+
+/**
+Spectral radiance measurements of a display, at viewing angles, and gray drive levels.
+```
+use colorimetry::prelude::*;
+
+// a display turning blue, and losing luminance, off-axis
+let white = |angle: f64| *Illuminant::d65() + *Illuminant::led(450.0, 20.0) * (angle / 60.0);
+let mut display = DisplayAngles::new(Observer::Std1931);
+for angle in [0.0, 30.0, 60.0] {
+    for level in [0.25, 0.5, 1.0] {
+        display = display.add(angle, level, &(white(angle) * level.powf(2.2 - angle / 100.0)));
+    }
+}
+let shifts = display.delta_uv_prime(1.0).unwrap();
+assert_eq!(shifts[0], (0.0, 0.0));
+assert!(shifts[2].1 > shifts[1].1);
+
+let gamma = display.gamma_shift().unwrap();
+approx::assert_abs_diff_eq!(gamma[2].1, -0.6, epsilon = 1E-9);
+```
+*/
+#[derive(Clone, Debug, Default)]
+pub struct DisplayAngles {
+    observer: Observer,
+    /// Measurements, as viewing angle in degrees, drive level, and tristimulus values.
+    data: Vec<(f64, f64, XYZ)>,
+}
+
+impl DisplayAngles {
+    pub fn new(observer: Observer) -> Self {
+        Self { observer, data: Vec::new() }
+    }
+
+    /// Adds a spectral radiance, measured at a viewing angle, in degrees, with zero the display
+    /// normal, for a gray drive level, ranging from 0.0 to 1.0, for white.
+    pub fn add(mut self, angle_deg: f64, level: f64, radiance: &Spectrum) -> Self {
+        let xyz = self.observer.data().xyz_from_spectrum(radiance, None);
+        self.data.push((angle_deg, level.clamp(0.0, 1.0), xyz));
+        self
+    }
+
+    /// The viewing angles of the measurements, in increasing order of their absolute values.
+    pub fn angles(&self) -> Vec<f64> {
+        let mut angles: Vec<f64> = self.data.iter().map(|&(a, _, _)| a).collect();
+        angles.sort_by(|a, b| a.abs().total_cmp(&b.abs()).then(a.total_cmp(b)));
+        angles.dedup();
+        angles
+    }
+
+    /// The tristimulus values measured at a viewing angle, and drive level.
+    pub fn xyz(&self, angle_deg: f64, level: f64) -> Option<XYZ> {
+        self.data.iter().find(|&&(a, l, _)| a == angle_deg && l == level).map(|&(_, _, xyz)| xyz)
+    }
+
+    /// Reference angle, closest to the display normal.
+    fn normal(&self) -> Result<f64, CmtError> {
+        self.angles().first().copied().ok_or_else(|| CmtError::ErrorString("DisplayAngles: no measurements".into()))
+    }
+
+    /// Values of a function of the measurement at each angle, and at the normal, for a drive
+    /// level; angles without a measurement for the level are skipped.
+    fn versus_angle(&self, level: f64, f: impl Fn(&XYZ, &XYZ) -> f64) -> Result<Vec<(f64, f64)>, CmtError> {
+        let normal = self.normal()?;
+        let reference = self
+            .xyz(normal, level)
+            .ok_or_else(|| CmtError::ErrorString(format!("DisplayAngles: no measurement at {normal}º for level {level}")))?;
+        Ok(self.angles().into_iter().filter_map(|a| self.xyz(a, level).map(|xyz| (a, f(&xyz, &reference)))).collect())
+    }
+
+    /// Chromaticity shift, as a Δu'v' distance to the measurement at the display normal, as
+    /// function of viewing angle, for a drive level.
+    pub fn delta_uv_prime(&self, level: f64) -> Result<Vec<(f64, f64)>, CmtError> {
+        self.versus_angle(level, |xyz, reference| xyz.uv_prime_distance(reference))
+    }
+
+    /// Largest chromaticity shift, as Δu'v', over all viewing angles, and drive levels.
+    pub fn max_delta_uv_prime(&self) -> Result<f64, CmtError> {
+        let mut levels: Vec<f64> = self.data.iter().map(|&(_, l, _)| l).collect();
+        levels.sort_by(f64::total_cmp);
+        levels.dedup();
+        let mut max = 0.0f64;
+        for level in levels {
+            if let Ok(shifts) = self.delta_uv_prime(level) {
+                max = shifts.iter().fold(max, |m, &(_, d)| m.max(d));
+            }
+        }
+        Ok(max)
+    }
+
+    /// Luminance relative to the luminance at the display normal, as function of viewing angle,
+    /// for a drive level.
+    pub fn relative_luminance(&self, level: f64) -> Result<Vec<(f64, f64)>, CmtError> {
+        self.versus_angle(level, |xyz, reference| xyz.luminous_value() / reference.luminous_value())
+    }
+
+    /**
+    Effective gamma at a viewing angle, as the least-squares slope of the logarithm of the
+    luminance, relative to the luminance of white, versus the logarithm of the drive level.
+    Requires a white measurement, and at least one measurement with a drive level between 0.0
+    and 1.0, at the angle.
+    */
+    pub fn gamma(&self, angle_deg: f64) -> Result<f64, CmtError> {
+        let white = self
+            .xyz(angle_deg, 1.0)
+            .ok_or_else(|| CmtError::ErrorString(format!("DisplayAngles: no white measurement at {angle_deg}º")))?
+            .luminous_value();
+        let (sxy, sxx) = self
+            .data
+            .iter()
+            .filter(|&&(a, l, _)| a == angle_deg && l > 0.0 && l < 1.0)
+            .filter_map(|&(_, l, xyz)| {
+                let y = xyz.luminous_value() / white;
+                (y > 0.0).then(|| (math::ln(l), math::ln(y)))
+            })
+            .fold((0.0, 0.0), |(sxy, sxx), (x, y)| (sxy + x * y, sxx + x * x));
+        if sxx > 0.0 {
+            Ok(sxy / sxx)
+        } else {
+            Err(CmtError::ErrorString(format!("DisplayAngles: no gray levels at {angle_deg}º")))
+        }
+    }
+
+    /// Change of the effective gamma, relative to the gamma at the display normal, as function
+    /// of viewing angle; angles without a gamma value are skipped.
+    pub fn gamma_shift(&self) -> Result<Vec<(f64, f64)>, CmtError> {
+        let normal = self.gamma(self.normal()?)?;
+        Ok(self.angles().into_iter().filter_map(|a| self.gamma(a).ok().map(|g| (a, g - normal))).collect())
+    }
+}
+
+#[cfg(test)]
+mod display_angles_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_display_angles() {
+        let white = *Illuminant::d65();
+        let mut display = DisplayAngles::new(Observer::Std1931);
+        for angle in [-45.0, 0.0, 45.0] {
+            let fade = f64::to_radians(angle).cos();
+            for level in [0.1, 0.5, 1.0] {
+                display = display.add(angle, level, &(white * (fade * level * level)));
+            }
+        }
+        assert_eq!(display.angles(), vec![0.0, -45.0, 45.0]);
+
+        // a neutral luminance drop does not change chromaticity, or gamma
+        assert_abs_diff_eq!(display.max_delta_uv_prime().unwrap(), 0.0, epsilon = 1E-12);
+        let luminance = display.relative_luminance(0.5).unwrap();
+        assert_abs_diff_eq!(luminance[1].1, 0.5f64.sqrt(), epsilon = 1E-12);
+        assert_abs_diff_eq!(display.gamma(45.0).unwrap(), 2.0, epsilon = 1E-12);
+        assert!(display.gamma_shift().unwrap().iter().all(|&(_, g)| g.abs() < 1E-12));
+
+        assert!(display.delta_uv_prime(0.3).is_err());
+        assert!(DisplayAngles::new(Observer::Std1931).gamma_shift().is_err());
+    }
+}
@@ -0,0 +1,545 @@
+/*!
+# Display Calibration Targets
+
+Display calibration, and profiling, software shows a sequence of color patches on a display, and
+measures each of them with a colorimeter, or a spectroradiometer.
+A [`CalibrationTarget`] generates such a sequence, with gray ramps, the primaries and secondaries,
+and saturation sweeps, and calculates the expected tristimulus values of each patch for a
+[`DisplayModel`]: an RGB color space, with its spectral primaries and its tone curve, the
+luminance of the display's white, and of its black level.
+
+The measured tristimulus values are compared with the expected values with
+[`CalibrationTarget::evaluate`], which returns the color differences of the patches as a single row
+[`DeltaEMap`], with its summary statistics.
+Tristimulus values are absolute, with Y as the luminance in cd/m².
+```
+use colorimetry::prelude::*;
+
+let display = DisplayModel::new(RgbSpace::SRGB).set_white_luminance(120.0);
+let target = CalibrationTarget::new(display).add_gray_ramp(5).add_primaries();
+assert_eq!(target.patches().len(), 5 + 8);
+
+// a display with a 10% low red channel
+let measured: Vec<XYZ> = target
+    .patches()
+    .iter()
+    .map(|patch| {
+        let [r, g, b] = patch.rgb;
+        display.xyz([0.9 * r, g, b])
+    })
+    .collect();
+let report = target.evaluate(&measured, DiffMetric::default()).unwrap();
+assert!(report.stats().max > 1.0);
+```
+
+# Tone Response and Gray Balance
+The measured tristimulus values of a neutral ramp give the [`ToneResponse`] of each of the
+channels of a display, as the linear RGB values of the display model at each drive level, from
+which a power law gamma is estimated for each channel, and a gray balance correction is derived:
+a 1D look-up table for each channel, with the drive values which reproduce the grays of the
+display model's tone curve, with the chromaticity of its white.
+```
+use colorimetry::prelude::*;
+
+let display = DisplayModel::new(RgbSpace::SRGB);
+let levels: Vec<f64> = (0..=16).map(|i| i as f64 / 16.0).collect();
+// a display with a weak blue channel
+let measured: Vec<XYZ> = levels.iter().map(|&v| display.xyz([v, v, 0.9 * v])).collect();
+let response = ToneResponse::try_from_ramp(&display, &levels, &measured).unwrap();
+let [lut_r, _, lut_b] = response.gray_balance_lut(256);
+assert!(lut_b[128] > lut_r[128]);
+```
+
+# Flare and Ambient Light
+Measurements of a display in a lit room include flare: ambient light reflected by its screen,
+which raises its black level, and reduces its contrast.
+A [`Flare`], measured with the display switched off, or modelled from the ambient illuminance, and
+the reflectance of the screen, can be removed from, or added to, measured tristimulus values, and
+spectra, and a [`ContrastReport`] checks the contrast ratio, with flare, against a minimum, such
+as required by ISO 12646 for displays used for soft proofing.
+```
+use colorimetry::prelude::*;
+
+let display = DisplayModel::new(RgbSpace::SRGB).set_white_luminance(160.0).set_black_luminance(0.2);
+let flare = Flare::from_ambient(&StdIlluminant::D50, 64.0, 0.04, Observer::Std1931);
+assert!(display.contrast(None, 200.0).pass);
+assert!(!display.contrast(Some(&flare), 200.0).pass);
+
+let measured = flare.add_to(&display.xyz([0.5; 3])).unwrap();
+let corrected = flare.remove_from(&measured).unwrap();
+approx::assert_abs_diff_eq!(corrected, display.xyz([0.5; 3]), epsilon = 1E-9);
+```
+*/
+
+use nalgebra::Vector3;
+
+use crate::{
+    delta_e_map::{DeltaEMap, DiffConditions, DiffMetric},
+    error::CmtError,
+    math,
+    observer::Observer,
+    rgbspace::RgbSpace,
+    stimulus::Stimulus,
+    traits::Light,
+    units::luminance_from_lux_lambertian,
+    xyz::XYZ,
+};
+
+/// Colorimetric model of a display, with the spectral primaries, and the tone curve, of an RGB
+/// color space, and the luminances of its white, and its black level, in cd/m².
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DisplayModel {
+    space: RgbSpace,
+    observer: Observer,
+    white_luminance: f64,
+    black_luminance: f64,
+}
+
+impl DisplayModel {
+    /// A display using a color space, for the CIE 1931 observer, with a white luminance of
+    /// 100 cd/m², and no black level.
+    pub fn new(space: RgbSpace) -> Self {
+        Self { space, observer: Observer::default(), white_luminance: 100.0, black_luminance: 0.0 }
+    }
+
+    pub fn set_observer(mut self, observer: Observer) -> Self {
+        self.observer = observer;
+        self
+    }
+
+    /// Sets the luminance of the white, with all RGB values at their maximum, in cd/m².
+    pub fn set_white_luminance(mut self, luminance: f64) -> Self {
+        self.white_luminance = luminance;
+        self
+    }
+
+    /// Sets the luminance of the black level, with all RGB values zero, in cd/m², with the
+    /// chromaticity of the white.
+    pub fn set_black_luminance(mut self, luminance: f64) -> Self {
+        self.black_luminance = luminance;
+        self
+    }
+
+    pub fn space(&self) -> RgbSpace {
+        self.space
+    }
+
+    pub fn observer(&self) -> Observer {
+        self.observer
+    }
+
+    pub fn white_luminance(&self) -> f64 {
+        self.white_luminance
+    }
+
+    pub fn black_luminance(&self) -> f64 {
+        self.black_luminance
+    }
+
+    /// Absolute tristimulus values of the white of the display.
+    pub fn white(&self) -> XYZ {
+        self.xyz([1.0; 3])
+    }
+
+    /// Expected absolute tristimulus values for a set of encoded RGB values, from 0.0 to 1.0,
+    /// with the display's white as reference white. Values out of this range are clamped.
+    pub fn xyz(&self, rgb: [f64; 3]) -> XYZ {
+        let rgb2xyz = self.observer.data().rgb2xyz(&self.space);
+        let gamma = &self.space.data().0.gamma;
+        let linear = Vector3::from(rgb.map(|v| gamma.decode(v.clamp(0.0, 1.0))));
+        let white = rgb2xyz * Vector3::repeat(1.0);
+        let xyz = rgb2xyz * linear * (self.white_luminance - self.black_luminance) + white * self.black_luminance;
+        XYZ::from_vecs(white * self.white_luminance, Some(xyz), self.observer)
+    }
+
+    /// Contrast of the display, with an optional flare added to its white, and its black level,
+    /// checked against a minimum contrast ratio.
+    pub fn contrast(&self, flare: Option<&Flare>, min_ratio: f64) -> ContrastReport {
+        let (white, black) = (self.white(), self.xyz([0.0; 3]));
+        ContrastReport::try_from_measurements(&white, &black, flare, min_ratio)
+            .expect("display white, black, and flare, use the same observer")
+    }
+}
+
+/// A patch of a [`CalibrationTarget`], with its encoded RGB values, and its expected tristimulus
+/// values.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Patch {
+    pub name: String,
+    pub rgb: [f64; 3],
+    pub xyz: XYZ,
+}
+
+/// A sequence of display patches, with their expected tristimulus values for a [`DisplayModel`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct CalibrationTarget {
+    display: DisplayModel,
+    patches: Vec<Patch>,
+}
+
+impl CalibrationTarget {
+    /// An empty target for a display model.
+    pub fn new(display: DisplayModel) -> Self {
+        Self { display, patches: Vec::new() }
+    }
+
+    /// Adds a patch with encoded RGB values, from 0.0 to 1.0.
+    pub fn add_patch(mut self, name: &str, rgb: [f64; 3]) -> Self {
+        let xyz = self.display.xyz(rgb);
+        self.patches.push(Patch { name: name.to_string(), rgb, xyz });
+        self
+    }
+
+    /// Adds a gray ramp, with `steps` equally spaced levels from black to white; a single step
+    /// adds a white patch only.
+    pub fn add_gray_ramp(self, steps: usize) -> Self {
+        (0..steps).fold(self, |target, i| {
+            let v = if steps > 1 { i as f64 / (steps - 1) as f64 } else { 1.0 };
+            target.add_patch(&format!("Gray {:.1}%", v * 100.0), [v; 3])
+        })
+    }
+
+    /// Adds the primaries, the secondaries, the white, and the black, at full drive levels.
+    pub fn add_primaries(self) -> Self {
+        [
+            ("Red", [1.0, 0.0, 0.0]),
+            ("Green", [0.0, 1.0, 0.0]),
+            ("Blue", [0.0, 0.0, 1.0]),
+            ("Cyan", [0.0, 1.0, 1.0]),
+            ("Magenta", [1.0, 0.0, 1.0]),
+            ("Yellow", [1.0, 1.0, 0.0]),
+            ("White", [1.0; 3]),
+            ("Black", [0.0; 3]),
+        ]
+        .into_iter()
+        .fold(self, |target, (name, rgb)| target.add_patch(name, rgb))
+    }
+
+    /// Adds saturation sweeps, for `hues` equally spaced hue angles of the RGB color cube, starting
+    /// at red, each with `steps` equally spaced saturation levels, from the white, excluded, to
+    /// the full saturated color.
+    pub fn add_saturation_sweep(self, hues: usize, steps: usize) -> Self {
+        (0..hues).flat_map(|i| (1..=steps).map(move |j| (i, j))).fold(self, |target, (i, j)| {
+            let h = 6.0 * i as f64 / hues as f64;
+            let s = j as f64 / steps as f64;
+            // the hexagonal hue model of HSV, with a value of one
+            let rgb = [5.0, 3.0, 1.0].map(|n| {
+                let k = (n + h) % 6.0;
+                1.0 - s * k.min(4.0 - k).clamp(0.0, 1.0)
+            });
+            target.add_patch(&format!("Hue {:.0}° Saturation {:.1}%", h * 60.0, s * 100.0), rgb)
+        })
+    }
+
+    pub fn display(&self) -> &DisplayModel {
+        &self.display
+    }
+
+    pub fn patches(&self) -> &[Patch] {
+        &self.patches
+    }
+
+    /// Compares measured absolute tristimulus values, one for each patch, in the order of the
+    /// patches, with the expected values, using the display's white as reference white.
+    pub fn evaluate(&self, measured: &[XYZ], metric: DiffMetric) -> Result<DeltaEMap, CmtError> {
+        if measured.len() != self.patches.len() {
+            return Err(CmtError::ErrorString(format!(
+                "CalibrationTarget: expected {} measurements, got {}",
+                self.patches.len(),
+                measured.len()
+            )));
+        }
+        let expected: Vec<XYZ> = self.patches.iter().map(|patch| patch.xyz).collect();
+        let conditions = DiffConditions::new(self.display.white())
+            .set_white_luminance(self.display.white_luminance)
+            .set_metric(metric);
+        DeltaEMap::try_from_xyz(expected.len(), 1, &expected, measured, &conditions)
+    }
+}
+
+/// Tone response curves of the channels of a display, from a measured neutral ramp.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ToneResponse {
+    display: DisplayModel,
+    levels: Vec<f64>,
+    rgb: Vec<[f64; 3]>,
+}
+
+impl ToneResponse {
+    /**
+    Tone responses from the absolute tristimulus values measured for a neutral ramp, with equal
+    encoded RGB values, at drive levels in increasing order, from 0.0 to 1.0, both included.
+    The linear RGB values of the measurements are calculated with the primaries of the display
+    model, after subtraction of the measured black level, and are relative to the model's white
+    luminance, less its black level.
+    */
+    pub fn try_from_ramp(display: &DisplayModel, levels: &[f64], measured: &[XYZ]) -> Result<Self, CmtError> {
+        if levels.len() < 2 || levels.len() != measured.len() {
+            return Err(CmtError::ErrorString("ToneResponse: provide a measurement for each of at least two levels".into()));
+        }
+        if levels[0] != 0.0 || levels[levels.len() - 1] != 1.0 || levels.windows(2).any(|w| w[0] >= w[1]) {
+            return Err(CmtError::ErrorString("ToneResponse: levels should increase from 0.0 to 1.0".into()));
+        }
+        if measured.iter().any(|xyz| xyz.observer != display.observer) {
+            return Err(CmtError::RequireSameObserver);
+        }
+        let xyz2rgb = display.observer.data().xyz2rgb(display.space);
+        let black = absolute(&measured[0]);
+        let range = display.white_luminance - display.black_luminance;
+        let rgb = measured.iter().map(|xyz| (xyz2rgb * (absolute(xyz) - black) / range).into()).collect();
+        Ok(Self { display: *display, levels: levels.to_vec(), rgb })
+    }
+
+    pub fn levels(&self) -> &[f64] {
+        &self.levels
+    }
+
+    /// Linear RGB values of the measurements, in the order of the levels.
+    pub fn rgb(&self) -> &[[f64; 3]] {
+        &self.rgb
+    }
+
+    /// Linear output of a channel, 0 for red, 1 for green, and 2 for blue, at a drive level,
+    /// linearly interpolated between the measured levels.
+    pub fn output(&self, channel: usize, level: f64) -> f64 {
+        let level = level.clamp(0.0, 1.0);
+        let i = self.levels.partition_point(|&l| l < level).clamp(1, self.levels.len() - 1);
+        let (l0, l1) = (self.levels[i - 1], self.levels[i]);
+        let (v0, v1) = (self.rgb[i - 1][channel], self.rgb[i][channel]);
+        v0 + (v1 - v0) * (level - l0) / (l1 - l0)
+    }
+
+    /// Power law exponents of the channels, as least squares fits of the logarithms of the
+    /// outputs, relative to the output at full drive, against the logarithms of the levels,
+    /// excluding black.
+    pub fn gamma(&self) -> [f64; 3] {
+        std::array::from_fn(|c| {
+            let full = self.rgb[self.rgb.len() - 1][c];
+            let (num, den) = self
+                .levels
+                .iter()
+                .zip(&self.rgb)
+                .filter(|(&l, rgb)| l > 0.0 && l < 1.0 && rgb[c] > 0.0)
+                .map(|(&l, rgb)| (math::ln(l), math::ln(rgb[c] / full)))
+                .fold((0.0, 0.0), |(num, den), (x, y)| (num + x * y, den + x * x));
+            num / den
+        })
+    }
+
+    /**
+    Gray balance correction, as a 1D look-up table for each channel, with `size` entries for
+    equally spaced input levels from 0.0 to 1.0, with the drive values which give the linear
+    output of the display model's tone curve at each input level, scaled down to the brightest
+    white with the chromaticity of the model's white which the display can produce.
+    The drive values are obtained by inverse linear interpolation of the measured outputs,
+    which should increase with the level.
+    */
+    pub fn gray_balance_lut(&self, size: usize) -> [Vec<f64>; 3] {
+        let gamma = &self.display.space.data().0.gamma;
+        let full = self.rgb[self.rgb.len() - 1];
+        let scale = full.iter().copied().fold(f64::INFINITY, f64::min).min(1.0);
+        std::array::from_fn(|c| {
+            (0..size)
+                .map(|i| {
+                    let input = if size > 1 { i as f64 / (size - 1) as f64 } else { 1.0 };
+                    let target = gamma.decode(input) * scale;
+                    let j = self.rgb.partition_point(|rgb| rgb[c] < target).clamp(1, self.rgb.len() - 1);
+                    let (v0, v1) = (self.rgb[j - 1][c], self.rgb[j][c]);
+                    let (l0, l1) = (self.levels[j - 1], self.levels[j]);
+                    if v1 > v0 { (l0 + (l1 - l0) * (target - v0) / (v1 - v0)).clamp(0.0, 1.0) } else { l1 }
+                })
+                .collect()
+        })
+    }
+}
+
+/// Absolute tristimulus values of a flare, or ambient, contribution, in cd/m², with an optional
+/// spectral distribution, to add to, or to remove from, display measurements.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Flare {
+    observer: Observer,
+    xyz: Vector3<f64>,
+    stimulus: Option<Stimulus>,
+}
+
+impl Flare {
+    /// A measured flare, such as the tristimulus values of a display switched off, or of its black
+    /// level, under the ambient light.
+    pub fn from_xyz(xyz: &XYZ) -> Self {
+        Self { observer: xyz.observer, xyz: absolute(xyz), stimulus: None }
+    }
+
+    /// A flare from ambient light, with a spectral distribution, and an illuminance in lux on the
+    /// screen, reflected by a screen, with a luminous reflectance factor, from 0.0 to 1.0, as a
+    /// Lambertian reflector.
+    pub fn from_ambient(light: &dyn Light, illuminance: f64, reflectance: f64, observer: Observer) -> Self {
+        let obs = observer.data();
+        let luminance = luminance_from_lux_lambertian(illuminance, reflectance);
+        let stimulus = Stimulus(light.spectrum().into_owned()).set_luminance(obs, luminance);
+        let xyz = obs.xyz_from_spectrum(&stimulus, None).xyzn;
+        Self { observer, xyz, stimulus: Some(stimulus) }
+    }
+
+    /// Absolute tristimulus values of the flare.
+    pub fn xyz(&self) -> XYZ {
+        XYZ::from_vecs(self.xyz, None, self.observer)
+    }
+
+    /// Luminance of the flare, in cd/m².
+    pub fn luminance(&self) -> f64 {
+        self.xyz.y
+    }
+
+    /// Spectral radiance of the flare, if modelled from ambient light.
+    pub fn stimulus(&self) -> Option<&Stimulus> {
+        self.stimulus.as_ref()
+    }
+
+    /// Adds the flare to absolute tristimulus values, and to their reference white.
+    pub fn add_to(&self, xyz: &XYZ) -> Result<XYZ, CmtError> {
+        if xyz.observer != self.observer {
+            return Err(CmtError::RequireSameObserver);
+        }
+        Ok(XYZ::from_vecs(xyz.xyzn + self.xyz, xyz.xyz.map(|v| v + self.xyz), self.observer))
+    }
+
+    /// Removes the flare from measured absolute tristimulus values, and from their reference
+    /// white, with negative values clamped to zero.
+    pub fn remove_from(&self, xyz: &XYZ) -> Result<XYZ, CmtError> {
+        if xyz.observer != self.observer {
+            return Err(CmtError::RequireSameObserver);
+        }
+        let sub = |v: Vector3<f64>| (v - self.xyz).map(|c| c.max(0.0));
+        Ok(XYZ::from_vecs(sub(xyz.xyzn), xyz.xyz.map(sub), self.observer))
+    }
+
+    /// Adds the flare to a spectral radiance, which requires a flare modelled from ambient light.
+    pub fn add_to_stimulus(&self, stimulus: Stimulus) -> Result<Stimulus, CmtError> {
+        let flare = self.stimulus.clone().ok_or(CmtError::ErrorString("Flare: no spectral distribution".into()))?;
+        Ok(stimulus + flare)
+    }
+
+    /// Removes the flare from a measured spectral radiance, with negative values clamped to zero,
+    /// which requires a flare modelled from ambient light.
+    pub fn remove_from_stimulus(&self, stimulus: Stimulus) -> Result<Stimulus, CmtError> {
+        let flare = self.stimulus.clone().ok_or(CmtError::ErrorString("Flare: no spectral distribution".into()))?;
+        Ok(stimulus - flare)
+    }
+}
+
+/// Absolute tristimulus values, or the reference white for tristimulus values without a sample.
+fn absolute(xyz: &XYZ) -> Vector3<f64> {
+    xyz.xyz.unwrap_or(xyz.xyzn)
+}
+
+/// The contrast ratio of a display, as the ratio of the luminances of its white, and its black,
+/// with flare, and its check against a minimum ratio.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ContrastReport {
+    pub white_luminance: f64,
+    pub black_luminance: f64,
+    pub ratio: f64,
+    pub min_ratio: f64,
+    pub pass: bool,
+}
+
+impl ContrastReport {
+    /// Contrast from the measured absolute tristimulus values of the white, and the black, of a
+    /// display, with an optional flare, which is added to both when measured in the dark.
+    pub fn try_from_measurements(white: &XYZ, black: &XYZ, flare: Option<&Flare>, min_ratio: f64) -> Result<Self, CmtError> {
+        let (white, black) = match flare {
+            Some(flare) => (flare.add_to(white)?, flare.add_to(black)?),
+            None => (*white, *black),
+        };
+        if white.observer != black.observer {
+            return Err(CmtError::RequireSameObserver);
+        }
+        let (white_luminance, black_luminance) = (absolute(&white).y, absolute(&black).y);
+        let ratio = white_luminance / black_luminance;
+        Ok(Self { white_luminance, black_luminance, ratio, min_ratio, pass: ratio >= min_ratio })
+    }
+}
+
+#[cfg(test)]
+mod display_calibration_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_calibration_target() {
+        let display = DisplayModel::new(RgbSpace::SRGB).set_white_luminance(200.0).set_black_luminance(0.5);
+        assert_abs_diff_eq!(display.white().luminous_value(), 200.0, epsilon = 1E-9);
+        assert_abs_diff_eq!(display.xyz([0.0; 3]).xyz.unwrap().y, 0.5, epsilon = 1E-9);
+        // the black level has the chromaticity of the white
+        let [x, y] = display.xyz([0.0; 3]).xyz.map(|v| [v.x / v.sum(), v.y / v.sum()]).unwrap();
+        assert_abs_diff_eq!([x, y].as_ref(), display.white().xy().values().as_ref(), epsilon = 1E-9);
+
+        let target = CalibrationTarget::new(display).add_gray_ramp(11).add_saturation_sweep(6, 4);
+        assert_eq!(target.patches().len(), 11 + 24);
+        assert_eq!(target.patches()[5].name, "Gray 50.0%");
+        let red = &target.patches()[11 + 3];
+        assert_eq!(red.rgb, [1.0, 0.0, 0.0]);
+        let cyan = &target.patches()[11 + 3 * 4 + 3];
+        assert_abs_diff_eq!(cyan.rgb.as_ref(), [0.0, 1.0, 1.0].as_ref(), epsilon = 1E-12);
+
+        // ideal measurements have no color differences
+        let measured: Vec<XYZ> = target.patches().iter().map(|patch| patch.xyz).collect();
+        for metric in [DiffMetric::default(), DiffMetric::Itp] {
+            let report = target.evaluate(&measured, metric).unwrap();
+            assert_abs_diff_eq!(report.stats().max, 0.0, epsilon = 1E-9);
+        }
+        assert!(target.evaluate(&measured[1..], DiffMetric::default()).is_err());
+    }
+
+    #[test]
+    fn test_tone_response() {
+        let display = DisplayModel::new(RgbSpace::SRGB).set_white_luminance(150.0).set_black_luminance(0.2);
+        let levels: Vec<f64> = (0..=32).map(|i| i as f64 / 32.0).collect();
+        let gamma = &RgbSpace::SRGB.data().0.gamma;
+        // a display with a red channel following a 2.2 power law, and a weak green channel
+        let drive = |[r, g, b]: [f64; 3]| display.xyz([gamma.encode(r.powf(2.2)), 0.95 * g, b]);
+        let measured: Vec<XYZ> = levels.iter().map(|&v| drive([v; 3])).collect();
+        let response = ToneResponse::try_from_ramp(&display, &levels, &measured).unwrap();
+        assert_abs_diff_eq!(response.rgb()[32].as_ref(), [1.0, gamma.decode(0.95), 1.0].as_ref(), epsilon = 1E-6);
+        let [gr, gg, gb] = response.gamma();
+        assert_abs_diff_eq!(gr, 2.2, epsilon = 1E-2);
+        // the sRGB tone curve, with its linear segment near black
+        assert!(gg > 1.9 && gg < 2.2 && gb > 1.9 && gb < 2.2);
+
+        // gray balanced drive values give grays with the chromaticity of the white
+        let lut = response.gray_balance_lut(11);
+        let white = display.white().xy().values();
+        for ((&r, &g), &b) in lut[0][1..11].iter().zip(&lut[1][1..11]).zip(&lut[2][1..11]) {
+            let xyz = drive([r, g, b]);
+            assert_abs_diff_eq!(xyz.xy().values().as_ref(), white.as_ref(), epsilon = 2E-3);
+        }
+        assert!(ToneResponse::try_from_ramp(&display, &levels[1..], &measured[1..]).is_err());
+    }
+
+    #[test]
+    fn test_flare() {
+        let display = DisplayModel::new(RgbSpace::SRGB).set_white_luminance(100.0).set_black_luminance(0.1);
+        assert_abs_diff_eq!(display.contrast(None, 100.0).ratio, 1000.0, epsilon = 1E-9);
+
+        // 1% of 100 lux, reflected by a Lambertian screen
+        let flare = Flare::from_ambient(&StdIlluminant::D65, 100.0, 0.01, Observer::Std1931);
+        assert_abs_diff_eq!(flare.luminance(), 1.0 / std::f64::consts::PI, epsilon = 1E-9);
+        let report = display.contrast(Some(&flare), 500.0);
+        assert_abs_diff_eq!(report.black_luminance, 0.1 + 1.0 / std::f64::consts::PI, epsilon = 1E-9);
+        assert!(!report.pass && report.ratio > 200.0);
+
+        // a flare measured with the display switched off, with the same tristimulus values
+        let measured = Flare::from_xyz(&flare.xyz());
+        let gray = display.xyz([0.5; 3]);
+        assert_abs_diff_eq!(measured.remove_from(&flare.add_to(&gray).unwrap()).unwrap(), gray, epsilon = 1E-9);
+        assert_eq!(measured.remove_from(&XYZ::new(&[0.0; 3], None, Observer::Std1931)).unwrap().xyzn, nalgebra::Vector3::zeros());
+
+        // spectral flare
+        let white = Stimulus::srgb(255, 255, 255).set_luminance(&CIE1931, 100.0);
+        let with_flare = flare.add_to_stimulus(white.clone()).unwrap();
+        let xyz = CIE1931.xyz_from_spectrum(&with_flare, None);
+        assert_abs_diff_eq!(xyz.xyzn.y, 100.0 + flare.luminance(), epsilon = 1E-6);
+        assert_abs_diff_eq!(flare.remove_from_stimulus(with_flare).unwrap().0, white.0, epsilon = 1E-9);
+        assert!(measured.add_to_stimulus(white).is_err());
+    }
+}
@@ -36,6 +36,8 @@ pub enum CmtError {
     MacAdamInterpolateError,
     #[error("RGB Display: Out of gamut")]
     RgbDisplayOutOfGamutError,
+    #[error("CRI: chromaticity difference with the reference illuminant DC={0:.5} exceeds 5.4E-3")]
+    CriChromaticityDifference(f64),
     #[error("CRI: No illuminant, use 'illuminant(light)' first")]
     CriNoSourceError,
     #[error("Colorant: No match found")]
@@ -86,6 +88,10 @@ pub enum CmtError {
     SpectrumNotFound(String),
     #[error("Provide at least {0} values")]
     ProvideAtLeastNValues(usize),
+    #[error("Parse error: {0}")]
+    ParseError(String),
+    #[error("Invariant {check}: deviation {deviation} exceeds tolerance {tolerance}")]
+    InvariantViolation{check: String, deviation: f64, tolerance: f64},
 }
 
 impl From<&str> for CmtError {
@@ -0,0 +1,26 @@
+/*!
+# Experimental API
+
+New, large, metrics, and models, are first published in this module, available with the
+`experimental` feature, so that users can try them, and give feedback, before their interface is
+settled. Items in this module are exempt from the semantic versioning guarantees of the rest of
+the library: they can change, or be removed, in any minor, or patch, release.
+They are not included in the [`prelude`](crate::prelude), so that glob imports of the prelude are
+not affected by these changes, and have to be used by their path, such as
+`colorimetry::experimental::tm30`.
+
+# Promotion Path
+An experimental item is promoted to the stable API when its interface has been unchanged for at
+least one minor release, it is fully documented, and tested against reference data. It then moves
+to a regular module, is included in the prelude, and remains available in this module, as a
+deprecated re-export, for one more minor release.
+
+# Modules
+- [`tm30`]: ANSI/IES TM-30-20 Annex E design intents, and priority levels, with the `cri`, and
+  `supplemental-observers`, features;
+- [`cam16`]: CAM16-UCS color differences of tristimulus values.
+*/
+
+pub mod cam16;
+#[cfg(all(feature = "cri", feature = "supplemental-observers"))]
+pub mod tm30;
@@ -0,0 +1,89 @@
+/*!
+# CAM16-UCS Color Difference Components
+
+The CAM16-UCS color difference ΔE', as calculated by
+[`CieCam16::delta_e`](crate::cam::CieCam16::delta_e), split into its lightness, colorfulness,
+and hue components, ΔJ', ΔM', and ΔH', similar to the components of the CIEDE2000 color
+difference, to show which attribute of a color changes most, for example under a different light
+source. The components add up to the color difference, in quadrature.
+```
+use colorimetry::prelude::*;
+use colorimetry::experimental::cam16::Cam16Difference;
+
+let white = [95.047, 100.0, 108.883];
+let a = XYZ::new(&white, Some(&[20.0, 20.0, 20.0]), Observer::Std1931);
+let b = XYZ::new(&white, Some(&[25.0, 20.0, 20.0]), Observer::Std1931);
+let d = Cam16Difference::try_new(a, b, ViewConditions::default()).unwrap();
+approx::assert_abs_diff_eq!(d.de.powi(2), d.dj.powi(2) + d.dm.powi(2) + d.dh.powi(2), epsilon = 1E-9);
+```
+*/
+
+use crate::{cam::CieCam16, error::CmtError, math, viewconditions::ViewConditions, xyz::XYZ};
+
+/// CAM16-UCS color difference, and its components, of a sample relative to a reference.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Cam16Difference {
+    /// Lightness difference ΔJ'.
+    pub dj: f64,
+    /// Colorfulness difference ΔM'.
+    pub dm: f64,
+    /// Hue difference ΔH', positive for a counter-clockwise hue shift.
+    pub dh: f64,
+    /// Color difference ΔE'.
+    pub de: f64,
+}
+
+impl Cam16Difference {
+    /// Color difference of a sample, relative to a reference, both given as tristimulus values
+    /// with their reference white, in a set of viewing conditions.
+    pub fn try_new(reference: XYZ, sample: XYZ, vc: ViewConditions) -> Result<Self, CmtError> {
+        let r = CieCam16::new(reference, vc)?;
+        let s = CieCam16::new(sample, vc)?;
+        Ok(Self::from_ucs(r.ucs(), s.ucs()))
+    }
+
+    /// Color difference from CAM16-UCS J', a', and b' coordinates.
+    pub fn from_ucs(reference: [f64; 3], sample: [f64; 3]) -> Self {
+        let [jr, ar, br] = reference;
+        let [js, as_, bs] = sample;
+        let dj = js - jr;
+        let dm = math::hypot(as_, bs) - math::hypot(ar, br);
+        let (da, db) = (as_ - ar, bs - br);
+        let de = (dj * dj + da * da + db * db).sqrt();
+        // the hue difference is the remainder of the chromatic difference, signed by the direction
+        // of the hue change
+        let dh = (da * da + db * db - dm * dm).max(0.0).sqrt().copysign(ar * bs - br * as_);
+        Self { dj, dm, dh, de }
+    }
+}
+
+#[cfg(test)]
+mod cam16_test {
+    use super::*;
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_cam16_difference() {
+        let white = [95.047, 100.0, 108.883];
+        let xyz = |v: [f64; 3]| XYZ::new(&white, Some(&v), Observer::Std1931);
+        let vc = ViewConditions::default();
+
+        // matches the CAM16-UCS color difference
+        let (a, b) = (xyz([30.0, 25.0, 10.0]), xyz([28.0, 26.0, 14.0]));
+        let d = Cam16Difference::try_new(a, b, vc).unwrap();
+        let de = CieCam16::new(a, vc).unwrap().delta_e(&CieCam16::new(b, vc).unwrap());
+        assert_abs_diff_eq!(d.de, de, epsilon = 1E-9);
+        assert_abs_diff_eq!(d.de.powi(2), d.dj.powi(2) + d.dm.powi(2) + d.dh.powi(2), epsilon = 1E-9);
+
+        // a pure lightness change, and a pure hue rotation
+        let d = Cam16Difference::from_ucs([50.0, 10.0, 0.0], [55.0, 10.0, 0.0]);
+        assert_abs_diff_eq!([d.dj, d.dm, d.dh, d.de].as_slice(), [5.0, 0.0, 0.0, 5.0].as_slice(), epsilon = 1E-12);
+        let d = Cam16Difference::from_ucs([50.0, 10.0, 0.0], [50.0, 0.0, 10.0]);
+        assert_abs_diff_eq!(d.dm, 0.0, epsilon = 1E-12);
+        assert_abs_diff_eq!(d.dh, 2f64.sqrt() * 10.0, epsilon = 1E-12);
+        assert!(Cam16Difference::from_ucs([50.0, 0.0, 10.0], [50.0, 10.0, 0.0]).dh < 0.0);
+
+        assert!(Cam16Difference::try_new(XYZ::new(&white, None, Observer::Std1931), b, vc).is_err());
+    }
+}
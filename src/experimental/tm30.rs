@@ -0,0 +1,117 @@
+/*!
+# TM-30 Design Intents
+
+ANSI/IES TM-30-20 Annex E specifies recommended values of the fidelity index Rf, the gamut index
+Rg, and the local chroma shift in the first hue angle bin Rcs,h1, for three design intents:
+color preference, color fidelity, and vividness, each at three priority levels, with Level 1 the
+most restrictive. [`design_level`] returns the highest priority level met by a
+[`TM30`](crate::tm30::TM30) evaluation, for a design intent.
+```
+use colorimetry::prelude::*;
+use colorimetry::experimental::tm30::{design_level, DesignIntent, PriorityLevel};
+
+let samples = synthetic_hue_bin_samples();
+let tm30 = TM30::try_new_with_samples(&Illuminant::planckian(3000.0), &samples).unwrap();
+assert_eq!(design_level(&tm30, DesignIntent::Fidelity), Some(PriorityLevel::P1));
+```
+
+# References
+- ANSI/IES TM-30-20, IES Method for Evaluating Light Source Color Rendition, Annex E
+*/
+
+use crate::tm30::TM30;
+
+/// Design intents of TM-30-20 Annex E.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, strum_macros::Display, strum_macros::EnumIter)]
+pub enum DesignIntent {
+    /// Color preference, with increased saturation of red colors.
+    Preference,
+    /// Color fidelity, with colors appearing as under the reference illuminant.
+    Fidelity,
+    /// Vividness, with increased saturation of all colors.
+    Vividness,
+}
+
+/// Priority levels of TM-30-20 Annex E, with `P1` the most restrictive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, strum_macros::Display, strum_macros::EnumIter)]
+pub enum PriorityLevel {
+    P1,
+    P2,
+    P3,
+}
+
+/// Recommended values for a design intent, and priority level.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DesignCriteria {
+    /// Minimum fidelity index Rf.
+    pub rf_min: f64,
+    /// Minimum gamut index Rg.
+    pub rg_min: f64,
+    /// Range of the local chroma shift Rcs,h1, as fractions.
+    pub rcs_h1: [f64; 2],
+}
+
+impl DesignIntent {
+    /// Recommended values of the intent for a priority level.
+    pub fn criteria(&self, level: PriorityLevel) -> DesignCriteria {
+        let (rf_min, rg_min, rcs_h1) = match (self, level) {
+            (DesignIntent::Preference, PriorityLevel::P1) => (78.0, 95.0, [-0.01, 0.15]),
+            (DesignIntent::Preference, PriorityLevel::P2) => (74.0, 92.0, [-0.07, 0.19]),
+            (DesignIntent::Preference, PriorityLevel::P3) => (70.0, 89.0, [-0.12, 0.23]),
+            (DesignIntent::Fidelity, PriorityLevel::P1) => (90.0, 0.0, [f64::NEG_INFINITY, f64::INFINITY]),
+            (DesignIntent::Fidelity, PriorityLevel::P2) => (85.0, 0.0, [f64::NEG_INFINITY, f64::INFINITY]),
+            (DesignIntent::Fidelity, PriorityLevel::P3) => (80.0, 0.0, [f64::NEG_INFINITY, f64::INFINITY]),
+            (DesignIntent::Vividness, PriorityLevel::P1) => (0.0, 117.0, [0.05, 0.19]),
+            (DesignIntent::Vividness, PriorityLevel::P2) => (0.0, 110.0, [0.02, 0.19]),
+            (DesignIntent::Vividness, PriorityLevel::P3) => (0.0, 100.0, [0.0, 0.19]),
+        };
+        DesignCriteria { rf_min, rg_min, rcs_h1 }
+    }
+}
+
+impl DesignCriteria {
+    /// Checks if a TM-30 evaluation meets these values.
+    pub fn is_met(&self, tm30: &TM30) -> bool {
+        let rcs_h1 = tm30.chroma_shift()[0];
+        tm30.rf() >= self.rf_min && tm30.rg() >= self.rg_min && rcs_h1 >= self.rcs_h1[0] && rcs_h1 <= self.rcs_h1[1]
+    }
+}
+
+/// The highest priority level of a design intent met by a TM-30 evaluation, or `None` if it does
+/// not meet any.
+pub fn design_level(tm30: &TM30, intent: DesignIntent) -> Option<PriorityLevel> {
+    [PriorityLevel::P1, PriorityLevel::P2, PriorityLevel::P3].into_iter().find(|&level| intent.criteria(level).is_met(tm30))
+}
+
+#[cfg(test)]
+mod tm30_test {
+    use super::*;
+    use crate::prelude::*;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn test_design_level() {
+        // levels are nested: a lower priority level is always less restrictive
+        for intent in DesignIntent::iter() {
+            for (a, b) in [(PriorityLevel::P1, PriorityLevel::P2), (PriorityLevel::P2, PriorityLevel::P3)] {
+                let (a, b) = (intent.criteria(a), intent.criteria(b));
+                assert!(a.rf_min >= b.rf_min && a.rg_min >= b.rg_min);
+                assert!(a.rcs_h1[0] >= b.rcs_h1[0] && a.rcs_h1[1] <= b.rcs_h1[1]);
+            }
+        }
+
+        let samples = synthetic_hue_bin_samples();
+
+        // the reference illuminant has perfect fidelity, but no increased saturation
+        let tm30 = TM30::try_new_with_samples(&Illuminant::planckian(3000.0), &samples).unwrap();
+        assert_eq!(design_level(&tm30, DesignIntent::Fidelity), Some(PriorityLevel::P1));
+        assert_eq!(design_level(&tm30, DesignIntent::Preference), Some(PriorityLevel::P1));
+        assert_eq!(design_level(&tm30, DesignIntent::Vividness), Some(PriorityLevel::P3));
+
+        // a narrow band LED mixture does not meet the fidelity intent
+        let rgb = Illuminant(*Illuminant::led(450.0, 20.0) + *Illuminant::led(530.0, 30.0) + *Illuminant::led(620.0, 20.0));
+        let tm30 = TM30::try_new_with_samples(&rgb, &samples).unwrap();
+        assert!(tm30.rf() < 80.0);
+        assert_eq!(design_level(&tm30, DesignIntent::Fidelity), None);
+    }
+}
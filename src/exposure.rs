@@ -0,0 +1,204 @@
+/*!
+# Exposure Bookkeeping
+
+Conservators limit the light exposure of sensitive objects by an annual budget, in lux-hours, such
+as 50,000 lx·h for highly sensitive materials, and by the damage weighted dose, as calculated with
+the [`damage`](crate::damage) module. Both follow from the illuminance, and the spectrum, of the
+lighting, and its operating hours, which differ over the year, for example for temporary
+exhibitions, or periods with daylight.
+
+An [`ExposureLog`] collects these periods, and its [`ExposureReport`] sums their lux-hours, and
+damage weighted doses, with a breakdown of the radiant exposure into the ultraviolet, blue, and
+remaining, parts of the spectrum.
+
+The report assumes the reciprocity law: the damage depends on the product of irradiance and time
+only, so that 50 lux for 1000 hours does as much damage as 100 lux for 500 hours.
+[`ExposureReport::equivalent_hours`] and [`ExposureReport::remaining_hours`] use this to convert
+exposures to operating hours, at another illuminance. Reciprocity holds approximately for most
+museum materials, at museum light levels.
+```
+use colorimetry::prelude::*;
+
+let schedule = OperatingSchedule::new(8.0, 6.0, 50.0);
+let report = ExposureLog::new(DamageFunction::new(DamageMaterial::Watercolor))
+    .add_period("gallery", &Illuminant::planckian(3000.0), 50.0, schedule.hours())
+    .report();
+assert_eq!(report.lux_hours, 120_000.0);
+
+// with a budget of 150,000 lx·h, 600 more hours at 50 lux remain
+approx::assert_abs_diff_eq!(report.remaining_hours(150_000.0, 50.0), 600.0, epsilon = 1E-9);
+```
+*/
+
+use crate::{action_spectrum::ActionSpectrum, damage::DamageFunction, illuminant::Illuminant};
+
+/// Wavelength bands for the breakdown of the radiant exposure, as half-open ranges, in
+/// nanometer.
+pub const EXPOSURE_BANDS: [(&str, usize, usize); 3] = [("UV", 380, 400), ("Blue", 400, 500), ("Other", 500, 781)];
+
+/// Operating hours of a light source, as hours per day, days per week, and weeks.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OperatingSchedule {
+    pub hours_per_day: f64,
+    pub days_per_week: f64,
+    pub weeks: f64,
+}
+
+impl OperatingSchedule {
+    pub fn new(hours_per_day: f64, days_per_week: f64, weeks: f64) -> Self {
+        Self { hours_per_day, days_per_week, weeks }
+    }
+
+    /// Total operating hours.
+    pub fn hours(&self) -> f64 {
+        self.hours_per_day * self.days_per_week * self.weeks
+    }
+}
+
+/// A period of exposure to a light source, at an illuminance, in lux, for a number of hours.
+#[derive(Clone, Debug)]
+struct ExposurePeriod {
+    label: String,
+    light: Illuminant,
+    illuminance: f64,
+    hours: f64,
+}
+
+/// Radiant exposure in a wavelength band.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BandExposure {
+    pub name: String,
+    /// Radiant exposure, in Wh/m².
+    pub radiant_exposure: f64,
+    /// Damage weighted radiant exposure, in Wh/m².
+    pub dose: f64,
+    /// Fraction of the total damage weighted dose.
+    pub dose_fraction: f64,
+}
+
+/// Exposure of a single period, in an [`ExposureReport`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct PeriodExposure {
+    pub label: String,
+    pub lux_hours: f64,
+    /// Damage weighted radiant exposure, in Wh/m².
+    pub dose: f64,
+}
+
+/// Accumulated exposure, as calculated by [`ExposureLog::report`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExposureReport {
+    /// Total illuminance times exposure time, in lx·h.
+    pub lux_hours: f64,
+    /// Total damage weighted radiant exposure, in Wh/m².
+    pub dose: f64,
+    /// Breakdown by wavelength band, for the bands in [`EXPOSURE_BANDS`].
+    pub bands: Vec<BandExposure>,
+    /// Exposures of the periods, in the order they were added.
+    pub periods: Vec<PeriodExposure>,
+}
+
+impl ExposureReport {
+    /// Number of hours at an illuminance, in lux, which gives the same lux-hours, by reciprocity.
+    pub fn equivalent_hours(&self, illuminance: f64) -> f64 {
+        self.lux_hours / illuminance
+    }
+
+    /// Number of hours at an illuminance, in lux, which remain before an exposure limit, in lx·h,
+    /// is reached, or zero if it has been exceeded.
+    pub fn remaining_hours(&self, limit: f64, illuminance: f64) -> f64 {
+        ((limit - self.lux_hours) / illuminance).max(0.0)
+    }
+}
+
+/// A log of exposure periods, for a material with a damage function.
+#[derive(Clone, Debug)]
+pub struct ExposureLog {
+    damage: DamageFunction,
+    periods: Vec<ExposurePeriod>,
+}
+
+impl ExposureLog {
+    pub fn new(damage: DamageFunction) -> Self {
+        Self { damage, periods: Vec::new() }
+    }
+
+    /// Adds a period of exposure to a light source, with its spectral distribution, its
+    /// illuminance, in lux, and its duration, in hours.
+    pub fn add_period(mut self, label: &str, light: &Illuminant, illuminance: f64, hours: f64) -> Self {
+        self.periods.push(ExposurePeriod { label: label.to_string(), light: light.clone(), illuminance, hours });
+        self
+    }
+
+    pub fn damage_function(&self) -> DamageFunction {
+        self.damage
+    }
+
+    /// Sums the exposures of all periods.
+    pub fn report(&self) -> ExposureReport {
+        let damage = self.damage.action_spectrum();
+        let photopic = ActionSpectrum::photopic();
+        let mut bands: Vec<BandExposure> = EXPOSURE_BANDS
+            .iter()
+            .map(|&(name, _, _)| BandExposure { name: name.to_string(), radiant_exposure: 0.0, dose: 0.0, dose_fraction: 0.0 })
+            .collect();
+        let mut periods = Vec::with_capacity(self.periods.len());
+        for period in &self.periods {
+            // spectral irradiance, in W/m²/nm, at the illuminance of the period, times its hours
+            let scale = period.illuminance / photopic.weight(&period.light) * period.hours;
+            for (band, &(_, low, high)) in bands.iter_mut().zip(EXPOSURE_BANDS.iter()) {
+                for l in low..high {
+                    let e = period.light[l] * scale;
+                    band.radiant_exposure += e;
+                    band.dose += e * damage.weights()[l];
+                }
+            }
+            periods.push(PeriodExposure {
+                label: period.label.clone(),
+                lux_hours: period.illuminance * period.hours,
+                dose: damage.weight(&period.light) * scale,
+            });
+        }
+        let dose: f64 = periods.iter().map(|p| p.dose).sum();
+        for band in &mut bands {
+            band.dose_fraction = if dose > 0.0 { band.dose / dose } else { 0.0 };
+        }
+        ExposureReport { lux_hours: periods.iter().map(|p| p.lux_hours).sum(), dose, bands, periods }
+    }
+}
+
+#[cfg(test)]
+mod exposure_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_exposure() {
+        let damage = DamageFunction::new(DamageMaterial::General);
+        let led = Illuminant::planckian(3000.0);
+        let report = ExposureLog::new(damage)
+            .add_period("permanent", &led, 50.0, 2000.0)
+            .add_period("exhibition", &Illuminant::d65(), 150.0, 400.0)
+            .report();
+        assert_abs_diff_eq!(report.lux_hours, 160_000.0);
+        assert_eq!(report.periods.len(), 2);
+
+        // the periods, and bands, add up to the total dose, which matches the damage module
+        let expected = damage.annual_exposure(&led, 50.0, 2000.0).dose + damage.annual_exposure(&Illuminant::d65(), 150.0, 400.0).dose;
+        assert_abs_diff_eq!(report.dose, expected, epsilon = 1E-9);
+        assert_abs_diff_eq!(report.bands.iter().map(|b| b.dose).sum::<f64>(), report.dose, epsilon = 1E-9);
+        assert_abs_diff_eq!(report.bands.iter().map(|b| b.dose_fraction).sum::<f64>(), 1.0, epsilon = 1E-12);
+
+        // daylight has more of its dose in the ultraviolet, and blue, than warm white light
+        let uv_blue = |light: &Illuminant| {
+            let report = ExposureLog::new(damage).add_period("", light, 100.0, 1.0).report();
+            report.bands[0].dose_fraction + report.bands[1].dose_fraction
+        };
+        assert!(uv_blue(&Illuminant::d65()) > uv_blue(&led));
+
+        // reciprocity
+        assert_abs_diff_eq!(report.equivalent_hours(100.0), 1600.0);
+        assert_eq!(report.remaining_hours(150_000.0, 50.0), 0.0);
+        assert_abs_diff_eq!(OperatingSchedule::new(10.0, 7.0, 52.0).hours(), 3640.0);
+    }
+}
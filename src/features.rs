@@ -0,0 +1,201 @@
+/*!
+# Spectral Features
+
+Machine learning pipelines, such as models exported to ONNX, or embedding stores, consume
+fixed-length numeric vectors. A [`FeatureExtractor`] computes such a vector of descriptors of a
+spectral power distribution, combining the spectral data with the colorimetric knowledge in this
+library:
+
+- relative band powers, as the fractions of the power in bands of equal width;
+- peak statistics: the wavelength, and full width at half maximum, of the highest peak, the number
+  of peaks, and the centroid wavelength;
+- the CIE 1931 chromaticity coordinates, and the luminous efficacy of radiation;
+- the correlated color temperature, and the distance to the Planckian locus Duv, with the `cct`
+  feature;
+- the general color rendering index Ra, and the special index R9, with the `cri` feature.
+
+All values are independent of the level of the spectrum. Values which can not be calculated for a
+spectrum, such as the correlated color temperature of a saturated green LED, are `NaN`.
+The [`schema`](FeatureExtractor::schema) lists the name, and unit, of each value, in the order of
+the vector, and depends on the extractor's settings, and the enabled crate features.
+```
+use colorimetry::prelude::*;
+
+let extractor = FeatureExtractor::new().set_band_width(50.0);
+let schema = extractor.schema();
+let features = extractor.extract(&Illuminant::led(450.0, 20.0));
+assert_eq!(features.len(), schema.len());
+
+let peak = schema.iter().position(|f| f.name == "peak_wavelength").unwrap();
+approx::assert_abs_diff_eq!(features[peak], 450.0, epsilon = 1.0);
+```
+*/
+
+use crate::{
+    data::observers::CIE1931,
+    illuminant::Illuminant,
+    spectrum::Spectrum,
+    traits::Light,
+};
+
+/// Name, and unit, of a feature.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FeatureInfo {
+    pub name: String,
+    /// Unit of the value, or an empty string for dimensionless values.
+    pub unit: &'static str,
+}
+
+impl FeatureInfo {
+    fn new(name: impl Into<String>, unit: &'static str) -> Self {
+        Self { name: name.into(), unit }
+    }
+}
+
+/// Minimum height of a local maximum, relative to the highest peak, to count as a peak.
+const PEAK_THRESHOLD: f64 = 0.1;
+
+/// Extracts fixed-length feature vectors from spectral power distributions.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FeatureExtractor {
+    band_width: f64,
+}
+
+impl Default for FeatureExtractor {
+    fn default() -> Self {
+        Self { band_width: 20.0 }
+    }
+}
+
+impl FeatureExtractor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the width of the bands, in nanometer, from 1 to 400 nm; the default is 20 nm.
+    /// The last band is narrower if the width does not divide the 380 to 780 nm domain.
+    pub fn set_band_width(mut self, band_width: f64) -> Self {
+        self.band_width = band_width.clamp(1.0, 400.0);
+        self
+    }
+
+    /// Bands, as half-open wavelength ranges, in nanometer.
+    fn bands(&self) -> Vec<(usize, usize)> {
+        let width = self.band_width.round() as usize;
+        (380..=780).step_by(width).map(|low| (low, (low + width).min(781))).collect()
+    }
+
+    /// Names, and units, of the features, in the order of the feature vectors.
+    pub fn schema(&self) -> Vec<FeatureInfo> {
+        let mut schema: Vec<FeatureInfo> =
+            self.bands().into_iter().map(|(low, high)| FeatureInfo::new(format!("band_{low}_{}", high - 1), "")).collect();
+        schema.push(FeatureInfo::new("peak_wavelength", "nm"));
+        schema.push(FeatureInfo::new("peak_fwhm", "nm"));
+        schema.push(FeatureInfo::new("peak_count", ""));
+        schema.push(FeatureInfo::new("centroid_wavelength", "nm"));
+        schema.push(FeatureInfo::new("x", ""));
+        schema.push(FeatureInfo::new("y", ""));
+        schema.push(FeatureInfo::new("luminous_efficacy", "lm/W"));
+        #[cfg(feature = "cct")]
+        {
+            schema.push(FeatureInfo::new("cct", "K"));
+            schema.push(FeatureInfo::new("duv", ""));
+        }
+        #[cfg(feature = "cri")]
+        {
+            schema.push(FeatureInfo::new("ra", ""));
+            schema.push(FeatureInfo::new("r9", ""));
+        }
+        schema
+    }
+
+    /// Feature vector of a light.
+    pub fn extract(&self, light: &dyn Light) -> Vec<f64> {
+        let spectrum = light.spectrum();
+        let s = spectrum.as_ref();
+        let total: f64 = s.0.sum();
+        let mut features: Vec<f64> = self.bands().into_iter().map(|(low, high)| (low..high).map(|l| s[l]).sum::<f64>() / total).collect();
+
+        let (peak, fwhm, count) = peak_statistics(s);
+        let centroid = (380..=780).map(|l| l as f64 * s[l]).sum::<f64>() / total;
+        features.extend([peak, fwhm, count, centroid]);
+
+        let illuminant = Illuminant(*s);
+        let xyz = CIE1931.xyz_from_spectrum(s, None);
+        let [x, y] = xyz.xy().values();
+        features.extend([x, y, illuminant.luminous_efficacy(&CIE1931)]);
+
+        #[cfg(feature = "cct")]
+        match xyz.cct() {
+            Ok(cct) => features.extend([cct.t(), cct.d()]),
+            Err(_) => features.extend([f64::NAN; 2]),
+        }
+        #[cfg(feature = "cri")]
+        match crate::cri::CRI::try_new(&illuminant) {
+            Ok(cri) => features.extend([cri.ra(), cri[8]]),
+            Err(_) => features.extend([f64::NAN; 2]),
+        }
+        features
+    }
+
+    /// Feature vectors of a set of illuminants, as a flat row-major matrix, with a row for each
+    /// illuminant, and a column for each feature in the schema.
+    pub fn extract_all(&self, illuminants: &[Illuminant]) -> Vec<f64> {
+        illuminants.iter().flat_map(|illuminant| self.extract(illuminant)).collect()
+    }
+}
+
+/// Wavelength, and full width at half maximum, of the highest peak, and the number of peaks.
+fn peak_statistics(s: &Spectrum) -> (f64, f64, f64) {
+    let v = s.0.as_slice();
+    let (imax, &max) = v.iter().enumerate().max_by(|a, b| a.1.total_cmp(b.1)).unwrap();
+    if max <= 0.0 {
+        return (f64::NAN, f64::NAN, 0.0);
+    }
+    let half = max / 2.0;
+    // interpolated half maximum crossings, or the domain limits
+    let low = (0..imax).rev().find(|&i| v[i] < half).map_or(0.0, |i| i as f64 + (half - v[i]) / (v[i + 1] - v[i]));
+    let high = (imax + 1..v.len()).find(|&i| v[i] < half).map_or((v.len() - 1) as f64, |i| i as f64 - (half - v[i]) / (v[i - 1] - v[i]));
+    let count = (1..v.len() - 1).filter(|&i| v[i] > v[i - 1] && v[i] >= v[i + 1] && v[i] > PEAK_THRESHOLD * max).count();
+    ((imax + 380) as f64, high - low, count as f64)
+}
+
+#[cfg(test)]
+mod features_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_features() {
+        let extractor = FeatureExtractor::new();
+        let schema = extractor.schema();
+        assert_eq!(schema[0].name, "band_380_399");
+        assert_eq!(schema[20].name, "band_780_780");
+        let index = |name: &str| schema.iter().position(|f| f.name == name).unwrap();
+
+        // band powers add up to one, and the features are independent of the level
+        let d65 = Illuminant::d65();
+        let features = extractor.extract(&d65);
+        assert_eq!(features.len(), schema.len());
+        assert_abs_diff_eq!(features[..21].iter().sum::<f64>(), 1.0, epsilon = 1E-12);
+        assert_abs_diff_eq!(features.as_slice(), extractor.extract(&(d65.clone() * 5.0)).as_slice(), epsilon = 1E-9);
+        assert_abs_diff_eq!(features[index("x")], 0.3127, epsilon = 1E-4);
+        assert_abs_diff_eq!(features[index("luminous_efficacy")], d65.luminous_efficacy(&CIE1931), epsilon = 1E-9);
+
+        // a two peak LED
+        let led = Illuminant(*Illuminant::led(450.0, 20.0) + *Illuminant::led(600.0, 30.0) * 2.0);
+        let features = extractor.extract(&led);
+        assert_abs_diff_eq!(features[index("peak_wavelength")], 600.0, epsilon = 1.0);
+        assert_abs_diff_eq!(features[index("peak_fwhm")], 30.0, epsilon = 1.0);
+        assert_eq!(features[index("peak_count")], 2.0);
+
+        #[cfg(feature = "cct")]
+        {
+            assert_abs_diff_eq!(extractor.extract(&Illuminant::planckian(3000.0))[index("cct")], 3000.0, epsilon = 1.0);
+            assert!(extractor.extract(&Illuminant::led(520.0, 20.0))[index("cct")].is_nan());
+        }
+
+        let all = extractor.extract_all(&[d65, led]);
+        assert_eq!(all.len(), 2 * schema.len());
+    }
+}
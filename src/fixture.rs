@@ -0,0 +1,227 @@
+/*!
+# Stage and Architectural Lighting Fixtures
+
+Multi-channel LED fixtures, as used in entertainment and architectural lighting, mix the light
+of their channels, such as red, green, blue, and white LEDs, driven by pulse width modulation,
+with their duty cycles usually set by DMX512 control values.
+
+A [`Fixture`] holds the calibrated spectral distributions of its channels, each measured at full
+output, and calculates the duty cycles for a target chromaticity, or correlated color temperature,
+at the maximum output of the fixture: the channel with the highest duty cycle is fully on.
+The light output of the channels is taken to be proportional to their duty cycles.
+
+Fixtures with a white channel can produce most target colors with different combinations of
+white and colored light; a [`WhiteSubstitution`] strategy sets how much of the mix of colored
+channels is replaced by white light, which typically improves the color rendition for unsaturated
+colors, and increases the light output for fixtures with a bright white channel.
+```
+use colorimetry::prelude::*;
+
+let rgbw = Fixture::new(&[
+    Illuminant::led(630.0, 20.0).set_irradiance(1.0),
+    Illuminant::led(530.0, 30.0).set_irradiance(1.0),
+    Illuminant::led(460.0, 20.0).set_irradiance(1.0),
+    Illuminant::planckian(5000.0).set_irradiance(3.0),
+])
+.set_white_channel(3);
+let duty = rgbw.duty_cycles_cct(4000.0).unwrap();
+// DMX values for the red, green, blue, and white channels
+let dmx = dmx8(&duty);
+assert!(dmx.contains(&255));
+
+let xy = CIE1931.xyz(&rgbw.output(&duty), None).xy().values();
+let target = CIE1931.xyz_planckian_locus(4000.0).xy().values();
+approx::assert_abs_diff_eq!(xy.as_ref(), target.as_ref(), epsilon = 1E-9);
+```
+*/
+
+use nalgebra::{DMatrix, DVector, SVector};
+
+use crate::{
+    error::CmtError,
+    illuminant::Illuminant,
+    observer::Observer,
+    spectrum::{Spectrum, NS},
+    tuning::nnls,
+};
+
+/// Strategy for replacing the mix of the colored channels of a fixture by its white channel.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum WhiteSubstitution {
+    /// Uses the colored channels only.
+    None,
+    /// Replaces as much of the colored light as possible by white light.
+    #[default]
+    Maximum,
+    /// Replaces a fraction, from 0.0 to 1.0, of the maximum possible substitution.
+    Fraction(f64),
+}
+
+/// A multi-channel LED fixture, with the spectral distributions of its channels at full output.
+#[derive(Clone)]
+pub struct Fixture {
+    channels: Vec<Illuminant>,
+    white: Option<usize>,
+    substitution: WhiteSubstitution,
+    observer: Observer,
+}
+
+impl Fixture {
+    /// A fixture with the spectral distributions of its channels, at full output, in control
+    /// channel order, without a white channel, for the CIE 1931 observer.
+    pub fn new(channels: &[Illuminant]) -> Self {
+        Self { channels: channels.to_vec(), white: None, substitution: WhiteSubstitution::default(), observer: Observer::Std1931 }
+    }
+
+    /// Sets the index of the white channel.
+    pub fn set_white_channel(mut self, index: usize) -> Self {
+        self.white = Some(index);
+        self
+    }
+
+    pub fn set_white_substitution(mut self, substitution: WhiteSubstitution) -> Self {
+        self.substitution = substitution;
+        self
+    }
+
+    pub fn set_observer(mut self, observer: Observer) -> Self {
+        self.observer = observer;
+        self
+    }
+
+    pub fn channels(&self) -> &[Illuminant] {
+        &self.channels
+    }
+
+    /// Spectral distribution of the fixture's output, for a set of duty cycles.
+    pub fn output(&self, duty_cycles: &[f64]) -> Illuminant {
+        let s = self.channels.iter().zip(duty_cycles).fold(SVector::<f64, NS>::zeros(), |s, (c, &d)| s + c.0 .0 * d);
+        Illuminant(Spectrum(s))
+    }
+
+    /**
+    Duty cycles of the channels, from 0.0 to 1.0, for a target chromaticity, in the xy diagram
+    of the fixture's observer, at the maximum output of the fixture.
+
+    The target has to be within the gamut of the colored channels; an `OutOfGamut` error is
+    returned otherwise.
+    */
+    pub fn duty_cycles(&self, xy: [f64; 2]) -> Result<Vec<f64>, CmtError> {
+        let [x, y] = xy;
+        if y <= 0.0 || x < 0.0 || x + y > 1.0 {
+            return Err(CmtError::InvalidChromaticityValues);
+        }
+        if self.white.is_some_and(|w| w >= self.channels.len()) {
+            return Err(CmtError::ErrorString("Fixture: white channel index out of range".into()));
+        }
+        let obs = self.observer.data();
+        let colored: Vec<usize> = (0..self.channels.len()).filter(|&i| Some(i) != self.white).collect();
+        let xyz = |i: usize| obs.xyz_from_spectrum(&self.channels[i], None).xyzn;
+        let a = DMatrix::from_fn(3, colored.len(), |r, c| xyz(colored[c])[r]);
+        // target tristimulus values, with a luminous value of one
+        let t = DVector::from_column_slice(&[x / y, 1.0, (1.0 - x - y) / y]);
+        let solve = |b: &DVector<f64>| {
+            let w = nnls(&a, b);
+            ((&a * &w - b).norm() <= 1E-9 * t.norm()).then_some(w)
+        };
+        let Some(mut weights) = solve(&t) else {
+            return Err(CmtError::OutOfGamut);
+        };
+
+        let mut all = vec![0.0; self.channels.len()];
+        if let Some(white) = self.white {
+            let fraction = match self.substitution {
+                WhiteSubstitution::None => 0.0,
+                WhiteSubstitution::Maximum => 1.0,
+                WhiteSubstitution::Fraction(f) => f.clamp(0.0, 1.0),
+            };
+            let w = DVector::from_column_slice(xyz(white).as_slice());
+            // the colored mix, which remains, can not have negative tristimulus values
+            let mut high = (0..3).filter(|&i| w[i] > 0.0).map(|i| t[i] / w[i]).fold(f64::MAX, f64::min);
+            let mut low = 0.0;
+            for _ in 0..60 {
+                let mid = (low + high) / 2.0;
+                if solve(&(&t - &w * mid)).is_some() {
+                    low = mid;
+                } else {
+                    high = mid;
+                }
+            }
+            let s = low * fraction;
+            if let Some(w_colored) = solve(&(&t - &w * s)) {
+                weights = w_colored;
+                all[white] = s;
+            }
+        }
+        colored.iter().zip(weights.iter()).for_each(|(&i, &v)| all[i] = v);
+        let max = all.iter().copied().fold(0.0, f64::max);
+        Ok(all.into_iter().map(|v| v / max).collect())
+    }
+
+    /// Duty cycles for a chromaticity on the Planckian locus, for a correlated color temperature in
+    /// kelvin, at the maximum output of the fixture.
+    pub fn duty_cycles_cct(&self, cct: f64) -> Result<Vec<f64>, CmtError> {
+        self.duty_cycles(self.observer.data().xyz_planckian_locus(cct).xy().values())
+    }
+}
+
+/// 8-bit DMX512 control values, for a set of duty cycles.
+pub fn dmx8(duty_cycles: &[f64]) -> Vec<u8> {
+    duty_cycles.iter().map(|d| (d.clamp(0.0, 1.0) * 255.0).round() as u8).collect()
+}
+
+/// 16-bit DMX512 control values, for a set of duty cycles, to be sent as a coarse, and a fine,
+/// channel, with the high and low bytes of the values.
+pub fn dmx16(duty_cycles: &[f64]) -> Vec<u16> {
+    duty_cycles.iter().map(|d| (d.clamp(0.0, 1.0) * 65535.0).round() as u16).collect()
+}
+
+#[cfg(test)]
+mod fixture_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    fn rgbw() -> Fixture {
+        Fixture::new(&[
+            Illuminant::led(630.0, 20.0).set_irradiance(1.0),
+            Illuminant::led(530.0, 30.0).set_irradiance(1.0),
+            Illuminant::led(460.0, 20.0).set_irradiance(1.0),
+            Illuminant::planckian(5000.0).set_irradiance(3.0),
+        ])
+        .set_white_channel(3)
+    }
+
+    #[test]
+    fn test_duty_cycles() {
+        let target = [0.35, 0.36];
+        let mut outputs = Vec::new();
+        for substitution in [WhiteSubstitution::None, WhiteSubstitution::Fraction(0.5), WhiteSubstitution::Maximum] {
+            let fixture = rgbw().set_white_substitution(substitution);
+            let duty = fixture.duty_cycles(target).unwrap();
+            assert_abs_diff_eq!(duty.iter().copied().fold(0.0, f64::max), 1.0);
+            assert!(duty.iter().all(|&d| d >= 0.0));
+            let xyz = CIE1931.xyz_from_spectrum(&fixture.output(&duty), None);
+            assert_abs_diff_eq!(xyz.xy().values().as_ref(), target.as_ref(), epsilon = 1E-9);
+            // share of the white channel in the output
+            let white = CIE1931.xyz_from_spectrum(&fixture.output(&[0.0, 0.0, 0.0, duty[3]]), None);
+            outputs.push((white.xyzn.y / xyz.xyzn.y, xyz.xyzn.y));
+        }
+        // more white light, and, with this bright white channel, more light output than without
+        assert_eq!(outputs[0].0, 0.0);
+        assert!(outputs[1].0 > 0.0 && outputs[2].0 > outputs[1].0);
+        assert!(outputs[1].1 > outputs[0].1);
+
+        // with maximum substitution one of the colored channels is off
+        let duty = rgbw().duty_cycles(target).unwrap();
+        assert!(duty[..3].iter().any(|&d| d < 1E-6));
+
+        assert_eq!(rgbw().duty_cycles([0.05, 0.8]), Err(CmtError::OutOfGamut));
+        assert!(rgbw().set_white_channel(4).duty_cycles(target).is_err());
+    }
+
+    #[test]
+    fn test_dmx() {
+        assert_eq!(dmx8(&[0.0, 0.5, 1.0, 1.2]), [0, 128, 255, 255]);
+        assert_eq!(dmx16(&[0.0, 0.5, 1.0]), [0, 32768, 65535]);
+    }
+}
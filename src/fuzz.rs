@@ -0,0 +1,146 @@
+/*!
+# Arbitrary Values
+
+Implementations of the [`arbitrary::Arbitrary`] trait, with the `arbitrary` feature, for use in
+property based tests, and in fuzz targets, such as those generated with `cargo fuzz`.
+
+The generated values are physically constrained, to exercise the calculations in this library
+rather than its input validation:
+- a [`Spectrum`] is a smooth, non-negative, distribution, as a sum of up to four Gaussian bands,
+  on a constant base;
+- a [`Colorant`] is such a distribution, limited to values from 0.0 to 1.0;
+- an [`Illuminant`] is a mixture of a Planckian radiator, and up to three LEDs, with an
+  illuminance from 1 to 100,000 lux;
+- and an [`RGB`] value has linear values from 0.0 to 1.0.
+
+```
+use arbitrary::{Arbitrary, Unstructured};
+use colorimetry::prelude::*;
+
+let bytes = [7u8; 256];
+let mut u = Unstructured::new(&bytes);
+let illuminant = Illuminant::arbitrary(&mut u).unwrap();
+assert!(CIE1931.xyz(&illuminant, None).values()[1] > 0.0);
+```
+*/
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+use nalgebra::SVector;
+use strum::IntoEnumIterator;
+
+use crate::{
+    colorant::Colorant,
+    data::observers::CIE1931,
+    illuminant::Illuminant,
+    math,
+    observer::Observer,
+    rgb::RGB,
+    rgbspace::RgbSpace,
+    spectrum::{Spectrum, NS},
+};
+
+/// An arbitrary value in the range from `min` to `max`.
+fn in_range(u: &mut Unstructured, min: f64, max: f64) -> Result<f64> {
+    let f = u.int_in_range(0..=u32::MAX)? as f64 / u32::MAX as f64;
+    Ok(min + f * (max - min))
+}
+
+/// Wavelength based distribution of Gaussian bands, with center wavelengths, and standard
+/// deviations, in nanometer, on a constant base.
+fn bands(base: f64, bands: &[(f64, f64, f64)]) -> Spectrum {
+    Spectrum(SVector::<f64, NS>::from_fn(|i, _| {
+        let l = (i + 380) as f64;
+        base + bands.iter().map(|&(a, c, s)| a * math::exp(-0.5 * ((l - c) / s).powi(2))).sum::<f64>()
+    }))
+}
+
+impl<'a> Arbitrary<'a> for Spectrum {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let base = in_range(u, 0.0, 1.0)?;
+        let n = u.int_in_range(0..=4)?;
+        let mut b = Vec::with_capacity(n);
+        for _ in 0..n {
+            b.push((in_range(u, 0.0, 1.0)?, in_range(u, 380.0, 780.0)?, in_range(u, 5.0, 150.0)?));
+        }
+        Ok(bands(base, &b))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Colorant {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let s = Spectrum::arbitrary(u)?;
+        Ok(Colorant(Spectrum(s.0.map(|v| v.clamp(0.0, 1.0)))))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Illuminant {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let cct = in_range(u, 1000.0, 25000.0)?;
+        let mut s = *Illuminant::planckian(cct) * in_range(u, 0.0, 1.0)?;
+        for _ in 0..u.int_in_range(0..=3)? {
+            // LEDs normalized to a power comparable to the Planckian radiator
+            let led = Illuminant::led(in_range(u, 400.0, 700.0)?, in_range(u, 10.0, 100.0)?);
+            s += *led * (in_range(u, 0.0, 1.0)? / led.0 .0.sum() * 1E-9);
+        }
+        let illuminance = math::powf(10.0, in_range(u, 0.0, 5.0)?);
+        // a pure Planckian, if all weights are zero
+        let s = if s.0.iter().all(|&v| v == 0.0) { *Illuminant::planckian(cct) } else { s };
+        Ok(Illuminant(s).set_illuminance(&CIE1931, illuminance))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Observer {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let observers: Vec<Observer> = Observer::iter().collect();
+        Ok(*u.choose(&observers)?)
+    }
+}
+
+impl<'a> Arbitrary<'a> for RgbSpace {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let spaces: Vec<RgbSpace> = RgbSpace::iter().collect();
+        Ok(*u.choose(&spaces)?)
+    }
+}
+
+impl<'a> Arbitrary<'a> for RGB {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let [r, g, b] = [in_range(u, 0.0, 1.0)?, in_range(u, 0.0, 1.0)?, in_range(u, 0.0, 1.0)?];
+        Ok(RGB::new(r, g, b, Some(Observer::arbitrary(u)?), Some(RgbSpace::arbitrary(u)?)))
+    }
+}
+
+#[cfg(test)]
+mod fuzz_test {
+    use crate::prelude::*;
+    use arbitrary::{Arbitrary, Unstructured};
+
+    #[test]
+    fn test_arbitrary() {
+        let mut seed = 2024u64;
+        let bytes: Vec<u8> = (0..100_000)
+            .map(|_| {
+                seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                (seed >> 56) as u8
+            })
+            .collect();
+        let mut u = Unstructured::new(&bytes);
+        for _ in 0..50 {
+            let colorant = Colorant::arbitrary(&mut u).unwrap();
+            assert!(colorant.0 .0.iter().all(|v| (0.0..=1.0).contains(v)));
+
+            let illuminant = Illuminant::arbitrary(&mut u).unwrap();
+            let xyz = CIE1931.xyz(&illuminant, None);
+            assert!(xyz.values()[1] > 0.0);
+
+            // may fail, but should not panic
+            #[cfg(feature = "cct")]
+            let _ = xyz.cct();
+            #[cfg(feature = "cri")]
+            let _ = CRI::try_new(&illuminant);
+
+            let rgb = RGB::arbitrary(&mut u).unwrap();
+            assert!(rgb.is_in_gamut());
+        }
+    }
+}
@@ -1,4 +1,4 @@
-
+use crate::math;
 
 #[derive(Clone)]
 /// General representation of the RGB encoding and decoding functions used by color spaces.
@@ -17,55 +17,82 @@ impl GammaCurve{
         if x<0.0 || x>1.0 { 
             f64::NAN
         } else {
-            match self.p.len() {
-                1 => {
-                    let g = self.p[0];
-                    x.powf(g)
-                },
-                3 => {
-                    let [g, a, b] = self.p[..] else {panic!()}; // never reached
-                    if x>= -b/a {
-                        (a*x + b).powf(g)
-                    } else {
-                        0.0
-                    }
-                },
-                4 => {
-                    let [g, a, b, c] = self.p[..] else {panic!()};
-                    if x>= -b/a {
-                        (a*x + b).powf(g) + c
-                    } else {
-                       c 
-                    }
-                },
-                5 => {
-                    let [g, a, b, c, d] = self.p[..] else {panic!()};
-                    if x>= d {
-                        (a*x + b).powf(g)
-                    } else {
-                        c*x
-                    }
-                },
-                7 => {
-                    let [g,a,b, c, d, e, f] = self.p[..] else {panic!()};
-                    if x>= d {
-                        (a*x + b).powf(g) + e
-                    } else {
-                        c*x + f
-                    }
-                },
-                _ => f64::NAN
-            }
+            self.decode_unclamped(x)
+        }
+    }
+
+    /// Decode with extended range (scRGB) semantics.
+    ///
+    /// Values outside the range from 0.0 to 1.0 are not rejected, but the curve
+    /// is extended beyond 1.0, and mirrored around the origin for negative
+    /// values, as used in extended range color encodings such as scRGB.
+    pub fn decode_extended(&self, x: f64) -> f64 {
+        x.signum() * self.decode_unclamped(x.abs())
+    }
+
+    fn decode_unclamped(&self, x: f64) -> f64 {
+        match self.p.len() {
+            1 => {
+                let g = self.p[0];
+                math::powf(x, g)
+            },
+            3 => {
+                let [g, a, b] = self.p[..] else {panic!()}; // never reached
+                if x>= -b/a {
+                    math::powf(a*x + b, g)
+                } else {
+                    0.0
+                }
+            },
+            4 => {
+                let [g, a, b, c] = self.p[..] else {panic!()};
+                if x>= -b/a {
+                    math::powf(a*x + b, g) + c
+                } else {
+                   c 
+                }
+            },
+            5 => {
+                let [g, a, b, c, d] = self.p[..] else {panic!()};
+                if x>= d {
+                    math::powf(a*x + b, g)
+                } else {
+                    c*x
+                }
+            },
+            7 => {
+                let [g,a,b, c, d, e, f] = self.p[..] else {panic!()};
+                if x>= d {
+                    math::powf(a*x + b, g) + e
+                } else {
+                    c*x + f
+                }
+            },
+            _ => f64::NAN
         }
     }
 
     // from xyz coordinates to rgb, gamma < 1.0
     pub fn encode(&self, x:f64) -> f64 {
-        let x = x.clamp(0.0, 1.0);
+        self.encode_unclamped(x.clamp(0.0, 1.0))
+    }
+
+    /// Encode with extended range (scRGB) semantics.
+    ///
+    /// Linear values are not clamped: values larger than 1.0 are encoded using
+    /// the extension of the curve, and negative values are encoded as the
+    /// negated encoding of their absolute value. This keeps out-of-gamut and
+    /// high dynamic range intermediate values intact, and can be reversed
+    /// using `decode_extended`.
+    pub fn encode_extended(&self, x: f64) -> f64 {
+        x.signum() * self.encode_unclamped(x.abs())
+    }
+
+    fn encode_unclamped(&self, x:f64) -> f64 {
         match self.p.len() {
             1 => {
                 let g = self.p[0];
-                x.powf(1.0/g)
+                math::powf(x, 1.0/g)
             },
             3 => {
                 let [g, a, b] = self.p[..] else {panic!()}; // never reached
@@ -78,7 +105,7 @@ impl GammaCurve{
             5 => {
                 let [g, a, b, c, d] = self.p[..] else {panic!()};
                 if x>= d * c {
-                    (x.powf(1.0/g) - b) / a
+                    (math::powf(x, 1.0/g) - b) / a
                 } else {
                     x / c
                 }
@@ -114,3 +141,23 @@ fn test_gamma_srgb(){
     println!("{} {}", gc.encode(1.0000001), gc.encode_u8(1.000001, Some(0.0), Some(1.0)));
 
 }
+
+#[test]
+fn test_gamma_srgb_extended(){
+    use approx::assert_ulps_eq;
+    let gc = GammaCurve::new(vec![2.4, 1.0/1.055, 0.055/1.055, 1.0/12.92, 0.04045]);
+
+    // within range identical to the regular encoding
+    assert_ulps_eq!(gc.encode_extended(0.5), gc.encode(0.5));
+    assert_ulps_eq!(gc.encode_extended(0.001), gc.encode(0.001));
+
+    // out of range values are not clamped, and mirrored for negative values
+    assert!(gc.encode_extended(2.0) > 1.0);
+    assert_ulps_eq!(gc.encode_extended(-0.5), -gc.encode(0.5));
+    assert!(gc.decode(-0.5).is_nan());
+
+    // round trip
+    for v in [-2.0, -0.5, -0.001, 0.0, 0.001, 0.5, 1.0, 2.0, 7.5] {
+        assert_ulps_eq!(gc.decode_extended(gc.encode_extended(v)), v, epsilon = 1E-12);
+    }
+}
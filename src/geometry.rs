@@ -4,7 +4,7 @@ use core::f64;
 use nalgebra::ComplexField;
 use wasm_bindgen::prelude::wasm_bindgen;
 
-use crate::error::CmtError;
+use crate::{error::CmtError, math};
 
 /// Distance of point (x,y) to line going through points (x0,y0) having slope m
 /// 
@@ -12,7 +12,7 @@ use crate::error::CmtError;
 /// lower color temperatures to deal with the change of the sign value of the Blackbody locus normal: it
 /// is negative for temperatures higher than 1667K, and positive otherwise.
 pub fn distance_to_line(x: f64, y: f64, x0: f64, y0: f64, m: f64) -> f64 {
-      m.signum() *((y-y0) - m * (x-x0))/m.hypot(1.0)
+      m.signum() *((y-y0) - m * (x-x0))/math::hypot(m, 1.0)
 }
 
 #[derive(Clone, Copy)]
@@ -40,8 +40,8 @@ impl LineAB {
 
     pub fn try_new(a: [f64;2], b: [f64;2]) -> Result<Self, CmtError> {
         let [[xa, ya], [xb, yb]] = [a, b];
-        let l = (xb-xa).hypot(yb-ya);
-        let angle = (yb - ya).atan2(xb-xa);
+        let l = math::hypot(xb-xa, yb-ya);
+        let angle = math::atan2(yb - ya, xb-xa);
         if l>1E-10 {
         //if l>f64::EPSILON {
             Ok( Self { xa, ya, xb, yb, l, angle} )
@@ -208,9 +208,9 @@ impl Triangle {
 
     pub fn try_new(a: [f64;2], b: [f64;2], c: [f64;2]) -> Result<Self, CmtError> {
         let [[xa, ya], [xb, yb], [xc, yc]] = [a, b, c];
-        let la = (xc-xb).hypot(yc-yb);
-        let lb = (xc-xa).hypot(yc-ya);
-        let lc = (xb-xa).hypot(yb-ya);
+        let la = math::hypot(xc-xb, yc-yb);
+        let lb = math::hypot(xc-xa, yc-ya);
+        let lc = math::hypot(xb-xa, yb-ya);
         let s = (la + lb + lc)/2.0;
         let area2 = s * (s-la) * (s-lb) * (s-lc);
         let nom = (yb - yc)*(xa - xc) + (xc - xb)*(ya - yc);
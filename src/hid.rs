@@ -0,0 +1,154 @@
+/*!
+# High Intensity Discharge Lamps
+
+Modeled spectral distributions of the high intensity discharge (HID) lamps used in legacy
+street and area lighting: high pressure sodium, clear mercury vapor, and metal halide lamps, to
+compare with replacement sources in retrofit analyses.
+
+These are not measured spectra, but are modeled from the principal emission lines of the lamps,
+with line widths, and relative intensities, typical for these lamps:
+
+- high pressure sodium: a pressure broadened, self-reversed, sodium D line, around 589 nanometer,
+  with weak sodium lines at 498, 515, 568, and 616 nanometer;
+- clear mercury vapor: the mercury lines at 405, 436, 546, and 577/579 nanometer, on a weak
+  continuum;
+- metal halide: mercury lines, with the sodium, thallium, and indium emission of a
+  sodium-thallium-indium lamp, and a rare earth continuum.
+
+Measured spectra of individual lamps can deviate considerably; use them when available.
+All spectra are scaled to an illuminance of 1000 lux, for the CIE 1931 observer.
+*/
+
+use std::sync::LazyLock;
+
+use nalgebra::SVector;
+
+use crate::{
+    data::observers::CIE1931,
+    illuminant::Illuminant,
+    physics::gaussian_peak_one,
+    spectrum::{Spectrum, NS},
+};
+
+/// Standard deviation, in nanometer, of the unresolved emission lines.
+const LINE_SIGMA: f64 = 1.5;
+
+/// High intensity discharge lamp types, with modeled spectral distributions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, strum_macros::Display, strum_macros::EnumIter)]
+pub enum HidLamp {
+    HighPressureSodium,
+    MercuryVapor,
+    MetalHalide,
+}
+
+impl HidLamp {
+    /**
+    Modeled spectral distribution of the lamp type, from its principal emission lines; this is not
+    a measured, or standard, spectrum.
+    ```
+    use colorimetry::prelude::*;
+
+    let hps = HidLamp::HighPressureSodium.modeled_illuminant();
+    let [x, y] = CIE1931.xyz(hps, None).xy().values();
+    assert!(x > 0.5 && y > 0.4);
+    ```
+    */
+    pub fn modeled_illuminant(&self) -> &'static Illuminant {
+        static HPS: LazyLock<Illuminant> = LazyLock::new(|| {
+            spectrum(
+                &[(498.0, 0.04), (515.0, 0.05), (568.5, 0.25), (616.0, 0.12)],
+                |l| {
+                    // pressure broadened D line, with self-reversal at its center
+                    let wing = 1.0 / (1.0 + ((l - 589.0) / 18.0).powi(2));
+                    wing * (1.0 - 0.95 * gaussian_peak_one(l, 589.0, 4.0))
+                },
+            )
+        });
+        static MERCURY: LazyLock<Illuminant> = LazyLock::new(|| {
+            spectrum(
+                &[
+                    (404.7, 0.45),
+                    (435.8, 1.0),
+                    (546.1, 1.15),
+                    (577.0, 0.55),
+                    (579.1, 0.6),
+                ],
+                |l| 0.012 + 0.01 * gaussian_peak_one(l, 500.0, 80.0),
+            )
+        });
+        static METAL_HALIDE: LazyLock<Illuminant> = LazyLock::new(|| {
+            spectrum(
+                &[
+                    (404.7, 0.25),
+                    (410.2, 0.5),
+                    (435.8, 0.45),
+                    (451.1, 0.9),
+                    (467.8, 0.15),
+                    (475.0, 0.12),
+                    (498.3, 0.15),
+                    (515.3, 0.12),
+                    (535.0, 1.0),
+                    (546.1, 0.55),
+                    (578.0, 0.4),
+                    (616.0, 0.15),
+                    (627.0, 0.12),
+                    (655.0, 0.12),
+                    (671.0, 0.2),
+                    (690.0, 0.12),
+                ],
+                |l| {
+                    0.08 * gaussian_peak_one(l, 540.0, 90.0)
+                        + 0.45 / (1.0 + ((l - 589.0) / 6.0).powi(2))
+                            * (1.0 - 0.8 * gaussian_peak_one(l, 589.0, 1.5))
+                },
+            )
+        });
+        match self {
+            HidLamp::HighPressureSodium => &HPS,
+            HidLamp::MercuryVapor => &MERCURY,
+            HidLamp::MetalHalide => &METAL_HALIDE,
+        }
+    }
+}
+
+/// Spectrum composed of emission lines, with their wavelength and relative peak value, and a
+/// continuum, scaled to an illuminance of 1000 lux.
+fn spectrum(lines: &[(f64, f64)], continuum: impl Fn(f64) -> f64) -> Illuminant {
+    let data = SVector::<f64, NS>::from_fn(|i, _| {
+        let l = (i + 380) as f64;
+        continuum(l)
+            + lines
+                .iter()
+                .map(|&(c, v)| v * gaussian_peak_one(l, c, LINE_SIGMA))
+                .sum::<f64>()
+    });
+    Illuminant(Spectrum(data)).set_illuminance(&CIE1931, 1000.0)
+}
+
+#[cfg(test)]
+mod hid_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn test_hid() {
+        for lamp in HidLamp::iter() {
+            let illuminant = lamp.modeled_illuminant();
+            assert_abs_diff_eq!(illuminant.illuminance(&CIE1931), 1000.0, epsilon = 1E-9);
+            assert_eq!(SourceType::classify(illuminant), SourceType::HID, "{lamp}");
+        }
+        let [x, y] = CIE1931
+            .xyz(HidLamp::HighPressureSodium.modeled_illuminant(), None)
+            .xy().values();
+        assert!(x > 0.5 && y > 0.4);
+        #[cfg(feature = "cct")]
+        {
+            let cct = CIE1931
+                .xyz(HidLamp::MercuryVapor.modeled_illuminant(), None)
+                .cct()
+                .unwrap();
+            assert!((5000.0..7000.0).contains(&cct.t()));
+        }
+    }
+}
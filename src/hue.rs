@@ -0,0 +1,108 @@
+/*!
+# Hue Angle Statistics
+
+Hue angles are circular quantities: 350º and 10º differ by 20º, not 340º, and their mean is 0º,
+not 180º. Arithmetic on hue angles, such as averaging the hues of a batch of measurements, or
+subtracting the hue of a reference, needs circular statistics, which are provided here, for
+angles in degrees, as used for the [`CieLab::hue_angle`].
+```
+use colorimetry::prelude::*;
+
+assert_eq!(hue_difference(350.0, 10.0), 20.0);
+approx::assert_abs_diff_eq!(mean_hue(&[350.0, 10.0]).unwrap(), 0.0, epsilon = 1E-9);
+```
+*/
+
+use crate::{lab::CieLab, math};
+
+/// Difference of two hue angles, in degrees, as the rotation from the first to the second,
+/// wrapped to the range from -180º to 180º.
+pub fn hue_difference(from_deg: f64, to_deg: f64) -> f64 {
+    let d = (to_deg - from_deg).rem_euclid(360.0);
+    if d > 180.0 {
+        d - 360.0
+    } else {
+        d
+    }
+}
+
+/// Circular mean of hue angles, in degrees, from 0 to 360, or None if the angles cancel, or if
+/// there are none.
+pub fn mean_hue(angles_deg: &[f64]) -> Option<f64> {
+    HueStatistics::from_angles(angles_deg).map(|s| s.mean)
+}
+
+/// Circular statistics of a set of hue angles.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HueStatistics {
+    /// Circular mean, in degrees, from 0 to 360.
+    pub mean: f64,
+    /// Length of the mean resultant vector of the angles, as unit vectors, ranging from 0.0, for
+    /// uniformly spread angles, to 1.0, for identical angles.
+    pub resultant_length: f64,
+    /// Circular standard deviation, in degrees.
+    pub std_dev: f64,
+}
+
+impl HueStatistics {
+    /// Statistics of hue angles in degrees, or None if the angles cancel, and have no mean, or
+    /// if there are none.
+    pub fn from_angles(angles_deg: &[f64]) -> Option<Self> {
+        if angles_deg.is_empty() {
+            return None;
+        }
+        let (s, c) = angles_deg.iter().fold((0.0, 0.0), |(s, c), a| {
+            let r = a.to_radians();
+            (s + math::sin(r), c + math::cos(r))
+        });
+        let n = angles_deg.len() as f64;
+        let resultant_length = (math::hypot(s, c) / n).min(1.0);
+        if resultant_length < 1E-12 {
+            return None;
+        }
+        // rem_euclid rounds small negative angles up to 360
+        let mean = math::atan2(s, c).to_degrees().rem_euclid(360.0);
+        Some(Self {
+            mean: if mean < 360.0 { mean } else { 0.0 },
+            resultant_length,
+            std_dev: (-2.0 * math::ln(resultant_length)).sqrt().to_degrees(),
+        })
+    }
+
+    /// Statistics of the hue angles of CIELAB values; neutral colors, without a hue, are
+    /// skipped.
+    pub fn from_lab(lab: &[CieLab]) -> Option<Self> {
+        let angles: Vec<f64> = lab.iter().filter(|l| l.chroma() > 1E-9).map(CieLab::hue_angle).collect();
+        Self::from_angles(&angles)
+    }
+}
+
+#[cfg(test)]
+mod hue_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_hue_statistics() {
+        assert_eq!(hue_difference(10.0, 350.0), -20.0);
+        assert_eq!(hue_difference(0.0, 180.0), 180.0);
+        assert_eq!(hue_difference(-90.0, 630.0), 0.0);
+
+        let stats = HueStatistics::from_angles(&[355.0, 5.0, 15.0]).unwrap();
+        assert_abs_diff_eq!(stats.mean, 5.0, epsilon = 1E-9);
+        assert!(stats.resultant_length < 1.0 && stats.std_dev > 5.0 && stats.std_dev < 15.0);
+        assert_abs_diff_eq!(HueStatistics::from_angles(&[90.0; 3]).unwrap().std_dev, 0.0, epsilon = 1E-6);
+        assert!(mean_hue(&[0.0, 120.0, 240.0]).is_none());
+        assert!(mean_hue(&[]).is_none());
+
+        let white = CIE1931.xyz(&StdIlluminant::D65, None).set_illuminance(100.0);
+        let lab = [
+            CieLab::new([50.0, 10.0, -1.0], white),
+            CieLab::new([50.0, 10.0, 1.0], white),
+            CieLab::new([50.0, 0.0, 0.0], white),
+        ];
+        let mean = HueStatistics::from_lab(&lab).unwrap().mean;
+        assert!((0.0..360.0).contains(&mean));
+        assert_abs_diff_eq!(hue_difference(0.0, mean), 0.0, epsilon = 1E-9);
+    }
+}
@@ -1,18 +1,19 @@
-use std::{borrow::Cow, ops::{Deref, Mul}};
+use std::{borrow::Cow, ops::{Deref, Mul}, sync::LazyLock};
 use wasm_bindgen::prelude::*;
 
 use colored::Color;
 use nalgebra::{ArrayStorage, SMatrix, SVector};
 
 use crate::{
-    data::illuminants::{D50, D65}, observer::ObserverData, physics::{gaussian_peak_one, led_ohno, planck, stefan_boltzmann, wavelength}, spectrum::{Spectrum, NS, wavelengths}, std_illuminants::StdIlluminant, traits::Light, error::CmtError
+    colorant::Colorant, data::{illuminants::{D50, D65}, observers::CIE1931}, lab::CieLab, math, observer::{Observer, ObserverData}, oklab::OkLab, physics::{gaussian_peak_one, led_ohno, planck, stefan_boltzmann, wavelength}, spectrum::{Spectrum, NS, wavelengths}, std_illuminants::StdIlluminant, traits::Light, error::CmtError, xyz::XYZ
 };
 
 #[cfg(feature="cri")]
 use crate::cri::CRI;
 
 
-#[derive(Clone, Default)]
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[wasm_bindgen]
 pub struct Illuminant(pub(crate) Spectrum);
 
@@ -71,7 +72,7 @@ impl Illuminant {
     /// 
     /// let p3000 = Illuminant::planckian(3000.0);
     /// let xyz = CIE1931.xyz(&p3000, None);
-    /// let [x,y] = xyz.chromaticity();
+    /// let [x,y] = xyz.xy().values();
     /// assert_ulps_eq!(x, 0.436_935, epsilon = 1E-6);
     /// assert_ulps_eq!(y, 0.404_083, epsilon = 1E-6);
     /// 
@@ -115,6 +116,9 @@ impl Illuminant {
             self.0.0.sum()
     }
 
+    /// Scales the spectrum to an illuminance value, in lux, for an observer.
+    /// See the [`units`](crate::units) module to convert luminous intensity or luminous flux
+    /// values into illuminance values.
     pub fn set_illuminance(mut self, obs: &ObserverData, illuminance: f64) -> Self {
         let l = illuminance / (obs.data.row(1) *  self.0.0 * obs.lumconst).x;
         self.0.0.iter_mut().for_each(|v| *v = *v * l);
@@ -125,6 +129,117 @@ impl Illuminant {
             (obs.data.row(1) * self.0.0 *  obs.lumconst).x
     }
 
+    /**
+    Luminous efficacy of radiation (LER), in lumen per watt, as the ratio of the illuminance and
+    the irradiance of the spectrum, for an observer.
+    ```
+    use colorimetry::prelude::*;
+
+    let ler = Illuminant::d65().luminous_efficacy(&CIE1931);
+    approx::assert_abs_diff_eq!(ler, 204.8, epsilon = 0.1);
+    ```
+    */
+    pub fn luminous_efficacy(&self, obs: &ObserverData) -> f64 {
+        self.illuminance(obs) / self.irradiance()
+    }
+
+    /**
+    Spectral irradiance, in W/m<sup>2</sup>/nm, on a surface at a distance from a point source,
+    with the source's spectral radiant intensity, in W/sr/nm, as spectrum.
+
+    The distance is in meter, and the incidence angle in degrees, measured from the surface normal.
+    Use this for example to calculate the spectral irradiance at the position of an observer's
+    eye, for a source with a known intensity in the direction of the eye.
+    */
+    pub fn from_radiant_intensity(intensity: &Spectrum, distance_m: f64, incidence_angle_deg: f64) -> Result<Self, CmtError> {
+        check_distance(distance_m)?;
+        let cos = cosine_factor(incidence_angle_deg)?;
+        Ok(Self(*intensity * (cos / (distance_m * distance_m))))
+    }
+
+    /// Scales a spectral irradiance, measured at a distance from a point source, to another
+    /// distance from that source, using the inverse square law. Distances are in meter.
+    pub fn at_distance(self, measured_m: f64, distance_m: f64) -> Result<Self, CmtError> {
+        check_distance(measured_m)?;
+        check_distance(distance_m)?;
+        let s = (measured_m / distance_m).powi(2);
+        Ok(Self(self.0 * s))
+    }
+
+    /// Spectral irradiance on a plane tilted over an angle, in degrees, from the plane
+    /// perpendicular to the direction of the incident light, for which the irradiance was
+    /// measured, or calculated.
+    pub fn on_tilted_plane(self, tilt_deg: f64) -> Result<Self, CmtError> {
+        let cos = cosine_factor(tilt_deg)?;
+        Ok(Self(self.0 * cos))
+    }
+
+    /**
+    The relative spectral distribution of this illuminant, scaled to a peak value of 1.0, as a
+    colorant.
+
+    Use this to use the shape of a light's spectrum as a spectral filter, or as a spectral
+    weighting function. The absolute scale of the light is lost, and negative values, from
+    reconstructed or measured spectra, are set to zero. Fails for spectra without positive
+    values.
+    */
+    pub fn into_colorant(self) -> Result<Colorant, CmtError> {
+        let max = self.0.0.max();
+        if max > 0.0 && max.is_finite() {
+            let mut spectrum = self.0 * (1.0 / max);
+            spectrum.clamp(0.0, 1.0);
+            Ok(Colorant(spectrum))
+        } else {
+            Err(CmtError::ErrorString("Illuminant: a colorant requires positive spectral values".into()))
+        }
+    }
+
+    /**
+    Spectral transmittance of a filter, from the spectral distribution of a source measured
+    through the filter, and of the source itself, measured without it.
+
+    Transmittance values are only calculated at wavelengths where the source has a spectral
+    value of at least 0.1% of its peak value; at other wavelengths, where the ratio is dominated
+    by measurement noise, the values are interpolated linearly from the nearest valid
+    wavelengths, or held constant at the ends of the spectrum.
+    Values are limited to a range from 0.0 to 1.0.
+    Fails if the source has no positive spectral values.
+    ```
+    use colorimetry::prelude::*;
+
+    let source = Illuminant::led(450.0, 20.0);
+    let filter = Colorant::gaussian(460.0, 30.0);
+    let product = *source * *filter;
+    let measured = Illuminant::try_from(AsRef::<[f64]>::as_ref(&product)).unwrap();
+    let transmittance = Illuminant::ratio(&measured, &source).unwrap();
+    approx::assert_abs_diff_eq!(transmittance[460], 1.0, epsilon = 1E-9);
+    approx::assert_abs_diff_eq!(transmittance[450], filter[450], epsilon = 1E-9);
+    ```
+    */
+    pub fn ratio(measured: &Illuminant, source: &Illuminant) -> Result<Colorant, CmtError> {
+        const THRESHOLD: f64 = 1E-3;
+        let max = source.0.0.max();
+        if !(max > 0.0 && max.is_finite()) {
+            return Err(CmtError::ErrorString("Illuminant ratio: source requires positive spectral values".into()));
+        }
+        let valid: Vec<usize> = (0..NS).filter(|&i| source.0.0[i] >= THRESHOLD * max).collect();
+        let t = |i: usize| measured.0.0[i] / source.0.0[i];
+        let data = SVector::<f64, NS>::from_fn(|i, _| {
+            let v = match valid.binary_search(&i) {
+                Ok(_) => t(i),
+                Err(0) => t(valid[0]),
+                Err(k) if k == valid.len() => t(valid[k - 1]),
+                Err(k) => {
+                    let [lo, hi] = [valid[k - 1], valid[k]];
+                    let f = (i - lo) as f64 / (hi - lo) as f64;
+                    t(lo) * (1.0 - f) + t(hi) * f
+                }
+            };
+            v.clamp(0.0, 1.0)
+        });
+        Ok(Colorant(Spectrum(data)))
+    }
+
     /// Calculates the Color Rendering Index values for illuminant spectrum.
     /// 
     /// To use this function, first use `CRI::init().await`, which downloads the
@@ -137,24 +252,103 @@ impl Illuminant {
         self.try_into()
     }
 
+    /// A CIE D illuminant, or daylight, spectrum, for a correlated color temperature from 4000 to
+    /// 25000 kelvin, normalized to an irradiance of 1 W/m².
+    pub fn try_d_illuminant(cct: f64) -> Result<Illuminant, CmtError> {
+        let [m1, m2] = d_illuminant_coefficients(cct)?;
+        // linear interpolation commutes with the linear combination of the S-vectors, so
+        // these are interpolated to the spectral domain once
+        let [s0, s1, s2] = &*CIE_D_S_NS;
+        let s = Spectrum(s0.0 + m1 * s1.0 + m2 * s2.0);
+        Ok(Illuminant(s).set_irradiance(1.0))
+    }
+
+    #[deprecated(since = "0.0.3", note = "use `Illuminant::try_d_illuminant`")]
     pub fn d_illuminant(cct: f64) -> Result<Illuminant, CmtError> {
-        if cct<4000.0 || cct>25000.0 {
-            Err(CmtError::OutOfRange{name:"CIE D Illuminant Temperature".to_string(), low: 4000.0, high: 25000.0})
-        } else { 
-            let xd = match cct {
-                t if t < 7000.0 => {
-                    0.244063 + 0.09911E3 / t + 2.9678E6 / t.powi(2) - 4.607E9 / t.powi(3)
-                }
-                t => 0.23704 + 0.24748E3 / t + 1.9018E6 / t.powi(2) - 2.0064E9 / t.powi(3),
-            };
-            let yd = -3. * xd.powi(2) + 2.87 * xd - 0.275;
-            let m = 0.0241 + 0.2562 * xd - 0.7341 * yd;
-            let m1 = (-1.3515 - 1.7703 * xd + 5.9114 * yd) / m;
-            let m2 = (0.03 - 31.4424 * xd + 30.0717 * yd) / m;
-            let mut v = [0.0; CIE_D_S_LEN];
-            v.iter_mut().enumerate().for_each(|(i,x)|*x = CIE_D_S[(i,0)] + m1 * CIE_D_S[(i,1)] + m2 * CIE_D_S[(i,2)]);
-            let s = Spectrum::linear_interpolate(&[380.0, 780.0], &v).unwrap();
-            Ok(Illuminant(s).set_irradiance(1.0))
+        Self::try_d_illuminant(cct)
+    }
+
+    /**
+    Spectral distributions for a fade from this illuminant to another, in `n` steps, including
+    both ends, as mixtures of the two, for tunable lighting scenes.
+
+    The mixing ratios are chosen so that the chromaticities of successive steps have equal
+    distances in a perceptual space, using the CIE 1931 observer, and the illuminance changes
+    linearly from the illuminance of this illuminant to that of the other.
+    ```
+    use colorimetry::prelude::*;
+
+    let warm = Illuminant::planckian(2700.0).set_illuminance(&CIE1931, 300.0);
+    let cool = Illuminant::d65().set_illuminance(&CIE1931, 500.0);
+    let fade = warm.gradient(&cool, 5, GradientSpace::UvPrime).unwrap();
+    let uv: Vec<XYZ> = fade.iter().map(|s| CIE1931.xyz(s, None)).collect();
+    let steps: Vec<f64> = uv.windows(2).map(|w| w[0].uv_prime_distance(&w[1])).collect();
+    approx::assert_abs_diff_eq!(steps[0], steps[3], epsilon = 1E-5);
+    approx::assert_abs_diff_eq!(fade[2].illuminance(&CIE1931), 400.0, epsilon = 1E-9);
+    ```
+    */
+    pub fn gradient(&self, other: &Illuminant, n: usize, space: GradientSpace) -> Result<Vec<Illuminant>, CmtError> {
+        if n < 2 {
+            return Err(CmtError::ProvideAtLeastNValues(2));
+        }
+        let mix = |w: f64| Illuminant(Spectrum(self.0 .0 * (1.0 - w) + other.0 .0 * w));
+        // cumulative path length of the mixtures' chromaticities, for a dense set of mixing ratios
+        let ratios: Vec<f64> = (0..=GRADIENT_GRID).map(|i| i as f64 / GRADIENT_GRID as f64).collect();
+        let points = ratios
+            .iter()
+            .map(|&w| space.coordinates(CIE1931.xyz_from_spectrum(&mix(w), None)))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut length = vec![0.0];
+        for p in points.windows(2) {
+            let d = ((p[1][0] - p[0][0]).powi(2) + (p[1][1] - p[0][1]).powi(2)).sqrt();
+            length.push(length[length.len() - 1] + d);
+        }
+        let total = length[GRADIENT_GRID];
+        let [e1, e2] = [self.illuminance(&CIE1931), other.illuminance(&CIE1931)];
+        Ok((0..n)
+            .map(|k| {
+                let t = k as f64 / (n - 1) as f64;
+                let w = if total > 0.0 {
+                    let target = t * total;
+                    let i = length.partition_point(|&l| l < target).clamp(1, GRADIENT_GRID);
+                    let f = if length[i] > length[i - 1] { (target - length[i - 1]) / (length[i] - length[i - 1]) } else { 0.0 };
+                    ratios[i - 1] + f * (ratios[i] - ratios[i - 1])
+                } else {
+                    t
+                };
+                mix(w).set_illuminance(&CIE1931, e1 + t * (e2 - e1))
+            })
+            .collect())
+    }
+}
+
+/// Number of mixing ratios used to find equal perceptual steps in [`Illuminant::gradient`].
+const GRADIENT_GRID: usize = 1000;
+
+/// Perceptual space, in which the steps of an [`Illuminant::gradient`] have equal sizes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, strum_macros::Display)]
+pub enum GradientSpace {
+    /// The CIE 1976 u'v' chromaticity diagram.
+    #[default]
+    UvPrime,
+    /// The a*, b* plane of CIELAB, at equal luminance, with CIE D65 as reference white.
+    CieLab,
+    /// The a, b plane of OKLab, at equal luminance.
+    OkLab,
+}
+
+impl GradientSpace {
+    /// Chromaticity coordinates, in this space, of the tristimulus values of a light.
+    fn coordinates(&self, xyz: XYZ) -> Result<[f64; 2], CmtError> {
+        if xyz.xyzn.y <= 0.0 {
+            return Err(CmtError::ErrorString("Illuminant: gradient requires a positive luminance".into()));
+        }
+        let white = CIE1931.xyz_d65().set_illuminance(100.0);
+        let sample = XYZ::from_vecs(white.xyzn, Some(xyz.xyzn * (100.0 / xyz.xyzn.y)), Observer::Std1931);
+        match self {
+            GradientSpace::UvPrime => Ok(xyz.xy().uvprime().values()),
+            GradientSpace::CieLab => CieLab::try_from(sample).map(|lab| [lab.lab[1], lab.lab[2]]),
+            GradientSpace::OkLab => OkLab::try_from(sample).map(|ok| [ok.values()[1], ok.values()[2]]),
         }
     }
 }
@@ -245,7 +439,22 @@ impl Illuminant {
         values.into()
     }
 
- 
+    /// Creates an illuminant from spectral data with another wavelength domain than the one used
+    /// in this library, by linear interpolation.
+    ///
+    /// For a regular domain, `wavelengths` contains the first and last wavelength only, and for
+    /// an irregular domain, a wavelength for each of the spectral values, in units of nanometer
+    /// or meter.
+    #[wasm_bindgen(js_name=linearInterpolate)]
+    pub fn linear_interpolate_js(wavelengths: &[f64], data: &[f64]) -> Result<Illuminant, CmtError> {
+        Ok(Illuminant(Spectrum::try_linear_interpolate(wavelengths, data)?))
+    }
+
+    /// Calculates the tristimulus values of this illuminant, for the CIE 1931 observer.
+    #[wasm_bindgen(js_name=xyz)]
+    pub fn xyz_js(&self) -> XYZ {
+        CIE1931.xyz(self, None)
+    }
 
     /// Calculates the Color Rendering Index values for illuminant spectrum.
     /// 
@@ -255,13 +464,13 @@ impl Illuminant {
     #[cfg(feature="cri")]
     #[wasm_bindgen(js_name=cri)]
     pub fn cri_js(&self) -> Result<crate::cri::CRI, CmtError> {
-        todo!()
+        crate::cri::CRI::try_new(self)
     }
 
     /// Get the StdIlluminant spectrum. Typically you don't need to use the Spectrum itself, as many
     /// methods just accept the StdIlluminant directly.
     #[wasm_bindgen(js_name=illuminant)]
-    pub fn llluminant_js(stdill: StdIlluminant) -> Self {
+    pub fn illuminant_js(stdill: StdIlluminant) -> Self {
         // need this as wasm_bindgen does not support `impl` on Enum types (yet?).
         // in Rust use StdIlluminant.spectrum() directly, which also gives a reference instead of a copy.
         stdill.illuminant().clone()
@@ -271,13 +480,101 @@ impl Illuminant {
 #[test]
 fn test_d_illuminant(){
     use crate::prelude::*;
-    let s = Illuminant::d_illuminant(6504.0).unwrap();
+    let s = Illuminant::try_d_illuminant(6504.0).unwrap();
     let xyz = CIE1931.xyz_from_spectrum(&s, None).set_illuminance(100.0);
     approx::assert_ulps_eq!(xyz, CIE1931.xyz_d65(), epsilon = 2E-2);
 }
 
+#[test]
+fn test_irradiance_geometry(){
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+    let intensity = Illuminant::equal_energy();
+    // 1 W/sr at 2 meter, at 60º from the normal
+    let e = Illuminant::from_radiant_intensity(&intensity, 2.0, 60.0).unwrap();
+    assert_abs_diff_eq!(e.irradiance(), 0.125, epsilon = 1E-12);
+    let e = e.at_distance(2.0, 1.0).unwrap();
+    assert_abs_diff_eq!(e.irradiance(), 0.5, epsilon = 1E-12);
+    let e = e.on_tilted_plane(60.0).unwrap();
+    assert_abs_diff_eq!(e.irradiance(), 0.25, epsilon = 1E-12);
+    assert!(Illuminant::from_radiant_intensity(&intensity, 0.0, 0.0).is_err());
+    assert!(Illuminant::from_radiant_intensity(&intensity, 1.0, 91.0).is_err());
+}
+
+#[test]
+fn test_into_colorant(){
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+    let led = Illuminant::led(450.0, 20.0).set_irradiance(25.0);
+    let colorant = led.into_colorant().unwrap();
+    assert_abs_diff_eq!(colorant.0.0.max(), 1.0);
+    assert!(Illuminant::default().into_colorant().is_err());
+}
+
+#[test]
+fn test_gradient(){
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+    let red = Illuminant::led(620.0, 20.0).set_illuminance(&CIE1931, 100.0);
+    let blue = Illuminant::led(460.0, 20.0).set_illuminance(&CIE1931, 100.0);
+    let white = CIE1931.xyz_d65().set_illuminance(100.0);
+    for space in [GradientSpace::UvPrime, GradientSpace::CieLab, GradientSpace::OkLab] {
+        let fade = red.gradient(&blue, 9, space).unwrap();
+        assert_eq!(fade.len(), 9);
+        assert_abs_diff_eq!(fade[0].0, red.0, epsilon = 1E-12);
+        assert_abs_diff_eq!(fade[8].0, blue.0, epsilon = 1E-12);
+        let lab: Vec<CieLab> = fade.iter().map(|s| {
+            let xyz = CIE1931.xyz(s, None).xyzn;
+            CieLab::try_from(XYZ::from_vecs(white.xyzn, Some(xyz), Observer::Std1931)).unwrap()
+        }).collect();
+        let steps: Vec<f64> = lab.windows(2).map(|w| DeltaE::CIE76.delta_e(&w[0], &w[1]).unwrap()).collect();
+        let range = steps.iter().copied().fold(0.0, f64::max) - steps.iter().copied().fold(f64::MAX, f64::min);
+        if space == GradientSpace::CieLab {
+            assert!(range < 1E-2 * steps[0]);
+        }
+        fade.iter().for_each(|s| assert_abs_diff_eq!(s.illuminance(&CIE1931), 100.0, epsilon = 1E-9));
+    }
+    assert!(red.gradient(&blue, 1, GradientSpace::UvPrime).is_err());
+}
+
+fn check_distance(distance_m: f64) -> Result<(), CmtError> {
+    if distance_m > 0.0 && distance_m.is_finite() {
+        Ok(())
+    } else {
+        Err(CmtError::OutOfRange{name:"Distance".to_string(), low: 0.0, high: f64::INFINITY})
+    }
+}
+
+/// Cosine correction factor for light incident at an angle, in degrees, from the surface normal.
+/// Light incident at angles larger than 90º does not reach the surface.
+fn cosine_factor(angle_deg: f64) -> Result<f64, CmtError> {
+    if (0.0..=90.0).contains(&angle_deg) {
+        Ok(math::cos(angle_deg.to_radians()).max(0.0))
+    } else {
+        Err(CmtError::OutOfRange{name:"Incidence Angle".to_string(), low: 0.0, high: 90.0})
+    }
+}
+
 const CIE_D_S_LEN: usize = 81;
 
+/// Coefficients M1 and M2 of the S1 and S2 daylight S-vectors, for a CIE D illuminant with a
+/// correlated color temperature `cct`.
+pub(crate) fn d_illuminant_coefficients(cct: f64) -> Result<[f64; 2], CmtError> {
+    let [xd, yd] = crate::daylight::daylight_chromaticity(cct)?;
+    let m = 0.0241 + 0.2562 * xd - 0.7341 * yd;
+    let m1 = (-1.3515 - 1.7703 * xd + 5.9114 * yd) / m;
+    let m2 = (0.03 - 31.4424 * xd + 30.0717 * yd) / m;
+    Ok([m1, m2])
+}
+
+/// CIE daylight S-vectors, interpolated to the library's 1 nanometer spectral domain.
+pub(crate) static CIE_D_S_NS: LazyLock<[Spectrum; 3]> = LazyLock::new(|| {
+    std::array::from_fn(|j| {
+        let v: Vec<f64> = CIE_D_S.column(j).iter().copied().collect();
+        Spectrum::try_linear_interpolate(&[380.0, 780.0], &v).unwrap()
+    })
+});
+
 static CIE_D_S: SMatrix::<f64, CIE_D_S_LEN, 3> = SMatrix::from_array_storage(ArrayStorage([
     [63.40, 64.60, 65.80, 80.30, 94.80, 99.80, 104.80, 105.35, 105.90, 101.35, 96.80, 105.35, 113.90, 119.75, 125.60, 125.55, 125.50, 123.40, 121.30, 121.30,
     121.30, 117.40, 113.50, 113.30, 113.10, 111.95, 110.80, 108.65, 106.50, 107.65, 108.80, 107.05, 105.30, 104.85, 104.40, 102.20, 100.00, 98.00, 96.00, 95.55,
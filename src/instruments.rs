@@ -0,0 +1,262 @@
+/*!
+# Spectroradiometer Data Import
+
+Importers for the export formats of common spectroradiometers, producing calibrated spectral
+irradiance distributions as [`Illuminant`]s, together with the metadata found in the files.
+
+Supported formats are:
+- JETI spectroradiometer `.csv` exports, such as produced by JETI LiVal and JETI Spectrum,
+  in both their semicolon and comma separated variants;
+- Konica Minolta CL-500A `.csv` exports, as produced by its CL-S10w software;
+- Ocean Insight OceanView tab separated `.txt` exports.
+
+The spectral values are converted into units of W/m<sup>2</sup>/nm, where the unit is given in
+the file. Values in the files are resampled to the 380 to 780 nanometer domain, with 1
+nanometer steps, as used by this library, using linear interpolation.
+*/
+
+use crate::{error::CmtError, illuminant::Illuminant, spectral_data::SpectralData, spectrum::Spectrum, stray_light::StrayLightCorrection};
+
+/// Export formats of spectroradiometers, supported by [`SpectralMeasurement::from_instrument`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, strum_macros::Display, strum_macros::EnumIter)]
+pub enum InstrumentFormat {
+    Jeti,
+    KonicaMinoltaCl500a,
+    OceanView,
+}
+
+/// A spectral irradiance measurement, with its instrument's metadata, such as measurement
+/// date, integration time, and instrument serial number.
+#[derive(Clone)]
+pub struct SpectralMeasurement {
+    illuminant: Illuminant,
+    metadata: Vec<(String, String)>,
+}
+
+impl SpectralMeasurement {
+
+    /// Parses an instrument export file.
+    pub fn from_instrument(format: InstrumentFormat, s: &str) -> Result<Self, CmtError> {
+        match format {
+            InstrumentFormat::Jeti => Self::from_jeti(s),
+            InstrumentFormat::KonicaMinoltaCl500a => Self::from_cl500a(s),
+            InstrumentFormat::OceanView => Self::from_oceanview(s),
+        }
+    }
+
+    /**
+    Parses a JETI spectroradiometer CSV export.
+
+    Lines with a wavelength and a spectral value are taken as spectral data; all other lines with a
+    label, and a value, are taken as metadata. Semicolon separated files can use a decimal comma.
+    ```
+    use colorimetry::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    let csv = "Device;specbos 1211
+    Integration time [ms];125,0
+    Wavelength [nm];Spectral irradiance [W/(m²*nm)]
+    380;0,01
+    580;0,02
+    780;0,03
+    ";
+    let m = SpectralMeasurement::from_jeti(csv).unwrap();
+    assert_eq!(m.metadata("Device"), Some("specbos 1211"));
+    assert_abs_diff_eq!(m.illuminant()[480], 0.015, epsilon = 1E-12);
+    ```
+    */
+    pub fn from_jeti(s: &str) -> Result<Self, CmtError> {
+        let mut metadata = Vec::new();
+        let mut data = Vec::new();
+        let mut scale = 1.0;
+        for line in s.lines().map(str::trim).filter(|l| !l.is_empty()) {
+            let fields = split_fields(line);
+            match (fields.first().and_then(|f| parse_number(f)), fields.get(1).and_then(|f| parse_number(f))) {
+                (Some(wl), Some(v)) if fields.len() == 2 => data.push((wl, v)),
+                _ if fields.len() >= 2 => {
+                    if fields[0].to_lowercase().starts_with("wavelength") {
+                        scale = unit_scale(fields[1]);
+                    }
+                    metadata.push((fields[0].to_string(), fields[1..].join(" ")));
+                }
+                _ => {}
+            }
+        }
+        Self::try_new(data, scale, metadata)
+    }
+
+    /**
+    Parses a Konica Minolta CL-500A CSV export, as produced by the CL-S10w software.
+
+    Spectral data rows are labeled with their wavelength, as for example "380nm", and use the
+    first value column; all other labeled rows are taken as metadata, such as "Ev", "Tcp", or
+    "Date".
+    */
+    pub fn from_cl500a(s: &str) -> Result<Self, CmtError> {
+        let mut metadata = Vec::new();
+        let mut data = Vec::new();
+        for line in s.lines().map(str::trim).filter(|l| !l.is_empty()) {
+            let fields = split_fields(line);
+            let Some(label) = fields.first() else { continue };
+            let wl = label.trim().strip_suffix("nm").and_then(parse_number);
+            match (wl, fields.get(1).and_then(|f| parse_number(f))) {
+                (Some(wl), Some(v)) => data.push((wl, v)),
+                _ if fields.len() >= 2 => metadata.push((label.to_string(), fields[1].to_string())),
+                _ => {}
+            }
+        }
+        Self::try_new(data, 1.0, metadata)
+    }
+
+    /**
+    Parses an Ocean Insight OceanView text export.
+
+    Header lines, in the form "Key: value", are taken as metadata; the spectral data follow the
+    ">>>>>Begin Spectral Data<<<<<" marker. Absolute irradiance data in units of
+    μW/cm<sup>2</sup>/nm, as indicated in the file's header, are converted to W/m<sup>2</sup>/nm.
+    Other data, such as raw counts, are used as is.
+    */
+    pub fn from_oceanview(s: &str) -> Result<Self, CmtError> {
+        let mut metadata = Vec::new();
+        let mut data = Vec::new();
+        let mut in_data = false;
+        let mut scale = 1.0;
+        for line in s.lines().map(str::trim).filter(|l| !l.is_empty()) {
+            if in_data {
+                let fields = split_fields(line);
+                if let (Some(wl), Some(v)) = (fields.first().and_then(|f| parse_number(f)), fields.get(1).and_then(|f| parse_number(f))) {
+                    data.push((wl, v));
+                }
+            } else if line.starts_with(">>>>>Begin") {
+                in_data = true;
+            } else {
+                if line.contains("uW/cm") || line.contains("µW/cm") || line.contains("μW/cm") {
+                    scale = 1E-2;
+                }
+                if let Some((k, v)) = line.split_once(':') {
+                    metadata.push((k.trim().to_string(), v.trim().to_string()));
+                }
+            }
+        }
+        Self::try_new(data, scale, metadata)
+    }
+
+    fn try_new(data: Vec<(f64, f64)>, scale: f64, metadata: Vec<(String, String)>) -> Result<Self, CmtError> {
+        if data.len() < 3 {
+            return Err(CmtError::ParseError("no spectral data found".into()));
+        }
+        let (wl, v): (Vec<f64>, Vec<f64>) = data.into_iter().map(|(w, v)| (w, v * scale)).unzip();
+        let spectrum = Spectrum::try_linear_interpolate(&wl, &v)?;
+        Ok(Self { illuminant: Illuminant(spectrum), metadata })
+    }
+
+    pub fn illuminant(&self) -> &Illuminant {
+        &self.illuminant
+    }
+
+    pub fn into_illuminant(self) -> Illuminant {
+        self.illuminant
+    }
+
+    /// The measured spectral irradiance, in W/m<sup>2</sup>/nm, as spectral data, for export.
+    pub fn spectral_data(&self) -> SpectralData {
+        SpectralData::from(&self.illuminant)
+    }
+
+    /// Value of a metadata field, by its label in the file.
+    pub fn metadata(&self, key: &str) -> Option<&str> {
+        self.metadata.iter().find(|(k, _)| k == key).map(|(_, v)| v.as_str())
+    }
+
+    /// All metadata fields, in the order of the file.
+    pub fn metadata_fields(&self) -> &[(String, String)] {
+        &self.metadata
+    }
+
+    /// This measurement, with its spectral irradiance corrected for the stray light of the
+    /// instrument.
+    pub fn correct_stray_light(mut self, correction: &StrayLightCorrection) -> Self {
+        self.illuminant.0 = correction.apply(&self.illuminant.0);
+        self
+    }
+}
+
+/// Splits a line into fields, using tabs, or semicolons, if present, or commas otherwise.
+fn split_fields(line: &str) -> Vec<&str> {
+    let sep = if line.contains('\t') {
+        '\t'
+    } else if line.contains(';') {
+        ';'
+    } else {
+        ','
+    };
+    line.split(sep).map(str::trim).filter(|f| !f.is_empty()).collect()
+}
+
+/// Parses a number, allowing a decimal comma.
+fn parse_number(s: &str) -> Option<f64> {
+    let s = s.trim();
+    s.parse().ok().or_else(|| s.replace(',', ".").parse().ok()).filter(|v: &f64| v.is_finite())
+}
+
+/// Scale factor to W/m²/nm, or W/sr/m²/nm, from a unit label.
+fn unit_scale(label: &str) -> f64 {
+    if label.contains("mW") {
+        1E-3
+    } else if label.contains("uW/cm") || label.contains("µW/cm") || label.contains("μW/cm") {
+        1E-2
+    } else {
+        1.0
+    }
+}
+
+#[cfg(test)]
+mod instruments_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_cl500a(){
+        let csv = "Date,2024/05/01 10:00:00
+Ev,512.3
+Tcp,4012
+360nm,0.001
+370nm,0.002
+380nm,0.003
+780nm,0.007
+";
+        let m = SpectralMeasurement::from_instrument(InstrumentFormat::KonicaMinoltaCl500a, csv).unwrap();
+        assert_eq!(m.metadata("Tcp"), Some("4012"));
+        assert_abs_diff_eq!(m.illuminant()[380], 0.003, epsilon = 1E-12);
+        assert_abs_diff_eq!(m.illuminant()[580], 0.005, epsilon = 1E-12);
+    }
+
+    #[test]
+    fn test_oceanview(){
+        let txt = "Data from irradiance.txt Node
+Date: Mon May 01 10:00:00 CEST 2024
+Integration Time (sec): 1.000000E-1
+Spectrometer: USB4F12345
+Absolute Irradiance (uW/cm^2/nm)
+>>>>>Begin Spectral Data<<<<<
+350.0\t10.0
+500.0\t20.0
+800.0\t30.0
+";
+        let m = SpectralMeasurement::from_instrument(InstrumentFormat::OceanView, txt).unwrap();
+        assert_eq!(m.metadata("Spectrometer"), Some("USB4F12345"));
+        assert_abs_diff_eq!(m.illuminant()[500], 0.2, epsilon = 1E-12);
+    }
+
+    #[test]
+    fn test_jeti_comma(){
+        let csv = "Wavelength [nm],Spectral irradiance [mW/(m²*nm)]
+380,10
+580,20
+780,30
+";
+        let m = SpectralMeasurement::from_jeti(csv).unwrap();
+        assert_abs_diff_eq!(m.illuminant()[780], 0.03, epsilon = 1E-12);
+        assert!(SpectralMeasurement::from_jeti("Device;specbos").is_err());
+    }
+}
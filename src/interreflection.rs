@@ -0,0 +1,181 @@
+/*!
+# Interreflections in a Room
+
+Light in a room reaches a surface not only directly from the light source, but also after one or
+more reflections from the walls, ceiling, floor, and furnishings; with strongly colored surfaces,
+this interreflected light is colored too, and the ambient light in the room differs noticeably
+from the light of the source — the reason a room with green walls can look green all over.
+
+A [`Room`] estimates the ambient light with a simple radiosity model, treating the room as an
+integrating sphere: the surfaces, with their spectral reflectance factors, and areas, are combined
+into an area weighted average reflectance ρ̄(λ), and each bounce multiplies the direct irradiance
+E(λ) by it, resulting in an ambient irradiance
+E(λ) · (1 + ρ̄(λ) + ρ̄(λ)² + …) = E(λ) / (1 - ρ̄(λ)), for an infinite number of bounces.
+This ignores the geometry of the room, the position of the source, and any light leaving the room,
+and is intended for quick estimates of the color shifts, and not for lighting design.
+
+[`Room::appearance`] compares the ambient light with the light of the source, and the colors of
+the room's surfaces under both, for an observer adapted to the light source.
+```
+use colorimetry::prelude::*;
+
+let room = Room::new()
+    .add_surface(Colorant::gaussian(530.0, 40.0) * 0.7, 40.0) // green walls
+    .add_surface(Colorant::gray(0.8), 20.0); // white ceiling
+
+let appearance = room.appearance(&Illuminant::d65(), &CIE1931, None).unwrap();
+assert!(appearance.ambient_xy[1] > appearance.light_xy[1]); // greener
+assert!(appearance.illuminance_factor > 2.0);
+```
+*/
+
+use crate::{
+    colorant::Colorant,
+    error::CmtError,
+    illuminant::Illuminant,
+    lab::CieLab,
+    observer::ObserverData,
+    spectrum::Spectrum,
+    xyz::XYZ,
+};
+
+/// A room, as a collection of surfaces, with their spectral reflectance factors, and areas.
+#[derive(Clone, Debug, Default)]
+pub struct Room {
+    surfaces: Vec<(Colorant, f64)>,
+}
+
+/// The color of a surface, in the light of the source, and in the ambient light of a room.
+#[derive(Clone, Debug)]
+pub struct SurfaceShift {
+    /// CIELAB values in the light of the source.
+    pub direct: CieLab,
+    /// CIELAB values in the ambient light, with the white of the light source as reference white.
+    pub ambient: CieLab,
+    /// CIEDE2000 color difference between the two.
+    pub delta_e: f64,
+}
+
+/// The outcome of [`Room::appearance`].
+#[derive(Clone, Debug)]
+pub struct RoomAppearance {
+    /// Chromaticity of the light source.
+    pub light_xy: [f64; 2],
+    /// Chromaticity of the ambient light.
+    pub ambient_xy: [f64; 2],
+    /// Distance in the CIE 1976 u'v' chromaticity diagram between the two.
+    pub uv_shift: f64,
+    /// Ratio of the ambient illuminance, and the direct illuminance.
+    pub illuminance_factor: f64,
+    /// Color shifts of the surfaces, in the order they were added.
+    pub surfaces: Vec<SurfaceShift>,
+}
+
+impl Room {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a surface, with its spectral reflectance factor, and area, in any unit.
+    pub fn add_surface(mut self, colorant: Colorant, area: f64) -> Self {
+        self.surfaces.push((colorant, area));
+        self
+    }
+
+    /// The surfaces of the room, with their areas.
+    pub fn surfaces(&self) -> &[(Colorant, f64)] {
+        &self.surfaces
+    }
+
+    /// Area weighted average spectral reflectance factor of the surfaces.
+    pub fn average_reflectance(&self) -> Result<Colorant, CmtError> {
+        if self.surfaces.is_empty() {
+            return Err(CmtError::ErrorString("Room: requires at least one surface".into()));
+        }
+        if self.surfaces.iter().any(|(_, area)| !(area.is_finite() && *area > 0.0)) {
+            return Err(CmtError::ErrorString("Room: surface areas should be positive".into()));
+        }
+        let total: f64 = self.surfaces.iter().map(|(_, area)| area).sum();
+        let data = self.surfaces.iter().fold(Spectrum::default().0, |acc, (colorant, area)| acc + colorant.0 .0 * (area / total));
+        Ok(Colorant(Spectrum(data)))
+    }
+
+    /// The ambient spectral irradiance in the room, for the direct irradiance of a light source,
+    /// after a number of bounces, or an infinite number for `None`.
+    pub fn ambient(&self, light: &Illuminant, bounces: Option<usize>) -> Result<Illuminant, CmtError> {
+        let rho = self.average_reflectance()?.0 .0;
+        let gain = match bounces {
+            Some(n) => rho.map(|r| (0..=n as i32).map(|k| r.powi(k)).sum::<f64>()),
+            None => {
+                if rho.iter().any(|&r| r >= 1.0) {
+                    return Err(CmtError::ErrorString("Room: average reflectance should be less than one".into()));
+                }
+                rho.map(|r| 1.0 / (1.0 - r))
+            }
+        };
+        Ok(Illuminant(Spectrum(light.0 .0.component_mul(&gain))))
+    }
+
+    /// Compares the ambient light in the room with the light of the source, and the colors of the
+    /// surfaces under both, for an observer adapted to the light source.
+    pub fn appearance(&self, light: &Illuminant, observer: &ObserverData, bounces: Option<usize>) -> Result<RoomAppearance, CmtError> {
+        let ambient = self.ambient(light, bounces)?;
+        let direct_white = observer.xyz_from_spectrum(&light.0, None);
+        let ambient_white = observer.xyz_from_spectrum(&ambient.0, None);
+        let illuminance_factor = ambient_white.xyzn.y / direct_white.xyzn.y;
+        let white = observer.xyz(light, None);
+        let surfaces = self
+            .surfaces
+            .iter()
+            .map(|(colorant, _)| {
+                let direct = CieLab::try_from(observer.xyz(light, Some(colorant)))?;
+                // tristimulus values in the ambient light, at the luminous level of the source
+                let xyz = observer.xyz(&ambient, Some(colorant)).xyz.ok_or(CmtError::NoColorant)?;
+                let ambient = CieLab::try_from(XYZ::from_vecs(white.xyzn, Some(xyz), white.observer))?;
+                let delta_e = direct.de2000(&ambient)?;
+                Ok(SurfaceShift { direct, ambient, delta_e })
+            })
+            .collect::<Result<Vec<_>, CmtError>>()?;
+        Ok(RoomAppearance {
+            light_xy: direct_white.xy().values(),
+            ambient_xy: ambient_white.xy().values(),
+            uv_shift: direct_white.uv_prime_distance(&ambient_white),
+            illuminance_factor,
+            surfaces,
+        })
+    }
+}
+
+#[cfg(test)]
+mod interreflection_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_room() {
+        // neutral surfaces: no color shift, and an integrating sphere gain of 1 / (1 - 0.5)
+        let gray = Room::new().add_surface(Colorant::gray(0.4), 1.0).add_surface(Colorant::gray(0.6), 1.0);
+        let appearance = gray.appearance(&Illuminant::d65(), &CIE1931, None).unwrap();
+        assert_abs_diff_eq!(appearance.illuminance_factor, 2.0, epsilon = 1E-9);
+        assert_abs_diff_eq!(appearance.uv_shift, 0.0, epsilon = 1E-9);
+        assert!(appearance.surfaces.iter().all(|s| s.delta_e < 1E-6));
+
+        // a finite number of bounces: 1 + 0.5 + 0.25
+        let ambient = gray.ambient(&Illuminant::d65(), Some(2)).unwrap();
+        assert_abs_diff_eq!(ambient.0[550] / Illuminant::d65().0[550], 1.75, epsilon = 1E-9);
+
+        // green walls make the ambient light greener, and shift the white ceiling most
+        let green = Room::new()
+            .add_surface(Colorant::gaussian(530.0, 40.0) * 0.7, 40.0)
+            .add_surface(Colorant::gray(0.8), 20.0);
+        let appearance = green.appearance(&Illuminant::d65(), &CIE1931, Some(3)).unwrap();
+        assert!(appearance.uv_shift > 0.01);
+        let [_, a, _] = appearance.surfaces[1].ambient.values();
+        assert!(a < -5.0);
+        assert!(appearance.surfaces[1].delta_e > 5.0);
+
+        assert!(Room::new().appearance(&Illuminant::d65(), &CIE1931, None).is_err());
+        assert!(Room::new().add_surface(Colorant::white(), 1.0).ambient(&Illuminant::d65(), None).is_err());
+        assert!(Room::new().add_surface(Colorant::gray(0.5), -1.0).average_reflectance().is_err());
+    }
+}
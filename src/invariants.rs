@@ -0,0 +1,147 @@
+/*!
+# Invariants
+
+Checks of properties which any correct combination of observer, color space, and calculation
+method in this library should satisfy, such as a color rendering index of 100 for any Planckian
+radiator, the linearity of tristimulus values, and RGB to XYZ round trips.
+
+They are used in this library's own tests, and can be used to verify extensions, such as added
+observers, color spaces, or spectral data, and to check builds on new targets.
+Each check returns an [`CmtError::InvariantViolation`] error, with the largest deviation found,
+if it exceeds the given tolerance.
+```
+use colorimetry::prelude::*;
+
+for space in [RgbSpace::SRGB, RgbSpace::ADOBE, RgbSpace::DisplayP3] {
+    check_rgb_roundtrip(&CIE1931, space, 1E-10).unwrap();
+    check_white_point(&CIE1931, space, 1E-6).unwrap();
+}
+check_xyz_linearity(&CIE1931, 1E-12).unwrap();
+```
+*/
+
+use crate::{
+    colorant::Colorant,
+    error::CmtError,
+    illuminant::Illuminant,
+    observer::ObserverData,
+    rgb::RGB,
+    rgbspace::RgbSpace,
+};
+
+/// Returns an error if a deviation exceeds the tolerance.
+fn verify(check: &str, deviation: f64, tolerance: f64) -> Result<(), CmtError> {
+    if deviation.is_nan() || deviation > tolerance {
+        Err(CmtError::InvariantViolation { check: check.to_string(), deviation, tolerance })
+    } else {
+        Ok(())
+    }
+}
+
+/**
+The general color rendering index of Planckian radiators, with correlated color temperatures
+from 2000 to 4900 kelvin, should be 100, as these are their own reference illuminants.
+The tolerance applies to the deviation of Ra from 100.
+*/
+#[cfg(feature = "cri")]
+pub fn check_planckian_cri(observer: &ObserverData, tolerance: f64) -> Result<(), CmtError> {
+    use crate::cri::CRI;
+    let mut deviation = 0.0f64;
+    for cct in [2000.0, 2700.0, 3000.0, 4000.0, 4900.0] {
+        let cri = CRI::try_new_with_observer(&Illuminant::planckian(cct), observer)?;
+        deviation = deviation.max((cri.ra() - 100.0).abs());
+    }
+    verify("Planckian CRI", deviation, tolerance)
+}
+
+/**
+Tristimulus values are linear in the spectral distribution: the values of a weighted sum of
+spectra should equal the weighted sum of their values.
+The tolerance applies to the deviation relative to the luminous value of the sum.
+*/
+pub fn check_xyz_linearity(observer: &ObserverData, tolerance: f64) -> Result<(), CmtError> {
+    let spectra = [
+        *Illuminant::planckian(3000.0),
+        *Illuminant::led(450.0, 20.0),
+        *Illuminant::d65() * *Colorant::gaussian(600.0, 40.0),
+    ];
+    let mut deviation = 0.0f64;
+    for (a, b) in [(1.0, 1.0), (0.3, 2.5), (1E3, 1E-3)] {
+        for i in 0..spectra.len() {
+            let j = (i + 1) % spectra.len();
+            let sum = spectra[i] * a + spectra[j] * b;
+            let xyz = observer.xyz_from_spectrum(&sum, None).xyzn;
+            let want = observer.xyz_from_spectrum(&spectra[i], None).xyzn * a
+                + observer.xyz_from_spectrum(&spectra[j], None).xyzn * b;
+            deviation = deviation.max((xyz - want).abs().max() / want.y);
+        }
+    }
+    verify("XYZ linearity", deviation, tolerance)
+}
+
+/**
+RGB values, converted to tristimulus values, and back, should be unchanged, for a grid of values
+in, and out of, the gamut of a color space.
+The tolerance applies to the largest absolute deviation of the RGB values.
+*/
+pub fn check_rgb_roundtrip(observer: &ObserverData, space: RgbSpace, tolerance: f64) -> Result<(), CmtError> {
+    if !observer.primaries_xyz(&space).lu().is_invertible() {
+        return Err(CmtError::CouldNotInvertRGBMatrix);
+    }
+    let values = [-0.2, 0.0, 0.25, 0.5, 0.75, 1.0, 1.2];
+    let mut deviation = 0.0f64;
+    for r in values {
+        for g in values {
+            for b in values {
+                let rgb = RGB::new(r, g, b, Some(observer.tag), Some(space));
+                let back = rgb.xyz().rgb(Some(space));
+                deviation = deviation.max((back.rgb - rgb.rgb).abs().max());
+            }
+        }
+    }
+    verify("RGB roundtrip", deviation, tolerance)
+}
+
+/**
+The white of a color space, with all RGB values equal to one, should have the chromaticity of the
+color space's white point.
+The tolerance applies to the largest deviation of the chromaticity coordinates.
+*/
+pub fn check_white_point(observer: &ObserverData, space: RgbSpace, tolerance: f64) -> Result<(), CmtError> {
+    if !observer.primaries_xyz(&space).lu().is_invertible() {
+        return Err(CmtError::CouldNotInvertRGBMatrix);
+    }
+    let white = RGB::new(1.0, 1.0, 1.0, Some(observer.tag), Some(space)).xyz();
+    let want = observer.xyz_from_spectrum(space.data().0.white.illuminant(), None);
+    let [x, y] = white.xy().values();
+    let [xw, yw] = want.xy().values();
+    verify("RGB white point", (x - xw).abs().max((y - yw).abs()), tolerance)
+}
+
+#[cfg(test)]
+mod invariants_test {
+    use crate::error::CmtError;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_invariants() {
+        #[allow(unused_mut)]
+        let mut observers = vec![&CIE1931];
+        #[cfg(feature = "supplemental-observers")]
+        observers.extend([&CIE1964, &CIE2015]);
+        for observer in observers {
+            check_xyz_linearity(observer, 1E-12).unwrap();
+            for space in [RgbSpace::SRGB, RgbSpace::ADOBE, RgbSpace::DisplayP3] {
+                check_rgb_roundtrip(observer, space, 1E-10).unwrap();
+                check_white_point(observer, space, 1E-9).unwrap();
+            }
+            #[cfg(feature = "cri")]
+            check_planckian_cri(observer, 1E-3).unwrap();
+        }
+
+        assert!(matches!(
+            check_xyz_linearity(&CIE1931, -1.0),
+            Err(CmtError::InvariantViolation { .. })
+        ));
+    }
+}
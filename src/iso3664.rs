@@ -0,0 +1,199 @@
+/*!
+# ISO 3664 Viewing Conditions
+
+ISO 3664:2009 specifies the viewing conditions for the critical comparison of prints, and
+transparencies, in the graphic technology and photography industries, in viewing booths with a
+simulation of the CIE D50 illuminant.
+
+An [`Iso3664Check`] evaluates a measured spectral irradiance against the requirements of the P1,
+critical comparison, and P2, practical appraisal, conditions for prints, and returns a
+[`ConformanceReport`], with a [`Criterion`] for each requirement:
+- the chromaticity, within a radius of 0.005 in the CIE 1976 u'v' diagram from D50;
+- the illuminance, 2000 ± 500 lux for P1, and 500 ± 125 lux for P2;
+- the general color rendering index, Ra, of at least 90, and the special indices R1 to R8 of at
+  least 80;
+- the visible, and ultraviolet, metamerism indices, CIE 51.2, of at most 1.0, and 1.5;
+- the illuminance uniformity, as ratio of the minimum, and the maximum, illuminance over the
+  viewing area, of at least 0.75.
+
+The metamerism indices, and the uniformity, are not derived from a single spectral measurement,
+and are set as inputs; requirements without an input are reported as not evaluated.
+The correlated color temperature, and the ultraviolet content relative to D50, are reported for
+information.
+*/
+
+use crate::{
+    cri::CRI,
+    data::{illuminants::D50, observers::CIE1931},
+    error::CmtError,
+    illuminant::Illuminant,
+};
+
+/// ISO 3664 viewing condition for reflective prints.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, strum_macros::Display)]
+pub enum Iso3664Condition {
+    /// Critical comparison.
+    #[default]
+    P1,
+    /// Practical appraisal.
+    P2,
+}
+
+impl Iso3664Condition {
+    /// Target illuminance, and tolerance, in lux.
+    pub fn illuminance(&self) -> (f64, f64) {
+        match self {
+            Iso3664Condition::P1 => (2000.0, 500.0),
+            Iso3664Condition::P2 => (500.0, 125.0),
+        }
+    }
+}
+
+/// A requirement, with the evaluated value, and its outcome.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Criterion {
+    pub name: String,
+    /// Value of the quantity, or NaN if it has not been evaluated.
+    pub value: f64,
+    pub requirement: String,
+    /// Outcome, or None for criteria without an input, and informative values.
+    pub pass: Option<bool>,
+}
+
+/// The outcome of an [`Iso3664Check`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConformanceReport {
+    pub condition: Iso3664Condition,
+    pub criteria: Vec<Criterion>,
+}
+
+impl ConformanceReport {
+    /// Checks if no evaluated criterion fails.
+    pub fn passes(&self) -> bool {
+        self.criteria.iter().all(|c| c.pass != Some(false))
+    }
+
+    /// Checks if all requirements have been evaluated, and pass.
+    pub fn conforms(&self) -> bool {
+        self.criteria.iter().filter(|c| !c.requirement.is_empty()).all(|c| c.pass == Some(true))
+    }
+
+    /// A criterion, by name.
+    pub fn criterion(&self, name: &str) -> Option<&Criterion> {
+        self.criteria.iter().find(|c| c.name == name)
+    }
+}
+
+/**
+Checker for the ISO 3664 viewing conditions for prints.
+```
+use colorimetry::prelude::*;
+
+// a D50 simulator at 2000 lux
+let booth = Illuminant::d50().set_illuminance(&CIE1931, 2000.0);
+let report = Iso3664Check::new(Iso3664Condition::P1)
+    .set_metamerism_index(0.4, 0.9)
+    .set_uniformity(0.85)
+    .evaluate(&booth)
+    .unwrap();
+assert!(report.conforms());
+
+// not bright enough for P1, but without a metamerism test
+let dim = Iso3664Check::new(Iso3664Condition::P1).evaluate(&Illuminant::d50().set_illuminance(&CIE1931, 500.0)).unwrap();
+assert!(!dim.passes());
+assert_eq!(dim.criterion("Illuminance").unwrap().pass, Some(false));
+assert_eq!(dim.criterion("MI visible").unwrap().pass, None);
+```
+*/
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Iso3664Check {
+    condition: Iso3664Condition,
+    metamerism_index: Option<(f64, f64)>,
+    uniformity: Option<f64>,
+}
+
+impl Iso3664Check {
+    pub fn new(condition: Iso3664Condition) -> Self {
+        Self { condition, ..Default::default() }
+    }
+
+    /// Sets the visible, and the ultraviolet, metamerism index, according to CIE 51.2.
+    pub fn set_metamerism_index(mut self, visible: f64, uv: f64) -> Self {
+        self.metamerism_index = Some((visible, uv));
+        self
+    }
+
+    /// Sets the illuminance uniformity, as the ratio of the minimum, and maximum, illuminance
+    /// over the viewing area.
+    pub fn set_uniformity(mut self, ratio: f64) -> Self {
+        self.uniformity = Some(ratio);
+        self
+    }
+
+    /// Evaluates a spectral irradiance, in W/m²/nm, measured at the viewing plane.
+    pub fn evaluate(&self, illuminant: &Illuminant) -> Result<ConformanceReport, CmtError> {
+        let criterion = |name: &str, value: f64, requirement: &str, pass: Option<bool>| Criterion {
+            name: name.to_string(),
+            value,
+            requirement: requirement.to_string(),
+            pass,
+        };
+        let xyz = CIE1931.xyz(illuminant, None);
+        let duv = xyz.uv_prime_distance(&CIE1931.xyz(&D50, None));
+        let illuminance = illuminant.illuminance(&CIE1931);
+        let (target, tolerance) = self.condition.illuminance();
+        let cri = CRI::try_new(illuminant)?;
+        let ri_min = (0..8).map(|i| cri[i]).fold(f64::INFINITY, f64::min);
+
+        let mut criteria = vec![
+            criterion("Chromaticity Δu'v'", duv, "≤ 0.005 from D50", Some(duv <= 0.005)),
+            criterion(
+                "Illuminance",
+                illuminance,
+                &format!("{target} ± {tolerance} lx"),
+                Some((illuminance - target).abs() <= tolerance),
+            ),
+            criterion("Ra", cri.ra(), "≥ 90", Some(cri.ra() >= 90.0)),
+            criterion("R1-R8 minimum", ri_min, "≥ 80", Some(ri_min >= 80.0)),
+        ];
+        let (mi_vis, mi_uv) = self.metamerism_index.unwrap_or((f64::NAN, f64::NAN));
+        criteria.push(criterion("MI visible", mi_vis, "≤ 1.0", self.metamerism_index.map(|_| mi_vis <= 1.0)));
+        criteria.push(criterion("MI UV", mi_uv, "≤ 1.5", self.metamerism_index.map(|_| mi_uv <= 1.5)));
+        let uniformity = self.uniformity.unwrap_or(f64::NAN);
+        criteria.push(criterion("Uniformity", uniformity, "≥ 0.75", self.uniformity.map(|u| u >= 0.75)));
+
+        // informative
+        criteria.push(criterion("CCT", xyz.cct().map(|c| c.t()).unwrap_or(f64::NAN), "", None));
+        criteria.push(criterion("UV content relative to D50", illuminant.uv_content().relative_d50, "", None));
+        Ok(ConformanceReport { condition: self.condition, criteria })
+    }
+}
+
+#[cfg(test)]
+mod iso3664_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_iso3664() {
+        let check = Iso3664Check::new(Iso3664Condition::P2).set_metamerism_index(0.4, 0.9).set_uniformity(0.8);
+        let d50 = Illuminant::d50().set_illuminance(&CIE1931, 500.0);
+        let report = check.evaluate(&d50).unwrap();
+        assert!(report.conforms());
+        assert_abs_diff_eq!(report.criterion("Chromaticity Δu'v'").unwrap().value, 0.0, epsilon = 1E-12);
+        assert_abs_diff_eq!(report.criterion("CCT").unwrap().value, 5003.0, epsilon = 5.0);
+
+        // a warm white LED fails on chromaticity, and color rendering
+        #[cfg(feature = "cie-illuminants")]
+        {
+            let led = StdIlluminant::LED_B1.illuminant().clone().set_illuminance(&CIE1931, 500.0);
+            let report = check.evaluate(&led).unwrap();
+            assert_eq!(report.criterion("Chromaticity Δu'v'").unwrap().pass, Some(false));
+            assert!(!report.passes());
+        }
+
+        let incomplete = Iso3664Check::new(Iso3664Condition::P2).evaluate(&d50).unwrap();
+        assert!(incomplete.passes() && !incomplete.conforms());
+        assert!(!check.set_uniformity(0.5).evaluate(&d50).unwrap().passes());
+    }
+}
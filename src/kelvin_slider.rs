@@ -0,0 +1,193 @@
+/*!
+# Color Temperature Sliders
+
+Applications with a "warmth" control, such as night modes, white balance, and smart lighting user
+interfaces, map a slider position to a color temperature, and display it as an RGB color.
+A [`KelvinSlider`] does this colorimetrically: the slider position is mapped to a color
+temperature in equal steps of reciprocal temperature, in mired, which are perceptually more
+uniform than equal steps in Kelvin, and each temperature is converted to display RGB values from
+the spectral distribution of a Planckian radiator, with the tristimulus values for an observer,
+and mapped into the gamut of an RGB color space, with the largest channel set to its full value.
+
+The RGB values for the slider are calculated on first use, in a table with 256 steps, and
+interpolated, so a slider can be updated at interactive rates.
+```
+use colorimetry::prelude::*;
+
+let slider = KelvinSlider::new(RgbSpace::SRGB).set_range(1500.0, 10000.0);
+let warm: [u8; 3] = slider.rgb(0.0).into();
+let cool: [u8; 3] = slider.rgb(1.0).into();
+assert!(warm[0] == 255 && warm[2] < 100);
+assert!(cool[2] == 255 && cool[0] < 255);
+
+// presets, for common light sources
+let candle: [u8; 3] = slider.rgb_for_cct(KelvinPreset::Candle.cct()).into();
+assert_eq!(candle[0], 255);
+```
+*/
+
+use std::sync::OnceLock;
+
+use nalgebra::Vector3;
+
+use crate::{
+    illuminant::Illuminant,
+    observer::Observer,
+    rgb::{GamutMapping, RGB},
+    rgbspace::RgbSpace,
+};
+
+/// Number of slider positions in the buffered table of RGB values.
+const TABLE_STEPS: usize = 256;
+
+/// Nominal color temperatures of common light sources, for use as slider presets.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, strum_macros::Display, strum_macros::EnumIter)]
+pub enum KelvinPreset {
+    /// Candle flame, 1900 K.
+    Candle,
+    /// Incandescent lamp, 2700 K.
+    Incandescent,
+    /// Warm white fluorescent, and LED, lamps, 3000 K.
+    WarmWhite,
+    /// Neutral white lamps, 4000 K.
+    NeutralWhite,
+    /// Cool white lamps, 5000 K.
+    CoolWhite,
+    /// Average daylight, 6500 K.
+    Daylight,
+    /// Overcast sky, 7500 K.
+    Overcast,
+    /// Clear blue sky, 10000 K.
+    BlueSky,
+}
+
+impl KelvinPreset {
+    /// Nominal color temperature, in Kelvin.
+    pub fn cct(&self) -> f64 {
+        match self {
+            KelvinPreset::Candle => 1900.0,
+            KelvinPreset::Incandescent => 2700.0,
+            KelvinPreset::WarmWhite => 3000.0,
+            KelvinPreset::NeutralWhite => 4000.0,
+            KelvinPreset::CoolWhite => 5000.0,
+            KelvinPreset::Daylight => 6500.0,
+            KelvinPreset::Overcast => 7500.0,
+            KelvinPreset::BlueSky => 10000.0,
+        }
+    }
+}
+
+/// Maps slider positions, from 0.0 to 1.0, to color temperatures, and display RGB values.
+#[derive(Clone, Debug)]
+pub struct KelvinSlider {
+    space: RgbSpace,
+    observer: Observer,
+    mapping: GamutMapping,
+    min_cct: f64,
+    max_cct: f64,
+    table: OnceLock<Vec<Vector3<f64>>>,
+}
+
+impl KelvinSlider {
+    /// A slider for an RGB color space, for the CIE 1931 observer, with the default gamut
+    /// mapping, and a range from 1500 to 10000 K.
+    pub fn new(space: RgbSpace) -> Self {
+        Self {
+            space,
+            observer: Observer::default(),
+            mapping: GamutMapping::default(),
+            min_cct: 1500.0,
+            max_cct: 10000.0,
+            table: OnceLock::new(),
+        }
+    }
+
+    /// Sets the color temperatures, in Kelvin, at the start, and the end, of the slider.
+    pub fn set_range(mut self, min_cct: f64, max_cct: f64) -> Self {
+        self.min_cct = min_cct;
+        self.max_cct = max_cct;
+        self.table = OnceLock::new();
+        self
+    }
+
+    pub fn set_observer(mut self, observer: Observer) -> Self {
+        self.observer = observer;
+        self.table = OnceLock::new();
+        self
+    }
+
+    pub fn set_gamut_mapping(mut self, mapping: GamutMapping) -> Self {
+        self.mapping = mapping;
+        self.table = OnceLock::new();
+        self
+    }
+
+    /// Color temperature for a slider position, from 0.0 to 1.0, interpolated linearly in mired.
+    pub fn cct(&self, position: f64) -> f64 {
+        let t = position.clamp(0.0, 1.0);
+        1.0 / ((1.0 - t) / self.min_cct + t / self.max_cct)
+    }
+
+    /// Slider position for a color temperature, clamped to the range of the slider.
+    pub fn position(&self, cct: f64) -> f64 {
+        ((1.0 / cct - 1.0 / self.min_cct) / (1.0 / self.max_cct - 1.0 / self.min_cct)).clamp(0.0, 1.0)
+    }
+
+    /// Linear RGB values for a Planckian radiator, mapped into the gamut of the color space, with
+    /// the largest channel set to one; not buffered.
+    pub fn rgb_for_cct(&self, cct: f64) -> RGB {
+        let xyz = self.observer.data().xyz_from_spectrum(&Illuminant::planckian(cct), None);
+        let mut rgb = xyz.rgb(Some(self.space));
+        rgb.rgb /= rgb.rgb.max();
+        let mut rgb = rgb.gamut_mapped(self.mapping);
+        rgb.rgb /= rgb.rgb.max();
+        rgb
+    }
+
+    /// Linear RGB values for a slider position, from 0.0 to 1.0, interpolated in a table which is
+    /// calculated on first use.
+    pub fn rgb(&self, position: f64) -> RGB {
+        let table = self.table.get_or_init(|| {
+            (0..TABLE_STEPS).map(|i| self.rgb_for_cct(self.cct(i as f64 / (TABLE_STEPS - 1) as f64)).rgb).collect()
+        });
+        let x = position.clamp(0.0, 1.0) * (TABLE_STEPS - 1) as f64;
+        let i = (x.floor() as usize).min(TABLE_STEPS - 2);
+        let f = x - i as f64;
+        let v = table[i] * (1.0 - f) + table[i + 1] * f;
+        RGB::new(v.x, v.y, v.z, Some(self.observer), Some(self.space))
+    }
+}
+
+#[cfg(test)]
+mod kelvin_slider_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn test_kelvin_slider() {
+        let slider = KelvinSlider::new(RgbSpace::SRGB).set_range(2000.0, 8000.0);
+        assert_abs_diff_eq!(slider.cct(0.0), 2000.0, epsilon = 1E-9);
+        assert_abs_diff_eq!(slider.cct(1.0), 8000.0, epsilon = 1E-9);
+        // halfway in mired: 312.5 mired
+        assert_abs_diff_eq!(slider.cct(0.5), 3200.0, epsilon = 1E-9);
+        assert_abs_diff_eq!(slider.position(3200.0), 0.5, epsilon = 1E-12);
+        assert_eq!(slider.position(20000.0), 1.0);
+
+        // the interpolated table values are close to direct calculations
+        for position in [0.0, 0.1234, 0.5, 0.987, 1.0] {
+            let direct = slider.rgb_for_cct(slider.cct(position));
+            let table = slider.rgb(position);
+            assert!(table.is_in_gamut());
+            assert_abs_diff_eq!(table.rgb.as_slice(), direct.rgb.as_slice(), epsilon = 1E-3);
+        }
+
+        // about 6500 K is a near neutral white in sRGB
+        let daylight = slider.rgb_for_cct(KelvinPreset::Daylight.cct());
+        assert!(daylight.rgb.iter().all(|&v| v > 0.9));
+
+        // red decreases, and blue increases, with temperature
+        let presets: Vec<RGB> = KelvinPreset::iter().map(|p| slider.rgb_for_cct(p.cct())).collect();
+        assert!(presets.windows(2).all(|w| w[0].rgb.x >= w[1].rgb.x && w[0].rgb.z <= w[1].rgb.z));
+    }
+}
@@ -0,0 +1,414 @@
+/*!
+# Kubelka-Munk Two-Flux Model
+
+The Kubelka-Munk theory describes the reflectance of turbid media, such as paints, inks, and
+plastics, by two diffuse light fluxes, travelling up and down in a layer, which are attenuated by
+absorption, with a coefficient K, and redirected by scattering, with a coefficient S.
+For an opaque layer, its internal reflectance R∞ depends only on the ratio K/S:
+
+K/S = (1 - R∞)² / 2R∞,
+
+and a translucent layer, of thickness X, over a background with a reflectance Rg, is described by
+the hyperbolic solution of Kubelka's equations.
+
+These reflectance values are internal values, below the surface of the medium.
+Measured reflectance factors include the reflection of light at the surface, which is taken into
+account by the [`Saunderson`] correction, with a coefficient k1 for the external reflection of the
+incident, collimated, light, and a coefficient k2 for the internal reflection of the diffuse light
+at the surface.
+```
+use colorimetry::prelude::*;
+
+// an opaque coating, characterized by a measurement including the specular reflection
+let measured = Colorant::gaussian(550.0, 60.0) * 0.8 + Colorant::gray(0.1);
+let layer = KmLayer::from_opaque(&measured, &Saunderson::default());
+// a thin layer of the coating, over a black background, is darker
+let thin = layer.reflectance(0.5, &Colorant::black(), &Saunderson::default());
+assert!(thin[550] < measured[550]);
+// and thick layers hide the background
+let thick = layer.reflectance(1E3, &Colorant::black(), &Saunderson::default());
+approx::assert_abs_diff_eq!(thick[550], measured[550], epsilon = 1E-4);
+```
+
+# Contrast Ratio and Hiding Power
+The opacity of a paint film is characterized by its contrast ratio, the ratio of the luminous
+reflectance factors Y of the film applied over a black and over a white backing, as in
+ISO 6504-3, and its hiding power, by the film thickness at which the contrast ratio reaches 0.98,
+or the corresponding spreading rate, the area covered by a liter of paint.
+```
+use colorimetry::prelude::*;
+
+let (k, s) = (Colorant::gray(0.02).spectrum().into_owned(), Colorant::gray(0.2).spectrum().into_owned());
+let layer = KmLayer::new(k, s);
+let sc = Saunderson::default();
+let (black, white) = (Colorant::gray(0.05), Colorant::gray(0.8));
+let thickness = layer.hiding_thickness(0.98, &black, &white, &sc).unwrap();
+let cr = contrast_ratio(&CIE1931, &StdIlluminant::D65,
+    &layer.reflectance(thickness, &black, &sc), &layer.reflectance(thickness, &white, &sc));
+approx::assert_abs_diff_eq!(cr, 0.98, epsilon = 1E-6);
+```
+
+# Colorant Mixing and Tinting Strength
+In the single-constant Kubelka-Munk model, used for the formulation of opaque paints, and inks,
+the K/S ratio of a mixture is the concentration weighted sum of the K/S ratios of its components.
+A [`KmMix`] predicts the reflectance of a mixture, from the measured reflectance factors of its
+opaque components, each applied at full strength, as masstone.
+
+The tinting strength of a colorant, relative to a standard, is compared in tints: mixtures of the
+colorant, and of the standard, with the same amount of a white base; [`tint_strength`] gives the
+ratio of their K/S values, at the wavelength of maximum absorption of the standard's tint.
+```
+use colorimetry::prelude::*;
+
+let (white, blue) = (Colorant::gray(0.9), Colorant::gaussian(450.0, 40.0) * 0.7 + Colorant::gray(0.05));
+let tint = KmMix::new(&[(white.clone(), 9.0), (blue.clone(), 1.0)]).colorant();
+assert!(tint[600] < white[600] && tint[600] > blue[600]);
+
+// twice as much blue in the same amount of white is a stronger tint
+let strong = KmMix::new(&[(white, 9.0), (blue, 2.0)]).colorant();
+assert!(tint_strength(&strong, &tint, &Saunderson::default()) > 1.5);
+```
+*/
+
+use nalgebra::SVector;
+
+use crate::{colorant::Colorant, data::observers::CIE1931, error::CmtError, math, observer::ObserverData, spectrum::{Spectrum, NS}, std_illuminants::StdIlluminant, traits::Light};
+
+/// Saunderson correction, relating internal reflectance values of a medium to measured reflectance
+/// factors.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Saunderson {
+    k1: f64,
+    k2: f64,
+    specular_included: bool,
+}
+
+impl Default for Saunderson {
+    /// Coefficients k1 = 0.04, and k2 = 0.6, as commonly used for a medium with a refractive index
+    /// of 1.5, and a measurement including the specular reflection.
+    fn default() -> Self {
+        Self { k1: 0.04, k2: 0.6, specular_included: true }
+    }
+}
+
+impl Saunderson {
+    /// Saunderson correction with a coefficient for the external reflection `k1`, and a
+    /// coefficient for the internal reflection `k2`, for a measurement including the specular
+    /// reflection.
+    pub fn new(k1: f64, k2: f64) -> Self {
+        Self { k1: k1.clamp(0.0, 1.0), k2: k2.clamp(0.0, 1.0), specular_included: true }
+    }
+
+    /// Sets if the measured reflectance factors include the specular reflection, as with an
+    /// integrating sphere with the specular port closed, or exclude it.
+    pub fn set_specular_included(mut self, included: bool) -> Self {
+        self.specular_included = included;
+        self
+    }
+
+    /// Measured reflectance factor, for an internal reflectance.
+    pub fn measured(&self, internal: f64) -> f64 {
+        let k1 = if self.specular_included { self.k1 } else { 0.0 };
+        k1 + (1.0 - self.k1) * (1.0 - self.k2) * internal / (1.0 - self.k2 * internal)
+    }
+
+    /// Internal reflectance, for a measured reflectance factor.
+    pub fn internal(&self, measured: f64) -> f64 {
+        let r = if self.specular_included { measured - self.k1 } else { measured };
+        (r / ((1.0 - self.k1) * (1.0 - self.k2) + self.k2 * r)).clamp(0.0, 1.0)
+    }
+
+    /// Measured reflectance factors, for a colorant with internal reflectance values.
+    pub fn apply(&self, internal: &Colorant) -> Colorant {
+        Colorant(Spectrum(internal.0 .0.map(|r| self.measured(r))))
+    }
+
+    /// Internal reflectance values, for a colorant with measured reflectance factors.
+    pub fn remove(&self, measured: &Colorant) -> Colorant {
+        Colorant(Spectrum(measured.0 .0.map(|r| self.internal(r))))
+    }
+}
+
+/// Absorption to scattering ratio K/S, of an opaque layer, from its internal reflectance.
+pub fn ks_from_reflectance(r_inf: f64) -> f64 {
+    let r = r_inf.clamp(1E-6, 1.0);
+    (1.0 - r).powi(2) / (2.0 * r)
+}
+
+/// Internal reflectance of an opaque layer, from its absorption to scattering ratio K/S.
+pub fn reflectance_from_ks(ks: f64) -> f64 {
+    let ks = ks.max(0.0);
+    1.0 + ks - (ks * ks + 2.0 * ks).sqrt()
+}
+
+/// Contrast ratio, as the ratio of the luminous reflectance factors of a film over a black, and
+/// over a white backing, for an observer, and a light source.
+pub fn contrast_ratio(observer: &ObserverData, light: &dyn Light, over_black: &Colorant, over_white: &Colorant) -> f64 {
+    observer.xyz(light, Some(over_black)).values()[1] / observer.xyz(light, Some(over_white)).values()[1]
+}
+
+/**
+Spreading rate, in square meters per liter of paint, for a dry film thickness in micrometers, and
+a volume solids fraction of the paint, from 0.0 to 1.0.
+```
+use colorimetry::prelude::*;
+
+// a dry film of 50 µm, from a paint with 40% volume solids
+assert_eq!(spreading_rate(50.0, 0.4), 8.0);
+```
+*/
+pub fn spreading_rate(dry_thickness_um: f64, volume_solids: f64) -> f64 {
+    1000.0 * volume_solids / dry_thickness_um
+}
+
+/// Relative tinting strength of a tint, as the ratio of its K/S value to that of a standard tint,
+/// at the wavelength of the maximum K/S value of the standard, from their measured reflectance
+/// factors.
+pub fn tint_strength(tint: &Colorant, standard: &Colorant, saunderson: &Saunderson) -> f64 {
+    let ks = |c: &Colorant| saunderson.remove(c).0 .0.map(ks_from_reflectance);
+    let ks_standard = ks(standard);
+    let i = ks_standard.imax();
+    ks(tint)[i] / ks_standard[i]
+}
+
+/**
+Mixture of opaque colorants, such as paints, and inks, in the single-constant Kubelka-Munk model.
+
+The components are given by the measured reflectance factors of their masstones, and their
+concentrations, in any unit, such as parts by weight.
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub struct KmMix {
+    components: Vec<(Colorant, f64)>,
+    saunderson: Saunderson,
+}
+
+impl KmMix {
+    /// Mixture of colorants, with their concentrations, using the default Saunderson correction.
+    pub fn new(components: &[(Colorant, f64)]) -> Self {
+        Self { components: components.to_vec(), saunderson: Saunderson::default() }
+    }
+
+    pub fn set_saunderson(mut self, saunderson: Saunderson) -> Self {
+        self.saunderson = saunderson;
+        self
+    }
+
+    pub fn components(&self) -> &[(Colorant, f64)] {
+        &self.components
+    }
+
+    /// Absorption to scattering ratio K/S of the mixture, as the sum of the K/S ratios of its
+    /// components, weighted by their concentrations, relative to their total; zero for a mixture
+    /// without components.
+    pub fn ks(&self) -> Spectrum {
+        let total: f64 = self.components.iter().map(|(_, c)| c).sum();
+        let ks = self.components.iter().fold(SVector::<f64, NS>::zeros(), |ks, (colorant, c)| {
+            ks + self.saunderson.remove(colorant).0 .0.map(ks_from_reflectance) * (c / total)
+        });
+        Spectrum(if total > 0.0 { ks } else { SVector::zeros() })
+    }
+
+    /// Measured reflectance factors of an opaque layer of the mixture.
+    pub fn colorant(&self) -> Colorant {
+        Colorant(Spectrum(self.ks().0.map(|ks| self.saunderson.measured(reflectance_from_ks(ks)))))
+    }
+}
+
+/// A layer of a turbid medium, with spectral absorption, and scattering, coefficients, per unit
+/// of thickness.
+#[derive(Clone, Debug, PartialEq)]
+pub struct KmLayer {
+    k: Spectrum,
+    s: Spectrum,
+}
+
+impl KmLayer {
+    pub fn new(k: Spectrum, s: Spectrum) -> Self {
+        Self { k, s }
+    }
+
+    /// Layer with a unit scattering coefficient, and an absorption coefficient matching the
+    /// measured reflectance factors of an opaque sample of the medium.
+    pub fn from_opaque(measured: &Colorant, saunderson: &Saunderson) -> Self {
+        let internal = saunderson.remove(measured);
+        Self {
+            k: Spectrum(internal.0 .0.map(ks_from_reflectance)),
+            s: Spectrum(SVector::<f64, NS>::repeat(1.0)),
+        }
+    }
+
+    pub fn k(&self) -> &Spectrum {
+        &self.k
+    }
+
+    pub fn s(&self) -> &Spectrum {
+        &self.s
+    }
+
+    /// Absorption to scattering ratio K/S.
+    pub fn ks(&self) -> Spectrum {
+        Spectrum(self.k.0.zip_map(&self.s.0, |k, s| k / s.max(f64::MIN_POSITIVE)))
+    }
+
+    /// Measured reflectance factors of an opaque layer.
+    pub fn opaque_reflectance(&self, saunderson: &Saunderson) -> Colorant {
+        Colorant(Spectrum(self.ks().0.map(|ks| saunderson.measured(reflectance_from_ks(ks)))))
+    }
+
+    /// Measured reflectance factors of a layer, with a thickness in the units of the coefficients,
+    /// over a background with internal reflectance values `background`.
+    pub fn reflectance(&self, thickness: f64, background: &Colorant, saunderson: &Saunderson) -> Colorant {
+        let data = SVector::<f64, NS>::from_fn(|i, _| {
+            let (k, s, rg) = (self.k.0[i], self.s.0[i], background.0 .0[i]);
+            let r = if s * thickness <= 0.0 {
+                rg * math::exp(-2.0 * k * thickness)
+            } else {
+                let a = 1.0 + k / s;
+                let b = (a * a - 1.0).sqrt();
+                if b == 0.0 {
+                    // non-absorbing layer
+                    let sx = s * thickness;
+                    (1.0 - rg) * sx / (1.0 + (1.0 - rg) * sx) + rg / (1.0 + (1.0 - rg) * sx)
+                } else {
+                    let coth = 1.0 / math::tanh(b * s * thickness);
+                    (1.0 - rg * (a - b * coth)) / (a - rg + b * coth)
+                }
+            };
+            saunderson.measured(r)
+        });
+        Colorant(Spectrum(data))
+    }
+
+    /// Contrast ratio of a layer, over a black, and over a white backing, for the CIE 1931
+    /// observer, and illuminant D65.
+    pub fn contrast_ratio(&self, thickness: f64, black: &Colorant, white: &Colorant, saunderson: &Saunderson) -> f64 {
+        contrast_ratio(
+            &CIE1931,
+            &StdIlluminant::D65,
+            &self.reflectance(thickness, black, saunderson),
+            &self.reflectance(thickness, white, saunderson),
+        )
+    }
+
+    /// Layer thickness at which a contrast ratio, such as 0.98, is reached, in the units of the
+    /// coefficients, using the CIE 1931 observer and illuminant D65.
+    ///
+    /// Fails if the contrast ratio is not reached, as for a transparent medium, or a contrast ratio
+    /// outside the range from 0.0 to 1.0.
+    pub fn hiding_thickness(&self, target: f64, black: &Colorant, white: &Colorant, saunderson: &Saunderson) -> Result<f64, CmtError> {
+        if !(0.0..1.0).contains(&target) {
+            return Err(CmtError::OutOfRange { name: "Contrast Ratio".into(), low: 0.0, high: 1.0 });
+        }
+        let cr = |x: f64| self.contrast_ratio(x, black, white, saunderson);
+        let (mut lo, mut hi) = (0.0, 1.0);
+        while cr(hi) < target {
+            hi *= 2.0;
+            if hi > 1E9 {
+                return Err(CmtError::ErrorString("Hiding power: contrast ratio not reached".into()));
+            }
+        }
+        for _ in 0..100 {
+            let mid = 0.5 * (lo + hi);
+            if cr(mid) < target {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        Ok(0.5 * (lo + hi))
+    }
+
+    /// Internal transmittance values of a layer, with a thickness in the units of the coefficients.
+    pub fn transmittance(&self, thickness: f64) -> Spectrum {
+        Spectrum(self.k.0.zip_map(&self.s.0, |k, s| {
+            if s * thickness <= 0.0 {
+                math::exp(-2.0 * k * thickness)
+            } else {
+                let a = 1.0 + k / s;
+                let b = (a * a - 1.0).sqrt();
+                let bsx = b * s * thickness;
+                if b == 0.0 {
+                    1.0 / (1.0 + s * thickness)
+                } else {
+                    b / (a * math::sinh(bsx) + b * math::cosh(bsx))
+                }
+            }
+        }))
+    }
+}
+
+#[cfg(test)]
+mod kubelka_munk_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_saunderson() {
+        let sc = Saunderson::default();
+        for r in [0.0, 0.2, 0.5, 0.9] {
+            assert_abs_diff_eq!(sc.internal(sc.measured(r)), r, epsilon = 1E-12);
+            assert_abs_diff_eq!(reflectance_from_ks(ks_from_reflectance(r.max(0.01))), r.max(0.01), epsilon = 1E-12);
+        }
+        // a black surface reflects k1 at its surface only, if the specular reflection is included
+        assert_abs_diff_eq!(sc.measured(0.0), 0.04);
+        assert_abs_diff_eq!(sc.set_specular_included(false).measured(0.0), 0.0);
+    }
+
+    #[test]
+    fn test_km_layer() {
+        let sc = Saunderson::default();
+        let layer = KmLayer::new(Colorant::gaussian(450.0, 40.0).0 * 0.5 + Colorant::gray(0.05).0, Colorant::gray(1.0).0 * 2.0);
+        // thin layers over a white background are lighter than over a black background
+        let over_white = layer.reflectance(0.2, &Colorant::white(), &sc);
+        let over_black = layer.reflectance(0.2, &Colorant::black(), &sc);
+        assert!(over_white[600] > over_black[600]);
+        // an infinitely thick layer equals the opaque layer
+        assert_abs_diff_eq!(layer.reflectance(1E3, &Colorant::white(), &sc), layer.opaque_reflectance(&sc), epsilon = 1E-4);
+        // no layer shows the background
+        let t = layer.transmittance(0.0);
+        assert_abs_diff_eq!(t[500], 1.0);
+        assert!(layer.transmittance(1.0)[450] < layer.transmittance(1.0)[650]);
+    }
+
+    #[test]
+    fn test_km_mix() {
+        let sc = Saunderson::default();
+        let (white, red) = (Colorant::gray(0.85), Colorant::gaussian(620.0, 50.0) * 0.6 + Colorant::gray(0.08));
+        // a single component is reproduced, independent of its concentration
+        assert_abs_diff_eq!(KmMix::new(&[(red.clone(), 3.0)]).colorant(), red, epsilon = 1E-9);
+        let ks_red = KmLayer::from_opaque(&red, &sc).ks();
+        assert_abs_diff_eq!(KmMix::new(&[(red.clone(), 0.5)]).ks(), ks_red, epsilon = 1E-12);
+
+        let mix = KmMix::new(&[(white.clone(), 1.0), (red.clone(), 1.0)]);
+        let ks_white = KmLayer::from_opaque(&white, &sc).ks();
+        assert_abs_diff_eq!(mix.ks()[500], (ks_red[500] + ks_white[500]) / 2.0, epsilon = 1E-12);
+        let pink = mix.colorant();
+        assert!(pink[500] > red[500] && pink[500] < white[500]);
+
+        // the K/S of a tint is proportional to the amount of colorant, for a white base without
+        // absorption at the standard's maximum absorption
+        let base = Colorant::gray(0.98);
+        let tint = |c: f64| KmMix::new(&[(base.clone(), 1.0 - c), (red.clone(), c)]).colorant();
+        assert_abs_diff_eq!(tint_strength(&tint(0.02), &tint(0.01), &sc), 2.0, epsilon = 0.05);
+        assert_abs_diff_eq!(tint_strength(&tint(0.01), &tint(0.01), &sc), 1.0);
+        assert_eq!(KmMix::new(&[]).ks(), Spectrum(nalgebra::SVector::zeros()));
+    }
+
+    #[test]
+    fn test_hiding() {
+        let sc = Saunderson::default();
+        let (black, white) = (Colorant::gray(0.02), Colorant::gray(0.85));
+        let layer = KmLayer::new(Colorant::gray(0.01).0, Colorant::gray(0.5).0);
+        // thicker films hide better
+        assert!(layer.contrast_ratio(1.0, &black, &white, &sc) < layer.contrast_ratio(5.0, &black, &white, &sc));
+        let x = layer.hiding_thickness(0.98, &black, &white, &sc).unwrap();
+        assert_abs_diff_eq!(layer.contrast_ratio(x, &black, &white, &sc), 0.98, epsilon = 1E-9);
+        // more scattering gives a higher hiding power
+        let opaque = KmLayer::new(Colorant::gray(0.01).0, Colorant::gray(1.0).0 * 2.0);
+        assert!(opaque.hiding_thickness(0.98, &black, &white, &sc).unwrap() < x);
+        // a clear coat does not hide
+        let clear = KmLayer::new(Colorant::gray(0.0).0, Colorant::gray(0.0).0);
+        assert!(clear.hiding_thickness(0.98, &black, &white, &sc).is_err());
+    }
+}
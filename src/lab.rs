@@ -3,7 +3,7 @@ use nalgebra::{RowVector3, Vector3};
 
 use strum_macros::Display;
 use wasm_bindgen::prelude::wasm_bindgen;
-use crate::{error::CmtError, prelude::Observer, xyz::XYZ};
+use crate::{error::CmtError, math, oklab::OkLab, prelude::Observer, xyz::XYZ};
 
 #[wasm_bindgen]
 #[derive(Debug, Clone, Copy)]
@@ -41,6 +41,22 @@ impl CieLab {
     /**
      * 
      */
+    /// CIELAB values, relative to the tristimulus values of a reference white, such as the
+    /// white of an illuminant, with a luminous value of 100.
+    pub fn new(lab: [f64;3], white: XYZ) -> Self {
+        Self { observer: white.observer, lab: Vector3::from(lab), xyzn: white.xyzn }
+    }
+
+    /// The L*, a*, and b* values.
+    pub fn values(&self) -> [f64;3] {
+        self.lab.into()
+    }
+
+    /// Tristimulus values, with the reference white used for these CIELAB values.
+    pub fn xyz(&self) -> XYZ {
+        XYZ::from_vecs(self.xyzn, Some(xyz_from_lab(self.lab, self.xyzn)), self.observer)
+    }
+
     pub fn delta_e(&self, other: &Self) -> Result<f64, CmtError> {
         if ulps_eq!(self.xyzn, other.xyzn) {
             let &[l1, a1, b1] = self.lab.as_ref();
@@ -51,6 +67,261 @@ impl CieLab {
         }
     }
 
+    /// Chroma, C*<sub>ab</sub>, as the distance to the neutral axis.
+    pub fn chroma(&self) -> f64 {
+        math::hypot(self.lab.y, self.lab.z)
+    }
+
+    /// Hue angle, h<sub>ab</sub>, in degrees, from 0 to 360.
+    pub fn hue_angle(&self) -> f64 {
+        math::atan2(self.lab.z, self.lab.y).to_degrees().rem_euclid(360.0)
+    }
+
+    /**
+    CIE 1976 color difference with another color, the sample, with its lightness, chroma, hue, and
+    a* and b* components, as differences of the sample and this color, as reference.
+    ```
+    use colorimetry::prelude::*;
+
+    let white = CIE1931.xyz(&StdIlluminant::D65, None).set_illuminance(100.0);
+    let reference = CieLab::new([50.0, 30.0, 0.0], white);
+    let sample = CieLab::new([51.0, 0.0, 40.0], white);
+    let d = reference.delta_breakdown(&sample).unwrap();
+    approx::assert_abs_diff_eq!(d.delta_l, 1.0, epsilon = 1E-12);
+    approx::assert_abs_diff_eq!(d.delta_c, 10.0, epsilon = 1E-12);
+    approx::assert_abs_diff_eq!(d.delta_h, 48.99, epsilon = 1E-2);
+    approx::assert_abs_diff_eq!(d.delta_e, 2501f64.sqrt(), epsilon = 1E-12);
+    ```
+    */
+    pub fn delta_breakdown(&self, other: &Self) -> Result<DeltaBreakdown, CmtError> {
+        if ulps_eq!(self.xyzn, other.xyzn) {
+            Ok(DeltaBreakdown::from_values(self.values(), other.values()))
+        } else {
+            Err(CmtError::RequiresSameIlluminant)
+        }
+    }
+
+    /// CIE 1976 color difference ΔE*<sub>ab</sub>, the same as [`CieLab::delta_e`].
+    pub fn de76(&self, other: &Self) -> Result<f64, CmtError> {
+        self.delta_e(other)
+    }
+
+    /**
+    CIE 1994 color difference ΔE*<sub>94</sub>, with this color as reference, and the weights
+    for graphic arts, with k<sub>L</sub> = k<sub>C</sub> = k<sub>H</sub> = 1.
+    ```
+    use colorimetry::prelude::*;
+
+    let white = CIE1931.xyz(&StdIlluminant::D65, None).set_illuminance(100.0);
+    let reference = CieLab::new([50.0, 40.0, 0.0], white);
+    let sample = CieLab::new([50.0, 42.0, 0.0], white);
+    // the chroma difference is weighted by 1 + 0.045 C*
+    approx::assert_abs_diff_eq!(reference.de94(&sample).unwrap(), 2.0 / 2.8, epsilon = 1E-12);
+    ```
+    */
+    pub fn de94(&self, other: &Self) -> Result<f64, CmtError> {
+        self.same_white(other)?;
+        Ok(DeltaBreakdown::cie94(self.values(), other.values()).delta_e)
+    }
+
+    /**
+    CIEDE2000 color difference ΔE<sub>00</sub>, with parametric factors
+    k<sub>L</sub> = k<sub>C</sub> = k<sub>H</sub> = 1, as defined in CIE 142:2001.
+    ```
+    use colorimetry::prelude::*;
+
+    // pair 1 of the test data of Sharma, Wu, and Dalal
+    let white = CIE1931.xyz(&StdIlluminant::D65, None).set_illuminance(100.0);
+    let lab1 = CieLab::new([50.0, 2.6772, -79.7751], white);
+    let lab2 = CieLab::new([50.0, 0.0, -82.7485], white);
+    approx::assert_abs_diff_eq!(lab1.de2000(&lab2).unwrap(), 2.0425, epsilon = 1E-4);
+    ```
+    */
+    pub fn de2000(&self, other: &Self) -> Result<f64, CmtError> {
+        self.same_white(other)?;
+        Ok(DeltaBreakdown::ciede2000(self.values(), other.values()).delta_e)
+    }
+
+    fn same_white(&self, other: &Self) -> Result<(), CmtError> {
+        if ulps_eq!(self.xyzn, other.xyzn) {
+            Ok(())
+        } else {
+            Err(CmtError::RequiresSameIlluminant)
+        }
+    }
+
+}
+
+/**
+Color difference metrics, for comparing colors in matching, and search, applications.
+```
+use colorimetry::prelude::*;
+
+let white = CIE1931.xyz(&StdIlluminant::D65, None).set_illuminance(100.0);
+let lab1 = CieLab::new([50.0, 20.0, -10.0], white);
+let lab2 = CieLab::new([52.0, 20.0, -10.0], white);
+approx::assert_abs_diff_eq!(DeltaE::CIE76.delta_e(&lab1, &lab2).unwrap(), 2.0, epsilon = 1E-12);
+
+let d = DeltaE::OkLab.breakdown(&lab1, &lab2).unwrap();
+approx::assert_abs_diff_eq!(d.delta_e, DeltaE::OkLab.delta_e(&lab1, &lab2).unwrap(), epsilon = 1E-12);
+approx::assert_abs_diff_eq!(d.delta_e.powi(2), d.delta_l.powi(2) + d.delta_c.powi(2) + d.delta_h.powi(2), epsilon = 1E-9);
+```
+*/
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Display)]
+pub enum DeltaE {
+    /// Euclidean distance in CIELAB.
+    #[default]
+    CIE76,
+    /// CIE 1994 color difference, with the graphic arts weights, and the first color as
+    /// reference.
+    CIE94,
+    /// CIEDE2000 color difference.
+    CIEDE2000,
+    /// Euclidean distance in OKLab, scaled by 100 to match the range of CIELAB differences.
+    /// Requires the CIE 1931 observer.
+    OkLab,
+}
+
+impl DeltaE {
+    /// Color difference of two colors, which need to have the same reference white.
+    pub fn delta_e(&self, lab1: &CieLab, lab2: &CieLab) -> Result<f64, CmtError> {
+        match self {
+            DeltaE::CIE76 => lab1.delta_e(lab2),
+            DeltaE::CIE94 => lab1.de94(lab2),
+            DeltaE::CIEDE2000 => lab1.de2000(lab2),
+            DeltaE::OkLab => {
+                if !ulps_eq!(lab1.xyzn, lab2.xyzn) {
+                    return Err(CmtError::RequiresSameIlluminant);
+                }
+                let ok1 = OkLab::try_from(lab1.xyz())?;
+                let ok2 = OkLab::try_from(lab2.xyz())?;
+                Ok(100.0 * ok1.delta_e(&ok2))
+            }
+        }
+    }
+
+    /// Color difference of two colors, the reference, and the sample, with its components.
+    pub fn breakdown(&self, lab1: &CieLab, lab2: &CieLab) -> Result<DeltaBreakdown, CmtError> {
+        match self {
+            DeltaE::CIE76 => lab1.delta_breakdown(lab2),
+            DeltaE::CIE94 => lab1.same_white(lab2).map(|_| DeltaBreakdown::cie94(lab1.values(), lab2.values())),
+            DeltaE::CIEDE2000 => lab1.same_white(lab2).map(|_| DeltaBreakdown::ciede2000(lab1.values(), lab2.values())),
+            DeltaE::OkLab => {
+                if !ulps_eq!(lab1.xyzn, lab2.xyzn) {
+                    return Err(CmtError::RequiresSameIlluminant);
+                }
+                let ok1 = OkLab::try_from(lab1.xyz())?.values().map(|v| 100.0 * v);
+                let ok2 = OkLab::try_from(lab2.xyz())?.values().map(|v| 100.0 * v);
+                Ok(DeltaBreakdown::from_values(ok1, ok2))
+            }
+        }
+    }
+}
+
+/**
+A color difference, with its components: the differences in lightness, chroma, and hue, which add
+up in quadrature to the total for Euclidean metrics, and the differences in the a and b
+coordinates.
+The hue difference is signed, positive for a counterclockwise hue rotation from the reference to
+the sample, and zero if one of the colors is neutral.
+
+For the CIE94, and CIEDE2000, metrics the lightness, chroma, and hue differences are divided by
+their weighting functions, and the a and b differences are the CIELAB differences; the CIEDE2000
+components do not add up in quadrature to the total, because of its rotation term.
+*/
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DeltaBreakdown {
+    pub delta_e: f64,
+    pub delta_l: f64,
+    pub delta_c: f64,
+    pub delta_h: f64,
+    pub delta_a: f64,
+    pub delta_b: f64,
+}
+
+impl DeltaBreakdown {
+    /// Euclidean color difference components, from the L, a, and b values of a reference, and
+    /// a sample.
+    fn from_values([l1, a1, b1]: [f64; 3], [l2, a2, b2]: [f64; 3]) -> Self {
+        let (c1, c2) = (math::hypot(a1, b1), math::hypot(a2, b2));
+        let dh = math::atan2(b2, a2) - math::atan2(b1, a1);
+        let delta_h = 2.0 * (c1 * c2).sqrt() * math::sin(dh / 2.0);
+        let (delta_l, delta_a, delta_b) = (l2 - l1, a2 - a1, b2 - b1);
+        Self {
+            delta_e: (delta_l.powi(2) + delta_a.powi(2) + delta_b.powi(2)).sqrt(),
+            delta_l,
+            delta_c: c2 - c1,
+            delta_h,
+            delta_a,
+            delta_b,
+        }
+    }
+
+    /// CIE 1994 color difference components, with the graphic arts weights.
+    fn cie94(lab1: [f64; 3], lab2: [f64; 3]) -> Self {
+        let d = Self::from_values(lab1, lab2);
+        let c1 = math::hypot(lab1[1], lab1[2]);
+        let (sc, sh) = (1.0 + 0.045 * c1, 1.0 + 0.015 * c1);
+        let (delta_l, delta_c, delta_h) = (d.delta_l, d.delta_c / sc, d.delta_h / sh);
+        Self {
+            delta_e: (delta_l.powi(2) + delta_c.powi(2) + delta_h.powi(2)).sqrt(),
+            delta_l,
+            delta_c,
+            delta_h,
+            ..d
+        }
+    }
+
+    /// CIEDE2000 color difference components, following the implementation notes of Sharma,
+    /// Wu, and Dalal (2005).
+    fn ciede2000([l1, a1, b1]: [f64; 3], [l2, a2, b2]: [f64; 3]) -> Self {
+        const P25_7: f64 = 6_103_515_625.0; // 25^7
+        let c_mean = (math::hypot(a1, b1) + math::hypot(a2, b2)) / 2.0;
+        let g = 0.5 * (1.0 - (c_mean.powi(7) / (c_mean.powi(7) + P25_7)).sqrt());
+        let (ap1, ap2) = ((1.0 + g) * a1, (1.0 + g) * a2);
+        let (cp1, cp2) = (math::hypot(ap1, b1), math::hypot(ap2, b2));
+        let hue = |b: f64, a: f64| if a == 0.0 && b == 0.0 { 0.0 } else { math::atan2(b, a).to_degrees().rem_euclid(360.0) };
+        let (hp1, hp2) = (hue(b1, ap1), hue(b2, ap2));
+        let neutral = cp1 * cp2 == 0.0;
+
+        let dl = l2 - l1;
+        let dc = cp2 - cp1;
+        let dh = match hp2 - hp1 {
+            _ if neutral => 0.0,
+            d if d > 180.0 => d - 360.0,
+            d if d < -180.0 => d + 360.0,
+            d => d,
+        };
+        let dhh = 2.0 * (cp1 * cp2).sqrt() * math::sin(dh.to_radians() / 2.0);
+
+        let l_mean = (l1 + l2) / 2.0;
+        let cp_mean = (cp1 + cp2) / 2.0;
+        let hp_mean = match (hp1 + hp2, (hp1 - hp2).abs()) {
+            (sum, _) if neutral => sum,
+            (sum, d) if d <= 180.0 => sum / 2.0,
+            (sum, _) if sum < 360.0 => (sum + 360.0) / 2.0,
+            (sum, _) => (sum - 360.0) / 2.0,
+        };
+        let cos = |deg: f64| math::cos(deg.to_radians());
+        let t = 1.0 - 0.17 * cos(hp_mean - 30.0) + 0.24 * cos(2.0 * hp_mean) + 0.32 * cos(3.0 * hp_mean + 6.0)
+            - 0.20 * cos(4.0 * hp_mean - 63.0);
+        let d_theta = 30.0 * math::exp(-((hp_mean - 275.0) / 25.0).powi(2));
+        let rc = 2.0 * (cp_mean.powi(7) / (cp_mean.powi(7) + P25_7)).sqrt();
+        let sl = 1.0 + 0.015 * (l_mean - 50.0).powi(2) / (20.0 + (l_mean - 50.0).powi(2)).sqrt();
+        let sc = 1.0 + 0.045 * cp_mean;
+        let sh = 1.0 + 0.015 * cp_mean * t;
+        let rt = -math::sin(2.0 * d_theta.to_radians()) * rc;
+
+        let (delta_l, delta_c, delta_h) = (dl / sl, dc / sc, dhh / sh);
+        Self {
+            delta_e: (delta_l.powi(2) + delta_c.powi(2) + delta_h.powi(2) + rt * delta_c * delta_h).sqrt(),
+            delta_l,
+            delta_c,
+            delta_h,
+            delta_a: a2 - a1,
+            delta_b: b2 - b1,
+        }
+    }
 }
 
 impl AsRef<[f64;3]> for CieLab {
@@ -69,12 +340,30 @@ const LABC2: f64 = 4f64 / 29f64;
 
 fn lab_f(t: f64) -> f64 {
     if t > DELTA_POW3 {
-        t.powf(LABPOW)
+        math::powf(t, LABPOW)
     } else {
         LABC1 * t + LABC2
     }
 }
 
+fn lab_f_inv(t: f64) -> f64 {
+    if t > DELTA {
+        t.powi(3)
+    } else {
+        (t - LABC2) / LABC1
+    }
+}
+
+fn xyz_from_lab(lab: Vector3<f64>, xyzn: Vector3<f64>) -> Vector3<f64> {
+    let &[l, a, b] = lab.as_ref();
+    let fy = (l + 16f64) / 116f64;
+    Vector3::new(
+        xyzn.x * lab_f_inv(fy + a / 500f64),
+        xyzn.y * lab_f_inv(fy),
+        xyzn.z * lab_f_inv(fy - b / 200f64),
+    )
+}
+
 fn lab(xyz: Vector3<f64>, xyzn: Vector3<f64>) -> Vector3<f64> {
     let &[x, y, z] = xyz.as_ref();
     let &[xn, yn, zn] = xyzn.as_ref();
@@ -84,4 +373,81 @@ fn lab(xyz: Vector3<f64>, xyzn: Vector3<f64>) -> Vector3<f64> {
         200f64 * (lab_f(y/yn) - lab_f(z/zn)) 
     )
 
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod lab_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    /// Test data of G. Sharma, W. Wu, and E. N. Dalal, The CIEDE2000 color-difference formula:
+    /// implementation notes, supplementary test data, and mathematical observations, Color
+    /// Research and Application, 30(1), 2005.
+    const SHARMA: [([f64; 3], [f64; 3], f64); 34] = [
+        ([50.0, 2.6772, -79.7751], [50.0, 0.0, -82.7485], 2.0425),
+        ([50.0, 3.1571, -77.2803], [50.0, 0.0, -82.7485], 2.8615),
+        ([50.0, 2.8361, -74.0200], [50.0, 0.0, -82.7485], 3.4412),
+        ([50.0, -1.3802, -84.2814], [50.0, 0.0, -82.7485], 1.0000),
+        ([50.0, -1.1848, -84.8006], [50.0, 0.0, -82.7485], 1.0000),
+        ([50.0, -0.9009, -85.5211], [50.0, 0.0, -82.7485], 1.0000),
+        ([50.0, 0.0, 0.0], [50.0, -1.0, 2.0], 2.3669),
+        ([50.0, -1.0, 2.0], [50.0, 0.0, 0.0], 2.3669),
+        ([50.0, 2.4900, -0.0010], [50.0, -2.4900, 0.0009], 7.1792),
+        ([50.0, 2.4900, -0.0010], [50.0, -2.4900, 0.0010], 7.1792),
+        ([50.0, 2.4900, -0.0010], [50.0, -2.4900, 0.0011], 7.2195),
+        ([50.0, 2.4900, -0.0010], [50.0, -2.4900, 0.0012], 7.2195),
+        ([50.0, -0.0010, 2.4900], [50.0, 0.0009, -2.4900], 4.8045),
+        ([50.0, -0.0010, 2.4900], [50.0, 0.0010, -2.4900], 4.8045),
+        ([50.0, -0.0010, 2.4900], [50.0, 0.0011, -2.4900], 4.7461),
+        ([50.0, 2.5000, 0.0], [50.0, 0.0, -2.5000], 4.3065),
+        ([50.0, 2.5000, 0.0], [73.0, 25.0, -18.0], 27.1492),
+        ([50.0, 2.5000, 0.0], [61.0, -5.0, 29.0], 22.8977),
+        ([50.0, 2.5000, 0.0], [56.0, -27.0, -3.0], 31.9030),
+        ([50.0, 2.5000, 0.0], [58.0, 24.0, 15.0], 19.4535),
+        ([50.0, 2.5000, 0.0], [50.0, 3.1736, 0.5854], 1.0000),
+        ([50.0, 2.5000, 0.0], [50.0, 3.2972, 0.0], 1.0000),
+        ([50.0, 2.5000, 0.0], [50.0, 1.8634, 0.5757], 1.0000),
+        ([50.0, 2.5000, 0.0], [50.0, 3.2592, 0.3350], 1.0000),
+        ([60.2574, -34.0099, 36.2677], [60.4626, -34.1751, 39.4387], 1.2644),
+        ([63.0109, -31.0961, -5.8663], [62.8187, -29.7946, -4.0864], 1.2630),
+        ([61.2901, 3.7196, -5.3901], [61.4292, 2.2480, -4.9620], 1.8731),
+        ([35.0831, -44.1164, 3.7933], [35.0232, -40.0716, 1.5901], 1.8645),
+        ([22.7233, 20.0904, -46.6940], [23.0331, 14.9730, -42.5619], 2.0373),
+        ([36.4612, 47.8580, 18.3852], [36.2715, 50.5065, 21.2231], 1.4146),
+        ([90.8027, -2.0831, 1.4410], [91.1528, -1.6435, 0.0447], 1.4441),
+        ([90.9257, -0.5406, -0.9208], [88.6381, -0.8985, -0.7239], 1.5381),
+        ([6.7747, -0.2908, -2.4247], [5.8714, -0.0985, -2.2286], 0.6377),
+        ([2.0776, 0.0795, -1.1350], [0.9033, -0.0636, -0.5514], 0.9082),
+    ];
+
+    #[test]
+    fn test_ciede2000() {
+        let white = CIE1931.xyz(&StdIlluminant::D65, None).set_illuminance(100.0);
+        for (i, &(lab1, lab2, de)) in SHARMA.iter().enumerate() {
+            let (lab1, lab2) = (CieLab::new(lab1, white), CieLab::new(lab2, white));
+            assert_abs_diff_eq!(lab1.de2000(&lab2).unwrap(), de, epsilon = 5E-5);
+            // symmetric
+            assert_abs_diff_eq!(lab2.de2000(&lab1).unwrap(), de, epsilon = 5E-5);
+            assert_eq!(DeltaE::CIEDE2000.delta_e(&lab1, &lab2).unwrap(), lab1.de2000(&lab2).unwrap(), "pair {}", i + 1);
+        }
+    }
+
+    #[test]
+    fn test_cie94() {
+        let white = CIE1931.xyz(&StdIlluminant::D65, None).set_illuminance(100.0);
+        // for a neutral reference CIE94 equals CIE76
+        let gray = CieLab::new([50.0, 0.0, 0.0], white);
+        let sample = CieLab::new([52.0, 3.0, -4.0], white);
+        assert_abs_diff_eq!(gray.de94(&sample).unwrap(), gray.de76(&sample).unwrap(), epsilon = 1E-12);
+        // chromatic differences are weighted down for saturated colors
+        let red = CieLab::new([50.0, 60.0, 20.0], white);
+        let sample = CieLab::new([50.0, 63.0, 24.0], white);
+        assert!(red.de94(&sample).unwrap() < red.de76(&sample).unwrap());
+        let d = DeltaE::CIE94.breakdown(&red, &sample).unwrap();
+        assert_abs_diff_eq!(d.delta_e.powi(2), d.delta_l.powi(2) + d.delta_c.powi(2) + d.delta_h.powi(2), epsilon = 1E-9);
+        assert_abs_diff_eq!(d.delta_a, 3.0, epsilon = 1E-12);
+
+        let other = CieLab::new([50.0, 60.0, 20.0], CIE1931.xyz(&StdIlluminant::D50, None).set_illuminance(100.0));
+        assert!(red.de94(&other).is_err() && red.de2000(&other).is_err());
+    }
+}
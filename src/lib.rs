@@ -2,33 +2,131 @@
 #![allow(dead_code, unused_variables, unused_imports, )]
 #![doc = include_str!("../README.md")]
 
+// instrumentation macros, defined before the modules which use them
+#[macro_use]
+mod trace;
 
 
 
+
+pub mod action_spectrum;
+pub mod aging;
+#[cfg(feature="cct")]
+pub mod ambient_white;
+pub mod analysis;
+pub mod argyll;
+pub mod audit;
+pub mod band_metric;
+pub mod batch;
+#[cfg(feature="bench-utils")]
+pub mod bench_utils;
+pub mod bispectral;
 pub mod cam;
+pub mod cat;
 #[cfg(feature="cct")]
 pub mod cct;
+#[cfg(all(feature="cri", feature="supplemental-observers"))]
+pub mod cfi;
+pub mod cgats;
+pub mod chromaticity;
+pub mod color_index;
 pub mod colorant;
+pub mod colorimeter;
+pub mod compliance;
+#[cfg(feature="supplemental-observers")]
+pub mod cone_fundamentals;
+#[cfg(feature="arrow")]
+pub mod columnar;
 #[cfg(feature="cri")]
 pub mod cri;
+pub mod delta_e_map;
+pub mod dimming;
+pub mod display_angles;
+pub mod display_calibration;
 pub mod error;
+#[cfg(feature="experimental")]
+pub mod experimental;
+pub mod exposure;
+pub mod features;
+pub mod fixture;
+#[cfg(feature="arbitrary")]
+pub mod fuzz;
+pub mod damage;
 pub mod data;
+pub mod daylight;
 pub mod gamma;
 pub mod geometry;
+pub mod hid;
+pub mod hue;
 pub mod illuminant;
+pub mod instruments;
+pub mod interreflection;
+pub mod invariants;
+#[cfg(feature="cri")]
+pub mod iso3664;
+pub mod kelvin_slider;
+pub mod kubelka_munk;
 pub mod lab;
+pub mod luminaire;
+pub mod math;
+pub mod measurement;
+pub mod mesopic;
+pub mod metamerism;
+pub mod multi_primary;
 #[cfg(feature="munsell")]
 pub mod munsell_matt;
+pub mod oklab;
 pub mod observer;
+pub mod observer_comparison;
+pub mod palette;
+pub mod photometer;
 pub mod physics;
+pub mod pipeline;
+pub mod polarization;
 pub mod prelude;
+pub mod provenance;
+pub mod random_spectra;
+pub mod reillumination;
+pub mod rendition;
+pub mod report;
+pub mod retroreflection;
 pub mod rgb;
 pub mod rgbspace;
+pub mod s026;
+pub mod safety_colors;
+#[cfg(feature="serde")]
+pub mod serialization;
+pub mod signal_lights;
+pub mod simulate;
+#[cfg(all(feature="cri", feature="supplemental-observers"))]
+pub mod skin_rendition;
+pub mod sky;
+pub mod source_type;
+pub mod spd_text;
+pub mod spectral_data;
+pub mod spectral_image;
 pub mod spectrum;
+pub mod ssi;
 pub mod std_illuminants;
+pub mod stray_light;
 pub mod stimulus;
+pub mod surface;
+#[cfg(feature="cri")]
+pub mod swatches;
+pub mod tm27;
+#[cfg(all(feature="cri", feature="supplemental-observers"))]
+pub mod tm30;
+pub mod tone_mapping;
 pub mod traits;
+pub mod tuning;
+pub mod units;
+pub mod upsampling;
+pub mod uv_content;
 pub mod viewconditions;
+pub mod video;
+pub mod wavelength;
+pub mod wavelength_domain;
+pub mod whitepoint;
 pub mod xyz;
 
 // Set "rust-analyzer.check.features": "all" or ["cri", ...] to limit processing time
@@ -0,0 +1,526 @@
+/*!
+# Luminaires
+
+Luminous intensity distributions of luminaires, as read from photometric data files, such as
+IES LM-63 (`.ies`), and EULUMDAT (`.ldt`) files.
+
+Combined with a spectral power distribution of the luminaire's light, these are used to calculate
+the spectral irradiance at a point in a room, which can then be used in any of the color metrics
+in this library.
+
+Only the luminous intensity distributions are used here: luminaire geometry data, used for
+near-field calculations, is ignored, and the luminaire is treated as a point source.
+*/
+
+use crate::{
+    data::observers::CIE1931,
+    error::CmtError,
+    illuminant::Illuminant,
+    math,
+};
+
+/// Photometric goniometer types, as used in photometric data files.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PhotometricType {
+    /// Vertical polar axis, with C-planes (horizontal angles) and gamma angles (vertical angles),
+    /// used for most architectural luminaires.
+    C,
+    /// Horizontal polar axis, typically used for flood lights.
+    B,
+    /// Horizontal polar axis, perpendicular to the B-type axis; used for automotive lamps.
+    A,
+}
+
+/**
+A luminaire's luminous intensity distribution.
+
+The intensities, in candela, are tabulated for a set of horizontal, or C-plane, angles, and for
+each of those, for a set of vertical, or gamma, angles. All angles are in degrees.
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub struct Luminaire {
+    keywords: Vec<(String, String)>,
+    photometric_type: PhotometricType,
+    vertical_angles: Vec<f64>,
+    horizontal_angles: Vec<f64>,
+    candela: Vec<Vec<f64>>, // candela[horizontal][vertical]
+    lamp_lumens: Option<f64>,
+    input_watts: f64,
+}
+
+impl Luminaire {
+
+    /**
+    Parses an IES LM-63 photometric data file, in any of its 1986, 1991, 1995, and 2002 versions.
+
+    The candela values are scaled by the file's candela multiplier, and by its ballast factor.
+    Tilt data, if included, is ignored.
+    ```
+    use colorimetry::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    let ies = "IESNA:LM-63-2002
+    [TEST] Example
+    [MANUFAC] Acme
+    TILT=NONE
+    1 -1 1.0 3 1 1 2 0.0 0.0 0.0
+    1.0 1.0 10.0
+    0.0 45.0 90.0
+    0.0
+    100.0 50.0 0.0
+    ";
+    let luminaire = Luminaire::from_ies(ies).unwrap();
+    assert_eq!(luminaire.keyword("MANUFAC"), Some("Acme"));
+    assert_abs_diff_eq!(luminaire.luminous_intensity(30.0, 22.5), 75.0, epsilon = 1E-10);
+    ```
+    */
+    pub fn from_ies(s: &str) -> Result<Self, CmtError> {
+        let mut keywords = Vec::new();
+        let mut lines = s.lines().map(str::trim);
+        let tilt = loop {
+            match lines.next() {
+                Some(line) if line.starts_with("TILT=") => break line["TILT=".len()..].trim(),
+                Some(line) if line.starts_with('[') => {
+                    if let Some((k, v)) = line[1..].split_once(']') {
+                        keywords.push((k.trim().to_string(), v.trim().to_string()));
+                    }
+                }
+                Some(_) => {} // file format identification, or unspecified text in older versions
+                None => return Err(CmtError::ParseError("IES: missing TILT line".into())),
+            }
+        };
+        let rest: Vec<&str> = lines.collect();
+        let mut values = Numbers::new(rest.iter().flat_map(|l| l.split(|c: char| c.is_whitespace() || c == ',')));
+        if tilt == "INCLUDE" {
+            let _geometry = values.next()?;
+            let n = values.next()? as usize;
+            values.skip(n.saturating_mul(2))?;
+        }
+        let _n_lamps = values.next()?;
+        let lumens = values.next()?;
+        let multiplier = values.next()?;
+        let nv = values.next()? as usize;
+        let nh = values.next()? as usize;
+        let photometric_type = match values.next()? as u32 {
+            1 => PhotometricType::C,
+            2 => PhotometricType::B,
+            3 => PhotometricType::A,
+            _ => return Err(CmtError::ParseError("IES: unknown photometric type".into())),
+        };
+        values.skip(4)?; // units, width, length, height
+        let ballast_factor = values.next()?;
+        let _future_use = values.next()?;
+        let input_watts = values.next()?;
+        // angles, and a candela value for each pair of angles
+        let n_values = nv.checked_mul(nh).and_then(|n| n.checked_add(nv)).and_then(|n| n.checked_add(nh));
+        if n_values.map_or(true, |n| n > values.remaining()) {
+            return Err(CmtError::ParseError("IES: number of angles exceeds the number of values".into()));
+        }
+        let vertical_angles = values.take(nv)?;
+        let horizontal_angles = values.take(nh)?;
+        let scale = multiplier * ballast_factor;
+        let mut candela = Vec::with_capacity(nh);
+        for _ in 0..nh {
+            candela.push(values.take(nv)?.into_iter().map(|v| v * scale).collect());
+        }
+        Self::try_new(
+            keywords,
+            photometric_type,
+            vertical_angles,
+            horizontal_angles,
+            candela,
+            (lumens > 0.0).then_some(lumens),
+            input_watts,
+        )
+    }
+
+    /**
+    Parses a EULUMDAT (`.ldt`) photometric data file.
+
+    The relative intensities in the file, in candela per 1000 lumen, are converted to absolute
+    intensities using the total luminous flux of the lamps, and the file's conversion factor.
+    Text fields are available as keywords, using the IES keyword names where available:
+    "MANUFAC", "LUMINAIRE", "LUMCAT", "DATE", and "LAMP".
+    */
+    pub fn from_ldt(s: &str) -> Result<Self, CmtError> {
+        let n_lines = s.lines().count();
+        let mut lines = s.lines().map(str::trim);
+        let mut text = || lines.next().ok_or_else(|| CmtError::ParseError("EULUMDAT: unexpected end of data".into()));
+        let mut keywords = Vec::new();
+        keywords.push(("MANUFAC".to_string(), text()?.to_string()));
+        let _type_indicator = ldt_number(text()?)?;
+        let symmetry = ldt_number(text()?)? as u32;
+        let n_c = ldt_number(text()?)? as usize;
+        let _d_c = ldt_number(text()?)?;
+        let n_g = ldt_number(text()?)? as usize;
+        let _d_g = ldt_number(text()?)?;
+        let _report = text()?;
+        keywords.push(("LUMINAIRE".to_string(), text()?.to_string()));
+        keywords.push(("LUMCAT".to_string(), text()?.to_string()));
+        let _file_name = text()?;
+        keywords.push(("DATE".to_string(), text()?.to_string()));
+        for _ in 0..9 {
+            let _dimensions = text()?;
+        }
+        let _downward_flux_fraction = text()?;
+        let _light_output_ratio = text()?;
+        let conversion_factor = ldt_number(text()?)?;
+        let _tilt = text()?;
+        let n_sets = ldt_number(text()?)? as usize;
+        // each set of lamps takes six lines
+        if n_sets.checked_mul(6).map_or(true, |n| n > n_lines) {
+            return Err(CmtError::ParseError("EULUMDAT: number of lamp sets exceeds the number of lines".into()));
+        }
+        let mut sets = vec![[0.0; 3]; n_sets]; // number of lamps, flux, watts
+        for set in sets.iter_mut() {
+            set[0] = ldt_number(text()?)?;
+        }
+        for _ in 0..n_sets {
+            keywords.push(("LAMP".to_string(), text()?.to_string()));
+        }
+        for set in sets.iter_mut() {
+            set[1] = ldt_number(text()?)?;
+        }
+        for _ in 0..2 * n_sets {
+            let _color_rendering = text()?;
+        }
+        for set in sets.iter_mut() {
+            set[2] = ldt_number(text()?)?;
+        }
+        let rest: Vec<&str> = lines.collect();
+        let mut values = Numbers::new(rest.iter().flat_map(|l| l.split_whitespace()));
+        values.skip(10)?; // direct ratios
+        let c_angles = values.take(n_c)?;
+        let vertical_angles = values.take(n_g)?;
+        let (first, n_planes) = match symmetry {
+            0 => (0, n_c),
+            1 => (0, 1),
+            2 => (0, n_c / 2 + 1),
+            3 => (n_c / 4, n_c / 2 + 1),
+            4 => (0, n_c / 4 + 1),
+            _ => return Err(CmtError::ParseError("EULUMDAT: unknown symmetry indicator".into())),
+        };
+        if first + n_planes > n_c {
+            return Err(CmtError::ParseError("EULUMDAT: number of C-planes does not match symmetry".into()));
+        }
+        let mut horizontal_angles = c_angles[first..first + n_planes].to_vec();
+        let lumens: f64 = sets.iter().map(|set| set[1]).sum();
+        let scale = lumens / 1000.0 * conversion_factor;
+        let mut candela = Vec::with_capacity(n_planes + 1);
+        for _ in 0..n_planes {
+            candela.push(values.take(n_g)?.into_iter().map(|v| v * scale).collect::<Vec<f64>>());
+        }
+        if symmetry == 0 {
+            // close the full circle, for interpolation between the last plane and C0
+            horizontal_angles.push(360.0);
+            candela.push(candela[0].clone());
+        }
+        Self::try_new(
+            keywords,
+            PhotometricType::C,
+            vertical_angles,
+            horizontal_angles,
+            candela,
+            Some(lumens),
+            sets.iter().map(|set| set[2]).sum(),
+        )
+    }
+
+    pub(crate) fn try_new(
+        keywords: Vec<(String, String)>,
+        photometric_type: PhotometricType,
+        vertical_angles: Vec<f64>,
+        horizontal_angles: Vec<f64>,
+        candela: Vec<Vec<f64>>,
+        lamp_lumens: Option<f64>,
+        input_watts: f64,
+    ) -> Result<Self, CmtError> {
+        let ascending = |a: &[f64]| !a.is_empty() && a.windows(2).all(|w| w[0] < w[1]);
+        if !ascending(&vertical_angles) || !ascending(&horizontal_angles) {
+            return Err(CmtError::ParseError("Luminaire: angles should be in ascending order".into()));
+        }
+        if candela.len() != horizontal_angles.len() || candela.iter().any(|c| c.len() != vertical_angles.len()) {
+            return Err(CmtError::ParseError("Luminaire: candela table size does not match the number of angles".into()));
+        }
+        Ok(Self { keywords, photometric_type, vertical_angles, horizontal_angles, candela, lamp_lumens, input_watts })
+    }
+
+    /// Value of a keyword in the photometric file, such as "MANUFAC", or "LUMCAT".
+    pub fn keyword(&self, key: &str) -> Option<&str> {
+        self.keywords.iter().find(|(k, _)| k == key).map(|(_, v)| v.as_str())
+    }
+
+    /// All keywords, and their values, in the order of the photometric file.
+    pub fn keywords(&self) -> &[(String, String)] {
+        &self.keywords
+    }
+
+    pub fn photometric_type(&self) -> PhotometricType {
+        self.photometric_type
+    }
+
+    /// Rated lumens of the lamps, or `None` for absolute photometry.
+    pub fn lamp_lumens(&self) -> Option<f64> {
+        self.lamp_lumens
+    }
+
+    /// Input power of the luminaire, in Watt.
+    pub fn input_watts(&self) -> f64 {
+        self.input_watts
+    }
+
+    pub fn vertical_angles(&self) -> &[f64] {
+        &self.vertical_angles
+    }
+
+    pub fn horizontal_angles(&self) -> &[f64] {
+        &self.horizontal_angles
+    }
+
+    /**
+    Luminous intensity, in candela, in a direction given by a horizontal, or C-plane, angle, and a
+    vertical, or gamma, angle, both in degrees.
+
+    The intensity is linearly interpolated between the tabulated values. The symmetry of the
+    distribution, as implied by the range of the horizontal angles, is taken into account.
+    Outside the range of vertical angles, the intensity is zero.
+    */
+    pub fn luminous_intensity(&self, horizontal_deg: f64, vertical_deg: f64) -> f64 {
+        let h = self.symmetric_horizontal_angle(horizontal_deg);
+        let (Some((iv, tv)), Some((ih, th))) = (
+            interpolation_index(&self.vertical_angles, vertical_deg),
+            interpolation_index(&self.horizontal_angles, h),
+        ) else {
+            return 0.0;
+        };
+        let at = |i: usize, j: usize| self.candela[i.min(self.horizontal_angles.len() - 1)][j.min(self.vertical_angles.len() - 1)];
+        let lerp = |i: usize| at(i, iv) * (1.0 - tv) + at(i, iv + 1) * tv;
+        lerp(ih) * (1.0 - th) + lerp(ih + 1) * th
+    }
+
+    /// Total luminous flux, in lumen, obtained by integrating the luminous intensity distribution,
+    /// in steps of 1 degree, over the full sphere.
+    pub fn luminous_flux(&self) -> f64 {
+        let d = 1f64.to_radians();
+        let mut flux = 0.0;
+        for i in 0..360 {
+            for j in 0..180 {
+                let (h, v) = (i as f64 + 0.5, j as f64 + 0.5);
+                let (horizontal, vertical) = match self.photometric_type {
+                    PhotometricType::C => (h, v),
+                    // polar axis horizontal, vertical angle range -90 to 90
+                    _ => (h - 180.0, v - 90.0),
+                };
+                let sin = match self.photometric_type {
+                    PhotometricType::C => math::sin(v.to_radians()),
+                    _ => math::cos((v - 90.0).to_radians()),
+                };
+                flux += self.luminous_intensity(horizontal, vertical) * sin * d * d;
+            }
+        }
+        flux
+    }
+
+    /**
+    Spectral irradiance at a point, illuminated by this luminaire, with its light having a
+    relative spectral distribution `spd`.
+
+    The direction of the point, as seen from the luminaire, is given by the horizontal and
+    vertical angles, in degrees, and its distance, in meter. The incidence angle, in degrees,
+    is the angle between the light's direction and the surface normal at the point.
+    The spectral distribution is scaled such that the luminous intensity of the spectral
+    radiant intensity matches the luminaire's intensity in that direction.
+    */
+    pub fn spectral_irradiance(
+        &self,
+        spd: &Illuminant,
+        horizontal_deg: f64,
+        vertical_deg: f64,
+        distance_m: f64,
+        incidence_angle_deg: f64,
+    ) -> Result<Illuminant, CmtError> {
+        let cd = self.luminous_intensity(horizontal_deg, vertical_deg);
+        let intensity = spd.clone().set_illuminance(&CIE1931, cd);
+        Illuminant::from_radiant_intensity(&intensity, distance_m, incidence_angle_deg)
+    }
+
+    /// Maps a horizontal angle into the range of tabulated horizontal angles, using the
+    /// distribution's symmetry.
+    fn symmetric_horizontal_angle(&self, h: f64) -> f64 {
+        let first = self.horizontal_angles[0];
+        let last = self.horizontal_angles[self.horizontal_angles.len() - 1];
+        if self.photometric_type != PhotometricType::C {
+            // B and A types: symmetric around 0 if only one side is given
+            return if first >= 0.0 { h.abs() } else { h };
+        }
+        let h = h.rem_euclid(360.0);
+        match (first, last) {
+            (_, 0.0) => 0.0,
+            (f, l) if f == 0.0 && l == 90.0 => {
+                let h = if h > 180.0 { 360.0 - h } else { h };
+                if h > 90.0 { 180.0 - h } else { h }
+            }
+            (f, l) if f == 0.0 && l == 180.0 => if h > 180.0 { 360.0 - h } else { h },
+            (f, l) if f == 90.0 && l == 270.0 => if h < 90.0 { 180.0 - h } else if h > 270.0 { 540.0 - h } else { h },
+            _ => h,
+        }
+    }
+}
+
+/// Index of the interval in an ascending grid containing `v`, and the relative position within
+/// that interval, or `None` if outside the range of the grid. For a grid with a single value, the
+/// interval index is 0, and its position 0.0.
+pub(crate) fn interpolation_index(grid: &[f64], v: f64) -> Option<(usize, f64)> {
+    const EPS: f64 = 1E-9;
+    let (first, last) = (grid[0], grid[grid.len() - 1]);
+    if grid.len() == 1 {
+        return Some((0, 0.0));
+    }
+    if v < first - EPS || v > last + EPS {
+        return None;
+    }
+    let v = v.clamp(first, last);
+    let i = grid.partition_point(|&g| g <= v).clamp(1, grid.len() - 1) - 1;
+    Some((i, (v - grid[i]) / (grid[i + 1] - grid[i])))
+}
+
+/// Numeric value of a EULUMDAT header line; some files use a decimal comma.
+fn ldt_number(line: &str) -> Result<f64, CmtError> {
+    line.replace(',', ".").parse()
+        .map_err(|_| CmtError::ParseError(format!("EULUMDAT: invalid number '{line}'")))
+}
+
+/// Whitespace separated numeric values from a photometric file.
+pub(crate) struct Numbers<'a, I: Iterator<Item = &'a str>>(I);
+
+impl<'a, I: Iterator<Item = &'a str>> Numbers<'a, I> {
+    pub(crate) fn new(iter: I) -> Self {
+        Self(iter)
+    }
+
+    pub(crate) fn next(&mut self) -> Result<f64, CmtError> {
+        let s = self.0.by_ref().find(|s| !s.is_empty())
+            .ok_or_else(|| CmtError::ParseError("unexpected end of data".into()))?;
+        s.parse().map_err(|_| CmtError::ParseError(format!("invalid number '{s}'")))
+    }
+
+    pub(crate) fn take(&mut self, n: usize) -> Result<Vec<f64>, CmtError> {
+        (0..n).map(|_| self.next()).collect()
+    }
+
+    pub(crate) fn skip(&mut self, n: usize) -> Result<(), CmtError> {
+        self.take(n).map(|_| ())
+    }
+
+    /// Number of values left, without consuming them.
+    pub(crate) fn remaining(&self) -> usize
+    where
+        I: Clone,
+    {
+        self.0.clone().filter(|s| !s.is_empty()).count()
+    }
+}
+
+#[cfg(test)]
+mod luminaire_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    const ISOTROPIC: &str = "IESNA91
+[TEST] isotropic
+TILT=INCLUDE
+1
+3
+0 45 90
+1 1 1
+1 1000 2.0 3 1 1 2 0 0 0
+1.0 1.0 10.0
+0 90 180
+0
+50 50 50
+";
+
+    const QUADRANT: &str = "IESNA:LM-63-2002
+TILT=NONE
+1 -1 1 2 2 1 2 0 0 0
+1 1 5
+0 90
+0 90
+100 0
+200 0
+";
+
+    #[test]
+    fn test_isotropic(){
+        let luminaire = Luminaire::from_ies(ISOTROPIC).unwrap();
+        assert_eq!(luminaire.lamp_lumens(), Some(1000.0));
+        assert_abs_diff_eq!(luminaire.luminous_intensity(123.0, 33.0), 100.0, epsilon = 1E-10);
+        assert_abs_diff_eq!(luminaire.luminous_flux(), 400.0 * std::f64::consts::PI, epsilon = 0.1);
+
+        // 100 cd at 2 m
+        let e = luminaire.spectral_irradiance(&Illuminant::d65(), 0.0, 0.0, 2.0, 0.0).unwrap();
+        assert_abs_diff_eq!(e.illuminance(&CIE1931), 25.0, epsilon = 1E-10);
+    }
+
+    #[test]
+    fn test_symmetry(){
+        let luminaire = Luminaire::from_ies(QUADRANT).unwrap();
+        assert_abs_diff_eq!(luminaire.luminous_intensity(0.0, 0.0), 100.0, epsilon = 1E-10);
+        assert_abs_diff_eq!(luminaire.luminous_intensity(45.0, 0.0), 150.0, epsilon = 1E-10);
+        assert_abs_diff_eq!(luminaire.luminous_intensity(135.0, 0.0), 150.0, epsilon = 1E-10);
+        assert_abs_diff_eq!(luminaire.luminous_intensity(270.0, 0.0), 200.0, epsilon = 1E-10);
+        assert_abs_diff_eq!(luminaire.luminous_intensity(0.0, 45.0), 50.0, epsilon = 1E-10);
+        assert_abs_diff_eq!(luminaire.luminous_intensity(0.0, 120.0), 0.0);
+    }
+
+    fn ldt(symmetry: u32, n_c: usize, intensities: &[f64]) -> String {
+        let mut s = format!("Acme\n1\n{symmetry}\n{n_c}\n{}\n3\n45\nreport\nDownlight\nDL-1\ndl1.ldt\n2024-01-01\n", 360 / n_c);
+        s += &"100\n".repeat(9);
+        s += "100\n80\n1,0\n0\n1\n1\nLED\n2000\n3000\n80\n20\n";
+        s += &"0.5\n".repeat(10);
+        for i in 0..n_c {
+            s += &format!("{}\n", i * 360 / n_c);
+        }
+        s += "0\n45\n90\n";
+        for v in intensities {
+            s += &format!("{v}\n");
+        }
+        s
+    }
+
+    #[test]
+    fn test_ldt(){
+        // rotational symmetric, 500 cd/klm downwards, with 2000 lm lamp
+        let luminaire = Luminaire::from_ldt(&ldt(1, 4, &[500.0, 250.0, 0.0])).unwrap();
+        assert_eq!(luminaire.keyword("LUMINAIRE"), Some("Downlight"));
+        assert_eq!(luminaire.lamp_lumens(), Some(2000.0));
+        assert_abs_diff_eq!(luminaire.input_watts(), 20.0);
+        assert_abs_diff_eq!(luminaire.luminous_intensity(77.0, 0.0), 1000.0, epsilon = 1E-10);
+        assert_abs_diff_eq!(luminaire.luminous_intensity(77.0, 22.5), 750.0, epsilon = 1E-10);
+
+        // no symmetry, with interpolation between C270 and C360
+        let luminaire = Luminaire::from_ldt(&ldt(0, 4, &[
+            100.0, 0.0, 0.0,
+            200.0, 0.0, 0.0,
+            300.0, 0.0, 0.0,
+            400.0, 0.0, 0.0,
+        ])).unwrap();
+        assert_abs_diff_eq!(luminaire.luminous_intensity(315.0, 0.0), 500.0, epsilon = 1E-10);
+        assert_abs_diff_eq!(luminaire.luminous_intensity(-45.0, 0.0), 500.0, epsilon = 1E-10);
+
+        // symmetric to C0-C180 and C90-C270
+        let luminaire = Luminaire::from_ldt(&ldt(4, 4, &[100.0, 0.0, 0.0, 300.0, 0.0, 0.0])).unwrap();
+        assert_abs_diff_eq!(luminaire.luminous_intensity(270.0, 0.0), 600.0, epsilon = 1E-10);
+    }
+
+    #[test]
+    fn test_parse_errors(){
+        assert!(Luminaire::from_ies("IESNA:LM-63-2002\n").is_err());
+        assert!(Luminaire::from_ies(&QUADRANT.replace("200 0", "200")).is_err());
+        assert!(Luminaire::from_ldt(&ldt(1, 4, &[500.0, 250.0, 0.0]).replace("\n0\n1\n1\nLED", "\n0\n1E18\n1\nLED")).is_err());
+        // angle counts beyond the values in the file
+        assert!(Luminaire::from_ies(&QUADRANT.replace("1 2 2 1 2", "1 2 1E18 1 2")).is_err());
+        assert!(Luminaire::from_ies(&QUADRANT.replace("1 2 2 1 2", "1 2 2 4294967296 2")).is_err());
+        assert!(Luminaire::from_ies(&ISOTROPIC.replace("1\n3\n", "1\n1E300\n")).is_err());
+    }
+}
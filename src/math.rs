@@ -0,0 +1,164 @@
+/*!
+# Floating Point Functions
+
+Elementary functions, such as powers, exponentials, and trigonometric functions, as used in the
+colorimetric calculations of this library; all its calculations use these functions, and none of
+the corresponding methods of `f64`.
+
+By default, these use the implementations of the platform's math library, which are fast, but are
+not required to be correctly rounded, and can differ in their last bits between targets, such as
+x86, ARM, and WebAssembly.
+With the `strictfp` feature, the pure Rust implementations of the [libm](https://docs.rs/libm)
+crate are used instead, which give bit-identical results on all targets, for certification
+workflows requiring identical outputs across builds.
+
+All other arithmetic in this library is IEEE 754 conforming, and reproducible across targets:
+Rust does not fuse multiplications and additions into fused multiply-add instructions, unless
+explicitly requested by `mul_add`, which is not used in this library, and has no fast-math mode.
+Enabling target features, such as `-C target-cpu=native`, does not change this.
+*/
+
+/// `x` raised to the power `y`.
+#[inline]
+pub fn powf(x: f64, y: f64) -> f64 {
+    #[cfg(feature = "strictfp")]
+    return libm::pow(x, y);
+    #[cfg(not(feature = "strictfp"))]
+    return x.powf(y);
+}
+
+/// Cube root, also for negative values.
+#[inline]
+pub fn cbrt(x: f64) -> f64 {
+    #[cfg(feature = "strictfp")]
+    return libm::cbrt(x);
+    #[cfg(not(feature = "strictfp"))]
+    return x.cbrt();
+}
+
+/// Exponential function, e<sup>x</sup>.
+#[inline]
+pub fn exp(x: f64) -> f64 {
+    #[cfg(feature = "strictfp")]
+    return libm::exp(x);
+    #[cfg(not(feature = "strictfp"))]
+    return x.exp();
+}
+
+/// Natural logarithm.
+#[inline]
+pub fn ln(x: f64) -> f64 {
+    #[cfg(feature = "strictfp")]
+    return libm::log(x);
+    #[cfg(not(feature = "strictfp"))]
+    return x.ln();
+}
+
+/// Sine of an angle in radians.
+#[inline]
+pub fn sin(x: f64) -> f64 {
+    #[cfg(feature = "strictfp")]
+    return libm::sin(x);
+    #[cfg(not(feature = "strictfp"))]
+    return x.sin();
+}
+
+/// Cosine of an angle in radians.
+#[inline]
+pub fn cos(x: f64) -> f64 {
+    #[cfg(feature = "strictfp")]
+    return libm::cos(x);
+    #[cfg(not(feature = "strictfp"))]
+    return x.cos();
+}
+
+/// Arccosine, in radians, in the range from 0 to π.
+#[inline]
+pub fn acos(x: f64) -> f64 {
+    #[cfg(feature = "strictfp")]
+    return libm::acos(x);
+    #[cfg(not(feature = "strictfp"))]
+    return x.acos();
+}
+
+/// Four quadrant arctangent of `y` and `x`, in radians, in the range from -π to π.
+#[inline]
+pub fn atan2(y: f64, x: f64) -> f64 {
+    #[cfg(feature = "strictfp")]
+    return libm::atan2(y, x);
+    #[cfg(not(feature = "strictfp"))]
+    return y.atan2(x);
+}
+
+/// Euclidean distance, √(x² + y²), without undue overflow, or underflow.
+#[inline]
+pub fn hypot(x: f64, y: f64) -> f64 {
+    #[cfg(feature = "strictfp")]
+    return libm::hypot(x, y);
+    #[cfg(not(feature = "strictfp"))]
+    return x.hypot(y);
+}
+
+/// Base 10 logarithm.
+#[inline]
+pub fn log10(x: f64) -> f64 {
+    #[cfg(feature = "strictfp")]
+    return libm::log10(x);
+    #[cfg(not(feature = "strictfp"))]
+    return x.log10();
+}
+
+/// Hyperbolic sine.
+#[inline]
+pub fn sinh(x: f64) -> f64 {
+    #[cfg(feature = "strictfp")]
+    return libm::sinh(x);
+    #[cfg(not(feature = "strictfp"))]
+    return x.sinh();
+}
+
+/// Hyperbolic cosine.
+#[inline]
+pub fn cosh(x: f64) -> f64 {
+    #[cfg(feature = "strictfp")]
+    return libm::cosh(x);
+    #[cfg(not(feature = "strictfp"))]
+    return x.cosh();
+}
+
+/// Hyperbolic tangent.
+#[inline]
+pub fn tanh(x: f64) -> f64 {
+    #[cfg(feature = "strictfp")]
+    return libm::tanh(x);
+    #[cfg(not(feature = "strictfp"))]
+    return x.tanh();
+}
+
+#[cfg(test)]
+mod math_test {
+    use super::*;
+    use crate::prelude::*;
+    use approx::assert_ulps_eq;
+
+    #[test]
+    fn test_math() {
+        assert_ulps_eq!(powf(2.0, 0.42), 2f64.powf(0.42), max_ulps = 2);
+        assert_ulps_eq!(cbrt(-27.0), -3.0);
+        assert_ulps_eq!(exp(ln(5.0)), 5.0, max_ulps = 2);
+        assert_ulps_eq!(atan2(1.0, -1.0), 0.75 * std::f64::consts::PI);
+        assert_ulps_eq!(sin(0.5).powi(2) + cos(0.5).powi(2), 1.0);
+        assert_ulps_eq!(hypot(3.0, 4.0), 5.0);
+        assert_ulps_eq!(log10(1E-3), -3.0);
+        assert_ulps_eq!(cosh(0.5).powi(2) - sinh(0.5).powi(2), 1.0, max_ulps = 4);
+        assert_ulps_eq!(tanh(0.5), sinh(0.5) / cosh(0.5), max_ulps = 2);
+    }
+
+    #[cfg(feature = "strictfp")]
+    #[test]
+    fn test_strictfp() {
+        // bit patterns, identical on all targets
+        let [x, y, z] = CIE1931.xyz(&Illuminant::planckian(2856.0), None).set_illuminance(100.0).values();
+        assert_eq!([x.to_bits(), y.to_bits(), z.to_bits()], [4637429888895500648, 4636737291354636288, 4630206310568500567]);
+    }
+}
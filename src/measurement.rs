@@ -0,0 +1,120 @@
+/*!
+# Reflectance and Transmittance Measurements
+
+Spectral reflectance factors, and transmittance values, are measured with a spectrometer by
+comparing the signal of a sample with the signal of a reference: a calibrated white standard for
+reflectance, or the open beam, without sample, for transmittance. Both signals are corrected for
+the detector's dark signal, measured with the light path blocked:
+
+R(λ) = (S(λ) − D(λ)) / (W(λ) − D(λ)) · R<sub>w</sub>(λ),
+
+with S the sample signal, W the reference signal, D the dark signal, and R<sub>w</sub> the
+calibrated reflectance of the white standard, which is 1.0 for transmittance.
+
+A [`Measurement`] holds the three raw signals, on this library's wavelength domain, and
+calculates the sample's [`Colorant`].
+*/
+
+use crate::{
+    colorant::Colorant,
+    error::CmtError,
+    spectrum::{Spectrum, NS},
+};
+
+/// Raw sample, dark, and reference signals of a reflectance, or transmittance, measurement.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Measurement {
+    sample: Spectrum,
+    dark: Spectrum,
+    reference: Spectrum,
+    reference_standard: Option<Colorant>,
+    nonlinearity: Vec<f64>,
+}
+
+impl Measurement {
+
+    /**
+    A measurement from the raw sample, dark, and reference signals, typically in detector counts.
+    ```
+    use colorimetry::prelude::*;
+
+    let dark = Colorant::gray(0.01);
+    let white = Colorant::gray(0.81);
+    let sample = Colorant::gray(0.41);
+    let colorant = Measurement::from_raw(&sample, &dark, &white).colorant().unwrap();
+    approx::assert_abs_diff_eq!(colorant[550], 0.5, epsilon = 1E-12);
+    ```
+    */
+    pub fn from_raw(sample: &Spectrum, dark: &Spectrum, reference: &Spectrum) -> Self {
+        Self { sample: *sample, dark: *dark, reference: *reference, reference_standard: None, nonlinearity: Vec::new() }
+    }
+
+    /// Sets the calibrated spectral reflectance factors of the white reference standard, which
+    /// are 1.0 if not set.
+    pub fn set_reference_standard(mut self, standard: &Colorant) -> Self {
+        self.reference_standard = Some(standard.clone());
+        self
+    }
+
+    /**
+    Sets the detector's nonlinearity correction, as polynomial coefficients, in the form used by
+    most spectrometer manufacturers: a dark corrected signal `x` is corrected as `x / p(x)`, with
+    p(x) = c₀ + c₁x + c₂x² + ..., and the coefficients given in this order.
+    */
+    pub fn set_nonlinearity(mut self, coefficients: &[f64]) -> Self {
+        self.nonlinearity = coefficients.to_vec();
+        self
+    }
+
+    /**
+    The spectral reflectance factors, or transmittance values, of the sample.
+
+    Values are clamped to the range from 0.0 to 1.0; fluorescent samples, and noise, can result in
+    values above 1.0. Returns an error if the dark corrected reference signal is not positive for
+    all wavelengths.
+    */
+    pub fn colorant(&self) -> Result<Colorant, CmtError> {
+        let mut values = [0.0; NS];
+        for (i, v) in values.iter_mut().enumerate() {
+            let w = self.linearize(self.reference.0[i] - self.dark.0[i]);
+            if w.is_nan() || w <= 0.0 {
+                return Err(CmtError::OutOfRange { name: "Dark Corrected Reference Signal".into(), low: 0.0, high: f64::INFINITY });
+            }
+            let s = self.linearize(self.sample.0[i] - self.dark.0[i]);
+            let r = self.reference_standard.as_ref().map_or(1.0, |c| c.0 .0[i]);
+            *v = (s / w * r).clamp(0.0, 1.0);
+        }
+        Ok(Colorant(Spectrum(values.into())))
+    }
+
+    fn linearize(&self, x: f64) -> f64 {
+        if self.nonlinearity.is_empty() {
+            x
+        } else {
+            x / self.nonlinearity.iter().rev().fold(0.0, |acc, c| acc * x + c)
+        }
+    }
+}
+
+#[cfg(test)]
+mod measurement_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_measurement() {
+        // detector with a sensitivity loss of 5% at 40000 counts
+        let p = [1.0, -1.25E-6];
+        let dark = Spectrum::default();
+        let white = Spectrum::try_from([38000.0; NS].as_ref()).unwrap();
+        let sample = Spectrum::try_from([9800.0; NS].as_ref()).unwrap();
+
+        let m = Measurement::from_raw(&sample, &dark, &white);
+        assert_abs_diff_eq!(m.colorant().unwrap()[500], 9800.0 / 38000.0, epsilon = 1E-12);
+        let m = m.set_nonlinearity(&p).set_reference_standard(&Colorant::gray(0.98));
+        let want = 0.98 * (9800.0 / (1.0 - 1.25E-6 * 9800.0)) / (38000.0 / (1.0 - 1.25E-6 * 38000.0));
+        assert_abs_diff_eq!(m.colorant().unwrap()[500], want, epsilon = 1E-12);
+
+        assert!(Measurement::from_raw(&sample, &white, &white).colorant().is_err());
+    }
+}
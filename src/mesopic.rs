@@ -0,0 +1,176 @@
+/*!
+# Scotopic and Mesopic Photometry
+
+At low light levels the rods in the retina take over from the cones, and the spectral sensitivity
+of the eye shifts to shorter wavelengths, with a peak at 507 nanometer instead of 555 nanometer:
+blue and green objects appear relatively brighter in twilight than red objects, an effect known as
+the Purkinje shift.
+For dark adapted, scotopic, vision, at luminance values below 0.005 cd/m², spectral radiance is
+weighted with the CIE 1951 scotopic luminous efficiency function V'(λ), included here as
+[`CIE1951_SCOTOPIC`]; in the mesopic range between 0.005 and 5 cd/m², the CIE 191:2010 system
+uses a blend of the photopic and scotopic functions, with an adaptation coefficient m, which
+depends on the adaptation luminance.
+
+[`Mesopic`] represents a state of adaptation, and calculates mesopic luminance values, and
+luminous efficiency functions, for example to render twilight simulations with pseudo-luminance
+values.
+```
+use colorimetry::prelude::*;
+
+// a blue sample looks brighter, relative to a red one, in twilight
+let blue = Illuminant::led(470.0, 20.0);
+let red = Illuminant::led(620.0, 20.0);
+let twilight = Mesopic::from_adaptation_luminance(0.01);
+let daylight = Mesopic::from_adaptation_luminance(10.0);
+let ratio = |m: &Mesopic| m.luminance(&blue) / m.luminance(&red);
+assert!(ratio(&twilight) > 5.0 * ratio(&daylight));
+```
+*/
+
+use std::sync::LazyLock;
+
+use crate::{data::observers::CIE1931, math, spectrum::Spectrum, units::{KM, KM_PRIME}};
+
+/// CIE 1951 scotopic luminous efficiency function V'(λ), from 380 to 780 nanometer, in steps of
+/// 10 nanometer, as published in CIE 15:2004.
+const V_PRIME_10: [f64; 41] = [
+    0.000589, 0.002209, 0.00929, 0.03484, 0.0966, 0.1998, 0.3281, 0.455, 0.567, 0.676, 0.793,
+    0.904, 0.982, 0.997, 0.935, 0.811, 0.650, 0.481, 0.3288, 0.2076, 0.1212, 0.0655, 0.03315,
+    0.01593, 0.00737, 0.003335, 0.001497, 0.000677, 0.0003129, 0.0001480, 0.0000715, 0.00003533,
+    0.00001780, 0.00000914, 0.00000478, 0.000002546, 0.000001379, 0.000000760, 0.000000425,
+    0.000000241, 0.000000139,
+];
+
+/// The CIE 1951 scotopic luminous efficiency function V'(λ), interpolated to one nanometer steps.
+///
+/// The values span seven orders of magnitude, and are interpolated on a logarithmic scale, using
+/// Sprague interpolation, to keep them positive, and smooth.
+pub static CIE1951_SCOTOPIC: LazyLock<Spectrum> = LazyLock::new(|| {
+    let ln: Vec<f64> = V_PRIME_10.iter().map(|&v| math::ln(v)).collect();
+    let mut s = Spectrum::try_sprague_interpolate([380.0, 780.0], &ln).unwrap();
+    s.0.iter_mut().for_each(|v| *v = math::exp(*v));
+    s
+});
+
+/// Lower limit of the mesopic range of CIE 191, in cd/m².
+pub const MESOPIC_MIN: f64 = 0.005;
+
+/// Upper limit of the mesopic range of CIE 191, in cd/m².
+pub const MESOPIC_MAX: f64 = 5.0;
+
+/// Scotopic luminance, or illuminance, of a spectral radiance, or irradiance, in units of W/m²/nm
+/// (/sr).
+pub fn scotopic_value(spectrum: &Spectrum) -> f64 {
+    KM_PRIME * CIE1951_SCOTOPIC.0.dot(&spectrum.0)
+}
+
+/// Photopic luminance, or illuminance, of a spectral radiance, or irradiance, using the CIE 1931
+/// luminous efficiency function V(λ).
+pub fn photopic_value(spectrum: &Spectrum) -> f64 {
+    KM * CIE1931.data.row(1).transpose().dot(&spectrum.0)
+}
+
+/// State of visual adaptation in the CIE 191:2010 system for mesopic photometry.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Mesopic {
+    m: f64,
+}
+
+impl Mesopic {
+    /// Adaptation for a mesopic adaptation luminance, in cd/m², with the adaptation coefficient
+    /// m = 0.7670 + 0.3334 log₁₀(L), limited to the range from 0.0, for scotopic vision, to 1.0,
+    /// for photopic vision.
+    pub fn from_adaptation_luminance(luminance: f64) -> Self {
+        let l = luminance.clamp(MESOPIC_MIN, MESOPIC_MAX);
+        Self { m: (0.7670 + 0.3334 * math::log10(l)).clamp(0.0, 1.0) }
+    }
+
+    /**
+    Adaptation for a visual field with photopic and scotopic luminance values, in cd/m², using the
+    iterative CIE 191 procedure to find the mesopic luminance, and the adaptation coefficient.
+    ```
+    use colorimetry::prelude::*;
+
+    // in bright light vision is photopic, in the dark scotopic
+    assert_eq!(Mesopic::from_luminances(100.0, 250.0).m(), 1.0);
+    assert_eq!(Mesopic::from_luminances(0.001, 0.002).m(), 0.0);
+    ```
+    */
+    pub fn from_luminances(photopic: f64, scotopic: f64) -> Self {
+        let v0 = KM / KM_PRIME; // V'(λ0), at 555 nm
+        let mut m = 0.5;
+        for _ in 0..100 {
+            let l_mes = (m * photopic + (1.0 - m) * scotopic * v0) / (m + (1.0 - m) * v0);
+            let next = Self::from_adaptation_luminance(l_mes).m;
+            let done = (next - m).abs() < 1E-9;
+            m = next;
+            if done {
+                break;
+            }
+        }
+        Self { m }
+    }
+
+    /// Adaptation coefficient, from 0.0 for scotopic vision, to 1.0 for photopic vision.
+    pub fn m(&self) -> f64 {
+        self.m
+    }
+
+    /// Mesopic luminous efficiency function, as blend of the photopic and scotopic functions,
+    /// normalized to a maximum value of 1.0.
+    pub fn efficiency(&self) -> Spectrum {
+        let v = CIE1931.data.row(1).transpose() * self.m + CIE1951_SCOTOPIC.0 * (1.0 - self.m);
+        let max = v.max();
+        Spectrum(v / max)
+    }
+
+    /// Mesopic luminance, or illuminance, of a spectral radiance, or irradiance, for this state
+    /// of adaptation, as a pseudo-luminance value, which equals the photopic luminance for
+    /// photopic vision, and the scotopic luminance for scotopic vision.
+    pub fn luminance(&self, spectrum: &Spectrum) -> f64 {
+        let v0 = KM / KM_PRIME;
+        (self.m * photopic_value(spectrum) + (1.0 - self.m) * scotopic_value(spectrum) * v0)
+            / (self.m + (1.0 - self.m) * v0)
+    }
+}
+
+#[cfg(test)]
+mod mesopic_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_scotopic() {
+        // peak at 507 nm, and equal photopic and scotopic values at 555 nm
+        let v = &CIE1951_SCOTOPIC;
+        let peak = (380..=780).max_by(|&a, &b| v[a].total_cmp(&v[b])).unwrap();
+        assert!((505..=509).contains(&peak));
+        assert_abs_diff_eq!(v[peak], 1.0, epsilon = 0.01);
+        let line = Illuminant::led(555.0, 1.0);
+        assert_abs_diff_eq!(scotopic_value(&line) / photopic_value(&line), 1.0, epsilon = 0.01);
+    }
+
+    #[test]
+    fn test_mesopic() {
+        assert_eq!(Mesopic::from_adaptation_luminance(1000.0).m(), 1.0);
+        assert_eq!(Mesopic::from_adaptation_luminance(1E-4).m(), 0.0);
+        let d65 = Illuminant::d65();
+        let photopic = Mesopic::from_adaptation_luminance(10.0);
+        assert_abs_diff_eq!(photopic.luminance(&d65), photopic_value(&d65), epsilon = 1E-9);
+
+        // for a field with equal photopic and scotopic luminance the mesopic value is the same
+        let m = Mesopic::from_luminances(1.0, 1.0);
+        assert!(m.m() > 0.0 && m.m() < 1.0);
+        assert_abs_diff_eq!(m.m(), 0.7670, epsilon = 1E-6);
+
+        // daylight, rich in blue, has a higher mesopic than photopic luminance, at low levels
+        let field = Illuminant::d65().set_illuminance(&CIE1931, 0.1);
+        let (lp, ls) = (photopic_value(&field), scotopic_value(&field));
+        assert!(ls > 2.0 * lp);
+        let m = Mesopic::from_luminances(lp, ls);
+        let l_mes = m.luminance(&field);
+        assert!(l_mes > lp && l_mes < ls);
+        assert_abs_diff_eq!(Mesopic::from_adaptation_luminance(l_mes).m(), m.m(), epsilon = 1E-6);
+        assert_abs_diff_eq!(m.efficiency().0.max(), 1.0, epsilon = 1E-12);
+    }
+}
@@ -0,0 +1,245 @@
+/*!
+# Metamerism Index of Daylight Simulators
+
+CIE 51.2 rates the quality of daylight simulators, such as those used in viewing booths, by
+their metamerism index: the mean color difference, under the simulator, of pairs of samples which
+are metamers, matching exactly, under the daylight illuminant simulated.
+
+The visible range index uses pairs of non-fluorescent samples; for the ultraviolet range index,
+one member of each pair is fluorescent, and its total radiance factor, as measured under the
+simulator, is used. The standard metameric pairs of CIE 51.2, and the fluorescent samples of its
+ultraviolet range index, are not included in this library, and neither are the index of that
+range, and the grades of CIE 51.2, which depend on them.
+[`metamerism_index`] uses pairs of [`Colorant`]s provided by the user, which should match under
+the reference illuminant. As its value depends on the pairs, it is not a CIE 51.2 metamerism index,
+and can not be used to grade a simulator in the categories of CIE 51.2, unless it is calculated
+with the standard pairs, the CIE 1964 10º observer, and CIELAB color differences.
+
+# Special Metamerism Indices
+The special metamerism index, as defined in CIE 15, rates a single pair of samples, which match
+under a reference illuminant, for the CIE 1931 observer, by their CIEDE2000 color difference for a
+change of illuminant, or for a change of observer.
+[`SpecialMetamerism`] evaluates a pair for a set of test illuminants, by default CIE illuminants A,
+F11, and the LED-B series, with the `cie-illuminants` feature, and for a set of test observers, by
+default the CIE 1964, and the CIE 2015 cone fundamental based observers, with the
+`supplemental-observers` feature.
+Pairs which do not match exactly under the reference condition are corrected, as recommended in
+CIE 15, by scaling the tristimulus values of the second sample in each test condition with the
+ratios of the tristimulus values of the two samples in the reference condition.
+```
+use colorimetry::prelude::*;
+
+let sample = Colorant::gray(0.5);
+let indices = SpecialMetamerism::new(StdIlluminant::D65).evaluate(&sample, &sample).unwrap();
+assert!(indices.reference < 1E-9);
+assert!(indices.illuminant.iter().chain(&indices.observer).all(|mi| mi.index < 1E-9));
+```
+*/
+
+use nalgebra::Vector3;
+use strum::IntoEnumIterator;
+
+use crate::{
+    colorant::Colorant,
+    error::CmtError,
+    illuminant::Illuminant,
+    lab::CieLab,
+    observer::{Observer, ObserverData},
+    std_illuminants::StdIlluminant,
+    xyz::XYZ,
+};
+
+/**
+Metamerism index of a daylight simulator, as the mean CIELAB color difference of metameric pairs
+of samples under the simulator, for an observer, and a set of pairs provided by the user.
+```
+use colorimetry::prelude::*;
+
+// a pair of metamers is hard to come by: a sample and itself matches under any light
+let pair = (Colorant::gaussian(550.0, 50.0), Colorant::gaussian(550.0, 50.0));
+let mi = metamerism_index(&Illuminant::d65(), &[pair], &CIE1931).unwrap();
+assert!(mi < 1E-9);
+```
+*/
+pub fn metamerism_index(
+    simulator: &Illuminant,
+    pairs: &[(Colorant, Colorant)],
+    observer: &ObserverData,
+) -> Result<f64, CmtError> {
+    if pairs.is_empty() {
+        return Err(CmtError::ErrorString("Metamerism index: requires at least one pair".into()));
+    }
+    let lab = |c: &Colorant| CieLab::try_from(observer.xyz(simulator, Some(c)));
+    let mut sum = 0.0;
+    for (a, b) in pairs {
+        sum += lab(a)?.delta_e(&lab(b)?)?;
+    }
+    Ok(sum / pairs.len() as f64)
+}
+
+/// A special metamerism index, for a test illuminant, or a test observer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SpecialMetamerismIndex {
+    /// Name of the test illuminant, or of the test observer.
+    pub condition: String,
+    /// CIEDE2000 color difference of the pair in the test condition.
+    pub index: f64,
+}
+
+/// The special metamerism indices of a pair of samples, from [`SpecialMetamerism::evaluate`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct SpecialMetamerismIndices {
+    /// CIEDE2000 color difference of the pair in the reference condition, which should be small
+    /// for a metameric pair.
+    pub reference: f64,
+    /// Indices for a change of illuminant, with the CIE 1931 observer.
+    pub illuminant: Vec<SpecialMetamerismIndex>,
+    /// Indices for a change of observer, under the reference illuminant.
+    pub observer: Vec<SpecialMetamerismIndex>,
+}
+
+/// Evaluator of special metamerism indices, for a reference illuminant, and sets of test
+/// illuminants, and test observers.
+#[derive(Clone, Debug)]
+pub struct SpecialMetamerism {
+    reference: StdIlluminant,
+    test_illuminants: Vec<StdIlluminant>,
+    test_observers: Vec<Observer>,
+}
+
+impl SpecialMetamerism {
+    /// A special metamerism evaluator for a reference illuminant, typically D65, with the default
+    /// test illuminants, and test observers, available with the enabled features.
+    pub fn new(reference: StdIlluminant) -> Self {
+        #[cfg(feature = "cie-illuminants")]
+        let test_illuminants = {
+            use StdIlluminant::*;
+            vec![A, F11, LED_B1, LED_B2, LED_B3, LED_B4, LED_B5]
+        };
+        #[cfg(not(feature = "cie-illuminants"))]
+        let test_illuminants = Vec::new();
+        let test_observers = Observer::iter().filter(|&o| o != Observer::Std1931).collect();
+        Self { reference, test_illuminants, test_observers }
+    }
+
+    pub fn set_test_illuminants(mut self, illuminants: &[StdIlluminant]) -> Self {
+        self.test_illuminants = illuminants.to_vec();
+        self
+    }
+
+    pub fn set_test_observers(mut self, observers: &[Observer]) -> Self {
+        self.test_observers = observers.to_vec();
+        self
+    }
+
+    /// Special metamerism indices of a sample, and its metamer, which should match under the
+    /// reference illuminant, for the CIE 1931 observer.
+    pub fn evaluate(&self, sample: &Colorant, metamer: &Colorant) -> Result<SpecialMetamerismIndices, CmtError> {
+        let xyz = |observer: Observer, illuminant: &StdIlluminant, colorant: &Colorant| {
+            let xyz = observer.data().xyz(illuminant, Some(colorant));
+            (xyz.xyzn, xyz.xyz.unwrap_or_default())
+        };
+        let (_, sample_ref) = xyz(Observer::Std1931, &self.reference, sample);
+        let (white_ref, metamer_ref) = xyz(Observer::Std1931, &self.reference, metamer);
+        let correction = sample_ref.component_div(&metamer_ref);
+        if correction.iter().any(|c| !c.is_finite()) {
+            return Err(CmtError::ErrorString("Special metamerism: the metamer has a tristimulus value of zero".into()));
+        }
+        let difference = |observer: Observer, white: Vector3<f64>, a: Vector3<f64>, b: Vector3<f64>| {
+            let lab = |v| CieLab::try_from(XYZ::from_vecs(white, Some(v), observer));
+            lab(a)?.de2000(&lab(b)?)
+        };
+        let reference = difference(Observer::Std1931, white_ref, sample_ref, metamer_ref)?;
+        let test = |observer: Observer, illuminant: &StdIlluminant| {
+            let (white, a) = xyz(observer, illuminant, sample);
+            let (_, b) = xyz(observer, illuminant, metamer);
+            difference(observer, white, a, b.component_mul(&correction))
+        };
+        let illuminant = self
+            .test_illuminants
+            .iter()
+            .map(|illuminant| {
+                let index = test(Observer::Std1931, illuminant)?;
+                Ok(SpecialMetamerismIndex { condition: illuminant.to_string(), index })
+            })
+            .collect::<Result<_, CmtError>>()?;
+        let observer = self
+            .test_observers
+            .iter()
+            .map(|&observer| {
+                let index = test(observer, &self.reference)?;
+                Ok(SpecialMetamerismIndex { condition: format!("{observer:?}"), index })
+            })
+            .collect::<Result<_, CmtError>>()?;
+        Ok(SpecialMetamerismIndices { reference, illuminant, observer })
+    }
+}
+
+#[cfg(test)]
+mod metamerism_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+    use nalgebra::{SMatrix, SVector};
+
+    #[test]
+    fn test_metamerism_index() {
+        // a metameric black for D65: a spectral perturbation without tristimulus response
+        let d65 = Illuminant::d65();
+        let w = SMatrix::<f64, 3, NS>::from_fn(|i, j| CIE1931.data[(i, j)] * d65.0 .0[j]);
+        let p = Colorant::gaussian(600.0, 20.0).0 .0 * 0.1;
+        let black = p - w.transpose() * (w * w.transpose()).try_inverse().unwrap() * (w * p);
+        let sample = Colorant::gray(0.5);
+        let metamer = Colorant(Spectrum(SVector::from(sample.0 .0 + black)));
+
+        let mi_d65 = metamerism_index(&d65, &[(sample.clone(), metamer.clone())], &CIE1931).unwrap();
+        assert_abs_diff_eq!(mi_d65, 0.0, epsilon = 1E-9);
+        #[cfg(feature = "cie-illuminants")]
+        {
+            let mi_a = metamerism_index(StdIlluminant::A.illuminant(), &[(sample, metamer)], &CIE1931).unwrap();
+            assert!(mi_a > 0.1);
+        }
+
+        assert!(metamerism_index(&d65, &[], &CIE1931).is_err());
+    }
+
+    #[test]
+    fn test_special_metamerism() {
+        // a metameric black for D65, and the CIE 1931 observer
+        let d65 = Illuminant::d65();
+        let w = SMatrix::<f64, 3, NS>::from_fn(|i, j| CIE1931.data[(i, j)] * d65.0 .0[j]);
+        let p = Colorant::gaussian(600.0, 20.0).0 .0 * 0.1;
+        let black = p - w.transpose() * (w * w.transpose()).try_inverse().unwrap() * (w * p);
+        let sample = Colorant::gray(0.5);
+        let metamer = Colorant(Spectrum(SVector::from(sample.0 .0 + black)));
+
+        let indices = SpecialMetamerism::new(StdIlluminant::D65).evaluate(&sample, &metamer).unwrap();
+        assert_abs_diff_eq!(indices.reference, 0.0, epsilon = 1E-6);
+        #[cfg(feature = "cie-illuminants")]
+        {
+            assert_eq!(indices.illuminant.len(), 7);
+            assert_eq!(indices.illuminant[0].condition, "A");
+            assert!(indices.illuminant.iter().all(|mi| mi.index > 0.1));
+        }
+        #[cfg(feature = "supplemental-observers")]
+        {
+            assert_eq!(indices.observer.len(), 3);
+            assert!(indices.observer.iter().all(|mi| mi.index > 0.05));
+            assert_eq!(indices.observer[0].condition, "Std1964");
+        }
+
+        // a slight mismatch is corrected, and hardly changes the indices
+        #[cfg(feature = "cie-illuminants")]
+        {
+            let scaled = Colorant(Spectrum(SVector::from(metamer.0 .0 * 1.01)));
+            let corrected = SpecialMetamerism::new(StdIlluminant::D65)
+                .set_test_illuminants(&[StdIlluminant::A])
+                .set_test_observers(&[])
+                .evaluate(&sample, &scaled)
+                .unwrap();
+            assert!(corrected.reference > 0.1);
+            assert_abs_diff_eq!(corrected.illuminant[0].index, indices.illuminant[0].index, epsilon = 0.05);
+            assert!(corrected.observer.is_empty());
+        }
+        assert!(SpecialMetamerism::new(StdIlluminant::D65).evaluate(&sample, &Colorant::black()).is_err());
+    }
+}
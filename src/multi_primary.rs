@@ -0,0 +1,200 @@
+/*!
+# Multi-Primary Gamuts
+
+Displays, and stage and architectural luminaires, with more than three primaries, such as
+RGBW, RGBA, or six-color LED engines, can produce any chromaticity within the convex hull of the
+chromaticities of their primaries.
+A [`PrimaryGamut`] finds this polygon, for primaries given as tristimulus values, and provides
+its area in the CIE 1976 u'v' diagram, a containment test for target chromaticities, and a set of
+non-negative primary weights to reproduce a target.
+
+For more than three primaries the weights for a target are not unique; the weights returned here
+are the non-negative least squares solution, which uses a minimal number of primaries.
+```
+use colorimetry::prelude::*;
+
+let leds: Vec<XYZ> = [(450.0, 20.0), (530.0, 30.0), (590.0, 15.0), (630.0, 20.0)]
+    .iter()
+    .map(|&(center, width)| CIE1931.xyz(&Illuminant::led(center, width), None))
+    .collect();
+let gamut = PrimaryGamut::try_new(&leds).unwrap();
+assert_eq!(gamut.hull().len(), 4);
+
+let d65 = CIE1931.xyz_d65().set_illuminance(100.0);
+assert!(gamut.contains(d65.xy().values()));
+let weights = gamut.weights(&d65).unwrap();
+assert!(weights.iter().all(|&w| w >= 0.0));
+```
+*/
+
+use nalgebra::{DMatrix, DVector};
+
+use crate::{error::CmtError, observer::Observer, tuning::nnls, xyz::XYZ};
+
+/// Tolerance for the containment test, and the residual of the primary weights.
+const TOLERANCE: f64 = 1E-9;
+
+/// The chromaticity gamut of a set of primaries.
+#[derive(Clone, Debug)]
+pub struct PrimaryGamut {
+    primaries: Vec<XYZ>,
+    hull: Vec<usize>,
+}
+
+impl PrimaryGamut {
+    /// Gamut of a set of at least three primaries, all for the same observer, with their
+    /// chromaticities not on a single line.
+    pub fn try_new(primaries: &[XYZ]) -> Result<Self, CmtError> {
+        if primaries.len() < 3 {
+            return Err(CmtError::ProvideAtLeastNValues(3));
+        }
+        if primaries.iter().any(|p| p.observer != primaries[0].observer) {
+            return Err(CmtError::RequireSameObserver);
+        }
+        let hull = convex_hull(&primaries.iter().map(|p| p.xy().values()).collect::<Vec<_>>());
+        if hull.len() < 3 {
+            return Err(CmtError::RequiresDistinctPoints);
+        }
+        Ok(Self { primaries: primaries.to_vec(), hull })
+    }
+
+    pub fn primaries(&self) -> &[XYZ] {
+        &self.primaries
+    }
+
+    pub fn observer(&self) -> Observer {
+        self.primaries[0].observer
+    }
+
+    /// Indices of the primaries on the boundary of the gamut, in counter-clockwise order.
+    /// Primaries within the gamut, and on its edges between two corners, are not included.
+    pub fn hull(&self) -> &[usize] {
+        &self.hull
+    }
+
+    /// Chromaticity coordinates of the corners of the gamut, in counter-clockwise order.
+    pub fn vertices(&self) -> Vec<[f64; 2]> {
+        self.hull.iter().map(|&i| self.primaries[i].xy().values()).collect()
+    }
+
+    /// Area of the gamut in the CIE 1931 xy chromaticity diagram.
+    pub fn area_xy(&self) -> f64 {
+        polygon_area(&self.vertices())
+    }
+
+    /// Area of the gamut in the CIE 1976 u'v' chromaticity diagram.
+    ///
+    /// Straight lines in the xy diagram are straight in the u'v' diagram too, so the gamut is the
+    /// polygon through the u'v' coordinates of its corners.
+    pub fn area_uvprime(&self) -> f64 {
+        polygon_area(&self.hull.iter().map(|&i| self.primaries[i].xy().uvprime().values()).collect::<Vec<_>>())
+    }
+
+    /// Checks if a chromaticity, in the CIE 1931 xy diagram, is within, or on the boundary of, the gamut.
+    pub fn contains(&self, xy: [f64; 2]) -> bool {
+        let vertices = self.vertices();
+        let [x, y] = xy;
+        (0..vertices.len()).all(|i| {
+            let [[xa, ya], [xb, yb]] = [vertices[i], vertices[(i + 1) % vertices.len()]];
+            // non-negative cross product: on the left of, or on, the counter-clockwise edge
+            (xb - xa) * (y - ya) - (yb - ya) * (x - xa) >= -TOLERANCE
+        })
+    }
+
+    /**
+    Non-negative weights of the primaries, reproducing the tristimulus values of a target, as a
+    linear combination of the primaries' tristimulus values, as given, without normalization of
+    their luminous values.
+
+    Returns an `OutOfGamut` error if the target's chromaticity is outside the gamut, and a
+    `RequireSameObserver` error for a target of another observer.
+    */
+    pub fn weights(&self, target: &XYZ) -> Result<Vec<f64>, CmtError> {
+        if target.observer != self.observer() {
+            return Err(CmtError::RequireSameObserver);
+        }
+        let a = DMatrix::from_fn(3, self.primaries.len(), |r, c| absolute(&self.primaries[c])[r]);
+        let b = DVector::from_column_slice(&absolute(target));
+        let w = nnls(&a, &b);
+        if (&a * &w - &b).norm() > TOLERANCE * b.norm().max(1.0) {
+            return Err(CmtError::OutOfGamut);
+        }
+        Ok(w.iter().copied().collect())
+    }
+}
+
+/// Tristimulus values, not normalized to a luminous value of 100 for lights, as `XYZ::values` does.
+fn absolute(xyz: &XYZ) -> [f64; 3] {
+    xyz.xyz.unwrap_or(xyz.xyzn).into()
+}
+
+/// Indices of the points on the convex hull, in counter-clockwise order, using Andrew's monotone
+/// chain algorithm; colinear points on its edges are excluded.
+fn convex_hull(points: &[[f64; 2]]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..points.len()).collect();
+    order.sort_by(|&i, &j| points[i][0].total_cmp(&points[j][0]).then(points[i][1].total_cmp(&points[j][1])));
+    let cross = |o: usize, a: usize, b: usize| {
+        let [[xo, yo], [xa, ya], [xb, yb]] = [points[o], points[a], points[b]];
+        (xa - xo) * (yb - yo) - (ya - yo) * (xb - xo)
+    };
+    let mut hull: Vec<usize> = Vec::with_capacity(2 * points.len());
+    // lower hull, followed by the upper hull, each without its last point
+    for pass in [order.clone(), order.into_iter().rev().collect()] {
+        let start = hull.len();
+        for i in pass {
+            while hull.len() >= start + 2 && cross(hull[hull.len() - 2], hull[hull.len() - 1], i) <= TOLERANCE {
+                hull.pop();
+            }
+            hull.push(i);
+        }
+        hull.pop();
+    }
+    hull
+}
+
+/// Area of a simple polygon, using the shoelace formula.
+fn polygon_area(vertices: &[[f64; 2]]) -> f64 {
+    let n = vertices.len();
+    let twice: f64 = (0..n)
+        .map(|i| {
+            let [[xa, ya], [xb, yb]] = [vertices[i], vertices[(i + 1) % n]];
+            xa * yb - xb * ya
+        })
+        .sum();
+    twice.abs() / 2.0
+}
+
+#[cfg(test)]
+mod multi_primary_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_primary_gamut() {
+        let xyz = |x: f64, y: f64, l: f64| XYZ::try_from_chromaticity(x, y, Some(l), None).unwrap();
+        // sRGB primaries, with a white point within, and a point on the green-blue edge
+        let primaries = [xyz(0.64, 0.33, 21.0), xyz(0.3127, 0.3290, 50.0), xyz(0.30, 0.60, 72.0), xyz(0.225, 0.33, 40.0), xyz(0.15, 0.06, 7.0)];
+        let gamut = PrimaryGamut::try_new(&primaries).unwrap();
+        assert_eq!(gamut.hull(), [4, 0, 2]);
+        assert_abs_diff_eq!(gamut.area_xy(), 0.1121, epsilon = 1E-4);
+        let [red, green, blue] = [primaries[0].xy().uvprime().values(), primaries[2].xy().uvprime().values(), primaries[4].xy().uvprime().values()];
+        let area = ((green[0] - red[0]) * (blue[1] - red[1]) - (green[1] - red[1]) * (blue[0] - red[0])).abs() / 2.0;
+        assert_abs_diff_eq!(gamut.area_uvprime(), area, epsilon = 1E-12);
+
+        assert!(gamut.contains([0.3127, 0.3290]));
+        assert!(gamut.contains([0.64, 0.33]));
+        assert!(!gamut.contains([0.70, 0.29]));
+
+        let target = xyz(0.3127, 0.3290, 80.0);
+        let w = gamut.weights(&target).unwrap();
+        let mix = w.iter().zip(primaries.iter()).fold([0.0; 3], |s, (&w, p)| {
+            let [x, y, z] = p.xyzn.into();
+            [s[0] + w * x, s[1] + w * y, s[2] + w * z]
+        });
+        assert_abs_diff_eq!(mix.as_ref(), target.xyzn.as_slice(), epsilon = 1E-9);
+        assert_eq!(gamut.weights(&xyz(0.70, 0.29, 10.0)), Err(CmtError::OutOfGamut));
+
+        assert!(PrimaryGamut::try_new(&primaries[..2]).is_err());
+        assert_eq!(PrimaryGamut::try_new(&[xyz(0.2, 0.2, 1.0), xyz(0.3, 0.3, 1.0), xyz(0.4, 0.4, 1.0)]).unwrap_err(), CmtError::RequiresDistinctPoints);
+    }
+}
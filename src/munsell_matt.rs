@@ -38,7 +38,7 @@ impl MunsellMatt {
     pub fn new(i:usize) -> Self {
         let key = MUNSELL_MATT_KEYS[i];
         let data:[f64;MATT_N] = MUNSELL_MATT_DATA[i*MATT_N..(i+1)*MATT_N].iter().map(|v| *v as f64).collect::<Vec<f64>>().try_into().unwrap();
-        let spectrum = Spectrum::linear_interpolate(&[380.0, 780.0], &data).unwrap();
+        let spectrum = Spectrum::try_linear_interpolate(&[380.0, 780.0], &data).unwrap();
         MunsellMatt(key.to_string(), spectrum)
     }
     
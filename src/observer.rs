@@ -68,9 +68,11 @@ Color matching functions defined by the CIE have a broad wavelength range.
 
 use std::{borrow::{Borrow, Cow}, sync::OnceLock};
 use wasm_bindgen::{convert::IntoWasmAbi, prelude::wasm_bindgen};
-use nalgebra::{Matrix3, SMatrix, Vector3};
+use nalgebra::{Matrix3, SMatrix, SVector, Vector3};
+use strum::IntoEnumIterator;
 use crate::{
     lab::CieLab, 
+    math,
     physics::{planck, planck_slope, to_wavelength}, 
     spectrum::{Spectrum, NS}, 
     xyz::XYZ, 
@@ -78,6 +80,7 @@ use crate::{
     colorant::Colorant, 
     traits::{Filter, Light}, 
     geometry::LineAB, 
+    rgb::RGB, 
     rgbspace::RgbSpace, 
     std_illuminants::StdIlluminant
 };
@@ -93,7 +96,8 @@ use crate::{
  */
 #[cfg(not(feature="supplemental-observers"))]
 #[wasm_bindgen]
-#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug, strum_macros::EnumIter)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Observer { 
     #[default]
     Std1931, 
@@ -101,7 +105,8 @@ pub enum Observer {
 
 #[cfg(feature="supplemental-observers")]
 #[wasm_bindgen]
-#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug, strum_macros::EnumIter)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Observer { 
     #[default]
     Std1931, 
@@ -110,6 +115,15 @@ pub enum Observer {
     Std2015_10
 }
 
+/// Number of observers, with the `supplemental-observers` feature, used to size the buffers of
+/// values calculated per observer.
+pub(crate) const N_OBSERVERS: usize = 4;
+
+/// Number of spectra in the chunks of a batch, which are calculated in parallel with the `rayon`
+/// feature.
+#[cfg(feature = "rayon")]
+const BATCH_CHUNK: usize = 4096;
+
 impl Observer {
     /**
         Get a reference to the data for the specified `Observer`.
@@ -128,6 +142,35 @@ impl Observer {
 }
 
 
+/// Numerical integration rule, for spectral integrals over the 380 to 780 nanometer domain, sampled
+/// at one nanometer steps.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug, strum_macros::EnumIter)]
+pub enum IntegrationRule {
+    /// Plain summation of the samples, as recommended by CIE 15:2004, and used by default.
+    #[default]
+    Rectangle,
+    /// Trapezoidal rule, with half weights for the samples at 380 and 780 nanometer.
+    Trapezoid,
+    /// Composite Simpson's rule, with weights 1/3, 4/3, 2/3, ..., 4/3, 1/3, for the even number of
+    /// intervals in the domain.
+    Simpson,
+}
+
+impl IntegrationRule {
+    /// Weights of the samples, for a unit step size.
+    pub fn weights(&self) -> SVector<f64, NS> {
+        match self {
+            IntegrationRule::Rectangle => SVector::repeat(1.0),
+            IntegrationRule::Trapezoid => SVector::from_fn(|i, _| if i == 0 || i == NS - 1 { 0.5 } else { 1.0 }),
+            IntegrationRule::Simpson => SVector::from_fn(|i, _| match i {
+                i if i == 0 || i == NS - 1 => 1.0 / 3.0,
+                i if i % 2 == 1 => 4.0 / 3.0,
+                _ => 2.0 / 3.0,
+            }),
+        }
+    }
+}
+
 /**
     A data structure to define Standard Observers, such as the CIE 1931 2º and
     the CIE 2015 standard observers.
@@ -176,6 +219,51 @@ impl ObserverData {
         xyz.set_illuminance(100.0)
     }
 
+    /// Tristimulus values of a fluorescent colorant, described by its Donaldson matrix, for a
+    /// light, with the light leaving the sample calculated by
+    /// [`BiSpectralColorant::radiance`](crate::bispectral::BiSpectralColorant::radiance), and
+    /// normalized as in [`xyz`](Self::xyz), to a luminance value of 100 for the light.
+    pub fn xyz_bispectral(&self, light: &dyn Light, colorant: &crate::bispectral::BiSpectralColorant) -> XYZ {
+        let xyzn = light.xyzn(self.tag, None);
+        self.xyz_from_spectrum(&colorant.radiance(light), Some(xyzn)).set_illuminance(100.0)
+    }
+
+    /**
+    Normalization constant k = 100 / ∫ S(λ) ȳ(λ) dλ, for reflectance, and transmittance,
+    colorimetry, as used in [`xyz`](Self::xyz) to scale the tristimulus values of the light to a
+    luminance value Y of 100.
+
+    The integral is a sum over the one nanometer samples in this library, and the result is
+    independent of the wavelength step of the original data, for legacy constants which include
+    the step size Δλ in the sum. Constants from sums without the step size, as used by some
+    instruments with 10 nanometer data, have to be divided by Δλ to be used in
+    [`xyz_with_k`](Self::xyz_with_k).
+    ```
+    use colorimetry::prelude::*;
+
+    let d65 = Illuminant::d65();
+    let k = CIE1931.k_factor(&d65);
+    let xyz = CIE1931.xyz_with_k(&d65, None, k);
+    approx::assert_abs_diff_eq!(xyz.luminous_value(), 100.0, epsilon = 1E-9);
+    ```
+    */
+    pub fn k_factor(&self, light: &dyn Light) -> f64 {
+        100.0 / self.data.row(1).transpose().dot(&light.spectrum().0)
+    }
+
+    /**
+    Tristimulus values of a light, and an optional filter, scaled with a fixed normalization
+    constant k, instead of the one calculated with [`k_factor`](Self::k_factor), for example to
+    match the results of instrument firmware using a fixed value.
+    The white reference luminance is then not necessarily equal to 100.
+    */
+    pub fn xyz_with_k(&self, light: &dyn Light, filter: Option<&dyn Filter>, k: f64) -> XYZ {
+        let s = light.spectrum();
+        let xyzn = self.data * s.0 * k;
+        let xyz = filter.map(|flt| self.data * s.0.component_mul(&flt.spectrum().0) * k);
+        XYZ::from_vecs(xyzn, xyz, self.tag)
+    }
+
     /**
         Calculates Tristimulus valus, in form of an [XYZ] object of a general spectrum.
         If a reference white is given (rhs), it will copy its  tristimulus value, and the spectrum
@@ -193,6 +281,66 @@ impl ObserverData {
         }
     }
 
+    /**
+    Tristimulus values of a general spectrum, as [`xyz_from_spectrum`](Self::xyz_from_spectrum),
+    using an alternative integration rule.
+
+    The rules only differ in the weights of the samples at the ends of the domain, and, for
+    Simpson's rule, in alternating weights; for the smooth color matching functions, tabulated at
+    one nanometer steps, and vanishing at the ends of the domain, the differences are small,
+    typically in the sixth significant digit, but they can be relevant to reconcile results with
+    other software, or for spectra with narrow lines, or with significant power at the domain
+    limits.
+    ```
+    use colorimetry::prelude::*;
+
+    let d65 = Illuminant::d65();
+    let rect = CIE1931.xyz_from_spectrum_with(&d65, None, IntegrationRule::Rectangle);
+    assert_eq!(rect, CIE1931.xyz_from_spectrum(&d65, None));
+    let simpson = CIE1931.xyz_from_spectrum_with(&d65, None, IntegrationRule::Simpson);
+    approx::assert_abs_diff_eq!(rect.xy().values().as_ref(), simpson.xy().values().as_ref(), epsilon = 1E-5);
+    ```
+    */
+    pub fn xyz_from_spectrum_with(&self, spectrum: &Spectrum, rhs: Option<XYZ>, rule: IntegrationRule) -> XYZ {
+        let weighted = Spectrum(spectrum.0.component_mul(&rule.weights()));
+        self.xyz_from_spectrum(&weighted, rhs)
+    }
+
+    /**
+    Tristimulus values of a batch of lights, such as the pixels of a multispectral image, with the
+    same values as [`xyz_from_spectrum`](Self::xyz_from_spectrum) for each spectrum, but calculated
+    as a single product of the color matching function matrix, and a matrix of the spectra.
+
+    With the `rayon` feature, the batch is split into chunks, which are calculated in parallel.
+    ```
+    use colorimetry::prelude::*;
+
+    let spectra: Vec<Spectrum> = (0..100).map(|i| *Illuminant::planckian(2000.0 + 50.0 * i as f64)).collect();
+    let xyz = CIE1931.xyz_batch(&spectra);
+    assert_eq!(xyz[10], CIE1931.xyz_from_spectrum(&spectra[10], None));
+    ```
+    */
+    pub fn xyz_batch(&self, spectra: &[Spectrum]) -> Vec<XYZ> {
+        #[cfg(feature = "rayon")]
+        {
+            use rayon::prelude::*;
+            spectra.par_chunks(BATCH_CHUNK).flat_map_iter(|chunk| self.xyz_batch_chunk(chunk)).collect()
+        }
+        #[cfg(not(feature = "rayon"))]
+        self.xyz_batch_chunk(spectra)
+    }
+
+    fn xyz_batch_chunk(&self, spectra: &[Spectrum]) -> Vec<XYZ> {
+        let xyz = self.data * Spectrum::spectra_as_matrix(spectra) * self.lumconst;
+        xyz.column_iter().map(|c| XYZ::from_vecs(c.into_owned(), None, self.tag)).collect()
+    }
+
+    /// RGB values of a batch of lights, in the given color space, with the tristimulus values
+    /// calculated by [`xyz_batch`](Self::xyz_batch).
+    pub fn rgb_batch(&self, spectra: &[Spectrum], space: RgbSpace) -> Vec<RGB> {
+        self.xyz_batch(spectra).iter().map(|xyz| xyz.rgb(Some(space))).collect()
+    }
+
 
     /**
         Tristimulus Values for the Standard Illuminants in this library.
@@ -201,11 +349,10 @@ impl ObserverData {
         value is provided, in case they are.
     */
     pub fn xyz_cie_table(&self, std_illuminant: &StdIlluminant, illuminance: Option<f64>) -> XYZ {
-        const EMPTY:OnceLock<XYZ> = OnceLock::new();
         const XYZ_STD_ILLUMINANTS_LEN: usize = 64;
-        static XYZ_STD_ILLUMINANTS : OnceLock<[OnceLock<XYZ>;XYZ_STD_ILLUMINANTS_LEN]> = OnceLock::new();
-        let xyz_std_illuminants = XYZ_STD_ILLUMINANTS.get_or_init(||[EMPTY; XYZ_STD_ILLUMINANTS_LEN]);
-        let xyz = *xyz_std_illuminants[*std_illuminant as usize].get_or_init(||{
+        static XYZ_STD_ILLUMINANTS : OnceLock<[[OnceLock<XYZ>;XYZ_STD_ILLUMINANTS_LEN];N_OBSERVERS]> = OnceLock::new();
+        let xyz_std_illuminants = XYZ_STD_ILLUMINANTS.get_or_init(||[const { [const { OnceLock::new() }; XYZ_STD_ILLUMINANTS_LEN] }; N_OBSERVERS]);
+        let xyz = *xyz_std_illuminants[self.tag as usize][*std_illuminant as usize].get_or_init(||{
             self.xyz_from_spectrum(std_illuminant.illuminant(), None)
         });
         if let Some(l) = illuminance {
@@ -370,12 +517,47 @@ impl ObserverData {
         [x/s, y/s]
     }
 
+    /**
+    Wavelength, in nanometer, of the spectral locus point nearest to a chromaticity point `[x, y]`,
+    and the distance of the point to the spectral locus, in the CIE xy chromaticity diagram.
+
+    The spectral locus is taken as the line through its points at 1 nanometer intervals, within its
+    unique range, as given by `spectral_locus_nm_min` and `spectral_locus_nm_max`, with the
+    wavelength linearly interpolated between them.
+    Chromaticities of laser, and other narrowband, sources are at small distances from the locus.
+    ```
+    use colorimetry::prelude::*;
+
+    let laser = CIE1931.xyz(&StdIlluminant::D65, Some(&Colorant::gaussian(532.0, 0.5)));
+    let (l, d) = CIE1931.spectral_locus_nearest(laser.xy().values());
+    approx::assert_abs_diff_eq!(l, 532.0, epsilon = 0.1);
+    assert!(d < 1E-4);
+    ```
+    */
+    pub fn spectral_locus_nearest(&self, xy: [f64;2]) -> (f64, f64) {
+        let [x, y] = xy;
+        let (min, max) = (self.spectral_locus_index_min(), self.spectral_locus_index_max());
+        let mut nearest = (f64::MAX, 0.0);
+        for i in min..max {
+            let [x0, y0] = self.spectral_locus_by_index(i);
+            let [x1, y1] = self.spectral_locus_by_index(i + 1);
+            let [dx, dy] = [x1 - x0, y1 - y0];
+            // fraction of the segment of the projection of the point, limited to the segment
+            let t = (((x - x0) * dx + (y - y0) * dy) / (dx * dx + dy * dy)).clamp(0.0, 1.0);
+            let d = math::hypot(x - x0 - t * dx, y - y0 - t * dy);
+            if d < nearest.0 {
+                nearest = (d, (i + 380) as f64 + t);
+            }
+        }
+        (nearest.1, nearest.0)
+    }
+
     /// The index value of the blue spectral locus edge.
     ///
     /// Any further spectral locus points will hover around this edge, and will not have a unique wavelength.
     pub fn spectral_locus_index_min(&self) -> usize {
-        static MIN: OnceLock<usize> = OnceLock::new();
-        *MIN.get_or_init(||{
+        static MIN: [OnceLock<usize>; N_OBSERVERS] = [const { OnceLock::new() }; N_OBSERVERS];
+        *MIN[self.tag as usize].get_or_init(||{
             const START: usize = 100;
             let mut lp = LineAB::try_new(self.spectral_locus_by_index(START), [0.33333, 0.33333]).unwrap();
             let mut m = START - 1;
@@ -402,8 +584,8 @@ impl ObserverData {
     ///
     /// Any further spectral locus points will hover around this edge.
     pub fn spectral_locus_index_max(&self) -> usize {
-        static MAX: OnceLock<usize> = OnceLock::new();
-        *MAX.get_or_init(||{
+        static MAX: [OnceLock<usize>; N_OBSERVERS] = [const { OnceLock::new() }; N_OBSERVERS];
+        *MAX[self.tag as usize].get_or_init(||{
             const START: usize = 300;
             let mut lp = LineAB::try_new(self.spectral_locus_by_index(START), [0.33333, 0.33333]).unwrap();
             let mut m = START + 1;
@@ -426,49 +608,69 @@ impl ObserverData {
         self.spectral_locus_index_max()+380
     }
 
-    /// Calculates the RGB to XYZ matrix, for a particular color space.
+    /// Tristimulus values of the primaries of a color space, as columns, each with a luminous
+    /// value of one.
+    pub(crate) fn primaries_xyz(&self, rgbspace: &RgbSpace) -> Matrix3<f64> {
+        rgbspace.data().0.primaries_xyz(self)
+    }
+
+    /// Calculates the RGB to XYZ matrix, for a particular color space, from the spectra of its
+    /// primaries, and its white, for this observer. See [`RgbSpaceData::rgb2xyz`](crate::rgbspace::RgbSpaceData::rgb2xyz).
     /// The matrices are buffered.
     pub fn rgb2xyz(&self, rgbspace: &RgbSpace) -> &'static Matrix3<f64> {
-        const EMPTY:OnceLock<Matrix3<f64>> = OnceLock::new();
         const RGB2XYZ_AR_LEN: usize = 16;
-        static RGB2XYZ_AR : OnceLock<[OnceLock<Matrix3<f64>>;RGB2XYZ_AR_LEN]> = OnceLock::new();
-        let rgb2xyz_ar =RGB2XYZ_AR.get_or_init(||[EMPTY;RGB2XYZ_AR_LEN]);
-        rgb2xyz_ar[*rgbspace as usize].get_or_init(||{
-            let (space,_) = rgbspace.data();
-            let mut rgb2xyz = 
-                Matrix3::from_iterator(space.primaries
-                    .iter()
-                    .flat_map(|s|self.xyz_from_spectrum(s, None)
-                    .set_illuminance(1.0).values()));
-           // let xyzw = self.xyz_raw(&space.white, None).set_illuminance(1.0);
-            let xyzw = self.xyz(&space.white, None).set_illuminance(1.0);
-            let decomp = rgb2xyz.lu();
+        static RGB2XYZ_AR : OnceLock<[[OnceLock<Matrix3<f64>>;RGB2XYZ_AR_LEN];N_OBSERVERS]> = OnceLock::new();
+        let rgb2xyz_ar =RGB2XYZ_AR.get_or_init(||[const { [const { OnceLock::new() }; RGB2XYZ_AR_LEN] }; N_OBSERVERS]);
+        rgb2xyz_ar[self.tag as usize][*rgbspace as usize].get_or_init(||{
             // unwrap: only used with library color spaces
-            let rgbw = decomp.solve(&xyzw.xyzn).unwrap();
-            for (i, mut col) in rgb2xyz.column_iter_mut().enumerate() {
-                col *= rgbw[i];
-            }
-            rgb2xyz
-
+            rgbspace.data().0.rgb2xyz(self).unwrap()
         })
     }
 
     /// Calculates the RGB to XYZ matrix, for a particular color space.
     /// The matrices are buffered.
     pub fn xyz2rgb(&self, rgbspace: RgbSpace) -> &'static Matrix3<f64> {
-        const EMPTY:OnceLock<Matrix3<f64>> = OnceLock::new();
         const XYZ2RGB_AR_LEN: usize = 16;
-        static XYZ2RGB_AR : OnceLock<[OnceLock<Matrix3<f64>>;XYZ2RGB_AR_LEN]> = OnceLock::new();
-        let xyz2rgb =XYZ2RGB_AR.get_or_init(||[EMPTY;XYZ2RGB_AR_LEN]);
-        xyz2rgb[rgbspace as usize].get_or_init(||{
+        static XYZ2RGB_AR : OnceLock<[[OnceLock<Matrix3<f64>>;XYZ2RGB_AR_LEN];N_OBSERVERS]> = OnceLock::new();
+        let xyz2rgb =XYZ2RGB_AR.get_or_init(||[const { [const { OnceLock::new() }; XYZ2RGB_AR_LEN] }; N_OBSERVERS]);
+        xyz2rgb[self.tag as usize][rgbspace as usize].get_or_init(||{
             // unwrap: only used with library color spaces
             self.rgb2xyz(&rgbspace).try_inverse().unwrap()
         })
     }
 
+    /// Calculates the values this observer buffers on first use: the RGB to XYZ matrices, and
+    /// their inverses, for all library color spaces, and the tristimulus values of the standard
+    /// illuminants. Color spaces with primaries which can not be resolved by the observer are
+    /// skipped.
+    ///
+    /// Calling this at startup avoids the delays of these calculations in the first conversions,
+    /// for example in real-time applications.
+    pub fn precompute(&self) {
+        for space in RgbSpace::iter() {
+            if self.primaries_xyz(&space).lu().is_invertible() {
+                self.xyz2rgb(space);
+            }
+        }
+        for std_illuminant in StdIlluminant::iter() {
+            self.xyz_cie_table(&std_illuminant, None);
+        }
+    }
+
 
 }
 
+/// Calculates all values buffered on first use, for all observers, and all library color spaces.
+/// See [`ObserverData::precompute`].
+pub fn precompute() {
+    for observer in Observer::iter() {
+        observer.data().precompute();
+    }
+    for space in RgbSpace::iter() {
+        space.data().0.primaries_as_colorants();
+    }
+}
+
 // JS-WASM Interface code
 #[cfg(target_arch="wasm32")]
 #[wasm_bindgen]
@@ -483,17 +685,79 @@ mod obs_test {
     use crate::prelude::{StdIlluminant, CIE1931};
     use approx::assert_ulps_eq;
 
+    #[test]
+    fn test_integration_rule(){
+        use crate::prelude::*;
+        for rule in [IntegrationRule::Rectangle, IntegrationRule::Trapezoid, IntegrationRule::Simpson] {
+            // the weights sum to the number of intervals, or samples, in the domain
+            let n = rule.weights().sum();
+            assert_ulps_eq!(n, if rule == IntegrationRule::Rectangle { 401.0 } else { 400.0 }, epsilon = 1E-9);
+        }
+        // a flat spectrum has power at the domain limits, where the rules differ
+        let flat = Spectrum(nalgebra::SVector::repeat(1.0));
+        let rect = CIE1931.xyz_from_spectrum_with(&flat, None, IntegrationRule::Rectangle);
+        let trap = CIE1931.xyz_from_spectrum_with(&flat, None, IntegrationRule::Trapezoid);
+        assert!(rect.xyzn.y > trap.xyzn.y);
+        assert_ulps_eq!(trap.xyzn.y / rect.xyzn.y, 1.0, epsilon = 1E-6);
+    }
+
+    #[test]
+    #[cfg(feature = "cie-illuminants")]
+    fn test_k_factor(){
+        use crate::prelude::*;
+        let a = StdIlluminant::A.illuminant();
+        let sample = Colorant::gaussian(550.0, 40.0);
+        let k = CIE1931.k_factor(a);
+        assert_ulps_eq!(CIE1931.xyz_with_k(a, Some(&sample), k), CIE1931.xyz(a, Some(&sample)), epsilon = 1E-9);
+        // a fixed, legacy, constant scales all values
+        let fixed = CIE1931.xyz_with_k(a, Some(&sample), 1.01 * k);
+        assert_ulps_eq!(fixed.xyzn.y, 101.0, epsilon = 1E-9);
+        assert_ulps_eq!(fixed.xyz.unwrap().y, 1.01 * CIE1931.xyz(a, Some(&sample)).xyz.unwrap().y, epsilon = 1E-9);
+    }
+
+    #[test]
+    fn test_xyz_batch(){
+        use crate::prelude::*;
+        let spectra: Vec<Spectrum> = (0..5000).map(|i| *Illuminant::led(400.0 + 0.07 * i as f64, 20.0)).collect();
+        let xyz = CIE1931.xyz_batch(&spectra);
+        let rgb = CIE1931.rgb_batch(&spectra, RgbSpace::SRGB);
+        assert_eq!(xyz.len(), spectra.len());
+        for i in [0, 4095, 4096, 4999] {
+            let want = CIE1931.xyz_from_spectrum(&spectra[i], None);
+            assert_ulps_eq!(xyz[i], want, epsilon = 1E-12);
+            assert_ulps_eq!(rgb[i], want.rgb(Some(RgbSpace::SRGB)), epsilon = 1E-12);
+        }
+        assert!(CIE1931.xyz_batch(&[]).is_empty());
+    }
+
     #[test]
     fn test_spectral_locus(){
-        let [x,y] = CIE1931.spectral_locus_by_nm(CIE1931.spectral_locus_nm_min()).unwrap().chromaticity();
+        let [x,y] = CIE1931.spectral_locus_by_nm(CIE1931.spectral_locus_nm_min()).unwrap().xy().values();
         assert_ulps_eq!(x, 0.17411, epsilon=1E-5);
         assert_ulps_eq!(y, 0.00496, epsilon=1E-5);
 
-        let [x,y] = CIE1931.spectral_locus_by_nm(CIE1931.spectral_locus_nm_max()).unwrap().chromaticity();
+        let [x,y] = CIE1931.spectral_locus_by_nm(CIE1931.spectral_locus_nm_max()).unwrap().xy().values();
         assert_ulps_eq!(x, 0.73469, epsilon=1E-5);
         assert_ulps_eq!(y, 0.26531, epsilon=1E-5);
     }
 
+    #[test]
+    fn test_spectral_locus_nearest(){
+        let (l, d) = CIE1931.spectral_locus_nearest(CIE1931.spectral_locus_by_nm(550).unwrap().xy().values());
+        assert_ulps_eq!(l, 550.0, epsilon=1E-9);
+        assert_ulps_eq!(d, 0.0, epsilon=1E-12);
+
+        // halfway between two locus points
+        let [x0, y0] = CIE1931.spectral_locus_by_index(100);
+        let [x1, y1] = CIE1931.spectral_locus_by_index(101);
+        let (l, _) = CIE1931.spectral_locus_nearest([(x0 + x1) / 2.0, (y0 + y1) / 2.0]);
+        assert_ulps_eq!(l, 480.5, epsilon=1E-9);
+
+        // a white point is far from the locus, with the nearest point in the green-yellow
+        let (l, d) = CIE1931.spectral_locus_nearest(CIE1931.xyz_d65().xy().values());
+        assert!(d > 0.2 && l > 490.0 && l < 600.0, "{l} {d}");
+    }
+
     #[test]
     fn test_spectral_locus_min_max(){
         let min = CIE1931.spectral_locus_index_min();
@@ -545,10 +809,10 @@ mod obs_test {
     fn test_planckian_locus(){
         // see https://www.waveformlighting.com/tech/calculate-cie-1931-xy-coordinates-from-cct
         // for test data (not clear what CMF domain they use)
-        let xy = CIE1931.xyz_planckian_locus(3000.0).chromaticity();
+        let xy = CIE1931.xyz_planckian_locus(3000.0).xy().values();
         approx::assert_abs_diff_eq!(&xy.as_ref(), &[0.43693,0.40407].as_ref(), epsilon = 2E-5);
 
-        let xy = CIE1931.xyz_planckian_locus(6500.0).chromaticity();
+        let xy = CIE1931.xyz_planckian_locus(6500.0).xy().values();
         approx::assert_abs_diff_eq!(&xy.as_ref(), &[0.31352,0.32363].as_ref(), epsilon = 6E-5);
     }
     
@@ -573,5 +837,22 @@ mod obs_test {
                 .xyz(&d65, Some(&crate::colorant::Colorant::black()));
         approx::assert_ulps_eq!(xyz, CIE1931.xyz_from_std_illuminant_x_fn(&d65, |_|0.0));
     }
+
+    #[test]
+    #[cfg(feature="supplemental-observers")]
+    fn test_buffers_per_observer(){
+        use crate::prelude::*;
+        // buffered values are calculated for each observer, and not shared between them
+        for obs in [&CIE1931, &CIE1964] {
+            approx::assert_ulps_eq!(obs.xyz_d65(), obs.xyz(&StdIlluminant::D65, None).set_illuminance(100.0), epsilon = 1E-9);
+            let white = obs.rgb2xyz(&RgbSpace::SRGB) * nalgebra::Vector3::repeat(1.0);
+            approx::assert_ulps_eq!(white, obs.xyz_d65().xyzn / 100.0, epsilon = 1E-9);
+            approx::assert_ulps_eq!(obs.xyz2rgb(RgbSpace::SRGB) * white, nalgebra::Vector3::repeat(1.0), epsilon = 1E-9);
+        }
+        assert!((CIE1931.xyz_d65().xy().values()[0] - CIE1964.xyz_d65().xy().values()[0]).abs() > 1E-3);
+
+        let lab = CIE1964.lab_d65(&Colorant::white());
+        approx::assert_ulps_eq!(lab.as_ref()[0], 100.0, epsilon = 1E-9);
+    }
     
 }
\ No newline at end of file
@@ -0,0 +1,174 @@
+/*!
+# Multi-Observer Comparison
+
+Tristimulus values, chromaticity coordinates, and correlated color temperatures, of the same
+spectral distribution, calculated with all the observers available in this library, together with
+their spread.
+
+The CIE 1931 2° observer is the basis of most lighting and display specifications, but
+observers for larger fields, such as the CIE 1964 10° observer, and the physiologically based
+CIE 2015 observers, can give significantly different results, in particular for narrow band
+sources such as LEDs.
+A comparison shows if these differences matter in an application.
+
+Correlated color temperatures are calculated for each observer with its own Planckian locus, as
+the temperature of the Planckian radiator with the shortest distance in the CIE 1960 uv
+chromaticity diagram of that observer.
+The supplemental observers require the `supplemental-observers` feature.
+*/
+
+use crate::{
+    illuminant::Illuminant,
+    math,
+    observer::Observer,
+    traits::Light,
+    xyz::XYZ,
+};
+
+/// Observers included in a comparison.
+const OBSERVERS: &[Observer] = &[
+    Observer::Std1931,
+    #[cfg(feature = "supplemental-observers")]
+    Observer::Std1964,
+    #[cfg(feature = "supplemental-observers")]
+    Observer::Std2015,
+    #[cfg(feature = "supplemental-observers")]
+    Observer::Std2015_10,
+];
+
+/// Temperature range, in Kelvin, of the correlated color temperature search.
+const CCT_RANGE: [f64; 2] = [1000.0, 100_000.0];
+
+/// Colorimetric values of a light for one observer.
+#[derive(Clone, Copy, Debug)]
+pub struct ObserverValues {
+    pub observer: Observer,
+    /// Tristimulus values, normalized to a luminous value of 100.
+    pub xyz: XYZ,
+    pub chromaticity: [f64; 2],
+    /// Correlated color temperature, in Kelvin, or `None` if out of the range from 1000 to
+    /// 100 000 K.
+    pub cct: Option<f64>,
+}
+
+/**
+Colorimetric values of a light, for all available observers.
+```
+use colorimetry::prelude::*;
+
+let comparison = ObserverComparison::new(&Illuminant::led(450.0, 20.0));
+assert_eq!(comparison.values()[0].observer, Observer::Std1931);
+assert!(comparison.max_uv_prime_distance() >= 0.0);
+```
+*/
+#[derive(Clone, Debug)]
+pub struct ObserverComparison(Vec<ObserverValues>);
+
+impl ObserverComparison {
+    pub fn new(light: &dyn Light) -> Self {
+        let values = OBSERVERS
+            .iter()
+            .map(|&observer| {
+                let xyz = light.xyzn(observer, Some(100.0));
+                ObserverValues { observer, xyz, chromaticity: xyz.xy().values(), cct: cct(xyz) }
+            })
+            .collect();
+        Self(values)
+    }
+
+    pub fn values(&self) -> &[ObserverValues] {
+        &self.0
+    }
+
+    /// Values for an observer, if available.
+    pub fn get(&self, observer: Observer) -> Option<&ObserverValues> {
+        self.0.iter().find(|v| v.observer == observer)
+    }
+
+    /// Largest difference, between all observers, of the x and the y chromaticity coordinates.
+    pub fn chromaticity_spread(&self) -> [f64; 2] {
+        [0, 1].map(|i| spread(self.0.iter().map(|v| v.chromaticity[i])))
+    }
+
+    /// Largest distance, between all pairs of observers, in the CIE 1976 u'v' chromaticity
+    /// diagram.
+    pub fn max_uv_prime_distance(&self) -> f64 {
+        let mut d: f64 = 0.0;
+        for (i, a) in self.0.iter().enumerate() {
+            for b in &self.0[i + 1..] {
+                d = d.max(a.xyz.uv_prime_distance(&b.xyz));
+            }
+        }
+        d
+    }
+
+    /// Largest difference in correlated color temperature, in Kelvin, between all observers, or
+    /// `None` if it could not be calculated for one of them.
+    pub fn cct_spread(&self) -> Option<f64> {
+        let ccts: Option<Vec<f64>> = self.0.iter().map(|v| v.cct).collect();
+        ccts.map(|c| spread(c.into_iter()))
+    }
+}
+
+fn spread(values: impl Iterator<Item = f64>) -> f64 {
+    let (min, max) = values.fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), v| (min.min(v), max.max(v)));
+    max - min
+}
+
+/// Correlated color temperature, with the observer's own Planckian locus, by a golden section
+/// search of the closest locus point in the CIE 1960 uv diagram, in the reciprocal temperature
+/// domain.
+fn cct(xyz: XYZ) -> Option<f64> {
+    let [u, v] = xyz.xy().uv60().values();
+    let distance = |mired: f64| {
+        let [up, vp] = Illuminant::planckian(1E6 / mired).xyzn(xyz.observer, None).xy().uv60().values();
+        math::hypot(u - up, v - vp)
+    };
+    let (mut a, mut b) = (1E6 / CCT_RANGE[1], 1E6 / CCT_RANGE[0]);
+    let g = (5f64.sqrt() - 1.0) / 2.0;
+    let (mut c, mut d) = (b - g * (b - a), a + g * (b - a));
+    let (mut fc, mut fd) = (distance(c), distance(d));
+    while b - a > 1E-6 {
+        if fc < fd {
+            (b, d, fd) = (d, c, fc);
+            c = b - g * (b - a);
+            fc = distance(c);
+        } else {
+            (a, c, fc) = (c, d, fd);
+            d = a + g * (b - a);
+            fd = distance(d);
+        }
+    }
+    let mired = 0.5 * (a + b);
+    // a minimum at the boundaries of the search range is out of range
+    let margin = 1E-3;
+    (mired > 1E6 / CCT_RANGE[1] + margin && mired < 1E6 / CCT_RANGE[0] - margin).then(|| 1E6 / mired)
+}
+
+#[cfg(test)]
+mod observer_comparison_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_comparison() {
+        let p = Illuminant::planckian(3000.0);
+        let comparison = ObserverComparison::new(&p);
+        for v in comparison.values() {
+            assert_abs_diff_eq!(v.cct.unwrap(), 3000.0, epsilon = 1E-3);
+            assert_abs_diff_eq!(v.xyz.values()[1], 100.0, epsilon = 1E-9);
+        }
+        assert_abs_diff_eq!(comparison.cct_spread().unwrap(), 0.0, epsilon = 2E-3);
+
+        #[cfg(feature = "cct")]
+        {
+            let led = Illuminant::led(450.0, 20.0) * 0.3;
+            let spd = *led + *Illuminant::led(570.0, 110.0);
+            let values: &[f64] = spd.as_ref();
+            let white = Illuminant::try_from(values).unwrap();
+            let want = CIE1931.xyz(&white, None).cct().unwrap().t();
+            let got = ObserverComparison::new(&white).get(Observer::Std1931).unwrap().cct.unwrap();
+            assert_abs_diff_eq!(got, want, epsilon = want * 1E-4);
+        }
+    }
+}
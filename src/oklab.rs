@@ -0,0 +1,144 @@
+/*!
+# OKLab
+
+OKLab is a perceptual color space, by Björn Ottosson, from "A perceptual color space for image
+processing", 2020, with a lightness L, ranging from 0.0 for black to 1.0 for white, and two
+opponent color coordinates a and b. It was derived from CAM16 data, and its Euclidean distances,
+and hue linearity, are better than those of CIELAB, while being as simple to calculate.
+This makes it a good choice for image processing operations, such as color clustering, gradients,
+and nearest color searches.
+
+OKLab is defined for the CIE 1931 observer, and a D65 white; tristimulus values with another white
+are chromatically adapted to D65 first, using a von Kries transform in the CAT16 cone space.
+*/
+
+use std::sync::LazyLock;
+
+use nalgebra::{matrix, Matrix3, Vector3};
+
+use crate::{cat::Cat, error::CmtError, math, observer::Observer, xyz::XYZ};
+
+/// D65 white point used in the OKLab definition, with a luminous value of 1.0.
+const D65: [f64; 3] = [0.950_47, 1.0, 1.088_83];
+
+/// Tristimulus values to cone responses.
+static M1: Matrix3<f64> = matrix![
+    0.818_933_010_1, 0.361_866_742_4, -0.128_859_713_7;
+    0.032_984_543_6, 0.929_311_871_5, 0.036_145_638_7;
+    0.048_200_301_8, 0.264_366_269_1, 0.633_851_707_0;
+];
+
+/// Non-linear cone responses to L, a, and b.
+static M2: Matrix3<f64> = matrix![
+    0.210_454_255_3, 0.793_617_785_0, -0.004_072_046_8;
+    1.977_998_495_1, -2.428_592_205_0, 0.450_593_709_9;
+    0.025_904_037_1, 0.782_771_766_2, -0.808_675_766_0;
+];
+
+static M1INV: LazyLock<Matrix3<f64>> = LazyLock::new(|| M1.try_inverse().unwrap());
+static M2INV: LazyLock<Matrix3<f64>> = LazyLock::new(|| M2.try_inverse().unwrap());
+
+/**
+OKLab coordinates of a color.
+```
+use colorimetry::prelude::*;
+
+let white = OkLab::try_from(CIE1931.xyz(&StdIlluminant::D65, Some(&Colorant::white()))).unwrap();
+approx::assert_abs_diff_eq!(white.values().as_ref(), [1.0, 0.0, 0.0].as_ref(), epsilon = 1E-3);
+```
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OkLab(pub(crate) Vector3<f64>);
+
+impl OkLab {
+    pub fn new(l: f64, a: f64, b: f64) -> Self {
+        Self(Vector3::new(l, a, b))
+    }
+
+    /// The L, a, and b values.
+    pub fn values(&self) -> [f64; 3] {
+        self.0.into()
+    }
+
+    /// Chroma, as the distance to the neutral axis.
+    pub fn chroma(&self) -> f64 {
+        math::hypot(self.0.y, self.0.z)
+    }
+
+    /// Hue angle, in radians, from 0 to 2π.
+    pub fn hue(&self) -> f64 {
+        math::atan2(self.0.z, self.0.y).rem_euclid(2.0 * std::f64::consts::PI)
+    }
+
+    /// Euclidean color difference with another color.
+    pub fn delta_e(&self, other: &Self) -> f64 {
+        (self.0 - other.0).norm()
+    }
+
+    /// Tristimulus values, for the CIE 1931 observer, with a D65 white with a luminous value of
+    /// 100.
+    pub fn xyz(&self) -> XYZ {
+        let xyzn = Vector3::from(D65) * 100.0;
+        XYZ::from_vecs(xyzn, Some(oklab_inverse(self.0, xyzn)), Observer::Std1931)
+    }
+}
+
+/// OKLab coordinates of tristimulus values, with a white reference, both for the CIE 1931 observer.
+pub(crate) fn oklab(xyz: Vector3<f64>, xyzn: Vector3<f64>) -> Vector3<f64> {
+    let [xyz, xyzn] = [xyz / xyzn.y, xyzn / xyzn.y];
+    let d65 = Vector3::from(D65);
+    let xyz = if (xyzn - d65).abs().max() > 1E-3 { Cat::Cat16.matrix(xyzn, d65) * xyz } else { xyz };
+    M2 * (M1 * xyz).map(math::cbrt)
+}
+
+/// Tristimulus values of OKLab coordinates, relative to a white reference, as the inverse of
+/// [`oklab`].
+pub(crate) fn oklab_inverse(lab: Vector3<f64>, xyzn: Vector3<f64>) -> Vector3<f64> {
+    let xyz = *M1INV * (*M2INV * lab).map(|v| v.powi(3)) * xyzn.y;
+    let [xyzn, d65] = [xyzn / xyzn.y, Vector3::from(D65)];
+    if (xyzn - d65).abs().max() > 1E-3 { Cat::Cat16.matrix(d65, xyzn) * xyz } else { xyz }
+}
+
+impl TryFrom<XYZ> for OkLab {
+    type Error = CmtError;
+
+    /// OKLab coordinates of a color, with its tristimulus values relative to its white reference.
+    fn try_from(xyz: XYZ) -> Result<Self, Self::Error> {
+        if xyz.observer != Observer::Std1931 {
+            return Err(CmtError::RequiresCIE1931XYZ);
+        }
+        let xyz0 = xyz.xyz.ok_or(CmtError::NoColorant)?;
+        Ok(Self(oklab(xyz0, xyz.xyzn)))
+    }
+}
+
+impl AsRef<[f64; 3]> for OkLab {
+    fn as_ref(&self) -> &[f64; 3] {
+        self.0.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod oklab_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_oklab() {
+        // reference values from Ottosson's OKLab description
+        let xyz = XYZ::new(&[95.047, 100.0, 108.883], Some(&[95.0, 100.0, 108.9]), Observer::Std1931);
+        let lab = OkLab::try_from(xyz).unwrap();
+        assert_abs_diff_eq!(lab.values()[0], 1.0, epsilon = 1E-3);
+        let xyz = XYZ::new(&[95.047, 100.0, 108.883], Some(&[100.0, 0.0, 0.0]), Observer::Std1931);
+        let lab = OkLab::try_from(xyz).unwrap();
+        assert_abs_diff_eq!(lab.values().as_ref(), [0.450, 1.236, -0.019].as_ref(), epsilon = 1E-3);
+
+        // roundtrip
+        let lab = OkLab::new(0.6, 0.1, -0.05);
+        let back = OkLab::try_from(lab.xyz()).unwrap();
+        assert_abs_diff_eq!(lab.values().as_ref(), back.values().as_ref(), epsilon = 1E-9);
+        assert_abs_diff_eq!(lab.chroma(), 0.1118, epsilon = 1E-4);
+
+        assert!(OkLab::try_from(XYZ_D65).is_err());
+    }
+}
@@ -0,0 +1,449 @@
+/*!
+# Palettes
+
+Extraction of color palettes from images, by k-means clustering of the pixel colors in OKLab, a
+perceptually uniform color space, in which Euclidean distances correspond to color differences.
+
+Each [`PaletteEntry`] records the fraction of the image's pixels in its cluster, and their spread,
+as the root mean square OKLab distance to the cluster's center, which characterizes the texture
+of the surfaces represented by a palette color.
+Its spectral reflectance factor is reconstructed from the cluster's center, by one of the
+[`Upsampling`] methods, so extracted palettes, such as brand colors from product photographs, can
+be evaluated under other light sources.
+
+A [`Palette`] is a container of named colors, such as a brand color library, or a swatch book,
+loaded from user-provided CSV, or CxF files, with CIELAB values, or spectral reflectance factors.
+It supports lookup by name, and nearest-match queries, for a selectable color difference metric.
+No proprietary color libraries are included in this library.
+*/
+
+use std::collections::HashMap;
+
+use nalgebra::Vector3;
+
+use crate::{
+    colorant::Colorant,
+    error::CmtError,
+    illuminant::Illuminant,
+    lab::{CieLab, DeltaE},
+    observer::Observer,
+    oklab::{oklab, oklab_inverse, OkLab},
+    rgb::RGB,
+    spectrum::Spectrum,
+    traits::Light,
+    upsampling::Upsampling,
+    xyz::XYZ,
+};
+
+/// Maximum number of k-means iterations.
+const MAX_ITERATIONS: usize = 100;
+
+/// A color extracted from an image.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PaletteEntry {
+    /// The center of the cluster of pixel colors.
+    pub oklab: OkLab,
+    /// The center of the cluster, in the color space of the image, clamped to its gamut.
+    pub rgb: RGB,
+    /// Fraction of the pixels in the cluster.
+    pub weight: f64,
+    /// Root mean square OKLab distance of the pixel colors to the center of the cluster.
+    pub spread: f64,
+    /// Spectral reflectance factor reconstructed from the cluster center.
+    pub colorant: Colorant,
+}
+
+/**
+Extracts a palette of at most `k` colors from the RGB pixels of an image, sorted by decreasing
+weight, with spectral reflectance factors reconstructed by an upsampling method.
+
+The clusters are initialized deterministically, with the first center closest to the mean color,
+and further centers at the pixel colors farthest from the centers already chosen, so the results
+are reproducible, and include small areas of distinct colors.
+Fails for images which are empty, or which do not use the CIE 1931 observer, for which OKLab is
+defined.
+```
+use colorimetry::prelude::*;
+
+let red = RGB::new(0.8, 0.1, 0.1, None, None);
+let gray = RGB::new(0.5, 0.5, 0.5, None, None);
+let pixels = [vec![red; 30], vec![gray; 70]].concat();
+let palette = extract_palette(&pixels, 2, Upsampling::Smits).unwrap();
+assert_eq!(palette.len(), 2);
+approx::assert_abs_diff_eq!(palette[0].weight, 0.7, epsilon = 1E-9);
+approx::assert_abs_diff_eq!(palette[1].rgb, red, epsilon = 1E-6);
+```
+*/
+pub fn extract_palette(pixels: &[RGB], k: usize, upsampling: Upsampling) -> Result<Vec<PaletteEntry>, CmtError> {
+    let Some(first) = pixels.first() else {
+        return Err(CmtError::ErrorString("extract_palette: no pixels".to_string()));
+    };
+    if first.observer != Observer::Std1931 {
+        return Err(CmtError::RequiresCIE1931XYZ);
+    }
+    let space = first.space;
+    let rgb2xyz = first.observer.data().rgb2xyz(&space);
+    let white = rgb2xyz * Vector3::repeat(1.0);
+    let labs: Vec<Vector3<f64>> = pixels.iter().map(|p| oklab(rgb2xyz * p.rgb, white)).collect();
+
+    let mut centers = initial_centers(&labs, k.max(1));
+    let mut assignment = vec![0; labs.len()];
+    for i in 0..MAX_ITERATIONS {
+        let mut changed = false;
+        for (a, lab) in assignment.iter_mut().zip(&labs) {
+            let nearest = nearest(&centers, lab);
+            changed |= *a != nearest;
+            *a = nearest;
+        }
+        if i > 0 && !changed {
+            break;
+        }
+        for (j, center) in centers.iter_mut().enumerate() {
+            let (sum, n) = labs
+                .iter()
+                .zip(&assignment)
+                .filter(|(_, &a)| a == j)
+                .fold((Vector3::zeros(), 0), |(s, n), (lab, _)| (s + lab, n + 1));
+            if n > 0 {
+                *center = sum / n as f64;
+            }
+        }
+    }
+
+    let xyz2rgb = first.observer.data().xyz2rgb(space);
+    let mut palette = Vec::with_capacity(centers.len());
+    for (j, center) in centers.iter().enumerate() {
+        let members: Vec<&Vector3<f64>> = labs.iter().zip(&assignment).filter(|(_, &a)| a == j).map(|(lab, _)| lab).collect();
+        if members.is_empty() {
+            continue;
+        }
+        let spread = (members.iter().map(|lab| (*lab - center).norm_squared()).sum::<f64>() / members.len() as f64).sqrt();
+        let oklab = OkLab(*center);
+        let xyz = oklab_inverse(*center, white);
+        let rgb = RGB::new(0.0, 0.0, 0.0, Some(first.observer), Some(space));
+        let rgb = RGB { rgb: (xyz2rgb * xyz).map(|v| v.clamp(0.0, 1.0)), ..rgb };
+        palette.push(PaletteEntry {
+            oklab,
+            rgb,
+            weight: members.len() as f64 / labs.len() as f64,
+            spread,
+            colorant: upsampling.colorant(&rgb)?,
+        });
+    }
+    palette.sort_by(|a, b| b.weight.total_cmp(&a.weight));
+    Ok(palette)
+}
+
+/// Index of the center closest to a color.
+fn nearest(centers: &[Vector3<f64>], lab: &Vector3<f64>) -> usize {
+    centers
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| (*a - lab).norm_squared().total_cmp(&(*b - lab).norm_squared()))
+        .map(|(i, _)| i)
+        .unwrap()
+}
+
+/// Initial cluster centers, by farthest point sampling, starting with the color closest to the
+/// mean, and omitting duplicate colors.
+fn initial_centers(labs: &[Vector3<f64>], k: usize) -> Vec<Vector3<f64>> {
+    let mean = labs.iter().sum::<Vector3<f64>>() / labs.len() as f64;
+    let mut centers = vec![labs[nearest(labs, &mean)]];
+    let mut distances: Vec<f64> = labs.iter().map(|lab| (lab - centers[0]).norm_squared()).collect();
+    while centers.len() < k {
+        let (i, &d) = distances.iter().enumerate().max_by(|(_, a), (_, b)| a.total_cmp(b)).unwrap();
+        if d < 1E-12 {
+            break;
+        }
+        centers.push(labs[i]);
+        for (distance, lab) in distances.iter_mut().zip(labs) {
+            *distance = distance.min((lab - labs[i]).norm_squared());
+        }
+    }
+    centers
+}
+
+/// A named color in a [`Palette`], with its CIELAB values, and its spectral reflectance factor, if
+/// known.
+#[derive(Clone, Debug)]
+pub struct NamedColor {
+    pub name: String,
+    pub lab: CieLab,
+    pub colorant: Option<Colorant>,
+}
+
+/**
+A library of named colors, with their CIELAB values for an illuminant, and an observer.
+```
+use colorimetry::prelude::*;
+
+let csv = "Name,L,a,b
+Sky,62.0,-5.0,-30.0
+Sun,85.0,5.0,80.0";
+let palette = Palette::from_csv(csv, &StdIlluminant::D50, Observer::Std1931).unwrap();
+assert_eq!(palette.len(), 2);
+assert_eq!(palette.get("Sun").unwrap().lab.values(), [85.0, 5.0, 80.0]);
+
+let sample = CieLab::new([60.0, -3.0, -28.0], palette.white());
+let (nearest, de) = palette.nearest(&sample, DeltaE::CIE76).unwrap();
+assert_eq!(nearest.name, "Sky");
+assert!(de < 4.0);
+```
+*/
+#[derive(Clone)]
+pub struct Palette {
+    illuminant: Illuminant,
+    observer: Observer,
+    colors: Vec<NamedColor>,
+    index: HashMap<String, usize>,
+}
+
+impl Palette {
+    /// An empty palette, with the CIELAB values of its colors for an illuminant, and an observer.
+    pub fn new(illuminant: &dyn Light, observer: Observer) -> Self {
+        Self { illuminant: Illuminant(illuminant.spectrum().into_owned()), observer, colors: Vec::new(), index: HashMap::new() }
+    }
+
+    /// The tristimulus values of the palette's illuminant, with a luminous value of 100, as
+    /// reference white of the CIELAB values.
+    pub fn white(&self) -> XYZ {
+        self.observer.data().xyz(&self.illuminant, None).set_illuminance(100.0)
+    }
+
+    /// Adds a color, by its CIELAB values. A color with the same name is replaced.
+    pub fn add_lab(mut self, name: &str, lab: [f64; 3]) -> Self {
+        let lab = CieLab::new(lab, self.white());
+        self.insert(NamedColor { name: name.to_string(), lab, colorant: None });
+        self
+    }
+
+    /// Adds a color, by its spectral reflectance factor. A color with the same name is replaced.
+    pub fn add_colorant(mut self, name: &str, colorant: Colorant) -> Self {
+        let xyz = self.observer.data().xyz(&self.illuminant, Some(&colorant));
+        // a colorant always results in a stimulus value
+        let lab = CieLab::try_from(xyz).unwrap();
+        self.insert(NamedColor { name: name.to_string(), lab, colorant: Some(colorant) });
+        self
+    }
+
+    fn insert(&mut self, color: NamedColor) {
+        match self.index.get(&color.name) {
+            Some(&i) => self.colors[i] = color,
+            None => {
+                self.index.insert(color.name.clone(), self.colors.len());
+                self.colors.push(color);
+            }
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.colors.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+
+    /// The palette's colors, in the order they were added.
+    pub fn colors(&self) -> &[NamedColor] {
+        &self.colors
+    }
+
+    /// A color, by its name.
+    pub fn get(&self, name: &str) -> Option<&NamedColor> {
+        self.index.get(name).map(|&i| &self.colors[i])
+    }
+
+    /**
+    The color closest to a sample, with their color difference, for a color difference metric.
+
+    The sample's CIELAB values need to use the palette's reference white, as obtained by
+    [`Palette::white`]; use [`Palette::nearest_colorant`] for spectral samples.
+    */
+    pub fn nearest(&self, lab: &CieLab, metric: DeltaE) -> Result<(&NamedColor, f64), CmtError> {
+        let mut best: Option<(&NamedColor, f64)> = None;
+        for color in &self.colors {
+            let de = metric.delta_e(lab, &color.lab)?;
+            if !matches!(best, Some((_, d)) if d <= de) {
+                best = Some((color, de));
+            }
+        }
+        best.ok_or_else(|| CmtError::ErrorString("Palette: no colors".to_string()))
+    }
+
+    /// The color closest to a sample, given by its spectral reflectance factor, with their color
+    /// difference, for a color difference metric.
+    pub fn nearest_colorant(&self, colorant: &Colorant, metric: DeltaE) -> Result<(&NamedColor, f64), CmtError> {
+        let lab = CieLab::try_from(self.observer.data().xyz(&self.illuminant, Some(colorant)))?;
+        self.nearest(&lab, metric)
+    }
+
+    /**
+    Reads a palette from comma, semicolon, or tab separated values, with a header line, and a row
+    for each color, with its name in the first column.
+
+    The other columns contain either CIELAB values, with headers `L`, `a`, and `b`, or
+    `LAB_L`, `LAB_A`, and `LAB_B`, or spectral reflectance factors, with their wavelengths in
+    nanometer as headers, such as `380`, `390`, ..., `730`. Spectral values are reflectance factors
+    in the range from 0.0 to 1.0, and are linearly interpolated.
+    If both are present, the spectral values are used.
+    */
+    pub fn from_csv(s: &str, illuminant: &dyn Light, observer: Observer) -> Result<Self, CmtError> {
+        let mut lines = s.lines().map(str::trim).filter(|l| !l.is_empty());
+        let header = split_fields(lines.next().ok_or_else(|| CmtError::ParseError("Palette: empty file".into()))?);
+        let column = |names: [&str; 2]| header.iter().position(|h| names.iter().any(|n| h.eq_ignore_ascii_case(n)));
+        let lab_columns = [column(["L", "LAB_L"]), column(["a", "LAB_A"]), column(["b", "LAB_B"])];
+        let wavelengths: Vec<(usize, f64)> = header.iter().enumerate().skip(1).filter_map(|(i, h)| h.trim_end_matches("nm").trim().parse().ok().map(|w| (i, w))).collect();
+
+        let mut palette = Self::new(illuminant, observer);
+        for line in lines {
+            let fields = split_fields(line);
+            let value = |i: usize| -> Result<f64, CmtError> {
+                fields.get(i).and_then(|f| f.parse().ok()).ok_or_else(|| CmtError::ParseError(format!("Palette: invalid value in \"{line}\"")))
+            };
+            let name = fields.first().copied().unwrap_or_default();
+            if wavelengths.len() >= 3 {
+                let wl: Vec<f64> = wavelengths.iter().map(|&(_, w)| w).collect();
+                let values = wavelengths.iter().map(|&(i, _)| value(i)).collect::<Result<Vec<f64>, _>>()?;
+                let colorant = Colorant(Spectrum::try_linear_interpolate(&wl, &values)?);
+                palette = palette.add_colorant(name, colorant);
+            } else if let [Some(l), Some(a), Some(b)] = lab_columns {
+                palette = palette.add_lab(name, [value(l)?, value(a)?, value(b)?]);
+            } else {
+                return Err(CmtError::ParseError("Palette: no CIELAB or spectral columns".into()));
+            }
+        }
+        Ok(palette)
+    }
+
+    /**
+    Reads a palette from a CxF3 (ISO 17972) color exchange file, using the `Object` elements, with
+    their `Name` attribute, and either a `ReflectanceSpectrum`, or a `ColorCIELab` element.
+
+    Reflectance spectra start at their `StartWL` attribute, with the increment of the file's
+    `WavelengthRange` element, or 10 nanometer if absent. CIELAB values are used as given, for
+    the palette's illuminant and observer, regardless of the color specification in the file.
+    */
+    pub fn from_cxf(s: &str, illuminant: &dyn Light, observer: Observer) -> Result<Self, CmtError> {
+        let increment = elements(s, "WavelengthRange").first().and_then(|(attrs, _)| attribute(attrs, "Increment")).and_then(|v| v.parse().ok()).unwrap_or(10.0);
+        let mut palette = Self::new(illuminant, observer);
+        for (attrs, content) in elements(s, "Object") {
+            let name = attribute(attrs, "Name").unwrap_or_default();
+            let number = |v: &str| v.trim().parse::<f64>().map_err(|_| CmtError::ParseError(format!("CxF: invalid number \"{v}\" in {name}")));
+            if let Some((attrs, values)) = elements(content, "ReflectanceSpectrum").first() {
+                let start = attribute(attrs, "StartWL").map(number).transpose()?.unwrap_or(380.0);
+                let values = values.split_whitespace().map(number).collect::<Result<Vec<f64>, _>>()?;
+                let wl: Vec<f64> = (0..values.len()).map(|i| start + i as f64 * increment).collect();
+                let colorant = Colorant(Spectrum::try_linear_interpolate(&wl, &values)?);
+                palette = palette.add_colorant(name, colorant);
+            } else if let Some((_, lab)) = elements(content, "ColorCIELab").first() {
+                let value = |tag| elements(lab, tag).first().map(|(_, v)| number(v)).transpose()?.ok_or_else(|| CmtError::ParseError(format!("CxF: missing {tag} in {name}")));
+                palette = palette.add_lab(name, [value("L")?, value("A")?, value("B")?]);
+            }
+        }
+        Ok(palette)
+    }
+}
+
+/// Splits a line of comma, semicolon, or tab separated values.
+fn split_fields(line: &str) -> Vec<&str> {
+    line.split([',', ';', '\t']).map(|f| f.trim().trim_matches('"')).collect()
+}
+
+/// Attributes, and contents, of the XML elements with a local name, ignoring namespace prefixes.
+fn elements<'a>(xml: &'a str, name: &str) -> Vec<(&'a str, &'a str)> {
+    let mut found = Vec::new();
+    let mut rest = xml;
+    while let Some(start) = rest.find('<') {
+        rest = &rest[start + 1..];
+        let Some(end) = rest.find('>') else { break };
+        let tag = &rest[..end];
+        let (tag_name, attrs) = tag.split_once(char::is_whitespace).unwrap_or((tag.trim_end_matches('/'), ""));
+        let local = tag_name.rsplit(':').next().unwrap_or(tag_name);
+        rest = &rest[end + 1..];
+        if local != name || tag_name.starts_with(['/', '?', '!']) {
+            continue;
+        }
+        if tag.ends_with('/') {
+            found.push((attrs.trim_end_matches('/'), ""));
+        } else if let Some(close) = rest.find(&format!("</{tag_name}>")) {
+            found.push((attrs, &rest[..close]));
+            rest = &rest[close..];
+        }
+    }
+    found
+}
+
+/// The value of an XML attribute.
+fn attribute<'a>(attrs: &'a str, name: &str) -> Option<&'a str> {
+    let start = attrs.match_indices(name).find(|(i, _)| attrs[i + name.len()..].trim_start().starts_with('=') && (*i == 0 || attrs[..*i].ends_with(char::is_whitespace)))?.0;
+    let value = attrs[start + name.len()..].trim_start()[1..].trim_start();
+    let quote = value.chars().next()?;
+    value[1..].split(quote).next()
+}
+
+#[cfg(test)]
+mod palette_test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_extract_palette() {
+        // a textured blue, and a uniform yellow area
+        let mut pixels: Vec<RGB> = (0..60).map(|i| RGB::new(0.1, 0.2, 0.6 + 0.002 * (i % 10) as f64, None, None)).collect();
+        pixels.extend(std::iter::repeat(RGB::new(0.8, 0.7, 0.1, None, None)).take(40));
+        let palette = extract_palette(&pixels, 3, Upsampling::SigmoidPolynomial).unwrap();
+        assert_eq!(palette.len(), 3);
+        assert!(palette.iter().map(|e| e.weight).sum::<f64>() > 0.999);
+        let yellow = palette.iter().find(|e| e.weight == 0.4).unwrap();
+        approx::assert_abs_diff_eq!(yellow.spread, 0.0, epsilon = 1E-12);
+
+        // the reconstructed spectrum matches the palette color
+        let lab = CIE1931.lab_d65(&yellow.colorant);
+        let want = CieLab::try_from(yellow.rgb.xyz()).unwrap();
+        assert!(lab.delta_e(&want).unwrap() < 0.01);
+
+        // fewer distinct colors than requested
+        let palette = extract_palette(&pixels[60..], 3, Upsampling::Smits).unwrap();
+        assert_eq!(palette.len(), 1);
+        assert!(extract_palette(&[], 3, Upsampling::Smits).is_err());
+    }
+
+    #[test]
+    fn test_palette() {
+        let cxf = r#"<?xml version="1.0" encoding="UTF-8"?>
+<cc:CxF xmlns:cc="http://colorexchangeformat.com/CxF3-core">
+  <cc:Resources>
+    <cc:ObjectCollection>
+      <cc:Object ObjectType="Standard" Name="Gray 50" Id="1">
+        <cc:ColorValues>
+          <cc:ReflectanceSpectrum ColorSpecification="CS1" StartWL="380">0.5 0.5 0.5 0.5 0.5</cc:ReflectanceSpectrum>
+        </cc:ColorValues>
+      </cc:Object>
+      <cc:Object ObjectType="Standard" Name="Brand Red" Id="2">
+        <cc:ColorValues>
+          <cc:ColorCIELab ColorSpecification="CS2"><cc:L>45.0</cc:L><cc:A>60.0</cc:A><cc:B>35.0</cc:B></cc:ColorCIELab>
+        </cc:ColorValues>
+      </cc:Object>
+    </cc:ObjectCollection>
+    <cc:ColorSpecificationCollection>
+      <cc:ColorSpecification Id="CS1"><cc:MeasurementSpec><cc:WavelengthRange StartWL="380" Increment="100"/></cc:MeasurementSpec></cc:ColorSpecification>
+    </cc:ColorSpecificationCollection>
+  </cc:Resources>
+</cc:CxF>"#;
+        let palette = Palette::from_cxf(cxf, &StdIlluminant::D50, Observer::Std1931).unwrap();
+        assert_eq!(palette.len(), 2);
+        let gray = palette.get("Gray 50").unwrap();
+        assert_eq!(gray.colorant.as_ref().unwrap()[780], 0.5);
+        approx::assert_abs_diff_eq!(gray.lab.values()[0], 76.07, epsilon = 0.01);
+        assert_eq!(palette.get("Brand Red").unwrap().lab.values(), [45.0, 60.0, 35.0]);
+
+        let (nearest, _) = palette.nearest_colorant(&Colorant::gray(0.45), DeltaE::OkLab).unwrap();
+        assert_eq!(nearest.name, "Gray 50");
+
+        let csv = "name;380;580;780\nflat;0.2;0.2;0.2\nflat;0.3;0.3;0.3";
+        let palette = Palette::from_csv(csv, &StdIlluminant::D65, Observer::Std1931).unwrap();
+        assert_eq!(palette.len(), 1);
+        assert_eq!(palette.colors()[0].colorant.as_ref().unwrap()[500], 0.3);
+        assert!(Palette::new(&StdIlluminant::D65, Observer::Std1931).nearest(&palette.colors()[0].lab, DeltaE::CIE76).is_err());
+    }
+}
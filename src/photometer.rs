@@ -0,0 +1,129 @@
+/*!
+# Photometer Spectral Mismatch
+
+A photometer, or lux meter, measures illuminance with a detector, whose relative spectral
+responsivity approximates the CIE luminous efficiency function V(λ). Photometers are calibrated
+with a CIE standard illuminant A source, and their readings are only exact for sources with the
+same spectral distribution. For other sources, such as white LEDs, the readings have to be
+multiplied by a spectral mismatch correction factor, as defined in CIE S 023:2013 and
+CIE 244:2021:
+
+F = (∫S<sub>Z</sub>V dλ · ∫S<sub>A</sub>s dλ) / (∫S<sub>Z</sub>s dλ · ∫S<sub>A</sub>V dλ),
+
+with S<sub>Z</sub> the spectral distribution of the measured source, S<sub>A</sub> the
+spectral distribution of the calibration source, and s the photometer's relative spectral
+responsivity.
+
+The quality of a photometer's V(λ) match is characterized by its general V(λ) mismatch index
+f<sub>1</sub>′, also defined in CIE S 023.
+*/
+
+use nalgebra::SVector;
+
+use crate::{
+    data::observers::CIE1931,
+    physics::{planck_c2, C2_NBS_1931},
+    spectrum::{Spectrum, NS},
+    traits::Light,
+};
+
+/// A photometer, by its relative spectral responsivity, and its calibration source.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Photometer {
+    responsivity: Spectrum,
+    calibration_source: Spectrum,
+}
+
+impl Photometer {
+
+    /// A photometer with a relative spectral responsivity, calibrated with CIE illuminant A.
+    pub fn new(responsivity: &Spectrum) -> Self {
+        Self { responsivity: *responsivity, calibration_source: cie_a() }
+    }
+
+    /// A photometer with a perfect V(λ) match, the CIE 1931 standard observer's y̅(λ) function.
+    pub fn ideal() -> Self {
+        Self::new(&Spectrum(v_lambda()))
+    }
+
+    pub fn responsivity(&self) -> &Spectrum {
+        &self.responsivity
+    }
+
+    /// Sets the calibration source, for photometers not calibrated with CIE illuminant A.
+    pub fn set_calibration_source(mut self, source: &dyn Light) -> Self {
+        self.calibration_source = source.spectrum().into_owned();
+        self
+    }
+
+    /**
+    The spectral mismatch correction factor for a source, to multiply the photometer's readings
+    with.
+    ```
+    use colorimetry::prelude::*;
+
+    let led = Illuminant::led(450.0, 20.0);
+    approx::assert_abs_diff_eq!(Photometer::ideal().mismatch_correction(&led), 1.0, epsilon = 1E-12);
+
+    // a photometer with a responsivity shifted to the blue reads too high for a blue LED
+    let photometer = Photometer::new(&Photometer::ideal().responsivity().shift_wavelength(-3.0));
+    assert!(photometer.mismatch_correction(&led) < 1.0);
+    ```
+    */
+    pub fn mismatch_correction(&self, source: &dyn Light) -> f64 {
+        let v = v_lambda();
+        let s = &self.responsivity.0;
+        let z = &source.spectrum().0;
+        let a = &self.calibration_source.0;
+        (z.dot(&v) * a.dot(s)) / (z.dot(s) * a.dot(&v))
+    }
+
+    /// The corrected reading, for a reading of the photometer of a source.
+    pub fn correct(&self, reading: f64, source: &dyn Light) -> f64 {
+        reading * self.mismatch_correction(source)
+    }
+
+    /**
+    The general V(λ) mismatch index f<sub>1</sub>′, as a fraction, with the responsivity
+    normalized by the calibration source: photometers with values below 0.03 are considered to
+    be of good quality.
+    */
+    pub fn f1_prime(&self) -> f64 {
+        let v = v_lambda();
+        let s = &self.responsivity.0;
+        let a = &self.calibration_source.0;
+        let s_norm = s * (a.dot(&v) / a.dot(s));
+        (s_norm - v).abs().sum() / v.sum()
+    }
+}
+
+/// CIE illuminant A, as a Planckian radiator of 2856 K, with the value for the second radiation
+/// constant used in its definition.
+fn cie_a() -> Spectrum {
+    Spectrum(SVector::<f64, NS>::from_fn(|i, _| planck_c2((i + 380) as f64 * 1E-9, 2856.0, C2_NBS_1931)))
+}
+
+fn v_lambda() -> SVector<f64, NS> {
+    CIE1931.data.row(1).transpose()
+}
+
+#[cfg(test)]
+mod photometer_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_mismatch() {
+        let ideal = Photometer::ideal();
+        let v = *ideal.responsivity();
+        assert_abs_diff_eq!(ideal.f1_prime(), 0.0, epsilon = 1E-12);
+        assert_abs_diff_eq!(ideal.mismatch_correction(&Illuminant::led(450.0, 20.0)), 1.0, epsilon = 1E-12);
+
+        let shifted = Photometer::new(&v.shift_wavelength(-3.0));
+        assert!(shifted.f1_prime() > 0.03);
+        assert_abs_diff_eq!(shifted.mismatch_correction(&Illuminant::planckian(2856.0)), 1.0, epsilon = 1E-3);
+        // the blue shifted photometer reads too high for a blue LED, and too low for a red one
+        assert!(shifted.correct(100.0, &Illuminant::led(450.0, 20.0)) < 100.0);
+        assert!(shifted.correct(100.0, &Illuminant::led(630.0, 20.0)) > 100.0);
+    }
+}
@@ -1,6 +1,7 @@
 
 use num_traits::ToPrimitive;
 use wasm_bindgen::prelude::wasm_bindgen;
+use crate::math;
 
 
 /// The speed of light (m/s)
@@ -24,7 +25,7 @@ pub const C2_ITS_1968: f64 = 1.4388E-2;
 //pub const FWHM2STDDEV: f64 = 2.354820045;
 
 // calculated on first dereference, can not use floating point calculations in const (yet?)
-pub static FWHM: LazyLock<f64> = LazyLock::new(|| (8.0 * 2f64.ln()).sqrt());
+pub static FWHM: LazyLock<f64> = LazyLock::new(|| (8.0 * math::ln(2.0)).sqrt());
 
 
 /**
@@ -40,19 +41,19 @@ c2: second radiative constant, in meter * Kelvin; can also be used to include re
 */
 #[inline]
 pub fn planck_c2(l: f64, t: f64, c2: f64) -> f64 {
-    crate::physics::C1 / l.powi(5) / ((c2 / (l * t)).exp() - 1.0)
+    crate::physics::C1 / l.powi(5) / (math::exp(c2 / (l * t)) - 1.0)
 }
 
 /// Planck Temperature derivate: d(Planck)/dT
 pub fn planck_slope_c2(l: f64, t: f64, c2: f64) -> f64 {
     let c3 = C1 * c2 / t.powi(2);
-    let e = (c2 / (l * t)).exp();
+    let e = math::exp(c2 / (l * t));
     c3 / l.powi(6) * e / (e - 1.0).powi(2)
 }
 
 /// Planck Temperature second derivative: d2(Planck)/dT2
 pub fn planck_curvature_c2(l: f64, t: f64, c2: f64) -> f64 {
-    let e = (c2 / (l * t)).exp();
+    let e = math::exp(c2 / (l * t));
     planck_slope_c2(l, t, c2) / t * (c2/(l*t) * (e+1.0)/(e-1.0) -2.0)
 }
 
@@ -110,7 +111,7 @@ pub fn fwhm_from_sigma(sigma: f64) -> f64 {
 #[inline]
 pub fn gaussian_peak_one(x: f64, mu: f64, sigma: f64) -> f64 {
     let exponent = -((x - mu).powi(2)) / (2.0 * sigma.powi(2));
-    exponent.exp()
+    math::exp(exponent)
 }
 
 #[test]
@@ -126,7 +127,7 @@ fn gaussian_peak_one_test(){
 #[inline]
 pub fn gaussian_normalized(x: f64, mu: f64, sigma: f64) -> f64 {
     let exponent = -((x - mu).powi(2)) / (2.0 * sigma.powi(2));
-    (1.0 / (sigma * (2.0 * PI).sqrt())) * exponent.exp()
+    (1.0 / (sigma * (2.0 * PI).sqrt())) * math::exp(exponent)
 }
 
 #[inline(always)]
@@ -0,0 +1,258 @@
+/*!
+# Conversion Pipeline
+
+A [`Pipeline`] chains the conversions used to reproduce a spectrally defined color on a display,
+such as soft-proofing a printed sample, as seen under a viewing booth light, on a monitor:
+
+1. spectral reflectances, or transmittances, to tristimulus values, for an illuminant and an
+   observer,
+2. chromatic adaptation, using a von Kries transform in the CAT16 cone space, from the white of
+   the illuminant to the white of the display,
+3. CIECAM16 color appearance correlates, for the viewing conditions of the original, transformed
+   back to tristimulus values, for the viewing conditions of the display,
+4. gamut mapping, by reducing chroma towards a gray of the same luminance,
+5. encoding, to gamma-encoded RGB display values.
+
+The chromatic adaptation, color appearance, and gamut mapping stages are optional.
+A pipeline is configured once, and can be reused for any number of samples; its result holds the
+intermediate values of each of its stages, for inspection.
+*/
+
+use std::fmt;
+
+use crate::{
+    cam::CieCam16,
+    cat::Cat,
+    colorant::Colorant,
+    error::CmtError,
+    illuminant::Illuminant,
+    observer::Observer,
+    rgb::RGB,
+    rgbspace::RgbSpace,
+    traits::Light,
+    viewconditions::ViewConditions,
+    xyz::XYZ,
+};
+
+/// The conversion stages of a [`Pipeline`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, strum_macros::Display)]
+pub enum PipelineStage {
+    #[strum(to_string = "Tristimulus Values")]
+    Tristimulus,
+    #[strum(to_string = "Chromatic Adaptation")]
+    ChromaticAdaptation,
+    #[strum(to_string = "CIECAM16 Appearance")]
+    Appearance,
+    #[strum(to_string = "Gamut Mapping")]
+    GamutMapping,
+    #[strum(to_string = "RGB Encoding")]
+    Encoding,
+}
+
+/**
+Reusable chain of conversions, from spectral data to RGB display values.
+```
+use colorimetry::prelude::*;
+
+// a red sample viewed under illuminant A, reproduced on an sRGB display
+let pipeline = Pipeline::new(RgbSpace::SRGB).set_illuminant(&StdIlluminant::A);
+let output = pipeline.run(&Colorant::gaussian(630.0, 30.0)).unwrap();
+let [r, g, b] = output.encoded;
+assert!(r > g && r > b);
+assert!(output.mapped.is_in_gamut());
+assert_eq!(pipeline.to_string(), "Tristimulus Values → Chromatic Adaptation → Gamut Mapping → RGB Encoding");
+```
+*/
+#[derive(Clone)]
+pub struct Pipeline {
+    observer: Observer,
+    illuminant: Illuminant,
+    adaptation: bool,
+    viewing_conditions: Option<(ViewConditions, ViewConditions)>,
+    gamut_mapping: bool,
+    space: RgbSpace,
+}
+
+/// Values produced by each of the stages of a [`Pipeline`], for a single sample.
+#[derive(Clone, Debug)]
+pub struct PipelineOutput {
+    /// Tristimulus values of the sample, with a luminous value of 100 for the white of the
+    /// illuminant.
+    pub xyz: XYZ,
+
+    /// Tristimulus values chromatically adapted to the display white, if adaptation is enabled.
+    pub adapted: Option<XYZ>,
+
+    /// CIECAM16 lightness J, chroma C, and hue angle h, in degrees, for the viewing conditions of
+    /// the original, if enabled.
+    pub appearance: Option<[f64; 3]>,
+
+    /// Tristimulus values to reproduce on the display.
+    pub reproduced: XYZ,
+
+    /// Linear RGB values of the reproduction, which can be out of the display's gamut.
+    pub rgb: RGB,
+
+    /// Linear RGB values, mapped into the display's gamut.
+    pub mapped: RGB,
+
+    /// Gamma-encoded 8-bit RGB display values.
+    pub encoded: [u8; 3],
+}
+
+impl Pipeline {
+    /// A pipeline reproducing samples, illuminated by D65, in an RGB color space, for the CIE 1931
+    /// observer, with chromatic adaptation, and gamut mapping, but without color appearance
+    /// modeling.
+    pub fn new(space: RgbSpace) -> Self {
+        Self {
+            observer: Observer::Std1931,
+            illuminant: Illuminant::d65(),
+            adaptation: true,
+            viewing_conditions: None,
+            gamut_mapping: true,
+            space,
+        }
+    }
+
+    /// Sets the illuminant the samples are viewed under.
+    pub fn set_illuminant(mut self, light: &dyn Light) -> Self {
+        self.illuminant = Illuminant(light.spectrum().into_owned());
+        self
+    }
+
+    pub fn set_observer(mut self, observer: Observer) -> Self {
+        self.observer = observer;
+        self
+    }
+
+    /// Enables, or disables, the chromatic adaptation to the display white. Without adaptation,
+    /// the tristimulus values are reproduced as they are, and a sample viewed under a warm light
+    /// looks warm on the display.
+    pub fn set_adaptation(mut self, adaptation: bool) -> Self {
+        self.adaptation = adaptation;
+        self
+    }
+
+    /// Adds a CIECAM16 stage, matching the appearance of the samples in the viewing conditions
+    /// of the original, to those of the display.
+    pub fn set_viewing_conditions(mut self, original: ViewConditions, display: ViewConditions) -> Self {
+        self.viewing_conditions = Some((original, display));
+        self
+    }
+
+    /// Enables, or disables, gamut mapping. Without gamut mapping, values out of the display's
+    /// gamut are clipped in the encoding stage.
+    pub fn set_gamut_mapping(mut self, gamut_mapping: bool) -> Self {
+        self.gamut_mapping = gamut_mapping;
+        self
+    }
+
+    /// Sets the RGB color space of the display.
+    pub fn set_space(mut self, space: RgbSpace) -> Self {
+        self.space = space;
+        self
+    }
+
+    pub fn space(&self) -> RgbSpace {
+        self.space
+    }
+
+    /// The enabled stages of the pipeline, in order.
+    pub fn stages(&self) -> Vec<PipelineStage> {
+        let mut stages = vec![PipelineStage::Tristimulus];
+        if self.adaptation {
+            stages.push(PipelineStage::ChromaticAdaptation);
+        }
+        if self.viewing_conditions.is_some() {
+            stages.push(PipelineStage::Appearance);
+        }
+        if self.gamut_mapping {
+            stages.push(PipelineStage::GamutMapping);
+        }
+        stages.push(PipelineStage::Encoding);
+        stages
+    }
+
+    /// Runs all stages of the pipeline for a sample, illuminated by the pipeline's illuminant.
+    pub fn run(&self, sample: &Colorant) -> Result<PipelineOutput, CmtError> {
+        let xyz = self.observer.data().xyz(&self.illuminant, Some(sample)).set_illuminance(100.0);
+        self.run_xyz(xyz)
+    }
+
+    /// Runs the pipeline for tristimulus values, with their white reference taken as the white of
+    /// the original, skipping the spectral stage.
+    pub fn run_xyz(&self, xyz: XYZ) -> Result<PipelineOutput, CmtError> {
+        if xyz.observer != self.observer {
+            return Err(CmtError::RequireSameObserver);
+        }
+        let xyz0 = xyz.xyz.ok_or(CmtError::NoColorant)?;
+        let display_white = self.observer.data().xyz_cie_table(&self.space.data().0.white, Some(100.0));
+
+        let adapted = self.adaptation.then(|| {
+            let cat = Cat::Cat16.matrix(xyz.xyzn / xyz.xyzn.y, display_white.xyzn / display_white.xyzn.y);
+            XYZ::from_vecs(display_white.xyzn, Some(cat * xyz0 * display_white.xyzn.y / xyz.xyzn.y), self.observer)
+        });
+        let current = adapted.unwrap_or(xyz);
+
+        let (appearance, reproduced) = match self.viewing_conditions {
+            Some((original, display)) => {
+                let cam = CieCam16::new(current, original)?;
+                let reproduced = cam.xyz(Some(display_white), Some(display))?;
+                (Some(cam.jch.into()), reproduced)
+            }
+            None => (None, current),
+        };
+
+        let rgb = reproduced.rgb(Some(self.space));
+        let mapped = if self.gamut_mapping { rgb.map_into_gamut() } else { rgb };
+        Ok(PipelineOutput { xyz, adapted, appearance, reproduced, rgb, mapped, encoded: mapped.into() })
+    }
+}
+
+impl fmt::Display for Pipeline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let stages: Vec<String> = self.stages().iter().map(PipelineStage::to_string).collect();
+        write!(f, "{}", stages.join(" → "))
+    }
+}
+
+#[cfg(test)]
+mod pipeline_test {
+    use crate::prelude::*;
+    use crate::viewconditions::ViewConditions;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    #[cfg(feature = "cie-illuminants")]
+    fn test_pipeline() {
+        // a white sample is reproduced as the display white, with or without appearance modeling
+        let white = Colorant::white();
+        let pipeline = Pipeline::new(RgbSpace::SRGB).set_illuminant(&StdIlluminant::A);
+        let output = pipeline.run(&white).unwrap();
+        assert_abs_diff_eq!(output.rgb.as_ref(), &nalgebra::Vector3::repeat(1.0), epsilon = 1E-6);
+        assert!(output.appearance.is_none());
+        assert_eq!(output.encoded, [255, 255, 255]);
+
+        let vc = ViewConditions::default();
+        let output = pipeline.clone().set_viewing_conditions(vc, vc).run(&white).unwrap();
+        assert_abs_diff_eq!(output.appearance.unwrap()[0], 100.0, epsilon = 1E-6);
+        assert_abs_diff_eq!(output.rgb.as_ref(), &nalgebra::Vector3::repeat(1.0), epsilon = 1E-6);
+
+        // without adaptation, the sample keeps the color of the illuminant
+        let output = pipeline.clone().set_adaptation(false).run(&white).unwrap();
+        assert!(output.adapted.is_none());
+        let [r, g, b] = output.encoded;
+        assert!(r > g && g > b);
+
+        // a saturated sample is out of gamut, unless mapped
+        let blue = Colorant::gaussian(450.0, 5.0);
+        let output = pipeline.clone().set_gamut_mapping(false).run(&blue).unwrap();
+        assert!(!output.rgb.is_in_gamut());
+        assert_eq!(output.rgb, output.mapped);
+        assert!(pipeline.run(&blue).unwrap().mapped.is_in_gamut());
+
+        assert_eq!(pipeline.stages().len(), 4);
+        assert!(pipeline.run_xyz(CIE1931.xyz_d65().set_illuminance(100.0)).is_err());
+    }
+}
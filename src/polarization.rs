@@ -0,0 +1,145 @@
+/*!
+# Polarization
+
+Spectral measurements tagged with a polarization state, and the rules to combine them.
+
+Light emitted by liquid crystal displays, and light reflected at an angle, is partially
+polarized, and its measured spectral distribution depends on the polarization sensitivity of the
+instrument. To characterize such sources, the light is measured through a linear polarizer,
+aligned with the s direction, perpendicular to the plane of incidence, or viewing, and with the
+p direction, parallel to it.
+
+Measurements are combined depending on what they represent:
+- spectral radiances, or irradiances, of the s and p components add up to the total, unpolarized,
+  measurement, as they are incoherent;
+- reflectance, or transmittance, factors, measured with s and p polarized illumination, are
+  averaged, to obtain the factors for unpolarized illumination.
+*/
+
+use crate::{error::CmtError, math, spectrum::Spectrum};
+
+/// Polarization state of spectral data.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, strum_macros::Display, strum_macros::EnumIter)]
+pub enum Polarization {
+    /// Unpolarized, or measured without a polarizer.
+    #[default]
+    Unpolarized,
+    /// Linearly polarized perpendicular to the plane of incidence.
+    S,
+    /// Linearly polarized parallel to the plane of incidence.
+    P,
+}
+
+/**
+Spectral data, such as a radiance, or a reflectance, tagged with its polarization state.
+```
+use colorimetry::prelude::*;
+
+// an LCD, measured through a polarizer in the s and p directions
+let s = PolarizedSpectrum::new(*Illuminant::led(550.0, 30.0) * 0.9, Polarization::S);
+let p = PolarizedSpectrum::new(*Illuminant::led(550.0, 30.0) * 0.1, Polarization::P);
+let total = s.add(&p).unwrap();
+assert_eq!(total.polarization(), Polarization::Unpolarized);
+approx::assert_abs_diff_eq!(s.degree_of_polarization(&p).unwrap()[550], 0.8, epsilon = 1E-12);
+```
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub struct PolarizedSpectrum {
+    spectrum: Spectrum,
+    polarization: Polarization,
+}
+
+impl PolarizedSpectrum {
+    pub fn new(spectrum: Spectrum, polarization: Polarization) -> Self {
+        Self { spectrum, polarization }
+    }
+
+    pub fn spectrum(&self) -> &Spectrum {
+        &self.spectrum
+    }
+
+    pub fn polarization(&self) -> Polarization {
+        self.polarization
+    }
+
+    /// The s and p components of a pair, in this order, if these are an s and p pair.
+    fn s_and_p<'a>(&'a self, other: &'a Self) -> Result<(&'a Spectrum, &'a Spectrum), CmtError> {
+        match (self.polarization, other.polarization) {
+            (Polarization::S, Polarization::P) => Ok((&self.spectrum, &other.spectrum)),
+            (Polarization::P, Polarization::S) => Ok((&other.spectrum, &self.spectrum)),
+            (a, b) => Err(CmtError::ErrorString(format!("Polarization: requires an s and p pair, got {a} and {b}"))),
+        }
+    }
+
+    /**
+    Sum of two radiances, or irradiances. The s and p components of a source add up to its
+    unpolarized total; spectra with the same polarization state add up to a spectrum with that
+    state. Other combinations result in partially polarized light, and return an error.
+    */
+    pub fn add(&self, other: &Self) -> Result<Self, CmtError> {
+        if self.polarization == other.polarization {
+            return Ok(Self::new(self.spectrum + other.spectrum, self.polarization));
+        }
+        let (s, p) = self.s_and_p(other)?;
+        Ok(Self::new(*s + *p, Polarization::Unpolarized))
+    }
+
+    /**
+    Mean of two reflectance, or transmittance, factors. The factors for s and p polarized
+    illumination average to the factors for unpolarized illumination; factors with the same
+    polarization state average to factors with that state.
+    */
+    pub fn average(&self, other: &Self) -> Result<Self, CmtError> {
+        if self.polarization == other.polarization {
+            return Ok(Self::new((self.spectrum + other.spectrum) * 0.5, self.polarization));
+        }
+        let (s, p) = self.s_and_p(other)?;
+        Ok(Self::new((*s + *p) * 0.5, Polarization::Unpolarized))
+    }
+
+    /// Spectral degree of linear polarization, (S − P) / (S + P), from the s and p components
+    /// of a radiance, with positive values for light predominantly s-polarized. It is zero at
+    /// wavelengths without power.
+    pub fn degree_of_polarization(&self, other: &Self) -> Result<Spectrum, CmtError> {
+        let (s, p) = self.s_and_p(other)?;
+        Ok(Spectrum(s.0.zip_map(&p.0, |s, p| if s + p > 0.0 { (s - p) / (s + p) } else { 0.0 })))
+    }
+
+    /// Radiance transmitted by an ideal linear polarizer, or analyzer, with its transmission
+    /// axis at an angle, in degrees, from the s direction, according to Malus' law, from the s
+    /// and p components of the light.
+    pub fn analyzed(&self, other: &Self, angle_deg: f64) -> Result<Spectrum, CmtError> {
+        let (s, p) = self.s_and_p(other)?;
+        let cos2 = math::cos(angle_deg.to_radians()).powi(2);
+        Ok(*s * cos2 + *p * (1.0 - cos2))
+    }
+}
+
+#[cfg(test)]
+mod polarization_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_polarization() {
+        let s = PolarizedSpectrum::new(*Colorant::gray(0.3), Polarization::S);
+        let p = PolarizedSpectrum::new(*Colorant::gray(0.1), Polarization::P);
+
+        let total = p.add(&s).unwrap();
+        assert_eq!(total.polarization(), Polarization::Unpolarized);
+        assert_abs_diff_eq!(total.spectrum()[500], 0.4, epsilon = 1E-12);
+
+        let mean = s.average(&p).unwrap();
+        assert_abs_diff_eq!(mean.spectrum()[500], 0.2, epsilon = 1E-12);
+        assert_eq!(s.average(&s).unwrap(), s);
+
+        assert_abs_diff_eq!(s.analyzed(&p, 0.0).unwrap()[600], 0.3, epsilon = 1E-12);
+        assert_abs_diff_eq!(s.analyzed(&p, 90.0).unwrap()[600], 0.1, epsilon = 1E-12);
+        assert_abs_diff_eq!(s.analyzed(&p, 45.0).unwrap()[600], 0.2, epsilon = 1E-12);
+        assert_abs_diff_eq!(p.degree_of_polarization(&s).unwrap()[450], 0.5, epsilon = 1E-12);
+
+        // partially polarized combinations are not supported
+        assert!(total.add(&s).is_err());
+        assert!(s.degree_of_polarization(&s).is_err());
+    }
+}
@@ -1,22 +1,133 @@
+/*!
+# Prelude
+
+The commonly used types, traits, functions, and constants of this library, such as [`Spectrum`],
+[`Illuminant`], [`Colorant`], [`XYZ`], [`RgbSpace`], the [`CIE1931`] observer data, and, with
+the `cri` feature, [`CRI`](crate::cri::CRI), for import with a single glob import:
+```
+use colorimetry::prelude::*;
+
+let xyz = CIE1931.xyz(&StdIlluminant::D65, Some(&Colorant::gray(0.5))).set_illuminance(100.0);
+let rgb: [u8; 3] = xyz.rgb(Some(RgbSpace::SRGB)).into();
+assert_eq!(rgb, [188, 188, 188]);
+```
+
+Constructors follow a common naming scheme:
+- `new` creates a value from its components, and `try_new` does so if these can be invalid;
+- `from_*` creates a value from another representation, and `try_from_*` does so if the
+  conversion can fail;
+- other fallible constructors, such as [`Illuminant::try_d_illuminant`], have a `try_` prefix;
+- named constructors, such as [`Illuminant::d65`], or [`Colorant::gaussian`], never fail.
+*/
+
+pub use super::action_spectrum::*;
+pub use super::aging::*;
+#[cfg(feature="cct")]
+pub use super::ambient_white::*;
+pub use super::analysis::*;
+pub use super::argyll::*;
+pub use super::audit::*;
+pub use super::band_metric::*;
+pub use super::batch::*;
+pub use super::bispectral::*;
+pub use super::cam::CieCam16;
+pub use super::cat::*;
 #[cfg(feature="cct")]
 pub use super::cct::*;
+#[cfg(all(feature="cri", feature="supplemental-observers"))]
+pub use super::cfi::*;
+pub use super::cgats::*;
+pub use super::chromaticity::*;
+pub use super::color_index::*;
 pub use super::colorant::*;
+pub use super::colorimeter::*;
+pub use super::compliance::*;
+#[cfg(feature="supplemental-observers")]
+pub use super::cone_fundamentals::*;
+#[cfg(feature="arrow")]
+pub use super::columnar::*;
 #[cfg(feature="cri")]
 pub use super::cri::*;
+pub use super::damage::*;
 pub use super::data::illuminants::*;
 pub use super::data::observers::*;
+pub use super::daylight::*;
+pub use super::delta_e_map::*;
+pub use super::dimming::*;
+pub use super::display_angles::*;
+pub use super::display_calibration::*;
+pub use super::error::CmtError;
+pub use super::exposure::*;
+pub use super::features::*;
+pub use super::fixture::*;
+pub use super::gamma::GammaCurve;
 pub use super::geometry::*;
+pub use super::hid::*;
+pub use super::hue::*;
 pub use super::illuminant::*;
+pub use super::instruments::*;
+pub use super::interreflection::*;
+pub use super::invariants::*;
+#[cfg(feature="cri")]
+pub use super::iso3664::*;
+pub use super::kelvin_slider::*;
+pub use super::kubelka_munk::*;
+pub use super::multi_primary::*;
 #[cfg(feature="munsell")]
 pub use super::munsell_matt::*;
+pub use super::oklab::*;
 pub use super::observer::*;
+pub use super::observer_comparison::*;
+pub use super::palette::*;
+pub use super::photometer::*;
 pub use super::physics::*;
+pub use super::pipeline::*;
+pub use super::polarization::*;
+pub use super::provenance::*;
+pub use super::random_spectra::*;
+pub use super::reillumination::*;
+pub use super::rendition::*;
+pub use super::report::*;
+pub use super::retroreflection::*;
 pub use super::rgb::*;
 pub use super::rgbspace::*;
+pub use super::s026::*;
+pub use super::safety_colors::*;
+pub use super::signal_lights::*;
+pub use super::simulate::*;
+#[cfg(all(feature="cri", feature="supplemental-observers"))]
+pub use super::skin_rendition::*;
+pub use super::sky::*;
+pub use super::source_type::*;
+pub use super::spd_text::*;
+pub use super::spectral_data::*;
+pub use super::spectral_image::*;
 pub use super::spectrum::*;
+pub use super::ssi::*;
 pub use super::std_illuminants::*;
+pub use super::stray_light::*;
 pub use super::stimulus::*;
+pub use super::surface::*;
+#[cfg(feature="cri")]
+pub use super::swatches::*;
+pub use super::tm27::*;
+#[cfg(all(feature="cri", feature="supplemental-observers"))]
+pub use super::tm30::*;
+pub use super::tone_mapping::*;
 pub use super::traits::*;
+pub use super::tuning::*;
 pub use super::lab::*;
+pub use super::luminaire::*;
+pub use super::measurement::*;
+pub use super::mesopic::*;
+pub use super::metamerism::*;
+pub use super::units::*;
+pub use super::upsampling::*;
+pub use super::uv_content::*;
+pub use super::viewconditions::{ViewConditions, CIE_HOME_DISPLAY, TM30VC};
+pub use super::video::*;
+pub use super::wavelength::*;
+pub use super::wavelength_domain::*;
+pub use super::whitepoint::*;
 pub use super::xyz::*;
 use wasm_bindgen::JsValue;
\ No newline at end of file
@@ -0,0 +1,157 @@
+/*!
+# Dataset Provenance
+
+The spectral datasets compiled into this library, such as the CIE standard observers and
+illuminants, are listed with their origin by [`dataset_info`], for use in quality records and
+software validation reports, which require the version, and license, of the reference data used in
+a calculation.
+
+Only the datasets included by the selected feature flags are listed. Retrieval dates, and
+licenses, are given only where they were recorded when the data was added to the library; they
+are `None` otherwise, and should be checked with the original publication.
+Data registered at runtime, such as the CIE 224 Color Evaluation Samples, is not included.
+```
+use colorimetry::prelude::*;
+
+for info in dataset_info() {
+    println!("{}: {} ({})", info.name, info.publication, info.license.unwrap_or("unknown license"));
+}
+let d65 = dataset("CIE standard illuminants D65 and D50").unwrap();
+assert_eq!(d65.retrieved, Some("2024-08"));
+```
+*/
+
+/// Origin of a dataset included in the library.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DatasetInfo {
+    /// Name of the dataset.
+    pub name: &'static str,
+    /// Publication, or standard, defining the data, including its version.
+    pub publication: &'static str,
+    /// Where the data was obtained from.
+    pub source: &'static str,
+    /// Date the data was retrieved, as an ISO 8601 date, or year and month, if recorded.
+    pub retrieved: Option<&'static str>,
+    /// License of the data, if known.
+    pub license: Option<&'static str>,
+    /// Changes made to the data for use in this library, such as truncation, or interpolation.
+    pub processing: &'static str,
+    /// Feature flag required to include the dataset, if any.
+    pub feature: Option<&'static str>,
+}
+
+const CIE_DATA_TABLES: &str = "https://cie.co.at/data-tables";
+const CC_BY_SA_4: &str = "CC BY-SA 4.0";
+
+static DATASETS: &[DatasetInfo] = &[
+    DatasetInfo {
+        name: "CIE 1931 standard colorimetric observer",
+        publication: "CIE 015:2018 Colorimetry, 4th edition",
+        source: "CIE 015:2018",
+        retrieved: None,
+        license: None,
+        processing: "1 nm steps, truncated to 380-780 nm",
+        feature: None,
+    },
+    DatasetInfo {
+        name: "CIE 1964 standard colorimetric observer",
+        publication: "CIE 015:2018 Colorimetry, 4th edition",
+        source: "CIE 015:2018",
+        retrieved: None,
+        license: None,
+        processing: "1 nm steps, truncated to 380-780 nm",
+        feature: None,
+    },
+    #[cfg(feature = "supplemental-observers")]
+    DatasetInfo {
+        name: "CIE 2015 2º and 10º cone fundamental based observers",
+        publication: "CIE 170-2:2015 Fundamental chromaticity diagram with physiological axes, part 2",
+        source: "CIE 170-2:2015",
+        retrieved: None,
+        license: None,
+        processing: "1 nm steps, truncated to 380-780 nm",
+        feature: Some("supplemental-observers"),
+    },
+    DatasetInfo {
+        name: "CIE standard illuminants D65 and D50",
+        publication: "CIE 015:2018 Colorimetry, 4th edition",
+        source: CIE_DATA_TABLES,
+        retrieved: Some("2024-08"),
+        license: None,
+        processing: "truncated to 380-780 nm",
+        feature: None,
+    },
+    #[cfg(feature = "cie-illuminants")]
+    DatasetInfo {
+        name: "CIE illuminants A, F1-F12, F3.1-F3.15, and LED-B1 to LED-V2",
+        publication: "CIE 015:2018 Colorimetry, 4th edition",
+        source: CIE_DATA_TABLES,
+        retrieved: Some("2024-08"),
+        license: None,
+        processing: "truncated to 380-780 nm, and interpolated to 1 nm steps where published in 5 nm steps",
+        feature: Some("cie-illuminants"),
+    },
+    DatasetInfo {
+        name: "CIE daylight components S0, S1, and S2",
+        publication: "CIE 015:2018 Colorimetry, 4th edition",
+        source: "CIE 015:2018",
+        retrieved: None,
+        license: None,
+        processing: "5 nm steps from 380 to 780 nm, linearly interpolated to 1 nm steps",
+        feature: None,
+    },
+    DatasetInfo {
+        name: "CIE 1951 scotopic luminous efficiency function V'(λ)",
+        publication: "CIE 015:2004 Colorimetry, 3rd edition",
+        source: "CIE 015:2004",
+        retrieved: None,
+        license: None,
+        processing: "10 nm steps from 380 to 780 nm, Sprague interpolated to 1 nm steps on a logarithmic scale",
+        feature: None,
+    },
+    #[cfg(feature = "cri")]
+    DatasetInfo {
+        name: "CIE 13.3 test color samples TCS01-TCS14",
+        publication: "CIE 013.3-1995 Method of measuring and specifying colour rendering properties of light sources",
+        source: CIE_DATA_TABLES,
+        retrieved: Some("2024-09-04"),
+        license: Some(CC_BY_SA_4),
+        processing: "truncated from 360-830 nm to 380-780 nm, in 5 nm steps, Sprague interpolated to 1 nm steps",
+        feature: Some("cri"),
+    },
+    #[cfg(feature = "munsell")]
+    DatasetInfo {
+        name: "Munsell matt spectral data, 1269 chips",
+        publication: "Spectral database, University of Eastern Finland",
+        source: "University of Eastern Finland, measured with a Perkin-Elmer lambda 9 spectrophotometer",
+        retrieved: None,
+        license: None,
+        processing: "1 nm steps from 380 to 800 nm, averaged to 5 nm steps from 380 to 780 nm",
+        feature: Some("munsell"),
+    },
+];
+
+/// Provenance of all datasets included in the library, with its current feature flags.
+pub fn dataset_info() -> &'static [DatasetInfo] {
+    DATASETS
+}
+
+/// Provenance of a dataset, by name.
+pub fn dataset(name: &str) -> Option<&'static DatasetInfo> {
+    DATASETS.iter().find(|d| d.name == name)
+}
+
+#[cfg(test)]
+mod provenance_test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_dataset_info() {
+        let info = dataset_info();
+        assert!(info.iter().any(|d| d.name.starts_with("CIE 1931")));
+        assert_eq!(info.iter().any(|d| d.feature == Some("cri")), cfg!(feature = "cri"));
+        #[cfg(feature = "cri")]
+        assert_eq!(dataset("CIE 13.3 test color samples TCS01-TCS14").unwrap().license, Some("CC BY-SA 4.0"));
+        assert!(dataset("unknown").is_none());
+    }
+}
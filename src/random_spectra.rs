@@ -0,0 +1,225 @@
+/*!
+# Random Spectra
+
+Generators of random, but plausible, spectral distributions, for creating machine learning
+datasets, and for stress-testing color metrics, and algorithms, with many more spectra than
+measured datasets provide:
+
+- [`ReflectanceGenerator`] produces smooth spectral reflectance factors, bounded between a
+  minimum, and a maximum, value, like those of natural objects, and most paints, and dyes;
+- [`LedGenerator`] produces LED-like spectral distributions, as combinations of narrow band LEDs,
+  optionally with the blue pump, and broad emission, of a phosphor converted white LED.
+
+The generators are seeded by the caller, and produce the same spectra for the same seed, using the
+same deterministic pseudo-random generator as the [`simulate`](crate::simulate) module.
+```
+use colorimetry::prelude::*;
+
+let reflectances = ReflectanceGenerator::new().set_smoothness(60.0).generate(100, 7);
+assert_eq!(reflectances.len(), 100);
+assert!(reflectances.iter().all(|r| (380..=780).all(|l| (0.0..=1.0).contains(&r[l]))));
+
+let leds = LedGenerator::new().set_primaries(2, 4).generate(10, 7);
+let again = LedGenerator::new().set_primaries(2, 4).generate(10, 7);
+assert!(leds.iter().zip(&again).all(|(a, b)| a.spectrum() == b.spectrum()));
+```
+*/
+
+use nalgebra::SVector;
+
+use crate::{
+    colorant::Colorant,
+    illuminant::Illuminant,
+    math,
+    simulate::Normal,
+    spectrum::{Spectrum, NS},
+};
+
+/// Generator of smooth, bounded, random spectral reflectance factors.
+///
+/// A spectrum is generated as a random smooth function, from random values at intervals of the
+/// smoothness length, interpolated with a Gaussian kernel, which is mapped into the range of
+/// reflectance values with a logistic function, with a random overall lightness.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ReflectanceGenerator {
+    smoothness: f64,
+    contrast: f64,
+    min: f64,
+    max: f64,
+}
+
+impl Default for ReflectanceGenerator {
+    fn default() -> Self {
+        Self { smoothness: 40.0, contrast: 2.0, min: 0.02, max: 0.95 }
+    }
+}
+
+impl ReflectanceGenerator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the correlation length, in nanometer, with larger values resulting in smoother, and
+    /// less saturated, spectra; the default is 40 nm.
+    pub fn set_smoothness(mut self, smoothness: f64) -> Self {
+        self.smoothness = smoothness.max(1.0);
+        self
+    }
+
+    /// Sets the amplitude of the spectral variations, before mapping them to the range of values,
+    /// with larger values resulting in more saturated colors; the default is 2.0.
+    pub fn set_contrast(mut self, contrast: f64) -> Self {
+        self.contrast = contrast;
+        self
+    }
+
+    /// Sets the minimum, and maximum, reflectance values, within the range from 0.0 to 1.0; the
+    /// defaults are 0.02 and 0.95.
+    pub fn set_range(mut self, min: f64, max: f64) -> Self {
+        self.min = min.clamp(0.0, 1.0);
+        self.max = max.clamp(self.min, 1.0);
+        self
+    }
+
+    /// Generates `n` reflectance spectra, using a seed for the random generator.
+    pub fn generate(&self, n: usize, seed: u64) -> Vec<Colorant> {
+        let mut rng = Normal::new(seed);
+        (0..n).map(|_| self.generate_with(&mut rng)).collect()
+    }
+
+    fn generate_with(&self, rng: &mut Normal) -> Colorant {
+        // knots extending one smoothness length beyond the domain, to avoid edge effects
+        let n_knots = (400.0 / self.smoothness).ceil() as usize + 3;
+        let knots: Vec<(f64, f64)> = (0..n_knots).map(|i| (380.0 + (i as f64 - 1.0) * self.smoothness, rng.sample())).collect();
+        let offset = rng.sample();
+        let sigma = self.smoothness / 2.0;
+        let data = SVector::<f64, NS>::from_fn(|i, _| {
+            let l = (i + 380) as f64;
+            let (sum, weights) = knots.iter().fold((0.0, 0.0), |(s, w), &(lk, v)| {
+                let k = math::exp(-0.5 * ((l - lk) / sigma).powi(2));
+                (s + k * v, w + k)
+            });
+            let v = self.contrast * sum / weights + offset;
+            self.min + (self.max - self.min) / (1.0 + math::exp(-v))
+        });
+        Colorant(Spectrum(data))
+    }
+}
+
+/// Generator of random LED-like spectral distributions, normalized to an irradiance of 1 W/m².
+///
+/// Each spectrum is a weighted sum of a random number of narrow band LEDs, with random center
+/// wavelengths, and widths, and, with a given probability, a phosphor converted white LED, with
+/// a blue pump from 440 to 465 nm, and a broad phosphor emission from 550 to 610 nm.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LedGenerator {
+    min_primaries: usize,
+    max_primaries: usize,
+    center: [f64; 2],
+    width: [f64; 2],
+    phosphor_probability: f64,
+}
+
+impl Default for LedGenerator {
+    fn default() -> Self {
+        Self { min_primaries: 1, max_primaries: 4, center: [420.0, 660.0], width: [15.0, 40.0], phosphor_probability: 0.5 }
+    }
+}
+
+impl LedGenerator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the minimum, and maximum, number of narrow band LEDs; the defaults are 1 and 4.
+    pub fn set_primaries(mut self, min: usize, max: usize) -> Self {
+        self.min_primaries = min;
+        self.max_primaries = max.max(min);
+        self
+    }
+
+    /// Sets the range of center wavelengths of the narrow band LEDs, in nanometer; the default
+    /// is from 420 to 660 nm.
+    pub fn set_center_range(mut self, min: f64, max: f64) -> Self {
+        self.center = [min, max.max(min)];
+        self
+    }
+
+    /// Sets the range of full widths at half maximum of the narrow band LEDs, in nanometer; the
+    /// default is from 15 to 40 nm.
+    pub fn set_width_range(mut self, min: f64, max: f64) -> Self {
+        self.width = [min, max.max(min)];
+        self
+    }
+
+    /// Sets the probability of including a phosphor converted white LED, from 0.0 to 1.0; the
+    /// default is 0.5.
+    pub fn set_phosphor_probability(mut self, probability: f64) -> Self {
+        self.phosphor_probability = probability.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Generates `n` spectral distributions, using a seed for the random generator.
+    pub fn generate(&self, n: usize, seed: u64) -> Vec<Illuminant> {
+        let mut rng = Normal::new(seed);
+        (0..n).map(|_| self.generate_with(&mut rng)).collect()
+    }
+
+    fn generate_with(&self, rng: &mut Normal) -> Illuminant {
+        let mut between = |[min, max]: [f64; 2]| min + (max - min) * rng.uniform();
+        let phosphor = between([0.0, 1.0]) < self.phosphor_probability;
+        let n = self.min_primaries + (between([0.0, 1.0]) * (self.max_primaries - self.min_primaries + 1) as f64) as usize;
+        let n = n.min(self.max_primaries);
+        let mut s = Spectrum::default();
+        for _ in 0..n {
+            let led = Illuminant::led(between(self.center), between(self.width));
+            s += led.0 * between([0.2, 1.0]);
+        }
+        if phosphor || n == 0 {
+            let pump = Illuminant::led(between([440.0, 465.0]), between([18.0, 25.0]));
+            let emission = Illuminant::led(between([550.0, 610.0]), between([100.0, 130.0]));
+            s += pump.0 * between([0.2, 0.4]) + emission.0;
+        }
+        Illuminant(s).set_irradiance(1.0)
+    }
+}
+
+#[cfg(test)]
+mod random_spectra_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_reflectance_generator() {
+        let generator = ReflectanceGenerator::new().set_range(0.1, 0.8);
+        let samples = generator.generate(200, 1);
+        for r in &samples {
+            assert!(r.0 .0.iter().all(|&v| (0.1..=0.8).contains(&v)));
+            // smooth: small differences between neighboring wavelengths
+            assert!(r.0 .0.as_slice().windows(2).all(|w| (w[1] - w[0]).abs() < 0.05));
+        }
+        assert_eq!(samples[..10], generator.generate(10, 1)[..]);
+        assert_ne!(samples[0], generator.generate(1, 2)[0]);
+
+        // a range of colors, from dark to light
+        let l: Vec<f64> = samples.iter().map(|r| CIE1931.lab_d65(r).values()[0]).collect();
+        assert!(l.iter().any(|&v| v < 50.0) && l.iter().any(|&v| v > 75.0));
+    }
+
+    #[test]
+    fn test_led_generator() {
+        let leds = LedGenerator::new().set_phosphor_probability(0.0).set_primaries(1, 1).set_center_range(520.0, 530.0).generate(20, 3);
+        for led in &leds {
+            assert_abs_diff_eq!(led.irradiance(), 1.0, epsilon = 1E-9);
+            let peak = (380..=780).max_by(|&a, &b| led[a].total_cmp(&led[b])).unwrap();
+            assert!((518..=532).contains(&peak));
+        }
+
+        // phosphor converted white LEDs are near white
+        let white = LedGenerator::new().set_primaries(0, 0).set_phosphor_probability(1.0).generate(20, 3);
+        for led in &white {
+            let [x, y] = CIE1931.xyz(led, None).xy().values();
+            assert!((0.25..0.5).contains(&x) && (0.25..0.5).contains(&y));
+        }
+    }
+}
@@ -0,0 +1,146 @@
+/*!
+# Image Re-illumination
+
+Re-renders images of a scene, captured under, or defined for, one illuminant, as they would appear
+under another, such as artwork lit by a halogen spotlight previewed under an LED replacement, or
+merchandise viewed under different shop lighting.
+
+For a [`SpectralImage`] of radiance spectra, the re-illumination is exact: the spectral values of
+each pixel are scaled by the ratio of the spectral distributions of the new and the original
+illuminant, which is the same as replacing the illuminant of the reflectances in the scene.
+For RGB images, without spectral information, the re-illumination is approximated by a von Kries
+chromatic adaptation, in the CAT16 cone space, from the white of the original illuminant to the
+white of the new one. This is exact for neutral colors only, and can deviate considerably for
+saturated colors, and for illuminants with very different spectral distributions, in particular
+narrow band sources.
+*/
+
+use nalgebra::Matrix3;
+
+use crate::{
+    cat::Cat,
+    observer::{Observer, ObserverData},
+    rgb::RGB,
+    spectral_image::SpectralImage,
+    spectrum::{Spectrum, NS},
+    traits::Light,
+    xyz::XYZ,
+};
+
+/**
+Transform re-rendering images made under an illuminant, as they would appear under another.
+```
+use colorimetry::prelude::*;
+
+let relight = Reillumination::new(&StdIlluminant::D65, &StdIlluminant::A, &CIE1931);
+
+// a white image, made under D65, takes on the color of illuminant A
+let relit = relight.rgb(&RGB::new(1.0, 1.0, 1.0, None, None));
+let xyz_a = CIE1931.xyz(&StdIlluminant::A, None);
+approx::assert_abs_diff_eq!(relit.xyz().xy().values().as_ref(), xyz_a.xy().values().as_ref(), epsilon = 1E-6);
+```
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub struct Reillumination {
+    ratio: Spectrum,
+    observer: Observer,
+    cat: Matrix3<f64>,
+}
+
+impl Reillumination {
+    /// Re-illumination from an illuminant `from`, to an illuminant `to`, with the chromatic
+    /// adaptation for RGB images calculated for an observer.
+    pub fn new(from: &dyn Light, to: &dyn Light, observer: &ObserverData) -> Self {
+        let [s_from, s_to] = [from.spectrum(), to.spectrum()];
+        let [xyz_from, xyz_to] = [from, to].map(|light| light.xyzn(observer.tag, None).xyzn);
+        // scale to an equal luminous value, and exclude wavelengths without power
+        let scale = xyz_from.y / xyz_to.y;
+        let cat = Cat::Cat16.matrix(xyz_from / xyz_from.y, xyz_to / xyz_to.y);
+        let ratio = Spectrum(nalgebra::SVector::<f64, NS>::from_fn(|i, _| {
+            let v = s_from.0[i];
+            if v > 0.0 {
+                s_to.0[i] / v * scale
+            } else {
+                0.0
+            }
+        }));
+        Self { ratio, observer: observer.tag, cat }
+    }
+
+    /// Spectral ratio of the new to the original illuminant, scaled to an equal luminous value.
+    pub fn ratio(&self) -> &Spectrum {
+        &self.ratio
+    }
+
+    /// A spectral image of radiance spectra, as it would be captured under the new illuminant.
+    /// The image's luminance is kept for neutral surfaces.
+    pub fn spectral_image(&self, image: &SpectralImage) -> SpectralImage {
+        let data = image.pixels().iter().flat_map(|pixel| (pixel.0.component_mul(&self.ratio.0)).data.0[0]).collect();
+        SpectralImage::try_new(image.width(), image.height(), data).unwrap()
+    }
+
+    /// Linear RGB values of a pixel, as they would appear under the new illuminant, approximated
+    /// by a chromatic adaptation transform. Values are not mapped into the color space's gamut.
+    ///
+    /// The pixel's observer is expected to be the observer of the re-illumination; otherwise its
+    /// values are returned unchanged.
+    pub fn rgb(&self, rgb: &RGB) -> RGB {
+        if rgb.observer != self.observer {
+            return *rgb;
+        }
+        let xyz = rgb.xyz();
+        let adapted = self.cat * xyz.xyz.unwrap_or(xyz.xyzn);
+        XYZ::from_vecs(xyz.xyzn, Some(adapted), self.observer).rgb(Some(rgb.space))
+    }
+
+    /// RGB values of all pixels of an image, re-illuminated as by [`Reillumination::rgb`].
+    pub fn rgb_image(&self, pixels: &[RGB]) -> Vec<RGB> {
+        pixels.iter().map(|rgb| self.rgb(rgb)).collect()
+    }
+
+    /// The chromatic adaptation matrix used for RGB images, operating on tristimulus values.
+    pub fn cat(&self) -> &Matrix3<f64> {
+        &self.cat
+    }
+}
+
+#[cfg(test)]
+mod reillumination_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    #[cfg(feature = "cie-illuminants")]
+    fn test_spectral_image() {
+        // reflectances captured as radiance under illuminant A, re-illuminated by D65
+        let samples = [Colorant::gray(0.5), Colorant::gaussian(600.0, 40.0)];
+        let a = StdIlluminant::A.illuminant();
+        let data = samples.iter().flat_map(|c| AsRef::<[f64]>::as_ref(&(c.0 * a.0)).to_vec()).collect();
+        let image = SpectralImage::try_new(2, 1, data).unwrap();
+
+        let relight = Reillumination::new(&StdIlluminant::A, &StdIlluminant::D65, &CIE1931);
+        let relit = relight.spectral_image(&image);
+        let xyz = relit.xyz(&CIE1931, None);
+        let scale = CIE1931.xyz(a, None).values()[1] / CIE1931.xyz(&StdIlluminant::D65, None).values()[1];
+        for (c, v) in samples.iter().zip(xyz) {
+            let want = CIE1931.xyz_from_spectrum(&(c.0 * StdIlluminant::D65.illuminant().0 * scale), None);
+            assert_abs_diff_eq!(v.values().as_ref(), want.values().as_ref(), epsilon = 1E-6);
+        }
+    }
+
+    #[test]
+    fn test_rgb() {
+        let relight = Reillumination::new(&StdIlluminant::D65, &StdIlluminant::D65, &CIE1931);
+        let rgb = RGB::new(0.2, 0.5, 0.7, None, None);
+        assert_abs_diff_eq!(relight.rgb(&rgb), rgb, epsilon = 1E-6);
+
+        // re-illuminating with a warm light makes images warmer
+        #[cfg(feature = "cie-illuminants")]
+        {
+            let relight = Reillumination::new(&StdIlluminant::D65, &StdIlluminant::A, &CIE1931);
+            let relit = relight.rgb_image(&[RGB::new(0.5, 0.5, 0.5, None, None)]);
+            let v: &nalgebra::Vector3<f64> = relit[0].as_ref();
+            assert!(v.x > v.y && v.y > v.z);
+        }
+    }
+}
@@ -0,0 +1,41 @@
+/*!
+# Color Rendition Metrics
+
+A common interface for color rendition metrics, such as the CIE 13.3 Color Rendering Index, so
+applications can evaluate a user selected set of metrics uniformly, and third parties can add
+their own metrics, by implementing the [`RenditionMetric`] trait.
+*/
+
+use crate::{error::CmtError, illuminant::Illuminant, observer::ObserverData};
+
+/// The result of a color rendition metric evaluation.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MetricResult {
+    /// Name of the metric, such as "CIE 13.3 Ra".
+    pub name: String,
+
+    /// The general, or overall, index value, such as Ra.
+    pub general: f64,
+
+    /// Special index values, with their labels, such as R1 to R14.
+    pub special: Vec<(String, f64)>,
+}
+
+impl MetricResult {
+    /// Value of a special index, by its label.
+    pub fn special(&self, label: &str) -> Option<f64> {
+        self.special.iter().find(|(l, _)| l == label).map(|&(_, v)| v)
+    }
+}
+
+/**
+A color rendition metric, evaluated for a light source, with an observer.
+
+Metrics which are only defined for a specific observer return an error for other observers.
+*/
+pub trait RenditionMetric {
+    /// Name of the metric.
+    fn name(&self) -> &str;
+
+    fn evaluate(&self, illuminant: &Illuminant, observer: &ObserverData) -> Result<MetricResult, CmtError>;
+}
@@ -0,0 +1,304 @@
+/*!
+# Reports
+
+A [`Report`] collects the colorimetric metrics of a light source, such as its chromaticity,
+correlated color temperature, and color rendering indices, and renders them as text, for
+datasheets and measurement reports.
+
+Numbers are formatted by a [`ReportFormatter`], which can be injected when rendering a report, to
+follow the conventions of a laboratory, or region.
+[`NumberFormat`] implements common conventions, with a choice of decimal separator, and the number
+of significant digits for each metric; closures taking a metric label and a value can be used as
+formatters too.
+
+[`CieFormat`], and [`round_for_report`], apply the rounding conventions of certification
+laboratories, following CIE 15 and CIE 13.3: chromaticity coordinates with four decimals, the
+correlated color temperature to the nearest kelvin, and color rendering indices as integers.
+Use [`round_to_uncertainty`] to round values to the precision justified by their measurement
+uncertainty instead.
+*/
+
+use std::collections::HashMap;
+
+use crate::{data::observers::CIE1931, illuminant::Illuminant, math};
+
+/// Formats the value of a metric, identified by its label, for a report.
+pub trait ReportFormatter {
+    fn format(&self, metric: &str, value: f64) -> String;
+}
+
+impl<F: Fn(&str, f64) -> String> ReportFormatter for F {
+    fn format(&self, metric: &str, value: f64) -> String {
+        self(metric, value)
+    }
+}
+
+/**
+Number format, with a decimal separator, and a number of significant digits, for each metric.
+```
+use colorimetry::prelude::*;
+
+let format = NumberFormat::new().set_decimal_separator(',').set_digits("CCT", 3);
+assert_eq!(format.format("x", 0.312_712), "0,3127");
+assert_eq!(format.format("CCT", 6504.0), "6500");
+```
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub struct NumberFormat {
+    decimal_separator: char,
+    default_digits: usize,
+    digits: HashMap<String, usize>,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NumberFormat {
+    /// A format with a decimal point, and four significant digits for all metrics.
+    pub fn new() -> Self {
+        Self { decimal_separator: '.', default_digits: 4, digits: HashMap::new() }
+    }
+
+    /// Sets the decimal separator, such as a `,` for a decimal comma.
+    pub fn set_decimal_separator(mut self, separator: char) -> Self {
+        self.decimal_separator = separator;
+        self
+    }
+
+    /// Sets the number of significant digits of metrics without a specific setting.
+    pub fn set_default_digits(mut self, digits: usize) -> Self {
+        self.default_digits = digits.max(1);
+        self
+    }
+
+    /// Sets the number of significant digits for a metric, by its label.
+    pub fn set_digits(mut self, metric: &str, digits: usize) -> Self {
+        self.digits.insert(metric.to_string(), digits.max(1));
+        self
+    }
+}
+
+impl ReportFormatter for NumberFormat {
+    fn format(&self, metric: &str, value: f64) -> String {
+        let digits = self.digits.get(metric).copied().unwrap_or(self.default_digits);
+        let s = if value == 0.0 || !value.is_finite() {
+            format!("{value}")
+        } else {
+            let magnitude = |v: f64| math::log10(v.abs()).floor() as i32;
+            let scale = 10f64.powi(magnitude(value) + 1 - digits as i32);
+            let rounded = (value / scale).round() * scale;
+            // rounding can add a digit, as in 99.996 to 100.0
+            let decimals = (digits as i32 - 1 - magnitude(rounded)).max(0) as usize;
+            format!("{rounded:.decimals$}")
+        };
+        if self.decimal_separator == '.' {
+            s
+        } else {
+            s.replace('.', &self.decimal_separator.to_string())
+        }
+    }
+}
+
+/// Number of decimals used for a metric in reports, following CIE 15, and CIE 13.3, or `None` for
+/// metrics without a prescribed rounding.
+fn report_decimals(metric: &str) -> Option<usize> {
+    match metric {
+        "x" | "y" | "u" | "v" | "u'" | "v'" | "Duv" => Some(4),
+        "CCT" | "Ra" | "Rf" | "Rg" => Some(0),
+        _ if metric.strip_prefix('R').is_some_and(|i| i.parse::<usize>().is_ok()) => Some(0),
+        _ => None,
+    }
+}
+
+/**
+Rounds the value of a metric, identified by its report label, as prescribed for reports:
+chromaticity coordinates `x`, `y`, `u'`, `v'`, and `Duv`, to four decimals, `CCT` to the nearest
+kelvin, and color rendering indices, such as `Ra`, `R9`, or `Rf`, to integers.
+Values of other metrics are returned unchanged.
+```
+use colorimetry::prelude::*;
+
+assert_eq!(round_for_report("Ra", 82.5), 83.0);
+assert_eq!(round_for_report("x", 0.312_727), 0.3127);
+assert_eq!(round_for_report("CCT", 6503.6), 6504.0);
+```
+*/
+pub fn round_for_report(metric: &str, value: f64) -> f64 {
+    match report_decimals(metric) {
+        Some(decimals) => {
+            let scale = 10f64.powi(decimals as i32);
+            (value * scale).round() / scale
+        }
+        None => value,
+    }
+}
+
+/**
+Rounds a value to the decimal position of the first significant digit of its uncertainty, such as
+a correlated color temperature of 6504 K, with an uncertainty of ±35 K, to 6500 K.
+Values with a zero, or non-finite, uncertainty are returned unchanged.
+```
+use colorimetry::prelude::*;
+
+assert_eq!(round_to_uncertainty(6504.2, 35.0), 6500.0);
+assert_eq!(round_to_uncertainty(0.312_727, 0.002), 0.313);
+```
+*/
+pub fn round_to_uncertainty(value: f64, uncertainty: f64) -> f64 {
+    if uncertainty == 0.0 || !uncertainty.is_finite() {
+        return value;
+    }
+    let exponent = math::log10(uncertainty.abs()).floor() as i32;
+    if exponent >= 0 {
+        let scale = 10f64.powi(exponent);
+        (value / scale).round() * scale
+    } else {
+        let scale = 10f64.powi(-exponent);
+        (value * scale).round() / scale
+    }
+}
+
+/**
+Report formatter using the rounding of [`round_for_report`], with a fixed number of decimals
+for each metric, and the significant digits of a [`NumberFormat`] for metrics without a prescribed
+rounding.
+```
+use colorimetry::prelude::*;
+
+let report = Report::new("Lamp").add("x", 0.4476, "").add("CCT", 2856.4, "K").add("Ra", 99.6, "");
+assert_eq!(report.render(&CieFormat::default()), "Lamp\nx\t0.4476\nCCT\t2856\tK\nRa\t100\n");
+```
+*/
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CieFormat(NumberFormat);
+
+impl CieFormat {
+    /// A formatter using a number format for metrics without a prescribed rounding, which also
+    /// sets the decimal separator.
+    pub fn new(format: NumberFormat) -> Self {
+        Self(format)
+    }
+}
+
+impl ReportFormatter for CieFormat {
+    fn format(&self, metric: &str, value: f64) -> String {
+        match report_decimals(metric) {
+            Some(decimals) => {
+                let s = format!("{:.decimals$}", round_for_report(metric, value));
+                if self.0.decimal_separator == '.' {
+                    s
+                } else {
+                    s.replace('.', &self.0.decimal_separator.to_string())
+                }
+            }
+            None => self.0.format(metric, value),
+        }
+    }
+}
+
+/// A named collection of metrics, with their labels, values, and units.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Report {
+    title: String,
+    rows: Vec<(String, f64, String)>,
+}
+
+impl Report {
+    pub fn new(title: &str) -> Self {
+        Self { title: title.to_string(), rows: Vec::new() }
+    }
+
+    /**
+    Report of the colorimetric metrics of a light source, for the CIE 1931 observer: its
+    chromaticity coordinates, and, depending on the enabled features, its correlated color
+    temperature and distance to the Planckian locus, and its general color rendering index
+    R<sub>a</sub>, and R<sub>9</sub>.
+    ```
+    use colorimetry::prelude::*;
+
+    let report = Report::for_light("D65", &Illuminant::d65());
+    let text = report.render(&NumberFormat::new().set_decimal_separator(','));
+    assert!(text.contains("x\t0,3127"));
+    ```
+    */
+    pub fn for_light(title: &str, light: &Illuminant) -> Self {
+        let xyz = CIE1931.xyz(light, None);
+        let [x, y] = xyz.xy().values();
+        let [u, v] = xyz.xy().uvprime().values();
+        #[allow(unused_mut)]
+        let mut report = Self::new(title).add("x", x, "").add("y", y, "").add("u'", u, "").add("v'", v, "");
+        #[cfg(feature = "cct")]
+        if let Ok(cct) = xyz.cct() {
+            report = report.add("CCT", cct.t(), "K").add("Duv", cct.d(), "");
+        }
+        #[cfg(feature = "cri")]
+        if let Ok(cri) = light.cri() {
+            report = report.add("Ra", cri.ra(), "").add("R9", cri[8], "");
+        }
+        report
+    }
+
+    /// Adds a metric, with its label, value, and unit, which can be empty.
+    pub fn add(mut self, metric: &str, value: f64, unit: &str) -> Self {
+        self.rows.push((metric.to_string(), value, unit.to_string()));
+        self
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// The value of a metric, by its label.
+    pub fn get(&self, metric: &str) -> Option<f64> {
+        self.rows.iter().find(|(m, _, _)| m == metric).map(|(_, v, _)| *v)
+    }
+
+    /// Renders the report as text, with its title on the first line, and a line for each metric,
+    /// with tab separated label, formatted value, and unit.
+    pub fn render(&self, formatter: &dyn ReportFormatter) -> String {
+        let mut s = format!("{}\n", self.title);
+        for (metric, value, unit) in &self.rows {
+            let line = format!("{metric}\t{}\t{unit}", formatter.format(metric, *value));
+            s.push_str(line.trim_end());
+            s.push('\n');
+        }
+        s
+    }
+}
+
+#[cfg(test)]
+mod report_test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_number_format() {
+        let f = NumberFormat::new();
+        assert_eq!(f.format("a", 1234.5678), "1235");
+        assert_eq!(f.format("a", -0.000_123_456), "-0.0001235");
+        assert_eq!(f.format("a", 0.0), "0");
+        assert_eq!(f.format("a", 99.996), "100.0");
+        assert_eq!(f.clone().set_digits("a", 2).format("a", 1234.5678), "1200");
+
+        let report = Report::new("Test").add("Ra", 82.345, "").add("L", 1500.0, "lm");
+        let custom = |metric: &str, value: f64| if metric == "Ra" { format!("{value:.0}") } else { format!("{value:.1}") };
+        assert_eq!(report.render(&custom), "Test\nRa\t82\nL\t1500.0\tlm\n");
+        assert_eq!(report.get("L"), Some(1500.0));
+    }
+
+    #[test]
+    fn test_round_for_report() {
+        assert_eq!(round_for_report("R9", -12.5), -13.0);
+        assert_eq!(round_for_report("R15", 91.49), 91.0);
+        assert_eq!(round_for_report("Duv", 0.003_26), 0.0033);
+        assert_eq!(round_for_report("L", 1234.567), 1234.567);
+        assert_eq!(round_to_uncertainty(1234.567, 0.5), 1234.6);
+        assert_eq!(round_to_uncertainty(1234.567, 150.0), 1200.0);
+
+        let format = CieFormat::new(NumberFormat::new().set_decimal_separator(','));
+        assert_eq!(format.format("y", 0.329_01), "0,3290");
+        assert_eq!(format.format("L", 1234.567), "1235");
+    }
+}
@@ -0,0 +1,250 @@
+/*!
+# Retroreflective Materials
+
+Retroreflective sheeting, used for traffic signs, road markings, and high-visibility garments,
+returns light towards its source, such as the headlamps of a car, and has two colors: its
+daytime color, as an ordinary surface color, measured with CIE illuminant D65, and a 45°:0°
+geometry, and its nighttime color, of the light retroreflected from a source with the spectral
+distribution of CIE illuminant A.
+
+The retroreflected light is described by the coefficient of retroreflection R<sub>A</sub>, in
+cd·lx<sup>-1</sup>·m<sup>-2</sup>, which depends on the entrance angle, between the illumination
+axis and the normal of the sample, and the observation angle, between the illumination axis and
+the observation axis.
+A [`RetroreflectiveMaterial`] holds the spectral reflectance factor of a material, and its spectral
+coefficients of retroreflection for a set of [`RetroGeometry`]s, and calculates the luminous
+coefficient of retroreflection, and the nighttime chromaticity, for illuminant A, and checks both
+colors against the color boxes of ASTM D4956, the standard specification for retroreflective
+sheeting for traffic control, with [`RetroreflectiveMaterial::check`].
+```
+use colorimetry::prelude::*;
+
+// a yellow sheeting, with a spectrally flat retroreflector behind a yellow filter
+let yellow = Colorant::top_hat(650.0, 260.0);
+let geometry = RetroGeometry::new(0.2, -4.0);
+let sheeting = RetroreflectiveMaterial::new()
+    .set_daytime_reflectance(yellow.clone())
+    .add_retroreflection(geometry, *yellow * 300.0);
+approx::assert_abs_diff_eq!(sheeting.coefficient(geometry).unwrap(), 300.0, epsilon = 60.0);
+
+let check = sheeting.check(D4956Color::Yellow, geometry).unwrap();
+assert_eq!(check.daytime_pass, Some(true));
+```
+*/
+
+use std::sync::OnceLock;
+
+use nalgebra::SVector;
+
+use crate::{
+    colorant::Colorant,
+    data::observers::CIE1931,
+    error::CmtError,
+    physics::{planck_c2, C2_NBS_1931},
+    safety_colors::convex_polygon_contains,
+    spectrum::{Spectrum, NS},
+    std_illuminants::StdIlluminant,
+};
+
+/// Measurement geometry for retroreflection, with the observation angle, and the entrance angle,
+/// in degrees.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RetroGeometry {
+    pub observation: f64,
+    pub entrance: f64,
+}
+
+impl RetroGeometry {
+    pub fn new(observation: f64, entrance: f64) -> Self {
+        Self { observation, entrance }
+    }
+}
+
+/// Color boxes of ASTM D4956.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, strum_macros::Display, strum_macros::EnumIter)]
+pub enum D4956Color {
+    White,
+    Yellow,
+    Orange,
+    Green,
+    Red,
+    Blue,
+    /// Brown, which has a daytime color box only.
+    Brown,
+}
+
+impl D4956Color {
+    /// Corner points of the daytime color box, in the CIE 1931 xy chromaticity diagram, for CIE
+    /// illuminant D65, and a 45°:0° geometry.
+    pub fn daytime_corners(&self) -> [[f64; 2]; 4] {
+        match self {
+            D4956Color::White => [[0.303, 0.300], [0.368, 0.366], [0.340, 0.393], [0.274, 0.329]],
+            D4956Color::Yellow => [[0.498, 0.412], [0.557, 0.442], [0.479, 0.520], [0.438, 0.472]],
+            D4956Color::Orange => [[0.558, 0.352], [0.636, 0.364], [0.570, 0.429], [0.506, 0.404]],
+            D4956Color::Green => [[0.026, 0.399], [0.166, 0.364], [0.286, 0.446], [0.207, 0.771]],
+            D4956Color::Red => [[0.648, 0.351], [0.735, 0.265], [0.629, 0.281], [0.565, 0.346]],
+            D4956Color::Blue => [[0.140, 0.035], [0.244, 0.210], [0.190, 0.255], [0.065, 0.216]],
+            D4956Color::Brown => [[0.430, 0.340], [0.610, 0.390], [0.550, 0.450], [0.430, 0.390]],
+        }
+    }
+
+    /// Corner points of the nighttime color box, in the CIE 1931 xy chromaticity diagram, for
+    /// retroreflected light of CIE illuminant A.
+    pub fn nighttime_corners(&self) -> Option<[[f64; 2]; 4]> {
+        match self {
+            D4956Color::White => Some([[0.475, 0.452], [0.360, 0.415], [0.392, 0.370], [0.515, 0.409]]),
+            D4956Color::Yellow => Some([[0.513, 0.487], [0.500, 0.470], [0.545, 0.425], [0.572, 0.425]]),
+            D4956Color::Orange => Some([[0.595, 0.405], [0.565, 0.405], [0.613, 0.355], [0.643, 0.355]]),
+            D4956Color::Green => Some([[0.007, 0.570], [0.200, 0.500], [0.322, 0.590], [0.193, 0.782]]),
+            D4956Color::Red => Some([[0.650, 0.348], [0.620, 0.348], [0.712, 0.255], [0.735, 0.265]]),
+            D4956Color::Blue => Some([[0.033, 0.370], [0.180, 0.370], [0.230, 0.240], [0.091, 0.133]]),
+            D4956Color::Brown => None,
+        }
+    }
+}
+
+/// The outcome of a [`RetroreflectiveMaterial::check`]; colors without data, or without a color
+/// box, are not evaluated.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RetroColorCheck {
+    pub color: D4956Color,
+    pub daytime_xy: Option<[f64; 2]>,
+    pub daytime_pass: Option<bool>,
+    pub nighttime_xy: Option<[f64; 2]>,
+    pub nighttime_pass: Option<bool>,
+    /// Luminous coefficient of retroreflection, in cd·lx⁻¹·m⁻², for CIE illuminant A.
+    pub coefficient: Option<f64>,
+}
+
+impl RetroColorCheck {
+    /// Checks if no evaluated color fails.
+    pub fn passes(&self) -> bool {
+        self.daytime_pass != Some(false) && self.nighttime_pass != Some(false)
+    }
+}
+
+/// Spectral data of a retroreflective material.
+#[derive(Clone, Debug, Default)]
+pub struct RetroreflectiveMaterial {
+    daytime: Option<Colorant>,
+    retroreflection: Vec<(RetroGeometry, Spectrum)>,
+}
+
+impl RetroreflectiveMaterial {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the spectral reflectance factor, for a 45°:0° geometry, which determines the daytime
+    /// color.
+    pub fn set_daytime_reflectance(mut self, reflectance: Colorant) -> Self {
+        self.daytime = Some(reflectance);
+        self
+    }
+
+    /// Adds the spectral coefficient of retroreflection, in cd·lx⁻¹·m⁻², for a geometry,
+    /// replacing previous data for the same geometry.
+    pub fn add_retroreflection(mut self, geometry: RetroGeometry, coefficient: Spectrum) -> Self {
+        self.retroreflection.retain(|(g, _)| *g != geometry);
+        self.retroreflection.push((geometry, coefficient));
+        self
+    }
+
+    pub fn daytime_reflectance(&self) -> Option<&Colorant> {
+        self.daytime.as_ref()
+    }
+
+    /// The geometries with retroreflection data, in the order they were added.
+    pub fn geometries(&self) -> Vec<RetroGeometry> {
+        self.retroreflection.iter().map(|(g, _)| *g).collect()
+    }
+
+    /// Spectral coefficient of retroreflection for a geometry.
+    pub fn spectral_coefficient(&self, geometry: RetroGeometry) -> Option<&Spectrum> {
+        self.retroreflection.iter().find(|(g, _)| *g == geometry).map(|(_, s)| s)
+    }
+
+    /// Luminous coefficient of retroreflection, in cd·lx⁻¹·m⁻², for CIE illuminant A, as the
+    /// spectral coefficient weighted by the illuminance of the source.
+    pub fn coefficient(&self, geometry: RetroGeometry) -> Option<f64> {
+        let ra = self.spectral_coefficient(geometry)?;
+        let a = illuminant_a();
+        let y = CIE1931.data.row(1);
+        Some((y * a.0.component_mul(&ra.0)).x / (y * a.0).x)
+    }
+
+    /// Chromaticity of the retroreflected light of CIE illuminant A, for the CIE 1931 observer.
+    pub fn nighttime_chromaticity(&self, geometry: RetroGeometry) -> Option<[f64; 2]> {
+        let ra = self.spectral_coefficient(geometry)?;
+        Some(CIE1931.xyz_from_spectrum(&Spectrum(illuminant_a().0.component_mul(&ra.0)), None).xy().values())
+    }
+
+    /// Chromaticity of the daytime color, for CIE illuminant D65, and the CIE 1931 observer.
+    pub fn daytime_chromaticity(&self) -> Option<[f64; 2]> {
+        self.daytime.as_ref().map(|c| CIE1931.xyz(&StdIlluminant::D65, Some(c)).xy().values())
+    }
+
+    /// Checks the daytime color, and the nighttime color, for a geometry, typically an observation
+    /// angle of 0.2°, and an entrance angle of -4°, against the color boxes of ASTM D4956.
+    pub fn check(&self, color: D4956Color, geometry: RetroGeometry) -> Result<RetroColorCheck, CmtError> {
+        if self.daytime.is_none() && self.retroreflection.is_empty() {
+            return Err(CmtError::ErrorString("RetroreflectiveMaterial: no spectral data".into()));
+        }
+        let daytime_xy = self.daytime_chromaticity();
+        let nighttime_xy = self.nighttime_chromaticity(geometry);
+        let daytime_pass = daytime_xy.map(|xy| convex_polygon_contains(&color.daytime_corners(), xy));
+        let nighttime_pass = nighttime_xy.zip(color.nighttime_corners()).map(|(xy, corners)| convex_polygon_contains(&corners, xy));
+        Ok(RetroColorCheck { color, daytime_xy, daytime_pass, nighttime_xy, nighttime_pass, coefficient: self.coefficient(geometry) })
+    }
+}
+
+/// Relative spectral distribution of CIE illuminant A, as defined by Planck's law, with the
+/// second radiation constant of 1931, for a temperature of 2848 K.
+fn illuminant_a() -> &'static Spectrum {
+    static A: OnceLock<Spectrum> = OnceLock::new();
+    A.get_or_init(|| {
+        let norm = planck_c2(560E-9, 2848.0, C2_NBS_1931);
+        Spectrum(SVector::<f64, NS>::from_fn(|i, _| 100.0 * planck_c2((380 + i) as f64 * 1E-9, 2848.0, C2_NBS_1931) / norm))
+    })
+}
+
+#[cfg(test)]
+mod retroreflection_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_illuminant_a() {
+        let a = super::illuminant_a();
+        assert_abs_diff_eq!(a[560], 100.0, epsilon = 1E-9);
+        let [x, y] = CIE1931.xyz_from_spectrum(a, None).xy().values();
+        assert_abs_diff_eq!(x, 0.44758, epsilon = 5E-5);
+        assert_abs_diff_eq!(y, 0.40745, epsilon = 5E-5);
+    }
+
+    #[test]
+    fn test_retroreflection() {
+        let geometry = RetroGeometry::new(0.2, -4.0);
+        let wide = RetroGeometry::new(0.5, 30.0);
+        // a white sheeting, spectrally flat
+        let white = RetroreflectiveMaterial::new()
+            .set_daytime_reflectance(Colorant::gray(0.8))
+            .add_retroreflection(geometry, *Colorant::gray(1.0) * 250.0)
+            .add_retroreflection(wide, *Colorant::gray(1.0) * 100.0);
+        assert_abs_diff_eq!(white.coefficient(geometry).unwrap(), 250.0, epsilon = 1E-9);
+        assert_abs_diff_eq!(white.coefficient(wide).unwrap(), 100.0, epsilon = 1E-9);
+        assert_eq!(white.geometries(), [geometry, wide]);
+
+        let check = white.check(D4956Color::White, geometry).unwrap();
+        assert_eq!((check.daytime_pass, check.nighttime_pass), (Some(true), Some(true)));
+        assert!(check.passes());
+        let check = white.check(D4956Color::Red, geometry).unwrap();
+        assert_eq!((check.daytime_pass, check.nighttime_pass), (Some(false), Some(false)));
+
+        // without retroreflection data, the nighttime color is not evaluated
+        let brown = RetroreflectiveMaterial::new().set_daytime_reflectance(Colorant::gray(0.1));
+        let check = brown.check(D4956Color::Brown, geometry).unwrap();
+        assert_eq!((check.nighttime_pass, check.coefficient), (None, None));
+        assert!(RetroreflectiveMaterial::new().check(D4956Color::White, geometry).is_err());
+    }
+}
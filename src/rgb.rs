@@ -9,13 +9,39 @@ use crate::{
     colorant::Colorant,
     traits::{Filter, Light},
     illuminant::Illuminant,
+    math,
     observer::Observer,
+    oklab::{oklab, oklab_inverse},
     rgbspace::RgbSpace,
     stimulus::Stimulus,
     data::observers::CIE1931
 };
 
 
+/// Tolerance used in the gamut check of RGB values.
+const GAMUT_TOLERANCE: f64 = 1E-9;
+
+/// Number of bisection steps used in the search for the cusp, and for the gamut boundary, in
+/// the [`GamutMapping::Cusp`] strategy.
+const CUSP_BISECTIONS: usize = 40;
+
+/// Strategies to map RGB values, outside the gamut of their color space, into it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, strum_macros::Display, strum_macros::EnumIter)]
+pub enum GamutMapping {
+    /// Clips each of the linear values to the range from 0.0 to 1.0. This is fast, but shifts
+    /// the hue, and the lightness, of saturated colors.
+    Clip,
+    /// Mixes the color with a gray of equal luminance, reducing its chroma towards the white
+    /// point until all values are non-negative, and clips values larger than one.
+    #[default]
+    ChromaReduction,
+    /// Reduces the chroma of the color in OKLab, at constant hue, along a line towards the gray
+    /// with the lightness of the cusp, the most chromatic color of that hue in the gamut, as
+    /// proposed by Björn Ottosson. This retains more of the chroma of saturated colors, and
+    /// maps colors which are too light, or too dark, onto the gamut boundary.
+    Cusp,
+}
+
 /// Representation of a color stimulus in a set of Red, Green, and Blue (RGB) values,
 /// representing its relative composition using standard primaries.
 /// 
@@ -26,6 +52,7 @@ use crate::{
 /// They cover a triangular area, referred to the _color gamut_ of a display.
 #[wasm_bindgen]
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RGB {
     
     /// The RGB color space the color values are using. Often this is the _sRGB_
@@ -73,6 +100,19 @@ impl RGB {
         RGB::new(r, g, b, observer, Some(space))
     }
 
+    /// Construct a RGB instance from gamma-encoded red, green, and blue values,
+    /// using extended range (scRGB) semantics.
+    ///
+    /// Encoded values are not restricted to the range from 0.0 to 1.0: negative
+    /// values, and values larger than 1.0, are decoded using the mirrored and
+    /// extended transfer function of the color space, and result in linear
+    /// values outside of the color space's gamut.
+    pub fn from_extended(r: f64, g: f64, b: f64, observer: Option<Observer>, space: Option<RgbSpace>) -> Self {
+        let space = space.unwrap_or_default();
+        let [r, g, b] = [r, g, b].map(|v|space.data().0.gamma.decode_extended(v));
+        RGB::new(r, g, b, observer, Some(space))
+    }
+
     pub fn from_xyz(xyz: XYZ, space: RgbSpace) -> Self {
         let xyz2rgb = xyz.observer.data().xyz2rgb(space);
         let xyz0 = xyz.xyz.unwrap_or(xyz.xyzn);
@@ -84,7 +124,7 @@ impl RGB {
     /// Converts the RGB value to a tri-stimulus XYZ value
     pub fn xyz(&self) -> XYZ {
         const YW: f64 = 100.0;
-        let xyzn = self.observer.data().xyz(&self.space.data().0.white, None).set_illuminance(100.0).xyzn;
+        let xyzn = self.observer.data().xyz_cie_table(&self.space.data().0.white, Some(100.0)).xyzn;
         let xyz = self.observer.data().rgb2xyz(&self.space) * self.rgb;
         XYZ {
             observer: self.observer,
@@ -93,6 +133,125 @@ impl RGB {
         }
     }
 
+    /// Checks if the linear RGB values are within the gamut of the color space,
+    /// with all values in the range from 0.0 to 1.0.
+    ///
+    /// A small tolerance is used, to allow for rounding errors in the
+    /// conversions from and to tristimulus values.
+    pub fn is_in_gamut(&self) -> bool {
+        self.rgb.iter().all(|&v| (-GAMUT_TOLERANCE..=1.0 + GAMUT_TOLERANCE).contains(&v))
+    }
+
+    /// Maps linear RGB values into the gamut of their color space, by mixing them with a gray of
+    /// equal luminance, and clipping values larger than one.
+    pub(crate) fn map_into_gamut(mut self) -> Self {
+        let rgb2xyz = self.observer.data().rgb2xyz(&self.space);
+        let y = (rgb2xyz.row(1) * self.rgb)[0].clamp(0.0, 1.0);
+        let gray = Vector3::repeat(y);
+        // largest fraction of the chromatic component for which all values are non-negative
+        let s = self
+            .rgb
+            .iter()
+            .zip(gray.iter())
+            .filter(|(&v, _)| v < 0.0)
+            .map(|(&v, &g)| g / (g - v))
+            .fold(1.0, f64::min);
+        self.rgb = (gray + (self.rgb - gray) * s).map(|v| v.clamp(0.0, 1.0));
+        self
+    }
+
+    /**
+    Maps linear RGB values outside the gamut of their color space into it, using a
+    [`GamutMapping`] strategy. Values within the gamut are returned unchanged, apart from rounding
+    errors, which are clipped.
+    ```
+    use colorimetry::prelude::*;
+
+    // a saturated Display P3 green, outside the sRGB gamut
+    let green = RGB::new(0.0, 1.0, 0.0, None, Some(RgbSpace::DisplayP3)).xyz().rgb(None);
+    assert!(!green.is_in_gamut());
+    for mapping in [GamutMapping::Clip, GamutMapping::ChromaReduction, GamutMapping::Cusp] {
+        assert!(green.gamut_mapped(mapping).is_in_gamut());
+    }
+    ```
+    */
+    pub fn gamut_mapped(self, mapping: GamutMapping) -> Self {
+        if self.is_in_gamut() {
+            return self.clipped();
+        }
+        match mapping {
+            GamutMapping::Clip => self.clipped(),
+            GamutMapping::ChromaReduction => self.map_into_gamut(),
+            GamutMapping::Cusp => self.cusp_mapped(),
+        }
+    }
+
+    /// Linear RGB values, clipped to the range from 0.0 to 1.0.
+    fn clipped(mut self) -> Self {
+        self.rgb = self.rgb.map(|v| v.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Maps linear RGB values into the gamut of their color space, with the
+    /// [`GamutMapping::Cusp`] strategy, using OKLab coordinates relative to the white of the color
+    /// space.
+    fn cusp_mapped(mut self) -> Self {
+        let rgb2xyz = self.observer.data().rgb2xyz(&self.space);
+        let xyz2rgb = self.observer.data().xyz2rgb(self.space);
+        let white = rgb2xyz * Vector3::repeat(1.0);
+        let lab = |rgb: Vector3<f64>| oklab(rgb2xyz * rgb, white);
+        let hue = |lab: Vector3<f64>| math::atan2(lab.z, lab.y);
+        let lab0 = lab(self.rgb);
+        if math::hypot(lab0.y, lab0.z) < GAMUT_TOLERANCE {
+            return self.clipped();
+        }
+
+        // The cusp is on the edges of the RGB cube, between the primaries and the secondaries,
+        // which are in order of increasing hue angle, starting at red.
+        const CORNERS: [[f64; 3]; 7] = [
+            [1.0, 0.0, 0.0], [1.0, 1.0, 0.0], [0.0, 1.0, 0.0], [0.0, 1.0, 1.0],
+            [0.0, 0.0, 1.0], [1.0, 0.0, 1.0], [1.0, 0.0, 0.0],
+        ];
+        let corners = CORNERS.map(Vector3::from);
+        let h0 = hue(lab(corners[0]));
+        let angle = |lab: Vector3<f64>| (hue(lab) - h0).rem_euclid(2.0 * std::f64::consts::PI);
+        let target = angle(lab0);
+        let i = (1..6).take_while(|&i| angle(lab(corners[i])) <= target).count();
+        let edge = |t: f64| corners[i] + (corners[i + 1] - corners[i]) * t;
+        let (mut lo, mut hi) = (0.0, 1.0);
+        for _ in 0..CUSP_BISECTIONS {
+            let t = (lo + hi) / 2.0;
+            if angle(lab(edge(t))) < target { lo = t } else { hi = t }
+        }
+        let l_cusp = lab(edge(lo)).x;
+
+        // largest fraction of the distance from the gray with the cusp lightness which is in gamut
+        let towards = |t: f64| {
+            let lab = Vector3::new(l_cusp + t * (lab0.x - l_cusp), t * lab0.y, t * lab0.z);
+            xyz2rgb * oklab_inverse(lab, white)
+        };
+        let (mut lo, mut hi) = (0.0, 1.0);
+        for _ in 0..CUSP_BISECTIONS {
+            let t = (lo + hi) / 2.0;
+            let rgb = towards(t);
+            if rgb.iter().all(|&v| (-GAMUT_TOLERANCE..=1.0 + GAMUT_TOLERANCE).contains(&v)) { lo = t } else { hi = t }
+        }
+        self.rgb = towards(lo);
+        self.clipped()
+    }
+
+    /// Gamma-encoded RGB values, using extended range (scRGB) semantics.
+    ///
+    /// As opposed to the conversion into `[u8;3]` values, the linear values are
+    /// not clamped, and negative values, or values larger than 1.0, are
+    /// retained in encoded form. Use this for high dynamic range, or wide
+    /// gamut intermediate values.
+    pub fn encode_extended(&self) -> [f64;3] {
+        let gamma = &self.space.data().0.gamma;
+        let &[r, g, b] = self.rgb.as_ref();
+        [r, g, b].map(|v|gamma.encode_extended(v))
+    }
+
     /// Creates a callback of closure function, which takes a set or RGB values, within a color
     /// space and viewed as one observer, and returns a new set of RGB values, represeting the
     /// stimulus in another color space, and using another observer.
@@ -127,6 +286,65 @@ impl RGB {
 #[cfg(test)]
 mod rgb_tests {
     use crate::prelude::*;
+    use approx::assert_ulps_eq;
+
+    #[test]
+    fn test_is_in_gamut(){
+        let white = RGB::new(1.0, 1.0, 1.0, None, None);
+        assert!(white.is_in_gamut());
+
+        let white_xyz = CIE1931.xyz_d65();
+        assert!(white_xyz.rgb(None).is_in_gamut());
+
+        let wide = RGB::new(1.2, 0.5, -0.1, None, None);
+        assert!(!wide.is_in_gamut());
+    }
+
+    #[test]
+    fn test_gamut_mapping(){
+        let wide = RGB::new(1.2, 0.5, -0.1, None, None);
+        assert_ulps_eq!(wide.gamut_mapped(GamutMapping::Clip), RGB::new(1.0, 0.5, 0.0, None, None));
+        let in_gamut = RGB::new(0.2, 0.5, 0.8, None, None);
+        for mapping in [GamutMapping::Clip, GamutMapping::ChromaReduction, GamutMapping::Cusp] {
+            assert_ulps_eq!(in_gamut.gamut_mapped(mapping), in_gamut);
+        }
+
+        // the cusp mapping retains the OKLab hue of saturated colors, and keeps more chroma than
+        // clipping them
+        let oklab = |rgb: RGB| OkLab::try_from(rgb.xyz()).unwrap();
+        for rgb in [[0.0, 1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0], [0.3, 1.0, 1.0]] {
+            let [r, g, b] = rgb;
+            let xyz = RGB::new(r, g, b, None, Some(RgbSpace::DisplayP3)).xyz();
+            let mapped = xyz.rgb_mapped(None, GamutMapping::Cusp);
+            assert!(mapped.is_in_gamut());
+            let lab = OkLab::try_from(xyz).unwrap();
+            let diff = (oklab(mapped).hue() - lab.hue()).abs();
+            assert!(diff.min(2.0 * std::f64::consts::PI - diff) < 1E-6);
+            assert!(oklab(mapped).chroma() <= lab.chroma());
+        }
+
+        // colors brighter than the white are mapped onto the gamut boundary
+        let bright = RGB::new(1.5, 1.4, 1.3, None, None).gamut_mapped(GamutMapping::Cusp);
+        assert!(bright.is_in_gamut());
+        assert!(bright.rgb.max() > 1.0 - 1E-6);
+    }
+
+    #[test]
+    fn test_extended_range(){
+        let rgb = RGB::new(1.5, 0.25, -0.2, None, None);
+        let [r, g, b] = rgb.encode_extended();
+        assert!(r > 1.0);
+        assert!(b < 0.0);
+
+        // roundtrip through the encoded values
+        let rgb2 = RGB::from_extended(r, g, b, None, None);
+        assert_ulps_eq!(rgb, rgb2, epsilon = 1E-12);
+
+        // u8 values are still clamped
+        let rgb_u8: [u8;3] = rgb.into();
+        assert_eq!(rgb_u8[0], 255);
+        assert_eq!(rgb_u8[2], 0);
+    }
 
 }
 
@@ -1,9 +1,12 @@
 use std::{collections::HashMap, sync::{LazyLock, OnceLock}};
 
 
+use nalgebra::Matrix3;
 use strum_macros::EnumIter;
 use wasm_bindgen::prelude::wasm_bindgen;
 use crate::{
+    error::CmtError,
+    observer::ObserverData,
     gamma::GammaCurve,
     rgb::gaussian_filtered_primaries,
     spectrum::Spectrum,
@@ -36,6 +39,7 @@ pub static XY_PRIMARIES: LazyLock<HashMap<&str, ([[f64;2];3], StdIlluminant)>> =
 
 
 #[derive(Debug, Clone, Copy, Default, EnumIter, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[wasm_bindgen]
 /**
 A Light Weight tag, representing an RGB color space.
@@ -90,6 +94,7 @@ pub struct RgbSpaceData {
     pub(crate) primaries: [Stimulus;3],
     pub(crate) white: StdIlluminant,
     pub(crate) gamma: GammaCurve,
+    colorants: OnceLock<[Colorant;3]>,
 }
 
 
@@ -106,18 +111,61 @@ impl RgbSpaceData {
     methods of `Observer`).
     */
     pub fn new(primaries: [Stimulus;3], white: StdIlluminant, gamma: GammaCurve) -> Self {
-        Self { primaries, white, gamma }
+        Self { primaries, white, gamma, colorants: OnceLock::new() }
+    }
+
+    /// Tristimulus values of the primaries, as columns, each with a luminous value of one, for
+    /// an observer.
+    pub(crate) fn primaries_xyz(&self, observer: &ObserverData) -> Matrix3<f64> {
+        Matrix3::from_iterator(self.primaries
+            .iter()
+            .flat_map(|s|observer.xyz_from_spectrum(s, None)
+            .set_illuminance(1.0).values()))
+    }
+
+    /**
+    RGB to XYZ matrix for an observer, derived from the spectra of the primaries, and of the
+    white, such that RGB values of one map to the white with a luminous value of one.
+
+    As the matrix is derived from the spectra, and not from the chromaticities of the primaries,
+    RGB values represent the same stimuli for all observers, such as the CIE 1964 or the CIE
+    2015 observers, with tristimulus values specific to the observer.
+    For the library color spaces, use the buffered [`ObserverData::rgb2xyz`] instead.
+    A `CouldNotInvertRGBMatrix` error is returned if the primaries are not resolved by the
+    observer, for example if two of them have the same chromaticity.
+    ```
+    use colorimetry::prelude::*;
+
+    let primaries = gaussian_filtered_primaries(&D65, [620.0, 20.0, 0.0], [530.0, 30.0], [455.0, 20.0]);
+    let space = RgbSpaceData::new(primaries, StdIlluminant::D65, GammaCurve::new(vec![2.2]));
+    let rgb2xyz = space.rgb2xyz(&CIE1931).unwrap();
+    let white = rgb2xyz * nalgebra::Vector3::repeat(100.0);
+    approx::assert_abs_diff_eq!(white.as_slice(), CIE1931.xyz_d65().values().as_slice(), epsilon = 1E-9);
+    ```
+    */
+    pub fn rgb2xyz(&self, observer: &ObserverData) -> Result<Matrix3<f64>, CmtError> {
+        let mut rgb2xyz = self.primaries_xyz(observer);
+        let xyzw = observer.xyz(&self.white, None).set_illuminance(1.0);
+        let rgbw = rgb2xyz.lu().solve(&xyzw.xyzn).ok_or(CmtError::CouldNotInvertRGBMatrix)?;
+        for (i, mut col) in rgb2xyz.column_iter_mut().enumerate() {
+            col *= rgbw[i];
+        }
+        Ok(rgb2xyz)
+    }
+
+    /// XYZ to RGB matrix for an observer, as the inverse of [`rgb2xyz`](Self::rgb2xyz).
+    pub fn xyz2rgb(&self, observer: &ObserverData) -> Result<Matrix3<f64>, CmtError> {
+        self.rgb2xyz(observer)?.try_inverse().ok_or(CmtError::CouldNotInvertRGBMatrix)
     }
 
     /**
       Get primaries as colorants.
 
-      Buffered - calculated on first use by division with the referene white illuminant.
+      Buffered per color space - calculated on first use by division with the referene white illuminant.
       Reference white spectra should not have 0.0 values.
      */
     pub fn primaries_as_colorants(&self) -> &[Colorant;3] {
-        static PRIMARY_FILTERS: OnceLock<[Colorant;3]> = OnceLock::new();
-        PRIMARY_FILTERS.get_or_init(||{
+        self.colorants.get_or_init(||{
             let white = self.white.illuminant().clone().set_illuminance(&CIE1931, 100.0).0;
             // RGB primaries defined with reference to CIE1931, and 100 cd/m2.
             let sv:  Vec<Spectrum> = self.primaries.iter().map(|v|&v.0/&white).collect();
@@ -152,7 +200,7 @@ impl RgbSpaceData {
             let primaries = gaussian_filtered_primaries(&D65, RED, GREEN, BLUE);
             let white = StdIlluminant::D65;
             let gamma = GammaCurve::new(vec![2.4, 1.0/1.055, 0.055/1.055, 1.0/12.92, 0.04045]);
-            Self { primaries, white, gamma}
+            Self::new(primaries, white, gamma)
         })
     }
     /**
@@ -179,7 +227,7 @@ impl RgbSpaceData {
             let white = StdIlluminant::D65;
             let gamma = GammaCurve::new(vec![563.0/256.0]);
                 // See https://en.wikipedia.org/wiki/Adobe_RGB_color_space#ICC_PCS_color_image_encoding
-            Self { primaries, white, gamma}
+            Self::new(primaries, white, gamma)
         })
     }
 
@@ -205,7 +253,7 @@ impl RgbSpaceData {
             let primaries = gaussian_filtered_primaries(&D65, RED, GREEN, BLUE);
             let white = StdIlluminant::D65;
             let gamma = GammaCurve::new(vec![2.4, 1.0/1.055, 0.055/1.055, 1.0/12.92, 0.04045]);
-            Self { primaries, white, gamma}
+            Self::new(primaries, white, gamma)
         })
     }
 }
@@ -224,7 +272,7 @@ mod rgbspace_tests {
         for space in RgbSpace::iter() {
             let (rgbspace, rgbstr) = space.data();
             for i in 0..3 {
-                let xy = CIE1931.xyz_from_spectrum(&rgbspace.primaries[i], None).chromaticity();
+                let xy = CIE1931.xyz_from_spectrum(&rgbspace.primaries[i], None).xy().values();
                 let xywant = XY_PRIMARIES[rgbstr].0[i];
                 assert_ulps_eq!(xy.as_ref(), xywant.as_ref(), epsilon = 1E-5);
 
@@ -232,4 +280,41 @@ mod rgbspace_tests {
         }
     }
 
+    #[test]
+    fn primaries_as_colorants_test(){
+        // buffered per color space
+        precompute();
+        let srgb = RgbSpace::SRGB.data().0.primaries_as_colorants();
+        let p3 = RgbSpace::DisplayP3.data().0.primaries_as_colorants();
+        assert!(srgb[1].0 != p3[1].0);
+        for space in RgbSpace::iter() {
+            let white = space.data().0.white.illuminant();
+            let colorants = space.data().0.primaries_as_colorants();
+            let xy = CIE1931.xyz(white, Some(&colorants[1])).xy().values();
+            let xywant = XY_PRIMARIES[space.data().1].0[1];
+            assert_ulps_eq!(xy.as_ref(), xywant.as_ref(), epsilon = 1E-5);
+        }
+    }
+
+    #[test]
+    fn observer_matrices_test(){
+        let srgb = RgbSpace::SRGB.data().0;
+        assert_ulps_eq!(srgb.rgb2xyz(&CIE1931).unwrap(), CIE1931.rgb2xyz(&RgbSpace::SRGB));
+        // primaries with the same spectrum can not be resolved
+        let primaries = gaussian_filtered_primaries(&D65, [550.0, 30.0, 0.0], [550.0, 30.0], [450.0, 20.0]);
+        let space = RgbSpaceData::new(primaries, StdIlluminant::D65, GammaCurve::new(vec![2.2]));
+        assert!(space.xyz2rgb(&CIE1931).is_err());
+
+        #[cfg(feature="supplemental-observers")]
+        {
+            // the spectral primaries give the same white, but different primary chromaticities,
+            // for the CIE 1964 observer
+            let m1964 = srgb.rgb2xyz(&CIE1964).unwrap();
+            let white = m1964 * nalgebra::Vector3::repeat(1.0);
+            assert_ulps_eq!(white, CIE1964.xyz_d65().xyzn / 100.0, epsilon = 1E-9);
+            assert!((m1964 - CIE1931.rgb2xyz(&RgbSpace::SRGB)).abs().max() > 1E-3);
+            assert_ulps_eq!(srgb.xyz2rgb(&CIE1964).unwrap(), CIE1964.xyz2rgb(RgbSpace::SRGB), epsilon = 1E-12);
+        }
+    }
+
 }
@@ -0,0 +1,272 @@
+/*!
+# CIE S 026 α-opic Metrology
+
+Besides the rods and cones, the human retina contains intrinsically photosensitive retinal
+ganglion cells, with the photopigment melanopsin, which drive non-visual responses to light,
+such as the entrainment of the circadian clock, and the suppression of melatonin.
+The CIE S 026/E:2018 standard defines five α-opic action spectra, for the S, M, and L cones, the
+rods, and melanopsin, and quantifies the effect of a light on each of these photoreceptors as an
+α-opic irradiance, in W/m², and as an α-opic equivalent daylight (D65) illuminance (EDI), in lux:
+the illuminance of CIE standard illuminant D65 with the same α-opic irradiance.
+The α-opic daylight efficacy ratio (DER) of a light is the ratio of its α-opic EDI to its
+illuminance, which is one for D65, and is used to compare the non-visual effectiveness of
+light sources, such as the melanopic DER of a LED lamp.
+
+The rhodopic action spectrum is the CIE 1951 scotopic luminous efficiency function V'(λ), as
+provided by [`CIE1951_SCOTOPIC`](crate::mesopic::CIE1951_SCOTOPIC), and the cone-opic action
+spectra are the CIE 2006 10º cone fundamentals, the basis of the CIE 2015 10º observer.
+The cyanopic action spectrum is recovered from the z̄<sub>F</sub> function of that observer, as
+[`CYANOPIC`], with the `supplemental-observers` feature, and [`AlphaOpicSpectra::set_spectrum`]
+replaces a spectrum in a set with it, or with the rhodopic action spectrum.
+The chloropic, and erythropic, action spectra can not be recovered yet, as the x̄<sub>F</sub> function
+of the CIE 2015 10º observer data in this library equals its ȳ<sub>F</sub> function, so that
+[`ConeFundamentals`](crate::cone_fundamentals::ConeFundamentals) rejects it; and the melanopic
+action spectrum, a tabulated photopigment template, is not included in this library.
+Load these from the tables published by the CIE, for example in its S 026 toolbox, using
+[`AlphaOpicSpectra::from_csv`].
+The Gaussian action spectra in the example below are stand-ins, which only illustrate the format,
+and do not give S 026 values for other lights than D65.
+```
+use colorimetry::prelude::*;
+
+// a table with wavelengths, and the cyanopic, chloropic, erythropic, rhodopic, and melanopic
+// action spectra, here with Gaussian stand-ins instead of the S 026 tables
+let peaks = [420.0, 535.0, 565.0, 507.0, 490.0];
+let csv = AlphaOpicSpectra::new(peaks.map(|p| Colorant::gaussian(p, 25.0).spectrum().into_owned())).to_csv();
+let spectra = AlphaOpicSpectra::from_csv(&csv).unwrap();
+let lamp = Illuminant::d65().set_illuminance(&CIE1931, 500.0);
+let alpha_opic = AlphaOpic::new(&lamp, &spectra);
+approx::assert_abs_diff_eq!(alpha_opic.edi(AlphaOpicReceptor::Melanopic), 500.0, epsilon = 0.01);
+approx::assert_abs_diff_eq!(alpha_opic.der(AlphaOpicReceptor::Melanopic), 1.0, epsilon = 1E-9);
+```
+*/
+
+#[cfg(feature = "supplemental-observers")]
+use std::sync::LazyLock;
+
+use strum::IntoEnumIterator;
+
+use crate::{
+    action_spectrum::ActionSpectrum,
+    error::CmtError,
+    illuminant::Illuminant,
+    mesopic::photopic_value,
+    spectrum::Spectrum,
+    traits::Light,
+};
+
+/// Number of α-opic action spectra in CIE S 026.
+pub const N_ALPHA_OPIC: usize = 5;
+
+/// The CIE S 026 cyanopic action spectrum, the CIE 2006 10º s̄ cone fundamental, normalized to a
+/// peak value of one, from the z̄<sub>F</sub> color matching function of the CIE 2015 10º observer,
+/// which is proportional to it.
+#[cfg(feature = "supplemental-observers")]
+pub static CYANOPIC: LazyLock<Spectrum> = LazyLock::new(|| {
+    let z = crate::data::observers::CIE2015_10.data.row(2).transpose();
+    Spectrum(z / z.max())
+});
+
+/// The photoreceptors of CIE S 026, in the order of its tables.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, strum_macros::Display, strum_macros::EnumIter)]
+pub enum AlphaOpicReceptor {
+    /// S cones
+    Cyanopic,
+    /// M cones
+    Chloropic,
+    /// L cones
+    Erythropic,
+    /// Rods
+    Rhodopic,
+    /// Intrinsically photosensitive retinal ganglion cells
+    Melanopic,
+}
+
+/// The five α-opic action spectra, and their efficacies for CIE standard illuminant D65.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AlphaOpicSpectra {
+    spectra: [Spectrum; N_ALPHA_OPIC],
+    efficacy_d65: [f64; N_ALPHA_OPIC],
+}
+
+impl AlphaOpicSpectra {
+    /// Action spectra, in the order of [`AlphaOpicReceptor`], each normalized to a peak value of one.
+    pub fn new(spectra: [Spectrum; N_ALPHA_OPIC]) -> Self {
+        let d65 = Illuminant::d65();
+        let illuminance = photopic_value(&d65.0);
+        let efficacy_d65 = std::array::from_fn(|i| spectra[i].0.dot(&d65.0 .0) / illuminance);
+        Self { spectra, efficacy_d65 }
+    }
+
+    /**
+    Action spectra from comma separated values, with wavelengths, in nanometer, in the first
+    column, followed by the cyanopic, chloropic, erythropic, rhodopic, and melanopic values, as in
+    the tables of CIE S 026.
+
+    An optional header line, and empty, and `#` comment lines, are skipped; empty fields, used in
+    the CIE tables for wavelengths where a function is not defined, are read as zero.
+    The data is linearly interpolated onto the 380 to 780 nanometer domain of this library.
+    */
+    pub fn from_csv(csv: &str) -> Result<Self, CmtError> {
+        let mut wavelengths = Vec::new();
+        let mut columns: [Vec<f64>; N_ALPHA_OPIC] = Default::default();
+        let lines = csv.lines().map(str::trim).enumerate().filter(|(_, l)| !l.is_empty() && !l.starts_with('#'));
+        for (n, (i, line)) in lines.enumerate() {
+            let fields: Result<Vec<f64>, _> = line
+                .split(',')
+                .map(|f| f.trim().trim_matches('"'))
+                .map(|f| if f.is_empty() { Ok(0.0) } else { f.parse::<f64>() })
+                .collect();
+            match fields {
+                Ok(values) if values.len() > N_ALPHA_OPIC => {
+                    if wavelengths.last().is_some_and(|&p| values[0] <= p) {
+                        return Err(CmtError::ParseError(format!("S 026: wavelengths not increasing on line {}", i + 1)));
+                    }
+                    wavelengths.push(values[0]);
+                    columns.iter_mut().zip(&values[1..]).for_each(|(c, &v)| c.push(v));
+                }
+                _ if n == 0 => {} // header
+                _ => return Err(CmtError::ParseError(format!("S 026: expected a wavelength and five values on line {}", i + 1))),
+            }
+        }
+        if wavelengths.len() < 2 {
+            return Err(CmtError::ProvideAtLeastNValues(2));
+        }
+        let mut spectra = Vec::with_capacity(N_ALPHA_OPIC);
+        for column in &columns {
+            spectra.push(Spectrum::try_linear_interpolate(&wavelengths, column)?);
+        }
+        Ok(Self::new(spectra.try_into().unwrap()))
+    }
+
+    /// Comma separated values, with a header line, and a line for each wavelength from 380 to 780
+    /// nanometer, in the layout read by [`from_csv`](Self::from_csv).
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("wavelength,cyanopic,chloropic,erythropic,rhodopic,melanopic\n");
+        for i in 0..self.spectra[0].0.len() {
+            let values: Vec<String> = self.spectra.iter().map(|s| s.0[i].to_string()).collect();
+            csv.push_str(&format!("{},{}\n", i + 380, values.join(",")));
+        }
+        csv
+    }
+
+    pub fn spectrum(&self, receptor: AlphaOpicReceptor) -> &Spectrum {
+        &self.spectra[receptor as usize]
+    }
+
+    /// Replaces the action spectrum of a receptor, normalized to a peak value of one, such as with
+    /// [`CYANOPIC`], or the rhodopic [`CIE1951_SCOTOPIC`](crate::mesopic::CIE1951_SCOTOPIC).
+    pub fn set_spectrum(self, receptor: AlphaOpicReceptor, spectrum: Spectrum) -> Self {
+        let mut spectra = self.spectra;
+        spectra[receptor as usize] = spectrum;
+        Self::new(spectra)
+    }
+
+    /// The α-opic efficacy of luminous radiation of CIE standard illuminant D65, in W/lm.
+    pub fn efficacy_d65(&self, receptor: AlphaOpicReceptor) -> f64 {
+        self.efficacy_d65[receptor as usize]
+    }
+
+    /// The action spectra, for α-opic irradiances in W/m², for use in an
+    /// [`ActionSpectrumRegistry`](crate::action_spectrum::ActionSpectrumRegistry), with the
+    /// lowercase names of the receptors.
+    pub fn action_spectra(&self) -> Vec<ActionSpectrum> {
+        AlphaOpicReceptor::iter()
+            .map(|r| ActionSpectrum::new(&r.to_string().to_lowercase(), "W/m²", *self.spectrum(r)))
+            .collect()
+    }
+}
+
+/// The α-opic irradiances of a light, with spectral irradiance values in W/m²/nm, and its illuminance.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AlphaOpic {
+    irradiance: [f64; N_ALPHA_OPIC],
+    efficacy_d65: [f64; N_ALPHA_OPIC],
+    illuminance: f64,
+}
+
+impl AlphaOpic {
+    pub fn new(light: &dyn Light, spectra: &AlphaOpicSpectra) -> Self {
+        let s = light.spectrum();
+        let irradiance = std::array::from_fn(|i| spectra.spectra[i].0.dot(&s.0));
+        Self { irradiance, efficacy_d65: spectra.efficacy_d65, illuminance: photopic_value(&s) }
+    }
+
+    /// The α-opic irradiance, in W/m².
+    pub fn irradiance(&self, receptor: AlphaOpicReceptor) -> f64 {
+        self.irradiance[receptor as usize]
+    }
+
+    /// The illuminance, in lux.
+    pub fn illuminance(&self) -> f64 {
+        self.illuminance
+    }
+
+    /// The α-opic efficacy of luminous radiation, the ratio of the α-opic irradiance to the
+    /// illuminance, in W/lm.
+    pub fn efficacy(&self, receptor: AlphaOpicReceptor) -> f64 {
+        self.irradiance(receptor) / self.illuminance
+    }
+
+    /// The α-opic equivalent daylight (D65) illuminance, in lux.
+    pub fn edi(&self, receptor: AlphaOpicReceptor) -> f64 {
+        self.irradiance(receptor) / self.efficacy_d65[receptor as usize]
+    }
+
+    /// The α-opic daylight (D65) efficacy ratio.
+    pub fn der(&self, receptor: AlphaOpicReceptor) -> f64 {
+        self.efficacy(receptor) / self.efficacy_d65[receptor as usize]
+    }
+}
+
+#[cfg(test)]
+mod s026_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+    use strum::IntoEnumIterator;
+
+    fn spectra() -> AlphaOpicSpectra {
+        let peaks = [420.0, 535.0, 565.0, 507.0, 490.0];
+        AlphaOpicSpectra::new(peaks.map(|p| Colorant::gaussian(p, 25.0).spectrum().into_owned()))
+    }
+
+    #[test]
+    fn test_alpha_opic() {
+        let spectra = spectra();
+        let d65 = AlphaOpic::new(&Illuminant::d65().set_illuminance(&CIE1931, 250.0), &spectra);
+        for r in AlphaOpicReceptor::iter() {
+            assert_abs_diff_eq!(d65.edi(r), d65.illuminance(), epsilon = 1E-9);
+            assert_abs_diff_eq!(d65.der(r), 1.0, epsilon = 1E-9);
+        }
+        // warm light has a lower melanopic efficacy than daylight
+        let lamp = AlphaOpic::new(&Illuminant::planckian(2700.0).set_illuminance(&CIE1931, 250.0), &spectra);
+        assert_abs_diff_eq!(lamp.illuminance(), 250.0, epsilon = 0.01);
+        assert!(lamp.der(AlphaOpicReceptor::Melanopic) < 0.6);
+        assert!(lamp.der(AlphaOpicReceptor::Erythropic) > 1.0);
+        assert_abs_diff_eq!(lamp.edi(AlphaOpicReceptor::Melanopic), lamp.illuminance() * lamp.der(AlphaOpicReceptor::Melanopic), epsilon = 1E-9);
+    }
+
+    #[test]
+    #[cfg(feature = "supplemental-observers")]
+    fn test_d65_efficacy() {
+        // the D65 α-opic efficacies of luminous radiation of CIE S 026, in mW/lm
+        let spectra = spectra()
+            .set_spectrum(AlphaOpicReceptor::Cyanopic, *CYANOPIC)
+            .set_spectrum(AlphaOpicReceptor::Rhodopic, *CIE1951_SCOTOPIC);
+        assert_abs_diff_eq!(1E3 * spectra.efficacy_d65(AlphaOpicReceptor::Cyanopic), 0.8173, epsilon = 1E-4);
+        assert_abs_diff_eq!(1E3 * spectra.efficacy_d65(AlphaOpicReceptor::Rhodopic), 1.4497, epsilon = 2E-4);
+        assert_abs_diff_eq!(spectra.spectrum(AlphaOpicReceptor::Cyanopic).0.max(), 1.0);
+    }
+
+    #[test]
+    fn test_csv() {
+        let spectra = spectra();
+        let back = AlphaOpicSpectra::from_csv(&spectra.to_csv()).unwrap();
+        for r in AlphaOpicReceptor::iter() {
+            assert_abs_diff_eq!(back.spectrum(r), spectra.spectrum(r), epsilon = 1E-12);
+        }
+        let with_gaps = AlphaOpicSpectra::from_csv("# S 026\nλ,S,M,L,rh,mel\n380,0.1,,,0.5,0.2\n780,0.0,0.1,0.2,0.0,0.0").unwrap();
+        assert_abs_diff_eq!(with_gaps.spectrum(AlphaOpicReceptor::Chloropic)[580], 0.05, epsilon = 1E-12);
+        assert!(AlphaOpicSpectra::from_csv("380,1,2,3\n780,1,2,3").is_err());
+    }
+}
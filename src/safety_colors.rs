@@ -0,0 +1,169 @@
+/*!
+# Safety Colors
+
+ISO 3864-4 specifies the colorimetric properties of the safety colors, and the contrast colors
+white and black, used in safety signs, and markings, and on personal protective equipment, for
+ordinary, non-fluorescent, and non-retroreflective, surface colors.
+Each color is defined by a quadrangle in the CIE 1931 xy chromaticity diagram, with its corner
+points, and a limit for its luminance factor, for measurements with CIE illuminant D65, the CIE
+1931 observer, and a 45°:0°, or 0°:45°, measurement geometry.
+
+A [`SafetyColor`] checks a spectral reflectance factor, or measured tristimulus values, against
+its region, and [`SafetyColor::classify`] finds the safety color of a sample, if any.
+```
+use colorimetry::prelude::*;
+
+let white = SafetyColor::White.check(&Colorant::gray(0.85));
+assert!(white.pass);
+let check = SafetyColor::Black.check(&Colorant::gray(0.05));
+assert!(check.chromaticity_pass && !check.luminance_factor_pass);
+assert_eq!(SafetyColor::classify(&Colorant::gray(0.02)), Some(SafetyColor::Black));
+```
+*/
+
+use strum::IntoEnumIterator;
+
+use crate::{
+    colorant::Colorant,
+    data::observers::CIE1931,
+    error::CmtError,
+    observer::Observer,
+    std_illuminants::StdIlluminant,
+    xyz::XYZ,
+};
+
+/// Safety colors, and contrast colors, of ISO 3864-4, for ordinary surface colors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, strum_macros::Display, strum_macros::EnumIter)]
+pub enum SafetyColor {
+    Red,
+    Yellow,
+    Green,
+    Blue,
+    White,
+    Black,
+}
+
+/// The outcome of a [`SafetyColor::check`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SafetyColorCheck {
+    pub color: SafetyColor,
+    /// CIE 1931 chromaticity coordinates of the sample, for illuminant D65.
+    pub xy: [f64; 2],
+    /// Luminance factor of the sample, from 0.0 to 1.0.
+    pub luminance_factor: f64,
+    pub chromaticity_pass: bool,
+    pub luminance_factor_pass: bool,
+    pub pass: bool,
+}
+
+impl SafetyColor {
+    /// Corner points of the chromaticity region, in the CIE 1931 xy diagram.
+    pub fn corners(&self) -> [[f64; 2]; 4] {
+        match self {
+            SafetyColor::Red => [[0.735, 0.265], [0.681, 0.239], [0.579, 0.341], [0.655, 0.345]],
+            SafetyColor::Yellow => [[0.545, 0.454], [0.494, 0.426], [0.444, 0.476], [0.481, 0.518]],
+            SafetyColor::Green => [[0.201, 0.776], [0.285, 0.441], [0.170, 0.364], [0.026, 0.399]],
+            SafetyColor::Blue => [[0.078, 0.171], [0.196, 0.250], [0.225, 0.184], [0.137, 0.038]],
+            SafetyColor::White => [[0.350, 0.360], [0.305, 0.315], [0.295, 0.325], [0.340, 0.370]],
+            SafetyColor::Black => [[0.385, 0.355], [0.300, 0.270], [0.260, 0.310], [0.345, 0.395]],
+        }
+    }
+
+    /// Minimum, and maximum, luminance factor, from 0.0 to 1.0.
+    pub fn luminance_factor_limits(&self) -> (f64, f64) {
+        match self {
+            SafetyColor::Red => (0.07, 1.0),
+            SafetyColor::Yellow => (0.45, 1.0),
+            SafetyColor::Green => (0.10, 1.0),
+            SafetyColor::Blue => (0.05, 1.0),
+            SafetyColor::White => (0.75, 1.0),
+            SafetyColor::Black => (0.0, 0.03),
+        }
+    }
+
+    /// Checks if a CIE 1931 chromaticity is within, or on the boundary of, the region.
+    pub fn contains(&self, xy: [f64; 2]) -> bool {
+        convex_polygon_contains(&self.corners(), xy)
+    }
+
+    /// Checks tristimulus values of a sample, for the CIE 1931 observer, and illuminant D65,
+    /// normalized to a luminous value of 100 for the illuminant.
+    pub fn check_xyz(&self, xyz: &XYZ) -> Result<SafetyColorCheck, CmtError> {
+        if xyz.observer != Observer::Std1931 {
+            return Err(CmtError::RequiresCIE1931XYZ);
+        }
+        let xy = xyz.xy().values();
+        let luminance_factor = xyz.values()[1] / 100.0;
+        let (min, max) = self.luminance_factor_limits();
+        let chromaticity_pass = self.contains(xy);
+        let luminance_factor_pass = (min..=max).contains(&luminance_factor);
+        Ok(SafetyColorCheck {
+            color: *self,
+            xy,
+            luminance_factor,
+            chromaticity_pass,
+            luminance_factor_pass,
+            pass: chromaticity_pass && luminance_factor_pass,
+        })
+    }
+
+    /// Checks a spectral reflectance factor.
+    pub fn check(&self, colorant: &Colorant) -> SafetyColorCheck {
+        let xyz = CIE1931.xyz(&StdIlluminant::D65, Some(colorant));
+        // unwrap: calculated for the CIE 1931 observer
+        self.check_xyz(&xyz).unwrap()
+    }
+
+    /// The safety color of a sample, if it meets the requirements of one.
+    pub fn classify(colorant: &Colorant) -> Option<SafetyColor> {
+        SafetyColor::iter().find(|color| color.check(colorant).pass)
+    }
+}
+
+/// Checks if a point is within, or on the boundary of, a convex polygon, with its corners in
+/// either clockwise, or counter-clockwise, order.
+pub(crate) fn convex_polygon_contains(corners: &[[f64; 2]], [x, y]: [f64; 2]) -> bool {
+    let n = corners.len();
+    let cross: Vec<f64> = (0..n)
+        .map(|i| {
+            let ([xa, ya], [xb, yb]) = (corners[i], corners[(i + 1) % n]);
+            (xb - xa) * (y - ya) - (yb - ya) * (x - xa)
+        })
+        .collect();
+    // on the same side of all edges
+    cross.iter().all(|&c| c >= 0.0) || cross.iter().all(|&c| c <= 0.0)
+}
+
+#[cfg(test)]
+mod safety_colors_test {
+    use crate::prelude::*;
+    use strum::IntoEnumIterator;
+
+    /// A colorant with a smooth transition between two reflectance values at a wavelength.
+    fn edge(wavelength: f64, low: f64, high: f64) -> Colorant {
+        let values: Vec<f64> = (380..=780).map(|l| low + (high - low) / (1.0 + (-(l as f64 - wavelength) / 8.0).exp())).collect();
+        Colorant::try_from(values.as_slice()).unwrap()
+    }
+
+    #[test]
+    fn test_safety_colors() {
+        // the corners are within, or on the boundary of, their regions
+        for color in SafetyColor::iter() {
+            assert!(color.corners().iter().all(|&xy| color.contains(xy)));
+        }
+
+        let red = edge(610.0, 0.01, 0.8);
+        assert_eq!(SafetyColor::classify(&red), Some(SafetyColor::Red));
+        let yellow = edge(530.0, 0.02, 0.85);
+        assert_eq!(SafetyColor::classify(&yellow), Some(SafetyColor::Yellow));
+        assert_eq!(SafetyColor::classify(&Colorant::gray(0.9)), Some(SafetyColor::White));
+        assert_eq!(SafetyColor::classify(&Colorant::gray(0.5)), None);
+
+        // too dark for a red
+        let check = SafetyColor::Red.check(&edge(610.0, 0.0, 0.1));
+        assert!(check.chromaticity_pass && !check.luminance_factor_pass && !check.pass);
+
+        let xyz = CIE1931.xyz(&StdIlluminant::D65, Some(&red));
+        assert!(SafetyColor::Red.check_xyz(&xyz).unwrap().pass);
+    }
+}
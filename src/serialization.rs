@@ -0,0 +1,136 @@
+/*!
+# Serialization
+
+Implementations of the [serde](https://serde.rs) `Serialize` and `Deserialize` traits, with the
+`serde` feature, to store calculated spectra, and results, and to exchange them with other
+applications, such as a web frontend, in any format supported by serde, such as JSON.
+
+A [`Spectrum`] is represented by its wavelength domain, with its first and last wavelengths, and
+its wavelength interval, in nanometer, and its values:
+```json
+{"domain": {"start": 380.0, "end": 780.0, "step": 1.0}, "values": [0.1, 0.1, ...]}
+```
+Spectra with other domains, such as data with 5 nanometer intervals, are accepted for
+deserialization too, and are linearly interpolated onto the domain of this library.
+Illuminants, colorants, and stimuli, are represented by their spectra, and tristimulus values,
+RGB values, and color rendering indices, by their fields, with vectors as arrays of values.
+```
+use colorimetry::prelude::*;
+
+let json = r#"{"domain": {"start": 380.0, "end": 780.0, "step": 100.0}, "values": [0.0, 0.25, 0.5, 0.75, 1.0]}"#;
+let colorant: Colorant = serde_json::from_str(json).unwrap();
+approx::assert_abs_diff_eq!(colorant[530], 0.375, epsilon = 1E-12);
+
+let xyz = CIE1931.xyz(&StdIlluminant::D65, Some(&colorant));
+let back: XYZ = serde_json::from_str(&serde_json::to_string(&xyz).unwrap()).unwrap();
+assert_eq!(back, xyz);
+```
+*/
+
+use nalgebra::SVector;
+use serde::{de::Error, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    spectrum::Spectrum,
+    wavelength_domain::WavelengthDomain,
+};
+
+/// Serialized form of a [`WavelengthDomain`], in nanometer.
+#[derive(Serialize, Deserialize)]
+struct Domain {
+    start: f64,
+    end: f64,
+    step: f64,
+}
+
+impl Serialize for WavelengthDomain {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Domain { start: self.start(), end: self.end(), step: self.step() }.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for WavelengthDomain {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let Domain { start, end, step } = Domain::deserialize(deserializer)?;
+        WavelengthDomain::try_new(start, end, step).map_err(D::Error::custom)
+    }
+}
+
+#[derive(Deserialize)]
+struct SpectrumData {
+    domain: WavelengthDomain,
+    values: Vec<f64>,
+}
+
+impl Serialize for Spectrum {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Spectrum", 2)?;
+        state.serialize_field("domain", &WavelengthDomain::LIBRARY)?;
+        state.serialize_field("values", self.0.as_slice())?;
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Spectrum {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let SpectrumData { domain, values } = SpectrumData::deserialize(deserializer)?;
+        if domain.len() != values.len() {
+            return Err(D::Error::custom(format!(
+                "Spectrum: {} values do not match a domain from {} to {} nm, with {} nm steps",
+                values.len(),
+                domain.start(),
+                domain.end(),
+                domain.step()
+            )));
+        }
+        if domain == WavelengthDomain::LIBRARY {
+            Ok(Spectrum(SVector::from_vec(values)))
+        } else {
+            Spectrum::try_linear_interpolate(&[domain.start(), domain.end()], &values).map_err(D::Error::custom)
+        }
+    }
+}
+
+#[cfg(test)]
+mod serialization_test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_serde_roundtrip() {
+        let d65 = Illuminant::d65();
+        let json = serde_json::to_string(&d65).unwrap();
+        assert!(json.starts_with(r#"{"domain":{"start":380.0,"end":780.0,"step":1.0},"values":["#));
+        let back: Illuminant = serde_json::from_str(&json).unwrap();
+        assert_eq!(*back, *d65);
+
+        let rgb = RGB::new(0.2, 0.4, 0.6, None, Some(RgbSpace::DisplayP3));
+        assert_eq!(serde_json::from_str::<RGB>(&serde_json::to_string(&rgb).unwrap()).unwrap(), rgb);
+
+        #[cfg(feature = "cri")]
+        {
+            let cri = CRI::try_new(&Illuminant::planckian(3000.0)).unwrap();
+            let back: CRI = serde_json::from_str(&serde_json::to_string(&cri).unwrap()).unwrap();
+            assert_eq!(back.as_ref(), cri.as_ref());
+        }
+
+        let xyz = CIE1931.xyz(&d65, None);
+        let json = serde_json::to_string(&xyz).unwrap();
+        assert_eq!(serde_json::from_str::<XYZ>(&json).unwrap(), xyz);
+
+        // values not matching the domain
+        let json = r#"{"domain": {"start": 380.0, "end": 780.0, "step": 5.0}, "values": [1.0, 2.0]}"#;
+        assert!(serde_json::from_str::<Spectrum>(json).is_err());
+        let json = r#"{"domain": {"start": 0.0, "end": 1E300, "step": 1.0}, "values": []}"#;
+        assert!(serde_json::from_str::<Spectrum>(json).is_err());
+        let json = r#"{"domain": {"start": 380.0, "end": 780.0, "step": 0.0}, "values": []}"#;
+        assert!(serde_json::from_str::<Spectrum>(json).is_err());
+
+        // wavelength domains are validated
+        let domain = WavelengthDomain::try_new(360.0, 830.0, 5.0).unwrap();
+        let json = serde_json::to_string(&domain).unwrap();
+        assert_eq!(json, r#"{"start":360.0,"end":830.0,"step":5.0}"#);
+        assert_eq!(serde_json::from_str::<WavelengthDomain>(&json).unwrap(), domain);
+        assert!(serde_json::from_str::<WavelengthDomain>(r#"{"start":380.0,"end":380.0,"step":0.0}"#).is_err());
+        assert!(serde_json::from_str::<WavelengthDomain>(r#"{"start":380.0,"step":0.0,"len":0}"#).is_err());
+    }
+}
@@ -0,0 +1,231 @@
+/*!
+# Signal Light Colors
+
+Traffic signals, and aeronautical ground lights, have to be recognized by their color, and their
+chromaticities are restricted to regions in the CIE 1931 xy chromaticity diagram, bounded by
+straight lines:
+
+- the Institute of Transportation Engineers (ITE) specification for Vehicle Traffic Control Signal
+  Heads defines the red, yellow, and green regions for traffic signals, and
+- Annex 14 of the ICAO Convention on International Civil Aviation defines the red, yellow, green,
+  blue, white, and variable white regions for aeronautical ground lights.
+
+A [`SignalRegion`] holds the boundaries of a color region, and tests if a chromaticity, or the
+spectral distribution of a light, is within it, with its margin to the nearest boundary, and the
+boundaries which are crossed.
+```
+use colorimetry::prelude::*;
+
+let red = SignalRegion::try_new(SignalStandard::Ite, SignalColor::Red).unwrap();
+let check = red.check(&Illuminant::led(630.0, 20.0));
+assert!(check.pass);
+
+// an orange LED is too yellow for a red signal
+let check = red.check(&Illuminant::led(605.0, 20.0));
+assert!(!check.pass);
+assert_eq!(check.violated, ["Yellow boundary"]);
+```
+*/
+
+use crate::{data::observers::CIE1931, error::CmtError, math, traits::Light};
+
+/// Standards defining signal light color regions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, strum_macros::Display, strum_macros::EnumIter)]
+pub enum SignalStandard {
+    /// ITE Vehicle Traffic Control Signal Heads, for traffic signals.
+    Ite,
+    /// ICAO Annex 14, Volume I, Appendix 1, for aeronautical ground lights.
+    Icao,
+}
+
+/// Signal light colors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, strum_macros::Display, strum_macros::EnumIter)]
+pub enum SignalColor {
+    Red,
+    Yellow,
+    Green,
+    Blue,
+    White,
+    /// White for lights with a variable intensity, such as dimmed incandescent lamps.
+    VariableWhite,
+}
+
+/// A half-plane a·x + b·y + c ≥ 0 in the CIE 1931 xy chromaticity diagram.
+type HalfPlane = [f64; 3];
+
+/// A named boundary of a region, as one or more half-planes, of which at least one has to contain
+/// a chromaticity, for boundaries consisting of two line segments with an inward corner.
+#[derive(Clone, Debug, PartialEq)]
+struct Boundary {
+    name: &'static str,
+    any_of: Vec<HalfPlane>,
+}
+
+impl Boundary {
+    /// Signed distance, in xy units, to the boundary, positive on the inside.
+    fn distance(&self, [x, y]: [f64; 2]) -> f64 {
+        self.any_of.iter().map(|&[a, b, c]| (a * x + b * y + c) / math::hypot(a, b)).fold(f64::NEG_INFINITY, f64::max)
+    }
+}
+
+/// The outcome of a [`SignalRegion::check`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct SignalCheck {
+    /// CIE 1931 chromaticity coordinates of the light.
+    pub xy: [f64; 2],
+    pub pass: bool,
+    /// Distance, in xy units, to the nearest boundary, positive inside the region, and negative
+    /// outside, where it is the distance to the boundary crossed most.
+    pub margin: f64,
+    /// Names of the boundaries which are crossed.
+    pub violated: Vec<String>,
+}
+
+/// A signal light color region, as the intersection of its boundaries.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SignalRegion {
+    standard: SignalStandard,
+    color: SignalColor,
+    boundaries: Vec<Boundary>,
+}
+
+impl SignalRegion {
+    /// The color region of a standard; returns an error for colors not defined by the standard.
+    pub fn try_new(standard: SignalStandard, color: SignalColor) -> Result<Self, CmtError> {
+        use SignalColor::*;
+        let b = |name: &'static str, any_of: &[HalfPlane]| Boundary { name, any_of: any_of.to_vec() };
+        let boundaries = match (standard, color) {
+            (SignalStandard::Ite, Red) => vec![
+                b("Yellow boundary", &[[0.0, -1.0, 0.308]]),
+                b("Purple boundary", &[[1.0, 1.0, -0.998]]),
+            ],
+            (SignalStandard::Ite, Yellow) => vec![
+                b("Red boundary", &[[0.0, 1.0, -0.411]]),
+                b("Green boundary", &[[0.0, -1.0, 0.452]]),
+                b("White boundary", &[[1.0, 1.0, -0.995]]),
+            ],
+            (SignalStandard::Ite, Green) => vec![
+                b("Blue boundary", &[[0.519, 1.0, -0.506]]),
+                b("White boundary", &[[-1.068, 1.0, -0.150]]),
+                b("Yellow boundary", &[[-1.0, -1.0, 0.730]]),
+            ],
+            (SignalStandard::Icao, Red) => vec![
+                b("Purple boundary", &[[1.0, 1.0, -0.980]]),
+                b("Yellow boundary", &[[0.0, -1.0, 0.335]]),
+            ],
+            (SignalStandard::Icao, Yellow) => vec![
+                b("Red boundary", &[[0.0, 1.0, -0.382]]),
+                b("White boundary", &[[0.667, 1.0, -0.790]]),
+                b("Green boundary", &[[1.0, -1.0, -0.120]]),
+            ],
+            (SignalStandard::Icao, Green) => vec![
+                b("Yellow boundary", &[[-1.0, -0.080, 0.360]]),
+                b("White boundary", &[[-1.0, 0.650, 0.0]]),
+                b("Blue boundary", &[[0.171, 1.0, -0.390]]),
+            ],
+            (SignalStandard::Icao, Blue) => vec![
+                b("Green boundary", &[[0.805, -1.0, 0.065]]),
+                b("White boundary", &[[-1.0, -1.0, 0.400]]),
+                b("Purple boundary", &[[-1.0, 0.600, 0.133]]),
+            ],
+            (SignalStandard::Icao, White) => vec![
+                b("Yellow boundary", &[[-1.0, 0.0, 0.500]]),
+                b("Blue boundary", &[[1.0, 0.0, -0.285]]),
+                b("Green boundary", &[[0.0, -1.0, 0.440]]),
+                b("Green boundary", &[[0.640, -1.0, 0.150]]),
+                b("Purple boundary", &[[-0.750, 1.0, -0.050], [0.0, 1.0, -0.382]]),
+            ],
+            (SignalStandard::Icao, VariableWhite) => vec![
+                b("Yellow boundary", &[[-1.0, 0.750, 0.255]]),
+                b("Yellow boundary", &[[-1.0, -1.500, 1.185]]),
+                b("Blue boundary", &[[1.0, 0.0, -0.285]]),
+                b("Green boundary", &[[0.0, -1.0, 0.440]]),
+                b("Green boundary", &[[0.640, -1.0, 0.150]]),
+                b("Purple boundary", &[[-0.750, 1.0, -0.050], [0.0, 1.0, -0.382]]),
+            ],
+            _ => return Err(CmtError::ErrorString(format!("{standard} does not define a {color} signal color"))),
+        };
+        Ok(Self { standard, color, boundaries })
+    }
+
+    pub fn standard(&self) -> SignalStandard {
+        self.standard
+    }
+
+    pub fn color(&self) -> SignalColor {
+        self.color
+    }
+
+    /// Checks if a CIE 1931 chromaticity is within, or on the boundary of, the region.
+    pub fn contains(&self, xy: [f64; 2]) -> bool {
+        self.boundaries.iter().all(|b| b.distance(xy) >= 0.0)
+    }
+
+    /// Checks a CIE 1931 chromaticity against the boundaries of the region.
+    pub fn check_chromaticity(&self, xy: [f64; 2]) -> SignalCheck {
+        let mut violated: Vec<String> = Vec::new();
+        let mut margin = f64::INFINITY;
+        for boundary in &self.boundaries {
+            let d = boundary.distance(xy);
+            margin = margin.min(d);
+            if d < 0.0 && !violated.iter().any(|v| v == boundary.name) {
+                violated.push(boundary.name.to_string());
+            }
+        }
+        SignalCheck { xy, pass: violated.is_empty(), margin, violated }
+    }
+
+    /// Checks the chromaticity of a light, such as a measured spectral distribution of a signal,
+    /// for the CIE 1931 observer, as used by the standards.
+    pub fn check(&self, light: &dyn Light) -> SignalCheck {
+        self.check_chromaticity(CIE1931.xyz(light, None).xy().values())
+    }
+}
+
+#[cfg(test)]
+mod signal_lights_test {
+    use crate::prelude::*;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn test_ite() {
+        let region = |color| SignalRegion::try_new(SignalStandard::Ite, color).unwrap();
+        assert!(region(SignalColor::Red).check(&Illuminant::led(630.0, 20.0)).pass);
+        assert!(region(SignalColor::Yellow).check(&Illuminant::led(592.0, 15.0)).pass);
+        assert!(region(SignalColor::Green).check(&Illuminant::led(505.0, 30.0)).pass);
+
+        // a yellow green LED is neither yellow, nor green
+        let lime = Illuminant::led(565.0, 20.0);
+        assert!(!region(SignalColor::Yellow).check(&lime).pass);
+        let check = region(SignalColor::Green).check(&lime);
+        assert!(check.violated.contains(&"Yellow boundary".to_string()));
+        assert!(check.margin < 0.0);
+
+        assert!(SignalRegion::try_new(SignalStandard::Ite, SignalColor::Blue).is_err());
+    }
+
+    #[test]
+    fn test_icao() {
+        let region = |color| SignalRegion::try_new(SignalStandard::Icao, color).unwrap();
+        assert!(region(SignalColor::Red).check(&Illuminant::led(625.0, 20.0)).pass);
+        assert!(region(SignalColor::Yellow).check(&Illuminant::led(590.0, 15.0)).pass);
+        assert!(region(SignalColor::Green).check(&Illuminant::led(520.0, 30.0)).pass);
+        assert!(region(SignalColor::Blue).check(&Illuminant::led(465.0, 20.0)).pass);
+
+        // incandescent, and daylight, whites are white, but the lime LED is not
+        let white = region(SignalColor::White);
+        #[cfg(feature = "cie-illuminants")]
+        assert!(white.check(&StdIlluminant::A).pass);
+        assert!(white.check(&StdIlluminant::D65).pass);
+        assert!(!white.check(&Illuminant::led(565.0, 20.0)).pass);
+        assert!(region(SignalColor::VariableWhite).check(&Illuminant::planckian(2200.0)).pass);
+        assert!(!white.check(&Illuminant::planckian(2200.0)).pass);
+
+        // all colors are defined by ICAO, and the regions do not overlap for the saturated colors
+        for color in SignalColor::iter() {
+            assert!(SignalRegion::try_new(SignalStandard::Icao, color).is_ok());
+        }
+        let red_led = Illuminant::led(630.0, 20.0);
+        assert_eq!(SignalColor::iter().filter(|&c| region(c).check(&red_led).pass).count(), 1);
+    }
+}
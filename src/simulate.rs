@@ -0,0 +1,152 @@
+/*!
+# Measurement Simulation
+
+Produces synthetic measurements of a known, "true", spectral distribution, as measured by an
+array spectrometer, with a [`Detector`] model including:
+
+- a finite spectral bandwidth, modeled as a Gaussian slit function;
+- a wavelength scale offset;
+- photon shot noise, with a standard deviation proportional to the square root of the signal;
+- detector dark, or read-out, noise, with a constant standard deviation.
+
+These are useful to test the sensitivity of colorimetric calculations, and of downstream
+algorithms, to measurement errors.
+The noise is generated by a small deterministic pseudo-random generator, seeded by the caller, so
+simulations can be repeated exactly.
+*/
+
+use crate::{math, spectrum::Spectrum};
+
+/**
+Spectrometer detector model.
+
+The shot noise is specified by the detector's gain, as the number of photo-electrons for a
+spectral value of 1.0; the standard deviation of the shot noise in a value `v` is
+`sqrt(v / gain)`. Set it to `None` for no shot noise.
+```
+use colorimetry::prelude::*;
+
+let detector = Detector { bandwidth: 5.0, dark_noise: 1E-3, ..Default::default() };
+let measured = detector.measure(&Illuminant::d65(), 42);
+approx::assert_abs_diff_eq!(measured[560], Illuminant::d65()[560], epsilon = 0.05);
+```
+*/
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Detector {
+    /// Full width at half maximum of the slit function, in nanometer, with 0.0 for an ideal
+    /// instrument.
+    pub bandwidth: f64,
+
+    /// Wavelength scale offset, in nanometer: the value reported at a wavelength λ is the value
+    /// at λ − `wavelength_shift` of the input spectrum.
+    pub wavelength_shift: f64,
+
+    /// Photo-electrons for a spectral value of 1.0, or `None` for no shot noise.
+    pub gain: Option<f64>,
+
+    /// Standard deviation of the dark noise, in the units of the spectral values.
+    pub dark_noise: f64,
+}
+
+impl Detector {
+
+    /// A simulated measurement of a spectrum, using a seed for the noise generator.
+    pub fn measure(&self, spectrum: &Spectrum, seed: u64) -> Spectrum {
+        let mut rng = Normal::new(seed);
+        self.measure_with(spectrum, &mut rng)
+    }
+
+    /// A series of `n` simulated measurements of a spectrum, each with different noise, using a
+    /// seed for the noise generator.
+    pub fn measure_n(&self, spectrum: &Spectrum, n: usize, seed: u64) -> Vec<Spectrum> {
+        let mut rng = Normal::new(seed);
+        (0..n).map(|_| self.measure_with(spectrum, &mut rng)).collect()
+    }
+
+    fn measure_with(&self, spectrum: &Spectrum, rng: &mut Normal) -> Spectrum {
+        let mut s = *spectrum;
+        if self.bandwidth > 0.0 {
+            s.smooth(self.bandwidth);
+        }
+        if self.wavelength_shift != 0.0 {
+            s = s.shift_wavelength(self.wavelength_shift);
+        }
+        for v in s.0.iter_mut() {
+            let shot = match self.gain {
+                Some(gain) if gain > 0.0 && *v > 0.0 => (*v / gain).sqrt(),
+                _ => 0.0,
+            };
+            let sd = (shot * shot + self.dark_noise * self.dark_noise).sqrt();
+            if sd > 0.0 {
+                *v += sd * rng.sample();
+            }
+        }
+        s
+    }
+}
+
+/// Standard normal distribution samples, using a SplitMix64 generator, and the Box-Muller
+/// transform.
+pub(crate) struct Normal {
+    state: u64,
+    spare: Option<f64>,
+}
+
+impl Normal {
+    pub(crate) fn new(seed: u64) -> Self {
+        Self { state: seed, spare: None }
+    }
+
+    pub(crate) fn uniform(&mut self) -> f64 {
+        self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^= z >> 31;
+        // 53 random bits, in the open interval (0, 1)
+        ((z >> 11) as f64 + 0.5) / (1u64 << 53) as f64
+    }
+
+    pub(crate) fn sample(&mut self) -> f64 {
+        if let Some(v) = self.spare.take() {
+            return v;
+        }
+        let r = (-2.0 * math::ln(self.uniform())).sqrt();
+        let phi = 2.0 * std::f64::consts::PI * self.uniform();
+        self.spare = Some(r * math::sin(phi));
+        r * math::cos(phi)
+    }
+}
+
+#[cfg(test)]
+mod simulate_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_noise() {
+        let flat = Colorant::gray(0.5).0;
+        let detector = Detector { gain: Some(1E4), dark_noise: 3E-3, ..Default::default() };
+        assert_eq!(detector.measure(&flat, 7), detector.measure(&flat, 7));
+        assert_ne!(detector.measure(&flat, 7), detector.measure(&flat, 8));
+
+        // expected standard deviation: sqrt(0.5/1E4 + 9E-6) = 7.7E-3
+        let values: Vec<f64> = detector.measure_n(&flat, 50, 1).iter().flat_map(|s| AsRef::<[f64]>::as_ref(s).to_vec()).collect();
+        let n = values.len() as f64;
+        let mean = values.iter().sum::<f64>() / n;
+        let sd = (values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.0)).sqrt();
+        assert_abs_diff_eq!(mean, 0.5, epsilon = 2E-4);
+        assert_abs_diff_eq!(sd, 7.7E-3, epsilon = 2E-4);
+    }
+
+    #[test]
+    fn test_shift_bandwidth() {
+        let line = Colorant::gaussian(550.0, 10.0).0;
+        let shifted = Detector { wavelength_shift: 2.0, ..Default::default() }.measure(&line, 0);
+        assert_abs_diff_eq!(shifted[552], 1.0, epsilon = 1E-12);
+
+        let broadened = Detector { bandwidth: 10.0, ..Default::default() }.measure(&line, 0);
+        // convolution of Gaussians with standard deviations of 10 and 4.2466 nm (FWHM 10 nm)
+        assert_abs_diff_eq!(broadened[550], 10.0 / (100.0f64 + 4.2466f64.powi(2)).sqrt(), epsilon = 1E-4);
+    }
+}
@@ -0,0 +1,144 @@
+/*!
+# Skin Tone Rendition
+
+Buyers of lighting for portraits, retail, and hospitality, ask specifically how well a light source
+renders human skin, which is not well represented by general fidelity indices. A
+[`SkinRendition`] collects the skin specific fidelity scores in one report:
+
+- the CIE 13.3 special color rendering index R13, for Test Color Sample 13, a light yellowish pink
+  representing a Caucasian complexion;
+- the special color rendering index R15, for the Japanese complexion sample No. 15 of JIS Z 8726,
+  which is required in some lighting specifications, but whose spectral data has to be provided
+  with [`SkinRendition::set_r15_sample`], as it is not included in this library;
+- the skin fidelity index Rf,skin, of ANSI/IES TM-30-20, as the mean of the special fidelity
+  indices of Color Evaluation Samples 15 and 18, which represent light, and dark, skin tones,
+  available if the samples have been registered with [`set_ces_samples`](crate::cfi::set_ces_samples),
+  as they are not included in this library either, so that, by default, a report only includes R13;
+- CIE 224 special fidelity indices Rf,i, for any additional skin reflectance spectra, such as
+  measured skin samples, set with [`SkinRendition::set_skin_samples`].
+
+```
+use colorimetry::prelude::*;
+
+let skin = Colorant::gaussian(640.0, 120.0) * 0.5 + Colorant::gray(0.1);
+let report = SkinRendition::new()
+    .set_r15_sample(skin.clone())
+    .set_skin_samples(vec![skin])
+    .evaluate(StdIlluminant::F2.illuminant())
+    .unwrap();
+assert!(report.r13 < 80.0);
+assert!(report.r15.unwrap() < 100.0);
+assert_eq!(report.rf_samples.len(), 1);
+```
+
+# References
+- CIE 013.3-1995 Method of measuring and specifying colour rendering properties of light sources
+- JIS Z 8726:1990 Method of specifying colour rendering properties of light sources
+- ANSI/IES TM-30-20, IES Method for Evaluating Light Source Color Rendition
+*/
+
+use crate::{
+    cfi::{ces_samples, Rf},
+    colorant::Colorant,
+    cri::{special_index, CRI},
+    error::CmtError,
+    illuminant::Illuminant,
+};
+
+/// Numbers of the TM-30 Color Evaluation Samples representing skin tones, used for Rf,skin.
+pub const CES_SKIN: [usize; 2] = [15, 18];
+
+/// Skin tone rendition evaluation, with its optional samples.
+#[derive(Clone, Debug, Default)]
+pub struct SkinRendition {
+    r15_sample: Option<Colorant>,
+    skin_samples: Vec<Colorant>,
+}
+
+/// Skin specific fidelity scores of a light source, as calculated by [`SkinRendition::evaluate`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct SkinRenditionReport {
+    /// CIE 13.3 special color rendering index R13.
+    pub r13: f64,
+    /// Special color rendering index R15, if the JIS Z 8726 sample was set.
+    pub r15: Option<f64>,
+    /// Special fidelity indices Rf,CES15 and Rf,CES18, if the Color Evaluation Samples were
+    /// registered.
+    pub rf_ces: Option<[f64; 2]>,
+    /// TM-30 skin fidelity index Rf,skin, the mean of Rf,CES15 and Rf,CES18.
+    pub rf_skin: Option<f64>,
+    /// CIE 224 special fidelity indices of the additional skin samples, in their order.
+    pub rf_samples: Vec<f64>,
+}
+
+impl SkinRendition {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the spectral reflectance factor of the JIS Z 8726 complexion sample No. 15, for R15.
+    pub fn set_r15_sample(mut self, sample: Colorant) -> Self {
+        self.r15_sample = Some(sample);
+        self
+    }
+
+    /// Sets additional skin reflectance spectra, evaluated with the CIE 224 special fidelity
+    /// index.
+    pub fn set_skin_samples(mut self, samples: Vec<Colorant>) -> Self {
+        self.skin_samples = samples;
+        self
+    }
+
+    /// Calculates the skin specific fidelity scores of a light source.
+    ///
+    /// Fails if the correlated color temperature of the source is out of range of the reference
+    /// illuminants.
+    pub fn evaluate(&self, illuminant: &Illuminant) -> Result<SkinRenditionReport, CmtError> {
+        let r13 = CRI::try_new(illuminant)?[12];
+        let r15 = self.r15_sample.as_ref().map(|sample| special_index(illuminant, sample)).transpose()?;
+        let rf_ces = match ces_samples() {
+            Some(ces) => {
+                let skin = CES_SKIN.map(|i| ces[i - 1].clone());
+                let rf = Rf::try_new_with_samples(illuminant, &skin)?;
+                Some([rf[0], rf[1]])
+            }
+            None => None,
+        };
+        let rf_skin = rf_ces.map(|[a, b]| (a + b) / 2.0);
+        let rf_samples = if self.skin_samples.is_empty() {
+            Vec::new()
+        } else {
+            Rf::try_new_with_samples(illuminant, &self.skin_samples)?.as_ref().to_vec()
+        };
+        Ok(SkinRenditionReport { r13, r15, rf_ces, rf_skin, rf_samples })
+    }
+}
+
+#[cfg(test)]
+mod skin_rendition_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_skin_rendition() {
+        let skin = vec![Colorant::gaussian(640.0, 120.0) * 0.5 + Colorant::gray(0.1), Colorant::gaussian(660.0, 100.0) * 0.3 + Colorant::gray(0.05)];
+
+        // a Planckian source is its own reference
+        let planckian = Illuminant::planckian(3000.0);
+        let report = SkinRendition::new().set_r15_sample(skin[0].clone()).set_skin_samples(skin.clone()).evaluate(&planckian).unwrap();
+        assert_abs_diff_eq!(report.r13, 100.0, epsilon = 0.05);
+        assert_abs_diff_eq!(report.r15.unwrap(), 100.0, epsilon = 0.05);
+        assert!(report.rf_samples.iter().all(|&rf| (rf - 100.0).abs() < 1E-3));
+
+        // R13, and R15, are the CRI special indices
+        #[cfg(feature = "cie-illuminants")]
+        {
+            let f2 = StdIlluminant::F2.illuminant();
+            let report = SkinRendition::new().set_r15_sample(skin[1].clone()).evaluate(f2).unwrap();
+            assert_abs_diff_eq!(report.r13, CRI::try_new(f2).unwrap()[12]);
+            assert_abs_diff_eq!(report.r15.unwrap(), special_index(f2, &skin[1]).unwrap());
+            assert!(report.rf_samples.is_empty());
+            assert_eq!(report.rf_skin.is_some(), ces_samples().is_some());
+        }
+    }
+}
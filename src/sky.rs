@@ -0,0 +1,190 @@
+/*!
+# CIE Standard General Sky
+
+The CIE Standard General Sky, ISO 15469:2004 / CIE S 011/E:2003, defines 15 relative luminance
+distributions of the sky, from overcast skies, with a luminance increasing towards the zenith, to
+clear, cloudless, skies, with a bright circumsolar region, for daylight simulations, such as
+daylight harvesting studies in buildings.
+
+The luminance of a sky element, relative to the zenith luminance, is the product of a gradation
+function φ(Z), of the zenith angle Z of the element, and a scattering indicatrix f(χ), of its
+angular distance χ from the sun:
+
+L/Lz = f(χ) φ(Z) / (f(Zs) φ(0)),
+
+with Zs the zenith angle of the sun; the five parameters a to e of these functions define the
+sky type.
+The standard defines luminance distributions only; it does not specify a distribution of
+chromaticity, or spectral radiance, of the sky.
+```
+use colorimetry::prelude::*;
+
+// on a clear sky the region around the sun is much brighter than the zenith
+let sky = CieSky::new(CieSkyType::Type12, 40.0, 180.0);
+assert!(sky.relative_luminance(45.0, 180.0) > 5.0);
+// and the opposite part of the sky is darker than the zenith
+assert!(sky.relative_luminance(45.0, 0.0) < 1.0);
+```
+*/
+
+use std::f64::consts::{FRAC_PI_2, PI};
+
+use crate::math;
+
+/// The 15 sky types of the CIE Standard General Sky.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, strum_macros::Display, strum_macros::EnumIter)]
+pub enum CieSkyType {
+    /// CIE standard overcast sky, steep gradation, and azimuthal uniformity.
+    #[default]
+    Type1,
+    /// Overcast, with steep gradation, and slight brightening towards the sun.
+    Type2,
+    /// Overcast, moderately graded, with azimuthal uniformity.
+    Type3,
+    /// Overcast, moderately graded, and slight brightening towards the sun.
+    Type4,
+    /// Sky of uniform luminance.
+    Type5,
+    /// Partly cloudy sky, no gradation towards the zenith, slight brightening towards the sun.
+    Type6,
+    /// Partly cloudy sky, no gradation towards the zenith, brighter circumsolar region.
+    Type7,
+    /// Partly cloudy sky, no gradation towards the zenith, distinct solar corona.
+    Type8,
+    /// Partly cloudy, with the obscured sun.
+    Type9,
+    /// Partly cloudy, with brighter circumsolar region.
+    Type10,
+    /// White-blue sky, with distinct solar corona.
+    Type11,
+    /// CIE standard clear sky, low luminance turbidity.
+    Type12,
+    /// CIE standard clear sky, polluted atmosphere.
+    Type13,
+    /// Cloudless turbid sky, with broad solar corona.
+    Type14,
+    /// White-blue turbid sky, with broad solar corona.
+    Type15,
+}
+
+impl CieSkyType {
+    /// The gradation parameters a and b, and the indicatrix parameters c, d, and e, of the
+    /// sky type.
+    pub fn parameters(&self) -> [f64; 5] {
+        match self {
+            CieSkyType::Type1 => [4.0, -0.70, 0.0, -1.0, 0.0],
+            CieSkyType::Type2 => [4.0, -0.70, 2.0, -1.5, 0.15],
+            CieSkyType::Type3 => [1.1, -0.8, 0.0, -1.0, 0.0],
+            CieSkyType::Type4 => [1.1, -0.8, 2.0, -1.5, 0.15],
+            CieSkyType::Type5 => [0.0, -1.0, 0.0, -1.0, 0.0],
+            CieSkyType::Type6 => [0.0, -1.0, 2.0, -1.5, 0.15],
+            CieSkyType::Type7 => [0.0, -1.0, 5.0, -2.5, 0.30],
+            CieSkyType::Type8 => [0.0, -1.0, 10.0, -3.0, 0.45],
+            CieSkyType::Type9 => [-1.0, -0.55, 2.0, -1.5, 0.15],
+            CieSkyType::Type10 => [-1.0, -0.55, 5.0, -2.5, 0.30],
+            CieSkyType::Type11 => [-1.0, -0.55, 10.0, -3.0, 0.45],
+            CieSkyType::Type12 => [-1.0, -0.32, 10.0, -3.0, 0.45],
+            CieSkyType::Type13 => [-1.0, -0.32, 16.0, -3.0, 0.30],
+            CieSkyType::Type14 => [-1.0, -0.15, 16.0, -3.0, 0.30],
+            CieSkyType::Type15 => [-1.0, -0.15, 24.0, -2.8, 0.15],
+        }
+    }
+}
+
+/// A sky of a standard type, with the sun at a position given by its zenith angle, and azimuth,
+/// in degrees.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CieSky {
+    sky_type: CieSkyType,
+    sun_zenith: f64,
+    sun_azimuth: f64,
+}
+
+impl CieSky {
+    pub fn new(sky_type: CieSkyType, sun_zenith_deg: f64, sun_azimuth_deg: f64) -> Self {
+        Self { sky_type, sun_zenith: sun_zenith_deg.to_radians(), sun_azimuth: sun_azimuth_deg.to_radians() }
+    }
+
+    pub fn sky_type(&self) -> CieSkyType {
+        self.sky_type
+    }
+
+    /// Gradation function φ(Z), for a zenith angle in radians.
+    fn gradation(&self, z: f64) -> f64 {
+        let [a, b, ..] = self.sky_type.parameters();
+        if z >= FRAC_PI_2 {
+            1.0
+        } else {
+            1.0 + a * math::exp(b / math::cos(z))
+        }
+    }
+
+    /// Scattering indicatrix f(χ), for an angular distance from the sun in radians.
+    fn indicatrix(&self, chi: f64) -> f64 {
+        let [_, _, c, d, e] = self.sky_type.parameters();
+        1.0 + c * (math::exp(d * chi) - math::exp(d * FRAC_PI_2)) + e * math::cos(chi).powi(2)
+    }
+
+    /// Angular distance, in radians, of a sky element from the sun.
+    fn sun_distance(&self, z: f64, azimuth: f64) -> f64 {
+        let cos_chi = math::cos(self.sun_zenith) * math::cos(z)
+            + math::sin(self.sun_zenith) * math::sin(z) * math::cos(azimuth - self.sun_azimuth);
+        math::acos(cos_chi.clamp(-1.0, 1.0))
+    }
+
+    /// Luminance of a sky element, at a zenith angle, and azimuth, in degrees, relative to the
+    /// zenith luminance.
+    pub fn relative_luminance(&self, zenith_deg: f64, azimuth_deg: f64) -> f64 {
+        let (z, azimuth) = (zenith_deg.to_radians(), azimuth_deg.to_radians());
+        self.indicatrix(self.sun_distance(z, azimuth)) * self.gradation(z)
+            / (self.indicatrix(self.sun_zenith) * self.gradation(0.0))
+    }
+
+    /**
+    Ratio of the diffuse illuminance on a horizontal, unobstructed, plane, in lux, to the zenith
+    luminance, in cd/m², obtained by integration over the sky hemisphere.
+    ```
+    use colorimetry::prelude::*;
+
+    // for a uniform sky the ratio is π
+    let uniform = CieSky::new(CieSkyType::Type5, 30.0, 0.0);
+    approx::assert_abs_diff_eq!(uniform.diffuse_illuminance_ratio(), std::f64::consts::PI, epsilon = 1E-3);
+    ```
+    */
+    pub fn diffuse_illuminance_ratio(&self) -> f64 {
+        const N_ZENITH: usize = 90;
+        const N_AZIMUTH: usize = 180;
+        let (dz, da) = (FRAC_PI_2 / N_ZENITH as f64, 2.0 * PI / N_AZIMUTH as f64);
+        let mut sum = 0.0;
+        for i in 0..N_ZENITH {
+            let z = (i as f64 + 0.5) * dz;
+            let ring: f64 = (0..N_AZIMUTH)
+                .map(|j| self.relative_luminance(z.to_degrees(), ((j as f64 + 0.5) * da).to_degrees()))
+                .sum();
+            sum += ring * math::cos(z) * math::sin(z) * dz * da;
+        }
+        sum
+    }
+}
+
+#[cfg(test)]
+mod sky_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn test_cie_sky() {
+        for sky_type in CieSkyType::iter() {
+            let sky = CieSky::new(sky_type, 35.0, 90.0);
+            assert_abs_diff_eq!(sky.relative_luminance(0.0, 0.0), 1.0, epsilon = 1E-12);
+            assert!(sky.diffuse_illuminance_ratio() > 0.0);
+        }
+        // the CIE overcast sky is three times brighter at the zenith than at the horizon
+        let overcast = CieSky::new(CieSkyType::Type1, 60.0, 0.0);
+        assert_abs_diff_eq!(overcast.relative_luminance(89.999, 0.0), 1.0 / 3.0, epsilon = 0.01);
+        assert_abs_diff_eq!(overcast.relative_luminance(45.0, 0.0), overcast.relative_luminance(45.0, 180.0), epsilon = 1E-12);
+        // the overcast ratio is 7π/9
+        assert_abs_diff_eq!(overcast.diffuse_illuminance_ratio(), 7.0 * std::f64::consts::PI / 9.0, epsilon = 0.01);
+    }
+}
@@ -0,0 +1,183 @@
+/*!
+# Light Source Classification
+
+Heuristic classification of light sources, by features of their spectral distribution, for
+automated processing of field measurements:
+
+- narrow emission lines, such as the mercury lines at 436 and 546 nanometer, found in fluorescent
+  lamps, and in high intensity discharge (HID) lamps;
+- the broad sodium emission, around 590 nanometer, of high pressure sodium lamps;
+- a smooth spectrum, following Planck's law, for incandescent lamps;
+- a narrow blue pump peak, followed by a broad phosphor emission band, for phosphor converted
+  LEDs, or a set of narrow bands without a phosphor, for RGB LEDs;
+- a smooth broad spectrum, for daylight.
+
+The classification uses spectral shapes only, and is intended for spectral measurements with a
+resolution of 5 nanometer, or better. It can fail for unusual sources, or for sources filtered by
+colored glass, or other materials.
+*/
+
+use crate::{
+    physics::planck,
+    spectrum::{Spectrum, NS},
+};
+
+/// Half width, in nanometer, of the window used to detect emission lines.
+const LINE_WINDOW: usize = 4;
+
+/// Minimum height of an emission line above its surroundings, relative to the spectrum's maximum.
+const LINE_HEIGHT: f64 = 0.08;
+
+/// Mercury emission lines, in nanometer, in the visible spectrum.
+const MERCURY_LINES: [usize; 4] = [405, 436, 546, 578];
+
+/// Light source types, as determined by [`SourceType::classify`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, strum_macros::Display)]
+pub enum SourceType {
+    Incandescent,
+    Fluorescent,
+    LedPhosphor,
+    LedRgb,
+    Daylight,
+    /// High intensity discharge lamps: mercury vapor, metal halide, and high pressure sodium.
+    HID,
+}
+
+impl SourceType {
+    /**
+    Classifies a light source, by its spectral distribution.
+    ```
+    use colorimetry::prelude::*;
+
+    assert_eq!(SourceType::classify(&Illuminant::planckian(2700.0)), SourceType::Incandescent);
+    assert_eq!(SourceType::classify(&Illuminant::d65()), SourceType::Daylight);
+    ```
+    */
+    pub fn classify(spectrum: &Spectrum) -> Self {
+        let max = spectrum.0.max();
+        if max <= 0.0 {
+            return SourceType::Daylight;
+        }
+        let s: Vec<f64> = spectrum.0.iter().map(|v| v / max).collect();
+        let band = |a: usize, b: usize| s[a - 380..=b - 380].iter().sum::<f64>();
+        let total = band(380, 780);
+
+        let lines = emission_lines(&s);
+        let mercury = MERCURY_LINES[1..3].iter().all(|&l| lines.iter().any(|&p| p.abs_diff(l) <= 2));
+        let other_lines = lines.iter().filter(|&&p| MERCURY_LINES.iter().all(|&l| p.abs_diff(l) > 2)).count();
+
+        if band(560, 620) / total > 0.45 && band(380, 500) / total < 0.12 {
+            // high pressure sodium
+            SourceType::HID
+        } else if mercury {
+            // fluorescent lamps have few lines other than mercury lines, from rare earth phosphors,
+            // while mercury vapor lamps lack red emission
+            if other_lines > 6 || band(600, 700) / total < 0.08 {
+                SourceType::HID
+            } else {
+                SourceType::Fluorescent
+            }
+        } else if planck_fit_error(&s) < 0.01 {
+            SourceType::Incandescent
+        } else {
+            let peaks = bands(&s);
+            let valley = |a: usize, b: usize| s[a - 380..=b - 380].iter().copied().fold(f64::INFINITY, f64::min);
+            match peaks.as_slice() {
+                [] => SourceType::Daylight,
+                [p, ..] if *p < 480 && valley(*p, 520) < 0.6 * s[*p - 380] => {
+                    if peaks.len() >= 3 && valley(540, 600) < 0.3 {
+                        SourceType::LedRgb
+                    } else {
+                        SourceType::LedPhosphor
+                    }
+                }
+                _ if peaks.len() >= 3 => SourceType::LedRgb,
+                _ => SourceType::Daylight,
+            }
+        }
+    }
+}
+
+/// Wavelengths of narrow emission lines, as local maxima exceeding the median of their
+/// surroundings.
+fn emission_lines(s: &[f64]) -> Vec<usize> {
+    (LINE_WINDOW..NS - LINE_WINDOW)
+        .filter(|&i| {
+            let window = &s[i - LINE_WINDOW..=i + LINE_WINDOW];
+            if window.iter().any(|&v| v > s[i]) {
+                return false;
+            }
+            let mut sorted = window.to_vec();
+            sorted.sort_by(f64::total_cmp);
+            s[i] - sorted[LINE_WINDOW] > LINE_HEIGHT
+        })
+        .map(|i| i + 380)
+        .collect()
+}
+
+/// Wavelengths of emission bands, as local maxima with a value of at least 0.04, separated from
+/// other maxima by a dip to less than 70% of the lower one.
+fn bands(s: &[f64]) -> Vec<usize> {
+    let mut peaks: Vec<usize> = Vec::new();
+    let mut min_since = f64::INFINITY;
+    for i in 1..NS - 1 {
+        min_since = min_since.min(s[i]);
+        if s[i] >= 0.04 && s[i] >= s[i - 1] && s[i] > s[i + 1] {
+            match peaks.last() {
+                Some(&p) if min_since > 0.7 * s[p].min(s[i]) => {
+                    if s[i] > s[p] {
+                        *peaks.last_mut().unwrap() = i;
+                    }
+                }
+                _ => peaks.push(i),
+            }
+            min_since = s[i];
+        }
+    }
+    peaks.into_iter().map(|i| i + 380).collect()
+}
+
+/// Smallest relative root mean square deviation of a normalized spectrum from a scaled Planckian
+/// radiator, with temperatures from 1000 to 5000 K, in 10 K steps.
+fn planck_fit_error(s: &[f64]) -> f64 {
+    (100..=500)
+        .map(|k| {
+            let t = k as f64 * 10.0;
+            let p: Vec<f64> = (0..NS).map(|i| planck((i + 380) as f64 * 1E-9, t)).collect();
+            let scale = s.iter().zip(&p).map(|(a, b)| a * b).sum::<f64>() / p.iter().map(|b| b * b).sum::<f64>();
+            (s.iter().zip(&p).map(|(a, b)| (a - scale * b).powi(2)).sum::<f64>() / NS as f64).sqrt()
+        })
+        .fold(f64::INFINITY, f64::min)
+}
+
+#[cfg(test)]
+mod source_type_test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_classify() {
+        assert_eq!(SourceType::classify(&Illuminant::planckian(3000.0)), SourceType::Incandescent);
+        assert_eq!(SourceType::classify(&Illuminant::d50()), SourceType::Daylight);
+        let led = *Illuminant::led(450.0, 20.0) + *Illuminant::led(570.0, 100.0) * 3.0;
+        assert_eq!(SourceType::classify(&led), SourceType::LedPhosphor);
+        let rgb = *Illuminant::led(460.0, 20.0) + *Illuminant::led(530.0, 30.0) + *Illuminant::led(625.0, 18.0);
+        assert_eq!(SourceType::classify(&rgb), SourceType::LedRgb);
+    }
+
+    #[test]
+    #[cfg(feature = "cie-illuminants")]
+    fn test_classify_std_illuminants() {
+        use strum::IntoEnumIterator;
+        for illuminant in StdIlluminant::iter() {
+            let source_type = SourceType::classify(illuminant.illuminant());
+            let want: &[SourceType] = match illuminant.info().category {
+                IlluminantCategory::Daylight => &[SourceType::Daylight],
+                IlluminantCategory::Incandescent => &[SourceType::Incandescent],
+                IlluminantCategory::Fluorescent => &[SourceType::Fluorescent],
+                IlluminantCategory::Led => &[SourceType::LedPhosphor, SourceType::LedRgb],
+            };
+            assert!(want.contains(&source_type), "{illuminant}: {source_type}");
+        }
+        assert_eq!(SourceType::classify(StdIlluminant::LED_RGB1.illuminant()), SourceType::LedRgb);
+    }
+}
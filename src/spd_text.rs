@@ -0,0 +1,315 @@
+/*!
+# Spectral Data Text Import
+
+A forgiving parser for spectral distributions pasted as text, for example copied from a
+spreadsheet, a data sheet, or a web page, into a web application, in which the layout of the data
+is not known in advance.
+
+[`SpdText::parse`] accepts:
+- columns separated by tabs, semicolons, commas, or spaces;
+- decimal commas, as used in many locales, if the columns are not separated by commas;
+- header lines, and other lines without spectral data, which are skipped;
+- a wavelength column, in nanometer, micrometer, or meter, followed by one, or more, value
+  columns, of which the first is used;
+- a leading row index column, as produced by some spreadsheet exports;
+- values only, which are taken to span the domain from 380 to 780 nanometer.
+
+Units of the values, given in a header line, such as mW/m²/nm, μW/cm²/nm, or %, are converted to
+the units of this library.
+All assumptions made are reported as [`ParseWarning`]s, so that applications can show them to
+their users.
+```
+use colorimetry::prelude::*;
+
+let text = "Wavelength (nm)\tReflectance (%)
+400\t10,5
+550\t40,0
+700\t80,0";
+let parsed = SpdText::parse(text).unwrap();
+approx::assert_abs_diff_eq!(parsed.spectrum()[550], 0.4, epsilon = 1E-12);
+assert!(parsed.warnings().contains(&ParseWarning::DecimalComma));
+```
+*/
+
+use std::fmt;
+
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::{compliance::compliance_mode, error::CmtError, spectral_data::SpectralUnit, spectrum::Spectrum};
+
+/// Assumptions made, and irregularities found, while parsing spectral data text.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParseWarning {
+    /// Lines before the data, which were skipped.
+    HeaderLines(usize),
+    /// A line after the start of the data, without the expected number of values, which was
+    /// skipped, by its line number, starting at 1.
+    IgnoredLine(usize),
+    /// Commas were taken as decimal separators.
+    DecimalComma,
+    /// The first column was taken as a row index, and not as wavelengths.
+    IndexColumn,
+    /// No wavelengths were found, and the values were taken to span this wavelength domain, in
+    /// nanometer, with equal intervals.
+    AssumedDomain { low: f64, high: f64 },
+    /// The wavelengths were taken to be in this unit, and converted to nanometer.
+    WavelengthUnit(&'static str),
+    /// The values were taken to be in this unit, as found in a header, and converted.
+    ValueUnit(SpectralUnit),
+    /// The number of additional value columns, which were not used.
+    ExtraColumns(usize),
+    /// The wavelengths were not in increasing order, and were sorted.
+    Unsorted,
+    /// The data covers only this wavelength range, in nanometer, and was extrapolated, with its
+    /// end values, to the domain from 380 to 780 nanometer; an error in the strict
+    /// [`ComplianceMode`](crate::compliance::ComplianceMode).
+    PartialCoverage { low: f64, high: f64 },
+}
+
+impl fmt::Display for ParseWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseWarning::HeaderLines(n) => write!(f, "skipped {n} header line(s)"),
+            ParseWarning::IgnoredLine(i) => write!(f, "ignored line {i}"),
+            ParseWarning::DecimalComma => write!(f, "commas taken as decimal separators"),
+            ParseWarning::IndexColumn => write!(f, "first column taken as a row index"),
+            ParseWarning::AssumedDomain { low, high } => write!(f, "no wavelengths found, assumed {low}-{high} nm"),
+            ParseWarning::WavelengthUnit(unit) => write!(f, "wavelengths taken to be in {unit}"),
+            ParseWarning::ValueUnit(unit) => write!(f, "values taken to be in {}", unit.symbol()),
+            ParseWarning::ExtraColumns(n) => write!(f, "{n} additional column(s) not used"),
+            ParseWarning::Unsorted => write!(f, "wavelengths sorted"),
+            ParseWarning::PartialCoverage { low, high } => write!(f, "data covers {low}-{high} nm only, extrapolated"),
+        }
+    }
+}
+
+/// Column separators, in order of preference.
+const SEPARATORS: [Option<char>; 4] = [Some('\t'), Some(';'), Some(','), None];
+
+/// A spectral distribution parsed from text, with the warnings about the assumptions made.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct SpdText {
+    spectrum: Spectrum,
+    warnings: Vec<ParseWarning>,
+}
+
+impl SpdText {
+    /// Parses spectral data text; fails if fewer than three data points are found.
+    pub fn parse(s: &str) -> Result<Self, CmtError> {
+        let lines: Vec<&str> = s.lines().collect();
+        let mut warnings = Vec::new();
+
+        // use the separator giving the most rows with two, or more, numbers
+        let sep = SEPARATORS
+            .into_iter()
+            .rev()
+            .max_by_key(|&sep| lines.iter().filter(|l| matches!(numbers(l, sep), Some((v, _)) if v.len() >= 2)).count())
+            .unwrap();
+        let rows: Vec<Option<(Vec<f64>, bool)>> = lines.iter().map(|l| numbers(l, sep)).collect();
+        let mut counts = std::collections::BTreeMap::new();
+        rows.iter().flatten().for_each(|(v, _)| *counts.entry(v.len()).or_insert(0usize) += 1);
+        let Some((&ncol, _)) = counts.iter().max_by_key(|&(&n, &c)| (c, n)) else {
+            return Err(CmtError::ParseError("SPD: no spectral data found".into()));
+        };
+
+        let mut data: Vec<Vec<f64>> = Vec::new();
+        let mut header = Vec::new();
+        let mut decimal_comma = false;
+        for (i, (line, row)) in lines.iter().zip(rows).enumerate() {
+            match row {
+                Some((v, comma)) if v.len() == ncol => {
+                    decimal_comma |= comma;
+                    data.push(v);
+                }
+                _ if data.is_empty() => header.push(line.to_lowercase()),
+                _ if !line.trim().is_empty() => warnings.push(ParseWarning::IgnoredLine(i + 1)),
+                _ => {}
+            }
+        }
+        if data.len() < 3 {
+            return Err(CmtError::ParseError("SPD: requires at least three data points".into()));
+        }
+        let n_header = header.iter().filter(|l| !l.trim().is_empty()).count();
+        if n_header > 0 {
+            warnings.insert(0, ParseWarning::HeaderLines(n_header));
+        }
+        if decimal_comma {
+            warnings.push(ParseWarning::DecimalComma);
+        }
+
+        // wavelength, and value, columns
+        let column = |j: usize| -> Vec<f64> { data.iter().map(|r| r[j]).collect() };
+        let first = column(0);
+        let index = ncol >= 2
+            && (first[0] == 0.0 || first[0] == 1.0)
+            && first.windows(2).all(|w| w[1] - w[0] == 1.0);
+        if index {
+            warnings.push(ParseWarning::IndexColumn);
+        }
+        let (wavelengths, values, used) = match (ncol, index) {
+            (1, _) => (None, first, 1),
+            (2, true) => (None, column(1), 2),
+            (_, true) => (Some(column(1)), column(2), 3),
+            (_, false) => (Some(first), column(1), 2),
+        };
+        if ncol > used {
+            warnings.push(ParseWarning::ExtraColumns(ncol - used));
+        }
+        let mut wavelengths = match wavelengths {
+            Some(wl) => {
+                let max = wl.iter().copied().fold(f64::MIN, f64::max);
+                if max < 1E-5 {
+                    warnings.push(ParseWarning::WavelengthUnit("m"));
+                    wl.iter().map(|w| round_nm(w * 1E9)).collect()
+                } else if max < 10.0 {
+                    warnings.push(ParseWarning::WavelengthUnit("µm"));
+                    wl.iter().map(|w| round_nm(w * 1E3)).collect()
+                } else {
+                    wl
+                }
+            }
+            None => {
+                warnings.push(ParseWarning::AssumedDomain { low: 380.0, high: 780.0 });
+                let step = 400.0 / (values.len() - 1) as f64;
+                (0..values.len()).map(|i| 380.0 + step * i as f64).collect()
+            }
+        };
+
+        let scale = match value_unit(&header) {
+            Some(unit) => {
+                warnings.push(ParseWarning::ValueUnit(unit));
+                unit.scale()
+            }
+            None => 1.0,
+        };
+        let mut points: Vec<(f64, f64)> = wavelengths.drain(..).zip(values.into_iter().map(|v| v * scale)).collect();
+        if points.windows(2).any(|w| w[1].0 <= w[0].0) {
+            warnings.push(ParseWarning::Unsorted);
+            points.sort_by(|a, b| a.0.total_cmp(&b.0));
+        }
+        let (low, high) = (points[0].0, points[points.len() - 1].0);
+        if low > 380.0 || high < 780.0 {
+            if compliance_mode().is_strict() {
+                return Err(CmtError::ParseError(format!("SPD: data covers {low}-{high} nm only")));
+            }
+            warnings.push(ParseWarning::PartialCoverage { low, high });
+        }
+        let (wl, v): (Vec<f64>, Vec<f64>) = points.into_iter().unzip();
+        Ok(Self { spectrum: Spectrum::try_linear_interpolate(&wl, &v)?, warnings })
+    }
+
+    pub fn spectrum(&self) -> &Spectrum {
+        &self.spectrum
+    }
+
+    pub fn into_spectrum(self) -> Spectrum {
+        self.spectrum
+    }
+
+    /// Assumptions made while parsing, in the order they were made.
+    pub fn warnings(&self) -> &[ParseWarning] {
+        &self.warnings
+    }
+}
+
+#[wasm_bindgen]
+impl SpdText {
+    /// Parses spectral data text, pasted in a web application.
+    #[wasm_bindgen(js_name=parse)]
+    pub fn parse_js(s: &str) -> Result<SpdText, CmtError> {
+        Self::parse(s)
+    }
+
+    #[wasm_bindgen(js_name=spectrum)]
+    pub fn spectrum_js(&self) -> Spectrum {
+        self.spectrum
+    }
+
+    /// The warnings, as messages.
+    #[wasm_bindgen(js_name=warnings)]
+    pub fn warnings_js(&self) -> Vec<String> {
+        self.warnings.iter().map(ToString::to_string).collect()
+    }
+}
+
+/// Numbers in a line, split by a separator, or by white space, if `None`, and if a decimal comma
+/// was used; `None` if the line contains other fields.
+fn numbers(line: &str, sep: Option<char>) -> Option<(Vec<f64>, bool)> {
+    let fields: Vec<&str> = match sep {
+        Some(c) => line.split(c).map(str::trim).filter(|f| !f.is_empty()).collect(),
+        None => line.split_whitespace().collect(),
+    };
+    if fields.is_empty() {
+        return None;
+    }
+    let mut comma = false;
+    let values = fields
+        .iter()
+        .map(|f| {
+            let f = f.trim_matches('"');
+            f.parse::<f64>().ok().or_else(|| {
+                if sep == Some(',') {
+                    return None;
+                }
+                comma = true;
+                f.replace(',', ".").parse().ok()
+            })
+        })
+        .collect::<Option<Vec<f64>>>()?;
+    values.iter().all(|v| v.is_finite()).then_some((values, comma))
+}
+
+/// Rounds a converted wavelength to a picometer, the precision of the interpolation.
+fn round_nm(wl: f64) -> f64 {
+    (wl * 1E3).round() / 1E3
+}
+
+/// Unit of the values, from the header lines, in lower case.
+fn value_unit(header: &[String]) -> Option<SpectralUnit> {
+    header.iter().find_map(|l| {
+        if l.contains("mw/m") {
+            Some(SpectralUnit::MilliWattPerSquareMeterNanometer)
+        } else if l.contains("uw/cm") || l.contains("µw/cm") || l.contains("μw/cm") {
+            Some(SpectralUnit::MicroWattPerSquareCentimeterNanometer)
+        } else if l.contains('%') {
+            Some(SpectralUnit::Percent)
+        } else {
+            None
+        }
+    })
+}
+
+#[cfg(test)]
+mod spd_text_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_spd_text() {
+        // comma separated, with an index column, and wavelengths in micrometer
+        let text = "#,wl,value,other\n1,0.38,1.0,5\n2,0.58,2.0,5\n3,0.78,3.0,5\n";
+        let p = SpdText::parse(text).unwrap();
+        assert_abs_diff_eq!(p.spectrum()[480], 1.5, epsilon = 1E-12);
+        for w in [ParseWarning::HeaderLines(1), ParseWarning::IndexColumn, ParseWarning::WavelengthUnit("µm"), ParseWarning::ExtraColumns(1)] {
+            assert!(p.warnings().contains(&w), "{w}");
+        }
+
+        // values only, with a trailing note
+        let p = SpdText::parse("0.0\n0.5\n1.0\nend of data").unwrap();
+        assert_abs_diff_eq!(p.spectrum()[580], 0.5, epsilon = 1E-12);
+        assert_eq!(p.warnings(), [ParseWarning::IgnoredLine(4), ParseWarning::AssumedDomain { low: 380.0, high: 780.0 }].as_ref());
+
+        let p = SpdText::parse("700 3\n500 2\n400 1\n").unwrap();
+        assert!(p.warnings().contains(&ParseWarning::Unsorted));
+        assert!(p.warnings().contains(&ParseWarning::PartialCoverage { low: 400.0, high: 700.0 }));
+        assert_abs_diff_eq!(p.spectrum()[450], 1.5, epsilon = 1E-12);
+
+        let p = SpdText::parse("λ;E [mW/m²/nm]\n380;1000\n580;2000\n780;3000").unwrap();
+        assert_abs_diff_eq!(p.spectrum()[780], 3.0, epsilon = 1E-12);
+        assert!(p.warnings().contains(&ParseWarning::ValueUnit(SpectralUnit::MilliWattPerSquareMeterNanometer)));
+
+        assert!(SpdText::parse("no data here").is_err());
+        assert!(SpdText::parse("380 1\n780 2").is_err());
+    }
+}
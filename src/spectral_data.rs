@@ -0,0 +1,286 @@
+/*!
+# Spectral Data
+
+A plain data representation of a spectral distribution, with its wavelength domain, its values,
+the kind of quantity it represents, and the unit of its values, for the exchange of spectral data
+with files, and other applications.
+
+Spectral distributions in this library always use a wavelength domain from 380 to 780 nanometer,
+with 1 nanometer steps, and fixed units: W/m<sup>2</sup>/nm for spectral irradiance, and values in
+the range from 0.0 to 1.0 for spectral reflectance and transmittance. Unit and quantity mismatches
+are a common source of errors when moving spectral data between formats: a [`SpectralData`]
+value carries this information along, and checks it when converted into an [`Illuminant`],
+[`Stimulus`], or [`Colorant`], or into a [`DomainSpectrum`], which keeps the values on their
+native wavelength domain.
+*/
+
+use crate::{
+    colorant::Colorant,
+    error::CmtError,
+    illuminant::Illuminant,
+    spectrum::{Spectrum, NS},
+    stimulus::Stimulus,
+    wavelength_domain::{DomainSpectrum, WavelengthDomain},
+};
+
+/// The physical quantity represented by a spectral distribution.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, strum_macros::Display, strum_macros::EnumIter)]
+pub enum QuantityKind {
+    /// Spectral irradiance of a light source, as used by [`Illuminant`].
+    Irradiance,
+    /// Spectral radiance of a stimulus, as used by [`Stimulus`].
+    Radiance,
+    /// Spectral radiant intensity of a point source.
+    RadiantIntensity,
+    /// Spectral reflectance, or reflectance factor, of a surface, as used by [`Colorant`].
+    Reflectance,
+    /// Spectral transmittance of a filter, as used by [`Colorant`].
+    Transmittance,
+    /// Relative spectral distribution of a light, in arbitrary units.
+    Relative,
+}
+
+impl QuantityKind {
+    /// Radiometric quantities, in contrast to the dimensionless reflectance and transmittance
+    /// ratios.
+    pub fn is_radiometric(&self) -> bool {
+        !matches!(self, QuantityKind::Reflectance | QuantityKind::Transmittance)
+    }
+}
+
+/// Units of spectral values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, strum_macros::EnumIter)]
+pub enum SpectralUnit {
+    /// W/m<sup>2</sup>/nm, for spectral irradiance.
+    WattPerSquareMeterNanometer,
+    /// mW/m<sup>2</sup>/nm, for spectral irradiance.
+    MilliWattPerSquareMeterNanometer,
+    /// μW/cm<sup>2</sup>/nm, for spectral irradiance.
+    MicroWattPerSquareCentimeterNanometer,
+    /// W/sr/m<sup>2</sup>/nm, for spectral radiance.
+    WattPerSteradianSquareMeterNanometer,
+    /// W/sr/nm, for spectral radiant intensity.
+    WattPerSteradianNanometer,
+    /// Ratios, in the range from 0.0 to 1.0, for reflectance and transmittance.
+    Fraction,
+    /// Percentages, in the range from 0.0 to 100.0, for reflectance and transmittance.
+    Percent,
+    /// Arbitrary units, for relative spectral distributions.
+    Arbitrary,
+}
+
+impl SpectralUnit {
+    /// Factor to convert values in this unit into the units used in this library.
+    pub fn scale(&self) -> f64 {
+        match self {
+            SpectralUnit::MilliWattPerSquareMeterNanometer => 1E-3,
+            SpectralUnit::MicroWattPerSquareCentimeterNanometer => 1E-2,
+            SpectralUnit::Percent => 1E-2,
+            _ => 1.0,
+        }
+    }
+
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            SpectralUnit::WattPerSquareMeterNanometer => "W/m²/nm",
+            SpectralUnit::MilliWattPerSquareMeterNanometer => "mW/m²/nm",
+            SpectralUnit::MicroWattPerSquareCentimeterNanometer => "μW/cm²/nm",
+            SpectralUnit::WattPerSteradianSquareMeterNanometer => "W/sr/m²/nm",
+            SpectralUnit::WattPerSteradianNanometer => "W/sr/nm",
+            SpectralUnit::Fraction => "1",
+            SpectralUnit::Percent => "%",
+            SpectralUnit::Arbitrary => "a.u.",
+        }
+    }
+
+    /// Checks if the unit can be used for a quantity.
+    pub fn is_unit_of(&self, kind: QuantityKind) -> bool {
+        use QuantityKind::*;
+        use SpectralUnit::*;
+        match self {
+            WattPerSquareMeterNanometer | MilliWattPerSquareMeterNanometer | MicroWattPerSquareCentimeterNanometer => kind == Irradiance,
+            WattPerSteradianSquareMeterNanometer => kind == Radiance,
+            WattPerSteradianNanometer => kind == RadiantIntensity,
+            Fraction | Percent => !kind.is_radiometric(),
+            Arbitrary => kind == Relative,
+        }
+    }
+}
+
+/**
+Spectral values over a regular wavelength domain, with their quantity kind and unit.
+
+The wavelength domain starts at `start`, in nanometer, with `step` nanometer intervals.
+```
+use colorimetry::prelude::*;
+use approx::assert_abs_diff_eq;
+
+// reflectance data, as percentages, from 400 to 700 nm in 100 nm steps
+let data = SpectralData::try_new(QuantityKind::Reflectance, SpectralUnit::Percent, 400.0, 100.0, vec![10.0, 20.0, 30.0, 40.0]).unwrap();
+let colorant = Colorant::try_from(&data).unwrap();
+assert_abs_diff_eq!(colorant[450], 0.15, epsilon = 1E-12);
+
+// but not as an illuminant
+assert!(Illuminant::try_from(&data).is_err());
+```
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub struct SpectralData {
+    pub kind: QuantityKind,
+    pub unit: SpectralUnit,
+    pub start: f64,
+    pub step: f64,
+    pub values: Vec<f64>,
+}
+
+impl SpectralData {
+
+    pub fn try_new(kind: QuantityKind, unit: SpectralUnit, start: f64, step: f64, values: Vec<f64>) -> Result<Self, CmtError> {
+        let data = Self { kind, unit, start, step, values };
+        data.check()?;
+        Ok(data)
+    }
+
+    /// Spectral data in the library's domain and units.
+    pub fn from_spectrum(spectrum: &Spectrum, kind: QuantityKind) -> Self {
+        let unit = match kind {
+            QuantityKind::Irradiance => SpectralUnit::WattPerSquareMeterNanometer,
+            QuantityKind::Radiance => SpectralUnit::WattPerSteradianSquareMeterNanometer,
+            QuantityKind::RadiantIntensity => SpectralUnit::WattPerSteradianNanometer,
+            QuantityKind::Reflectance | QuantityKind::Transmittance => SpectralUnit::Fraction,
+            QuantityKind::Relative => SpectralUnit::Arbitrary,
+        };
+        Self { kind, unit, start: 380.0, step: 1.0, values: AsRef::<[f64]>::as_ref(spectrum).to_vec() }
+    }
+
+    /// The wavelengths of the values, in nanometer.
+    pub fn wavelengths(&self) -> Vec<f64> {
+        (0..self.values.len()).map(|i| self.start + i as f64 * self.step).collect()
+    }
+
+    /// The last wavelength of the domain, in nanometer.
+    pub fn end(&self) -> f64 {
+        self.start + (self.values.len().max(1) - 1) as f64 * self.step
+    }
+
+    /// Converts the values to the library's units, and resamples them to its wavelength domain,
+    /// using linear interpolation.
+    pub fn to_spectrum(&self) -> Result<Spectrum, CmtError> {
+        self.check()?;
+        let scale = self.unit.scale();
+        let values: Vec<f64> = self.values.iter().map(|v| v * scale).collect();
+        if self.start == 380.0 && self.step == 1.0 && values.len() == NS {
+            Spectrum::try_from(values.as_slice())
+        } else {
+            Spectrum::try_linear_interpolate(&self.wavelengths(), &values)
+        }
+    }
+
+    /// Converts the values to the library's units, on their native wavelength domain, without
+    /// resampling.
+    pub fn to_domain_spectrum(&self) -> Result<DomainSpectrum, CmtError> {
+        self.check()?;
+        let domain = WavelengthDomain::try_new(self.start, self.end(), self.step)?;
+        let scale = self.unit.scale();
+        DomainSpectrum::try_new(domain, self.values.iter().map(|v| v * scale).collect())
+    }
+
+    fn check(&self) -> Result<(), CmtError> {
+        if !self.unit.is_unit_of(self.kind) {
+            return Err(CmtError::ErrorString(format!("unit {} can not be used for {}", self.unit.symbol(), self.kind)));
+        }
+        if self.step.is_nan() || self.step <= 0.0 {
+            return Err(CmtError::OutOfRange { name: "Spectral Data Step".into(), low: 0.0, high: f64::INFINITY });
+        }
+        if self.values.len() < 2 {
+            return Err(CmtError::ProvideAtLeastNValues(2));
+        }
+        Ok(())
+    }
+
+    fn require(&self, kinds: &[QuantityKind], target: &str) -> Result<Spectrum, CmtError> {
+        if kinds.contains(&self.kind) {
+            self.to_spectrum()
+        } else {
+            Err(CmtError::ErrorString(format!("{} data can not be used as {target}", self.kind)))
+        }
+    }
+}
+
+/// Spectral irradiance, or relative spectral distribution data, as an illuminant.
+impl TryFrom<&SpectralData> for Illuminant {
+    type Error = CmtError;
+
+    fn try_from(data: &SpectralData) -> Result<Self, Self::Error> {
+        Ok(Illuminant(data.require(&[QuantityKind::Irradiance, QuantityKind::Relative], "Illuminant")?))
+    }
+}
+
+/// Spectral radiance, or relative spectral distribution data, as a stimulus.
+impl TryFrom<&SpectralData> for Stimulus {
+    type Error = CmtError;
+
+    fn try_from(data: &SpectralData) -> Result<Self, Self::Error> {
+        Ok(Stimulus(data.require(&[QuantityKind::Radiance, QuantityKind::Relative], "Stimulus")?))
+    }
+}
+
+/// Spectral reflectance, or transmittance data, as a colorant, with its values checked to be
+/// within the range from 0.0 to 1.0.
+impl TryFrom<&SpectralData> for Colorant {
+    type Error = CmtError;
+
+    fn try_from(data: &SpectralData) -> Result<Self, Self::Error> {
+        let spectrum = data.require(&[QuantityKind::Reflectance, QuantityKind::Transmittance], "Colorant")?;
+        Colorant::try_from(spectrum.as_ref() as &[f64])
+    }
+}
+
+impl From<&Illuminant> for SpectralData {
+    fn from(illuminant: &Illuminant) -> Self {
+        SpectralData::from_spectrum(illuminant, QuantityKind::Irradiance)
+    }
+}
+
+impl From<&Stimulus> for SpectralData {
+    fn from(stimulus: &Stimulus) -> Self {
+        SpectralData::from_spectrum(stimulus, QuantityKind::Radiance)
+    }
+}
+
+impl From<&Colorant> for SpectralData {
+    fn from(colorant: &Colorant) -> Self {
+        SpectralData::from_spectrum(colorant, QuantityKind::Reflectance)
+    }
+}
+
+#[cfg(test)]
+mod spectral_data_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_roundtrip(){
+        let d65 = Illuminant::d65();
+        let data = SpectralData::from(&d65);
+        assert_eq!(data.end(), 780.0);
+        let d65_back = Illuminant::try_from(&data).unwrap();
+        assert_abs_diff_eq!(*d65_back, *d65, epsilon = 1E-12);
+        assert!(Colorant::try_from(&data).is_err());
+        assert!(Stimulus::try_from(&data).is_err());
+    }
+
+    #[test]
+    fn test_units(){
+        let data = SpectralData::try_new(QuantityKind::Irradiance, SpectralUnit::MicroWattPerSquareCentimeterNanometer, 380.0, 200.0, vec![100.0, 100.0, 100.0]).unwrap();
+        let illuminant = Illuminant::try_from(&data).unwrap();
+        assert_abs_diff_eq!(illuminant[555], 1.0, epsilon = 1E-12);
+        let native = data.to_domain_spectrum().unwrap();
+        assert_eq!((native.domain().step(), native.domain().end()), (200.0, 780.0));
+        assert_abs_diff_eq!(native.values()[1], 1.0, epsilon = 1E-12);
+        assert!(SpectralData::try_new(QuantityKind::Irradiance, SpectralUnit::Percent, 380.0, 1.0, vec![1.0, 2.0]).is_err());
+        assert!(SpectralData::try_new(QuantityKind::Reflectance, SpectralUnit::Fraction, 380.0, 0.0, vec![1.0, 2.0]).is_err());
+        let over = SpectralData::try_new(QuantityKind::Reflectance, SpectralUnit::Percent, 380.0, 400.0, vec![50.0, 120.0]).unwrap();
+        assert!(Colorant::try_from(&over).is_err());
+    }
+}
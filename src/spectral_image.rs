@@ -0,0 +1,195 @@
+/*!
+# Spectral Images
+
+A [`SpectralImage`] holds the spectral distributions of the pixels of a hyperspectral image, or
+"cube", with 401 values per pixel, covering the wavelength range from 380 to 780 nanometer, stored
+contiguously, and in row-major pixel order.
+
+Pixel values are interpreted as lights, such as the radiance spectra of a display or a scene, or,
+when an illuminant is given, as spectral reflectance factors of the objects in a scene.
+
+The spectral integrations for a whole image are done using a single set of weights, combining the
+observer's color matching functions with the illuminant, which are calculated only once.
+With the `gpu` feature, these integrations can also be done on a graphics processor, using
+[wgpu](https://wgpu.rs), with a fallback to the CPU implementation if no suitable adapter is found.
+The GPU calculations use single precision, and agree with the CPU results within a relative
+tolerance of about 1E-5.
+*/
+
+#[cfg(feature = "gpu")]
+mod gpu;
+
+use nalgebra::{SMatrix, Vector3};
+
+use crate::{
+    error::CmtError,
+    observer::ObserverData,
+    rgb::RGB,
+    rgbspace::RgbSpace,
+    spectrum::{Spectrum, NS},
+    traits::Light,
+    xyz::XYZ,
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SpectralImage {
+    width: usize,
+    height: usize,
+    data: Vec<f64>,
+}
+
+impl SpectralImage {
+    /**
+    Creates a spectral image from a buffer of `width * height * 401` spectral values, with the
+    values of each pixel stored contiguously.
+    ```
+    use colorimetry::prelude::*;
+
+    let image = SpectralImage::try_new(2, 1, vec![0.5; 2 * NS]).unwrap();
+    let xyz = image.xyz(&CIE1931, Some(&StdIlluminant::D65));
+    approx::assert_abs_diff_eq!(xyz[1].values()[1], 50.0, epsilon = 1E-9);
+    ```
+    */
+    pub fn try_new(width: usize, height: usize, data: Vec<f64>) -> Result<Self, CmtError> {
+        if data.len() != width * height * NS {
+            return Err(CmtError::ErrorString(format!(
+                "SpectralImage: expected {} spectral values for a {width}x{height} image, got {}",
+                width * height * NS,
+                data.len()
+            )));
+        }
+        Ok(Self { width, height, data })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The pixel spectra, in row-major order, referencing the image data.
+    pub fn pixels(&self) -> &[Spectrum] {
+        Spectrum::spectra_from_slice(&self.data).unwrap()
+    }
+
+    /// The spectrum of the pixel in column `x` and row `y`, or `None` if out of range.
+    pub fn pixel(&self, x: usize, y: usize) -> Option<&Spectrum> {
+        if x < self.width && y < self.height {
+            Some(&self.pixels()[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /**
+    Tristimulus values of all pixels, in row-major order.
+
+    Without an illuminant, the pixels are interpreted as lights, and the results are not
+    normalized, as for [`ObserverData::xyz_from_spectrum`].
+    With an illuminant, the pixels are interpreted as spectral reflectance factors, and the
+    results are normalized to an illuminant luminous value of 100, as for [`ObserverData::xyz`].
+    */
+    pub fn xyz(&self, observer: &ObserverData, illuminant: Option<&dyn Light>) -> Vec<XYZ> {
+        let (weights, xyzn) = weights(observer, illuminant);
+        self.pixels()
+            .iter()
+            .map(|s| to_xyz(weights * s.0, xyzn, observer))
+            .collect()
+    }
+
+    /// RGB values of all pixels, in row-major order, in the given color space, with the
+    /// tristimulus values calculated by [`SpectralImage::xyz`].
+    pub fn rgb(&self, observer: &ObserverData, illuminant: Option<&dyn Light>, space: RgbSpace) -> Vec<RGB> {
+        self.xyz(observer, illuminant).iter().map(|xyz| xyz.rgb(Some(space))).collect()
+    }
+
+    /**
+    Tristimulus values of all pixels, as [`SpectralImage::xyz`], but with the spectral integrations
+    done on a graphics processor.
+
+    Falls back to the CPU implementation if no GPU adapter is available.
+    */
+    #[cfg(feature = "gpu")]
+    pub fn xyz_gpu(&self, observer: &ObserverData, illuminant: Option<&dyn Light>) -> Vec<XYZ> {
+        let (weights, xyzn) = weights(observer, illuminant);
+        match gpu::integrate(&weights, &self.data) {
+            Some(values) => values.into_iter().map(|v| to_xyz(v, xyzn, observer)).collect(),
+            None => self.xyz(observer, illuminant),
+        }
+    }
+
+    /// RGB values of all pixels, as [`SpectralImage::rgb`], with the tristimulus values
+    /// calculated by [`SpectralImage::xyz_gpu`].
+    #[cfg(feature = "gpu")]
+    pub fn rgb_gpu(&self, observer: &ObserverData, illuminant: Option<&dyn Light>, space: RgbSpace) -> Vec<RGB> {
+        self.xyz_gpu(observer, illuminant).iter().map(|xyz| xyz.rgb(Some(space))).collect()
+    }
+}
+
+/// Spectral weights, as the observer's color matching functions, multiplied by the illuminant, and
+/// scaled to a luminous value of 100 for the illuminant, together with the illuminant's
+/// tristimulus values, if any.
+fn weights(observer: &ObserverData, illuminant: Option<&dyn Light>) -> (SMatrix<f64, 3, NS>, Option<Vector3<f64>>) {
+    let mut weights = observer.data * observer.lumconst;
+    if let Some(light) = illuminant {
+        let xyzn = light.xyzn(observer.tag, None);
+        let scale = 100.0 / xyzn.xyzn.y;
+        let spectrum = light.spectrum();
+        for (mut column, v) in weights.column_iter_mut().zip(spectrum.0.iter()) {
+            column *= v * scale;
+        }
+        (weights, Some(xyzn.xyzn * scale))
+    } else {
+        (weights, None)
+    }
+}
+
+fn to_xyz(xyz: Vector3<f64>, xyzn: Option<Vector3<f64>>, observer: &ObserverData) -> XYZ {
+    match xyzn {
+        Some(xyzn) => XYZ::from_vecs(xyzn, Some(xyz), observer.tag),
+        None => XYZ::from_vecs(xyz, None, observer.tag),
+    }
+}
+
+#[cfg(test)]
+mod spectral_image_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    fn image() -> SpectralImage {
+        let spectra = [Colorant::gray(0.2), Colorant::top_hat(550.0, 50.0), Colorant::gaussian(450.0, 30.0)];
+        let data = spectra.iter().flat_map(|c| AsRef::<[f64]>::as_ref(&c.0).to_vec()).collect();
+        SpectralImage::try_new(3, 1, data).unwrap()
+    }
+
+    #[test]
+    fn test_xyz() {
+        let image = image();
+        assert_eq!(image.pixel(2, 0).unwrap()[450], 1.0);
+        assert!(image.pixel(0, 1).is_none());
+        assert!(SpectralImage::try_new(2, 2, vec![0.0; NS]).is_err());
+
+        let xyz = image.xyz(&CIE1931, Some(&StdIlluminant::D65));
+        for (c, v) in [Colorant::gray(0.2), Colorant::top_hat(550.0, 50.0), Colorant::gaussian(450.0, 30.0)].iter().zip(xyz) {
+            let want = CIE1931.xyz(&StdIlluminant::D65, Some(c));
+            assert_abs_diff_eq!(v.values().as_ref(), want.values().as_ref(), epsilon = 1E-9);
+        }
+
+        let lights = image.xyz(&CIE1931, None);
+        let want = CIE1931.xyz_from_spectrum(&image.pixels()[1], None);
+        assert_abs_diff_eq!(lights[1].values().as_ref(), want.values().as_ref(), epsilon = 1E-9);
+    }
+
+    #[cfg(feature = "gpu")]
+    #[test]
+    fn test_xyz_gpu() {
+        let image = image();
+        let cpu = image.xyz(&CIE1931, Some(&StdIlluminant::D65));
+        let gpu = image.xyz_gpu(&CIE1931, Some(&StdIlluminant::D65));
+        for (c, g) in cpu.iter().zip(gpu) {
+            assert_abs_diff_eq!(c.values().as_ref(), g.values().as_ref(), epsilon = 1E-3);
+        }
+    }
+}
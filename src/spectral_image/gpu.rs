@@ -0,0 +1,178 @@
+/*!
+wgpu compute backend for the spectral integrations of a [`SpectralImage`](super::SpectralImage).
+
+The device, queue, and compute pipeline are created on first use, and shared by all later calls.
+Images which do not fit in a single storage buffer are processed in chunks of pixels.
+*/
+
+use std::sync::OnceLock;
+
+use nalgebra::{SMatrix, Vector3};
+use wgpu::util::DeviceExt;
+
+use crate::spectrum::NS;
+
+const WORKGROUP_SIZE: u32 = 64;
+const MAX_WORKGROUPS: u32 = 65535;
+
+const SHADER: &str = r#"
+struct Params {
+    n: u32,
+    stride: u32,
+}
+
+@group(0) @binding(0) var<storage, read> weights: array<f32>;
+@group(0) @binding(1) var<storage, read> pixels: array<f32>;
+@group(0) @binding(2) var<storage, read_write> xyz: array<f32>;
+@group(0) @binding(3) var<uniform> params: Params;
+
+@compute @workgroup_size(64)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    let i = id.x + id.y * params.stride;
+    if (i >= params.n) {
+        return;
+    }
+    var x = 0.0;
+    var y = 0.0;
+    var z = 0.0;
+    let base = i * 401u;
+    for (var j = 0u; j < 401u; j++) {
+        let v = pixels[base + j];
+        x += weights[j] * v;
+        y += weights[401u + j] * v;
+        z += weights[802u + j] * v;
+    }
+    xyz[3u * i] = x;
+    xyz[3u * i + 1u] = y;
+    xyz[3u * i + 2u] = z;
+}
+"#;
+
+struct Gpu {
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    pipeline: wgpu::ComputePipeline,
+    max_pixels: usize,
+}
+
+fn gpu() -> Option<&'static Gpu> {
+    static GPU: OnceLock<Option<Gpu>> = OnceLock::new();
+    GPU.get_or_init(|| pollster::block_on(init())).as_ref()
+}
+
+async fn init() -> Option<Gpu> {
+    let instance = wgpu::Instance::new(wgpu::InstanceDescriptor::default());
+    let adapter = instance.request_adapter(&wgpu::RequestAdapterOptions::default()).await?;
+    let limits = adapter.limits();
+    let (device, queue) = adapter
+        .request_device(
+            &wgpu::DeviceDescriptor {
+                label: Some("colorimetry"),
+                required_features: wgpu::Features::empty(),
+                required_limits: limits.clone(),
+                memory_hints: wgpu::MemoryHints::Performance,
+            },
+            None,
+        )
+        .await
+        .ok()?;
+    let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+        label: Some("spectral integration"),
+        source: wgpu::ShaderSource::Wgsl(SHADER.into()),
+    });
+    let pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+        label: Some("spectral integration"),
+        layout: None,
+        module: &module,
+        entry_point: "main",
+        compilation_options: Default::default(),
+        cache: None,
+    });
+    let max_bytes = (limits.max_storage_buffer_binding_size as u64).min(limits.max_buffer_size) as usize;
+    let max_pixels = (max_bytes / (NS * 4)).min((MAX_WORKGROUPS * WORKGROUP_SIZE) as usize * MAX_WORKGROUPS as usize);
+    Some(Gpu { device, queue, pipeline, max_pixels })
+}
+
+/// Integrates the pixel spectra in `data` with the weights, on the GPU, or returns `None` if no
+/// GPU is available.
+pub(super) fn integrate(weights: &SMatrix<f64, 3, NS>, data: &[f64]) -> Option<Vec<Vector3<f64>>> {
+    let gpu = gpu()?;
+    // row-major weights, one color matching function after the other
+    let w: Vec<f32> = weights.transpose().iter().map(|&v| v as f32).collect();
+    let weights_buffer = gpu.device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+        label: Some("weights"),
+        contents: bytemuck::cast_slice(&w),
+        usage: wgpu::BufferUsages::STORAGE,
+    });
+    let mut xyz = Vec::with_capacity(data.len() / NS);
+    for chunk in data.chunks(gpu.max_pixels * NS) {
+        xyz.extend(integrate_chunk(gpu, &weights_buffer, chunk)?);
+    }
+    Some(xyz)
+}
+
+fn integrate_chunk(gpu: &Gpu, weights: &wgpu::Buffer, chunk: &[f64]) -> Option<Vec<Vector3<f64>>> {
+    let n = (chunk.len() / NS) as u32;
+    let groups = n.div_ceil(WORKGROUP_SIZE);
+    let (gx, gy) = (groups.min(MAX_WORKGROUPS), groups.div_ceil(MAX_WORKGROUPS));
+    let params = [n, gx * WORKGROUP_SIZE];
+
+    let pixels: Vec<f32> = chunk.iter().map(|&v| v as f32).collect();
+    let pixels = gpu.device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+        label: Some("pixels"),
+        contents: bytemuck::cast_slice(&pixels),
+        usage: wgpu::BufferUsages::STORAGE,
+    });
+    let params = gpu.device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+        label: Some("params"),
+        contents: bytemuck::cast_slice(&params),
+        usage: wgpu::BufferUsages::UNIFORM,
+    });
+    let size = (n as u64) * 3 * 4;
+    let output = gpu.device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("xyz"),
+        size,
+        usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+        mapped_at_creation: false,
+    });
+    let staging = gpu.device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("staging"),
+        size,
+        usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+        mapped_at_creation: false,
+    });
+    let bind_group = gpu.device.create_bind_group(&wgpu::BindGroupDescriptor {
+        label: None,
+        layout: &gpu.pipeline.get_bind_group_layout(0),
+        entries: &[
+            wgpu::BindGroupEntry { binding: 0, resource: weights.as_entire_binding() },
+            wgpu::BindGroupEntry { binding: 1, resource: pixels.as_entire_binding() },
+            wgpu::BindGroupEntry { binding: 2, resource: output.as_entire_binding() },
+            wgpu::BindGroupEntry { binding: 3, resource: params.as_entire_binding() },
+        ],
+    });
+
+    let mut encoder = gpu.device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+    {
+        let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor::default());
+        pass.set_pipeline(&gpu.pipeline);
+        pass.set_bind_group(0, &bind_group, &[]);
+        pass.dispatch_workgroups(gx, gy, 1);
+    }
+    encoder.copy_buffer_to_buffer(&output, 0, &staging, 0, size);
+    gpu.queue.submit(Some(encoder.finish()));
+
+    let slice = staging.slice(..);
+    let (sender, receiver) = std::sync::mpsc::channel();
+    slice.map_async(wgpu::MapMode::Read, move |result| {
+        let _ = sender.send(result);
+    });
+    gpu.device.poll(wgpu::Maintain::Wait);
+    receiver.recv().ok()?.ok()?;
+    let values: Vec<Vector3<f64>> = bytemuck::cast_slice::<u8, f32>(&slice.get_mapped_range())
+        .chunks_exact(3)
+        .map(|v| Vector3::new(v[0] as f64, v[1] as f64, v[2] as f64))
+        .collect();
+    staging.unmap();
+    Some(values)
+}
@@ -17,11 +17,12 @@ use url::Url;
 
 use wasm_bindgen::prelude::*;
 
-use nalgebra::{DVector, SVector};
+use nalgebra::{DMatrix, DMatrixView, DVector, SVector};
 
 use crate::{
     data::illuminants::{D50, D65},
     data::observers::CIE1931,
+    math,
     observer::ObserverData,
     physics::{gaussian_peak_one, led_ohno, planck, stefan_boltzmann, sigma_from_fwhm, wavelength},
     error::CmtError,
@@ -49,6 +50,7 @@ display.
  */
 #[wasm_bindgen]
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(transparent)]
 pub struct Spectrum(pub(crate) SVector<f64, NS>);
 
 impl Spectrum {
@@ -84,7 +86,7 @@ impl Spectrum {
     use approx::assert_ulps_eq;
     let data = [0.0, 1.0];
     let wl = [380.0, 780.0];
-    let mut spd = Spectrum::linear_interpolate(&wl, &data).unwrap();
+    let mut spd = Spectrum::try_linear_interpolate(&wl, &data).unwrap();
     assert_ulps_eq!(spd[380], 0.);
     assert_ulps_eq!(spd[380+100], 0.25);
     assert_ulps_eq!(spd[380+200], 0.5);
@@ -95,7 +97,7 @@ impl Spectrum {
     // wavelength domain.
     let data = vec![0.0, 1.0, 1.0, 0.0];
     let wl = vec![480.0, 490.0, 570.0, 580.0];
-    let spd = Spectrum::linear_interpolate(&wl, &data).unwrap();
+    let spd = Spectrum::try_linear_interpolate(&wl, &data).unwrap();
     assert_ulps_eq!(spd[380+0], 0.0);
     assert_ulps_eq!(spd[380+100], 0.0);
     assert_ulps_eq!(spd[380+110], 1.0);
@@ -105,7 +107,7 @@ impl Spectrum {
     assert_ulps_eq!(spd[380+400], 0.0);
     ```
     */
-    pub fn linear_interpolate(wavelengths: &[f64], data: &[f64]) ->Result<Self, CmtError> {
+    pub fn try_linear_interpolate(wavelengths: &[f64], data: &[f64]) ->Result<Self, CmtError> {
         let data = match wavelengths.len() {
            2 =>  linterp(wavelengths.try_into().unwrap(), data)?,
            3.. => linterp_irr(wavelengths, data)?,
@@ -123,15 +125,396 @@ impl Spectrum {
     /// for the description of the method.
     /// This implementation uses end-point values for extrapolation, as recommended by CIE15:2004 7.2.2.1.
     
-    pub fn sprague_interpolate(wavelengths: [f64;2], data: &[f64]) ->Result<Self, CmtError> {
+    pub fn try_sprague_interpolate(wavelengths: [f64;2], data: &[f64]) ->Result<Self, CmtError> {
         let data = sprinterp(wavelengths.try_into().unwrap(), data)?;
         Ok(Self(SVector::<f64, 401>::from_array_storage(nalgebra::ArrayStorage([data]))))
     }
 
+    /**
+    Interpolation using a natural cubic spline, for data with an irregular, or a regular,
+    wavelength domain, given as in [`try_linear_interpolate`](Spectrum::try_linear_interpolate):
+    either the wavelengths of all the data points, or the first and last wavelength of equally
+    spaced data.
+
+    The spline is smooth, with continuous first and second derivatives, and passes through all
+    the data points; end-point values are used for extrapolation, as for the other interpolation
+    methods.
+    ```
+    use colorimetry::prelude::*;
+
+    let wl = [380.0, 420.0, 500.0, 640.0, 780.0];
+    let data = [0.1, 0.3, 0.8, 0.6, 0.2];
+    let s = Spectrum::try_spline_interpolate(&wl, &data).unwrap();
+    approx::assert_abs_diff_eq!(s[500], 0.8, epsilon = 1E-12);
+    ```
+    */
+    pub fn try_spline_interpolate(wavelengths: &[f64], data: &[f64]) -> Result<Self, CmtError> {
+        let data = splinterp(wavelengths, data)?;
+        Ok(Self(SVector::<f64, 401>::from_array_storage(nalgebra::ArrayStorage([data]))))
+    }
+
+    /**
+    Resamples spectral data onto the 380 to 780 nanometer domain of this library, using an
+    interpolation method, with its wavelength domain given as in
+    [`try_linear_interpolate`](Spectrum::try_linear_interpolate).
+
+    CIE 15:2018 recommends Sprague interpolation for equally spaced data, which is required by
+    [`Interpolation::Sprague`]; data given with the wavelengths of all its data points is accepted
+    when these are equally spaced.
+    ```
+    use colorimetry::prelude::*;
+
+    let wl: Vec<f64> = (0..=16).map(|i| 380.0 + 25.0 * i as f64).collect();
+    let data: Vec<f64> = wl.iter().map(|l| (l / 100.0).sin()).collect();
+    let sprague = Spectrum::try_resample(&wl, &data, Interpolation::Sprague).unwrap();
+    let linear = Spectrum::try_resample(&wl, &data, Interpolation::Linear).unwrap();
+    // Sprague interpolation follows the smooth curve more closely
+    let error = |s: &Spectrum| (400..=760).map(|l| (s[l] - (l as f64 / 100.0).sin()).abs()).fold(0.0, f64::max);
+    assert!(error(&sprague) < error(&linear));
+    ```
+    */
+    pub fn try_resample(wavelengths: &[f64], data: &[f64], method: Interpolation) -> Result<Self, CmtError> {
+        match method {
+            Interpolation::Linear => Self::try_linear_interpolate(wavelengths, data),
+            Interpolation::Spline => Self::try_spline_interpolate(wavelengths, data),
+            Interpolation::Sprague => match wavelengths.len() {
+                2 => Self::try_sprague_interpolate([wavelengths[0], wavelengths[1]], data),
+                n if n == data.len() && n > 2 => {
+                    let step = (wavelengths[n - 1] - wavelengths[0]) / (n - 1) as f64;
+                    let regular = wavelengths.iter().enumerate().all(|(i, &l)| (l - wavelengths[0] - step * i as f64).abs() < 1E-6 * step.abs());
+                    if !regular {
+                        return Err(CmtError::ErrorString("Spectrum: Sprague interpolation requires equally spaced data".into()));
+                    }
+                    Self::try_sprague_interpolate([wavelengths[0], wavelengths[n - 1]], data)
+                }
+                _ => Err(CmtError::InterpolateWavelengthError),
+            },
+        }
+    }
+
+    /**
+    Fraction of the power in spectral data, which is lost when it is interpolated onto the 380 to
+    780 nanometer domain of the spectra in this library.
+
+    The CIE publishes observer and illuminant data from 360 to 830 nanometer, but this library
+    uses a truncated domain. For most sources the power outside this domain is negligible, but
+    sources with ultraviolet, or violet, pump LEDs can have a significant part of their power
+    below 380 nanometer; use this to check, and report, the truncation of measured data.
+    This only reports the loss: the domain of this library is not extended to 360 to 830
+    nanometer, as its observer, and sample, tables are only bundled for 380 to 780 nanometer; use
+    a [`DomainSpectrum`](crate::wavelength_domain::DomainSpectrum) to keep the measured data
+    outside it.
+    The wavelengths are given as in [`try_linear_interpolate`](Spectrum::try_linear_interpolate),
+    and the power is integrated using the trapezoidal rule.
+    ```
+    use colorimetry::prelude::*;
+
+    // a flat spectrum from 360 to 830 nm
+    let lost = Spectrum::truncated_power_fraction(&[360.0, 830.0], &[1.0, 1.0]).unwrap();
+    approx::assert_abs_diff_eq!(lost, 70.0 / 470.0, epsilon = 1E-12);
+    ```
+    */
+    pub fn truncated_power_fraction(wavelengths: &[f64], data: &[f64]) -> Result<f64, CmtError> {
+        let wl: Vec<f64> = match (wavelengths.len(), data.len()) {
+            (2, n) if n >= 2 => {
+                let [a, b] = [wavelength(wavelengths[0]), wavelength(wavelengths[1])];
+                (0..n).map(|i| a + (b - a) * i as f64 / (n - 1) as f64).collect()
+            }
+            (m, n) if m == n && m >= 2 => wavelengths.iter().map(|&l| wavelength(l)).collect(),
+            _ => return Err(CmtError::InterpolateWavelengthError),
+        };
+        let (mut total, mut inside) = (0.0, 0.0);
+        for i in 1..wl.len() {
+            let (l0, l1, v0, v1) = (wl[i - 1], wl[i], data[i - 1], data[i]);
+            total += (l1 - l0) * (v0 + v1) / 2.0;
+            // part of the interval inside the domain, with interpolated values at its ends
+            let (a, b) = (l0.max(380E-9), l1.min(780E-9));
+            if b > a {
+                let value = |l: f64| v0 + (v1 - v0) * (l - l0) / (l1 - l0);
+                inside += (b - a) * (value(a) + value(b)) / 2.0;
+            }
+        }
+        if total > 0.0 {
+            Ok(((total - inside) / total).clamp(0.0, 1.0))
+        } else {
+            Err(CmtError::ErrorString("Spectrum: spectral data without power".into()))
+        }
+    }
+
+    #[deprecated(since = "0.0.3", note = "use `Spectrum::try_linear_interpolate`")]
+    pub fn linear_interpolate(wavelengths: &[f64], data: &[f64]) -> Result<Self, CmtError> {
+        Self::try_linear_interpolate(wavelengths, data)
+    }
+
+    #[deprecated(since = "0.0.3", note = "use `Spectrum::try_sprague_interpolate`")]
+    pub fn sprague_interpolate(wavelengths: [f64; 2], data: &[f64]) -> Result<Self, CmtError> {
+        Self::try_sprague_interpolate(wavelengths, data)
+    }
+
+    /**
+    A spectrum reference to an array of 401 spectral values, without copying the data.
+
+    Use this for data owned by other code, such as a buffer received through a foreign function
+    interface, to use it in this library's calculations directly.
+    ```
+    use colorimetry::prelude::*;
+
+    let data = [1.0; NS];
+    let s: &Spectrum = Spectrum::from_slice_ref(&data);
+    let xyz = CIE1931.xyz_from_spectrum(s, None);
+    approx::assert_abs_diff_eq!(xyz.xy().values().as_ref(), [0.3333, 0.3333].as_ref(), epsilon = 1E-4);
+    ```
+    */
+    pub fn from_slice_ref(data: &[f64; NS]) -> &Self {
+        // SAFETY: `Spectrum` is a transparent wrapper of an `SVector<f64, NS>`, which is a
+        // `repr(C)` matrix with a transparent `ArrayStorage<f64, NS, 1>`, and a zero-sized marker
+        // field, and has the same layout as `[f64; NS]`.
+        unsafe { &*(data as *const [f64; NS] as *const Self) }
+    }
+
+    /// A mutable spectrum reference to an array of 401 spectral values, without copying the data.
+    ///
+    /// Changes made through the spectrum, such as smoothing, are written into the array.
+    pub fn from_slice_mut(data: &mut [f64; NS]) -> &mut Self {
+        // SAFETY: same layout as `[f64; NS]`, see `from_slice_ref`.
+        unsafe { &mut *(data as *mut [f64; NS] as *mut Self) }
+    }
+
+    /// A spectrum reference to a slice of spectral values, without copying the data.
+    ///
+    /// Returns an error if the slice does not contain exactly 401 values.
+    pub fn try_from_slice_ref(data: &[f64]) -> Result<&Self, CmtError> {
+        let data: &[f64; NS] = data.try_into().map_err(|_| CmtError::DataSize401Error)?;
+        Ok(Self::from_slice_ref(data))
+    }
+
+    /**
+    A slice of spectra, referencing a buffer of consecutive spectral distributions, each with 401
+    values, without copying the data.
+
+    This is typically used for hyperspectral image data, with the spectral values of a pixel
+    stored contiguously. Returns an error if the length of the buffer is not a multiple of 401.
+    ```
+    use colorimetry::prelude::*;
+
+    let cube = vec![0.5; 4 * NS]; // four pixels
+    let pixels = Spectrum::spectra_from_slice(&cube).unwrap();
+    assert_eq!(pixels.len(), 4);
+    assert_eq!(pixels[3][550], 0.5);
+    ```
+    */
+    pub fn spectra_from_slice(data: &[f64]) -> Result<&[Self], CmtError> {
+        if data.len() % NS != 0 {
+            return Err(CmtError::DataSize401Error);
+        }
+        // SAFETY: each `Spectrum` has the layout of `[f64; NS]`, see `from_slice_ref`, and the
+        // buffer holds exactly `data.len() / NS` of these arrays.
+        Ok(unsafe { std::slice::from_raw_parts(data.as_ptr() as *const Self, data.len() / NS) })
+    }
+
+    /// The spectral values of a slice of spectra, as a single slice, without copying the data.
+    pub fn spectra_as_slice(spectra: &[Self]) -> &[f64] {
+        // SAFETY: each `Spectrum` has the layout of `[f64; NS]`, see `from_slice_ref`.
+        unsafe { std::slice::from_raw_parts(spectra.as_ptr() as *const f64, spectra.len() * NS) }
+    }
+
+    /**
+    A matrix view of a slice of spectra, with 401 rows, one for each wavelength, and a column for
+    each spectrum, without copying the data.
+
+    Use this for matrix level calculations, such as the mean spectrum of a set of measurements, or
+    a principal component analysis.
+    Use `into_owned` to get a [`DMatrix`] copy of the data.
+    ```
+    use colorimetry::prelude::*;
+
+    let spectra = [*Colorant::gray(0.2), *Colorant::gray(0.4)];
+    let m = Spectrum::spectra_as_matrix(&spectra);
+    assert_eq!(m.shape(), (NS, 2));
+    approx::assert_abs_diff_eq!(m.column_mean()[200], 0.3);
+    ```
+    */
+    pub fn spectra_as_matrix(spectra: &[Self]) -> DMatrixView<'_, f64> {
+        DMatrixView::from_slice(Self::spectra_as_slice(spectra), NS, spectra.len())
+    }
+
+    /// Spectra from the columns of a matrix with 401 rows, such as produced by
+    /// [`Spectrum::spectra_as_matrix`].
+    pub fn spectra_from_matrix(matrix: &DMatrix<f64>) -> Result<Vec<Self>, CmtError> {
+        if matrix.nrows() != NS {
+            return Err(CmtError::DataSize401Error);
+        }
+        Ok(Self::spectra_from_slice(matrix.as_slice())?.to_vec())
+    }
+
+    /// An [`ndarray`] view of a slice of spectra, with 401 rows, one for each wavelength, and a
+    /// column for each spectrum, without copying the data.
+    #[cfg(feature = "ndarray")]
+    pub fn spectra_as_array2(spectra: &[Self]) -> ndarray::ArrayView2<'_, f64> {
+        use ndarray::ShapeBuilder;
+        ndarray::ArrayView2::from_shape((NS, spectra.len()).f(), Self::spectra_as_slice(spectra)).unwrap()
+    }
+
+    /// Spectra from the columns of an [`ndarray`] array with 401 rows, such as produced by
+    /// [`Spectrum::spectra_as_array2`].
+    #[cfg(feature = "ndarray")]
+    pub fn spectra_from_array2(array: ndarray::ArrayView2<'_, f64>) -> Result<Vec<Self>, CmtError> {
+        if array.nrows() != NS {
+            return Err(CmtError::DataSize401Error);
+        }
+        Ok(array.columns().into_iter().map(|c| Self(SVector::from_iterator(c.iter().copied()))).collect())
+    }
+
     pub fn clamp(&mut self, min: f64, max: f64) {
         self.0.iter_mut().for_each(|v|*v = v.clamp(min, max));
     }
 
+    /**
+    Removes negative values from a spectrum, according to a [`NegativeValuePolicy`].
+
+    Reconstructed spectra, and linear combinations of primaries with negative weights, can have
+    negative spectral values, which are physically impossible for lights and colorants. For the
+    `Renormalize` policy the spectrum is scaled, after clipping, to keep its luminous value for
+    the given observer. Negative values smaller than 1E-12 in magnitude are treated as zero, and
+    never result in an error.
+    ```
+    use colorimetry::prelude::*;
+
+    let s = Spectrum::try_linear_interpolate(&[380.0, 780.0], &[-1.0, 1.0]).unwrap();
+    assert!(s.clone().apply_negative_policy(NegativeValuePolicy::Error, &CIE1931).is_err());
+    let clipped = s.apply_negative_policy(NegativeValuePolicy::Clip, &CIE1931).unwrap();
+    assert_eq!(clipped[380], 0.0);
+    ```
+    */
+    pub fn apply_negative_policy(mut self, policy: NegativeValuePolicy, observer: &ObserverData) -> Result<Self, CmtError> {
+        const TOLERANCE: f64 = 1E-12;
+        let min = self.0.min();
+        if min >= 0.0 {
+            return Ok(self);
+        }
+        match policy {
+            NegativeValuePolicy::Error if min < -TOLERANCE => Err(CmtError::OutOfRange { name: "Spectral Value".into(), low: 0.0, high: f64::INFINITY }),
+            NegativeValuePolicy::Renormalize => {
+                let y = (observer.data.row(1) * self.0).x;
+                self.clamp(0.0, f64::INFINITY);
+                let y_clipped = (observer.data.row(1) * self.0).x;
+                if y_clipped > 0.0 && y > 0.0 {
+                    self.0 *= y / y_clipped;
+                }
+                Ok(self)
+            }
+            _ => {
+                self.clamp(0.0, f64::INFINITY);
+                Ok(self)
+            }
+        }
+    }
+
+
+    /**
+    Spectrum with its spectral features moved by `delta` nanometer, to longer wavelengths for
+    positive values, using linear interpolation.
+
+    Use this to correct a constant wavelength offset of an instrument: if the instrument reports
+    features at wavelengths which are `d` nanometer too long, use `shift_wavelength(-d)`.
+    Values beyond the wavelength domain are extrapolated using end-point values.
+    ```
+    use colorimetry::prelude::*;
+
+    let line = Colorant::gaussian(550.0, 10.0);
+    let shifted = line.shift_wavelength(2.5);
+    approx::assert_abs_diff_eq!(shifted[552], shifted[553], epsilon = 1E-12);
+    ```
+    */
+    pub fn shift_wavelength(self, delta: f64) -> Self {
+        self.resample(|l| l - delta)
+    }
+
+    /**
+    Spectrum corrected for a wavelength scale error, described by a polynomial, and re-interpolated
+    onto the standard 1 nanometer wavelength grid.
+
+    The instrument's reported wavelengths λ, in nanometer, relate to the true wavelengths as
+    λ + p(λ), with p(λ) = c₀ + c₁λ + c₂λ² + ..., and the coefficients given in this order.
+    A single coefficient corrects a constant offset, and is equal to
+    [`shift_wavelength`](Spectrum::shift_wavelength).
+    ```
+    use colorimetry::prelude::*;
+
+    // reported wavelengths are 0.2% too short
+    let measured = Colorant::gaussian(549.0, 10.0);
+    let corrected = measured.correct_wavelength_scale(&[0.0, 0.002]);
+    approx::assert_abs_diff_eq!(corrected[550], 1.0, epsilon = 1E-3);
+    ```
+    */
+    pub fn correct_wavelength_scale(self, coefficients: &[f64]) -> Self {
+        let p = |l: f64| coefficients.iter().rev().fold(0.0, |acc, c| acc * l + c);
+        // find the reported wavelength, for each true wavelength, by fixed point iteration
+        self.resample(|l| (0..8).fold(l, |lr, _| l - p(lr)))
+    }
+
+    /// Resamples this spectrum, with the wavelengths of the new values mapped to wavelengths in
+    /// this spectrum, in nanometer, using linear interpolation, and end-point values for
+    /// extrapolation.
+    fn resample(&self, source: impl Fn(f64) -> f64) -> Self {
+        let v = &self.0;
+        Self(SVector::<f64, NS>::from_fn(|i, _| {
+            let x = (source((i + 380) as f64) - 380.0).clamp(0.0, (NS - 1) as f64);
+            let j = (x.floor() as usize).min(NS - 2);
+            let f = x - j as f64;
+            v[j] * (1.0 - f) + v[j + 1] * f
+        }))
+    }
+
+    /**
+    Spectral derivative, per nanometer, using central differences, and one-sided differences at
+    the ends of the wavelength domain.
+
+    Use it to locate features such as absorption edges, and the cut-on wavelengths of filters,
+    where the derivative has its extremes.
+    ```
+    use colorimetry::prelude::*;
+
+    // a long pass filter, with its edge at 500 nm
+    let filter = Colorant::top_hat(640.0, 280.0);
+    let d = filter.derivative();
+    assert_eq!(d[500], 0.5);
+    assert_eq!(d[450], 0.0);
+    ```
+    */
+    pub fn derivative(&self) -> Self {
+        let v = &self.0;
+        Self(SVector::<f64, NS>::from_fn(|i, _| match i {
+            0 => v[1] - v[0],
+            i if i == NS - 1 => v[i] - v[i - 1],
+            i => (v[i + 1] - v[i - 1]) / 2.0,
+        }))
+    }
+
+    /**
+    Cumulative sum of the spectral values, from 380 nanometer up to, and including, each
+    wavelength, with the value at 780 nanometer equal to the total, as used for
+    [`Illuminant::irradiance`](crate::illuminant::Illuminant::irradiance).
+
+    The power in a wavelength band is the difference of the cumulative values at its ends.
+    ```
+    use colorimetry::prelude::*;
+
+    // fraction of the power of D65 in the 440-490 nm blue band
+    let d65 = Illuminant::d65();
+    let c = d65.cumulative();
+    let blue = (c[490] - c[439]) / c[780];
+    approx::assert_abs_diff_eq!(c[780], d65.irradiance(), epsilon = 1E-9);
+    assert!(blue > 0.1 && blue < 0.2);
+    ```
+    */
+    pub fn cumulative(&self) -> Self {
+        let mut sum = 0.0;
+        Self(self.0.map(|v| {
+            sum += v;
+            sum
+        }))
+    }
 
     /**
     Smooth a Spectrum by convolution with a Gaussian function
@@ -159,7 +542,101 @@ impl Spectrum {
         self.0 = SVector::from_iterator(t.iter().copied().skip(sd3 as usize).take(NS));
     }
 
+    /**
+    Goodness-of-fit coefficient (GFC) of this spectrum, and another, such as a reconstructed, or
+    upsampled, spectrum and a measured one, as the normalized inner product of the two spectra.
+
+    A value of 1.0 indicates spectra of identical shape; values above 0.999 are regarded as a
+    colorimetrically accurate fit, and values above 0.9999 as an almost exact fit.
+    It is insensitive to the scale of the spectra, and is NaN if one of them is zero.
+    ```
+    use colorimetry::prelude::*;
+
+    let d65 = Illuminant::d65();
+    let scaled = *d65 * 0.5;
+    approx::assert_abs_diff_eq!(d65.goodness_of_fit(&scaled), 1.0, epsilon = 1E-12);
+    assert!(d65.goodness_of_fit(&Illuminant::planckian(2856.0)) < 0.95);
+    ```
+    */
+    pub fn goodness_of_fit(&self, other: &Self) -> f64 {
+        self.0.dot(&other.0).abs() / (self.0.norm() * other.0.norm())
+    }
+
+    /// Root mean square difference of the values of this spectrum, and another, over all
+    /// wavelengths.
+    pub fn rmse(&self, other: &Self) -> f64 {
+        (self.0 - other.0).norm() / (NS as f64).sqrt()
+    }
 
+    /**
+    Spectral angle, in radians, between this spectrum and another, regarded as vectors, as used by
+    the spectral angle mapper (SAM) to compare spectral shapes, independent of their scale.
+
+    It is zero for spectra of identical shape, and π/2 for spectra without overlap.
+    ```
+    use colorimetry::prelude::*;
+
+    let blue = Colorant::gaussian(450.0, 10.0);
+    let red = Colorant::gaussian(650.0, 10.0);
+    approx::assert_abs_diff_eq!(blue.spectral_angle(&red), std::f64::consts::FRAC_PI_2, epsilon = 1E-9);
+    ```
+    */
+    pub fn spectral_angle(&self, other: &Self) -> f64 {
+        math::acos((self.0.dot(&other.0) / (self.0.norm() * other.0.norm())).clamp(-1.0, 1.0))
+    }
+
+
+    /**
+    Spectrum from comma separated values, with wavelengths, in nanometer, in the first column, and
+    spectral values in the second, as exported by most spectroradiometers.
+
+    An optional header line, and empty, and `#` comment lines, are skipped, and any further
+    columns are ignored.
+    The wavelengths must be increasing, but can have any range, and step size: the data is
+    linearly interpolated onto the 380 to 780 nanometer domain, with its end values used outside
+    the range of the data.
+    ```
+    use colorimetry::prelude::*;
+
+    let csv = "wavelength,value\n370,0.1\n580,0.5\n790,0.9\n";
+    let s = Spectrum::from_csv(csv).unwrap();
+    approx::assert_abs_diff_eq!(s[580], 0.5, epsilon = 1E-12);
+    let back = Spectrum::from_csv(&s.to_csv()).unwrap();
+    approx::assert_abs_diff_eq!(back, s);
+    ```
+    */
+    pub fn from_csv(csv: &str) -> Result<Self, CmtError> {
+        let (mut wavelengths, mut values) = (Vec::new(), Vec::new());
+        let lines = csv.lines().map(str::trim).enumerate().filter(|(_, l)| !l.is_empty() && !l.starts_with('#'));
+        for (n, (i, line)) in lines.enumerate() {
+            let mut fields = line.split(',').map(|f| f.trim().trim_matches('"').parse::<f64>());
+            match (fields.next(), fields.next()) {
+                (Some(Ok(l)), Some(Ok(v))) => {
+                    if wavelengths.last().is_some_and(|&p| l <= p) {
+                        return Err(CmtError::ParseError(format!("CSV: wavelengths not increasing on line {}", i + 1)));
+                    }
+                    wavelengths.push(l);
+                    values.push(v);
+                }
+                _ if n == 0 => {} // header
+                _ => return Err(CmtError::ParseError(format!("CSV: expected a wavelength and a value on line {}", i + 1))),
+            }
+        }
+        if wavelengths.len() < 2 {
+            return Err(CmtError::ProvideAtLeastNValues(2));
+        }
+        Self::try_linear_interpolate(&wavelengths, &values)
+    }
+
+    /// Comma separated values, with a `wavelength,value` header line, and a line for each
+    /// wavelength from 380 to 780 nanometer.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("wavelength,value\n");
+        for (i, v) in self.0.iter().enumerate() {
+            csv.push_str(&format!("{},{}\n", i + 380, v));
+        }
+        csv
+    }
 
     /// Downloads a spectrum
     pub async fn fetch(loc: &str) -> Result<Self, Box<dyn Error>> {
@@ -172,6 +649,32 @@ impl Spectrum {
 
 }
 
+/// Interpolation methods, to resample spectral data onto the library's wavelength domain, as used
+/// by [`Spectrum::try_resample`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, strum_macros::Display, strum_macros::EnumIter)]
+pub enum Interpolation {
+    /// Linear interpolation, for any wavelength domain.
+    #[default]
+    Linear,
+    /// Sprague interpolation, for equally spaced data, as recommended by CIE 15:2018.
+    Sprague,
+    /// Natural cubic spline interpolation, for any wavelength domain.
+    Spline,
+}
+
+/// Handling of negative values in calculated spectra, as used by
+/// [`Spectrum::apply_negative_policy`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, strum_macros::Display, strum_macros::EnumIter)]
+pub enum NegativeValuePolicy {
+    /// Sets negative values to zero.
+    #[default]
+    Clip,
+    /// Sets negative values to zero, and rescales the spectrum to keep its luminous value.
+    Renormalize,
+    /// Returns an error for spectra with negative values.
+    Error,
+}
+
 impl TryFrom<&[f64]> for Spectrum {
     type Error = CmtError;
 
@@ -298,7 +801,7 @@ impl Spectrum {
     */
     #[wasm_bindgen(js_name=linearInterpolate)]
     pub fn linear_interpolate_js(wavelengths: &[f64], data: &[f64], total_js: &JsValue) -> Result<Spectrum, CmtError> {
-        Self::linear_interpolate(wavelengths, data)
+        Self::try_linear_interpolate(wavelengths, data)
 
     }
 
@@ -544,6 +1047,55 @@ fn linterp_irr(wl: &[f64], data: &[f64]) -> Result<[f64;NS], CmtError> {
 }
 
 /// Sprague interpolation over a dataset over an equidistant wavelength domain
+/**
+Spectrum constructed by natural cubic spline interpolation, with the second derivatives at the
+data points found by solving a tridiagonal system, using the Thomas algorithm.
+ */
+fn splinterp(wl: &[f64], data: &[f64]) -> Result<[f64;NS], CmtError> {
+    let n = data.len();
+    // wavelengths in nanometer
+    let x: Vec<f64> = match wl.len() {
+        2 if n >= 2 => {
+            let [a, b] = wavelengths([wl[0], wl[1]]).map(|l| l * 1E9);
+            (0..n).map(|i| a + (b - a) * i as f64 / (n - 1) as f64).collect()
+        }
+        m if m == n && m >= 2 => wl.iter().map(|&l| wavelength(l) * 1E9).collect(),
+        _ => return Err(CmtError::InterpolateWavelengthError),
+    };
+    if x.windows(2).any(|w| w[1] <= w[0]) {
+        return Err(CmtError::InterpolateWavelengthError);
+    }
+    let h: Vec<f64> = x.windows(2).map(|w| w[1] - w[0]).collect();
+    // second derivatives, zero at the ends
+    let mut m = vec![0.0; n];
+    if n > 2 {
+        let (mut c, mut d) = (vec![0.0; n], vec![0.0; n]);
+        for i in 1..n - 1 {
+            let r = 6.0 * ((data[i + 1] - data[i]) / h[i] - (data[i] - data[i - 1]) / h[i - 1]);
+            let b = 2.0 * (h[i - 1] + h[i]) - h[i - 1] * c[i - 1];
+            c[i] = h[i] / b;
+            d[i] = (r - h[i - 1] * d[i - 1]) / b;
+        }
+        for i in (1..n - 1).rev() {
+            m[i] = d[i] - c[i] * m[i + 1];
+        }
+    }
+    let mut spd = [0f64; NS];
+    spd.iter_mut().enumerate().for_each(|(k, v)| {
+        let l = (k + 380) as f64;
+        *v = if l <= x[0] {
+            data[0]
+        } else if l >= x[n - 1] {
+            data[n - 1]
+        } else {
+            let i = x.partition_point(|&xi| xi <= l) - 1;
+            let (a, b) = ((x[i + 1] - l) / h[i], (l - x[i]) / h[i]);
+            a * data[i] + b * data[i + 1] + ((a * a * a - a) * m[i] + (b * b * b - b) * m[i + 1]) * h[i] * h[i] / 6.0
+        };
+    });
+    Ok(spd)
+}
+
 fn sprinterp(mut wl: [f64;2], data: &[f64]) -> Result<[f64;NS], CmtError> {
     let imax = data.len()-1;
     if imax <6 { return Err(CmtError::ProvideAtLeastNValues(imax))};
@@ -591,12 +1143,110 @@ mod tests {
     use approx::assert_ulps_eq;
     use std::f64::consts::PI;
 
+    #[test]
+    fn test_spline() {
+        // a spline reproduces linear data, on an irregular domain
+        let wl = [380.0, 400.0, 450.0, 600.0, 780.0];
+        let data = wl.map(|l| 2.0 - l / 400.0);
+        let s = Spectrum::try_spline_interpolate(&wl, &data).unwrap();
+        assert_ulps_eq!(s[555], 2.0 - 555.0 / 400.0, epsilon = 1E-12);
+
+        // and follows a smooth curve more closely than linear interpolation
+        let wl: Vec<f64> = (0..=8).map(|i| 380.0 + 50.0 * i as f64).collect();
+        let data: Vec<f64> = wl.iter().map(|l| (l / 80.0).sin()).collect();
+        let spline = Spectrum::try_resample(&wl, &data, Interpolation::Spline).unwrap();
+        let linear = Spectrum::try_resample(&wl, &data, Interpolation::Linear).unwrap();
+        let error = |s: &Spectrum| (380..=780).map(|l| (s[l] - (l as f64 / 80.0).sin()).abs()).fold(0.0, f64::max);
+        assert!(error(&spline) < 0.5 * error(&linear));
+
+        assert!(Spectrum::try_spline_interpolate(&[400.0, 390.0, 500.0], &[1.0, 2.0, 3.0]).is_err());
+        assert!(Spectrum::try_resample(&[380.0, 400.0, 450.0], &[1.0; 3], Interpolation::Sprague).is_err());
+    }
+
+    #[test]
+    fn test_csv() {
+        let csv = "# measured 2024-10-01\nnm,W/m2/nm,flags\n400,1.0,ok\n\n500,2.0,ok\n600,1.0,ok\n";
+        let s = Spectrum::from_csv(csv).unwrap();
+        assert_ulps_eq!(s[450], 1.5);
+        assert_ulps_eq!(s[380], 1.0);
+        assert_ulps_eq!(s[700], 1.0);
+        assert!(s.to_csv().starts_with("wavelength,value\n380,1\n"));
+        assert_eq!(s.to_csv().lines().count(), NS + 1);
+
+        assert!(Spectrum::from_csv("400,1.0\n390,2.0\n").is_err());
+        assert!(Spectrum::from_csv("400,1.0\n500,x\n").is_err());
+        assert_eq!(Spectrum::from_csv("nm,value\n400,1.0\n"), Err(CmtError::ProvideAtLeastNValues(2)));
+    }
+
+    #[test]
+    fn test_similarity() {
+        let measured = Illuminant::d65();
+        let mut smoothed = *measured;
+        smoothed.smooth(10.0);
+        let gfc = measured.goodness_of_fit(&smoothed);
+        assert!(gfc > 0.99 && gfc < 1.0);
+        approx::assert_abs_diff_eq!(gfc, measured.spectral_angle(&smoothed).cos(), epsilon = 1E-12);
+        assert_ulps_eq!(measured.rmse(&measured), 0.0);
+        assert_ulps_eq!(measured.rmse(&(*measured + Spectrum([1.0; NS].into()))), 1.0);
+    }
+
+    #[test]
+    fn test_wavelength_correction() {
+        let line = Colorant::gaussian(550.0, 10.0).0;
+        assert_ulps_eq!(line.shift_wavelength(3.0)[553], 1.0);
+        assert_ulps_eq!(line.shift_wavelength(-2.0)[548], 1.0);
+        assert_eq!(line.shift_wavelength(0.7), line.correct_wavelength_scale(&[0.7]));
+        assert_eq!(line.correct_wavelength_scale(&[]), line);
+
+        // reported wavelengths 1 nm too short at 380 nm, and 3 nm too short at 780 nm
+        let measured = line.shift_wavelength(-2.0);
+        let corrected = measured.correct_wavelength_scale(&[1.0 - 380.0 * 0.005, 0.005]);
+        approx::assert_abs_diff_eq!(corrected[550], 1.0, epsilon = 1E-3);
+        approx::assert_abs_diff_eq!(corrected[380], line[380], epsilon = 1E-12);
+    }
+
+    #[test]
+    fn test_spectra_matrix() {
+        let spectra: Vec<Spectrum> = (1..=3).map(|i| Colorant::gaussian(450.0 + 100.0 * i as f64, 20.0).0).collect();
+        let m = Spectrum::spectra_as_matrix(&spectra);
+        assert_eq!(m.shape(), (NS, 3));
+        assert_eq!(m[(550 - 380, 0)], 1.0);
+        assert_eq!(Spectrum::spectra_from_matrix(&m.into_owned()).unwrap(), spectra);
+        assert!(Spectrum::spectra_from_matrix(&DMatrix::zeros(3, NS)).is_err());
+
+        #[cfg(feature = "ndarray")]
+        {
+            let a = Spectrum::spectra_as_array2(&spectra);
+            assert_eq!(a.dim(), (NS, 3));
+            assert_eq!(a[[650 - 380, 1]], 1.0);
+            assert_eq!(Spectrum::spectra_from_array2(a.t().t()).unwrap(), spectra);
+            assert!(Spectrum::spectra_from_array2(a.t()).is_err());
+        }
+    }
+
+    #[test]
+    fn test_zero_copy() {
+        let mut data = [0.0; NS];
+        data[550-380] = 2.0;
+        assert_eq!(Spectrum::from_slice_ref(&data)[550], 2.0);
+        assert_eq!(AsRef::<[f64]>::as_ref(Spectrum::from_slice_ref(&data)).as_ptr(), data.as_ptr());
+
+        Spectrum::from_slice_mut(&mut data)[560] = 3.0;
+        assert_eq!(data[560-380], 3.0);
+
+        assert!(Spectrum::try_from_slice_ref(&data[1..]).is_err());
+        let cube: Vec<f64> = (0..3*NS).map(|i| (i / NS) as f64).collect();
+        let spectra = Spectrum::spectra_from_slice(&cube).unwrap();
+        assert_eq!(spectra.iter().map(|s| s[780]).collect::<Vec<_>>(), [0.0, 1.0, 2.0]);
+        assert!(Spectrum::spectra_from_slice(&cube[1..]).is_err());
+    }
+
     #[test]
     fn test_spectrum_from_rgb(){
         let white: Stimulus = RGB::new(1.0, 1.0, 1.0, None, None).into();
         approx::assert_ulps_eq!(CIE1931.xyz_from_spectrum(&white, None), CIE1931.xyz_d65().set_illuminance(100.0), epsilon = 1E-6);
         let red = Stimulus::srgb(255, 0, 0);
-        assert_ulps_eq!(CIE1931.xyz_from_spectrum(&red, None).chromaticity().as_ref(), &[0.64, 0.33].as_ref(), epsilon = 1E-5);
+        assert_ulps_eq!(CIE1931.xyz_from_spectrum(&red, None).xy().values().as_ref(), &[0.64, 0.33].as_ref(), epsilon = 1E-5);
     }
 
     #[test]
@@ -609,12 +1259,12 @@ mod tests {
     #[test]
     fn test_chromaticity(){
         let xyz0 = CIE1931.xyz_from_spectrum(&D65, None);
-        let [x0, y0] = xyz0.chromaticity();
+        let [x0, y0] = xyz0.xy().values();
 
         let illuminance = D65.illuminance(&CIE1931);
         let d65 = D65.clone().set_illuminance(&CIE1931, 100.0);
         let xyz = CIE1931.xyz_from_spectrum(&d65, None);
-        let [x, y] = xyz.chromaticity();
+        let [x, y] = xyz.xy().values();
     
         assert_ulps_eq!(x0, x);
         assert_ulps_eq!(y0, y);
@@ -651,7 +1301,7 @@ mod tests {
     #[test]
     fn ee() {
         let [x, y ] = CIE1931.xyz_from_spectrum(
-            &Illuminant::equal_energy().set_illuminance(&CIE1931, 100.0), None).chromaticity();
+            &Illuminant::equal_energy().set_illuminance(&CIE1931, 100.0), None).xy().values();
         assert_ulps_eq!(x, 0.333_3, epsilon = 5E-5);
         assert_ulps_eq!(y, 0.333_3, epsilon = 5E-5);
     }
@@ -659,7 +1309,7 @@ mod tests {
     #[test]
     fn d65() {
         let [x, y ] = CIE1931.xyz_from_spectrum(
-            &&Illuminant::d65().set_illuminance(&CIE1931, 100.0), None).chromaticity();
+            &&Illuminant::d65().set_illuminance(&CIE1931, 100.0), None).xy().values();
         // See table T3 CIE15:2004 (calculated with 5nm intervals, instead of 1nm, as used here)
         assert_ulps_eq!(x, 0.312_72, epsilon = 5E-5);
         assert_ulps_eq!(y, 0.329_03, epsilon = 5E-5);
@@ -667,7 +1317,7 @@ mod tests {
 
     #[test]
     fn d50() {
-        let [x, y ] = CIE1931.xyz_from_spectrum(&Illuminant::d50().set_illuminance(&CIE1931, 100.0), None).chromaticity();
+        let [x, y ] = CIE1931.xyz_from_spectrum(&Illuminant::d50().set_illuminance(&CIE1931, 100.0), None).xy().values();
         // See table T3 CIE15:2004 (calculated with 5nm intervals, instead of 1nm, as used here)
         assert_ulps_eq!(x, 0.345_67, epsilon = 5E-5);
         assert_ulps_eq!(y, 0.358_51, epsilon = 5E-5);
@@ -676,7 +1326,7 @@ mod tests {
     #[cfg_attr(test, cfg(feature="cie-illuminants"))]
     fn a() {
         let a: Illuminant = StdIlluminant::A.into();
-        let [x, y ] = CIE1931.xyz_from_spectrum(&a, None).chromaticity();
+        let [x, y ] = CIE1931.xyz_from_spectrum(&a, None).xy().values();
         // See table T3 CIE15:2004 (calculated with 5nm intervals, instead of 1nm, as used here)
         assert_ulps_eq!(x, 0.447_58, epsilon = 5E-5);
         assert_ulps_eq!(y, 0.407_45, epsilon = 5E-5);
@@ -857,4 +1507,33 @@ mod tests {
 
     }
 
-}
\ No newline at end of file
+    #[test]
+    fn test_derivative_cumulative(){
+        use approx::assert_abs_diff_eq;
+
+        let ramp = Spectrum::try_linear_interpolate(&[380.0, 780.0], &[0.0, 4.0]).unwrap();
+        let d = ramp.derivative();
+        assert_abs_diff_eq!(d[380], 0.01, epsilon = 1E-12);
+        assert_abs_diff_eq!(d[600], 0.01, epsilon = 1E-12);
+        assert_abs_diff_eq!(d[780], 0.01, epsilon = 1E-12);
+
+        let c = Colorant::white().cumulative();
+        assert_eq!(c[380], 1.0);
+        assert_eq!(c[780], 401.0);
+        assert_abs_diff_eq!(c.derivative()[550], 1.0, epsilon = 1E-12);
+    }
+
+    #[test]
+    fn test_truncated_power_fraction(){
+        use approx::assert_abs_diff_eq;
+
+        // a violet pump line at 370 nm, with equal power in a band within the domain
+        let wl = [360.0, 365.0, 370.0, 375.0, 380.0, 500.0, 505.0, 510.0];
+        let v = [0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0];
+        assert_abs_diff_eq!(Spectrum::truncated_power_fraction(&wl, &v).unwrap(), 0.5, epsilon = 1E-12);
+        assert_eq!(Spectrum::truncated_power_fraction(&[400.0, 700.0], &[1.0, 2.0, 1.0]).unwrap(), 0.0);
+        assert!(Spectrum::truncated_power_fraction(&wl, &[0.0; 8]).is_err());
+        assert!(Spectrum::truncated_power_fraction(&wl, &[1.0; 3]).is_err());
+    }
+
+}
@@ -0,0 +1,170 @@
+/*!
+# Spectral Similarity Index
+
+The Spectral Similarity Index (SSI), developed by the Science and Technology Council of the
+Academy of Motion Picture Arts and Sciences, compares the spectral distribution of a light source
+directly with that of a reference, without the color samples, and observer, used by the color
+rendering index, as cameras have other spectral sensitivities than the eye. Cinematographers use
+it, next to the color rendering index, to evaluate LED fixtures against a tungsten, or daylight,
+reference. A score of 100 means the distributions are identical, up to a scale factor.
+
+The test and reference spectra are summed in 30 bands of 10 nm, from 375 to 675 nm, and normalized
+to a sum of one. The relative differences of the bands, with a small offset to limit the effect of
+bands with little reference power, are weighted to reduce the importance of the ends of the range,
+smoothed, and their root sum of squares, times 32, is subtracted from 100.
+
+The wavelength domain of this library starts at 380 nm, and the first band only includes values
+from 380 to 384 nm, as for reference spectra given from 380 nm in the Academy's calculator.
+Published SSI values are rounded to integers; [`ssi`] returns the unrounded value.
+```
+use colorimetry::prelude::*;
+
+let tungsten = Illuminant::planckian(3200.0);
+approx::assert_abs_diff_eq!(ssi(&(tungsten.clone() * 5.0), &tungsten).unwrap(), 100.0, epsilon = 1E-10);
+
+let led = Illuminant::led(590.0, 80.0);
+assert!(ssi(&led, &tungsten).unwrap() < 50.0);
+
+// as a color rendition metric, with a CIE D55 reference
+let metric = SsiMetric::daylight(5500.0).unwrap();
+let result = metric.evaluate(&Illuminant::d65(), &CIE1931).unwrap();
+assert!(result.general > 90.0);
+```
+
+# References
+- J. Holm, T. Maier, P. Debevec, C. LeGendre, J. Pines, J. Erland, G. Joblove, S. Dyer, B. Sloan,
+  J. di Gennaro, D. Sherlock, A Cinematographic Spectral Similarity Index, SMPTE 2016 Annual
+  Technical Conference and Exhibition.
+*/
+
+use crate::{
+    error::CmtError,
+    illuminant::Illuminant,
+    observer::ObserverData,
+    rendition::{MetricResult, RenditionMetric},
+    traits::Light,
+};
+
+/// Number of 10 nm bands, from 375 to 675 nm.
+const N_SSI: usize = 30;
+
+/// Weights of the relative band differences.
+const SSI_WEIGHTS: [f64; N_SSI] = [
+    12.0 / 45.0, 22.0 / 45.0, 32.0 / 45.0, 40.0 / 45.0, 44.0 / 45.0,
+    1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0,
+    11.0 / 15.0, 3.0 / 15.0,
+];
+
+/// Smoothing kernel for the weighted differences.
+const SSI_KERNEL: [f64; 3] = [0.22, 0.56, 0.22];
+
+/// Spectral Similarity Index of a test light source, relative to a reference light source.
+///
+/// Returns an error if either spectrum has no power in the range from 380 to 675 nm.
+pub fn ssi(test: &dyn Light, reference: &dyn Light) -> Result<f64, CmtError> {
+    let t = bands(test)?;
+    let r = bands(reference)?;
+    // weighted relative differences, padded with two zeros at both ends, for the smoothing
+    let mut d = [0.0; N_SSI + 4];
+    for i in 0..N_SSI {
+        d[i + 2] = SSI_WEIGHTS[i] * (t[i] - r[i]) / (r[i] + 1.0 / N_SSI as f64);
+    }
+    let sum_squares: f64 = d
+        .windows(3)
+        .map(|w| w.iter().zip(SSI_KERNEL.iter()).map(|(v, k)| v * k).sum::<f64>().powi(2))
+        .sum();
+    Ok(100.0 - 32.0 * sum_squares.sqrt())
+}
+
+/// Band powers, normalized to a sum of one.
+fn bands(light: &dyn Light) -> Result<[f64; N_SSI], CmtError> {
+    let spectrum = light.spectrum();
+    let s = spectrum.as_ref();
+    let mut bands = [0.0; N_SSI];
+    for (i, band) in bands.iter_mut().enumerate() {
+        let low = 375 + 10 * i;
+        *band = (low.max(380)..low + 10).map(|l| s[l]).sum();
+    }
+    let total: f64 = bands.iter().sum();
+    if total <= 0.0 || !total.is_finite() {
+        return Err(CmtError::ErrorString("SSI: spectrum has no power from 380 to 675 nm".into()));
+    }
+    Ok(bands.map(|b| b / total))
+}
+
+/**
+The Spectral Similarity Index as a [`RenditionMetric`], with its reference light source.
+
+The index does not use an observer, and the observer argument of
+[`evaluate`](RenditionMetric::evaluate) is ignored.
+*/
+#[derive(Clone, Debug)]
+pub struct SsiMetric {
+    reference: Illuminant,
+}
+
+impl SsiMetric {
+    pub fn new(reference: Illuminant) -> Self {
+        Self { reference }
+    }
+
+    /// With a Planckian reference, such as 3200 K for tungsten studio lighting.
+    pub fn planckian(cct: f64) -> Self {
+        Self::new(Illuminant::planckian(cct))
+    }
+
+    /// With a CIE D illuminant reference, such as CIE D55 for daylight; returns an error for
+    /// correlated color temperatures outside the range of the daylight locus.
+    pub fn daylight(cct: f64) -> Result<Self, CmtError> {
+        Ok(Self::new(Illuminant::try_d_illuminant(cct)?))
+    }
+
+    pub fn reference(&self) -> &Illuminant {
+        &self.reference
+    }
+}
+
+impl RenditionMetric for SsiMetric {
+    fn name(&self) -> &str {
+        "Academy SSI"
+    }
+
+    fn evaluate(&self, illuminant: &Illuminant, _observer: &ObserverData) -> Result<MetricResult, CmtError> {
+        Ok(MetricResult { name: self.name().to_string(), general: ssi(illuminant, &self.reference)?, special: Vec::new() })
+    }
+}
+
+#[cfg(test)]
+mod ssi_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_ssi() {
+        let tungsten = Illuminant::planckian(3200.0);
+        assert_abs_diff_eq!(ssi(&tungsten, &tungsten).unwrap(), 100.0);
+
+        // closer temperatures are more similar
+        let near = ssi(&Illuminant::planckian(3100.0), &tungsten).unwrap();
+        let far = ssi(&Illuminant::planckian(2700.0), &tungsten).unwrap();
+        assert!(near < 100.0 && far < near);
+
+        // power outside the range from 375 to 675 nm does not count
+        let deep_red = Illuminant(*tungsten + *Illuminant::led(740.0, 10.0) * 10.0);
+        assert_abs_diff_eq!(ssi(&deep_red, &tungsten).unwrap(), 100.0, epsilon = 1E-3);
+
+        // a phosphor converted white LED is more similar to daylight than a narrow band RGB mixture
+        let d55 = Illuminant::try_d_illuminant(5500.0).unwrap();
+        let pc = Illuminant(*Illuminant::led(450.0, 20.0) * 0.3 + *Illuminant::led(570.0, 120.0));
+        let rgb = Illuminant(*Illuminant::led(450.0, 20.0) + *Illuminant::led(530.0, 30.0) + *Illuminant::led(620.0, 20.0));
+        assert!(ssi(&pc, &d55).unwrap() > ssi(&rgb, &d55).unwrap());
+
+        assert!(ssi(&Illuminant::default(), &tungsten).is_err());
+
+        let metric = SsiMetric::planckian(3200.0);
+        let result = metric.evaluate(&Illuminant::planckian(3100.0), &CIE1931).unwrap();
+        assert_eq!(result.name, "Academy SSI");
+        assert_abs_diff_eq!(result.general, near);
+        assert!(SsiMetric::daylight(2000.0).is_err());
+    }
+}
@@ -3,7 +3,7 @@
 
 Many of the CIE Standard Illuminants are made available in this module through
 `StdIlluminant` for data-defined illuminants and the
-`Spectrum::cie_d_illuminant(cct: f64)` function for generic D-illuminant.
+`Illuminant::try_d_illuminant(cct: f64)` function for generic D-illuminant.
 
 The `StdIlluminant` object gives access to all the CIE illuminants defined in this library, obtained from the datasets published in the
 CIE15::2018 standard, downloaded from the [CIE Website](https://web.archive.org/web/20240314231650/https://cie.co.at/data-tables) August 2024.
@@ -128,3 +128,115 @@ std_illuminants!(D65 D50 [A F1 F2 F3 F4 F5 F6 F7 F8 F9 F10 F11 F12
 
 
 
+
+/// Light source category of a standard illuminant.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, strum_macros::Display, strum_macros::EnumIter)]
+pub enum IlluminantCategory {
+    Daylight,
+    Incandescent,
+    Fluorescent,
+    Led,
+}
+
+/// Metadata of a standard illuminant, as obtained by [`StdIlluminant::info`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StdIlluminantInfo {
+    /// Nominal correlated color temperature, in kelvin, as listed in its standard.
+    pub nominal_cct: f64,
+    pub category: IlluminantCategory,
+    /// Short description of the light source.
+    pub description: &'static str,
+    /// The standard defining the illuminant.
+    pub reference: &'static str,
+}
+
+impl StdIlluminant {
+    /**
+    Nominal correlated color temperature, category, description, and defining standard of a
+    standard illuminant, for example to present them in a user interface.
+    ```
+    use colorimetry::prelude::*;
+
+    let info = StdIlluminant::D65.info();
+    assert_eq!(info.nominal_cct, 6504.0);
+    assert_eq!(info.category, IlluminantCategory::Daylight);
+    ```
+    */
+    pub fn info(&self) -> StdIlluminantInfo {
+        use IlluminantCategory::*;
+        let (nominal_cct, category, description, reference) = match self {
+            Self::D65 => (6504.0, Daylight, "Average daylight", "ISO 11664-2:2007(E)/CIE S 014-2/E:2006"),
+            Self::D50 => (5003.0, Daylight, "Horizon daylight, graphic arts viewing", "CIE 15:2018"),
+            #[cfg(feature="cie-illuminants")]
+            other => return cie_illuminant_info(other),
+        };
+        StdIlluminantInfo { nominal_cct, category, description, reference }
+    }
+}
+
+#[cfg(feature="cie-illuminants")]
+fn cie_illuminant_info(illuminant: &StdIlluminant) -> StdIlluminantInfo {
+    use IlluminantCategory::*;
+    use StdIlluminant::*;
+    const HALOPHOSPHATE: &str = "Standard halophosphate fluorescent lamp";
+    const BROADBAND: &str = "Broadband fluorescent lamp";
+    const THREE_BAND: &str = "Three-band fluorescent lamp";
+    const PC_LED: &str = "Phosphor-converted blue LED";
+    let (nominal_cct, category, description) = match illuminant {
+        A => (2856.0, Incandescent, "Tungsten filament lamp"),
+        F1 => (6430.0, Fluorescent, HALOPHOSPHATE),
+        F2 => (4230.0, Fluorescent, HALOPHOSPHATE),
+        F3 => (3450.0, Fluorescent, HALOPHOSPHATE),
+        F4 => (2940.0, Fluorescent, HALOPHOSPHATE),
+        F5 => (6350.0, Fluorescent, HALOPHOSPHATE),
+        F6 => (4150.0, Fluorescent, HALOPHOSPHATE),
+        F7 => (6500.0, Fluorescent, BROADBAND),
+        F8 => (5000.0, Fluorescent, BROADBAND),
+        F9 => (4150.0, Fluorescent, BROADBAND),
+        F10 => (5000.0, Fluorescent, THREE_BAND),
+        F11 => (4000.0, Fluorescent, THREE_BAND),
+        F12 => (3000.0, Fluorescent, THREE_BAND),
+        F3_1 => (2932.0, Fluorescent, HALOPHOSPHATE),
+        F3_2 => (3965.0, Fluorescent, HALOPHOSPHATE),
+        F3_3 => (6280.0, Fluorescent, HALOPHOSPHATE),
+        F3_4 => (2904.0, Fluorescent, "DeLuxe fluorescent lamp"),
+        F3_5 => (4086.0, Fluorescent, "DeLuxe fluorescent lamp"),
+        F3_6 => (4894.0, Fluorescent, "DeLuxe fluorescent lamp"),
+        F3_7 => (2979.0, Fluorescent, THREE_BAND),
+        F3_8 => (4006.0, Fluorescent, THREE_BAND),
+        F3_9 => (4853.0, Fluorescent, THREE_BAND),
+        F3_10 => (5000.0, Fluorescent, THREE_BAND),
+        F3_11 => (5854.0, Fluorescent, THREE_BAND),
+        F3_12 => (2984.0, Fluorescent, "Multi-band fluorescent lamp"),
+        F3_13 => (3896.0, Fluorescent, "Multi-band fluorescent lamp"),
+        F3_14 => (5045.0, Fluorescent, "Multi-band fluorescent lamp"),
+        F3_15 => (6509.0, Fluorescent, "D65 simulator fluorescent lamp"),
+        LED_B1 => (2733.0, Led, PC_LED),
+        LED_B2 => (2998.0, Led, PC_LED),
+        LED_B3 => (4103.0, Led, PC_LED),
+        LED_B4 => (5109.0, Led, PC_LED),
+        LED_B5 => (6598.0, Led, PC_LED),
+        LED_BH1 => (2851.0, Led, "Hybrid phosphor-converted blue, and red LED"),
+        LED_RGB1 => (2840.0, Led, "Red, green, and blue LED mixture"),
+        LED_V1 => (2724.0, Led, "Phosphor-converted violet LED"),
+        LED_V2 => (4070.0, Led, "Phosphor-converted violet LED"),
+        D65 | D50 => unreachable!(),
+    };
+    StdIlluminantInfo { nominal_cct, category, description, reference: "CIE 15:2018" }
+}
+
+#[cfg(test)]
+mod std_illuminants_test {
+    use crate::prelude::*;
+    use strum::IntoEnumIterator;
+
+    #[cfg(feature="cct")]
+    #[test]
+    fn test_nominal_cct() {
+        for illuminant in StdIlluminant::iter() {
+            let cct = CIE1931.xyz(&illuminant, None).cct().unwrap().t();
+            let info = illuminant.info();
+            assert!((cct - info.nominal_cct).abs() / cct < 2E-3, "{illuminant}: {cct} {}", info.nominal_cct);
+        }
+    }
+}
@@ -1,16 +1,49 @@
-use std::{borrow::Cow, iter::Sum, ops::{Deref, Mul}};
+use std::{borrow::Cow, iter::Sum, ops::{Add, Deref, Mul, Sub}};
+
+use nalgebra::{DMatrix, DVector, SVector};
 
 use crate::{
-    spectrum::Spectrum,
+    colorant::Colorant,
+    error::CmtError,
+    math,
+    spectrum::{NegativeValuePolicy, Spectrum, NS},
     traits::Light,
     observer::ObserverData,
     illuminant::Illuminant,
-    rgb::RGB
+    rgb::RGB,
+    xyz::XYZ
 };
 
+/// Maximum number of Newton iterations in the [`Reconstruction::LogLeastSlopeSquared`] method.
+const MAX_ITERATIONS: usize = 100;
+
+/**
+Method to reconstruct a smooth spectrum for a set of tristimulus values, as used by
+[`Stimulus::try_from_xyz`].
+
+Both methods are from Scott A. Burns, "Numerical methods for smoothest reflectance
+reconstruction", Color Research and Application, 45(1), 2020, and find the spectrum with the
+smallest sum of squared differences between successive spectral values, which reproduces the
+tristimulus values exactly.
+*/
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, strum_macros::Display, strum_macros::EnumIter)]
+pub enum Reconstruction {
+    /// Least slope squared, with the logarithm of the spectral values, which results in strictly
+    /// positive spectra, for any chromaticity within the spectral locus, solved by Newton's
+    /// method.
+    #[default]
+    LogLeastSlopeSquared,
+    /// Least slope squared, with the spectral values, which is a linear problem, with a direct
+    /// solution, but which results in negative values for saturated colors, handled according to
+    /// a [`NegativeValuePolicy`]; clipped, or renormalized, spectra no longer reproduce the
+    /// tristimulus values exactly.
+    LeastSlopeSquared(NegativeValuePolicy),
+}
+
 
 
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Stimulus(pub(crate) Spectrum);
 
 impl Deref for Stimulus {
@@ -43,7 +76,158 @@ impl Stimulus {
         rgb.into()
     }
 
+    /// A spectral composition of a display pixel, as in [`Stimulus::rgb`], with negative
+    /// spectral values, from negative RGB values, handled according to a [`NegativeValuePolicy`].
+    pub fn try_from_rgb(rgb: RGB, policy: NegativeValuePolicy) -> Result<Self, CmtError> {
+        let observer = rgb.observer;
+        let s = Stimulus::from(rgb);
+        Ok(Stimulus(s.0.apply_negative_policy(policy, observer.data())?))
+    }
+
+    /**
+    A smooth spectral composition for a set of tristimulus values, which reproduces these values
+    exactly for their observer, for use with other observers, such as simulating a color matched
+    for the CIE 1931 observer as seen by a CIE 2015 observer.
+
+    The tristimulus values are taken as absolute values, and the luminous value of the stimulus
+    equals the luminous value of the XYZ values, or of its sample, if any.
+    Returns an `OutOfGamut` error if no positive spectrum is found, which is the case for
+    chromaticities on, or outside, the spectral locus, with the
+    [`LogLeastSlopeSquared`](Reconstruction::LogLeastSlopeSquared) method.
+    ```
+    use colorimetry::prelude::*;
+
+    let xyz = XYZ::try_from_chromaticity(0.25, 0.55, Some(40.0), None).unwrap();
+    let s = Stimulus::try_from_xyz(&xyz, Reconstruction::LogLeastSlopeSquared).unwrap();
+    assert!(AsRef::<[f64]>::as_ref(&*s).iter().all(|&v| v > 0.0));
+    let back = CIE1931.xyz_from_spectrum(&s, None);
+    approx::assert_abs_diff_eq!(back.xy().values().as_ref(), [0.25, 0.55].as_ref(), epsilon = 1E-9);
+    approx::assert_abs_diff_eq!(back.luminous_value(), 40.0, epsilon = 1E-9);
+    ```
+    */
+    pub fn try_from_xyz(xyz: &XYZ, method: Reconstruction) -> Result<Self, CmtError> {
+        let obs = xyz.observer.data();
+        let target = xyz.xyz.unwrap_or(xyz.xyzn);
+        if target.y <= 0.0 {
+            return Err(CmtError::InvalidChromaticityValues);
+        }
+        // color matching functions, scaled to tristimulus values with a luminous value of one for
+        // the equal energy spectrum with values of one, which is the start of the iterations
+        let sum_y = obs.data.row(1).sum();
+        let a = DMatrix::from_fn(3, NS, |r, c| obs.data[(r, c)]) / sum_y;
+        let t = DVector::from_column_slice((target / target.y).as_slice());
+        let s = match method {
+            Reconstruction::LeastSlopeSquared(_) => {
+                let (s, _) = solve_kkt(&DVector::zeros(NS), &a.transpose(), &DVector::zeros(NS), &t).ok_or(CmtError::OutOfGamut)?;
+                s
+            }
+            Reconstruction::LogLeastSlopeSquared => log_least_slope_squared(&a, &t)?,
+        };
+        let spectrum = Spectrum(SVector::<f64, NS>::from_iterator(s.iter().map(|v| v * target.y / (obs.lumconst * sum_y))));
+        match method {
+            Reconstruction::LeastSlopeSquared(policy) => Ok(Stimulus(spectrum.apply_negative_policy(policy, obs)?)),
+            Reconstruction::LogLeastSlopeSquared => Ok(Stimulus(spectrum)),
+        }
+    }
+
+
+}
+
+/// The product Cz, with C = DᵀD, and D the first difference matrix, such that zᵀCz is the sum of
+/// the squared differences between successive values of z.
+fn slope(z: &DVector<f64>) -> DVector<f64> {
+    let n = z.len();
+    DVector::from_fn(n, |i, _| {
+        let left = if i > 0 { z[i] - z[i - 1] } else { 0.0 };
+        let right = if i < n - 1 { z[i] - z[i + 1] } else { 0.0 };
+        left + right
+    })
+}
+
+/// Solves T x = p, for a symmetric tridiagonal matrix T with a diagonal, and off-diagonal values
+/// of -1, as in C, using the Thomas algorithm.
+fn solve_tridiagonal(diagonal: &[f64], p: &DVector<f64>) -> Option<DVector<f64>> {
+    let n = diagonal.len();
+    let (mut c, mut x) = (vec![0.0; n], p.clone());
+    for i in 0..n {
+        let pivot = if i > 0 { diagonal[i] + c[i - 1] } else { diagonal[0] };
+        if pivot.abs() < 1E-14 {
+            return None;
+        }
+        c[i] = -1.0 / pivot;
+        x[i] = if i > 0 { (x[i] + x[i - 1]) / pivot } else { x[0] / pivot };
+    }
+    for i in (0..n - 1).rev() {
+        x[i] -= c[i] * x[i + 1];
+    }
+    Some(x)
+}
+
+/**
+Solves the system [T B; Bᵀ 0] [x; y] = [p; q], with T = C + diag(d), and B a matrix with three
+columns, as found in the least slope squared problems.
 
+T is singular for d = 0, as C has the constant vectors as null space; the system is solved for
+T₀ = T + e₀e₀ᵀ, by block elimination, using the tridiagonal structure of T₀, and corrected for the
+rank one difference, using the Sherman-Morrison formula.
+*/
+fn solve_kkt(d: &DVector<f64>, b: &DMatrix<f64>, p: &DVector<f64>, q: &DVector<f64>) -> Option<(DVector<f64>, DVector<f64>)> {
+    let n = d.len();
+    // the diagonal of T₀, with the first value of C increased from 1 to 2
+    let diagonal: Vec<f64> = (0..n).map(|i| d[i] + if i == n - 1 { 1.0 } else { 2.0 }).collect();
+    let mut w = DMatrix::zeros(n, 3);
+    for k in 0..3 {
+        w.set_column(k, &solve_tridiagonal(&diagonal, &b.column(k).into_owned())?);
+    }
+    let s = (b.transpose() * &w).try_inverse()?;
+    let solve0 = |p: &DVector<f64>, q: &DVector<f64>| -> Option<(DVector<f64>, DVector<f64>)> {
+        let u = solve_tridiagonal(&diagonal, p)?;
+        let y = &s * (b.transpose() * &u - q);
+        Some((u - &w * &y, y))
+    };
+    let (x0, y0) = solve0(p, q)?;
+    let (xe, ye) = solve0(&DVector::from_fn(n, |i, _| if i == 0 { 1.0 } else { 0.0 }), &DVector::zeros(3))?;
+    let f = x0[0] / (1.0 - xe[0]);
+    Some((x0 + xe * f, y0 + ye * f))
+}
+
+/// Solves the log least slope squared problem, for the spectral values exp(z), minimizing zᵀCz,
+/// subject to A exp(z) = t, using Newton's method, with step halving, on the Lagrange
+/// conditions Cz + exp(z)∘Aᵀλ = 0, and A exp(z) - t = 0.
+fn log_least_slope_squared(a: &DMatrix<f64>, t: &DVector<f64>) -> Result<DVector<f64>, CmtError> {
+    let residual = |z: &DVector<f64>, lambda: &DVector<f64>| {
+        let r = z.map(math::exp);
+        (slope(z) + r.component_mul(&(a.transpose() * lambda)), a * &r - t)
+    };
+    let norm = |(f1, f2): &(DVector<f64>, DVector<f64>)| (f1.norm_squared() + f2.norm_squared()).sqrt();
+    let mut z = DVector::<f64>::zeros(NS);
+    let mut lambda = DVector::<f64>::zeros(3);
+    let mut f = residual(&z, &lambda);
+    debug_span!("log_least_slope_squared", target = ?t.as_slice());
+    for _ in 0..MAX_ITERATIONS {
+        debug_event!(residual = norm(&f), ?lambda);
+        if f.0.amax().max(f.1.amax()) < 1E-12 {
+            return Ok(z.map(math::exp));
+        }
+        let r = z.map(math::exp);
+        let d = r.component_mul(&(a.transpose() * &lambda));
+        let b = DMatrix::from_fn(NS, 3, |i, j| r[i] * a[(j, i)]);
+        let Some((dz, dl)) = solve_kkt(&d, &b, &-&f.0, &-&f.1) else { break };
+        let mut step = 1.0;
+        loop {
+            let (zn, ln) = (&z + &dz * step, &lambda + &dl * step);
+            let fnew = residual(&zn, &ln);
+            if norm(&fnew) < norm(&f) {
+                (z, lambda, f) = (zn, ln, fnew);
+                break;
+            }
+            step *= 0.5;
+            if step < 1E-8 {
+                return Err(CmtError::OutOfGamut);
+            }
+        }
+    }
+    Err(CmtError::OutOfGamut)
 }
 
 impl Light for Stimulus {
@@ -70,6 +254,9 @@ impl Sum for Stimulus {
 /// but you can also use your own color space based on primaries measured by a spectrometer.
 /// Spectral representations of pixels allow color matching for arbitrary observers,
 /// not only the CIE 1931 standard observer.
+///
+/// Extended range RGB values result in negative spectral values, which are kept; use
+/// [`Stimulus::try_from_rgb`] to handle these with a [`NegativeValuePolicy`].
 impl From<RGB> for Stimulus {
     fn from(rgb: RGB) -> Self {
         let prim = &rgb.space.data().0.primaries;
@@ -92,4 +279,136 @@ impl Mul<Stimulus> for f64 {
     fn mul(self, rhs: Stimulus) -> Self::Output {
         Stimulus(self * rhs.0)
     }
-}
\ No newline at end of file
+}
+
+impl Add for Stimulus {
+    type Output = Self;
+
+    /// Additive mixing of two stimuli, such as the light of a display and ambient light reflected
+    /// by its screen.
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl Sub for Stimulus {
+    type Output = Self;
+
+    /**
+    Subtraction of a stimulus, such as a measured flare or ambient contribution, with negative
+    spectral values, which are physically impossible, clamped to zero.
+    ```
+    use colorimetry::prelude::*;
+
+    let white = Stimulus::srgb(255, 255, 255);
+    let red = Stimulus::srgb(255, 0, 0);
+    let cyan = white.clone() - red.clone();
+    let back = cyan + red;
+    approx::assert_abs_diff_eq!(back[550], white[550], epsilon = 1E-9);
+    let none = Stimulus::srgb(0, 0, 255) - white;
+    assert!((380..=780).all(|l| none[l] == 0.0));
+    ```
+    */
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(Spectrum((self.0.0 - rhs.0.0).map(|v| v.max(0.0))))
+    }
+}
+
+impl Mul<Colorant> for Stimulus {
+    type Output = Self;
+
+    /// A stimulus transmitted by a filter, such as a display viewed through a screen filter, or
+    /// colored glasses.
+    fn mul(self, rhs: Colorant) -> Self::Output {
+        Self(self.0 * rhs.0)
+    }
+}
+
+impl Mul<&Colorant> for &Stimulus {
+    type Output = Stimulus;
+
+    /// A stimulus transmitted by a filter, as non-consuming multiplication.
+    fn mul(self, rhs: &Colorant) -> Self::Output {
+        Stimulus(self.0 * rhs.0)
+    }
+}
+
+#[test]
+fn test_negative_policy(){
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+    // extended range red, outside the sRGB gamut
+    let rgb = RGB::from_extended(1.0, -0.2, -0.2, None, None);
+    assert!(Stimulus::try_from_rgb(rgb, NegativeValuePolicy::Error).is_err());
+    let unchanged: Stimulus = rgb.into();
+    assert!(unchanged.0.0.min() < 0.0);
+    let clipped = Stimulus::try_from_rgb(rgb, NegativeValuePolicy::Clip).unwrap();
+    assert!(clipped.0.0.min() >= 0.0);
+    let renormalized = Stimulus::try_from_rgb(rgb, NegativeValuePolicy::Renormalize).unwrap();
+    assert!(renormalized.0.0.min() >= 0.0);
+    let raw = Light::spectrum(&rgb);
+    assert_abs_diff_eq!(
+        CIE1931.xyz_from_spectrum(&renormalized, None).luminous_value(),
+        CIE1931.xyz_from_spectrum(&raw, None).luminous_value(),
+        epsilon = 1E-6
+    );
+
+    // in gamut colors have no negative values
+    let rgb = RGB::new(0.5, 0.2, 0.1, None, None);
+    assert!(Stimulus::try_from_rgb(rgb, NegativeValuePolicy::Error).is_ok());
+}
+
+#[test]
+fn test_arithmetic(){
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+    let display = Stimulus::srgb(200, 150, 100);
+    let ambient = Stimulus(Illuminant::d65().set_illuminance(&CIE1931, 5.0).0);
+    let viewed = display.clone() + ambient.clone();
+    let yv = CIE1931.xyz_from_spectrum(&viewed, None).luminous_value();
+    let yd = CIE1931.xyz_from_spectrum(&display, None).luminous_value();
+    assert_abs_diff_eq!(yv, yd + 5.0, epsilon = 1E-9);
+    assert_abs_diff_eq!((viewed - ambient).0.0, display.0.0, epsilon = 1E-9);
+
+    // a neutral density filter scales the luminance
+    let filtered = &display * &Colorant::gray(0.5);
+    assert_abs_diff_eq!(CIE1931.xyz_from_spectrum(&filtered, None).luminous_value(), yd / 2.0, epsilon = 1E-9);
+    assert_abs_diff_eq!((display * Colorant::black()).0.0.max(), 0.0);
+}
+
+#[test]
+fn test_try_from_xyz(){
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+    for [x, y] in [[0.3127, 0.3290], [0.60, 0.35], [0.20, 0.65], [0.17, 0.10], [0.45, 0.50]] {
+        let xyz = XYZ::try_from_chromaticity(x, y, Some(25.0), None).unwrap();
+        let s = Stimulus::try_from_xyz(&xyz, Reconstruction::LogLeastSlopeSquared).unwrap();
+        assert_abs_diff_eq!(CIE1931.xyz_from_spectrum(&s, None).xyzn, xyz.xyzn, epsilon = 1E-8);
+        assert!(s.0.0.min() > 0.0);
+
+        // exact, unless the spectrum has negative values
+        match Stimulus::try_from_xyz(&xyz, Reconstruction::LeastSlopeSquared(NegativeValuePolicy::Error)) {
+            Ok(s) => assert_abs_diff_eq!(CIE1931.xyz_from_spectrum(&s, None).xyzn, xyz.xyzn, epsilon = 1E-8),
+            Err(_) => {
+                let s = Stimulus::try_from_xyz(&xyz, Reconstruction::LeastSlopeSquared(NegativeValuePolicy::Clip)).unwrap();
+                assert!(s.0.0.min() >= 0.0);
+            }
+        }
+    }
+    let saturated = XYZ::try_from_chromaticity(0.60, 0.35, Some(25.0), None).unwrap();
+    assert!(Stimulus::try_from_xyz(&saturated, Reconstruction::LeastSlopeSquared(NegativeValuePolicy::Error)).is_err());
+    // the equal energy white is the smoothest spectrum for its chromaticity
+    let e = CIE1931.xyz_from_spectrum(&Illuminant::equal_energy(), None);
+    let s = Stimulus::try_from_xyz(&e, Reconstruction::LeastSlopeSquared(NegativeValuePolicy::Error)).unwrap();
+    assert_abs_diff_eq!(s.0.0.max(), s.0.0.min(), epsilon = 1E-9);
+
+    #[cfg(feature="supplemental-observers")]
+    {
+        let xyz = XYZ::try_from_chromaticity(0.40, 0.40, Some(10.0), Some(Observer::Std2015)).unwrap();
+        let s = Stimulus::try_from_xyz(&xyz, Reconstruction::default()).unwrap();
+        assert_abs_diff_eq!(CIE2015.xyz_from_spectrum(&s, None).xyzn, xyz.xyzn, epsilon = 1E-8);
+    }
+
+    let outside = XYZ::try_from_chromaticity(0.05, 0.05, Some(25.0), None).unwrap();
+    assert_eq!(Stimulus::try_from_xyz(&outside, Reconstruction::LogLeastSlopeSquared).err(), Some(CmtError::OutOfGamut));
+}
@@ -0,0 +1,131 @@
+/*!
+# Stray-Light Correction
+
+Array spectroradiometers suffer from stray light: a fraction of the light of each wavelength is
+detected at other wavelengths, by scattering inside the instrument. This causes large relative
+errors in spectral regions with low signal, such as in the blue region of incandescent lamps,
+and in the deep red of LEDs.
+
+The stray light of an instrument can be characterized, using a tunable laser, or a set of lasers,
+as a stray-light signal distribution matrix **D**, with a column for each input wavelength, and
+with the in-band values set to zero, as described by Zong et al., in
+"Simple spectral stray light correction method for array spectroradiometers", Applied Optics,
+45(6), 2006.
+A measured spectrum is then the product (**I** + **D**) *s*, of the true spectrum *s*, and the
+correction matrix **C** = (**I** + **D**)<sup>-1</sup> recovers the true spectrum from a
+measurement.
+
+In this library, these matrices are expressed on its standard wavelength domain, from 380 to 780
+nanometer, with 1 nanometer steps, with 401 rows and 401 columns.
+*/
+
+use nalgebra::DMatrix;
+
+use crate::{
+    error::CmtError,
+    luminaire::Numbers,
+    spectrum::{Spectrum, NS},
+};
+
+/// A stray-light correction matrix, for an instrument.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StrayLightCorrection(DMatrix<f64>);
+
+impl StrayLightCorrection {
+
+    /**
+    Correction from a stray-light signal distribution matrix **D**, with its in-band values set to
+    zero, by inverting **I** + **D**.
+    ```
+    use colorimetry::prelude::*;
+    use nalgebra::DMatrix;
+
+    // 0.001% uniform out-of-band stray light
+    let sdf = DMatrix::from_fn(NS, NS, |i, j| if i.abs_diff(j) > 5 { 1E-5 } else { 0.0 });
+    let correction = StrayLightCorrection::from_sdf(&sdf).unwrap();
+
+    let a = Illuminant::planckian(2856.0);
+    let m = (DMatrix::identity(NS, NS) + sdf) * Spectrum::spectra_as_matrix(&[*a]);
+    let measured = Spectrum::try_from(m.as_slice()).unwrap();
+    approx::assert_abs_diff_eq!(correction.apply(&measured), *a, epsilon = 1E-12);
+    ```
+    */
+    pub fn from_sdf(sdf: &DMatrix<f64>) -> Result<Self, CmtError> {
+        check_shape(sdf)?;
+        let c = (DMatrix::identity(NS, NS) + sdf)
+            .try_inverse()
+            .ok_or_else(|| CmtError::ErrorString("Stray-light: singular signal distribution matrix".into()))?;
+        Ok(Self(c))
+    }
+
+    /// Correction from a correction matrix **C** directly.
+    pub fn from_matrix(matrix: DMatrix<f64>) -> Result<Self, CmtError> {
+        check_shape(&matrix)?;
+        Ok(Self(matrix))
+    }
+
+    /**
+    Reads a correction matrix from a text file, with a row of 401 values on each line, for output
+    wavelengths from 380 to 780 nanometer, and separated by whitespace, or commas.
+    Empty lines, and lines starting with a `#`, are ignored.
+    */
+    pub fn read(s: &str) -> Result<Self, CmtError> {
+        let tokens = s
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .flat_map(|line| line.split(|c: char| c == ',' || c.is_whitespace()));
+        let mut numbers = Numbers::new(tokens);
+        let values = numbers.take(NS * NS)?;
+        if numbers.next().is_ok() {
+            return Err(CmtError::ParseError(format!("Stray-light: expected {} values", NS * NS)));
+        }
+        Ok(Self(DMatrix::from_row_slice(NS, NS, &values)))
+    }
+
+    /// Writes the correction matrix in the format read by [`StrayLightCorrection::read`].
+    pub fn write(&self) -> String {
+        let mut s = String::from("# Stray-light correction matrix, 380 to 780 nm, 1 nm steps\n");
+        for row in self.0.row_iter() {
+            let line: Vec<String> = row.iter().map(|v| format!("{v:e}")).collect();
+            s.push_str(&line.join(" "));
+            s.push('\n');
+        }
+        s
+    }
+
+    pub fn matrix(&self) -> &DMatrix<f64> {
+        &self.0
+    }
+
+    /// The stray-light corrected spectrum of a measurement.
+    pub fn apply(&self, spectrum: &Spectrum) -> Spectrum {
+        Spectrum(self.0.fixed_view::<NS, NS>(0, 0) * spectrum.0)
+    }
+}
+
+fn check_shape(matrix: &DMatrix<f64>) -> Result<(), CmtError> {
+    if matrix.shape() != (NS, NS) {
+        Err(CmtError::ErrorString(format!("Stray-light: matrix should have {NS} rows and columns")))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod stray_light_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+    use nalgebra::DMatrix;
+
+    #[test]
+    fn test_read_write() {
+        let sdf = DMatrix::from_fn(NS, NS, |i, j| if i.abs_diff(j) > 10 { 2E-5 * (1.0 + (i % 7) as f64) } else { 0.0 });
+        let correction = StrayLightCorrection::from_sdf(&sdf).unwrap();
+        let read = StrayLightCorrection::read(&correction.write()).unwrap();
+        assert_abs_diff_eq!(read.matrix(), correction.matrix(), epsilon = 1E-12);
+
+        assert!(StrayLightCorrection::read("1 2 3").is_err());
+        assert!(StrayLightCorrection::from_matrix(DMatrix::identity(3, 3)).is_err());
+    }
+}
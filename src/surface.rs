@@ -0,0 +1,161 @@
+/*!
+# Gloss-Aware Surface Model
+
+Colorants in this library describe the diffuse reflection of a surface, as measured with a
+geometry which excludes, or includes, the specular reflection. The appearance of a glossy
+surface however depends on the geometry of illumination and viewing: close to the mirror
+direction, the neutral specular reflection of the surface adds to the light reflected by the
+colorant, which reduces the saturation of its color.
+
+A [`Surface`] combines a diffuse, Lambertian, colorant with a neutral specular reflection, using
+Schlick's approximation of the Fresnel reflectance, and a Beckmann distribution of microfacets,
+with a roughness parameter, without shadowing, and masking. It is a minimal model, for gloss aware
+color previews, and not a full bidirectional reflectance distribution function.
+
+The surface is characterized by its radiance factor for a [`SurfaceGeometry`], the ratio of its
+radiance to the radiance of a perfect diffuser, with the same illumination.
+```
+use colorimetry::prelude::*;
+
+let red = Surface::new(Colorant::gaussian(620.0, 40.0)).set_roughness(0.1);
+// seen at 45º from the normal, illuminated from the opposite side, at the mirror angle
+let mirror = red.xyz(&CIE1931, &StdIlluminant::D65, SurfaceGeometry::new(45.0, 45.0, 180.0));
+// seen from the same side as the light
+let off = red.xyz(&CIE1931, &StdIlluminant::D65, SurfaceGeometry::new(45.0, 45.0, 0.0));
+assert!(mirror.luminous_value() > 2.0 * off.luminous_value());
+```
+*/
+
+use nalgebra::Vector3;
+
+use crate::{
+    colorant::Colorant,
+    math,
+    observer::ObserverData,
+    spectrum::Spectrum,
+    stimulus::Stimulus,
+    traits::Light,
+    xyz::XYZ,
+};
+
+/// Directions of illumination, and viewing, of a surface.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SurfaceGeometry {
+    /// Angle of incidence, from the surface normal, in degrees.
+    pub incidence: f64,
+    /// Viewing angle, from the surface normal, in degrees.
+    pub viewing: f64,
+    /// Azimuth of the viewing direction relative to the direction of the light, in degrees, with
+    /// the mirror direction at an azimuth of 180º.
+    pub azimuth: f64,
+}
+
+impl SurfaceGeometry {
+    pub fn new(incidence_deg: f64, viewing_deg: f64, azimuth_deg: f64) -> Self {
+        Self { incidence: incidence_deg, viewing: viewing_deg, azimuth: azimuth_deg }
+    }
+
+    /// Unit vectors pointing to the light, and to the viewer, with the surface normal along the
+    /// z-axis.
+    fn directions(&self) -> (Vector3<f64>, Vector3<f64>) {
+        let (ti, tv, phi) = (self.incidence.to_radians(), self.viewing.to_radians(), self.azimuth.to_radians());
+        let light = Vector3::new(math::sin(ti), 0.0, math::cos(ti));
+        let view = Vector3::new(math::sin(tv) * math::cos(phi), math::sin(tv) * math::sin(phi), math::cos(tv));
+        (light, view)
+    }
+}
+
+/// A surface with a diffuse colorant, and a neutral specular reflection.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Surface {
+    diffuse: Colorant,
+    specular: f64,
+    roughness: f64,
+}
+
+impl Surface {
+    /// Surface with a diffuse colorant, measured without its specular reflection, and a
+    /// specular reflectance of 0.04 at normal incidence, as for a surface with a refractive
+    /// index of 1.5, and a roughness of 0.3.
+    pub fn new(diffuse: Colorant) -> Self {
+        Self { diffuse, specular: 0.04, roughness: 0.3 }
+    }
+
+    /// Sets the specular reflectance at normal incidence, from 0.0 to 1.0, with a value of 0.0
+    /// giving a Lambertian surface.
+    pub fn set_specular(mut self, f0: f64) -> Self {
+        self.specular = f0.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the roughness, as the root mean square slope of the microfacets, with small values
+    /// for glossy surfaces, and values approaching one for matte surfaces.
+    pub fn set_roughness(mut self, roughness: f64) -> Self {
+        self.roughness = roughness.max(1E-3);
+        self
+    }
+
+    /// Schlick approximation of the Fresnel reflectance, for the cosine of the angle of incidence.
+    fn fresnel(&self, cos_theta: f64) -> f64 {
+        if self.specular == 0.0 {
+            return 0.0;
+        }
+        self.specular + (1.0 - self.specular) * (1.0 - cos_theta.clamp(0.0, 1.0)).powi(5)
+    }
+
+    /// Radiance factor of the surface for an illumination, and viewing, geometry; outside
+    /// the mirror direction values are below those of the colorant, but close to it they can be
+    /// much larger than one.
+    pub fn radiance_factor(&self, geometry: SurfaceGeometry) -> Spectrum {
+        let (light, view) = geometry.directions();
+        let (cos_i, cos_v) = (light.z, view.z);
+        if cos_i <= 0.0 || cos_v <= 0.0 {
+            return Spectrum::default();
+        }
+        let half = (light + view).normalize();
+        let cos_h = half.z;
+        let m2 = self.roughness * self.roughness;
+        let tan2_h = (1.0 - cos_h * cos_h) / (cos_h * cos_h);
+        let beckmann = math::exp(-tan2_h / m2) / (std::f64::consts::PI * m2 * cos_h.powi(4));
+        let specular = std::f64::consts::PI * self.fresnel(light.dot(&half)) * beckmann / (4.0 * cos_i * cos_v);
+        let body = 1.0 - self.fresnel(cos_i);
+        Spectrum(self.diffuse.0 .0.map(|r| body * r + specular))
+    }
+
+    /// Spectral radiance of the surface, illuminated by a light, relative to the radiance of a
+    /// perfect diffuser.
+    pub fn stimulus(&self, light: &dyn Light, geometry: SurfaceGeometry) -> Stimulus {
+        Stimulus(Spectrum(light.spectrum().0.component_mul(&self.radiance_factor(geometry).0)))
+    }
+
+    /// Tristimulus values of the surface, with a perfect diffuser, illuminated by the same light,
+    /// as white reference, with a luminous value of 100.
+    pub fn xyz(&self, observer: &ObserverData, light: &dyn Light, geometry: SurfaceGeometry) -> XYZ {
+        observer.xyz(light, Some(&Colorant(self.radiance_factor(geometry))))
+    }
+}
+
+#[cfg(test)]
+mod surface_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_surface() {
+        let gray = Surface::new(Colorant::gray(0.5)).set_specular(0.0);
+        // without specular reflection the surface is Lambertian
+        for g in [SurfaceGeometry::new(0.0, 45.0, 0.0), SurfaceGeometry::new(30.0, 30.0, 180.0)] {
+            assert_abs_diff_eq!(gray.radiance_factor(g)[550], 0.5, epsilon = 1E-12);
+        }
+        // gloss desaturates a colored surface in the mirror direction
+        let red = Surface::new(Colorant::gaussian(620.0, 30.0)).set_roughness(0.1);
+        let chroma = |g| CieLab::try_from(red.xyz(&CIE1931, &StdIlluminant::D65, g)).unwrap().chroma();
+        let mirror = SurfaceGeometry::new(30.0, 30.0, 180.0);
+        let diffuse = SurfaceGeometry::new(30.0, 0.0, 0.0);
+        assert!(chroma(mirror) < chroma(diffuse));
+        let s = red.stimulus(&StdIlluminant::D65, mirror);
+        assert!(s[450] > 0.0);
+        // no light from below the surface
+        assert_eq!(red.radiance_factor(SurfaceGeometry::new(95.0, 0.0, 0.0))[550], 0.0);
+    }
+}
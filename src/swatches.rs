@@ -0,0 +1,102 @@
+/*!
+# Rendered Swatches
+
+Renders samples, as seen under a light source, and as seen under the reference illuminant used in
+the CIE 13.3 color rendering index calculation, as pairs of RGB display values, for side-by-side
+visual comparisons of the color rendering properties of lamps.
+
+An observer adapts to the white point of the light source, and the samples are chromatically
+adapted, using a von Kries transform in the CAT16 cone space, from the white of the light source,
+or its reference, to the white of the display's color space.
+Samples outside the gamut of the display are mapped into it, by reducing their chroma, towards
+a gray of the same luminance, while keeping their hue.
+*/
+
+use nalgebra::Vector3;
+
+use crate::{
+    cat::Cat,
+    colorant::Colorant,
+    cri::reference_illuminant,
+    data::observers::CIE1931,
+    error::CmtError,
+    illuminant::Illuminant,
+    rgb::RGB,
+    rgbspace::RgbSpace,
+    xyz::XYZ,
+};
+
+/// A sample rendered as seen under a test light source, and under its reference illuminant.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Swatch {
+    pub test: RGB,
+    pub reference: RGB,
+}
+
+/**
+Gamut-mapped RGB swatches of samples, as seen under a light source, and under its CIE 13.3
+reference illuminant, for display in an RGB color space.
+
+A light source, and its reference illuminant, are both rendered with the same luminance for a
+perfect white sample, which is mapped to the white of the display.
+```
+use colorimetry::prelude::*;
+
+let samples = [Colorant::gray(0.5), Colorant::gaussian(620.0, 30.0)];
+let swatches = render_swatches(&Illuminant::planckian(2700.0), &samples, RgbSpace::SRGB).unwrap();
+
+// the reference for a Planckian source is the source itself
+let [r, g, b]: [u8; 3] = swatches[1].test.into();
+assert_eq!([r, g, b], <[u8; 3]>::from(swatches[1].reference));
+assert!(r > g && r > b);
+```
+*/
+pub fn render_swatches(illuminant: &Illuminant, samples: &[Colorant], space: RgbSpace) -> Result<Vec<Swatch>, CmtError> {
+    let reference = reference_illuminant(CIE1931.xyz(illuminant, None))?;
+    let display_white = CIE1931.xyz_cie_table(&space.data().0.white, Some(100.0)).xyzn;
+    let test = rendered(illuminant, samples, space, display_white);
+    let reference = rendered(&reference, samples, space, display_white);
+    Ok(test.into_iter().zip(reference).map(|(test, reference)| Swatch { test, reference }).collect())
+}
+
+/// Samples illuminated by a light source, adapted to the display white, and mapped into the
+/// display's gamut.
+fn rendered(light: &Illuminant, samples: &[Colorant], space: RgbSpace, display_white: Vector3<f64>) -> Vec<RGB> {
+    let white = CIE1931.xyz(light, None).set_illuminance(100.0).xyzn;
+    let adapt = Cat::Cat16.matrix(white, display_white);
+    samples
+        .iter()
+        .map(|sample| {
+            let xyz = CIE1931.xyz(light, Some(sample)).set_illuminance(100.0).xyz.unwrap_or(white);
+            let adapted = XYZ::from_vecs(display_white, Some(adapt * xyz), CIE1931.tag);
+            adapted.rgb(Some(space)).map_into_gamut()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod swatches_test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_render_swatches() {
+        let samples = [Colorant::white(), Colorant::gaussian(450.0, 5.0)];
+        let swatches = render_swatches(&Illuminant::d65(), &samples, RgbSpace::SRGB).unwrap();
+        assert_eq!(swatches.len(), 2);
+
+        // a white sample is rendered as the display white
+        approx::assert_abs_diff_eq!(swatches[0].test.as_ref(), &nalgebra::Vector3::repeat(1.0), epsilon = 1E-6);
+
+        // a saturated blue sample is out of the sRGB gamut, and mapped into it
+        assert!(swatches[1].test.is_in_gamut());
+        let [r, g, b]: [u8; 3] = swatches[1].test.into();
+        assert!(b > r && b > g);
+
+        // a narrow band source renders a red sample quite differently from its reference
+        let led = Illuminant(*Illuminant::led(450.0, 20.0) + *Illuminant::led(555.0, 60.0) * 2.5);
+        let red = render_swatches(&led, &[Colorant::gaussian(640.0, 20.0)], RgbSpace::SRGB).unwrap();
+        let [r, _, _]: [u8; 3] = red[0].test.into();
+        let [rr, _, _]: [u8; 3] = red[0].reference.into();
+        assert!(rr > r);
+    }
+}
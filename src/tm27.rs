@@ -0,0 +1,286 @@
+/*!
+# IES TM-27-14 Spectral Data Files
+
+Reading and writing of the IES TM-27-14 XML format for the exchange of spectral data, also known
+as SPDX, as used by manufacturers of lamps and luminaires, and by spectroradiometer software.
+
+A TM-27-14 document has a header, describing the product and the measurement, and a spectral
+distribution, with the spectral quantity, such as `"irradiance"`, or `"relative"`, and the spectral
+values, each with its wavelength, in nanometer, as an attribute:
+```xml
+<IESTM2714 xmlns="iestm2714" version="1.0">
+  <Header>
+    <Manufacturer>Acme</Manufacturer>
+    <Description>3000K LED</Description>
+    ...
+  </Header>
+  <SpectralDistribution>
+    <SpectralQuantity>relative</SpectralQuantity>
+    <SpectralData wavelength="380">0.012</SpectralData>
+    ...
+  </SpectralDistribution>
+</IESTM2714>
+```
+The data can have any wavelength range, and step size, and is linearly interpolated onto the 380
+to 780 nanometer domain of this library when read as an [`Illuminant`].
+```
+use colorimetry::prelude::*;
+
+let header = Tm27Header { manufacturer: "Acme".into(), description: "3000K blackbody".into(), ..Default::default() };
+let xml = Illuminant::planckian(3000.0).to_tm27(&header);
+let tm27 = Tm27::parse(&xml).unwrap();
+assert_eq!(tm27.header.description, "3000K blackbody");
+let lamp = Illuminant::from_tm27(&xml).unwrap();
+approx::assert_abs_diff_eq!(lamp.spectrum()[560], Illuminant::planckian(3000.0).spectrum()[560], epsilon = 1E-9);
+```
+This is not a general XML parser: comments, CDATA sections, and entities other than the five
+predefined XML entities, are not supported.
+*/
+
+use crate::{
+    error::CmtError,
+    illuminant::Illuminant,
+    spectrum::Spectrum,
+};
+
+/// Header of a TM-27-14 document, with all fields as text, and empty if not present.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Tm27Header {
+    pub manufacturer: String,
+    pub catalog_number: String,
+    pub description: String,
+    pub file_creator: String,
+    pub laboratory: String,
+    pub unique_identifier: String,
+    pub report_number: String,
+    pub report_date: String,
+    pub document_creation_date: String,
+    pub comments: String,
+}
+
+/// Parsed contents of a TM-27-14 document.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Tm27 {
+    pub header: Tm27Header,
+    /// Spectral quantity, such as `"flux"`, `"irradiance"`, `"reflectance"`, or `"relative"`.
+    pub spectral_quantity: String,
+    /// Bandwidth of the instrument, full width at half maximum, in nanometer, if given.
+    pub bandwidth_fwhm: Option<f64>,
+    /// Wavelengths, in nanometer, in increasing order.
+    pub wavelengths: Vec<f64>,
+    pub values: Vec<f64>,
+}
+
+impl Tm27 {
+    /// Parses a TM-27-14 document.
+    pub fn parse(xml: &str) -> Result<Self, CmtError> {
+        if element(xml, "IESTM2714").is_none() {
+            return Err(CmtError::ParseError("TM-27: no IESTM2714 element".into()));
+        }
+        let header = element(xml, "Header").map(|(_, h)| h).unwrap_or_default();
+        let text = |tag: &str| element(header, tag).map(|(_, t)| unescape(t.trim())).unwrap_or_default();
+        let header = Tm27Header {
+            manufacturer: text("Manufacturer"),
+            catalog_number: text("CatalogNumber"),
+            description: text("Description"),
+            file_creator: text("FileCreator"),
+            laboratory: text("Laboratory"),
+            unique_identifier: text("UniqueIdentifier"),
+            report_number: text("ReportNumber"),
+            report_date: text("ReportDate"),
+            document_creation_date: text("DocumentCreationDate"),
+            comments: text("Comments"),
+        };
+        let (_, sd) = element(xml, "SpectralDistribution")
+            .ok_or_else(|| CmtError::ParseError("TM-27: no SpectralDistribution element".into()))?;
+        let spectral_quantity = element(sd, "SpectralQuantity").map(|(_, q)| unescape(q.trim())).unwrap_or_default();
+        let bandwidth_fwhm = element(sd, "BandwidthFWHM").and_then(|(_, b)| b.trim().parse().ok());
+
+        let mut data: Vec<(f64, f64)> = Vec::new();
+        let mut rest = sd;
+        while let Some((attributes, value, end)) = next_element(rest, "SpectralData") {
+            rest = &rest[end..];
+            let wavelength = attribute(attributes, "wavelength")
+                .and_then(|w| w.trim().parse::<f64>().ok())
+                .ok_or_else(|| CmtError::ParseError("TM-27: SpectralData without a valid wavelength".into()))?;
+            let value = value.trim().parse::<f64>()
+                .map_err(|_| CmtError::ParseError(format!("TM-27: invalid spectral value at {wavelength} nm")))?;
+            data.push((wavelength, value));
+        }
+        if data.len() < 2 {
+            return Err(CmtError::ProvideAtLeastNValues(2));
+        }
+        data.sort_by(|a, b| a.0.total_cmp(&b.0));
+        if data.windows(2).any(|w| w[0].0 == w[1].0) {
+            return Err(CmtError::ParseError("TM-27: duplicate wavelengths".into()));
+        }
+        let (wavelengths, values) = data.into_iter().unzip();
+        Ok(Self { header, spectral_quantity, bandwidth_fwhm, wavelengths, values })
+    }
+
+    /// The spectral data, linearly interpolated onto the 380 to 780 nanometer domain, with its end
+    /// values used outside the range of the data.
+    pub fn spectrum(&self) -> Result<Spectrum, CmtError> {
+        Spectrum::try_linear_interpolate(&self.wavelengths, &self.values)
+    }
+
+    /// Writes the document, with the spectral values in full precision.
+    pub fn write(&self) -> String {
+        let h = &self.header;
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<IESTM2714 xmlns=\"iestm2714\" version=\"1.0\">\n  <Header>\n");
+        for (tag, value) in [
+            ("Manufacturer", &h.manufacturer),
+            ("CatalogNumber", &h.catalog_number),
+            ("Description", &h.description),
+            ("FileCreator", &h.file_creator),
+            ("Laboratory", &h.laboratory),
+            ("UniqueIdentifier", &h.unique_identifier),
+            ("ReportNumber", &h.report_number),
+            ("ReportDate", &h.report_date),
+            ("DocumentCreationDate", &h.document_creation_date),
+            ("Comments", &h.comments),
+        ] {
+            xml.push_str(&format!("    <{tag}>{}</{tag}>\n", escape(value)));
+        }
+        xml.push_str("  </Header>\n  <SpectralDistribution>\n");
+        xml.push_str(&format!("    <SpectralQuantity>{}</SpectralQuantity>\n", escape(&self.spectral_quantity)));
+        if let Some(b) = self.bandwidth_fwhm {
+            xml.push_str(&format!("    <BandwidthFWHM>{b}</BandwidthFWHM>\n"));
+        }
+        for (l, v) in self.wavelengths.iter().zip(self.values.iter()) {
+            xml.push_str(&format!("    <SpectralData wavelength=\"{l}\">{v}</SpectralData>\n"));
+        }
+        xml.push_str("  </SpectralDistribution>\n</IESTM2714>\n");
+        xml
+    }
+}
+
+impl Illuminant {
+    /// Illuminant from a TM-27-14 document, using its spectral data as spectral irradiance.
+    pub fn from_tm27(xml: &str) -> Result<Self, CmtError> {
+        Ok(Illuminant(Tm27::parse(xml)?.spectrum()?))
+    }
+
+    /// TM-27-14 document, with the spectral irradiance of this illuminant, in W/m²/nm, from 380 to
+    /// 780 nanometer, in 1 nanometer steps.
+    pub fn to_tm27(&self, header: &Tm27Header) -> String {
+        Tm27 {
+            header: header.clone(),
+            spectral_quantity: "irradiance".into(),
+            bandwidth_fwhm: None,
+            wavelengths: (380..=780).map(f64::from).collect(),
+            values: self.0 .0.iter().copied().collect(),
+        }
+        .write()
+    }
+}
+
+/// Attributes, and content, of the first element with a tag name; the content of an empty
+/// element is an empty string.
+fn element<'a>(xml: &'a str, tag: &str) -> Option<(&'a str, &'a str)> {
+    next_element(xml, tag).map(|(attributes, content, _)| (attributes, content))
+}
+
+/// Attributes, content, and end position, of the first element with a tag name.
+fn next_element<'a>(xml: &'a str, tag: &str) -> Option<(&'a str, &'a str, usize)> {
+    let mut from = 0;
+    loop {
+        let start = from + xml[from..].find(&format!("<{tag}"))?;
+        let offset = start + 1 + tag.len();
+        let after = &xml[offset..];
+        from = start + 1;
+        // skip elements with a longer name, starting with this tag name
+        if !after.starts_with(|c: char| c == '>' || c == '/' || c.is_whitespace()) {
+            continue;
+        }
+        let end = after.find('>')?;
+        if after[..end].ends_with('/') {
+            return Some((&after[..end - 1], "", offset + end + 1));
+        }
+        let close_tag = format!("</{tag}>");
+        let close = after[end + 1..].find(&close_tag)?;
+        return Some((&after[..end], &after[end + 1..end + 1 + close], offset + end + 1 + close + close_tag.len()));
+    }
+}
+
+/// Value of an attribute, in single, or double, quotes.
+fn attribute<'a>(attributes: &'a str, name: &str) -> Option<&'a str> {
+    let mut rest = attributes;
+    while let Some(i) = rest.find(name) {
+        let before = rest[..i].chars().last();
+        let after = rest[i + name.len()..].trim_start();
+        rest = &rest[i + name.len()..];
+        if before.is_some_and(|c| !c.is_whitespace()) {
+            continue;
+        }
+        let Some(value) = after.strip_prefix('=').map(str::trim_start) else { continue };
+        let quote = value.chars().next()?;
+        if quote == '"' || quote == '\'' {
+            return value[1..].find(quote).map(|end| &value[1..1 + end]);
+        }
+    }
+    None
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;").replace('\'', "&apos;")
+}
+
+fn unescape(s: &str) -> String {
+    s.replace("&lt;", "<").replace("&gt;", ">").replace("&quot;", "\"").replace("&apos;", "'").replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tm27_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    const XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<IESTM2714 xmlns="iestm2714" version="1.0">
+  <Header>
+    <Manufacturer>Lamps &amp; Co</Manufacturer>
+    <CatalogNumber>L-3000</CatalogNumber>
+    <Description>Warm white LED</Description>
+    <Laboratory/>
+  </Header>
+  <SpectralDistribution>
+    <SpectralQuantity>relative</SpectralQuantity>
+    <BandwidthFWHM>2.5</BandwidthFWHM>
+    <BandwidthCorrected>true</BandwidthCorrected>
+    <SpectralData wavelength="360">0.0</SpectralData>
+    <SpectralData wavelength="560.0">1.0</SpectralData>
+    <SpectralData wavelength='830'>0.5</SpectralData>
+  </SpectralDistribution>
+</IESTM2714>"#;
+
+    #[test]
+    fn test_parse() {
+        let tm27 = Tm27::parse(XML).unwrap();
+        assert_eq!(tm27.header.manufacturer, "Lamps & Co");
+        assert_eq!(tm27.header.catalog_number, "L-3000");
+        assert_eq!(tm27.header.laboratory, "");
+        assert_eq!(tm27.spectral_quantity, "relative");
+        assert_eq!(tm27.bandwidth_fwhm, Some(2.5));
+        assert_eq!(tm27.wavelengths, [360.0, 560.0, 830.0]);
+        assert_eq!(tm27.values, [0.0, 1.0, 0.5]);
+
+        let lamp = Illuminant::from_tm27(XML).unwrap();
+        assert_abs_diff_eq!(lamp.spectrum()[460], 0.5, epsilon = 1E-12);
+        assert_abs_diff_eq!(lamp.spectrum()[560], 1.0, epsilon = 1E-12);
+
+        assert!(Tm27::parse("<Other/>").is_err());
+        assert!(Tm27::parse(&XML.replace("560.0", "abc")).is_err());
+    }
+
+    #[test]
+    fn test_write() {
+        let tm27 = Tm27::parse(XML).unwrap();
+        let written = tm27.write();
+        assert!(written.contains("<Manufacturer>Lamps &amp; Co</Manufacturer>"));
+        assert_eq!(Tm27::parse(&written).unwrap(), tm27);
+
+        let d65 = Illuminant::d65();
+        let back = Illuminant::from_tm27(&d65.to_tm27(&Tm27Header::default())).unwrap();
+        assert_abs_diff_eq!(back.spectrum().as_ref(), d65.spectrum().as_ref(), epsilon = 1E-12);
+    }
+}
@@ -0,0 +1,228 @@
+/*!
+# ANSI/IES TM-30-20 Color Rendition Metrics
+
+ANSI/IES TM-30-20 extends the CIE 224:2017 fidelity index Rf with a gamut index Rg, and hue angle
+bin based local metrics, calculated from the same 99 Color Evaluation Samples, reference
+illuminant, and CAM02-UCS color differences, as implemented in the [`cfi`](crate::cfi) module.
+
+The samples are grouped in 16 hue angle bins, of 22.5º each, by their hue angle under the reference
+illuminant, and their average a' and b' values in each bin, for the test source and for the
+reference illuminant, define the corners of two polygons:
+- the gamut index Rg is the ratio of the areas of these polygons, times 100;
+- the local chroma shifts Rcs,hj, and hue shifts Rhs,hj, describe the shift of the test corner
+  relative to the reference corner, and are shown in the color vector graphic;
+- the local fidelity indices Rf,hj are obtained from the mean color difference of the samples in
+  each bin.
+
+As for [`Rf`](crate::cfi::Rf), the 99 Color Evaluation Samples have to be registered with
+[`set_ces_samples`](crate::cfi::set_ces_samples), or a sample set covering all hue angle bins has
+to be provided, such as the [`synthetic_hue_bin_samples`] used in the examples below.
+As the Color Evaluation Samples are not included, Rg, and the local metrics, have not been
+validated against the values published in ANSI/IES TM-30-20.
+```
+use colorimetry::prelude::*;
+
+let samples = synthetic_hue_bin_samples();
+let tm30 = TM30::try_new_with_samples(&Illuminant::planckian(3000.0), &samples).unwrap();
+approx::assert_abs_diff_eq!(tm30.rf(), 100.0, epsilon = 1E-3);
+approx::assert_abs_diff_eq!(tm30.rg(), 100.0, epsilon = 1E-3);
+```
+
+# References
+- ANSI/IES TM-30-20, IES Method for Evaluating Light Source Color Rendition
+*/
+
+use std::f64::consts::PI;
+
+use nalgebra::Vector3;
+
+use crate::{
+    cfi::{cam02_ucs_samples, ces_samples, fidelity, Rf},
+    colorant::Colorant,
+    error::CmtError,
+    illuminant::Illuminant,
+    math,
+};
+
+/// Number of hue angle bins.
+pub const N_HUE_BINS: usize = 16;
+
+/// TM-30-20 fidelity, and gamut, indices, and their hue angle bin based local values.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TM30 {
+    rf: Rf,
+    rg: f64,
+    test: [[f64; 2]; N_HUE_BINS],
+    reference: [[f64; 2]; N_HUE_BINS],
+    local_rf: [f64; N_HUE_BINS],
+}
+
+impl TM30 {
+    pub fn try_new(s: &Illuminant) -> Result<Self, CmtError> {
+        s.try_into()
+    }
+
+    /// TM-30 metrics for a set of samples, other than the Color Evaluation Samples, which has to
+    /// include samples in each of the hue angle bins.
+    pub fn try_new_with_samples(s: &Illuminant, samples: &[Colorant]) -> Result<Self, CmtError> {
+        let rf = Rf::try_new_with_samples(s, samples)?;
+        let (jab, jab_ref) = cam02_ucs_samples(s, samples)?;
+
+        let mut sums = [(Vector3::<f64>::zeros(), Vector3::<f64>::zeros(), 0.0, 0usize); N_HUE_BINS];
+        for (t, r) in jab.iter().zip(jab_ref.iter()) {
+            let h = math::atan2(r.z, r.y).rem_euclid(2.0 * PI);
+            let bin = ((h / (2.0 * PI) * N_HUE_BINS as f64) as usize).min(N_HUE_BINS - 1);
+            let (st, sr, de, n) = &mut sums[bin];
+            *st += t;
+            *sr += r;
+            *de += (t - r).norm();
+            *n += 1;
+        }
+        if let Some(j) = sums.iter().position(|s| s.3 == 0) {
+            return Err(CmtError::ErrorString(format!("TM30: no samples in hue angle bin {}", j + 1)));
+        }
+        let test = sums.map(|(st, _, _, n)| [st.y / n as f64, st.z / n as f64]);
+        let reference = sums.map(|(_, sr, _, n)| [sr.y / n as f64, sr.z / n as f64]);
+        let local_rf = sums.map(|(_, _, de, n)| fidelity(de / n as f64));
+        let rg = 100.0 * polygon_area(&test) / polygon_area(&reference);
+        Ok(Self { rf, rg, test, reference, local_rf })
+    }
+
+    /// Fidelity index Rf.
+    pub fn rf(&self) -> f64 {
+        self.rf.rf()
+    }
+
+    /// Gamut index Rg.
+    pub fn rg(&self) -> f64 {
+        self.rg
+    }
+
+    /// Special fidelity index of the sample at position `i`, counting from 0, as [`Rf::rfi`].
+    pub fn rfi(&self, i: usize) -> Option<f64> {
+        self.rf.rfi(i)
+    }
+
+    /// Local fidelity indices Rf,hj, for the 16 hue angle bins.
+    pub fn local_rf(&self) -> [f64; N_HUE_BINS] {
+        self.local_rf
+    }
+
+    /// Local chroma shifts Rcs,hj, as fractions of the reference chroma, for the 16 hue angle
+    /// bins.
+    pub fn chroma_shift(&self) -> [f64; N_HUE_BINS] {
+        std::array::from_fn(|j| {
+            let [at, bt] = self.test[j];
+            let [ar, br] = self.reference[j];
+            (math::hypot(at, bt) - math::hypot(ar, br)) / math::hypot(ar, br)
+        })
+    }
+
+    /// Local hue shifts Rhs,hj, in radians, for the 16 hue angle bins.
+    pub fn hue_shift(&self) -> [f64; N_HUE_BINS] {
+        std::array::from_fn(|j| {
+            let [at, bt] = self.test[j];
+            let [ar, br] = self.reference[j];
+            let d = math::atan2(bt, at) - math::atan2(br, ar);
+            (d + PI).rem_euclid(2.0 * PI) - PI
+        })
+    }
+
+    /// Average a' and b' values, for the test source, and for the reference illuminant, in the 16
+    /// hue angle bins.
+    pub fn bin_averages(&self) -> ([[f64; 2]; N_HUE_BINS], [[f64; 2]; N_HUE_BINS]) {
+        (self.test, self.reference)
+    }
+
+    /**
+    Coordinates of the test source corners in the color vector graphic, in which the reference
+    corners are on a unit circle, at the center angles of the hue bins.
+    ```
+    use colorimetry::prelude::*;
+
+    # let samples = synthetic_hue_bin_samples();
+    let tm30 = TM30::try_new_with_samples(StdIlluminant::F2.illuminant(), &samples).unwrap();
+    for (j, [x, y]) in tm30.vector_graphic().into_iter().enumerate() {
+        // the distance of the corner to the unit circle is the local chroma shift
+        approx::assert_abs_diff_eq!(x.hypot(y) - 1.0, tm30.chroma_shift()[j], epsilon = 0.02);
+    }
+    ```
+    */
+    pub fn vector_graphic(&self) -> [[f64; 2]; N_HUE_BINS] {
+        std::array::from_fn(|j| {
+            let [at, bt] = self.test[j];
+            let [ar, br] = self.reference[j];
+            let cr = math::hypot(ar, br);
+            let h = (j as f64 + 0.5) * 2.0 * PI / N_HUE_BINS as f64;
+            [(at - ar) / cr + math::cos(h), (bt - br) / cr + math::sin(h)]
+        })
+    }
+}
+
+/// TM-30 calculation.
+///
+/// Requires the Color Evaluation Samples to be registered with
+/// [`set_ces_samples`](crate::cfi::set_ces_samples).
+impl TryFrom<&Illuminant> for TM30 {
+    type Error = CmtError;
+
+    fn try_from(illuminant: &Illuminant) -> Result<Self, Self::Error> {
+        let samples = ces_samples().ok_or_else(|| {
+            CmtError::ErrorString("TM30: the CIE 224 Color Evaluation Samples have not been set".into())
+        })?;
+        Self::try_new_with_samples(illuminant, samples)
+    }
+}
+
+/// A synthetic set of 49 samples, with Gaussian reflectance spectra, and mixtures of a blue and a
+/// red Gaussian spectrum for the purple hues, covering all hue angle bins, for examples and tests,
+/// in the absence of the Color Evaluation Samples.
+pub fn synthetic_hue_bin_samples() -> Vec<Colorant> {
+    (0..32)
+        .map(|i| Colorant::gaussian(400.0 + 10.0 * i as f64, 30.0))
+        .chain((0..=16).map(|i| Colorant::gaussian(440.0, 25.0) * (i as f64 / 16.0) + Colorant::gaussian(640.0, 40.0) * (1.0 - i as f64 / 16.0)))
+        .collect()
+}
+
+/// Area of a polygon, using the shoelace formula.
+fn polygon_area(corners: &[[f64; 2]]) -> f64 {
+    let n = corners.len();
+    (0..n)
+        .map(|i| {
+            let ([x0, y0], [x1, y1]) = (corners[i], corners[(i + 1) % n]);
+            x0 * y1 - x1 * y0
+        })
+        .sum::<f64>()
+        .abs()
+        / 2.0
+}
+
+#[cfg(test)]
+mod tm30_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_tm30() {
+        let samples = synthetic_hue_bin_samples();
+        let tm30 = TM30::try_new_with_samples(&Illuminant::planckian(3000.0), &samples).unwrap();
+        assert_abs_diff_eq!(tm30.local_rf().as_ref(), [100.0; N_HUE_BINS].as_ref(), epsilon = 1E-3);
+        assert_abs_diff_eq!(tm30.chroma_shift().as_ref(), [0.0; N_HUE_BINS].as_ref(), epsilon = 1E-6);
+        assert_abs_diff_eq!(tm30.hue_shift().as_ref(), [0.0; N_HUE_BINS].as_ref(), epsilon = 1E-6);
+
+        // a halophosphate fluorescent lamp has a low fidelity, and desaturates reds
+        #[cfg(feature = "cie-illuminants")]
+        {
+            let f2 = TM30::try_new_with_samples(StdIlluminant::F2.illuminant(), &samples).unwrap();
+            let rf = Rf::try_new_with_samples(StdIlluminant::F2.illuminant(), &samples).unwrap();
+            assert_abs_diff_eq!(f2.rf(), rf.rf());
+            assert!(f2.rf() < 80.0 && f2.rg() < 95.0);
+            assert!(f2.chroma_shift()[0] < -0.1);
+            assert!(f2.local_rf().iter().all(|&r| r <= 100.01));
+        }
+
+        // samples covering a part of the hue circle only
+        assert!(TM30::try_new_with_samples(&Illuminant::planckian(3000.0), &samples[..8]).is_err());
+        assert!(TM30::try_new(&Illuminant::planckian(3000.0)).is_err());
+    }
+}
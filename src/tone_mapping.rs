@@ -0,0 +1,206 @@
+/*!
+# Tone Mapping
+
+High dynamic range (HDR) content, with highlights up to thousands of cd/m², has to be tone mapped
+to be shown on a display with a lower peak luminance, such as a standard dynamic range (SDR)
+display with a peak of 100 cd/m².
+A [`ToneMapper`] compresses the luminance of tristimulus values with a [`ToneCurve`]:
+- the ITU-R BT.2390 EETF, an electrical-optical transfer function, which leaves the
+  lower part of the perceptual quantizer (PQ) range unchanged, and rolls off the highlights with
+  a Hermite spline;
+- the extended Reinhard operator;
+- a fit of the ACES filmic curve, by Krzysztof Narkowicz.
+
+To evaluate the colorimetric intent of a conversion, the curves are applied to the intensity of
+[`ICtCp`] values, or the lightness of [`OkLab`] values, with a proportional scaling of the color
+difference components, which preserves the hue; alternatively, the curve is applied to each of
+the linear BT.2020 RGB values, as done by simple tone mappers, which shifts the hue, and desaturates
+highlights.
+
+Tristimulus values are absolute, with Y as the luminance in cd/m².
+```
+use colorimetry::prelude::*;
+
+let mapper = ToneMapper::new(ToneCurve::Bt2390 { source_peak: 1000.0 }, 100.0);
+let highlight = XYZ::from_vecs([380.0, 400.0, 435.0].into(), None, Observer::Std1931);
+let mapped = mapper.map(&highlight);
+assert!(mapped.luminous_value() < 100.0);
+```
+*/
+
+use nalgebra::Vector3;
+
+use crate::{
+    data::observers::CIE1931,
+    math,
+    oklab::OkLab,
+    std_illuminants::StdIlluminant,
+    video::{pq, pq_inverse, xyz_to_bt2020, ICtCp},
+    xyz::XYZ,
+};
+
+/// Luminance, in cd/m², of the signal range of the perceptual quantizer.
+const PQ_PEAK: f64 = 10_000.0;
+
+/// Tone curve, mapping source luminance values to display luminance values.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ToneCurve {
+    /// ITU-R BT.2390 EETF, for content mastered with a peak luminance `source_peak`, in cd/m².
+    Bt2390 { source_peak: f64 },
+    /// Extended Reinhard operator, mapping a luminance `white`, in cd/m², to the display peak.
+    Reinhard { white: f64 },
+    /// Narkowicz fit of the ACES filmic curve, with luminance values relative to the display
+    /// peak.
+    AcesFit,
+}
+
+impl ToneCurve {
+    /// Display luminance, for a source luminance, both in cd/m², and a display peak luminance.
+    pub fn apply(&self, luminance: f64, target_peak: f64) -> f64 {
+        let l = luminance.max(0.0);
+        match *self {
+            ToneCurve::Bt2390 { source_peak } => {
+                if source_peak <= target_peak {
+                    return l.min(source_peak);
+                }
+                let e_max = pq(source_peak / PQ_PEAK);
+                let e = pq(l / PQ_PEAK) / e_max;
+                let max_lum = pq(target_peak / PQ_PEAK) / e_max;
+                let ks = 1.5 * max_lum - 0.5;
+                let e = if e < ks {
+                    e
+                } else {
+                    let t = ((e - ks) / (1.0 - ks)).min(1.0);
+                    let (t2, t3) = (t * t, t * t * t);
+                    (2.0 * t3 - 3.0 * t2 + 1.0) * ks + (t3 - 2.0 * t2 + t) * (1.0 - ks) + (-2.0 * t3 + 3.0 * t2) * max_lum
+                };
+                pq_inverse(e * e_max) * PQ_PEAK
+            }
+            ToneCurve::Reinhard { white } => {
+                let (x, w) = (l / target_peak, white / target_peak);
+                (x * (1.0 + x / (w * w)) / (1.0 + x)).min(1.0) * target_peak
+            }
+            ToneCurve::AcesFit => {
+                let x = l / target_peak;
+                ((x * (2.51 * x + 0.03)) / (x * (2.43 * x + 0.59) + 0.14)).min(1.0) * target_peak
+            }
+        }
+    }
+}
+
+/// Color space in which a [`ToneMapper`] operates.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, strum_macros::Display)]
+pub enum ToneMapSpace {
+    /// Maps the PQ based intensity, with the BT.2390 chroma correction.
+    #[default]
+    ICtCp,
+    /// Maps the lightness, with the chroma scaled by the lightness ratio.
+    OkLab,
+    /// Maps each linear BT.2020 RGB value, without hue preservation.
+    PerChannel,
+}
+
+/// A tone mapping operator, for a display with a peak luminance.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ToneMapper {
+    curve: ToneCurve,
+    target_peak: f64,
+    space: ToneMapSpace,
+}
+
+impl ToneMapper {
+    /// Tone mapper for a display with a peak luminance `target_peak`, in cd/m², operating in
+    /// the ICtCp space.
+    pub fn new(curve: ToneCurve, target_peak: f64) -> Self {
+        Self { curve, target_peak, space: ToneMapSpace::default() }
+    }
+
+    pub fn set_space(mut self, space: ToneMapSpace) -> Self {
+        self.space = space;
+        self
+    }
+
+    /// Maps absolute tristimulus values, with the luminance in cd/m², of a stimulus, or, if it
+    /// has none, of its reference white.
+    pub fn map(&self, xyz: &XYZ) -> XYZ {
+        match xyz.xyz {
+            Some(v) => XYZ::from_vecs(xyz.xyzn, Some(self.map_values(v)), xyz.observer),
+            None => XYZ::from_vecs(self.map_values(xyz.xyzn), None, xyz.observer),
+        }
+    }
+
+    fn map_values(&self, xyz: Vector3<f64>) -> Vector3<f64> {
+        let curve = |l: f64| self.curve.apply(l, self.target_peak);
+        if xyz.y <= 0.0 {
+            return Vector3::zeros();
+        }
+        match self.space {
+            ToneMapSpace::ICtCp => {
+                let v = ICtCp::from_absolute(xyz);
+                let i = pq(curve(pq_inverse(v.i) * PQ_PEAK) / PQ_PEAK);
+                let s = if v.i > 0.0 { (i / v.i).min(v.i / i) } else { 1.0 };
+                ICtCp { i, ct: v.ct * s, cp: v.cp * s }.xyz_values(100.0).into()
+            }
+            ToneMapSpace::OkLab => {
+                // OKLab values relative to a D65 white at the display peak
+                let white = CIE1931.xyz(&StdIlluminant::D65, None);
+                let rel = xyz * (100.0 / self.target_peak);
+                let [l, a, b] = OkLab::try_from(XYZ::from_vecs(white.xyzn, Some(rel), CIE1931.tag))
+                    .unwrap()
+                    .values();
+                let l_out = math::cbrt(curve(l.powi(3) * self.target_peak) / self.target_peak);
+                let s = l_out / l;
+                Vector3::from(OkLab::new(l_out, a * s, b * s).xyz().values()) * (self.target_peak / 100.0)
+            }
+            ToneMapSpace::PerChannel => {
+                let m = xyz_to_bt2020();
+                m.try_inverse().unwrap() * (m * xyz).map(curve)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tone_mapping_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_tone_curves() {
+        let eetf = ToneCurve::Bt2390 { source_peak: 1000.0 };
+        // shadows are unchanged, the source peak is mapped to the display peak
+        assert_abs_diff_eq!(eetf.apply(1.0, 100.0), 1.0, epsilon = 1E-9);
+        assert_abs_diff_eq!(eetf.apply(1000.0, 100.0), 100.0, epsilon = 1E-6);
+        assert!(eetf.apply(500.0, 100.0) < 100.0 && eetf.apply(500.0, 100.0) > eetf.apply(200.0, 100.0));
+        assert_abs_diff_eq!(ToneCurve::Reinhard { white: 400.0 }.apply(400.0, 100.0), 100.0, epsilon = 1E-9);
+        assert!(ToneCurve::AcesFit.apply(1E4, 100.0) <= 100.0);
+    }
+
+    #[test]
+    fn test_tone_mapper() {
+        // a saturated highlight, from an sRGB red, at about 170 cd/m²
+        let red = RGB::from_u8(255, 40, 40, None, None).xyz().xyz.unwrap() * 8.0;
+        let red = XYZ::from_vecs(red, None, Observer::Std1931);
+        // hue, as the direction from the D65 white point in the chromaticity diagram
+        let hue = |xyz: &XYZ| {
+            let [x, y] = xyz.xy().values();
+            (y - 0.3290).atan2(x - 0.3127)
+        };
+        let hue_ictcp = |xyz: &XYZ| {
+            let v = ICtCp::from_absolute(xyz.xyzn);
+            v.cp.atan2(v.ct)
+        };
+        let curve = ToneCurve::Reinhard { white: 1000.0 };
+        let mapped = ToneMapper::new(curve, 100.0).map(&red);
+        assert!(mapped.luminous_value() < red.luminous_value());
+        assert_abs_diff_eq!(hue_ictcp(&mapped), hue_ictcp(&red), epsilon = 1E-6);
+        let mapped = ToneMapper::new(curve, 100.0).set_space(ToneMapSpace::OkLab).map(&red);
+        assert!(mapped.luminous_value() < red.luminous_value());
+        assert_abs_diff_eq!(hue(&mapped), hue(&red), epsilon = 1E-6);
+
+        // mapping each channel shifts the hue of highlights
+        let mapped = ToneMapper::new(curve, 100.0).set_space(ToneMapSpace::PerChannel).map(&red);
+        assert!(mapped.luminous_value() < red.luminous_value());
+        assert!((hue(&mapped) - hue(&red)).abs() > 0.1);
+    }
+}
@@ -0,0 +1,23 @@
+/*!
+Instrumentation macros for the `tracing` feature.
+
+With the feature, these emit [tracing](https://docs.rs/tracing) spans, and debug level events, in
+the iterative calculations of this library; without it, they expand to nothing, and their
+arguments are not evaluated.
+*/
+
+/// Enters a debug level span, until the end of the enclosing block.
+macro_rules! debug_span {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!($($arg)*).entered();
+    };
+}
+
+/// Emits a debug level event, with intermediate values of a calculation.
+macro_rules! debug_event {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "tracing")]
+        tracing::debug!($($arg)*);
+    };
+}
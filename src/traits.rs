@@ -37,6 +37,15 @@ impl From<&dyn Light> for Illuminant {
     }
 }
 
+/**
+Spectral representation of color samples and filters, with spectral reflectance factors, or
+transmittance values, in the range from 0.0 to 1.0.
+
+Filters and [`Light`]s represent different physical quantities, and are kept apart by their
+types. To use a filter as a light, or a light as a filter, use the explicit
+[`Colorant::into_illuminant`], and [`Illuminant::into_colorant`] conversions, which document
+their scaling assumptions.
+*/
 pub trait Filter {
 
     fn spectrum(&self) -> Cow<Spectrum>;
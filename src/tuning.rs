@@ -0,0 +1,333 @@
+/*!
+# Spectral Tuning
+
+Optimization of the channel weights of multi-primary light sources, such as LED modules with
+several types of LEDs, to maximize a color rendition index, such as R9, for a fixed correlated
+color temperature, and a fixed light output.
+
+A [`SpectralTuner`] holds the spectral distributions of the channels, at full drive, and the
+target: a chromaticity on the Planckian locus, for a correlated color temperature, and an
+illuminance, or, for spectra given as spectral radiant flux, a luminous flux.
+A feasible set of weights is found as the non-negative least squares solution for the target
+tristimulus values; this is then improved by a pattern search, with steps constrained to keep
+the tristimulus values of the mix, and the weights non-negative.
+Any [`RenditionMetric`] can be optimized, using its general index, or one of its special indices.
+The trade-off between the luminous efficacy of radiation of the mix and a metric, such as a
+fidelity index, is explored with [`SpectralTuner::pareto`].
+*/
+
+use nalgebra::{DMatrix, DVector};
+
+use crate::{
+    error::CmtError,
+    illuminant::Illuminant,
+    observer::Observer,
+    rendition::RenditionMetric,
+    spectrum::{Spectrum, NS},
+};
+
+/// Maximum number of metric evaluations in an optimization.
+const MAX_EVALUATIONS: usize = 5000;
+
+/// A light source mix, with its channel weights, and the value of the optimized metric.
+#[derive(Clone)]
+pub struct TunedSource {
+    pub weights: Vec<f64>,
+    pub illuminant: Illuminant,
+    pub value: f64,
+}
+
+/// A mix on the Pareto front of luminous efficacy of radiation, and a color rendition metric.
+#[derive(Clone)]
+pub struct ParetoPoint {
+    /// Luminous efficacy of radiation of the mix, in lumen per watt.
+    pub luminous_efficacy: f64,
+    pub source: TunedSource,
+}
+
+/// Optimizer for the channel weights of a multi-primary light source.
+#[derive(Clone)]
+pub struct SpectralTuner {
+    channels: Vec<Illuminant>,
+    observer: Observer,
+    cct: f64,
+    illuminance: f64,
+}
+
+impl SpectralTuner {
+    /// A tuner for a set of channels, with a target correlated color temperature, in kelvin, and
+    /// an illuminance, in lux, for the CIE 1931 observer.
+    pub fn new(channels: &[Illuminant], cct: f64, illuminance: f64) -> Self {
+        Self { channels: channels.to_vec(), observer: Observer::Std1931, cct, illuminance }
+    }
+
+    pub fn set_observer(mut self, observer: Observer) -> Self {
+        self.observer = observer;
+        self
+    }
+
+    pub fn channels(&self) -> &[Illuminant] {
+        &self.channels
+    }
+
+    /// Spectral distribution of a mix of the channels.
+    pub fn mix(&self, weights: &[f64]) -> Illuminant {
+        let s = self.channels.iter().zip(weights).fold(nalgebra::SVector::<f64, NS>::zeros(), |s, (c, &w)| s + c.0 .0 * w);
+        Illuminant(Spectrum(s))
+    }
+
+    /// Tristimulus values of the channels, as columns, and the target values, relative to the
+    /// target illuminance.
+    fn system(&self) -> (DMatrix<f64>, DVector<f64>) {
+        let obs = self.observer.data();
+        let target = obs.xyz_from_spectrum(&Illuminant::planckian(self.cct), None).set_illuminance(1.0).xyzn;
+        let a = DMatrix::from_fn(3, self.channels.len(), |i, j| {
+            obs.xyz_from_spectrum(&self.channels[j], None).xyzn[i] / self.illuminance
+        });
+        (a, DVector::from_column_slice(target.as_slice()))
+    }
+
+    /// Non-negative channel weights, which result in the target chromaticity, and illuminance.
+    pub fn feasible(&self) -> Result<Vec<f64>, CmtError> {
+        if self.channels.len() < 3 {
+            return Err(CmtError::ErrorString("SpectralTuner: requires at least three channels".into()));
+        }
+        let (a, b) = self.system();
+        let w = nnls(&a, &b);
+        if (&a * &w - &b).norm() > 1E-9 * b.norm() {
+            return Err(CmtError::ErrorString(format!(
+                "SpectralTuner: a correlated color temperature of {} K is out of the gamut of the channels",
+                self.cct
+            )));
+        }
+        Ok(w.iter().copied().collect())
+    }
+
+    /**
+    Channel weights, starting from a feasible set, which maximize a metric, as its general index,
+    or as the special index with the given label, such as "R9", while keeping the chromaticity
+    and illuminance of the mix.
+
+    This finds a local maximum, which depends on the starting weights; with more than four
+    channels, results can be improved by starting from different weights.
+    */
+    pub fn maximize(&self, metric: &dyn RenditionMetric, label: Option<&str>) -> Result<TunedSource, CmtError> {
+        let start = self.feasible()?;
+        self.maximize_from(&start, |illuminant| self.index(metric, label, illuminant))
+    }
+
+    /**
+    The trade-off between luminous efficacy of radiation (LER), and a color rendition metric, as
+    a set of at most `n` mixes on their Pareto front, ordered from the highest efficacy, to the highest
+    metric value.
+
+    The metric is maximized for a range of minimum efficacy values, evenly spaced between the
+    efficacy of the mix with the best metric value, and the highest efficacy of the channels
+    for the target; mixes which are improved upon in both efficacy and metric value, by another
+    mix, are removed.
+    */
+    pub fn pareto(&self, metric: &dyn RenditionMetric, label: Option<&str>, n: usize) -> Result<Vec<ParetoPoint>, CmtError> {
+        let obs = self.observer.data();
+        let start = self.feasible()?;
+        let efficient = self.maximize_from(&start, |illuminant| Ok(illuminant.luminous_efficacy(obs)))?;
+        let best = self.maximize(metric, label)?;
+        let ler_max = efficient.value;
+        let ler_min = best.illuminant.luminous_efficacy(obs);
+
+        let mut points = Vec::with_capacity(n + 1);
+        let mut weights = efficient.weights;
+        for i in 0..n.max(2) - 1 {
+            let ler_limit = ler_max - (ler_max - ler_min) * i as f64 / (n.max(2) - 1) as f64;
+            let source = self.maximize_from(&weights, |illuminant| {
+                if illuminant.luminous_efficacy(obs) < ler_limit {
+                    Err(CmtError::ErrorString("SpectralTuner: efficacy below limit".into()))
+                } else {
+                    self.index(metric, label, illuminant)
+                }
+            })?;
+            weights.clone_from(&source.weights);
+            points.push(ParetoPoint { luminous_efficacy: source.illuminant.luminous_efficacy(obs), source });
+        }
+        points.push(ParetoPoint { luminous_efficacy: ler_min, source: best });
+
+        // keep the non-dominated mixes only
+        let front = points
+            .iter()
+            .filter(|p| {
+                !points.iter().any(|q| {
+                    q.luminous_efficacy >= p.luminous_efficacy
+                        && q.source.value >= p.source.value
+                        && (q.luminous_efficacy > p.luminous_efficacy || q.source.value > p.source.value)
+                })
+            })
+            .cloned()
+            .collect();
+        Ok(front)
+    }
+
+    /// Value of a metric, as its general index, or as a special index, by label, for a mix.
+    fn index(&self, metric: &dyn RenditionMetric, label: Option<&str>, illuminant: &Illuminant) -> Result<f64, CmtError> {
+        let result = metric.evaluate(illuminant, self.observer.data())?;
+        match label {
+            Some(label) => result
+                .special(label)
+                .ok_or_else(|| CmtError::ErrorString(format!("SpectralTuner: {} has no index {label}", result.name))),
+            None => Ok(result.general),
+        }
+    }
+
+    /// Pattern search for the maximum of an objective function of the mix, from feasible starting
+    /// weights, along directions in the null space of the tristimulus matrix of the channels.
+    fn maximize_from(
+        &self,
+        start: &[f64],
+        objective: impl Fn(&Illuminant) -> Result<f64, CmtError>,
+    ) -> Result<TunedSource, CmtError> {
+        let (a, _) = self.system();
+        let n = self.channels.len();
+        // projection onto the null space of A: steps along these directions keep the XYZ values
+        let aat_inv = (&a * a.transpose()).try_inverse().ok_or_else(|| CmtError::ErrorString("SpectralTuner: channels with dependent tristimulus values".into()))?;
+        let p = DMatrix::identity(n, n) - a.transpose() * aat_inv * &a;
+        let directions: Vec<DVector<f64>> = (0..n)
+            .map(|j| p.column(j).into_owned())
+            .filter(|d| d.norm() > 1E-9)
+            .flat_map(|d| {
+                let d = d.normalize();
+                [d.clone(), -d]
+            })
+            .collect();
+
+        debug_span!("maximize", channels = n);
+        let mut w = DVector::from_column_slice(start);
+        let mut value = objective(&self.mix(w.as_slice()))?;
+        let mut step = 0.25 * w.norm();
+        let min_step = 1E-6 * w.norm();
+        let mut evaluations = 1;
+        while step > min_step && evaluations < MAX_EVALUATIONS {
+            let mut improved = false;
+            for d in &directions {
+                // largest step keeping the weights non-negative
+                let t = d.iter().zip(w.iter()).filter(|(&di, _)| di < 0.0).fold(step, |t, (&di, &wi)| t.min(wi / -di));
+                if t <= 0.0 {
+                    continue;
+                }
+                let candidate = (&w + d * t).map(|v| v.max(0.0));
+                evaluations += 1;
+                if let Ok(v) = objective(&self.mix(candidate.as_slice())) {
+                    if v > value {
+                        w = candidate;
+                        value = v;
+                        improved = true;
+                        break;
+                    }
+                }
+            }
+            if !improved {
+                step *= 0.5;
+            }
+            debug_event!(evaluations, step, value, improved);
+        }
+        let weights: Vec<f64> = w.iter().copied().collect();
+        Ok(TunedSource { illuminant: self.mix(&weights), weights, value })
+    }
+}
+
+/// Non-negative least squares solution of A x = b, using the Lawson-Hanson active set method.
+pub(crate) fn nnls(a: &DMatrix<f64>, b: &DVector<f64>) -> DVector<f64> {
+    let n = a.ncols();
+    let mut x = DVector::zeros(n);
+    let mut passive = vec![false; n];
+    let tolerance = 1E-12 * a.norm() * b.norm();
+    for _ in 0..3 * n {
+        let gradient = a.transpose() * (b - a * &x);
+        let Some(j) = (0..n).filter(|&j| !passive[j] && gradient[j] > tolerance).max_by(|&i, &j| gradient[i].total_cmp(&gradient[j]))
+        else {
+            break;
+        };
+        passive[j] = true;
+        loop {
+            let indices: Vec<usize> = (0..n).filter(|&j| passive[j]).collect();
+            if indices.is_empty() {
+                break;
+            }
+            let Ok(z) = a.select_columns(&indices).svd(true, true).solve(b, 1E-15) else {
+                break;
+            };
+            if z.iter().all(|&v| v > 0.0) {
+                indices.iter().zip(z.iter()).for_each(|(&j, &v)| x[j] = v);
+                break;
+            }
+            // move towards the new solution, until a weight becomes zero, and remove it
+            let alpha = indices
+                .iter()
+                .zip(z.iter())
+                .filter(|(_, &v)| v <= 0.0)
+                .fold(1.0f64, |alpha, (&j, &v)| alpha.min(x[j] / (x[j] - v)));
+            for (&j, &v) in indices.iter().zip(z.iter()) {
+                x[j] += alpha * (v - x[j]);
+                if x[j] <= 1E-15 {
+                    x[j] = 0.0;
+                    passive[j] = false;
+                }
+            }
+        }
+    }
+    x
+}
+
+#[cfg(test)]
+mod tuning_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_nnls() {
+        let a = nalgebra::DMatrix::from_row_slice(2, 3, &[1.0, 0.0, 1.0, 0.0, 1.0, 1.0]);
+        let x = super::nnls(&a, &nalgebra::DVector::from_column_slice(&[1.0, -1.0]));
+        assert_eq!(x.as_slice(), &[1.0, 0.0, 0.0]);
+    }
+
+    #[cfg(feature = "cri")]
+    #[test]
+    fn test_maximize_r9() {
+        let channels: Vec<Illuminant> = [450.0, 520.0, 560.0, 600.0, 640.0]
+            .iter()
+            .map(|&l| Illuminant::led(l, 25.0))
+            .collect();
+        let tuner = SpectralTuner::new(&channels, 3000.0, 500.0);
+        let start = tuner.mix(&tuner.feasible().unwrap());
+        let r9_start = CRI::try_new(&start).unwrap()[8];
+
+        let tuned = tuner.maximize(&CriMetric, Some("R9")).unwrap();
+        assert!(tuned.weights.iter().all(|&w| w >= 0.0));
+        assert!(tuned.value > r9_start + 10.0);
+        assert_abs_diff_eq!(CRI::try_new(&tuned.illuminant).unwrap()[8], tuned.value, epsilon = 1E-9);
+
+        // same chromaticity and illuminance
+        let xyz = CIE1931.xyz_from_spectrum(&tuned.illuminant, None);
+        let want = CIE1931.xyz(&Illuminant::planckian(3000.0), None);
+        assert_abs_diff_eq!(xyz.luminous_value(), 500.0, epsilon = 1E-6);
+        assert_abs_diff_eq!(xyz.xy().values().as_ref(), want.xy().values().as_ref(), epsilon = 1E-9);
+
+        assert!(SpectralTuner::new(&channels[..3], 3000.0, 500.0).feasible().is_err());
+        assert!(tuner.maximize(&CriMetric, Some("R99")).is_err());
+    }
+
+    #[cfg(feature = "cri")]
+    #[test]
+    fn test_pareto() {
+        let channels: Vec<Illuminant> = [450.0, 520.0, 560.0, 600.0, 640.0]
+            .iter()
+            .map(|&l| Illuminant::led(l, 25.0))
+            .collect();
+        let tuner = SpectralTuner::new(&channels, 4000.0, 500.0);
+        let front = tuner.pareto(&CriMetric, None, 4).unwrap();
+        assert!(front.len() >= 2);
+        for pair in front.windows(2) {
+            assert!(pair[0].luminous_efficacy > pair[1].luminous_efficacy);
+            assert!(pair[0].source.value < pair[1].source.value);
+        }
+        let last = front.last().unwrap();
+        assert_abs_diff_eq!(last.luminous_efficacy, last.source.illuminant.luminous_efficacy(&CIE1931), epsilon = 1E-9);
+    }
+}
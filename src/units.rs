@@ -0,0 +1,151 @@
+/*!
+# Photometric Units
+
+Luminous efficacy constants, and conversions between photometric quantities for a few common
+geometries.
+
+The photometric functions in this library, such as [`Illuminant::illuminance`](crate::illuminant::Illuminant::illuminance)
+and [`Illuminant::set_illuminance`](crate::illuminant::Illuminant::set_illuminance), use spectral
+irradiance values in units of W/m<sup>2</sup>/nm, and result in illuminance values in lux
+(lm/m<sup>2</sup>).
+When a source is specified by its luminous flux, or its luminous intensity, use the functions in
+this module to convert those to an illuminance first, taking the geometry of the measurement into
+account.
+*/
+
+use std::f64::consts::PI;
+
+use crate::math;
+
+/// Luminous efficacy of monochromatic radiation with a frequency of 540 THz (lm/W), which defines
+/// the candela in the SI.
+pub const KCD: f64 = 683.0;
+
+/// Maximum luminous efficacy for photopic vision (lm/W), at a wavelength of 555 nm.
+/// This value is slightly larger than [`KCD`], as the wavelength of the 540 THz radiation in
+/// standard air is 555.016 nm.
+pub const KM: f64 = 683.002;
+
+/// Maximum luminous efficacy for scotopic vision (lm/W), at a wavelength of 507 nm.
+pub const KM_PRIME: f64 = 1700.06;
+
+/// Number of lux in a foot-candle.
+pub const LUX_PER_FOOTCANDLE: f64 = 10.763910416709722;
+
+/// Number of candela per square meter in a foot-lambert.
+pub const NIT_PER_FOOTLAMBERT: f64 = 3.4262590996353905;
+
+/// Solid angle, in steradian, of a cone with a half apex angle in degrees.
+pub fn solid_angle_cone(half_angle_deg: f64) -> f64 {
+    2.0 * PI * (1.0 - math::cos(half_angle_deg.to_radians()))
+}
+
+/// Luminous flux, in lumen, of an isotropic point source with a luminous intensity in candela.
+pub fn lumen_from_candela_isotropic(candela: f64) -> f64 {
+    4.0 * PI * candela
+}
+
+/// Luminous intensity, in candela, of an isotropic point source with a luminous flux in lumen.
+pub fn candela_from_lumen_isotropic(lumen: f64) -> f64 {
+    lumen / (4.0 * PI)
+}
+
+/// Luminous flux, in lumen, emitted into a cone with a half apex angle in degrees, by a source
+/// with a uniform luminous intensity in candela.
+pub fn lumen_from_candela_cone(candela: f64, half_angle_deg: f64) -> f64 {
+    candela * solid_angle_cone(half_angle_deg)
+}
+
+/// Uniform luminous intensity, in candela, of a source emitting its luminous flux into a cone
+/// with a half apex angle in degrees, such as a spot light with a given beam angle.
+///
+/// ```
+/// use colorimetry::prelude::*;
+/// use approx::assert_abs_diff_eq;
+///
+/// // 1000 lm into a beam with a full width of 60º
+/// let cd = candela_from_lumen_cone(1000.0, 30.0);
+/// assert_abs_diff_eq!(cd, 1187.9, epsilon = 0.1);
+/// ```
+pub fn candela_from_lumen_cone(lumen: f64, half_angle_deg: f64) -> f64 {
+    lumen / solid_angle_cone(half_angle_deg)
+}
+
+/// Illuminance, in lux, on a surface at a distance in meter from a point source with a luminous
+/// intensity in candela, in the direction of the surface normal.
+///
+/// This uses the inverse square law, and is only valid for distances large compared to the
+/// size of the source.
+pub fn lux_from_candela(candela: f64, distance_m: f64) -> f64 {
+    candela / (distance_m * distance_m)
+}
+
+/// Luminous intensity, in candela, of a point source producing an illuminance in lux at a
+/// distance in meter.
+pub fn candela_from_lux(lux: f64, distance_m: f64) -> f64 {
+    lux * distance_m * distance_m
+}
+
+/// Illuminance, in lux, on a surface with a uniform luminous flux in lumen falling on an area in
+/// square meter.
+pub fn lux_from_lumen(lumen: f64, area_m2: f64) -> f64 {
+    lumen / area_m2
+}
+
+/// Luminance, in cd/m<sup>2</sup>, of a Lambertian reflector with a luminous reflectance factor
+/// (0.0 to 1.0), illuminated with an illuminance in lux.
+pub fn luminance_from_lux_lambertian(lux: f64, reflectance: f64) -> f64 {
+    reflectance * lux / PI
+}
+
+/// Illuminance, in lux, producing a luminance in cd/m<sup>2</sup> on a Lambertian reflector with
+/// a luminous reflectance factor (0.0 to 1.0).
+pub fn lux_from_luminance_lambertian(luminance: f64, reflectance: f64) -> f64 {
+    PI * luminance / reflectance
+}
+
+/// Converts foot-candles to lux.
+pub fn lux_from_footcandle(footcandle: f64) -> f64 {
+    footcandle * LUX_PER_FOOTCANDLE
+}
+
+/// Converts lux to foot-candles.
+pub fn footcandle_from_lux(lux: f64) -> f64 {
+    lux / LUX_PER_FOOTCANDLE
+}
+
+/// Converts foot-lamberts to cd/m<sup>2</sup>.
+pub fn nit_from_footlambert(footlambert: f64) -> f64 {
+    footlambert * NIT_PER_FOOTLAMBERT
+}
+
+/// Converts cd/m<sup>2</sup> to foot-lamberts.
+pub fn footlambert_from_nit(nit: f64) -> f64 {
+    nit / NIT_PER_FOOTLAMBERT
+}
+
+#[cfg(test)]
+mod units_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_cone(){
+        // a hemisphere, and a full sphere
+        assert_abs_diff_eq!(lumen_from_candela_cone(1.0, 90.0), 2.0 * std::f64::consts::PI, epsilon = 1E-12);
+        assert_abs_diff_eq!(lumen_from_candela_cone(1.0, 180.0), lumen_from_candela_isotropic(1.0), epsilon = 1E-12);
+        assert_abs_diff_eq!(candela_from_lumen_cone(lumen_from_candela_cone(250.0, 12.5), 12.5), 250.0, epsilon = 1E-10);
+    }
+
+    #[test]
+    fn test_illuminance(){
+        // 1000 cd at 2 m, measured on an illuminant spectrum
+        let lux = lux_from_candela(1000.0, 2.0);
+        assert_abs_diff_eq!(lux, 250.0);
+        let d65 = Illuminant::d65().set_illuminance(&CIE1931, lux);
+        assert_abs_diff_eq!(d65.illuminance(&CIE1931), 250.0, epsilon = 1E-10);
+        assert_abs_diff_eq!(candela_from_lux(lux, 2.0), 1000.0);
+        assert_abs_diff_eq!(footcandle_from_lux(lux_from_footcandle(3.0)), 3.0, epsilon = 1E-12);
+        assert_abs_diff_eq!(lux_from_luminance_lambertian(luminance_from_lux_lambertian(500.0, 0.8), 0.8), 500.0, epsilon = 1E-10);
+    }
+}
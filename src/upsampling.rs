@@ -0,0 +1,446 @@
+/*!
+# Spectral Upsampling of RGB Values
+
+Spectral renderers need spectral reflectance factors for the RGB colors in textures and material
+definitions. This module implements the sigmoid-polynomial model by Wenzel Jakob and Johannes
+Hanika, from "A Low-Dimensional Function Space for Efficient Spectral Upsampling", Computer
+Graphics Forum, 38(2), 2019. In this model, a reflectance spectrum is represented by three
+coefficients of a quadratic polynomial, mapped into the range from 0.0 to 1.0 by a sigmoid
+function:
+
+R(λ) = S(c<sub>0</sub>t<sup>2</sup> + c<sub>1</sub>t + c<sub>2</sub>), with
+S(x) = ½ + x / (2√(1 + x<sup>2</sup>)),
+
+with t the wavelength normalized to the range from 0.0 to 1.0 over the domain from 380 to 780
+nanometer, in this library.
+These spectra are smooth, and bounded, and evaluating them costs only a few operations for each
+wavelength.
+
+The coefficients for an RGB value are found by a Gauss-Newton optimization in CIELAB, with the
+reference white of the RGB color space as illuminant. A [`SigmoidTable`] holds precomputed
+coefficients on a regular grid of RGB values, which are interpolated, for fast upsampling of
+large numbers of RGB values, as an alternative for the Gaussian primaries based
+[`Stimulus`](crate::stimulus::Stimulus) model.
+Tables are computed when constructed, except for the precomputed sRGB table, with a resolution of
+16, for the CIE 1931 observer, available as [`SigmoidTable::srgb`].
+
+As a cheap alternative, the classic method by Brian Smits, from "An RGB-to-Spectrum Conversion for
+Reflectances", Journal of Graphics Tools, 4(4), 1999, is also available, which composes spectra
+from seven tabulated basis spectra. Select a method with the [`Upsampling`] enum.
+*/
+
+use nalgebra::{Matrix3, SMatrix, Vector3};
+
+use crate::{
+    colorant::Colorant,
+    data::sigmoid_srgb::{SIGMOID_SRGB, SIGMOID_SRGB_RESOLUTION},
+    error::CmtError,
+    lab::CieLab,
+    observer::Observer,
+    rgb::RGB,
+    rgbspace::RgbSpace,
+    spectrum::{Spectrum, NS},
+    xyz::XYZ,
+};
+
+/// Maximum CIELAB color difference of a successful fit.
+const FIT_TOLERANCE: f64 = 1E-3;
+
+/// Number of intermediate targets, from the center of the RGB cube, used in a fit without a
+/// start value.
+const FIT_STEPS: usize = 8;
+
+/**
+Reflectance spectrum defined by the three coefficients of a sigmoid-polynomial.
+```
+use colorimetry::prelude::*;
+
+let rgb = RGB::new(0.6, 0.3, 0.2, None, None);
+let sp = SigmoidPolynomial::fit(&rgb).unwrap();
+let xyz = CIE1931.xyz(&StdIlluminant::D65, Some(&sp.colorant()));
+approx::assert_abs_diff_eq!(xyz, rgb.xyz(), epsilon = 1E-3);
+```
+*/
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SigmoidPolynomial([f64; 3]);
+
+impl SigmoidPolynomial {
+    pub fn new(coefficients: [f64; 3]) -> Self {
+        Self(coefficients)
+    }
+
+    pub fn coefficients(&self) -> [f64; 3] {
+        self.0
+    }
+
+    /// Reflectance factor at a wavelength, in nanometer.
+    pub fn value(&self, wavelength: f64) -> f64 {
+        let t = (wavelength - 380.0) / 400.0;
+        let [c0, c1, c2] = self.0;
+        sigmoid((c0 * t + c1) * t + c2)
+    }
+
+    pub fn colorant(&self) -> Colorant {
+        Colorant(Spectrum(SMatrix::<f64, NS, 1>::from_fn(|i, _| self.value((i + 380) as f64))))
+    }
+
+    /**
+    Fits the coefficients for a linear RGB value, with its values in the range from 0.0 to 1.0, for
+    the observer and the reference white illuminant of its color space.
+
+    Fully saturated values, with components of exactly 0.0 or 1.0, can not be represented
+    exactly, and result in large coefficients. Returns an error if no fit within a CIELAB color
+    difference of 0.001 is found.
+    */
+    pub fn fit(rgb: &RGB) -> Result<Self, CmtError> {
+        let fitter = Fitter::new(rgb.space, rgb.observer);
+        let target: [f64; 3] = rgb.rgb.into();
+        let mut c = Self::default();
+        for step in 1..=FIT_STEPS {
+            let f = step as f64 / FIT_STEPS as f64;
+            c = fitter.fit(target.map(|v| 0.5 + f * (v - 0.5)), c).0;
+        }
+        let (c, de) = fitter.fit(target, c);
+        if de <= FIT_TOLERANCE {
+            Ok(c)
+        } else {
+            Err(CmtError::ErrorString(format!("Sigmoid polynomial fit failed, with a color difference of {de:.4}")))
+        }
+    }
+}
+
+fn sigmoid(x: f64) -> f64 {
+    if x.is_infinite() {
+        if x > 0.0 { 1.0 } else { 0.0 }
+    } else {
+        0.5 + x / (2.0 * (1.0 + x * x).sqrt())
+    }
+}
+
+/**
+Sigmoid-polynomial coefficients, precomputed on a regular grid of linear RGB values, for a color
+space, and an observer.
+```
+use colorimetry::prelude::*;
+
+let table = SigmoidTable::new(RgbSpace::SRGB, Observer::Std1931, 5);
+let colorant = table.colorant([0.5, 0.5, 0.5]);
+approx::assert_abs_diff_eq!(colorant[550], 0.5, epsilon = 1E-3);
+```
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub struct SigmoidTable {
+    space: RgbSpace,
+    observer: Observer,
+    resolution: usize,
+    coefficients: Vec<[f64; 3]>,
+}
+
+impl SigmoidTable {
+    /**
+    Computes a table, with `resolution` grid points, of at least 2, along each of the red, green,
+    and blue axes, with grid values ranging from 0.0 to 1.0.
+
+    Saturated values at the edges of the cube are fitted as close as possible. The computation
+    time increases with the third power of the resolution: a resolution of 16 takes in the order
+    of a second.
+    */
+    pub fn new(space: RgbSpace, observer: Observer, resolution: usize) -> Self {
+        let n = resolution.max(2);
+        let fitter = Fitter::new(space, observer);
+        let grid = |i: usize| i as f64 / (n - 1) as f64;
+        let mut coefficients = vec![[0.0; 3]; n * n * n];
+        // fit from the center of the cube outwards, starting from the fit of a neighbor closer to
+        // the center, which has been fitted before
+        let center = (n - 1) / 2;
+        let order: Vec<usize> = (center..n).chain((0..center).rev()).collect();
+        for &r in &order {
+            for &g in &order {
+                for &b in &order {
+                    let neighbor = if b != center {
+                        Some((r, g, closer(b, center)))
+                    } else if g != center {
+                        Some((r, closer(g, center), b))
+                    } else if r != center {
+                        Some((closer(r, center), g, b))
+                    } else {
+                        None
+                    };
+                    let start = neighbor.map_or(SigmoidPolynomial::default(), |(r, g, b)| {
+                        SigmoidPolynomial(coefficients[(r * n + g) * n + b])
+                    });
+                    let (c, _) = fitter.fit([grid(r), grid(g), grid(b)], start);
+                    coefficients[(r * n + g) * n + b] = c.0;
+                }
+            }
+        }
+        Self { space, observer, resolution: n, coefficients }
+    }
+
+    /// The precomputed table for the sRGB color space, and the CIE 1931 observer, with a resolution
+    /// of 16, as computed by [`SigmoidTable::new`].
+    pub fn srgb() -> Self {
+        Self {
+            space: RgbSpace::SRGB,
+            observer: Observer::Std1931,
+            resolution: SIGMOID_SRGB_RESOLUTION,
+            coefficients: SIGMOID_SRGB.to_vec(),
+        }
+    }
+
+    pub fn space(&self) -> RgbSpace {
+        self.space
+    }
+
+    pub fn observer(&self) -> Observer {
+        self.observer
+    }
+
+    /// Sigmoid polynomial for a linear RGB value, by trilinear interpolation of the table's
+    /// coefficients, with the values clamped to the range from 0.0 to 1.0.
+    pub fn sigmoid_polynomial(&self, rgb: [f64; 3]) -> SigmoidPolynomial {
+        let n = self.resolution;
+        let pos = rgb.map(|v| v.clamp(0.0, 1.0) * (n - 1) as f64);
+        let i0 = pos.map(|p| (p.floor() as usize).min(n - 2));
+        let f: [f64; 3] = std::array::from_fn(|k| pos[k] - i0[k] as f64);
+        let mut c = [0.0; 3];
+        for corner in 0..8 {
+            let d = [corner >> 2 & 1, corner >> 1 & 1, corner & 1];
+            let w: f64 = (0..3).map(|k| if d[k] == 1 { f[k] } else { 1.0 - f[k] }).product();
+            if w > 0.0 {
+                let [r, g, b]: [usize; 3] = std::array::from_fn(|k| i0[k] + d[k]);
+                let v = self.coefficients[(r * n + g) * n + b];
+                (0..3).for_each(|k| c[k] += w * v[k]);
+            }
+        }
+        SigmoidPolynomial(c)
+    }
+
+    /// Reflectance spectrum for a linear RGB value.
+    pub fn colorant(&self, rgb: [f64; 3]) -> Colorant {
+        self.sigmoid_polynomial(rgb).colorant()
+    }
+}
+
+/// Upper wavelength limit, in nanometer, of the basis spectra by Smits; the values of its last
+/// bin are used for longer wavelengths.
+const SMITS_END: f64 = 720.0;
+
+/// Basis spectra by Smits, in 10 bins of equal width from 380 to 720 nanometer: white, cyan,
+/// magenta, yellow, red, green, and blue.
+const SMITS_BASIS: [[f64; 10]; 7] = [
+    [1.0000, 1.0000, 0.9999, 0.9993, 0.9992, 0.9998, 1.0000, 1.0000, 1.0000, 1.0000],
+    [0.9710, 0.9426, 1.0007, 1.0007, 1.0007, 1.0007, 0.1564, 0.0000, 0.0000, 0.0000],
+    [1.0000, 1.0000, 0.9685, 0.2229, 0.0000, 0.0458, 0.8369, 1.0000, 1.0000, 0.9959],
+    [0.0001, 0.0000, 0.1088, 0.6651, 1.0000, 1.0000, 0.9996, 0.9586, 0.9685, 0.9840],
+    [0.1012, 0.0515, 0.0000, 0.0000, 0.0000, 0.0000, 0.8325, 1.0149, 1.0149, 1.0149],
+    [0.0000, 0.0000, 0.0273, 0.7937, 1.0000, 0.9418, 0.1719, 0.0000, 0.0000, 0.0025],
+    [1.0000, 1.0000, 0.8916, 0.3323, 0.0000, 0.0000, 0.0003, 0.0369, 0.0483, 0.0496],
+];
+
+/**
+Reflectance spectrum for a linear RGB value, composed from the basis spectra by Smits.
+
+The spectrum is a sum of the white basis spectrum, scaled by the smallest RGB component, and of a
+secondary, and a primary, basis spectrum, for the differences of the other components, with
+values clamped to the range from 0.0 to 1.0.
+The basis spectra were optimized for a generic RGB space, and the colors of the spectra only
+approximate the RGB values, in particular for saturated colors.
+```
+use colorimetry::prelude::*;
+
+let gray = smits([0.5, 0.5, 0.5]);
+approx::assert_abs_diff_eq!(gray[550], 0.5, epsilon = 1E-3);
+```
+*/
+pub fn smits(rgb: [f64; 3]) -> Colorant {
+    let [r, g, b] = rgb;
+    let [white, cyan, magenta, yellow, red, green, blue] = [0, 1, 2, 3, 4, 5, 6];
+    let terms = if r <= g && r <= b {
+        if g <= b { [(white, r), (cyan, g - r), (blue, b - g)] } else { [(white, r), (cyan, b - r), (green, g - b)] }
+    } else if g <= r && g <= b {
+        if r <= b { [(white, g), (magenta, r - g), (blue, b - r)] } else { [(white, g), (magenta, b - g), (red, r - b)] }
+    } else if r <= g {
+        [(white, b), (yellow, r - b), (green, g - r)]
+    } else {
+        [(white, b), (yellow, g - b), (red, r - g)]
+    };
+    let width = (SMITS_END - 380.0) / 10.0;
+    Colorant(Spectrum(SMatrix::<f64, NS, 1>::from_fn(|i, _| {
+        let bin = ((i as f64 / width) as usize).min(9);
+        terms.iter().map(|&(k, w)| w * SMITS_BASIS[k][bin]).sum::<f64>().clamp(0.0, 1.0)
+    })))
+}
+
+/// Spectral upsampling method.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Upsampling {
+    /// Sigmoid-polynomial fit by Jakob and Hanika, accurate for the observer and color space of
+    /// the RGB value.
+    #[default]
+    SigmoidPolynomial,
+
+    /// Basis spectra by Smits, fast, but approximate.
+    Smits,
+}
+
+impl Upsampling {
+    /**
+    Reflectance spectrum for an RGB value.
+    ```
+    use colorimetry::prelude::*;
+
+    let rgb = RGB::new(0.7, 0.4, 0.2, None, None);
+    let want = CieLab::try_from(rgb.xyz()).unwrap();
+    for method in [Upsampling::SigmoidPolynomial, Upsampling::Smits] {
+        let lab = CIE1931.lab_d65(&method.colorant(&rgb).unwrap());
+        assert!(lab.delta_e(&want).unwrap() < 5.0);
+    }
+    ```
+    */
+    pub fn colorant(&self, rgb: &RGB) -> Result<Colorant, CmtError> {
+        match self {
+            Upsampling::SigmoidPolynomial => Ok(SigmoidPolynomial::fit(rgb)?.colorant()),
+            Upsampling::Smits => Ok(smits(rgb.rgb.into())),
+        }
+    }
+}
+
+/// Grid index one step closer to the center.
+fn closer(i: usize, center: usize) -> usize {
+    match i.cmp(&center) {
+        std::cmp::Ordering::Less => i + 1,
+        std::cmp::Ordering::Greater => i - 1,
+        std::cmp::Ordering::Equal => i,
+    }
+}
+
+/// Gauss-Newton fitting of sigmoid-polynomial coefficients, for a color space's white reference,
+/// and an observer.
+struct Fitter {
+    observer: Observer,
+    /// Color matching functions multiplied by the white's spectrum, normalized to Y = 100.
+    weights: SMatrix<f64, 3, NS>,
+    rgb2xyz: Matrix3<f64>,
+    xyzn: Vector3<f64>,
+}
+
+impl Fitter {
+    fn new(space: RgbSpace, observer: Observer) -> Self {
+        let data = observer.data();
+        let white = space.data().0.white.illuminant();
+        let mut weights = data.data;
+        for (mut col, w) in weights.column_iter_mut().zip(white.0 .0.iter()) {
+            col *= *w;
+        }
+        let xyzn = weights.column_sum();
+        weights *= 100.0 / xyzn.y;
+        Self { observer, weights, rgb2xyz: *data.rgb2xyz(&space) * 100.0, xyzn: xyzn * (100.0 / xyzn.y) }
+    }
+
+    fn lab(&self, xyz: Vector3<f64>) -> Vector3<f64> {
+        // unwrap: XYZ values with a sample value
+        CieLab::try_from(XYZ::from_vecs(self.xyzn, Some(xyz), self.observer)).unwrap().lab
+    }
+
+    fn residual(&self, c: &[f64; 3], target: &Vector3<f64>) -> Vector3<f64> {
+        let xyz = self.weights * SigmoidPolynomial(*c).colorant().0 .0;
+        self.lab(xyz) - target
+    }
+
+    /// Fits coefficients for a linear RGB value, from a start value, returning the coefficients,
+    /// and the remaining CIELAB color difference.
+    fn fit(&self, rgb: [f64; 3], start: SigmoidPolynomial) -> (SigmoidPolynomial, f64) {
+        const H: f64 = 1E-6;
+        debug_span!("sigmoid_fit", ?rgb);
+        let target = self.lab(self.rgb2xyz * Vector3::from(rgb));
+        let mut c = start.0;
+        let mut r = self.residual(&c, &target);
+        for _ in 0..30 {
+            debug_event!(?c, residual = r.norm());
+            if r.norm() < FIT_TOLERANCE * 1E-2 {
+                break;
+            }
+            let mut jac = Matrix3::zeros();
+            for k in 0..3 {
+                let mut ch = c;
+                ch[k] += H;
+                jac.set_column(k, &((self.residual(&ch, &target) - r) / H));
+            }
+            let Some(dc) = jac.lu().solve(&r) else { break };
+            // step halving, to guarantee a decreasing residual
+            let mut step = 1.0;
+            loop {
+                let cn: [f64; 3] = std::array::from_fn(|k| c[k] - step * dc[k]);
+                let rn = self.residual(&cn, &target);
+                if rn.norm() < r.norm() {
+                    (c, r) = (cn, rn);
+                    break;
+                }
+                step *= 0.5;
+                if step < 1E-4 {
+                    return (SigmoidPolynomial(c), r.norm());
+                }
+            }
+        }
+        (SigmoidPolynomial(c), r.norm())
+    }
+}
+
+#[cfg(test)]
+mod upsampling_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_sigmoid_polynomial() {
+        for rgb in [[0.5, 0.5, 0.5], [0.8, 0.1, 0.1], [0.05, 0.6, 0.2], [0.1, 0.2, 0.9], [0.9, 0.9, 0.02]] {
+            let rgb = RGB::new(rgb[0], rgb[1], rgb[2], None, None);
+            let sp = SigmoidPolynomial::fit(&rgb).unwrap();
+            let lab = CIE1931.lab_d65(&sp.colorant());
+            let want = CieLab::try_from(rgb.xyz()).unwrap();
+            assert!(lab.delta_e(&want).unwrap() < 1E-3);
+        }
+
+        let table = SigmoidTable::new(RgbSpace::SRGB, Observer::Std1931, 5);
+        let at_grid = table.sigmoid_polynomial([0.75, 0.25, 0.5]);
+        let fit = SigmoidPolynomial::fit(&RGB::new(0.75, 0.25, 0.5, None, None)).unwrap();
+        for (a, b) in at_grid.coefficients().iter().zip(fit.coefficients()) {
+            assert_abs_diff_eq!(*a, b, epsilon = 1E-3);
+        }
+    }
+
+    #[test]
+    fn test_sigmoid_table_srgb() {
+        // the precomputed table holds the fits at its grid points
+        let table = SigmoidTable::srgb();
+        for rgb in [[0.6, 0.2, 0.4], [0.2, 0.8, 0.0], [1.0 / 3.0, 1.0 / 3.0, 1.0]] {
+            let at_grid = table.sigmoid_polynomial(rgb);
+            let fit = SigmoidPolynomial::fit(&RGB::new(rgb[0], rgb[1], rgb[2], None, None)).unwrap();
+            for (a, b) in at_grid.coefficients().iter().zip(fit.coefficients()) {
+                assert_abs_diff_eq!(*a, b, epsilon = 1E-3);
+            }
+        }
+
+        // round trip color differences, between the grid points
+        for rgb in [[0.5, 0.5, 0.5], [0.7, 0.3, 0.25], [0.15, 0.45, 0.65], [0.9, 0.85, 0.1]] {
+            let want = CieLab::try_from(RGB::new(rgb[0], rgb[1], rgb[2], None, None).xyz()).unwrap();
+            let lab = CIE1931.lab_d65(&table.colorant(rgb));
+            assert!(lab.delta_e(&want).unwrap() < 1.0);
+        }
+    }
+
+    #[test]
+    fn test_smits() {
+        // the basis spectra approximately reproduce the sRGB primaries, and secondaries
+        for rgb in [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0], [1.0, 1.0, 0.0], [0.0, 1.0, 1.0], [1.0, 0.0, 1.0]] {
+            let xyz = CIE1931.xyz(&StdIlluminant::D65, Some(&smits(rgb)));
+            let got = RGB::from_xyz(xyz, RgbSpace::SRGB).rgb / 100.0;
+            for (g, w) in got.iter().zip(rgb) {
+                assert_abs_diff_eq!(*g, w, epsilon = 0.06);
+            }
+        }
+        let white = smits([1.0, 1.0, 1.0]);
+        assert!(white.0 .0.iter().all(|&v| v > 0.999));
+    }
+}
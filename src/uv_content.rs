@@ -0,0 +1,76 @@
+/*!
+# Ultraviolet Content
+
+Optical brightening agents (OBAs) in papers, and textiles, absorb near-ultraviolet radiation, and
+emit it as blue fluorescence; the appearance of such samples depends on the ultraviolet content
+of the light source. ISO 3664 therefore requires the ultraviolet content of viewing booths to be
+close to that of the CIE D50 illuminant.
+
+The ultraviolet content is characterized here by the ratio of the irradiance in the 380 to 400
+nanometer band, the part of the OBA excitation band within the wavelength domain of this library,
+to the irradiance in the 400 to 700 nanometer band, and expressed relative to the ratios of the
+D50 and D65 illuminants. Excitation by radiation below 380 nanometer is not included; use
+[`Spectrum::truncated_power_fraction`](crate::spectrum::Spectrum::truncated_power_fraction) on
+the measured data to check for significant power outside the domain.
+*/
+
+use crate::{data::illuminants::{D50, D65}, illuminant::Illuminant};
+
+/// Ultraviolet content of a light source.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UvContent {
+    /// Ratio of the irradiance from 380 to 400 nanometer, to the irradiance from 400 to 700
+    /// nanometer.
+    pub ratio: f64,
+    /// Ratio, relative to the ratio of the D50 illuminant, as an estimate of the OBA excitation
+    /// relative to D50.
+    pub relative_d50: f64,
+    /// Ratio, relative to the ratio of the D65 illuminant.
+    pub relative_d65: f64,
+}
+
+/// Ratio of the irradiance from 380 to 400 nanometer, to the irradiance from 400 to 700 nanometer.
+fn uv_ratio(illuminant: &Illuminant) -> f64 {
+    let uv: f64 = (380..400).map(|l| illuminant[l]).sum();
+    let visible: f64 = (400..=700).map(|l| illuminant[l]).sum();
+    uv / visible
+}
+
+impl Illuminant {
+    /**
+    Ultraviolet content of this illuminant, relative to the D50 and D65 illuminants.
+    ```
+    use colorimetry::prelude::*;
+
+    let d50 = Illuminant::d50().uv_content();
+    approx::assert_abs_diff_eq!(d50.relative_d50, 1.0, epsilon = 1E-12);
+
+    // incandescent light lacks ultraviolet
+    let a = Illuminant::planckian(2856.0).uv_content();
+    assert!(a.relative_d50 < 0.5);
+    ```
+    */
+    pub fn uv_content(&self) -> UvContent {
+        let ratio = uv_ratio(self);
+        UvContent { ratio, relative_d50: ratio / uv_ratio(&D50), relative_d65: ratio / uv_ratio(&D65) }
+    }
+}
+
+#[cfg(test)]
+mod uv_content_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_uv_content() {
+        let d65 = Illuminant::d65().uv_content();
+        assert_abs_diff_eq!(d65.relative_d65, 1.0, epsilon = 1E-12);
+        assert!(d65.relative_d50 > 1.0);
+
+        // scaling does not change the content, a violet pump line adds to it
+        assert_abs_diff_eq!((Illuminant::d65() * 3.0).uv_content().ratio, d65.ratio, epsilon = 1E-12);
+        let pump = *Illuminant::led(390.0, 10.0) * (0.05 * Illuminant::d65().irradiance());
+        let violet = Illuminant(*Illuminant::d65() + pump);
+        assert!(violet.uv_content().relative_d65 > 1.1);
+    }
+}
@@ -0,0 +1,318 @@
+/*!
+# Video Signal Encodings
+
+Video, and broadcast, signals do not carry gamma-encoded R'G'B' values directly, but a luma
+value Y', and two color difference values Cb and Cr, derived from the R'G'B' values with the
+matrices of ITU-R BT.601, BT.709, or BT.2020, and quantized into code values with a limited, or
+"legal", range, or the full range.
+[`YCbCr`] converts the encoded values of an [`RGB`] value into these signals, and back, to inspect
+coded values against their colorimetric intent.
+
+For high dynamic range content, ITU-R BT.2100 defines the [`ICtCp`] encoding, with the perceptual
+quantizer (PQ) transfer function applied to cone-like LMS signals, derived from BT.2020 RGB
+values, for absolute luminance values up to 10,000 cd/m².
+Its color difference metric, ΔE<sub>ITP</sub>, as defined by ITU-R BT.2124, is used for quality
+control of HDR video, with a value of 1 as a just noticeable difference.
+*/
+
+use nalgebra::{Matrix3, Vector3};
+
+use crate::{math, rgb::RGB, xyz::XYZ};
+
+/// Luma coefficients of a video standard.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, strum_macros::Display, strum_macros::EnumIter)]
+pub enum YCbCrMatrix {
+    /// ITU-R BT.601, standard definition.
+    Bt601,
+    /// ITU-R BT.709, high definition.
+    #[default]
+    Bt709,
+    /// ITU-R BT.2020, ultra high definition, non-constant luminance.
+    Bt2020,
+}
+
+impl YCbCrMatrix {
+    /// The red and blue luma coefficients, Kr and Kb.
+    pub fn kr_kb(&self) -> (f64, f64) {
+        match self {
+            YCbCrMatrix::Bt601 => (0.299, 0.114),
+            YCbCrMatrix::Bt709 => (0.2126, 0.0722),
+            YCbCrMatrix::Bt2020 => (0.2627, 0.0593),
+        }
+    }
+}
+
+/// Quantization range of video code values.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, strum_macros::Display)]
+pub enum VideoRange {
+    /// Luma from 16 to 235, and chroma from 16 to 240, for 8-bit values, with the codes below,
+    /// and above, reserved for foot- and headroom.
+    #[default]
+    Limited,
+    /// All code values, as used in computer graphics, and JPEG images.
+    Full,
+}
+
+/// Luma, and color difference values, with luma from 0.0 to 1.0, and the color differences
+/// from -0.5 to 0.5.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct YCbCr {
+    pub y: f64,
+    pub cb: f64,
+    pub cr: f64,
+}
+
+impl YCbCr {
+    /// Converts gamma-encoded R'G'B' values, in a range from 0.0 to 1.0.
+    pub fn from_encoded_rgb(rgb: [f64; 3], matrix: YCbCrMatrix) -> Self {
+        let (kr, kb) = matrix.kr_kb();
+        let [r, g, b] = rgb;
+        let y = kr * r + (1.0 - kr - kb) * g + kb * b;
+        Self { y, cb: (b - y) / (2.0 * (1.0 - kb)), cr: (r - y) / (2.0 * (1.0 - kr)) }
+    }
+
+    /// Gamma-encoded R'G'B' values.
+    pub fn encoded_rgb(&self, matrix: YCbCrMatrix) -> [f64; 3] {
+        let (kr, kb) = matrix.kr_kb();
+        let r = self.y + 2.0 * (1.0 - kr) * self.cr;
+        let b = self.y + 2.0 * (1.0 - kb) * self.cb;
+        let g = (self.y - kr * r - kb * b) / (1.0 - kr - kb);
+        [r, g, b]
+    }
+
+    /**
+    Code values, for a bit depth from 8 to 16, rounded to the nearest code, and clamped to the
+    valid codes of the range; in the limited range the lowest, and highest, codes are reserved
+    for timing references.
+    ```
+    use colorimetry::prelude::*;
+
+    let white = YCbCr { y: 1.0, cb: 0.0, cr: 0.0 };
+    assert_eq!(white.quantize(VideoRange::Limited, 8), [235, 128, 128]);
+    assert_eq!(white.quantize(VideoRange::Full, 10), [1023, 512, 512]);
+    ```
+    */
+    pub fn quantize(&self, range: VideoRange, bits: u32) -> [u16; 3] {
+        let max = ((1u32 << bits) - 1) as f64;
+        let (lo, hi) = match range {
+            VideoRange::Limited => (1.0, max - 1.0),
+            VideoRange::Full => (0.0, max),
+        };
+        let [y, cb, cr] = self.codes(range, bits);
+        [y, cb, cr].map(|v| v.round().clamp(lo, hi) as u16)
+    }
+
+    /// Values from code values, for a bit depth from 8 to 16.
+    pub fn from_quantized(codes: [u16; 3], range: VideoRange, bits: u32) -> Self {
+        let [y, cb, cr] = codes.map(|c| c as f64);
+        let (scale_y, scale_c, offset_y, offset_c) = Self::scales(range, bits);
+        Self { y: (y - offset_y) / scale_y, cb: (cb - offset_c) / scale_c, cr: (cr - offset_c) / scale_c }
+    }
+
+    /**
+    Mean of the color difference values of a group of pixels, such as the two, or four, pixels
+    sharing their chroma samples in 4:2:2, or 4:2:0, subsampling, or None for an empty group.
+
+    Averaging the unquantized values, and quantizing once, avoids the bias of rounding twice.
+    ```
+    use colorimetry::prelude::*;
+
+    let pixels = [YCbCr { y: 0.5, cb: 0.1, cr: -0.1 }, YCbCr { y: 0.6, cb: 0.2, cr: 0.0 }];
+    let [cb, cr] = YCbCr::subsampled_chroma(&pixels).unwrap();
+    approx::assert_abs_diff_eq!(cb, 0.15, epsilon = 1E-12);
+    approx::assert_abs_diff_eq!(cr, -0.05, epsilon = 1E-12);
+    ```
+    */
+    pub fn subsampled_chroma(pixels: &[YCbCr]) -> Option<[f64; 2]> {
+        if pixels.is_empty() {
+            return None;
+        }
+        let n = pixels.len() as f64;
+        let (cb, cr) = pixels.iter().fold((0.0, 0.0), |(cb, cr), p| (cb + p.cb, cr + p.cr));
+        Some([cb / n, cr / n])
+    }
+
+    /// Unrounded code values.
+    fn codes(&self, range: VideoRange, bits: u32) -> [f64; 3] {
+        let (scale_y, scale_c, offset_y, offset_c) = Self::scales(range, bits);
+        [self.y * scale_y + offset_y, self.cb * scale_c + offset_c, self.cr * scale_c + offset_c]
+    }
+
+    /// Luma, and chroma, scales, and offsets, of the code values.
+    fn scales(range: VideoRange, bits: u32) -> (f64, f64, f64, f64) {
+        let n = (1u32 << (bits - 8)) as f64;
+        let max = ((1u32 << bits) - 1) as f64;
+        let mid = (1u32 << (bits - 1)) as f64;
+        match range {
+            VideoRange::Limited => (219.0 * n, 224.0 * n, 16.0 * n, mid),
+            VideoRange::Full => (max, max, 0.0, mid),
+        }
+    }
+}
+
+impl RGB {
+    /**
+    Luma, and color difference, values of the gamma-encoded values of this color, using the
+    transfer function of its color space.
+    ```
+    use colorimetry::prelude::*;
+
+    let red = RGB::from_u8(255, 0, 0, None, None);
+    let codes = red.ycbcr(YCbCrMatrix::Bt709).quantize(VideoRange::Limited, 8);
+    assert_eq!(codes, [63, 102, 240]);
+    ```
+    */
+    pub fn ycbcr(&self, matrix: YCbCrMatrix) -> YCbCr {
+        YCbCr::from_encoded_rgb(self.encode_extended(), matrix)
+    }
+}
+
+/// CIE 1931 chromaticity coordinates of the ITU-R BT.2020 red, green, and blue primaries, and
+/// the D65 white point.
+const BT2020_PRIMARIES: [[f64; 2]; 3] = [[0.708, 0.292], [0.170, 0.797], [0.131, 0.046]];
+const BT2020_WHITE: [f64; 2] = [0.3127, 0.3290];
+
+/// ITU-R BT.2100 matrix from BT.2020 RGB to LMS values.
+const BT2100_LMS: Matrix3<f64> = Matrix3::new(
+    1688.0 / 4096.0, 2146.0 / 4096.0, 262.0 / 4096.0,
+    683.0 / 4096.0, 2951.0 / 4096.0, 462.0 / 4096.0,
+    99.0 / 4096.0, 309.0 / 4096.0, 3688.0 / 4096.0,
+);
+
+/// Matrix from XYZ to BT.2020 RGB values, derived from the chromaticities of the primaries, and
+/// the white point.
+pub(crate) fn xyz_to_bt2020() -> Matrix3<f64> {
+    let column = |[x, y]: [f64; 2]| Vector3::new(x / y, 1.0, (1.0 - x - y) / y);
+    let p = Matrix3::from_columns(&BT2020_PRIMARIES.map(column));
+    let p_inv = p.try_inverse().unwrap();
+    let s = p_inv * column(BT2020_WHITE);
+    (p * Matrix3::from_diagonal(&s)).try_inverse().unwrap()
+}
+
+const PQ_M1: f64 = 2610.0 / 16384.0;
+const PQ_M2: f64 = 2523.0 / 4096.0 * 128.0;
+const PQ_C1: f64 = 3424.0 / 4096.0;
+const PQ_C2: f64 = 2413.0 / 4096.0 * 32.0;
+const PQ_C3: f64 = 2392.0 / 4096.0 * 32.0;
+
+/// SMPTE ST 2084 perceptual quantizer, for luminance values relative to 10,000 cd/m².
+pub(crate) fn pq(v: f64) -> f64 {
+    let p = math::powf(v.max(0.0), PQ_M1);
+    math::powf((PQ_C1 + PQ_C2 * p) / (1.0 + PQ_C3 * p), PQ_M2)
+}
+
+/// Inverse of the perceptual quantizer, with luminance values relative to 10,000 cd/m².
+pub(crate) fn pq_inverse(e: f64) -> f64 {
+    let p = math::powf(e.max(0.0), 1.0 / PQ_M2);
+    math::powf((p - PQ_C1).max(0.0) / (PQ_C2 - PQ_C3 * p), 1.0 / PQ_M1)
+}
+
+/// ITU-R BT.2100 matrix from PQ encoded LMS values to ICtCp values.
+const BT2100_ICTCP: Matrix3<f64> = Matrix3::new(
+    0.5, 0.5, 0.0,
+    6610.0 / 4096.0, -13613.0 / 4096.0, 7003.0 / 4096.0,
+    17933.0 / 4096.0, -17390.0 / 4096.0, -543.0 / 4096.0,
+);
+
+/// ITU-R BT.2100 ICtCp values, with the PQ transfer function.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ICtCp {
+    pub i: f64,
+    pub ct: f64,
+    pub cp: f64,
+}
+
+impl ICtCp {
+    /**
+    ICtCp values of tristimulus values, relative to a D65 white, with a luminance value of 100
+    corresponding to an absolute luminance `white_luminance`, in cd/m².
+    ```
+    use colorimetry::prelude::*;
+
+    // neutral colors have no color difference components
+    let white = CIE1931.xyz(&StdIlluminant::D65, None);
+    let ictcp = ICtCp::from_xyz(&white, 100.0);
+    approx::assert_abs_diff_eq!(ictcp.ct, 0.0, epsilon = 1E-3);
+    approx::assert_abs_diff_eq!(ictcp.i, 0.508, epsilon = 1E-3);
+    ```
+    */
+    pub fn from_xyz(xyz: &XYZ, white_luminance: f64) -> Self {
+        Self::from_absolute(Vector3::from(xyz.values()) * (white_luminance / 100.0))
+    }
+
+    /// ICtCp values of absolute tristimulus values, with Y as the luminance in cd/m².
+    pub(crate) fn from_absolute(xyz: Vector3<f64>) -> Self {
+        let [i, ct, cp] = (BT2100_ICTCP * (BT2100_LMS * xyz_to_bt2020() * xyz / 10_000.0).map(pq)).into();
+        Self { i, ct, cp }
+    }
+
+    /// Tristimulus values, as the inverse of [`from_xyz`](Self::from_xyz), with a luminance
+    /// value of 100 for an absolute luminance `white_luminance`, in cd/m².
+    pub fn xyz_values(&self, white_luminance: f64) -> [f64; 3] {
+        let m = BT2100_LMS * xyz_to_bt2020();
+        let lms = BT2100_ICTCP.try_inverse().unwrap() * Vector3::new(self.i, self.ct, self.cp);
+        let xyz = m.try_inverse().unwrap() * lms.map(pq_inverse);
+        (xyz * (10_000.0 * 100.0 / white_luminance)).into()
+    }
+
+    /**
+    ITU-R BT.2124 color difference ΔE<sub>ITP</sub>, with the Ct component halved to obtain the T
+    component, and scaled to a just noticeable difference of about 1.
+    ```
+    use colorimetry::prelude::*;
+
+    let white = CIE1931.xyz(&StdIlluminant::D65, None);
+    let a = ICtCp::from_xyz(&white, 100.0);
+    let b = ICtCp { i: a.i + 1.0 / 720.0, ..a };
+    approx::assert_abs_diff_eq!(a.delta_e_itp(&b), 1.0, epsilon = 1E-9);
+    ```
+    */
+    pub fn delta_e_itp(&self, other: &Self) -> f64 {
+        720.0 * ((self.i - other.i).powi(2) + (0.5 * (self.ct - other.ct)).powi(2) + (self.cp - other.cp).powi(2)).sqrt()
+    }
+}
+
+#[cfg(test)]
+mod video_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_ycbcr() {
+        for matrix in [YCbCrMatrix::Bt601, YCbCrMatrix::Bt709, YCbCrMatrix::Bt2020] {
+            let rgb = [0.2, 0.7, 0.4];
+            let ycbcr = YCbCr::from_encoded_rgb(rgb, matrix);
+            assert_abs_diff_eq!(ycbcr.encoded_rgb(matrix).as_ref(), rgb.as_ref(), epsilon = 1E-12);
+            // gray has no color difference
+            let gray = YCbCr::from_encoded_rgb([0.5; 3], matrix);
+            assert_abs_diff_eq!(gray.cb.hypot(gray.cr), 0.0, epsilon = 1E-12);
+        }
+        let black = YCbCr::default();
+        assert_eq!(black.quantize(VideoRange::Limited, 10), [64, 512, 512]);
+        // out of range values are clamped to the valid codes
+        assert_eq!(YCbCr { y: 1.2, cb: -0.6, cr: 0.0 }.quantize(VideoRange::Limited, 8), [254, 1, 128]);
+        let codes = [180, 100, 150];
+        let decoded = YCbCr::from_quantized(codes, VideoRange::Limited, 8);
+        assert_eq!(decoded.quantize(VideoRange::Limited, 8), codes);
+        assert!(YCbCr::subsampled_chroma(&[]).is_none());
+    }
+
+    #[test]
+    fn test_ictcp() {
+        // PQ encodes 100 cd/m² at about half the signal range
+        let white = CIE1931.xyz(&StdIlluminant::D65, None);
+        let i100 = ICtCp::from_xyz(&white, 100.0).i;
+        assert!(i100 < ICtCp::from_xyz(&white, 1000.0).i);
+        assert_abs_diff_eq!(ICtCp::from_xyz(&white, 10_000.0).i, 1.0, epsilon = 2E-3);
+        let red = RGB::from_u8(255, 0, 0, None, None).xyz();
+        let ictcp = ICtCp::from_xyz(&red, 100.0);
+        assert!(ictcp.cp > 0.05);
+        assert_abs_diff_eq!(ictcp.xyz_values(100.0).as_ref(), red.values().as_ref(), epsilon = 1E-6);
+
+        // ΔEITP is symmetric, and zero for identical colors
+        let green = ICtCp::from_xyz(&RGB::from_u8(0, 255, 0, None, None).xyz(), 100.0);
+        assert_abs_diff_eq!(ictcp.delta_e_itp(&ictcp), 0.0);
+        assert_abs_diff_eq!(ictcp.delta_e_itp(&green), green.delta_e_itp(&ictcp), epsilon = 1E-12);
+        assert!(ictcp.delta_e_itp(&green) > 100.0);
+    }
+}
@@ -4,6 +4,7 @@ use wasm_bindgen::prelude::wasm_bindgen;
 
 
 
+use crate::math;
 use super::cam::{achromatic_rsp, M16, MCAT02, MCAT02INV, MHPE};
 
 
@@ -34,14 +35,14 @@ impl ViewConditions {
 	#[inline]
 	pub fn f_l(&self) -> f64 {
 		let k = self.k();
-		k.powi(4) * self.la + (1. - k.powi(4)).powi(2) / 10. * (5.0 * self.la).powf(1. / 3.)
+		k.powi(4) * self.la + (1. - k.powi(4)).powi(2) / 10. * math::powf(5.0 * self.la, 1. / 3.)
 	}
 
 	pub fn dd(&self) -> f64 {
 		if let Some(d) = self.dopt {
 			d.clamp(0.0, 1.0)
 		} else {
-			(self.f * (1.0 - (1.0 / 3.6) * ((-1.0 * self.la - 42.0) / 92.0).exp())).clamp(0.0, 1.0)
+			(self.f * (1.0 - (1.0 / 3.6) * math::exp((-1.0 * self.la - 42.0) / 92.0))).clamp(0.0, 1.0)
 		}
 	}
 
@@ -50,7 +51,7 @@ impl ViewConditions {
     /// As used in CIECAM02 and CAM16
     /*
     pub fn lum_adapt(&self, v: &mut f64) {
-        let t = (self.f_l() * *v / 100.).powf(0.42);
+        let t = math::powf(self.f_l() * *v / 100., 0.42);
         *v = v.signum() * 400. * t / (27.13 + t) + 0.1;
     }
      */
@@ -67,15 +68,15 @@ impl ViewConditions {
 
         // CIECAM16 eq 3.4
         let f = |q: f64| -> f64 {
-            let t = (fl * q/ 100.).powf(0.42);
+            let t = math::powf(fl * q/ 100., 0.42);
             400.0 * t / (27.13 + t)
         };
 
         // CIECAM16 eq 3.5
         let fp = |qu: f64|->f64 {
             let t = fl * qu/ 100.;
-            let den = 1.68 * 27.13 * fl * t.powf(-0.58); 
-            let nom = (27.13 + t.powf(0.42)).powi(2);
+            let den = 1.68 * 27.13 * fl * math::powf(t, -0.58); 
+            let nom = (27.13 + math::powf(t, 0.42)).powi(2);
             den/nom
         };
         
@@ -151,7 +152,7 @@ impl ReferenceValues {
 		let d_rgb = rgb_w.map(|v|vcd * yw /v + 1.0 - vcd);
 		let n = vc.yb / yw;
 		let z = n.sqrt() + 1.48;
-		let nbb = 0.725 * n.powf(-0.2);
+		let nbb = 0.725 * math::powf(n, -0.2);
 		let ncb = nbb;
         rgb_w.component_mul_assign(&Vector3::from(d_rgb)); // rgb_wc
       //  println!("***RGBwc {rgb_w}");
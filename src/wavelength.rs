@@ -0,0 +1,139 @@
+/*!
+# Wavelength
+
+A [`Wavelength`] holds a vacuum wavelength, and converts it from, and to, the units used in
+different fields: nanometer, as used for spectral data in this library, meter, as used in the
+[`physics`](crate::physics) module, and in SI-based code, ångström, as used in spectroscopy and
+astronomy, and frequency, in terahertz.
+
+Use it at the boundaries of this library, to avoid unit errors when exchanging wavelengths with
+other code.
+*/
+
+use std::fmt;
+
+use crate::{physics::C, spectrum::Spectrum};
+
+/**
+A vacuum wavelength.
+```
+use colorimetry::prelude::*;
+use approx::assert_abs_diff_eq;
+
+let green = Wavelength::from_m(555E-9);
+assert_abs_diff_eq!(green.nm(), 555.0, epsilon = 1E-9);
+assert_abs_diff_eq!(green.angstrom(), 5550.0, epsilon = 1E-9);
+
+// the frequency defining the candela
+let kcd = Wavelength::from_thz(540.0);
+assert_abs_diff_eq!(kcd.nm(), 555.17, epsilon = 5E-3);
+assert_eq!(format!("{green:.1}"), "555.0 nm");
+```
+*/
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Wavelength(f64);
+
+impl Wavelength {
+    pub fn from_nm(nm: f64) -> Self {
+        Self(nm)
+    }
+
+    pub fn from_m(m: f64) -> Self {
+        Self(m * 1E9)
+    }
+
+    pub fn from_angstrom(angstrom: f64) -> Self {
+        Self(angstrom * 0.1)
+    }
+
+    /// The wavelength, in vacuum, of radiation with a frequency in hertz.
+    pub fn from_hz(hz: f64) -> Self {
+        Self::from_m(C / hz)
+    }
+
+    /// The wavelength, in vacuum, of radiation with a frequency in terahertz.
+    pub fn from_thz(thz: f64) -> Self {
+        Self::from_hz(thz * 1E12)
+    }
+
+    pub fn nm(&self) -> f64 {
+        self.0
+    }
+
+    pub fn m(&self) -> f64 {
+        self.0 * 1E-9
+    }
+
+    pub fn angstrom(&self) -> f64 {
+        self.0 * 10.0
+    }
+
+    pub fn hz(&self) -> f64 {
+        C / self.m()
+    }
+
+    pub fn thz(&self) -> f64 {
+        self.hz() * 1E-12
+    }
+
+    /// Checks if the wavelength is in the 380 to 780 nanometer domain of the spectra in this
+    /// library.
+    pub fn in_domain(&self) -> bool {
+        (380.0..=780.0).contains(&self.0)
+    }
+}
+
+impl fmt::Display for Wavelength {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)?;
+        write!(f, " nm")
+    }
+}
+
+impl Spectrum {
+    /**
+    Spectral value at a wavelength, linearly interpolated between the values at integer
+    nanometers, or NaN if the wavelength is outside the 380 to 780 nanometer domain.
+    ```
+    use colorimetry::prelude::*;
+
+    let s = Colorant::top_hat(550.0, 100.0);
+    assert_eq!(s.at(Wavelength::from_m(520E-9)), 1.0);
+    assert_eq!(s.at(Wavelength::from_nm(600.5)), 0.5);
+    assert!(s.at(Wavelength::from_thz(1000.0)).is_nan());
+    ```
+    */
+    pub fn at(&self, wavelength: Wavelength) -> f64 {
+        if !wavelength.in_domain() {
+            return f64::NAN;
+        }
+        let l = wavelength.nm() - 380.0;
+        let i = (l.floor() as usize).min(399);
+        let f = l - i as f64;
+        self.0[i] * (1.0 - f) + self.0[i + 1] * f
+    }
+}
+
+#[cfg(test)]
+mod wavelength_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_wavelength() {
+        for nm in [380.0, 555.0, 780.0] {
+            let w = Wavelength::from_nm(nm);
+            assert_abs_diff_eq!(Wavelength::from_m(w.m()).nm(), nm, epsilon = 1E-9);
+            assert_abs_diff_eq!(Wavelength::from_angstrom(w.angstrom()).nm(), nm, epsilon = 1E-9);
+            assert_abs_diff_eq!(Wavelength::from_thz(w.thz()).nm(), nm, epsilon = 1E-9);
+            assert!(w.in_domain());
+        }
+        assert!(Wavelength::from_nm(379.9) < Wavelength::from_nm(380.0));
+        assert!(!Wavelength::from_nm(379.9).in_domain());
+
+        let s = Colorant::gaussian(550.0, 20.0);
+        assert_eq!(s.at(Wavelength::from_nm(380.0)), s[380]);
+        assert_eq!(s.at(Wavelength::from_nm(780.0)), s[780]);
+        assert_abs_diff_eq!(s.at(Wavelength::from_nm(549.5)), (s[549] + s[550]) / 2.0, epsilon = 1E-15);
+    }
+}
@@ -0,0 +1,343 @@
+/*!
+# Wavelength Domains
+
+A [`Spectrum`] in this library always uses the wavelength domain from 380 to 780 nanometer, with
+1 nanometer steps, which allows the observer integrations, and spectral arithmetic, to use fast,
+statically sized, vector operations.
+Measured data often uses another domain: ultraviolet, and near-infrared, capable instruments
+cover 360 to 830 nanometer, or more, and many instruments, and standard tables, use 5, or 10,
+nanometer steps.
+
+A [`DomainSpectrum`] keeps spectral values on their native [`WavelengthDomain`], without loss of
+the data outside the library's domain, and without the memory overhead of 1 nanometer data for
+coarsely sampled spectra. It is resampled, by linear interpolation, only when needed:
+
+- [`DomainSpectrum::spectrum`] resamples it to the library's domain on first use, and keeps the
+  result, for observer integrations, and conversion into an [`Illuminant`], [`Stimulus`], or
+  [`Colorant`];
+- arithmetic between spectra on different domains resamples both to the overlapping range of their
+  domains, with the smallest of their steps.
+
+Values outside the range of a domain are extrapolated using the values at its ends, as in
+[`Spectrum::try_linear_interpolate`].
+
+[`Spectrum`], [`Illuminant`], [`Colorant`], and [`Stimulus`] do not carry a native domain: they
+hold their values on the library's domain, with statically sized vectors, used by all observer
+integrations, and a [`DomainSpectrum`] is resampled when it is converted into one of them.
+Keep measured data in a [`DomainSpectrum`] for as long as its native domain is needed.
+```
+use colorimetry::prelude::*;
+
+// a 5 nm reflectance measurement from 360 to 830 nm
+let domain = WavelengthDomain::try_new(360.0, 830.0, 5.0).unwrap();
+assert_eq!(domain.len(), 95);
+let values: Vec<f64> = domain.wavelengths().map(|l| if l < 600.0 { 0.1 } else { 0.8 }).collect();
+let red = DomainSpectrum::try_new(domain, values).unwrap();
+assert_eq!(red.value_at(820.0), 0.8);
+
+let colorant = Colorant::try_from(&red).unwrap();
+let xyz = CIE1931.xyz(&StdIlluminant::D65, Some(&colorant));
+assert!(xyz.xy().x() > 0.4);
+```
+*/
+
+use std::{
+    ops::{Add, Mul},
+    sync::OnceLock,
+};
+
+use crate::{
+    colorant::Colorant,
+    error::CmtError,
+    illuminant::Illuminant,
+    spectrum::{Spectrum, NS},
+    stimulus::Stimulus,
+};
+
+/// An equidistant wavelength domain, with its first wavelength, its step, and its number of
+/// wavelengths, in nanometer.
+///
+/// With the `serde` feature, it is represented by its first and last wavelengths, and its step,
+/// as the domain of a serialized [`Spectrum`], and validated by [`WavelengthDomain::try_new`] on
+/// deserialization.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WavelengthDomain {
+    start: f64,
+    step: f64,
+    len: usize,
+}
+
+impl WavelengthDomain {
+    /// The domain of the library, from 380 to 780 nanometer, with 1 nanometer steps.
+    pub const LIBRARY: Self = Self { start: 380.0, step: 1.0, len: NS };
+
+    /// A domain from a start wavelength to an end wavelength, in nanometer, with a step size
+    /// which divides the range in a whole number of steps.
+    pub fn try_new(start: f64, end: f64, step: f64) -> Result<Self, CmtError> {
+        if !(start.is_finite() && end.is_finite() && step.is_finite()) || step <= 0.0 || end <= start {
+            return Err(CmtError::InterpolateWavelengthError);
+        }
+        let steps = (end - start) / step;
+        if steps >= usize::MAX as f64 {
+            return Err(CmtError::ErrorString(format!(
+                "WavelengthDomain: too many steps of {step} from {start} to {end}"
+            )));
+        }
+        if (steps - steps.round()).abs() > 1E-6 {
+            return Err(CmtError::ErrorString(format!(
+                "WavelengthDomain: the step size {step} does not divide the range from {start} to {end}"
+            )));
+        }
+        Ok(Self { start, step, len: steps.round() as usize + 1 })
+    }
+
+    pub fn start(&self) -> f64 {
+        self.start
+    }
+
+    pub fn end(&self) -> f64 {
+        self.start + (self.len - 1) as f64 * self.step
+    }
+
+    pub fn step(&self) -> f64 {
+        self.step
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The wavelengths of the domain, in nanometer.
+    pub fn wavelengths(&self) -> impl Iterator<Item = f64> + '_ {
+        (0..self.len).map(|i| self.start + i as f64 * self.step)
+    }
+
+    /// Checks if a wavelength, in nanometer, is within the range of the domain.
+    pub fn contains(&self, wavelength: f64) -> bool {
+        (self.start..=self.end()).contains(&wavelength)
+    }
+
+    /// The overlapping range of two domains, with the smallest of their step sizes; the range is
+    /// reduced to a whole number of steps.
+    pub fn overlap(&self, other: &Self) -> Result<Self, CmtError> {
+        let start = self.start.max(other.start);
+        let end = self.end().min(other.end());
+        let step = self.step.min(other.step);
+        if end <= start {
+            return Err(CmtError::ErrorString("WavelengthDomain: domains do not overlap".into()));
+        }
+        Ok(Self { start, step, len: ((end - start) / step + 1E-6).floor() as usize + 1 })
+    }
+}
+
+/// Spectral values on their native wavelength domain, resampled to the library's domain on first
+/// use.
+#[derive(Clone, Debug)]
+pub struct DomainSpectrum {
+    domain: WavelengthDomain,
+    values: Vec<f64>,
+    spectrum: OnceLock<Spectrum>,
+}
+
+impl DomainSpectrum {
+    /// Spectral values, one for each of the wavelengths of a domain.
+    pub fn try_new(domain: WavelengthDomain, values: Vec<f64>) -> Result<Self, CmtError> {
+        if values.len() != domain.len() {
+            return Err(CmtError::ErrorString(format!(
+                "DomainSpectrum: expected {} values, but got {}",
+                domain.len(),
+                values.len()
+            )));
+        }
+        Ok(Self { domain, values, spectrum: OnceLock::new() })
+    }
+
+    pub fn domain(&self) -> WavelengthDomain {
+        self.domain
+    }
+
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    /// Value at a wavelength, in nanometer, linearly interpolated, and extrapolated with the
+    /// values at the ends of the domain.
+    pub fn value_at(&self, wavelength: f64) -> f64 {
+        let x = ((wavelength - self.domain.start) / self.domain.step).clamp(0.0, (self.domain.len - 1) as f64);
+        let i = (x.floor() as usize).min(self.domain.len.saturating_sub(2));
+        let f = x - i as f64;
+        if f == 0.0 || i + 1 >= self.values.len() {
+            self.values[i]
+        } else {
+            self.values[i] * (1.0 - f) + self.values[i + 1] * f
+        }
+    }
+
+    /// Resamples the values to another domain, using linear interpolation.
+    pub fn resample(&self, domain: WavelengthDomain) -> Self {
+        if domain == self.domain {
+            return self.clone();
+        }
+        let values = domain.wavelengths().map(|l| self.value_at(l)).collect();
+        Self { domain, values, spectrum: OnceLock::new() }
+    }
+
+    /// The values resampled to the library's domain, from 380 to 780 nanometer, with 1 nanometer
+    /// steps, calculated on first use.
+    pub fn spectrum(&self) -> &Spectrum {
+        self.spectrum.get_or_init(|| {
+            let resampled = self.resample(WavelengthDomain::LIBRARY);
+            // unwrap: the library domain has NS values
+            Spectrum::try_from(resampled.values.as_slice()).unwrap()
+        })
+    }
+
+    /// Applies an elementwise operation to two spectra, on their common domain, or on the
+    /// overlap of their domains.
+    fn zip_with(&self, rhs: &Self, f: impl Fn(f64, f64) -> f64) -> Result<Self, CmtError> {
+        let domain = if self.domain == rhs.domain { self.domain } else { self.domain.overlap(&rhs.domain)? };
+        let (a, b) = (self.resample(domain), rhs.resample(domain));
+        let values = a.values.iter().zip(&b.values).map(|(&a, &b)| f(a, b)).collect();
+        Ok(Self { domain, values, spectrum: OnceLock::new() })
+    }
+
+    /// Sum of two spectra, on the overlap of their domains.
+    pub fn try_add(&self, rhs: &Self) -> Result<Self, CmtError> {
+        self.zip_with(rhs, |a, b| a + b)
+    }
+
+    /// Product of two spectra, such as a light and a filter, on the overlap of their domains.
+    pub fn try_mul(&self, rhs: &Self) -> Result<Self, CmtError> {
+        self.zip_with(rhs, |a, b| a * b)
+    }
+}
+
+/// Spectra are equal if their domains, and their values, are equal.
+impl PartialEq for DomainSpectrum {
+    fn eq(&self, other: &Self) -> bool {
+        self.domain == other.domain && self.values == other.values
+    }
+}
+
+/// Sum of two spectra, on the overlap of their domains; panics if they do not overlap, use
+/// [`DomainSpectrum::try_add`] to handle this case.
+impl Add for &DomainSpectrum {
+    type Output = DomainSpectrum;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.try_add(rhs).expect("spectra with overlapping domains")
+    }
+}
+
+/// Product of two spectra, on the overlap of their domains; panics if they do not overlap, use
+/// [`DomainSpectrum::try_mul`] to handle this case.
+impl Mul for &DomainSpectrum {
+    type Output = DomainSpectrum;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.try_mul(rhs).expect("spectra with overlapping domains")
+    }
+}
+
+impl Mul<f64> for DomainSpectrum {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        let values = self.values.iter().map(|v| v * rhs).collect();
+        Self { domain: self.domain, values, spectrum: OnceLock::new() }
+    }
+}
+
+/// A spectrum of the library, on its own domain.
+impl From<&Spectrum> for DomainSpectrum {
+    fn from(spectrum: &Spectrum) -> Self {
+        let values = AsRef::<[f64]>::as_ref(spectrum).to_vec();
+        Self { domain: WavelengthDomain::LIBRARY, values, spectrum: OnceLock::from(*spectrum) }
+    }
+}
+
+impl From<&DomainSpectrum> for Illuminant {
+    fn from(spectrum: &DomainSpectrum) -> Self {
+        Illuminant(*spectrum.spectrum())
+    }
+}
+
+impl From<&DomainSpectrum> for Stimulus {
+    fn from(spectrum: &DomainSpectrum) -> Self {
+        Stimulus(*spectrum.spectrum())
+    }
+}
+
+/// Spectral reflectance, or transmittance, values, checked to be within the range from 0.0 to
+/// 1.0, in the library's domain.
+impl TryFrom<&DomainSpectrum> for Colorant {
+    type Error = CmtError;
+
+    fn try_from(spectrum: &DomainSpectrum) -> Result<Self, Self::Error> {
+        Colorant::try_from(spectrum.spectrum().as_ref() as &[f64])
+    }
+}
+
+#[cfg(test)]
+mod wavelength_domain_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_domain() {
+        let domain = WavelengthDomain::try_new(360.0, 830.0, 5.0).unwrap();
+        assert_eq!((domain.len(), domain.end()), (95, 830.0));
+        assert!(domain.contains(365.0) && !domain.contains(840.0));
+        assert!(WavelengthDomain::try_new(380.0, 780.0, 3.0).is_err());
+        assert!(WavelengthDomain::try_new(780.0, 380.0, 1.0).is_err());
+        assert!(WavelengthDomain::try_new(f64::NAN, 780.0, 1.0).is_err());
+        assert!(WavelengthDomain::try_new(380.0, f64::INFINITY, 1.0).is_err());
+        assert!(WavelengthDomain::try_new(380.0, 780.0, f64::INFINITY).is_err());
+        assert!(WavelengthDomain::try_new(0.0, 1E300, 1.0).is_err());
+        assert_eq!(WavelengthDomain::try_new(380.0, 780.0, 1.0).unwrap(), WavelengthDomain::LIBRARY);
+
+        let other = WavelengthDomain::try_new(400.0, 700.0, 10.0).unwrap();
+        let overlap = domain.overlap(&other).unwrap();
+        assert_eq!((overlap.start(), overlap.end(), overlap.step()), (400.0, 700.0, 5.0));
+        assert!(other.overlap(&WavelengthDomain::try_new(750.0, 800.0, 10.0).unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_domain_spectrum() {
+        // D65 at 5 nm steps, from 300 to 830 nm
+        let domain = WavelengthDomain::try_new(300.0, 830.0, 5.0).unwrap();
+        let d65 = Illuminant::d65();
+        let values = domain.wavelengths().map(|l| if (380.0..=780.0).contains(&l) { d65[l as usize] } else { 1.0 }).collect();
+        let native = DomainSpectrum::try_new(domain, values).unwrap();
+        assert_eq!(native.values().len(), 107);
+        assert_eq!(native.value_at(300.0), 1.0);
+        assert_abs_diff_eq!(native.value_at(560.0), d65[560], epsilon = 1E-12);
+
+        // resampled to the library's domain, with the data outside it not used
+        let xyz = CIE1931.xyz(&Illuminant::from(&native), None);
+        let [x, y] = xyz.xy().values();
+        assert_abs_diff_eq!(x, 0.3127, epsilon = 5E-4);
+        assert_abs_diff_eq!(y, 0.3290, epsilon = 5E-4);
+
+        // arithmetic on the overlap of the domains, with the smallest step
+        let filter_domain = WavelengthDomain::try_new(380.0, 780.0, 2.0).unwrap();
+        let half = DomainSpectrum::try_new(filter_domain, vec![0.5; filter_domain.len()]).unwrap();
+        let product = &native * &half;
+        assert_eq!(product.domain(), WavelengthDomain::try_new(380.0, 780.0, 2.0).unwrap());
+        assert_abs_diff_eq!(product.value_at(560.0), 0.5 * d65[560], epsilon = 1E-12);
+        let sum = &half + &half;
+        assert_eq!(sum.values()[0], 1.0);
+        assert!(native.try_mul(&DomainSpectrum::try_new(WavelengthDomain::try_new(850.0, 900.0, 10.0).unwrap(), vec![1.0; 6]).unwrap()).is_err());
+
+        // the library's spectra roundtrip without loss
+        let spectrum = DomainSpectrum::from(&*d65);
+        assert_eq!(spectrum.domain(), WavelengthDomain::LIBRARY);
+        assert_eq!(spectrum.spectrum(), &*d65);
+        assert!(Colorant::try_from(&(native * 0.001)).is_ok());
+        assert!(Stimulus::from(&half)[500] == 0.5);
+    }
+}
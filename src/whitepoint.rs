@@ -0,0 +1,142 @@
+/*!
+# White Points
+
+Named reference whites, for use wherever a reference white is required, such as in the definition
+of RGB color spaces, CIELAB calculations, or dominant wavelength calculations.
+
+Most white points are defined by a spectral distribution, such as the CIE D65 and D50 standard
+illuminants, and their tristimulus values can be calculated for any of the observers in this
+library. These are calculated on first use, and buffered.
+Some, such as the DCI projector white, are defined by their CIE 1931 chromaticity coordinates only,
+and are available for the CIE 1931 standard observer only.
+*/
+
+use std::sync::OnceLock;
+
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::{
+    error::CmtError,
+    illuminant::Illuminant,
+    observer::Observer,
+    std_illuminants::StdIlluminant,
+    xyz::XYZ,
+};
+
+/// Correlated color temperature used for the D93 white point.
+const D93_CCT: f64 = 9300.0;
+
+/// CIE 1931 chromaticity coordinates of the DCI-P3 theater projector white, as specified in SMPTE
+/// RP 431-2. It has a correlated color temperature of about 6300 K, and is located above the
+/// Planckian locus, with a greenish tint.
+const DCI_XY: [f64;2] = [0.314, 0.351];
+
+/**
+Named white points.
+
+- `D65`: CIE D65 standard daylight illuminant, the white point of the sRGB, Display P3
+  (P3-D65), Rec. 709 and Rec. 2020 color spaces.
+- `D50`: CIE D50 standard daylight illuminant, used in the graphic arts and in ICC color
+  profiles.
+- `E`: the equal energy white.
+- `D93`: CIE daylight illuminant with a correlated color temperature of 9300 K, used as display
+  white in East-Asian broadcast.
+- `DCI`: the 6300 K DCI-P3 theater projector white, defined by its CIE 1931 chromaticity only.
+*/
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, strum_macros::Display, strum_macros::EnumIter)]
+pub enum WhitePoint {
+    D65,
+    D50,
+    E,
+    D93,
+    DCI,
+}
+
+impl WhitePoint {
+
+    /// Spectral distribution of the white point, if it is defined by a spectrum.
+    pub fn illuminant(&self) -> Option<Illuminant> {
+        match self {
+            WhitePoint::D65 => Some(StdIlluminant::D65.into()),
+            WhitePoint::D50 => Some(StdIlluminant::D50.into()),
+            WhitePoint::E => Some(Illuminant::equal_energy()),
+            // unwrap: temperature in range of the D-illuminant
+            WhitePoint::D93 => Some(Illuminant::try_d_illuminant(D93_CCT).unwrap()),
+            WhitePoint::DCI => None,
+        }
+    }
+
+    /// Tristimulus values of the white point for an observer, normalized to a luminous value
+    /// of 100.0.
+    ///
+    /// Values are calculated on first use. White points defined by chromaticity coordinates
+    /// only, such as `DCI`, are only available for the CIE 1931 standard observer.
+    /// ```
+    /// use colorimetry::prelude::*;
+    /// use approx::assert_abs_diff_eq;
+    ///
+    /// let [x, y] = WhitePoint::D65.xyz(Observer::Std1931).unwrap().xy().values();
+    /// assert_abs_diff_eq!(x, 0.3127, epsilon = 1E-4);
+    /// assert_abs_diff_eq!(y, 0.3290, epsilon = 1E-4);
+    /// ```
+    pub fn xyz(&self, observer: Observer) -> Result<XYZ, CmtError> {
+        const WHITE_POINTS_LEN: usize = 64;
+        static WHITE_POINTS: OnceLock<[OnceLock<Option<XYZ>>;WHITE_POINTS_LEN]> = OnceLock::new();
+        let white_points = WHITE_POINTS.get_or_init(||[const { OnceLock::new() }; WHITE_POINTS_LEN]);
+        let xyz = white_points[observer as usize * 8 + *self as usize].get_or_init(||{
+            match (self.illuminant(), self) {
+                (Some(illuminant), _) => Some(observer.data().xyz_from_spectrum(&illuminant, None).set_illuminance(100.0)),
+                (None, WhitePoint::DCI) if observer == Observer::Std1931 => {
+                    let [x, y] = DCI_XY;
+                    XYZ::try_from_chromaticity(x, y, None, Some(observer)).ok()
+                }
+                _ => None
+            }
+        });
+        xyz.ok_or(CmtError::RequiresCIE1931XYZ)
+    }
+
+    /// Chromaticity coordinates of the white point for an observer.
+    pub fn chromaticity(&self, observer: Observer) -> Result<[f64;2], CmtError> {
+        Ok(self.xyz(observer)?.xy().values())
+    }
+}
+
+#[cfg(test)]
+mod whitepoint_test {
+    use crate::prelude::*;
+    use approx::assert_abs_diff_eq;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn test_cie1931(){
+        for (wp, want) in [
+            (WhitePoint::D65, [0.3127, 0.3290]),
+            (WhitePoint::D50, [0.3457, 0.3585]),
+            (WhitePoint::E, [1.0/3.0, 1.0/3.0]),
+            (WhitePoint::D93, [0.2831, 0.2971]),
+            (WhitePoint::DCI, [0.314, 0.351]),
+        ] {
+            let xy = wp.chromaticity(Observer::Std1931).unwrap();
+            assert_abs_diff_eq!(xy.as_ref(), want.as_ref(), epsilon = 2E-4);
+        }
+    }
+
+    #[test]
+    fn test_normalized(){
+        for wp in WhitePoint::iter() {
+            let xyz = wp.xyz(Observer::Std1931).unwrap();
+            assert_abs_diff_eq!(xyz.luminous_value(), 100.0, epsilon = 1E-10);
+        }
+    }
+
+    #[cfg(feature="supplemental-observers")]
+    #[test]
+    fn test_cie1964(){
+        // CIE 15:2004 Table T.3, 10º observer
+        let xy = WhitePoint::D65.chromaticity(Observer::Std1964).unwrap();
+        assert_abs_diff_eq!(xy.as_ref(), [0.31382, 0.33100].as_ref(), epsilon = 2E-4);
+        assert!(WhitePoint::DCI.xyz(Observer::Std1964).is_err());
+    }
+}
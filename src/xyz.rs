@@ -7,6 +7,7 @@ use crate::{
     geometry::{LineAB, Orientation},
     observer::{self, Observer},
     error::CmtError,
+    math,
     illuminant::Illuminant,
     rgbspace::RgbSpace,
     spectrum::Spectrum,
@@ -22,6 +23,7 @@ pub const XYZ_D65WHITE: XYZ = XYZ::new(&D65A, Some(&D65A), Observer::Std1931);
 
 #[wasm_bindgen]
 #[derive(Clone, Copy, Debug, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// A set of two CIE XYZ Tristimulus values, for a Standard Observer.
 /// 
 /// One is associated with an illuminant or a reference white value, denoted by the fieldname `xyzn`, and
@@ -79,6 +81,15 @@ impl XYZ {
         XYZ::try_from_chromaticity(x, y, l, observer)
     }
 
+    /// Create tristimulus values from CIE 1976 u'v' chromaticity coordinates,
+    /// with optional Luminous Value l, with a default value of 100.0.
+    pub fn try_from_uvprime(u: f64, v: f64, l: Option<f64>, observer: Option<Observer>) ->Result<XYZ, CmtError> {
+        let den = 6.0 * u - 16.0 * v + 12.0;
+        let x = (9.0 * u)/den;
+        let y = (4.0 * v)/den;
+        XYZ::try_from_chromaticity(x, y, l, observer)
+    }
+
     /// Try to add two tristimulus values.
     /// Requires sharing the same observer, and no reference white set.
     /// Used for adding illuminants.
@@ -136,18 +147,27 @@ impl XYZ {
     }
     
     /// The chromaticity coordinates as an array with an  x and y coordinate
+    #[deprecated(since = "0.0.3", note = "use `XYZ::xy`, which returns a `Chromaticity`")]
+    pub fn chromaticity(&self) -> [f64; 2] {
+        self.xy().values()
+    }
+
+    /// Chromaticity of the tristimulus values, as a [`Chromaticity`](crate::chromaticity::Chromaticity),
+    /// which converts to CIE 1976 (u',v'), and CIE 1960 (u,v), coordinates, and calculates
+    /// distances in these diagrams, without mixing up the coordinate systems.
+    /// Uses xyz tristimulus values if present, else uses illuminant's values.
     /// ```
     /// use colorimetry::prelude::*;
     /// use approx::assert_ulps_eq;
     ///
     /// let d65_xyz = CIE1931.xyz(&StdIlluminant::D65, None);
-    /// let xy = d65_xyz.chromaticity();
-    /// assert_ulps_eq!(xy.as_slice(), [0.312_738, 0.329_052].as_slice(), epsilon = 1E-6);
+    /// let xy = d65_xyz.xy();
+    /// assert_ulps_eq!(xy.values().as_slice(), [0.312_738, 0.329_052].as_slice(), epsilon = 1E-6);
     /// ```
-    pub fn chromaticity(&self) -> [f64; 2] {
+    pub fn xy(&self) -> crate::chromaticity::Chromaticity {
         let [x,y,z] = self.values();
         let s = x + y + z;
-        [x/s, y/s]
+        crate::chromaticity::Chromaticity { x: x/s, y: y/s }
     }
 
     /// Luminous value Y of the tristimulus values.
@@ -163,10 +183,9 @@ impl XYZ {
 
     /// CIE 1960 UCS Color Space uv coordinates *Deprecated* by the CIE, but
     /// still used for CCT calculation. Applied to illuminant xyzn values only.
+    #[deprecated(since = "0.0.3", note = "use `XYZ::xy`, and `Chromaticity::uv60`, which returns typed coordinates")]
     pub fn uv60(&self) -> [f64; 2] {
-        let &[x, y, z] = self.xyz.unwrap_or(self.xyzn).as_ref();
-        let den = x + 15.0 * y + 3.0 * z;
-        [4.0 * x / den, 6.0 * y / den]
+        self.xy().uv60().values()
     }
     
     /// The CIE 1964 (U*, V*, W*) color space, also known as CIEUVW, based on
@@ -175,26 +194,87 @@ impl XYZ {
     /// Uses xyz tristimulus values if present, else uses illuminant's values.
     pub fn uvw64(&self, xyz_ref: XYZ) -> [f64; 3] {
         let yy = self.luminous_value();
-        let [ur, vr] = xyz_ref.uv60();
-        let [u, v] = self.uv60();
-        let ww = 25.0 * yy.powf(1.0 / 3.0) - 17.0;
+        let [ur, vr] = xyz_ref.xy().uv60().values();
+        let [u, v] = self.xy().uv60().values();
+        let ww = 25.0 * math::powf(yy, 1.0 / 3.0) - 17.0;
         let uu = 13.0 * ww * (u - ur);
         let vv = 13.0 * ww * (v - vr);
         [uu, vv, ww]
     }
 
     /// CIE 1976 CIELUV space, with (u',v') coordinates, calculated for stimulus xyz if present, or else for illuminant.
+    #[deprecated(since = "0.0.3", note = "use `XYZ::xy`, and `Chromaticity::uvprime`, which returns typed coordinates")]
     pub fn uvprime(&self) -> [f64;2] {
-        let &[x, y, z] = self.xyz.unwrap_or(self.xyzn).as_ref();
-        let den = x + 15.0 * y + 3.0 * z;
-        [4.0 * x / den, 9.0 * y / den]
+        self.xy().uvprime().values()
     }
 
-    //
+    /// Distance Δu'v' between the chromaticities of two sets of tristimulus values, in the CIE 1976
+    /// (u',v') diagram.
     pub fn uv_prime_distance(&self, other: &Self) -> f64 {
-        let [u1, v1] = self.uvprime();
-        let [u2, v2]= other.uvprime();
-        (v2-v1).hypot(u2-u1)
+        self.xy().distance_uvprime(&other.xy())
+    }
+
+    /// Corresponding tristimulus values, as seen by an observer adapted to a white `to_white`, of
+    /// these values seen by an observer adapted to a white `from_white`, using a chromatic
+    /// adaptation transform. The whites are taken from their stimulus values if present, or else
+    /// from their illuminant values.
+    pub fn adapt(&self, cat: crate::cat::Cat, from_white: &XYZ, to_white: &XYZ) -> Result<XYZ, CmtError> {
+        if self.observer != from_white.observer || self.observer != to_white.observer {
+            return Err(CmtError::RequireSameObserver);
+        }
+        let [from, to] = [from_white, to_white].map(|w| w.xyz.unwrap_or(w.xyzn));
+        let m = cat.matrix(from, to);
+        Ok(XYZ::from_vecs(m * self.xyzn, self.xyz.map(|v| m * v), self.observer))
+    }
+
+    /// CIEDE2000 color difference with another stimulus, with their CIELAB values calculated for
+    /// a reference white, taken from the stimulus values of `white` if present, or else from its
+    /// illuminant values.
+    ///
+    /// ```
+    /// use colorimetry::prelude::*;
+    ///
+    /// let white = CIE1931.xyz(&StdIlluminant::D65, None);
+    /// let red = CIE1931.xyz(&StdIlluminant::D65, Some(&Colorant::gaussian(620.0, 40.0)));
+    /// let orange = CIE1931.xyz(&StdIlluminant::D65, Some(&Colorant::gaussian(600.0, 40.0)));
+    /// let de = red.delta_e2000(&orange, &white).unwrap();
+    /// let lab = |xyz| CieLab::try_from(xyz).unwrap();
+    /// approx::assert_abs_diff_eq!(de, lab(red).de2000(&lab(orange)).unwrap(), epsilon = 1E-9);
+    /// ```
+    pub fn delta_e2000(&self, other: &Self, white: &XYZ) -> Result<f64, CmtError> {
+        if self.observer != other.observer || self.observer != white.observer {
+            return Err(CmtError::RequireSameObserver);
+        }
+        let xyzn = white.xyz.unwrap_or(white.xyzn);
+        let lab = |xyz: &XYZ| crate::lab::CieLab::try_from(XYZ::from_vecs(xyzn, xyz.xyz, xyz.observer));
+        lab(self)?.de2000(&lab(other)?)
+    }
+
+    /// ITU-R BT.2124 ΔE<sub>ITP</sub> color difference, for HDR video, with the tristimulus values
+    /// relative to a reference white, taken from the stimulus values of `white` if present, or
+    /// else from its illuminant values, with an absolute luminance `white_luminance`, in cd/m².
+    /// The metric is defined for the CIE 1931 observer only.
+    ///
+    /// ```
+    /// use colorimetry::prelude::*;
+    ///
+    /// let white = CIE1931.xyz(&StdIlluminant::D65, None);
+    /// let red = CIE1931.xyz(&StdIlluminant::D65, Some(&Colorant::gaussian(620.0, 40.0)));
+    /// let orange = CIE1931.xyz(&StdIlluminant::D65, Some(&Colorant::gaussian(600.0, 40.0)));
+    /// let sdr = red.delta_e_itp(&orange, &white, 100.0).unwrap();
+    /// let hdr = red.delta_e_itp(&orange, &white, 1000.0).unwrap();
+    /// assert!(hdr > sdr);
+    /// ```
+    pub fn delta_e_itp(&self, other: &Self, white: &XYZ, white_luminance: f64) -> Result<f64, CmtError> {
+        if self.observer != other.observer || self.observer != white.observer {
+            return Err(CmtError::RequireSameObserver);
+        }
+        if self.observer != Observer::Std1931 {
+            return Err(CmtError::RequiresCIE1931XYZ);
+        }
+        let scale = white_luminance / white.xyz.unwrap_or(white.xyzn).y;
+        let itp = |xyz: &XYZ| crate::video::ICtCp::from_absolute(xyz.xyz.unwrap_or(xyz.xyzn) * scale);
+        Ok(itp(self).delta_e_itp(&itp(other)))
     }
     
     /// The Dominant Wavelength of a color point is the wavelength of spectral
@@ -217,11 +297,11 @@ impl XYZ {
         if white.observer!=self.observer {
             Err(CmtError::RequireSameObserver)
         } else {
-            let [mut x, mut y] = self.chromaticity();
-            let [xw, yw] = white.chromaticity();
+            let [mut x, mut y] = self.xy().values();
+            let [xw, yw] = white.xy().values();
             // if color point is in the purple rotate it around the white point by 180º, and give wavelength a negative value
-            let blue_edge = LineAB::try_new([xw, yw], self.observer.data().spectral_locus_by_nm(low).unwrap().chromaticity()).unwrap();
-            let red_edge = LineAB::try_new([xw, yw], self.observer.data().spectral_locus_by_nm(high).unwrap().chromaticity()).unwrap();
+            let blue_edge = LineAB::try_new([xw, yw], self.observer.data().spectral_locus_by_nm(low).unwrap().xy().values()).unwrap();
+            let red_edge = LineAB::try_new([xw, yw], self.observer.data().spectral_locus_by_nm(high).unwrap().xy().values()).unwrap();
             match (blue_edge.orientation(x, y), red_edge.orientation(x, y)) {
                 (Orientation::Colinear, _) => return Ok(380.0),
                 (_, Orientation::Colinear) => return Ok(699.0),
@@ -234,7 +314,7 @@ impl XYZ {
             }
             // start bisectional search
             while high - low > 1 {
-                let bisect = LineAB::try_new([xw, yw], self.observer.data().spectral_locus_by_nm(mid).unwrap().chromaticity()).unwrap();
+                let bisect = LineAB::try_new([xw, yw], self.observer.data().spectral_locus_by_nm(mid).unwrap().xy().values()).unwrap();
              //   let a = bisect.angle_deg();
                 match bisect.orientation(x, y) {
                     Orientation::Left => high = mid,
@@ -250,9 +330,9 @@ impl XYZ {
             if low == high {
                 Ok(sign * low as f64)
             } else {
-                let low_ab = LineAB::try_new(white.chromaticity(), self.observer.data().spectral_locus_by_nm(low).unwrap().chromaticity()).unwrap();
+                let low_ab = LineAB::try_new(white.xy().values(), self.observer.data().spectral_locus_by_nm(low).unwrap().xy().values()).unwrap();
                 let dlow = low_ab.distance_with_sign(x, y);
-                let high_ab = LineAB::try_new(white.chromaticity(), self.observer.data().spectral_locus_by_nm(high).unwrap().chromaticity()).unwrap();
+                let high_ab = LineAB::try_new(white.xy().values(), self.observer.data().spectral_locus_by_nm(high).unwrap().xy().values()).unwrap();
                 let dhigh= high_ab.distance_with_sign(x, y);
                 if dlow<0.0 || dhigh>0.0 { // not ended up between two lines
                     let s = format!("bisection error in dominant wavelength search:  {dlow} {low} {dhigh} {high}");
@@ -264,6 +344,114 @@ impl XYZ {
         }
     }
 
+    /**
+    Excitation purity, as the ratio of the distances, in the xy chromaticity diagram, from the
+    white point to this color, and from the white point to the boundary of the diagram, along
+    the line through both points: the spectral locus point at the dominant wavelength, or, for
+    purples, with a negative dominant wavelength, the point on the purple line.
+    It ranges from 0.0 for the white, to 1.0 for spectral colors.
+    ```
+    use colorimetry::prelude::*;
+
+    let d65 = CIE1931.xyz_d65();
+    let amber = CIE1931.xyz(&StdIlluminant::D65, Some(&Colorant::gaussian(590.0, 10.0)));
+    let pe = amber.excitation_purity(d65).unwrap();
+    assert!(pe > 0.8 && pe < 1.0);
+    approx::assert_abs_diff_eq!(CIE1931.spectral_locus_by_nm(520).unwrap().excitation_purity(d65).unwrap(), 1.0, epsilon = 1E-9);
+    ```
+    */
+    pub fn excitation_purity(&self, white: XYZ) -> Result<f64, CmtError> {
+        let [x, y] = self.xy().values();
+        let [xw, yw] = white.xy().values();
+        let d = math::hypot(x - xw, y - yw);
+        if d < f64::EPSILON {
+            return Ok(0.0);
+        }
+        let dl = self.dominant_wavelength(white)?;
+        let obs = self.observer.data();
+        let locus = |nm: usize| obs.spectral_locus_by_nm(nm).map(|xyz| xyz.xy().values());
+        let (a, b) = if dl >= 0.0 {
+            (locus(dl.floor() as usize)?, locus(dl.ceil() as usize)?)
+        } else {
+            (locus(obs.spectral_locus_nm_min())?, locus(obs.spectral_locus_nm_max())?)
+        };
+        let [xb, yb] = if a == b {
+            a
+        } else {
+            LineAB::try_new([xw, yw], [x, y])?.intersect(&LineAB::try_new(a, b)?)?.0
+        };
+        Ok(d / math::hypot(xb - xw, yb - yw))
+    }
+
+    /**
+    CIE whiteness W, and tint T<sub>W</sub>, as defined in CIE 15:2004, of a sample, with its
+    tristimulus values relative to a reference white, taken from the stimulus values of `white`
+    if present, or else from its illuminant values, with a luminous value of 100.
+    The formulas are defined for the D65 illuminant, and the CIE 1931 or CIE 1964 observers, and
+    are only valid for nearly white samples, with a whiteness W between 40 and 5Y − 280, and a
+    tint between −4 and 2, with positive values for greenish, and negative values for reddish,
+    samples.
+    ```
+    use colorimetry::prelude::*;
+
+    let d65 = CIE1931.xyz(&StdIlluminant::D65, None);
+    let paper = CIE1931.xyz(&StdIlluminant::D65, Some(&Colorant::gray(0.9)));
+    let (w, tw) = paper.cie_whiteness(d65).unwrap();
+    approx::assert_abs_diff_eq!(w, 90.0, epsilon = 1E-6);
+    approx::assert_abs_diff_eq!(tw, 0.0, epsilon = 1E-6);
+    ```
+    */
+    pub fn cie_whiteness(&self, white: XYZ) -> Result<(f64, f64), CmtError> {
+        if white.observer != self.observer {
+            return Err(CmtError::RequireSameObserver);
+        }
+        let tw_x = match self.observer {
+            Observer::Std1931 => 1000.0,
+            #[cfg(feature="supplemental-observers")]
+            Observer::Std1964 => 900.0,
+            #[allow(unreachable_patterns)]
+            _ => return Err(CmtError::ErrorString("CIE whiteness: requires the CIE 1931 or CIE 1964 observer".into())),
+        };
+        let xyz = self.xyz.ok_or(CmtError::NoColorant)?;
+        let y = 100.0 * xyz.y / white.xyz.unwrap_or(white.xyzn).y;
+        let [x0, y0] = self.xy().values();
+        let [xn, yn] = white.xy().values();
+        Ok((y + 800.0 * (xn - x0) + 1700.0 * (yn - y0), tw_x * (xn - x0) - 650.0 * (yn - y0)))
+    }
+
+    /**
+    ASTM E313 yellowness index, YI = 100 (C<sub>X</sub>X − C<sub>Z</sub>Z) / Y, of a sample
+    illuminated by the D65 illuminant, using the coefficients C<sub>X</sub> and C<sub>Z</sub> of
+    the CIE 1931, or the CIE 1964, observer, with positive values for yellowish, and negative
+    values for bluish, samples.
+    ```
+    use colorimetry::prelude::*;
+
+    let paper = CIE1931.xyz(&StdIlluminant::D65, Some(&Colorant::gray(0.9)));
+    approx::assert_abs_diff_eq!(paper.yellowness_e313().unwrap(), 0.0, epsilon = 0.1);
+    let aged = CIE1931.xyz(&StdIlluminant::D65, Some(&Colorant::gaussian(600.0, 200.0)));
+    assert!(aged.yellowness_e313().unwrap() > 5.0);
+    ```
+    */
+    pub fn yellowness_e313(&self) -> Result<f64, CmtError> {
+        let [cx, cz] = match self.observer {
+            Observer::Std1931 => [1.2985, 1.1335],
+            #[cfg(feature="supplemental-observers")]
+            Observer::Std1964 => [1.3013, 1.1498],
+            #[allow(unreachable_patterns)]
+            _ => return Err(CmtError::ErrorString("ASTM E313: requires the CIE 1931 or CIE 1964 observer".into())),
+        };
+        let xyz = self.xyz.ok_or(CmtError::NoColorant)?;
+        Ok(100.0 * (cx * xyz.x - cz * xyz.z) / xyz.y)
+    }
+
+    /// Wavelength of the spectral locus point nearest to the chromaticity of these tristimulus
+    /// values, and the distance to it, in the xy chromaticity diagram of their observer.
+    /// See [`ObserverData::spectral_locus_nearest`](crate::observer::ObserverData::spectral_locus_nearest).
+    pub fn spectral_locus_nearest(&self) -> (f64, f64) {
+        self.observer.data().spectral_locus_nearest(self.xy().values())
+    }
+
 
     #[cfg(feature="cct")]
     pub fn cct(self) -> Result<crate::cct::CCT, CmtError> {
@@ -286,6 +474,22 @@ impl XYZ {
         }
     }
 
+    /**
+    RGB values in a color space, as [`rgb`](Self::rgb), mapped into the gamut of the color space
+    using a [`GamutMapping`](crate::rgb::GamutMapping) strategy, for display-bound conversions.
+    ```
+    use colorimetry::prelude::*;
+
+    let laser = CIE1931.xyz(&StdIlluminant::D65, Some(&Colorant::gaussian(532.0, 1.0)));
+    assert!(!laser.rgb(None).is_in_gamut());
+    let rgb = laser.rgb_mapped(Some(RgbSpace::SRGB), GamutMapping::Cusp);
+    assert!(rgb.is_in_gamut());
+    ```
+    */
+    pub fn rgb_mapped(&self, space: Option<RgbSpace>, mapping: crate::rgb::GamutMapping) -> RGB {
+        self.rgb(space).gamut_mapped(mapping)
+    }
+
     pub fn srgb(&self) -> [u8;3] {
         self.rgb(None).into()
     }
@@ -307,7 +511,7 @@ impl From<XYZ> for [f64;3] {
     fn from(xyz0: XYZ) -> Self {
         let xyz = xyz0.xyz.unwrap_or(xyz0.xyzn);
         let s = 100.0/xyz0.xyzn.y;
-        xyz.into_iter().map(|&v|s*v).collect::<Vec<f64>>().try_into().unwrap()
+        (xyz * s).into()
     }
 }
 
@@ -499,7 +703,7 @@ impl XYZ {
     /// Get the chromaticity coordinates
     #[wasm_bindgen(js_name=chromaticity)]
     pub fn chromaticity_js(&self)->js_sys::Array {
-        let [x, y] = self.chromaticity();
+        let [x, y] = self.xy().values();
         js_sys::Array::of2(&x.into(), &y.into())
     }
 
@@ -508,6 +712,21 @@ impl XYZ {
     pub fn luminous_value_js(&self)->f64 {
         self.luminous_value()
     }
+
+    /// Get the CIE 1976 u' and v' chromaticity coordinates
+    #[wasm_bindgen(js_name=uvPrime)]
+    pub fn uv_prime_js(&self)->js_sys::Array {
+        let [u, v] = self.xy().uvprime().values();
+        js_sys::Array::of2(&u.into(), &v.into())
+    }
+
+    /// Get the CIELAB values, relative to the reference white of this color, as an array with
+    /// the L*, a*, and b* values.
+    #[wasm_bindgen(js_name=lab)]
+    pub fn lab_js(&self)->Result<js_sys::Array, crate::error::CmtError> {
+        let [l, a, b] = crate::lab::CieLab::try_from(*self)?.values();
+        Ok(js_sys::Array::of3(&l.into(), &a.into(), &b.into()))
+    }
 }
 
 #[cfg(test)]
@@ -547,20 +766,65 @@ mod xyz_test {
 
     }
 
+    #[test]
+    fn excitation_purity_test(){
+        let d65 = CIE1931.xyz_d65();
+        assert_ulps_eq!(d65.excitation_purity(d65).unwrap(), 0.0);
+        // a mixture of white and a spectral color, with equal luminance, has its chromaticity
+        // on the line between them
+        for wl in [450usize, 500, 550, 600] {
+            let sl = CIE1931.spectral_locus_by_nm(wl).unwrap();
+            let mix = d65.set_illuminance(50.0).try_add(sl.set_illuminance(50.0)).unwrap();
+            let [x, y] = mix.xy().values();
+            let [xw, yw] = d65.xy().values();
+            let [xs, ys] = sl.xy().values();
+            assert_ulps_eq!(mix.excitation_purity(d65).unwrap(), (x - xw).hypot(y - yw) / (xs - xw).hypot(ys - yw), epsilon = 1E-9);
+        }
+        // purples are bounded by the purple line
+        let blue = CIE1931.spectral_locus_by_nm(CIE1931.spectral_locus_nm_min()).unwrap();
+        let red = CIE1931.spectral_locus_by_nm(CIE1931.spectral_locus_nm_max()).unwrap();
+        let purple = blue.try_add(red).unwrap();
+        assert_ulps_eq!(purple.excitation_purity(d65).unwrap(), 1.0, epsilon = 1E-9);
+    }
+
+    #[test]
+    fn whiteness_test(){
+        let d65 = CIE1931.xyz(&StdIlluminant::D65, None);
+        // a bluish white is whiter, and a yellowish white less white, than a neutral white
+        let neutral = CIE1931.xyz(&StdIlluminant::D65, Some(&Colorant::gray(0.85)));
+        let bluish = CIE1931.xyz(&StdIlluminant::D65, Some(&(Colorant::gray(0.8) + Colorant::gaussian(450.0, 30.0) * 0.1)));
+        let yellowish = CIE1931.xyz(&StdIlluminant::D65, Some(&(Colorant::gray(0.8) + Colorant::gaussian(580.0, 30.0) * 0.1)));
+        let w = |xyz: XYZ| xyz.cie_whiteness(d65).unwrap().0;
+        assert!(w(bluish) > w(neutral) && w(neutral) > w(yellowish));
+        assert!(bluish.yellowness_e313().unwrap() < 0.0 && yellowish.yellowness_e313().unwrap() > 0.0);
+        assert!(d65.cie_whiteness(d65).is_err());
+
+        #[cfg(feature="supplemental-observers")]
+        {
+            let d65 = CIE1964.xyz(&StdIlluminant::D65, None);
+            let neutral = CIE1964.xyz(&StdIlluminant::D65, Some(&Colorant::gray(0.85)));
+            let (w, tw) = neutral.cie_whiteness(d65).unwrap();
+            assert_ulps_eq!(w, 85.0, epsilon = 1E-9);
+            assert_ulps_eq!(tw, 0.0, epsilon = 1E-9);
+            assert_ulps_eq!(neutral.yellowness_e313().unwrap(), 0.0, epsilon = 0.2);
+            assert!(CIE2015.xyz(&StdIlluminant::D65, Some(&Colorant::gray(0.85))).yellowness_e313().is_err());
+        }
+    }
+
     #[test]
     fn dominant_wavelength_purple_test(){
         let d65 = CIE1931.xyz_d65();
-        let [xw, yw] = d65.chromaticity();
+        let [xw, yw] = d65.xy().values();
         
         // get purple line
         let xyzb = CIE1931.spectral_locus_by_nm(380).unwrap();
-        let [xb, yb] = xyzb.chromaticity();
+        let [xb, yb] = xyzb.xy().values();
         let xyzr = CIE1931.spectral_locus_by_nm(699).unwrap();
-        let [xr, yr] = xyzr.chromaticity();
+        let [xr, yr] = xyzr.xy().values();
         let line_t = LineAB::try_new([xb, yb], [xr, yr]).unwrap();
         for wl in 380..=699usize {
             let sl = CIE1931.spectral_locus_by_nm(wl).unwrap();
-            let [x, y] = sl.chromaticity();
+            let [x, y] = sl.xy().values();
             let line_u = LineAB::try_new([x, y], [xw, yw]).unwrap();
             let ([xi, yi], t, _) = line_t.intersect(&line_u).unwrap();
             if t>0.0 && t<1.0 {
@@ -581,7 +845,7 @@ mod xyz_test {
     fn test_rgb_roundtrip() {
         let rgb_blue = RGB::new(0.0, 0.0, 1.0, Some(Observer::Std1931), Some(RgbSpace::SRGB));
         let xyz_blue = rgb_blue.xyz();
-        let xy_blue = xyz_blue.chromaticity();
+        let xy_blue = xyz_blue.xy().values();
         assert_ulps_eq!(xy_blue.as_ref(), [0.15, 0.06].as_ref(), epsilon = 1E-5);
         let rgbb = xyz_blue.rgb(None);
         assert_ulps_eq!(rgbb, rgb_blue);